@@ -1,15 +1,27 @@
 #![doc = include_str!("../README.md")]
 #![allow(clippy::needless_doctest_main)]
 
-use es_fluent_toml::I18nConfig;
+use es_fluent_toml::{AssetsLayout, CONFIG_PATH_ENV, I18nConfig};
+use std::fs;
 use std::path::Path;
 
 #[allow(clippy::needless_doctest_main)]
 /// Emits Cargo rebuild hints for `i18n.toml` and the configured assets directory.
 ///
+/// The configuration file named by the `ES_FLUENT_CONFIG` environment variable
+/// replaces the crate and workspace `i18n.toml` when set, and changing the
+/// variable also triggers a rebuild.
+///
 /// Call this from your crate's `build.rs` to ensure changes to locale files
 /// (including renames and deletions) trigger a rebuild, keeping embedded/localized data fresh.
 ///
+/// On a fresh checkout the assets directory may not exist yet. It is created
+/// together with the fallback-language subdirectory, so the manager macros see
+/// an empty locale tree instead of failing before `cargo es-fluent generate`
+/// has run. With `layout = "domain_first"` only the assets directory is
+/// created, since each domain folder holds its own `{language}.ftl` files. While the fallback folder holds no `.ftl` files, a Cargo warning
+/// reports that every lookup will miss until translations are generated.
+///
 /// # Example
 ///
 /// ```no_run
@@ -20,15 +32,56 @@ use std::path::Path;
 /// ```
 pub fn track_i18n_assets() {
     let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR must be set");
-    let config =
-        I18nConfig::read_from_manifest_dir().expect("Failed to read i18n.toml configuration");
+    for directive in i18n_asset_directives(Path::new(&manifest_dir)) {
+        println!("cargo:{directive}");
+    }
+}
+
+/// Creates the missing assets skeleton for the crate in `manifest_dir` and
+/// returns the Cargo directives, without the `cargo:` prefix, that
+/// [`track_i18n_assets`] prints.
+fn i18n_asset_directives(manifest_dir: &Path) -> Vec<String> {
+    let config = I18nConfig::from_manifest_dir_or_workspace(manifest_dir)
+        .expect("Failed to read i18n.toml configuration");
     let assets_dir = config
-        .assets_dir_from_manifest()
+        .assets_dir_from_base(Some(config.config_dir.as_deref().unwrap_or(manifest_dir)))
         .expect("Failed to resolve assets directory from i18n.toml");
+    let skeleton_dir = match config.layout {
+        AssetsLayout::LanguageFirst => assets_dir.join(config.fallback_language_id()),
+        AssetsLayout::DomainFirst => assets_dir.clone(),
+    };
+    if !skeleton_dir.is_dir() {
+        fs::create_dir_all(&skeleton_dir).unwrap_or_else(|error| {
+            panic!(
+                "Failed to create locale directory {}: {error}",
+                skeleton_dir.display()
+            )
+        });
+    }
+
+    let mut directives = Vec::new();
+    if let Some(warning) = missing_fallback_warning(&config) {
+        directives.push(format!("warning={warning}"));
+    }
 
-    let config_path = Path::new(&manifest_dir).join("i18n.toml");
-    println!("cargo:rerun-if-changed={}", config_path.display());
-    println!("cargo:rerun-if-changed={}", assets_dir.display());
+    let crate_config_path = manifest_dir.join("i18n.toml");
+    directives.push(format!("rerun-if-env-changed={CONFIG_PATH_ENV}"));
+    directives.push(format!("rerun-if-changed={}", crate_config_path.display()));
+    if let Some(config_path) = I18nConfig::config_path_for_manifest_dir(manifest_dir)
+        && config_path != crate_config_path
+    {
+        directives.push(format!("rerun-if-changed={}", config_path.display()));
+    }
+    directives.push(format!("rerun-if-changed={}", assets_dir.display()));
+    directives
+}
+
+/// Describes a fallback locale folder without FTL files, which the manager
+/// macros accept so a fresh checkout still builds.
+fn missing_fallback_warning(config: &I18nConfig) -> Option<String> {
+    config.validate_fallback_present().err().map(|error| {
+        format!("{error}; run `cargo es-fluent generate` so fallback lookups have messages")
+    })
 }
 
 #[cfg(test)]
@@ -63,6 +116,111 @@ mod tests {
         });
     }
 
+    #[test]
+    fn track_i18n_assets_creates_missing_assets_skeleton() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        fs::write(
+            temp.path().join("i18n.toml"),
+            "fallback_language = \"en-US\"\nassets_dir = \"i18n\"\n",
+        )
+        .expect("write config");
+
+        with_manifest_env(Some(temp.path()), || {
+            track_i18n_assets();
+        });
+
+        assert!(temp.path().join("i18n").join("en-US").is_dir());
+        assert_eq!(
+            fs::read_dir(temp.path().join("i18n").join("en-US"))
+                .expect("read fallback dir")
+                .count(),
+            0,
+            "skeleton should not contain generated files"
+        );
+    }
+
+    #[test]
+    fn track_i18n_assets_creates_only_the_assets_dir_for_domain_first_layout() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        fs::write(
+            temp.path().join("i18n.toml"),
+            "fallback_language = \"en-US\"\nassets_dir = \"i18n\"\nlayout = \"domain_first\"\n",
+        )
+        .expect("write config");
+
+        let directives = i18n_asset_directives(temp.path());
+
+        assert!(temp.path().join("i18n").is_dir());
+        assert_eq!(
+            fs::read_dir(temp.path().join("i18n"))
+                .expect("read assets dir")
+                .count(),
+            0,
+            "a fallback folder would read as a domain named after the language"
+        );
+        assert!(directives.contains(&format!(
+            "rerun-if-changed={}",
+            temp.path().join("i18n").display()
+        )));
+    }
+
+    #[test]
+    fn missing_fallback_warning_clears_once_fallback_ftl_exists() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        fs::create_dir_all(temp.path().join("i18n/en-US")).expect("create fallback dir");
+        fs::write(
+            temp.path().join("i18n.toml"),
+            "fallback_language = \"en-US\"\nassets_dir = \"i18n\"\n",
+        )
+        .expect("write config");
+        let config = I18nConfig::from_manifest_dir(temp.path()).expect("read config");
+
+        let warning = with_manifest_env(Some(temp.path()), || missing_fallback_warning(&config))
+            .expect("empty fallback folder should warn");
+        assert!(
+            warning.contains("en-US' contains no .ftl files"),
+            "{warning}"
+        );
+
+        fs::write(temp.path().join("i18n/en-US/app.ftl"), "hello = Hello").expect("write ftl");
+        assert_eq!(
+            with_manifest_env(Some(temp.path()), || missing_fallback_warning(&config)),
+            None
+        );
+    }
+
+    #[test]
+    fn track_i18n_assets_reads_config_from_env_path() {
+        let crate_dir = tempfile::tempdir().expect("crate dir");
+        let config_dir = tempfile::tempdir().expect("config dir");
+        fs::create_dir_all(config_dir.path().join("locales")).expect("create assets dir");
+        let config_path = config_dir.path().join("i18n.toml");
+        fs::write(
+            &config_path,
+            "fallback_language = \"en-US\"\nassets_dir = \"locales\"\n",
+        )
+        .expect("write config");
+
+        let directives = temp_env::with_var(CONFIG_PATH_ENV, Some(&config_path), || {
+            with_manifest_env(Some(crate_dir.path()), || {
+                i18n_asset_directives(crate_dir.path())
+            })
+        });
+
+        assert!(
+            directives.contains(&format!(
+                "rerun-if-changed={}",
+                config_dir.path().join("locales").display()
+            )),
+            "env-pointed assets dir should be tracked: {directives:?}"
+        );
+        assert!(
+            directives.contains(&format!("rerun-if-changed={}", config_path.display())),
+            "env-pointed config should be tracked: {directives:?}"
+        );
+        assert!(!crate_dir.path().join("locales").exists());
+    }
+
     #[test]
     fn track_i18n_assets_does_not_create_stamp_file() {
         let temp = tempfile::tempdir().expect("tempdir");
@@ -85,6 +243,42 @@ mod tests {
         assert!(!stamp.exists(), "stamp file should not be created");
     }
 
+    #[test]
+    fn track_i18n_assets_falls_back_to_workspace_config() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let crate_dir = temp.path().join("crates").join("app");
+        fs::create_dir_all(&crate_dir).expect("create crate dir");
+        fs::create_dir_all(temp.path().join("i18n").join("en")).expect("create assets dir");
+        fs::write(
+            temp.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/app\"]\n",
+        )
+        .expect("write workspace manifest");
+        fs::write(
+            temp.path().join("i18n.toml"),
+            "fallback_language = \"en\"\nassets_dir = \"i18n\"\n",
+        )
+        .expect("write workspace config");
+
+        let directives = with_manifest_env(Some(&crate_dir), || i18n_asset_directives(&crate_dir));
+
+        assert!(
+            directives.contains(&format!(
+                "rerun-if-changed={}",
+                temp.path().join("i18n").display()
+            )),
+            "workspace assets dir should be tracked: {directives:?}"
+        );
+        assert!(
+            directives.contains(&format!(
+                "rerun-if-changed={}",
+                temp.path().join("i18n.toml").display()
+            )),
+            "workspace config should be tracked: {directives:?}"
+        );
+        assert!(!crate_dir.join("i18n").exists());
+    }
+
     #[test]
     fn track_i18n_assets_rejects_external_assets_dir_without_stamp_file() {
         let temp = tempfile::tempdir().expect("tempdir");
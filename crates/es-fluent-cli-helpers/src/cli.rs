@@ -1,11 +1,17 @@
 //! Inventory collection functionality for CLI commands.
 
-use es_fluent_runner::{ExpectedKey, InventoryData, PackageName, RunnerMetadataStore};
+use es_fluent_generate::ftl;
+use es_fluent_runner::{
+    CheckSummary, ExpectedKey, InventoryData, PackageName, RunnerMetadataStore,
+};
 use es_fluent_shared::fluent::{FluentArgumentName, FluentEntryId};
+use es_fluent_shared::meta::TypeKind;
 use es_fluent_shared::resource::{ModuleResourceSpec, ResourceRoute};
 use es_fluent_shared::source::{SourceFile, SourceLine};
+use es_fluent_toml::I18nConfig;
+use fs_err as fs;
 use std::collections::{BTreeMap, BTreeSet, btree_map::Entry};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Intermediate metadata for a key during collection.
 struct KeyMeta {
@@ -14,6 +20,8 @@ struct KeyMeta {
     source_file: Option<SourceFile>,
     source_line: SourceLine,
     source_description: String,
+    type_name: &'static str,
+    type_kind: TypeKind,
 }
 
 /// Collects inventory data for a crate and writes it to `inventory.json`.
@@ -34,10 +42,22 @@ pub fn write_inventory_for_crate_at(
     crate_name: &str,
     manifest_dir: &Path,
 ) -> Result<(), es_fluent_runner::RunnerIoError> {
+    let package_name = PackageName::try_new(crate_name)?;
+    let data = collect_inventory_for_crate_at(crate_name, manifest_dir)?;
+    RunnerMetadataStore::new(Path::new(".")).write_inventory(&package_name, &data)
+}
+
+/// Collects the expected FTL keys registered by `crate_name`.
+pub(crate) fn collect_inventory_for_crate_at(
+    crate_name: &str,
+    manifest_dir: &Path,
+) -> Result<InventoryData, es_fluent_runner::RunnerIoError> {
     let package_name = PackageName::try_new(crate_name)?;
     let crate_ident = package_name.rust_module_prefix();
+    let file_stem = ftl_file_stem(crate_name, manifest_dir)?;
 
-    // Collect all registered type infos for this crate
+    // Collect all registered type infos for this crate. `check` validates
+    // messages, so `#[fluent(as_terms)]` types are left to generation.
     let type_infos: Vec<_> = es_fluent::registry::get_all_ftl_type_infos()
         .filter(|info| {
             info.module_path() == crate_ident.as_str()
@@ -45,6 +65,7 @@ pub fn write_inventory_for_crate_at(
                     .module_path()
                     .starts_with(&format!("{}::", crate_ident.as_str()))
         })
+        .filter(|info| !info.is_term())
         .collect();
 
     // Build a map of expected keys with their metadata
@@ -59,7 +80,7 @@ pub fn write_inventory_for_crate_at(
                     ))
                 })?,
         )
-        .resource_spec(crate_name, true);
+        .resource_spec_with_base_file(crate_name, &file_stem, true);
         for variant in info.variants() {
             let key = variant.entry_id();
             let vars: BTreeSet<FluentArgumentName> = variant.argument_names().into_iter().collect();
@@ -71,6 +92,8 @@ pub fn write_inventory_for_crate_at(
                     source_file: info.source_file(),
                     source_line: variant.source_line(),
                     source_description: source_description.clone(),
+                    type_name: info.type_name(),
+                    type_kind: *info.type_kind(),
                 }),
                 Entry::Occupied(entry) => {
                     return Err(es_fluent_runner::RunnerIoError::Message(format!(
@@ -94,12 +117,118 @@ pub fn write_inventory_for_crate_at(
             resource: Some(meta.resource),
             source_file: meta.source_file,
             source_line: Some(meta.source_line),
+            type_name: Some(meta.type_name.to_string()),
+            type_kind: Some(meta.type_kind),
         })
         .collect();
 
-    let data = InventoryData { expected_keys };
+    Ok(InventoryData { expected_keys })
+}
 
-    RunnerMetadataStore::new(Path::new(".")).write_inventory(&package_name, &data)
+/// Returns the crate's base FTL file name without `.ftl`: the crate name, or
+/// `ftl_file_name` from the `i18n.toml` that applies to `manifest_dir`.
+fn ftl_file_stem(
+    crate_name: &str,
+    manifest_dir: &Path,
+) -> Result<String, es_fluent_runner::RunnerIoError> {
+    let Some(config_path) = I18nConfig::config_path_for_manifest_dir(manifest_dir) else {
+        return Ok(crate_name.to_string());
+    };
+    let config = I18nConfig::read_from_path(&config_path).map_err(|error| {
+        es_fluent_runner::RunnerIoError::Message(format!(
+            "failed to read {}: {error}",
+            config_path.display()
+        ))
+    })?;
+    Ok(config.ftl_file_stem(crate_name))
+}
+
+/// Diffs the expected keys against the message keys in the fallback locale.
+///
+/// Each key is looked up in the FTL file its resource routes to. The base
+/// `{file_stem}.ftl` file and every `{crate}/**/*.ftl` file under
+/// `fallback_dir` are scanned for orphans, so messages left in files no type
+/// routes to are reported too.
+pub(crate) fn check_summary(
+    crate_name: &str,
+    file_stem: &str,
+    fallback_dir: &Path,
+    inventory: &InventoryData,
+) -> Result<CheckSummary, es_fluent_runner::RunnerIoError> {
+    let mut expected_by_file: BTreeMap<PathBuf, BTreeSet<&str>> = BTreeMap::new();
+    for expected in &inventory.expected_keys {
+        let relative = expected.resource.as_ref().map_or_else(
+            || PathBuf::from(format!("{file_stem}.ftl")),
+            |resource| PathBuf::from(resource.locale_relative_path.as_str()),
+        );
+        expected_by_file
+            .entry(relative)
+            .or_default()
+            .insert(expected.key.as_str());
+    }
+
+    let mut files: BTreeSet<PathBuf> = expected_by_file.keys().cloned().collect();
+    files.extend(crate_ftl_files(crate_name, file_stem, fallback_dir)?);
+
+    let mut missing = BTreeSet::new();
+    let mut orphan = BTreeSet::new();
+    for relative in files {
+        // Syntax errors are reported by the check command itself; diff whatever parsed.
+        let (resource, _) = ftl::parse_ftl_file_with_errors(&fallback_dir.join(&relative))?;
+        let present = ftl::extract_message_keys(&resource);
+        let expected = expected_by_file.get(&relative);
+
+        if let Some(expected) = expected {
+            missing.extend(
+                expected
+                    .iter()
+                    .filter(|key| !present.contains(**key))
+                    .map(|key| key.to_string()),
+            );
+        }
+        orphan.extend(
+            present
+                .into_iter()
+                .filter(|key| expected.is_none_or(|expected| !expected.contains(key.as_str()))),
+        );
+    }
+
+    Ok(CheckSummary {
+        missing_in_ftl: missing.into_iter().collect(),
+        orphan_in_ftl: orphan.into_iter().collect(),
+    })
+}
+
+/// Lists the crate's FTL files under `fallback_dir`, relative to it.
+fn crate_ftl_files(
+    crate_name: &str,
+    file_stem: &str,
+    fallback_dir: &Path,
+) -> Result<Vec<PathBuf>, es_fluent_runner::RunnerIoError> {
+    let mut files = Vec::new();
+    let main_file = PathBuf::from(format!("{file_stem}.ftl"));
+    if fallback_dir.join(&main_file).is_file() {
+        files.push(main_file);
+    }
+
+    let mut pending = vec![PathBuf::from(crate_name)];
+    while let Some(relative_dir) = pending.pop() {
+        let dir = fallback_dir.join(&relative_dir);
+        if !dir.is_dir() {
+            continue;
+        }
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let relative = relative_dir.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                pending.push(relative);
+            } else if relative.extension().is_some_and(|ext| ext == "ftl") {
+                files.push(relative);
+            }
+        }
+    }
+
+    Ok(files)
 }
 
 #[cfg(test)]
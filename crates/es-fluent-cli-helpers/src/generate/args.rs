@@ -14,6 +14,15 @@ pub(super) enum Action {
         /// Parse mode
         #[arg(long, default_value_t = FluentParseMode::default())]
         mode: FluentParseMode,
+        /// Append new keys to the other locales with a TODO comment
+        #[arg(long)]
+        sync_locales: bool,
+        /// Also write `{file stem}.keys.json` next to the base FTL file
+        #[arg(long)]
+        keys_json: bool,
+        /// Add a `# @source:` comment naming the Rust item behind each entry
+        #[arg(long)]
+        annotate_source: bool,
         /// Dry run (don't write changes)
         #[arg(long)]
         dry_run: bool,
@@ -23,6 +32,9 @@ pub(super) enum Action {
         /// Clean all locales
         #[arg(long)]
         all: bool,
+        /// Rewrite `{file stem}.keys.json` without the removed keys
+        #[arg(long)]
+        keys_json: bool,
         /// Dry run (don't write changes)
         #[arg(long)]
         dry_run: bool,
@@ -33,6 +33,20 @@ pub enum GeneratorError {
         details: es_fluent_shared::namespace::NamespacePathError,
     },
 
+    /// A namespace filter matched no registered types.
+    #[error("No types in crate '{crate_name}' use namespace '{namespace}'")]
+    UnknownNamespace {
+        namespace: String,
+        crate_name: String,
+    },
+
+    /// A generated FTL file differs from what generation would write.
+    #[error(
+        "Generated FTL is stale: {}; regenerate and commit the result",
+        path.display()
+    )]
+    Stale { path: std::path::PathBuf },
+
     /// Failed to inspect locale directories.
     #[error("Locale discovery error: {0}")]
     RunnerIo(#[from] es_fluent_runner::RunnerIoError),
@@ -60,3 +60,24 @@ pub(super) fn validate_namespaces(
 
     Ok(())
 }
+
+pub(super) fn filter_namespace(
+    type_infos: Vec<&'static FtlTypeInfo>,
+    namespace: &str,
+    crate_name: &str,
+    manifest_dir: &Path,
+) -> Result<Vec<&'static FtlTypeInfo>, GeneratorError> {
+    let filtered: Vec<_> = type_infos
+        .into_iter()
+        .filter(|info| info.resolved_namespace(manifest_dir).as_deref() == Some(namespace))
+        .collect();
+
+    if filtered.is_empty() {
+        return Err(GeneratorError::UnknownNamespace {
+            namespace: namespace.to_string(),
+            crate_name: crate_name.to_string(),
+        });
+    }
+
+    Ok(filtered)
+}
@@ -0,0 +1,87 @@
+//! The `{crate}.keys.json` manifest written next to the generated FTL.
+
+use es_fluent::registry::FtlTypeInfo;
+use es_fluent_shared::fluent::{FluentArgumentName, FluentEntryId};
+use fs_err as fs;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Every message id generation writes for a crate, with its argument names.
+///
+/// Serialized as `{crate}.keys.json` when [`super::EsFluentGenerator`] is
+/// built with `keys_json(true)`, for tooling that should not parse FTL:
+///
+/// ```json
+/// {
+///   "messages": [
+///     { "id": "login_form-username", "args": [] },
+///     { "id": "welcome", "args": ["name"] }
+///   ]
+/// }
+/// ```
+#[derive(Clone, Debug, Default, serde::Deserialize, Eq, PartialEq, serde::Serialize)]
+pub struct KeysManifest {
+    /// Generated messages, sorted by id.
+    pub messages: Vec<KeysManifestEntry>,
+}
+
+/// One generated message in a [`KeysManifest`].
+#[derive(Clone, Debug, serde::Deserialize, Eq, PartialEq, serde::Serialize)]
+pub struct KeysManifestEntry {
+    /// The Fluent message id.
+    pub id: FluentEntryId,
+    /// The message's `$argument` names, in declaration order.
+    pub args: Vec<FluentArgumentName>,
+}
+
+impl KeysManifest {
+    /// Lists the messages generated for `type_infos`. Types marked
+    /// `skip_generate` and `#[fluent(as_terms)]` types are left out, since neither
+    /// adds a message to the generated FTL.
+    pub(super) fn from_type_infos(type_infos: &[&'static FtlTypeInfo]) -> Self {
+        let mut messages = BTreeMap::new();
+        for info in type_infos
+            .iter()
+            .filter(|info| !info.skip_generate() && !info.is_term())
+        {
+            for variant in info.variants() {
+                messages
+                    .entry(variant.entry_id())
+                    .or_insert_with(|| variant.argument_names());
+            }
+        }
+
+        Self {
+            messages: messages
+                .into_iter()
+                .map(|(id, args)| KeysManifestEntry { id, args })
+                .collect(),
+        }
+    }
+
+    fn to_json(&self) -> Result<String, serde_json::Error> {
+        Ok(format!("{}\n", serde_json::to_string_pretty(self)?))
+    }
+
+    /// Returns whether `path` already holds exactly this manifest.
+    pub(super) fn is_written_at(
+        &self,
+        path: &Path,
+    ) -> Result<bool, es_fluent_runner::RunnerIoError> {
+        let json = self.to_json()?;
+        Ok(fs::read_to_string(path).is_ok_and(|existing| existing == json))
+    }
+
+    /// Writes the manifest as pretty-printed JSON, leaving an identical file
+    /// untouched. Returns whether the file changed.
+    pub(super) fn write(&self, path: &Path) -> Result<bool, es_fluent_runner::RunnerIoError> {
+        if self.is_written_at(path)? {
+            return Ok(false);
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_json()?)?;
+        Ok(true)
+    }
+}
@@ -3,6 +3,7 @@
 mod args;
 mod error;
 mod inventory;
+mod keys_json;
 
 #[cfg(test)]
 mod tests;
@@ -11,8 +12,11 @@ use self::args::Action;
 
 pub use self::args::GeneratorArgs;
 pub use self::error::GeneratorError;
-pub use es_fluent_generate::FluentParseMode;
-use es_fluent_toml::{I18nConfigError, ResolvedI18nLayout};
+pub use self::keys_json::{KeysManifest, KeysManifestEntry};
+use es_fluent::registry::FtlTypeInfo;
+use es_fluent_generate::{AssetsLayout, FtlTarget};
+pub use es_fluent_generate::{FluentParseMode, GenerateReport, PostProcess, SortMode};
+use es_fluent_toml::ResolvedI18nLayout;
 use std::path::{Path, PathBuf};
 
 /// Builder for generating FTL files from registered types.
@@ -42,6 +46,47 @@ pub struct EsFluentGenerator {
     #[builder(into)]
     manifest_dir: Option<PathBuf>,
 
+    /// Only generate types whose resolved namespace matches, so just the
+    /// `{crate_name}/{namespace}.ftl` file is written (defaults to every
+    /// namespace and the crate's main file).
+    #[builder(into)]
+    namespace: Option<String>,
+
+    /// Also append new keys to every other locale's files, using the fallback
+    /// value under a `# TODO: translate` comment. Existing translations are
+    /// never overwritten. Defaults to off.
+    #[builder(default)]
+    sync_locales: bool,
+
+    /// Transforms each merged resource before it is serialized, for house-style
+    /// rules such as alphabetized groups or key prefixes. Runs once per file in
+    /// generate, clean, and [`Self::check_only`].
+    post_process: Option<PostProcess>,
+
+    /// Where merge mode inserts keys it adds to an existing group. Defaults to
+    /// appending them; `Alphabetical` slots them into sorted position.
+    #[builder(default)]
+    sort_mode: SortMode,
+
+    /// Banner written as a `###` resource comment at the top of every
+    /// generated file, one comment line per line of text. Regenerating keeps
+    /// a single copy.
+    #[builder(into)]
+    header: Option<String>,
+
+    /// Also write `{crate}.keys.json` next to the base FTL file, listing every
+    /// generated message id and its argument names as a [`KeysManifest`].
+    /// Defaults to off.
+    #[builder(default)]
+    keys_json: bool,
+
+    /// Add a `# @source: module::Type::Variant` comment naming the Rust item
+    /// behind every generated message and term. Conservative generation
+    /// rewrites the line when the type moves. Defaults to off, which removes
+    /// existing annotations.
+    #[builder(default)]
+    annotate_source: bool,
+
     /// Dry run (don't write changes).
     #[builder(default)]
     dry_run: bool,
@@ -50,17 +95,40 @@ pub struct EsFluentGenerator {
 impl EsFluentGenerator {
     /// Runs the generator based on command line arguments.
     pub fn run_cli(self) -> Result<bool, GeneratorError> {
+        self.run_cli_report().map(|report| report.changed)
+    }
+
+    /// Runs the generator based on command line arguments and reports which
+    /// keys and groups were, or in dry-run mode would be, added or removed.
+    pub fn run_cli_report(self) -> Result<GenerateReport, GeneratorError> {
         use clap::Parser as _;
         let args = GeneratorArgs::parse();
 
         match args.action {
-            Action::Generate { mode, dry_run } => {
+            Action::Generate {
+                mode,
+                sync_locales,
+                keys_json,
+                annotate_source,
+                dry_run,
+            } => {
                 let mut generator = self;
                 generator.mode = mode;
+                generator.sync_locales |= sync_locales;
+                generator.keys_json |= keys_json;
+                generator.annotate_source |= annotate_source;
                 generator.dry_run = dry_run;
-                generator.generate()
+                generator.generate_report()
+            },
+            Action::Clean {
+                all,
+                keys_json,
+                dry_run,
+            } => {
+                let mut generator = self;
+                generator.keys_json |= keys_json;
+                generator.clean_report(all, dry_run)
             },
-            Action::Clean { all, dry_run } => self.clean(all, dry_run),
         }
     }
 
@@ -97,6 +165,40 @@ impl EsFluentGenerator {
         Ok(PathBuf::from(manifest_dir))
     }
 
+    /// Base FTL file name from `ftl_file_name` in `i18n.toml`.
+    ///
+    /// When the output path is overridden the config is optional, and a
+    /// missing or unreadable one keeps the default `{crate_name}.ftl`.
+    fn resolve_ftl_file_stem(&self, crate_name: &str) -> Result<String, GeneratorError> {
+        match self.resolve_layout() {
+            Ok(layout) => Ok(layout.config.ftl_file_stem(crate_name)),
+            Err(_) if self.output_path.is_some() => Ok(crate_name.to_string()),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn resolve_assets_layout(&self) -> Result<AssetsLayout, GeneratorError> {
+        match self.resolve_layout() {
+            Ok(layout) => Ok(layout.config.layout),
+            Err(_) if self.output_path.is_some() => Ok(AssetsLayout::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn target<'a>(&'a self, crate_name: &'a str, file_stem: &'a str) -> FtlTarget<'a> {
+        let mut target = FtlTarget::new(crate_name)
+            .with_base_file_stem(file_stem)
+            .with_sort_mode(self.sort_mode)
+            .with_annotate_source(self.annotate_source);
+        if let Some(header) = &self.header {
+            target = target.with_header(header);
+        }
+        match self.post_process {
+            Some(post_process) => target.with_post_process(post_process),
+            None => target,
+        }
+    }
+
     fn resolve_layout(&self) -> Result<ResolvedI18nLayout, GeneratorError> {
         let manifest_dir = self.resolve_manifest_dir()?;
         Ok(ResolvedI18nLayout::from_manifest_dir(&manifest_dir)?)
@@ -126,14 +228,37 @@ impl EsFluentGenerator {
         Ok(paths)
     }
 
+    /// Locale directories other than the output path that receive stubs when
+    /// `sync_locales` is enabled.
+    fn resolve_locale_stub_dirs(&self, output_path: &Path) -> Result<Vec<PathBuf>, GeneratorError> {
+        if !self.sync_locales {
+            return Ok(Vec::new());
+        }
+
+        let mut dirs = self.resolve_clean_paths(true)?;
+        dirs.retain(|dir| dir != output_path);
+        Ok(dirs)
+    }
+
     /// Generates FTL files from all registered types.
     pub fn generate(&self) -> Result<bool, GeneratorError> {
+        self.generate_report().map(|report| report.changed)
+    }
+
+    /// Generates FTL files from all registered types and reports the keys and
+    /// groups that were added or removed.
+    ///
+    /// With `dry_run` enabled nothing is written and the report describes what
+    /// a real run would change.
+    pub fn generate_report(&self) -> Result<GenerateReport, GeneratorError> {
         let crate_name = self.resolve_crate_name()?;
         let output_path = self.resolve_output_path()?;
         let manifest_dir = self.resolve_manifest_dir()?;
-        let type_infos = self::inventory::collect_type_infos(&crate_name);
-
-        self::inventory::validate_namespaces(&type_infos, &manifest_dir)?;
+        let file_stem = self.resolve_ftl_file_stem(&crate_name)?;
+        let target = self
+            .target(&crate_name, &file_stem)
+            .with_layout(self.resolve_assets_layout()?);
+        let type_infos = self.resolve_type_infos(&crate_name, &manifest_dir)?;
 
         tracing::info!(
             "Generating FTL files for {} types in crate '{}'",
@@ -141,26 +266,113 @@ impl EsFluentGenerator {
             crate_name
         );
 
-        let changed = es_fluent_generate::generate(
-            &crate_name,
-            output_path,
+        let locale_stub_dirs = self.resolve_locale_stub_dirs(&output_path)?;
+        let mut report = es_fluent_generate::generate_report_with_locale_stubs(
+            target,
+            &output_path,
+            &locale_stub_dirs,
             &manifest_dir,
             &type_infos,
             self.mode,
             self.dry_run,
         )?;
 
-        Ok(changed)
+        if self.keys_json && !self.dry_run {
+            report.changed |= Self::keys_manifest(&crate_name)
+                .write(&Self::keys_json_path(&output_path, &file_stem))?;
+        }
+
+        Ok(report)
+    }
+
+    /// The crate's `keys.json` manifest. It is built from every generated
+    /// type, so a namespace-filtered run still covers the whole crate.
+    fn keys_manifest(crate_name: &str) -> KeysManifest {
+        KeysManifest::from_type_infos(&self::inventory::collect_type_infos(crate_name))
+    }
+
+    fn keys_json_path(output_path: &Path, file_stem: &str) -> PathBuf {
+        output_path.join(format!("{file_stem}.keys.json"))
+    }
+
+    /// Checks that the generated FTL on disk is up to date, without writing.
+    ///
+    /// Runs the same merge as [`Self::generate`] in memory and returns
+    /// [`GeneratorError::Stale`] for the first file it would rewrite, so CI can
+    /// assert that regenerated translations were committed. With `keys_json`
+    /// set, a missing or outdated `keys.json` is stale too. Locale stubs from
+    /// `sync_locales` are not checked.
+    pub fn check_only(&self) -> Result<(), GeneratorError> {
+        let crate_name = self.resolve_crate_name()?;
+        let output_path = self.resolve_output_path()?;
+        let manifest_dir = self.resolve_manifest_dir()?;
+        let file_stem = self.resolve_ftl_file_stem(&crate_name)?;
+        let target = self
+            .target(&crate_name, &file_stem)
+            .with_layout(self.resolve_assets_layout()?);
+        let type_infos = self.resolve_type_infos(&crate_name, &manifest_dir)?;
+
+        let stale = es_fluent_generate::stale_files(
+            target,
+            &output_path,
+            &manifest_dir,
+            &type_infos,
+            self.mode,
+        )?;
+        if let Some(path) = stale.into_iter().next() {
+            return Err(GeneratorError::Stale { path });
+        }
+
+        let keys_json = Self::keys_json_path(&output_path, &file_stem);
+        if self.keys_json && !Self::keys_manifest(&crate_name).is_written_at(&keys_json)? {
+            return Err(GeneratorError::Stale { path: keys_json });
+        }
+        Ok(())
+    }
+
+    /// Collects the crate's registered types, validated and narrowed to the
+    /// requested namespace.
+    fn resolve_type_infos(
+        &self,
+        crate_name: &str,
+        manifest_dir: &Path,
+    ) -> Result<Vec<&'static FtlTypeInfo>, GeneratorError> {
+        let type_infos = self::inventory::collect_type_infos(crate_name);
+
+        self::inventory::validate_namespaces(&type_infos, manifest_dir)?;
+        match &self.namespace {
+            Some(namespace) => {
+                self::inventory::filter_namespace(type_infos, namespace, crate_name, manifest_dir)
+            },
+            None => Ok(type_infos),
+        }
     }
 
     /// Cleans FTL files by removing orphan keys while preserving existing translations.
     pub fn clean(&self, all_locales: bool, dry_run: bool) -> Result<bool, GeneratorError> {
+        self.clean_report(all_locales, dry_run)
+            .map(|report| report.changed)
+    }
+
+    /// Cleans FTL files like [`Self::clean`] and reports the removed keys.
+    ///
+    /// With `keys_json` set, the `keys.json` manifest is rewritten without the
+    /// removed keys. Otherwise an existing manifest is left alone.
+    pub fn clean_report(
+        &self,
+        all_locales: bool,
+        dry_run: bool,
+    ) -> Result<GenerateReport, GeneratorError> {
         let crate_name = self.resolve_crate_name()?;
         let paths = self.resolve_clean_paths(all_locales)?;
         let manifest_dir = self.resolve_manifest_dir()?;
+        let file_stem = self.resolve_ftl_file_stem(&crate_name)?;
+        let target = self
+            .target(&crate_name, &file_stem)
+            .with_layout(self.resolve_assets_layout()?);
         let type_infos = self::inventory::collect_type_infos(&crate_name);
 
-        let mut any_changed = false;
+        let mut report = GenerateReport::default();
         for output_path in paths {
             if !dry_run {
                 tracing::info!(
@@ -171,38 +383,48 @@ impl EsFluentGenerator {
                 );
             }
 
-            if es_fluent_generate::clean::clean(
-                &crate_name,
+            report.merge(es_fluent_generate::clean::clean_report(
+                target,
                 output_path,
                 &manifest_dir,
                 &type_infos,
                 dry_run,
-            )? {
-                any_changed = true;
-            }
+            )?);
+        }
+
+        if self.keys_json {
+            let keys_json = Self::keys_json_path(&self.resolve_output_path()?, &file_stem);
+            let manifest = Self::keys_manifest(&crate_name);
+            report.changed |= if dry_run {
+                !manifest.is_written_at(&keys_json)?
+            } else {
+                manifest.write(&keys_json)?
+            };
         }
 
-        Ok(any_changed)
+        Ok(report)
     }
 
     fn resolve_clean_locale_dirs(&self, assets_dir: &Path) -> Result<Vec<PathBuf>, GeneratorError> {
         let manifest_dir = self.resolve_manifest_dir()?;
-        let config_assets_dir = if assets_dir.is_absolute() {
-            assets_dir.strip_prefix(&manifest_dir).map_err(|_| {
-                I18nConfigError::InvalidAssetsDir {
-                    path: assets_dir.display().to_string(),
-                    reason: "must be relative to the crate root",
-                }
-            })?
-        } else {
-            assets_dir
+        let config_assets_dir = assets_dir.strip_prefix(&manifest_dir).unwrap_or(assets_dir);
+
+        // The override replaces only the assets directory; the config still
+        // supplies the `supported_locales` allowlist. Only a missing config
+        // falls back to listing every locale.
+        let config = match es_fluent_toml::ResolvedI18nLayout::from_manifest_dir(&manifest_dir) {
+            Ok(layout) => es_fluent_toml::I18nConfig {
+                assets_dir: config_assets_dir.to_path_buf(),
+                config_dir: None,
+                ..layout.config
+            },
+            Err(es_fluent_toml::I18nConfigError::NotFound) => es_fluent_toml::I18nConfig::builder()
+                .fallback_language("en")
+                .assets_dir(config_assets_dir)
+                .build()?,
+            Err(err) => return Err(err.into()),
         };
 
-        let config = es_fluent_toml::I18nConfig::builder()
-            .fallback_language("en".parse().expect("static fallback language"))
-            .assets_dir(config_assets_dir)
-            .build();
-
         Ok(config
             .available_locale_names_from_base(Some(&manifest_dir))?
             .into_iter()
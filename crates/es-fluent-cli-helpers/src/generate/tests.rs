@@ -30,6 +30,42 @@ static INVALID_NAMESPACE_INFO: FtlTypeInfo = FtlTypeInfo::new(
     "test_crate",
     Some(__macro::namespace_literal("../escape")),
 );
+static UI_VARIANTS: &[FtlVariant] = &[FtlVariant::new(
+    "Save",
+    __macro::static_entry_id("button-Save"),
+    &[],
+    "test",
+    0,
+)];
+static UI_INFO: FtlTypeInfo = FtlTypeInfo::new(
+    TypeKind::Enum,
+    "Button",
+    UI_VARIANTS,
+    "src/lib.rs",
+    "namespace_filter_crate",
+    Some(__macro::namespace_literal("ui")),
+);
+static MAIN_VARIANTS: &[FtlVariant] = &[FtlVariant::new(
+    "Title",
+    __macro::static_entry_id("title"),
+    &[],
+    "test",
+    0,
+)];
+static MAIN_INFO: FtlTypeInfo = FtlTypeInfo::new(
+    TypeKind::Struct,
+    "Title",
+    MAIN_VARIANTS,
+    "src/lib.rs",
+    "namespace_filter_crate",
+    None,
+);
+es_fluent::__inventory::submit! {
+    es_fluent::registry::RegisteredFtlType(&UI_INFO)
+}
+es_fluent::__inventory::submit! {
+    es_fluent::registry::RegisteredFtlType(&MAIN_INFO)
+}
 static CLEAN_VARIANTS: &[FtlVariant] = &[FtlVariant::new(
     "Key1",
     __macro::static_entry_id("group_a-Key1"),
@@ -49,6 +85,75 @@ es_fluent::__inventory::submit! {
     es_fluent::registry::RegisteredFtlType(&CLEAN_INFO)
 }
 
+static KEYS_JSON_VARIANTS: &[FtlVariant] = &[
+    FtlVariant::new(
+        "Welcome",
+        __macro::static_entry_id("welcome"),
+        &[
+            __macro::static_argument_name("name"),
+            __macro::static_argument_name("count"),
+        ],
+        "test",
+        0,
+    ),
+    FtlVariant::new(
+        "Farewell",
+        __macro::static_entry_id("farewell"),
+        &[],
+        "test",
+        0,
+    ),
+];
+static KEYS_JSON_INFO: FtlTypeInfo = FtlTypeInfo::new(
+    TypeKind::Enum,
+    "Greeting",
+    KEYS_JSON_VARIANTS,
+    "src/lib.rs",
+    "keys_json_crate",
+    None,
+);
+static KEYS_JSON_SKIPPED_VARIANTS: &[FtlVariant] = &[FtlVariant::new(
+    "Hidden",
+    __macro::static_entry_id("hidden"),
+    &[],
+    "test",
+    0,
+)];
+static KEYS_JSON_SKIPPED_INFO: FtlTypeInfo = FtlTypeInfo::new(
+    TypeKind::Struct,
+    "Hidden",
+    KEYS_JSON_SKIPPED_VARIANTS,
+    "src/lib.rs",
+    "keys_json_crate",
+    None,
+)
+.with_skip_generate();
+static KEYS_JSON_TERM_VARIANTS: &[FtlVariant] = &[FtlVariant::new(
+    "Brand",
+    __macro::static_entry_id("brand"),
+    &[],
+    "test",
+    0,
+)];
+static KEYS_JSON_TERM_INFO: FtlTypeInfo = FtlTypeInfo::new(
+    TypeKind::Struct,
+    "Brand",
+    KEYS_JSON_TERM_VARIANTS,
+    "src/lib.rs",
+    "keys_json_crate",
+    None,
+)
+.with_term();
+es_fluent::__inventory::submit! {
+    es_fluent::registry::RegisteredFtlType(&KEYS_JSON_INFO)
+}
+es_fluent::__inventory::submit! {
+    es_fluent::registry::RegisteredFtlType(&KEYS_JSON_SKIPPED_INFO)
+}
+es_fluent::__inventory::submit! {
+    es_fluent::registry::RegisteredFtlType(&KEYS_JSON_TERM_INFO)
+}
+
 fn with_env_var<T>(key: &str, value: Option<&str>, f: impl FnOnce() -> T) -> T {
     temp_env::with_var(key, value, f)
 }
@@ -266,6 +371,77 @@ fn resolve_clean_paths_honors_assets_dir_override_for_all_locales() {
     );
 }
 
+#[test]
+fn resolve_clean_paths_skips_locales_outside_supported_locales() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let Value::Table(mut config) = i18n_config("en-US", "i18n", &[]) else {
+        unreachable!("i18n_config builds a table");
+    };
+    config.insert(
+        "supported_locales".to_string(),
+        Value::Array(vec![string_value("fr")]),
+    );
+    write_toml(&temp.path().join("i18n.toml"), &Value::Table(config));
+    for locale in ["en-US", "fr", "de"] {
+        fs::create_dir_all(temp.path().join("i18n").join(locale)).expect("mkdir locale");
+    }
+    let override_assets = temp.path().join("custom-assets");
+    fs::create_dir_all(override_assets.join("fr")).expect("mkdir fr");
+    fs::create_dir_all(override_assets.join("ja")).expect("mkdir ja");
+
+    let generator = EsFluentGenerator::builder()
+        .crate_name("missing-crate")
+        .manifest_dir(temp.path())
+        .build();
+    assert_eq!(
+        generator
+            .resolve_clean_paths(true)
+            .expect("all clean paths"),
+        vec![temp.path().join("i18n/en-US"), temp.path().join("i18n/fr")]
+    );
+
+    let generator = EsFluentGenerator::builder()
+        .crate_name("missing-crate")
+        .manifest_dir(temp.path())
+        .assets_dir(&override_assets)
+        .build();
+    assert_eq!(
+        generator
+            .resolve_clean_paths(true)
+            .expect("override clean paths"),
+        vec![override_assets.join("fr")]
+    );
+}
+
+#[test]
+fn resolve_clean_paths_with_assets_override_reports_invalid_config() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let Value::Table(mut config) = i18n_config("en-US", "i18n", &[]) else {
+        unreachable!("i18n_config builds a table");
+    };
+    config.insert(
+        "supported_locales".to_string(),
+        Value::Array(vec![string_value("fr_FR!")]),
+    );
+    write_toml(&temp.path().join("i18n.toml"), &Value::Table(config));
+    let override_assets = temp.path().join("custom-assets");
+    fs::create_dir_all(override_assets.join("fr")).expect("mkdir fr");
+
+    let generator = EsFluentGenerator::builder()
+        .crate_name("missing-crate")
+        .manifest_dir(temp.path())
+        .assets_dir(&override_assets)
+        .build();
+
+    let err = generator
+        .resolve_clean_paths(true)
+        .expect_err("invalid config should fail");
+    assert!(matches!(
+        err,
+        GeneratorError::Config(es_fluent_toml::I18nConfigError::InvalidSupportedLocale { .. })
+    ));
+}
+
 #[test]
 fn resolve_clean_paths_rejects_invalid_locale_directory_names() {
     let temp = tempfile::tempdir().expect("tempdir");
@@ -362,6 +538,261 @@ fn generate_and_clean_handle_empty_inventory() {
     assert!(!clean_all_changed);
 }
 
+#[test]
+fn generate_with_namespace_only_writes_that_namespace_file() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    write_basic_i18n_config(temp.path());
+
+    let report = EsFluentGenerator::builder()
+        .crate_name("namespace-filter-crate")
+        .manifest_dir(temp.path())
+        .namespace("ui")
+        .build()
+        .generate_report()
+        .expect("generate");
+
+    assert!(report.changed);
+    assert_eq!(report.added, ["button-Save"]);
+    let ui_file = temp.path().join("i18n/en-US/namespace-filter-crate/ui.ftl");
+    assert!(
+        fs::read_to_string(&ui_file)
+            .expect("read ui ftl")
+            .contains("button-Save = Save")
+    );
+    assert!(
+        !temp
+            .path()
+            .join("i18n/en-US/namespace-filter-crate.ftl")
+            .exists()
+    );
+}
+
+#[test]
+fn check_only_reports_stale_ftl_without_writing() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    write_basic_i18n_config(temp.path());
+    let generator = EsFluentGenerator::builder()
+        .crate_name("namespace-filter-crate")
+        .manifest_dir(temp.path())
+        .namespace("ui")
+        .build();
+    let ui_file = temp.path().join("i18n/en-US/namespace-filter-crate/ui.ftl");
+
+    let err = generator
+        .check_only()
+        .expect_err("missing generated file should be stale");
+    assert!(matches!(&err, GeneratorError::Stale { path } if path == &ui_file));
+    assert!(err.to_string().contains("Generated FTL is stale"));
+    assert!(!ui_file.exists());
+
+    generator.generate().expect("generate");
+    generator.check_only().expect("fresh FTL should pass");
+
+    fs::write(&ui_file, "").expect("truncate ui ftl");
+    assert!(matches!(
+        generator.check_only(),
+        Err(GeneratorError::Stale { path }) if path == ui_file
+    ));
+    assert_eq!(fs::read_to_string(&ui_file).expect("read ui ftl"), "");
+}
+
+fn add_house_style_comment(resource: &mut fluent_syntax::ast::Resource<String>) {
+    let comment = fluent_syntax::ast::Entry::ResourceComment(fluent_syntax::ast::Comment {
+        content: vec!["House style".to_string()],
+    });
+    resource.body.retain(|entry| entry != &comment);
+    resource.body.insert(0, comment);
+}
+
+#[test]
+fn post_process_runs_on_each_generated_file_before_serialization() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    write_basic_i18n_config(temp.path());
+    let generator = EsFluentGenerator::builder()
+        .crate_name("namespace-filter-crate")
+        .manifest_dir(temp.path())
+        .post_process(add_house_style_comment)
+        .build();
+
+    assert!(generator.generate().expect("generate"));
+
+    for file in [
+        "i18n/en-US/namespace-filter-crate.ftl",
+        "i18n/en-US/namespace-filter-crate/ui.ftl",
+    ] {
+        let content = fs::read_to_string(temp.path().join(file)).expect("read ftl");
+        assert!(
+            content.starts_with("### House style\n"),
+            "{file} was not post-processed:\n{content}"
+        );
+        assert_eq!(content.matches("House style").count(), 1);
+    }
+    generator
+        .check_only()
+        .expect("post-processed FTL should be fresh");
+    assert!(!generator.generate().expect("regenerate"));
+}
+
+#[test]
+fn header_is_written_once_and_kept_on_regeneration() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    write_basic_i18n_config(temp.path());
+    let generator = EsFluentGenerator::builder()
+        .crate_name("namespace-filter-crate")
+        .manifest_dir(temp.path())
+        .header("Generated file, edit translations only.")
+        .build();
+
+    assert!(generator.generate().expect("generate"));
+    assert!(!generator.generate().expect("regenerate"));
+
+    for file in [
+        "i18n/en-US/namespace-filter-crate.ftl",
+        "i18n/en-US/namespace-filter-crate/ui.ftl",
+    ] {
+        let content = fs::read_to_string(temp.path().join(file)).expect("read ftl");
+        assert!(
+            content.starts_with(
+                "### @generated by es-fluent\n### Generated file, edit translations only.\n"
+            ),
+            "{file} is missing the header:\n{content}"
+        );
+        assert_eq!(content.matches("Generated file").count(), 1);
+    }
+    generator
+        .check_only()
+        .expect("FTL with a header should be fresh");
+}
+
+#[test]
+fn keys_json_lists_generated_message_ids_and_arguments() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    write_basic_i18n_config(temp.path());
+    let keys_json = temp.path().join("i18n/en-US/keys-json-crate.keys.json");
+    let generator = |dry_run: bool| {
+        EsFluentGenerator::builder()
+            .crate_name("keys-json-crate")
+            .manifest_dir(temp.path())
+            .keys_json(true)
+            .dry_run(dry_run)
+            .build()
+    };
+
+    generator(true).generate().expect("dry-run generate");
+    assert!(!keys_json.exists());
+
+    generator(false).generate().expect("generate");
+    let content = fs::read_to_string(&keys_json).expect("read keys json");
+    let manifest: KeysManifest = serde_json::from_str(&content).expect("parse keys json");
+    let entries: Vec<_> = manifest
+        .messages
+        .iter()
+        .map(|entry| {
+            (
+                entry.id.as_str(),
+                entry
+                    .args
+                    .iter()
+                    .map(|arg| arg.as_str())
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        entries,
+        [("farewell", vec![]), ("welcome", vec!["name", "count"])]
+    );
+
+    let default_output = temp.path().join("default");
+    EsFluentGenerator::builder()
+        .crate_name("keys-json-crate")
+        .manifest_dir(temp.path())
+        .output_path(&default_output)
+        .build()
+        .generate()
+        .expect("generate without keys json");
+    assert!(!default_output.join("keys-json-crate.keys.json").exists());
+}
+
+#[test]
+fn keys_json_is_checked_by_check_only_and_only_touched_by_clean_when_enabled() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    write_basic_i18n_config(temp.path());
+    let keys_json = temp.path().join("i18n/en-US/keys-json-crate.keys.json");
+    let generator = |keys_json: bool| {
+        EsFluentGenerator::builder()
+            .crate_name("keys-json-crate")
+            .manifest_dir(temp.path())
+            .keys_json(keys_json)
+            .build()
+    };
+
+    generator(true).generate().expect("generate");
+    generator(true).check_only().expect("fresh keys json");
+    let generated = fs::read_to_string(&keys_json).expect("read keys json");
+
+    fs::write(&keys_json, "{}\n").expect("write outdated keys json");
+    let err = generator(true)
+        .check_only()
+        .expect_err("outdated keys json is stale");
+    assert!(matches!(err, GeneratorError::Stale { ref path } if *path == keys_json));
+
+    assert!(
+        generator(true)
+            .clean_report(false, true)
+            .expect("dry-run clean")
+            .changed
+    );
+    assert_eq!(fs::read_to_string(&keys_json).expect("read"), "{}\n");
+    assert!(generator(true).clean(false, false).expect("clean"));
+    assert_eq!(fs::read_to_string(&keys_json).expect("read"), generated);
+    generator(true)
+        .check_only()
+        .expect("cleaned keys json is fresh");
+
+    assert!(!generator(false).clean(false, false).expect("clean"));
+    assert_eq!(fs::read_to_string(&keys_json).expect("read"), generated);
+}
+
+#[test]
+fn sort_mode_is_forwarded_to_the_ftl_target() {
+    let default_generator = EsFluentGenerator::builder().build();
+    assert_eq!(
+        default_generator.target("my-crate", "my-crate").sort_mode(),
+        SortMode::SourceOrder
+    );
+
+    let generator = EsFluentGenerator::builder()
+        .sort_mode(SortMode::Alphabetical)
+        .build();
+    assert_eq!(
+        generator.target("my-crate", "my-crate").sort_mode(),
+        SortMode::Alphabetical
+    );
+}
+
+#[test]
+fn generate_with_namespace_rejects_namespaces_without_types() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    write_basic_i18n_config(temp.path());
+
+    let err = EsFluentGenerator::builder()
+        .crate_name("namespace-filter-crate")
+        .manifest_dir(temp.path())
+        .namespace("errors")
+        .build()
+        .generate()
+        .expect_err("unused namespace should fail");
+
+    assert!(matches!(
+        err,
+        GeneratorError::UnknownNamespace {
+            namespace,
+            crate_name,
+        } if namespace == "errors" && crate_name == "namespace-filter-crate"
+    ));
+}
+
 #[test]
 fn clean_marks_changes_when_cleaner_rewrites_files() {
     let temp = tempfile::tempdir().expect("tempdir");
@@ -383,6 +814,160 @@ fn clean_marks_changes_when_cleaner_rewrites_files() {
     assert!(changed);
 }
 
+#[test]
+fn generate_writes_the_base_file_named_by_ftl_file_name() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    fs::create_dir_all(temp.path().join("i18n/en-US")).expect("mkdir en-US");
+    let mut config = i18n_config("en-US", "i18n", &[]);
+    config.as_table_mut().expect("config table").insert(
+        "ftl_file_name".to_string(),
+        string_value("{crate}-messages"),
+    );
+    write_toml(&temp.path().join("i18n.toml"), &config);
+
+    let generator = EsFluentGenerator::builder()
+        .crate_name("coverage-test-crate")
+        .manifest_dir(temp.path())
+        .build();
+
+    assert!(generator.generate().expect("generate"));
+    let target_file = temp
+        .path()
+        .join("i18n/en-US/coverage-test-crate-messages.ftl");
+    assert!(
+        fs::read_to_string(&target_file)
+            .expect("read ftl")
+            .contains("group_a-Key1 = Key1")
+    );
+    assert!(
+        !temp
+            .path()
+            .join("i18n/en-US/coverage-test-crate.ftl")
+            .exists()
+    );
+    generator.check_only().expect("fresh FTL should pass");
+
+    fs::write(
+        &target_file,
+        "## GroupA\n\ngroup_a-Key1 = Keep\norphan-Old = stale value\n",
+    )
+    .expect("write stale ftl");
+    let report = generator.clean_report(false, false).expect("clean report");
+    assert_eq!(report.removed, ["orphan-Old"]);
+}
+
+#[test]
+fn generate_writes_domain_first_files_under_the_domain_dir() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let mut config = i18n_config("en-US", "i18n", &[]);
+    config
+        .as_table_mut()
+        .expect("config table")
+        .insert("layout".to_string(), string_value("domain_first"));
+    write_toml(&temp.path().join("i18n.toml"), &config);
+
+    let generator = EsFluentGenerator::builder()
+        .crate_name("coverage-test-crate")
+        .manifest_dir(temp.path())
+        .build();
+
+    assert!(generator.generate().expect("generate"));
+    let target_file = temp.path().join("i18n/coverage-test-crate/en-US.ftl");
+    assert!(
+        fs::read_to_string(&target_file)
+            .expect("read ftl")
+            .contains("group_a-Key1 = Key1")
+    );
+    assert!(!temp.path().join("i18n/en-US").exists());
+    generator.check_only().expect("fresh FTL should pass");
+
+    fs::write(
+        &target_file,
+        "## GroupA\n\ngroup_a-Key1 = Keep\norphan-Old = stale value\n",
+    )
+    .expect("write stale ftl");
+    let report = generator.clean_report(false, false).expect("clean report");
+    assert_eq!(report.removed, ["orphan-Old"]);
+}
+
+#[test]
+fn clean_report_lists_orphan_keys_without_writing_in_dry_run() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    write_basic_i18n_config(temp.path());
+
+    let target_file = temp.path().join("i18n/en-US/coverage-test-crate.ftl");
+    let original = "## GroupA\n\ngroup_a-Key1 = Keep\norphan-Old = stale value\n";
+    fs::write(&target_file, original).expect("write stale ftl");
+
+    let generator = EsFluentGenerator::builder()
+        .crate_name("coverage-test-crate")
+        .manifest_dir(temp.path())
+        .build();
+
+    let report = generator.clean_report(false, true).expect("clean report");
+    assert!(report.changed);
+    assert!(report.removed.contains(&"orphan-Old".to_string()));
+    assert!(report.added.is_empty());
+    assert_eq!(
+        fs::read_to_string(&target_file).expect("read ftl"),
+        original
+    );
+}
+
+#[test]
+fn generate_with_sync_locales_stubs_new_keys_in_other_locales() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    write_basic_i18n_config(temp.path());
+    fs::create_dir_all(temp.path().join("i18n/de")).expect("mkdir de");
+    let fr_file = temp.path().join("i18n/fr/coverage-test-crate.ftl");
+    fs::write(&fr_file, "## GroupA\n\ngroup_a-Key1 = Clé\n").expect("write fr ftl");
+
+    let generator = EsFluentGenerator::builder()
+        .crate_name("coverage-test-crate")
+        .manifest_dir(temp.path())
+        .sync_locales(true)
+        .build();
+    let report = generator.generate_report().expect("generate");
+
+    assert!(report.changed);
+    assert_eq!(
+        fs::read_to_string(temp.path().join("i18n/en-US/coverage-test-crate.ftl"))
+            .expect("read en-US ftl"),
+        "## GroupA\n\ngroup_a-Key1 = Key1\n"
+    );
+    assert_eq!(
+        fs::read_to_string(&fr_file).expect("read fr ftl"),
+        "## GroupA\n\ngroup_a-Key1 = Clé\n"
+    );
+    assert_eq!(
+        fs::read_to_string(temp.path().join("i18n/de/coverage-test-crate.ftl"))
+            .expect("read de ftl"),
+        "## GroupA\n\n# TODO: translate\ngroup_a-Key1 = Key1\n"
+    );
+
+    assert!(!generator.generate_report().expect("regenerate").changed);
+}
+
+#[test]
+fn generate_without_sync_locales_leaves_other_locales_alone() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    write_basic_i18n_config(temp.path());
+
+    EsFluentGenerator::builder()
+        .crate_name("coverage-test-crate")
+        .manifest_dir(temp.path())
+        .build()
+        .generate()
+        .expect("generate");
+
+    assert!(
+        temp.path()
+            .join("i18n/en-US/coverage-test-crate.ftl")
+            .exists()
+    );
+    assert!(!temp.path().join("i18n/fr/coverage-test-crate.ftl").exists());
+}
+
 #[test]
 #[serial_test::serial(process)]
 fn detect_crate_name_works_in_test_environment() {
@@ -4,13 +4,16 @@ mod cli;
 mod generate;
 
 use es_fluent_runner::{PackageName, RunnerMetadataStore, RunnerRequest, RunnerResult};
-use es_fluent_toml::ResolvedI18nLayout;
+use es_fluent_toml::{I18nConfig, ResolvedI18nLayout};
 #[cfg(test)]
 use std::path::Path;
 
 pub use cli::write_inventory_for_crate;
-pub use es_fluent_runner::{ExpectedKey, InventoryData};
-pub use generate::{EsFluentGenerator, FluentParseMode, GeneratorArgs, GeneratorError};
+pub use es_fluent_runner::{CheckSummary, ExpectedKey, InventoryData};
+pub use generate::{
+    EsFluentGenerator, FluentParseMode, GenerateReport, GeneratorArgs, GeneratorError,
+    KeysManifest, KeysManifestEntry, PostProcess, SortMode,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum CliHelpersError {
@@ -41,8 +44,15 @@ impl RunnerContext {
         })
     }
 
-    fn write_changed_result(&self, changed: bool) -> Result<(), es_fluent_runner::RunnerIoError> {
-        let result = RunnerResult { changed };
+    fn write_report_result(
+        &self,
+        report: &GenerateReport,
+    ) -> Result<(), es_fluent_runner::RunnerIoError> {
+        let result = RunnerResult {
+            changed: report.changed,
+            report: report.clone(),
+            check: None,
+        };
         RunnerMetadataStore::new(".").write_result(&self.crate_name, &result)
     }
 }
@@ -57,6 +67,8 @@ fn build_generator(
         .manifest_dir(ctx.layout.manifest_dir.clone())
         .crate_name(ctx.crate_name.as_str())
         .mode(mode)
+        .keys_json(ctx.layout.config.keys_json)
+        .annotate_source(ctx.layout.config.annotate_source)
         .dry_run(dry_run)
         .build()
 }
@@ -70,13 +82,13 @@ fn run_generator_command(
 ) -> Result<bool, CliHelpersError> {
     let ctx = RunnerContext::from_i18n_path(i18n_toml_path, crate_name)?;
     let generator = build_generator(&ctx, mode, dry_run);
-    let changed = match run {
-        GeneratorRun::Cli => generator.run_cli(),
-        GeneratorRun::Generate => generator.generate(),
-        GeneratorRun::Clean { all_locales } => generator.clean(all_locales, dry_run),
+    let report = match run {
+        GeneratorRun::Cli => generator.run_cli_report(),
+        GeneratorRun::Generate => generator.generate_report(),
+        GeneratorRun::Clean { all_locales } => generator.clean_report(all_locales, dry_run),
     }?;
-    ctx.write_changed_result(changed)?;
-    Ok(changed)
+    ctx.write_report_result(&report)?;
+    Ok(report.changed)
 }
 
 fn run_request(request: RunnerRequest) -> Result<(), CliHelpersError> {
@@ -157,17 +169,41 @@ pub fn run_generate_with_options(
 
 /// Run the inventory check process for a crate.
 ///
-/// This writes the collected inventory data for the specified crate.
+/// This writes the collected inventory data for the specified crate and,
+/// when an `i18n.toml` applies, a result.json whose `check` field lists the
+/// keys missing from and orphaned in the fallback locale.
 pub fn run_check(crate_name: &str) -> Result<(), CliHelpersError> {
-    write_inventory_for_crate(crate_name)?;
-    Ok(())
+    let manifest_dir = std::env::current_dir().map_err(es_fluent_runner::RunnerIoError::from)?;
+    run_check_at(crate_name, &manifest_dir)
 }
 
 pub fn run_check_at(
     crate_name: &str,
     manifest_dir: &std::path::Path,
 ) -> Result<(), CliHelpersError> {
-    cli::write_inventory_for_crate_at(crate_name, manifest_dir)?;
+    let package_name = PackageName::try_new(crate_name)?;
+    let inventory = cli::collect_inventory_for_crate_at(crate_name, manifest_dir)?;
+    let store = RunnerMetadataStore::new(".");
+    store.write_inventory(&package_name, &inventory)?;
+
+    let Some(config_path) = I18nConfig::config_path_for_manifest_dir(manifest_dir) else {
+        return Ok(());
+    };
+    let layout = ResolvedI18nLayout::from_config_path(config_path).map_err(GeneratorError::from)?;
+    let summary = cli::check_summary(
+        crate_name,
+        &layout.config.ftl_file_stem(crate_name),
+        &layout.output_dir,
+        &inventory,
+    )?;
+    store.write_result(
+        &package_name,
+        &RunnerResult {
+            changed: false,
+            report: GenerateReport::default(),
+            check: Some(summary),
+        },
+    )?;
     Ok(())
 }
 
@@ -270,6 +306,38 @@ mod tests {
         });
     }
 
+    #[test]
+    fn run_generate_writes_keys_json_when_enabled_in_i18n_toml() {
+        with_temp_cwd(|cwd| {
+            write_basic_manifest(cwd);
+            let i18n_path = cwd.join("i18n.toml");
+            let keys_json = cwd.join("i18n/en-US/missing-crate.keys.json");
+
+            run_generate_with_options(
+                i18n_path.to_str().expect("path"),
+                "missing-crate",
+                FluentParseMode::Conservative,
+                false,
+            )
+            .expect("run generate");
+            assert!(!keys_json.exists());
+
+            std::fs::write(
+                &i18n_path,
+                "fallback_language = \"en-US\"\nassets_dir = \"i18n\"\nkeys_json = true\n",
+            )
+            .expect("write i18n.toml");
+            run_generate_with_options(
+                i18n_path.to_str().expect("path"),
+                "missing-crate",
+                FluentParseMode::Conservative,
+                false,
+            )
+            .expect("run generate");
+            assert!(keys_json.is_file());
+        });
+    }
+
     #[test]
     fn run_check_writes_inventory_json_for_requested_crate() {
         with_temp_cwd(|cwd| {
@@ -282,6 +350,63 @@ mod tests {
         });
     }
 
+    #[test]
+    fn run_check_writes_missing_and_orphan_keys_to_result_json() {
+        with_temp_cwd(|cwd| {
+            write_basic_manifest(cwd);
+            std::fs::create_dir_all(cwd.join("i18n/en-US/test-crate")).expect("mkdir ns");
+            std::fs::write(
+                cwd.join("i18n/en-US/test-crate/ui.ftl"),
+                "my_key = Mine\nstale_key = Stale\n-brand = Term\n",
+            )
+            .expect("write ui.ftl");
+            std::fs::write(cwd.join("i18n/en-US/test-crate.ftl"), "leftover = Left\n")
+                .expect("write crate ftl");
+
+            run_check_at("test-crate", cwd).expect("run check");
+
+            let check = RunnerMetadataStore::new(cwd)
+                .read_result(&PackageName::try_new("test-crate").expect("package"))
+                .expect("read result")
+                .check
+                .expect("check summary");
+            assert_eq!(check.missing_in_ftl, ["secondary_key"]);
+            assert_eq!(check.orphan_in_ftl, ["leftover", "stale_key"]);
+        });
+    }
+
+    #[test]
+    fn run_check_reads_the_base_file_named_by_ftl_file_name() {
+        with_temp_cwd(|cwd| {
+            write_basic_manifest(cwd);
+            std::fs::write(
+                cwd.join("i18n.toml"),
+                "fallback_language = \"en-US\"\nassets_dir = \"i18n\"\nftl_file_name = \"messages\"\n",
+            )
+            .expect("write i18n.toml");
+            std::fs::create_dir_all(cwd.join("i18n/en-US/test-crate")).expect("mkdir ns");
+            std::fs::write(
+                cwd.join("i18n/en-US/test-crate/ui.ftl"),
+                "my_key = Mine\nsecondary_key = Second\n",
+            )
+            .expect("write ui.ftl");
+            std::fs::write(cwd.join("i18n/en-US/messages.ftl"), "leftover = Left\n")
+                .expect("write messages.ftl");
+            std::fs::write(cwd.join("i18n/en-US/test-crate.ftl"), "ignored = Ignored\n")
+                .expect("write crate ftl");
+
+            run_check_at("test-crate", cwd).expect("run check");
+
+            let check = RunnerMetadataStore::new(cwd)
+                .read_result(&PackageName::try_new("test-crate").expect("package"))
+                .expect("read result")
+                .check
+                .expect("check summary");
+            assert!(check.missing_in_ftl.is_empty());
+            assert_eq!(check.orphan_in_ftl, ["leftover"]);
+        });
+    }
+
     #[test]
     fn run_generate_returns_structured_error_for_invalid_config() {
         with_temp_cwd(|cwd| {
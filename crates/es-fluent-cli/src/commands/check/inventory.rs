@@ -1,5 +1,5 @@
 use anyhow::Result;
-use es_fluent_runner::{PackageName, RunnerIoError, RunnerMetadataStore};
+use es_fluent_runner::{InventoryData, PackageName, RunnerIoError, RunnerMetadataStore};
 use es_fluent_shared::fluent::{FluentArgumentName, FluentEntryId};
 use es_fluent_shared::resource::ModuleResourceSpec;
 use es_fluent_shared::source::{SourceFile, SourceLine};
@@ -17,14 +17,14 @@ pub(crate) struct KeyInfo {
     pub(crate) source_line: Option<SourceLine>,
 }
 
-/// Read inventory data from the generated inventory.json file.
-pub(crate) fn read_inventory_file(
+/// Read the raw inventory data written by a runner check request.
+pub(crate) fn read_inventory_data(
     temp_dir: &std::path::Path,
     package_name: &PackageName,
-) -> Result<ExpectedKeys> {
+) -> Result<InventoryData> {
     let store = RunnerMetadataStore::new(temp_dir);
     let inventory_path = store.inventory_path(package_name);
-    let data = store
+    store
         .read_inventory(package_name)
         .map_err(|error| match error {
             RunnerIoError::Io(_) => anyhow::Error::new(error)
@@ -35,7 +35,20 @@ pub(crate) fn read_inventory_file(
             RunnerIoError::InvalidRunnerRequest(_) | RunnerIoError::Message(_) => {
                 anyhow::Error::new(error)
             },
-        })?;
+        })
+}
+
+/// Read inventory data from the generated inventory.json file.
+///
+/// Keys without a recorded resource are routed to the crate's base file,
+/// `{file_stem}.ftl`.
+pub(crate) fn read_inventory_file(
+    temp_dir: &std::path::Path,
+    package_name: &PackageName,
+    file_stem: &str,
+) -> Result<ExpectedKeys> {
+    let inventory_path = RunnerMetadataStore::new(temp_dir).inventory_path(package_name);
+    let data = read_inventory_data(temp_dir, package_name)?;
 
     let mut expected_keys = IndexMap::new();
     for key_info in data.expected_keys {
@@ -45,9 +58,9 @@ pub(crate) fn read_inventory_file(
             key.clone(),
             KeyInfo {
                 variables,
-                resource: key_info
-                    .resource
-                    .unwrap_or_else(|| ModuleResourceSpec::base(package_name.as_str(), true)),
+                resource: key_info.resource.unwrap_or_else(|| {
+                    ModuleResourceSpec::base_with_file_stem(package_name.as_str(), file_stem, true)
+                }),
                 source_file: key_info.source_file,
                 source_line: key_info.source_line,
             },
@@ -99,7 +112,8 @@ mod tests {
         )
         .unwrap();
 
-        let inventory = read_inventory_file(temp.path(), &package("test-crate")).unwrap();
+        let inventory =
+            read_inventory_file(temp.path(), &package("test-crate"), "test-crate").unwrap();
 
         assert_eq!(inventory.len(), 2);
         let hello_key = FluentEntryId::try_new("hello").unwrap();
@@ -148,7 +162,7 @@ mod tests {
         )
         .unwrap();
 
-        let error = read_inventory_file(temp.path(), &package("test-crate"))
+        let error = read_inventory_file(temp.path(), &package("test-crate"), "test-crate")
             .err()
             .expect("invalid key should fail");
         assert!(error.to_string().contains("Failed to parse inventory JSON"));
@@ -181,7 +195,7 @@ mod tests {
         )
         .unwrap();
 
-        let error = read_inventory_file(temp.path(), &package("test-crate"))
+        let error = read_inventory_file(temp.path(), &package("test-crate"), "test-crate")
             .err()
             .expect("duplicate key should fail");
         assert!(
@@ -199,7 +213,7 @@ mod tests {
         fs::create_dir_all(inventory_path.parent().unwrap()).unwrap();
         fs::write(&inventory_path, "{invalid-json").unwrap();
 
-        let error = read_inventory_file(temp.path(), &package("test-crate"))
+        let error = read_inventory_file(temp.path(), &package("test-crate"), "test-crate")
             .err()
             .expect("expected invalid json to fail");
         assert!(error.to_string().contains("Failed to parse inventory JSON"));
@@ -208,7 +222,7 @@ mod tests {
     #[test]
     fn read_inventory_file_returns_error_when_missing() {
         let temp = tempfile::tempdir().unwrap();
-        let error = read_inventory_file(temp.path(), &package("missing-crate"))
+        let error = read_inventory_file(temp.path(), &package("missing-crate"), "missing-crate")
             .err()
             .expect("missing inventory should fail");
         assert!(error.to_string().contains("Failed to read"));
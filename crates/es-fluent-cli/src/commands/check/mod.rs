@@ -8,7 +8,7 @@
 //! - Reporting unexpected FTL variables as errors
 //! - Reporting Rust-declared variables omitted by translations as warnings
 
-mod inventory;
+pub(super) mod inventory;
 mod validation;
 
 use super::common::{OutputFormat, WorkspaceArgs, WorkspaceCrates};
@@ -477,7 +477,7 @@ fn locale_setup_issues_for_crates(
         }
 
         let fallback_dir = ctx.locale_dir(&ctx.fallback);
-        let fallback_path_invalid = !crate::ftl::is_real_locale_directory(&fallback_dir);
+        let fallback_path_invalid = !ctx.has_locale_dir(&ctx.fallback);
         if fallback_path_invalid {
             issue_crates.insert(krate.name.to_string());
             issues.push(ValidationIssue::ValidationExecution(
@@ -541,18 +541,11 @@ fn locale_setup_issues_for_crates(
 
         if let Ok(all_ctx) = crate::ftl::LocaleContext::from_crate(krate, true) {
             for locale in &all_ctx.locales {
-                let locale_dir = all_ctx.locale_dir(locale);
-                if !crate::ftl::is_real_locale_directory(&locale_dir) {
+                if !all_ctx.has_locale_dir(locale) {
                     continue;
                 }
 
-                if let Err(error) = crate::ftl::CrateFtlLayout::from_assets_dir(
-                    &all_ctx.assets_dir,
-                    locale,
-                    &all_ctx.crate_name,
-                )
-                .discover_files()
-                {
+                if let Err(error) = all_ctx.crate_layout(locale).discover_files() {
                     issue_crates.insert(krate.name.to_string());
                     issues.push(ValidationIssue::ValidationExecution(
                         ValidationExecutionError {
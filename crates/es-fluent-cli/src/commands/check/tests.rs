@@ -460,6 +460,41 @@ fn collect_check_run_reports_missing_fallback_locale_as_setup_issue() {
     assert_eq!(run.issues.len(), 1);
 }
 
+#[test]
+fn collect_check_run_reports_missing_domain_first_fallback_as_setup_issue() {
+    let temp = crate::test_fixtures::create_test_crate_workspace();
+    setup_fake_runner_and_cache(&temp);
+    let config_path = temp.path().join("i18n.toml");
+    let config = fs::read_to_string(&config_path).expect("read i18n.toml");
+    fs::write(&config_path, format!("{config}layout = \"domain_first\"\n"))
+        .expect("write i18n.toml");
+    fs::remove_dir_all(temp.path().join("i18n/en")).expect("remove language-first dir");
+    // The crate's domain directory exists, but only holds French.
+    crate::test_fixtures::write_file(
+        &temp.path().join("i18n/test-app/fr.ftl"),
+        "hello = Bonjour\n",
+    );
+    let inventory_path = es_fluent_runner::RunnerMetadataStore::new(temp.path().join(".es-fluent"))
+        .inventory_path(&package("test-app"));
+    fs::create_dir_all(inventory_path.parent().unwrap()).expect("create inventory dir");
+    fs::write(&inventory_path, INVENTORY_WITH_HELLO).expect("write inventory");
+    let workspace = WorkspaceCrates::discover(WorkspaceArgs {
+        path: Some(temp.path().to_path_buf()),
+        package: None,
+    })
+    .expect("discover workspace");
+
+    let run = collect_check_run(&workspace, false, &[], false, true, false).expect("collect check");
+
+    let setup_issues = run
+        .issues
+        .iter()
+        .filter(|issue| matches!(issue, ValidationIssue::ValidationExecution(error) if error.help.contains("fallback locale directory 'en' for test-app")))
+        .count();
+    assert_eq!(setup_issues, 1);
+    assert_eq!(run.issues.len(), 1);
+}
+
 #[cfg(unix)]
 #[test]
 fn collect_check_run_reports_symlinked_fallback_locale_as_setup_issue() {
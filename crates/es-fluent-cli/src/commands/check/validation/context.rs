@@ -3,6 +3,7 @@ use crate::core::{
     DuplicateKeyError, FtlSyntaxError, MissingKeyError, MissingVariableWarning,
     UnexpectedVariableError, UntranslatedMessageWarning, ValidationIssue,
 };
+use es_fluent_toml::AssetsLayout;
 use miette::{NamedSource, SourceSpan};
 use std::path::Path;
 use terminal_link::Link;
@@ -11,6 +12,7 @@ pub(super) struct ValidationContext<'a> {
     pub(super) expected_keys: &'a ExpectedKeys,
     pub(super) workspace_root: &'a Path,
     pub(super) manifest_dir: &'a Path,
+    pub(super) layout: AssetsLayout,
 }
 
 impl ValidationContext<'_> {
@@ -62,7 +64,8 @@ impl ValidationContext<'_> {
         locale: &str,
         key_info: &super::super::inventory::KeyInfo,
     ) -> String {
-        format!("{locale}/{}", key_info.resource.locale_relative_path)
+        self.layout
+            .locale_path(locale, key_info.resource.locale_relative_path.as_str())
     }
 
     pub(super) fn missing_variable_issue(
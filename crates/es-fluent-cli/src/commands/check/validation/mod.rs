@@ -18,7 +18,8 @@ pub(crate) fn validate_crate(
     check_all: bool,
     check_fallback_copies: bool,
 ) -> Result<Vec<ValidationIssue>> {
-    let expected_keys = super::inventory::read_inventory_file(temp_dir, &krate.name)?;
+    let expected_keys =
+        super::inventory::read_inventory_file(temp_dir, &krate.name, &krate.ftl_file_stem()?)?;
     validate_ftl_files(
         krate,
         workspace_root,
@@ -40,17 +41,16 @@ fn validate_ftl_files(
         expected_keys,
         workspace_root,
         manifest_dir: &krate.manifest_dir,
+        layout: locale_ctx.layout,
     };
     let check_fallback_copies =
         check_all && check_fallback_copies && locale_ctx.check_fallback_copies;
     let fallback_keys = if check_fallback_copies {
-        crate::ftl::discover_and_load_ftl_files(
-            &locale_ctx.assets_dir,
-            &locale_ctx.fallback,
-            &locale_ctx.crate_name,
-        )
-        .ok()
-        .map(|files| loaded::collect_fallback_keys(&files))
+        locale_ctx
+            .crate_layout(&locale_ctx.fallback)
+            .discover_and_load_files()
+            .ok()
+            .map(|files| loaded::collect_fallback_keys(&files))
     } else {
         None
     };
@@ -58,17 +58,10 @@ fn validate_ftl_files(
     let mut issues = Vec::new();
 
     for locale in &locale_ctx.locales {
-        match crate::ftl::discover_and_load_ftl_files(
-            &locale_ctx.assets_dir,
-            locale,
-            &locale_ctx.crate_name,
-        ) {
+        let layout = locale_ctx.crate_layout(locale);
+        match layout.discover_and_load_files() {
             Ok(loaded_files) if loaded_files.is_empty() => {
-                let ftl_abs_path = crate::ftl::main_ftl_path(
-                    &locale_ctx.assets_dir,
-                    locale,
-                    &locale_ctx.crate_name,
-                );
+                let ftl_abs_path = layout.main_file();
                 let ftl_relative_path = ctx.to_relative_path(&ftl_abs_path);
                 let ftl_header_link = ctx.format_terminal_link(
                     &ftl_relative_path,
@@ -87,11 +80,7 @@ fn validate_ftl_files(
                 ));
             },
             Err(error) => {
-                let ftl_abs_path = crate::ftl::main_ftl_path(
-                    &locale_ctx.assets_dir,
-                    locale,
-                    &locale_ctx.crate_name,
-                );
+                let ftl_abs_path = layout.main_file();
                 issues.push(ctx.syntax_error_issue(
                     locale,
                     &ftl_abs_path,
@@ -8,6 +8,7 @@ use es_fluent_shared::{
     resource::ModuleResourceSpec,
     source::{SourceFile, SourceLine},
 };
+use es_fluent_toml::AssetsLayout;
 use fs_err as fs;
 use indexmap::IndexMap;
 use std::path::PathBuf;
@@ -73,6 +74,7 @@ fn validate_loaded_ftl_files_reports_key_in_wrong_resource_as_missing_from_expec
         expected_keys: &expected_keys,
         workspace_root: temp.path(),
         manifest_dir: temp.path(),
+        layout: AssetsLayout::LanguageFirst,
     };
 
     let issues = validate_loaded(&ctx, loaded_files, "en");
@@ -114,6 +116,7 @@ fn missing_file_issues_returns_issue_for_each_expected_key() {
         expected_keys: &expected_keys,
         workspace_root: temp.path(),
         manifest_dir: temp.path(),
+        layout: AssetsLayout::LanguageFirst,
     };
 
     let issues = ctx.missing_file_issues("en", "i18n/en/test-app.ftl");
@@ -156,6 +159,7 @@ fn validate_loaded_ftl_files_reports_missing_key_and_variable() {
         expected_keys: &expected_keys,
         workspace_root: temp.path(),
         manifest_dir: temp.path(),
+        layout: AssetsLayout::LanguageFirst,
     };
 
     let issues = validate_loaded(&ctx, loaded_files, "en");
@@ -196,6 +200,7 @@ fn validate_loaded_ftl_files_reports_unexpected_variable_as_error() {
         expected_keys: &expected_keys,
         workspace_root: temp.path(),
         manifest_dir: temp.path(),
+        layout: AssetsLayout::LanguageFirst,
     };
 
     let issues = validate_loaded(&ctx, loaded_files, "en");
@@ -242,6 +247,7 @@ fn validate_loaded_ftl_files_reports_non_fallback_copy_as_untranslated() {
         expected_keys: &expected_keys,
         workspace_root: temp.path(),
         manifest_dir: temp.path(),
+        layout: AssetsLayout::LanguageFirst,
     };
     let fallback_keys = super::loaded::collect_fallback_keys(&fallback_files);
 
@@ -303,6 +309,7 @@ fn validate_loaded_ftl_files_allows_marked_same_as_fallback_message() {
         expected_keys: &expected_keys,
         workspace_root: temp.path(),
         manifest_dir: temp.path(),
+        layout: AssetsLayout::LanguageFirst,
     };
     let fallback_keys = super::loaded::collect_fallback_keys(&fallback_files);
 
@@ -360,6 +367,7 @@ fn validate_loaded_ftl_files_allows_same_as_fallback_marker_before_next_message_
         expected_keys: &expected_keys,
         workspace_root: temp.path(),
         manifest_dir: temp.path(),
+        layout: AssetsLayout::LanguageFirst,
     };
     let fallback_keys = super::loaded::collect_fallback_keys(&fallback_files);
 
@@ -411,6 +419,7 @@ fn validate_loaded_ftl_files_reports_duplicate_keys_and_ignores_non_messages() {
         expected_keys: &expected_keys,
         workspace_root: temp.path(),
         manifest_dir: temp.path(),
+        layout: AssetsLayout::LanguageFirst,
     };
 
     let issues = validate_loaded(&ctx, loaded_files, "en");
@@ -473,6 +482,59 @@ fn validate_crate_reports_missing_main_file_as_missing_key() {
     );
 }
 
+#[test]
+fn validate_crate_reads_the_base_file_named_by_ftl_file_name() {
+    let temp = tempfile::tempdir().unwrap();
+    fs::create_dir_all(temp.path().join("src")).unwrap();
+    fs::create_dir_all(temp.path().join("i18n/en")).unwrap();
+    fs::write(
+        temp.path().join("i18n.toml"),
+        "fallback_language = \"en\"\nassets_dir = \"i18n\"\nftl_file_name = \"messages\"\n",
+    )
+    .unwrap();
+    fs::write(temp.path().join("i18n/en/messages.ftl"), "hello = Hello\n").unwrap();
+    fs::write(
+        temp.path().join("i18n/en/test-crate.ftl"),
+        "ignored = Ignored\n",
+    )
+    .unwrap();
+
+    let inventory_path = es_fluent_runner::RunnerMetadataStore::new(temp.path())
+        .inventory_path(&package("test-crate"));
+    fs::create_dir_all(inventory_path.parent().unwrap()).unwrap();
+    fs::write(
+        &inventory_path,
+        r#"{
+  "expected_keys": [
+    {
+      "key": "hello",
+      "variables": [],
+      "source_file": null,
+      "source_line": null
+    }
+  ]
+}"#,
+    )
+    .unwrap();
+
+    let krate = CrateInfo {
+        name: package("test-crate"),
+        manifest_dir: crate::core::ManifestDir::from_discovered(temp.path().to_path_buf()),
+        src_dir: crate::core::SourceDir::from_discovered(temp.path().join("src")),
+        i18n_config_path: crate::core::DiscoveredI18nConfigPath::from_discovered(
+            temp.path().join("i18n.toml"),
+        ),
+        ftl_output_dir: crate::core::DiscoveredFtlOutputDir::from_discovered(
+            temp.path().join("i18n/en"),
+        ),
+        has_lib_rs: true,
+        fluent_features: Vec::new(),
+    };
+
+    let issues = validate_crate(&krate, temp.path(), temp.path(), false, true).unwrap();
+    assert!(issues.is_empty(), "unexpected issues: {issues:?}");
+}
+
 #[test]
 fn validate_crate_respects_config_disabled_fallback_copy_check() {
     let temp = tempfile::tempdir().unwrap();
@@ -573,6 +635,7 @@ fn validate_loaded_ftl_files_handles_source_file_variants_and_terminal_links() {
             expected_keys: &expected_keys,
             workspace_root: temp.path(),
             manifest_dir: temp.path(),
+            layout: AssetsLayout::LanguageFirst,
         };
 
         let issues = validate_loaded(&ctx, loaded_files, "en");
@@ -611,6 +674,7 @@ fn validate_loaded_ftl_files_falls_back_to_unknown_when_no_actual_files() {
         expected_keys: &expected_keys,
         workspace_root: temp.path(),
         manifest_dir: temp.path(),
+        layout: AssetsLayout::LanguageFirst,
     };
 
     let issues = validate_loaded(&ctx, Vec::new(), "en");
@@ -671,6 +735,7 @@ fn to_relative_path_uses_non_canonical_strip_fallback() {
         expected_keys: &expected_keys,
         workspace_root: &alternate_root,
         manifest_dir: &alternate_root,
+        layout: AssetsLayout::LanguageFirst,
     };
 
     let virtual_path = alternate_root.join("i18n/en/missing.ftl");
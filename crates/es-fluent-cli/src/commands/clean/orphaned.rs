@@ -2,19 +2,36 @@ use super::super::common::WorkspaceCrates;
 use crate::core::{CliError, CrateInfo};
 use crate::ftl::{CrateFtlLayout, LocaleContext};
 use colored::Colorize as _;
+use es_fluent_toml::AssetsLayout;
 use fs_err as fs;
-use std::collections::{BTreeSet, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 struct OrphanedCleaner<'a> {
     crate_names: HashSet<&'a str>,
+    /// Main file stems from `ftl_file_name`, for crates that configure one.
+    file_stems: HashMap<String, String>,
 }
 
+/// A locale to scan, with language-first `{assets_dir}/{locale}` directories
+/// that `layout` resolves.
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 struct LocaleCleanupTarget {
     fallback_locale_dir: PathBuf,
     locale: String,
     locale_dir: PathBuf,
+    layout: AssetsLayout,
+}
+
+impl LocaleCleanupTarget {
+    /// The directory empty parents are removed up to, which is the assets
+    /// directory when locale files live in per-domain directories.
+    fn cleanup_root(&self) -> &Path {
+        match self.layout {
+            AssetsLayout::LanguageFirst => &self.locale_dir,
+            AssetsLayout::DomainFirst => self.locale_dir.parent().unwrap_or(&self.locale_dir),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -25,7 +42,24 @@ pub(crate) struct OrphanedFtlFile {
 
 impl<'a> OrphanedCleaner<'a> {
     fn new(crate_names: HashSet<&'a str>) -> Self {
-        Self { crate_names }
+        Self {
+            crate_names,
+            file_stems: HashMap::new(),
+        }
+    }
+
+    fn layout(
+        &self,
+        locale_dir: &Path,
+        crate_name: &str,
+        assets_layout: AssetsLayout,
+    ) -> CrateFtlLayout {
+        let layout =
+            CrateFtlLayout::new(locale_dir.to_path_buf(), crate_name).with_layout(assets_layout);
+        match self.file_stems.get(crate_name) {
+            Some(file_stem) => layout.with_file_stem(file_stem),
+            None => layout,
+        }
     }
 
     /// Get the expected FTL file paths for a locale by mirroring every known
@@ -34,13 +68,13 @@ impl<'a> OrphanedCleaner<'a> {
         &self,
         locale_dir: &std::path::Path,
         fallback_locale_dir: &std::path::Path,
+        assets_layout: AssetsLayout,
     ) -> Result<HashSet<PathBuf>, CliError> {
         let mut expected = HashSet::new();
 
         for crate_name in &self.crate_names {
-            let fallback_layout =
-                CrateFtlLayout::new(fallback_locale_dir.to_path_buf(), crate_name);
-            let locale_layout = CrateFtlLayout::new(locale_dir.to_path_buf(), crate_name);
+            let fallback_layout = self.layout(fallback_locale_dir, crate_name, assets_layout);
+            let locale_layout = self.layout(locale_dir, crate_name, assets_layout);
             expected.extend(locale_layout.expected_files_from_fallback(&fallback_layout)?);
         }
 
@@ -49,10 +83,12 @@ impl<'a> OrphanedCleaner<'a> {
 
     #[cfg(test)]
     fn find_all_ftl_files(&self, dir: &std::path::Path) -> Result<Vec<PathBuf>, CliError> {
-        Ok(crate::ftl::discover_locale_ftl_files(dir)?
-            .into_iter()
-            .map(|info| info.abs_path)
-            .collect())
+        Ok(
+            crate::ftl::discover_locale_ftl_files(dir, AssetsLayout::LanguageFirst)?
+                .into_iter()
+                .map(|info| info.abs_path)
+                .collect(),
+        )
     }
 }
 
@@ -74,10 +110,13 @@ pub(super) fn clean_orphaned_files(
     )?;
 
     for target in cleanup_targets {
-        let expected_files =
-            cleaner.expected_files_for_locale(&target.locale_dir, &target.fallback_locale_dir)?;
+        let expected_files = cleaner.expected_files_for_locale(
+            &target.locale_dir,
+            &target.fallback_locale_dir,
+            target.layout,
+        )?;
 
-        for file_info in crate::ftl::discover_locale_ftl_files(&target.locale_dir)? {
+        for file_info in crate::ftl::discover_locale_ftl_files(&target.locale_dir, target.layout)? {
             total_files_checked += 1;
 
             if !expected_files.contains(&file_info.abs_path) {
@@ -100,7 +139,7 @@ pub(super) fn clean_orphaned_files(
                     fs::remove_file(&file_info.abs_path)?;
 
                     if let Some(parent) = file_info.abs_path.parent() {
-                        remove_empty_parent_dirs(parent, &target.locale_dir);
+                        remove_empty_parent_dirs(parent, target.cleanup_root());
                     }
                 }
             }
@@ -214,10 +253,13 @@ fn find_orphaned_file_infos_with_expected_names(
     let mut orphaned = Vec::new();
 
     for target in cleanup_targets {
-        let expected_files =
-            cleaner.expected_files_for_locale(&target.locale_dir, &target.fallback_locale_dir)?;
+        let expected_files = cleaner.expected_files_for_locale(
+            &target.locale_dir,
+            &target.fallback_locale_dir,
+            target.layout,
+        )?;
 
-        for file_info in crate::ftl::discover_locale_ftl_files(&target.locale_dir)? {
+        for file_info in crate::ftl::discover_locale_ftl_files(&target.locale_dir, target.layout)? {
             if !expected_files.contains(&file_info.abs_path) {
                 orphaned.push(OrphanedFtlFile {
                     abs_path: file_info.abs_path,
@@ -237,19 +279,34 @@ fn orphaned_scan_context<'a>(
     all_locales: bool,
 ) -> Result<(OrphanedCleaner<'a>, BTreeSet<LocaleCleanupTarget>), CliError> {
     let crate_names: HashSet<&str> = expected_crate_names.iter().map(String::as_str).collect();
-    let cleaner = OrphanedCleaner::new(crate_names);
+    let mut cleaner = OrphanedCleaner::new(crate_names);
     let mut cleanup_targets = BTreeSet::new();
 
     for krate in scan_crates {
         let ctx = LocaleContext::from_crate(krate, all_locales)
             .map_err(|e| CliError::from(std::io::Error::other(e)))?;
-        let fallback_locale_dir = ctx.locale_dir(&ctx.fallback);
+        cleaner
+            .file_stems
+            .insert(ctx.crate_name.clone(), ctx.ftl_file_stem.clone());
+        let fallback_locale_dir = ctx.locale_root(&ctx.fallback);
         validate_orphaned_fallback_locale_dir(
             &ctx.fallback,
             krate.name.as_str(),
             &fallback_locale_dir,
         )?;
-        validate_orphaned_locale_ftl_paths(&fallback_locale_dir, krate.name.as_str())?;
+        if !ctx.has_locale(&ctx.fallback) {
+            return Err(CliError::Other(format!(
+                "fallback locale '{}' has no FTL files for {}: {}; refusing to scan orphaned files because every non-fallback FTL file would look orphaned",
+                ctx.fallback,
+                krate.name,
+                ctx.ftl_path(&ctx.fallback).display()
+            )));
+        }
+        validate_orphaned_locale_ftl_paths(
+            &ctx.assets_dir.join(&ctx.fallback),
+            ctx.layout,
+            krate.name.as_str(),
+        )?;
 
         if all_locales {
             let mut invalid_paths = Vec::new();
@@ -272,12 +329,13 @@ fn orphaned_scan_context<'a>(
         }
 
         for (locale, _ftl_path) in ctx.iter_non_fallback() {
-            let locale_dir = ctx.locale_dir(locale);
-            validate_orphaned_locale_ftl_paths(&locale_dir, krate.name.as_str())?;
+            let locale_dir = ctx.assets_dir.join(locale);
+            validate_orphaned_locale_ftl_paths(&locale_dir, ctx.layout, krate.name.as_str())?;
             cleanup_targets.insert(LocaleCleanupTarget {
-                fallback_locale_dir: fallback_locale_dir.clone(),
+                fallback_locale_dir: ctx.assets_dir.join(&ctx.fallback),
                 locale: locale.to_string(),
                 locale_dir,
+                layout: ctx.layout,
             });
         }
     }
@@ -323,7 +381,11 @@ fn validate_expected_files_for_cleanup_targets(
 ) -> Result<(), CliError> {
     for target in cleanup_targets {
         cleaner
-            .expected_files_for_locale(&target.locale_dir, &target.fallback_locale_dir)
+            .expected_files_for_locale(
+                &target.locale_dir,
+                &target.fallback_locale_dir,
+                target.layout,
+            )
             .map_err(|error| {
                 CliError::Other(format!(
                     "FTL file layout could not be read while preparing orphaned file expectations for locale '{}' at {}: {}; refusing to scan orphaned files",
@@ -339,9 +401,10 @@ fn validate_expected_files_for_cleanup_targets(
 
 fn validate_orphaned_locale_ftl_paths(
     locale_dir: &std::path::Path,
+    layout: AssetsLayout,
     crate_name: &str,
 ) -> Result<(), CliError> {
-    crate::ftl::discover_locale_ftl_files(locale_dir).map_err(|error| {
+    crate::ftl::discover_locale_ftl_files(locale_dir, layout).map_err(|error| {
         CliError::Other(format!(
             "FTL file layout could not be read for {crate_name}: {error}; refusing to scan orphaned files"
         ))
@@ -376,7 +439,7 @@ mod tests {
         let valid_crates = HashSet::from(["test-app-a"]);
         let cleaner = OrphanedCleaner::new(valid_crates);
         let expected = cleaner
-            .expected_files_for_locale(&locale_dir, &fallback_dir)
+            .expected_files_for_locale(&locale_dir, &fallback_dir, AssetsLayout::LanguageFirst)
             .expect("build expected files");
 
         assert!(expected.contains(&locale_dir.join("test-app-a.ftl")));
@@ -416,7 +479,7 @@ mod tests {
         let valid_crates = HashSet::from(["crate-a", "crate-b"]);
         let cleaner = OrphanedCleaner::new(valid_crates);
         let expected = cleaner
-            .expected_files_for_locale(&locale_dir, &fallback_dir)
+            .expected_files_for_locale(&locale_dir, &fallback_dir, AssetsLayout::LanguageFirst)
             .expect("build expected files");
 
         assert!(expected.contains(&locale_dir.join("crate-a.ftl")));
@@ -793,6 +856,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn clean_orphaned_files_domain_first_requires_the_crate_fallback_files() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let workspace = build_workspace(&temp);
+        std::fs::write(
+            temp.path().join("i18n.toml"),
+            "fallback_language = \"en\"\nassets_dir = \"i18n\"\nlayout = \"domain_first\"\n",
+        )
+        .expect("write i18n.toml");
+        let domain_dir = temp.path().join("i18n/test-app");
+        crate::test_fixtures::write_file(&domain_dir.join("en.ftl"), "hello = Hello\n");
+        crate::test_fixtures::write_file(&domain_dir.join("en/ui.ftl"), "button = Button\n");
+        crate::test_fixtures::write_file(&domain_dir.join("fr.ftl"), "hello = Bonjour\n");
+        crate::test_fixtures::write_file(&domain_dir.join("fr/ui.ftl"), "button = Bouton\n");
+        crate::test_fixtures::write_file(&domain_dir.join("fr/orphan.ftl"), "orphan = Orphelin\n");
+
+        clean_orphaned_files(&workspace, true, false).expect("clean domain_first orphans");
+        assert!(!domain_dir.join("fr/orphan.ftl").exists());
+        assert!(domain_dir.join("fr.ftl").exists());
+        assert!(domain_dir.join("fr/ui.ftl").exists());
+
+        // The shared domain directory alone does not count as the fallback.
+        std::fs::remove_file(domain_dir.join("en.ftl")).expect("remove fallback main file");
+        std::fs::remove_dir_all(domain_dir.join("en")).expect("remove fallback namespace dir");
+        let error = clean_orphaned_files(&workspace, true, false)
+            .expect_err("missing fallback files should stop orphan cleanup");
+        assert!(
+            error
+                .to_string()
+                .contains("refusing to scan orphaned files")
+        );
+        assert!(domain_dir.join("fr.ftl").exists());
+        assert!(domain_dir.join("fr/ui.ftl").exists());
+    }
+
     #[test]
     fn clean_orphaned_files_removes_empty_nested_parent_dirs() {
         let temp = tempfile::tempdir().expect("tempdir");
@@ -464,7 +464,7 @@ pub(crate) fn validate_generation_paths(
             ));
         }
 
-        let fallback_dir = ctx.locale_dir(&ctx.fallback);
+        let fallback_dir = ctx.locale_root(&ctx.fallback);
         if validate_fallback_locale && fallback_dir.exists() && !fallback_dir.is_dir() {
             invalid_paths.push(format!(
                 "fallback locale path '{}' for {} is not a directory: {}",
@@ -486,11 +486,7 @@ pub(crate) fn validate_generation_paths(
         }
 
         if validate_fallback_locale && fallback_dir.is_dir() {
-            let layout = crate::ftl::CrateFtlLayout::from_assets_dir(
-                &ctx.assets_dir,
-                &ctx.fallback,
-                krate.name.as_str(),
-            );
+            let layout = ctx.crate_layout(&ctx.fallback);
             if let Err(error) = layout.discover_files() {
                 invalid_paths.push(format!(
                     "fallback locale FTL layout for {} could not be read: {}",
@@ -524,6 +520,7 @@ pub fn run_generation_command(
     action: GenerationAction,
     force_run: bool,
     dry_run: bool,
+    error_on_orphans: bool,
     verb: GenerationVerb,
 ) -> Result<(), CliError> {
     let workspace = WorkspaceCrates::discover(workspace_args)?;
@@ -534,6 +531,10 @@ pub fn run_generation_command(
     workspace.require_all_crates_valid()?;
     validate_generation_paths(&workspace.valid, true)?;
 
+    if error_on_orphans {
+        reject_orphan_keys(&workspace, force_run)?;
+    }
+
     let results = run_generation_for_crates(
         &workspace.workspace_info,
         &workspace.valid,
@@ -552,6 +553,52 @@ pub fn run_generation_command(
     Ok(())
 }
 
+/// Fail when the fallback locale holds keys that no registered type produces.
+///
+/// The check is a dry-run clean, so it writes nothing; each orphan key is
+/// listed with the group it sits under.
+fn reject_orphan_keys(workspace: &WorkspaceCrates, force_run: bool) -> Result<(), CliError> {
+    let results = run_generation_for_crates(
+        &workspace.workspace_info,
+        &workspace.valid,
+        &GenerationAction::Clean {
+            all_locales: false,
+            dry_run: true,
+        },
+        force_run,
+        true,
+    );
+
+    let has_errors = render_generation_results(
+        &results,
+        |result| {
+            if !result.report.removed.is_empty() {
+                ui::Ui::print_orphan_keys(result.name.as_str(), &result.report);
+            }
+        },
+        |result| {
+            ui::Ui::print_generation_error(result.name.as_str(), result.error.as_ref().unwrap())
+        },
+    );
+
+    if has_errors {
+        return Err(CliError::Other(
+            "orphan key check failed; see diagnostics above".to_string(),
+        ));
+    }
+    let orphan_count: usize = results
+        .iter()
+        .map(|result| result.report.removed.len())
+        .sum();
+    if orphan_count > 0 {
+        return Err(CliError::Other(format!(
+            "found {orphan_count} orphan key(s); remove them with `clean` or restore their types"
+        )));
+    }
+
+    Ok(())
+}
+
 /// Render a list of `GenerateResult`s with custom success/error handlers.
 ///
 /// Returns `true` when any errors were encountered.
@@ -628,6 +675,7 @@ pub fn render_generation_results_with_dry_run(
                 } else {
                     println!("{} {}", "Unchanged:".dimmed(), result.name.as_str().bold());
                 }
+                ui::Ui::print_generate_report(&result.report);
             } else if result.changed {
                 verb.print_changed(result);
             } else {
@@ -21,6 +21,7 @@ pub enum DryRunSummary {
     Format { formatted: usize },
     Sync { keys: usize, locales: usize },
     AddLocale { keys: usize, locales: usize },
+    Rename { keys: usize, files: usize },
 }
 
 impl DryRunSummary {
@@ -35,6 +36,9 @@ impl DryRunSummary {
             DryRunSummary::AddLocale { keys, locales } => {
                 ui::Ui::print_add_locale_dry_run_summary(keys, locales);
             },
+            DryRunSummary::Rename { keys, files } => {
+                ui::Ui::print_rename_dry_run_summary(keys, files);
+            },
         }
     }
 }
@@ -66,5 +70,6 @@ mod tests {
             locales: 2,
         }
         .print();
+        DryRunSummary::Rename { keys: 4, files: 2 }.print();
     }
 }
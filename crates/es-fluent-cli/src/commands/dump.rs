@@ -0,0 +1,251 @@
+//! Dump command for exporting the Rust-derived key catalog.
+//!
+//! The catalog is collected with the same runner pass as `check` and printed
+//! to stdout as JSON with a versioned schema, so translation-management tools
+//! can ingest it without depending on the runner's `metadata/` file layout.
+
+use super::check::inventory::read_inventory_data;
+use super::common::{WorkspaceArgs, WorkspaceCrates};
+use crate::core::{CliError, CrateInfo, WorkspaceInfo};
+use crate::ftl::LocaleContext;
+use crate::generation::MonolithicExecutor;
+use clap::Parser;
+use es_fluent_runner::{ExpectedKey, RunnerMetadataStore};
+use es_fluent_shared::fluent::{FluentArgumentName, FluentEntryId};
+use es_fluent_shared::meta::TypeKind;
+use es_fluent_shared::resource::ModuleResourceSpec;
+use es_fluent_shared::source::{SourceFile, SourceLine};
+use serde::Serialize;
+use std::path::Path;
+
+/// Version of the `dump` JSON schema, bumped on incompatible changes.
+pub(crate) const DUMP_SCHEMA_VERSION: u32 = 1;
+
+/// Arguments for the dump command.
+#[derive(Debug, Parser)]
+pub struct DumpArgs {
+    #[command(flatten)]
+    pub workspace: WorkspaceArgs,
+
+    /// Run the generated runner through Cargo, ignoring the staleness cache.
+    #[arg(long)]
+    pub force_run: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct DumpReport {
+    schema_version: u32,
+    crates: Vec<DumpCrate>,
+}
+
+#[derive(Debug, Serialize)]
+struct DumpCrate {
+    name: String,
+    fallback_language: String,
+    keys: Vec<DumpKey>,
+}
+
+#[derive(Debug, Serialize)]
+struct DumpKey {
+    key: FluentEntryId,
+    type_name: Option<String>,
+    type_kind: Option<TypeKind>,
+    variables: Vec<FluentArgumentName>,
+    /// Domain that owns the resource, usually the crate name.
+    domain: String,
+    /// FTL file path under each locale directory.
+    resource: String,
+    source_file: Option<SourceFile>,
+    source_line: Option<SourceLine>,
+}
+
+impl DumpKey {
+    fn from_expected(crate_name: &str, expected: ExpectedKey) -> Self {
+        let resource = expected
+            .resource
+            .unwrap_or_else(|| ModuleResourceSpec::base(crate_name, true));
+
+        Self {
+            key: expected.key,
+            type_name: expected.type_name,
+            type_kind: expected.type_kind,
+            variables: expected.variables,
+            domain: resource.key.domain().to_string(),
+            resource: resource.locale_relative_path.into_string(),
+            source_file: expected.source_file,
+            source_line: expected.source_line,
+        }
+    }
+}
+
+/// Run the dump command.
+pub fn run_dump(args: DumpArgs) -> Result<(), CliError> {
+    let workspace = WorkspaceCrates::discover(args.workspace)?;
+    workspace.require_non_empty_selection()?;
+    workspace.require_all_crates_valid()?;
+
+    let report = collect_dump_report(&workspace, args.force_run)?;
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&report)
+            .map_err(|error| CliError::Other(error.to_string()))?
+    );
+
+    Ok(())
+}
+
+fn collect_dump_report(
+    workspace: &WorkspaceCrates,
+    force_run: bool,
+) -> Result<DumpReport, CliError> {
+    let root_dir = &workspace.workspace_info.root_dir;
+    let runner_workspace = WorkspaceInfo {
+        root_dir: root_dir.clone(),
+        target_dir: workspace.workspace_info.target_dir.clone(),
+        crates: workspace.valid.clone(),
+    };
+
+    let _runner_lock = crate::generation::acquire_monolithic_runner_lock(root_dir)
+        .map_err(|e| CliError::Other(e.to_string()))?;
+    crate::generation::prepare_monolithic_runner_crate(&runner_workspace)
+        .map_err(|e| CliError::Other(e.to_string()))?;
+
+    let temp_store = RunnerMetadataStore::temp_for_workspace(root_dir);
+    let executor = MonolithicExecutor::new(&runner_workspace);
+    let mut crates = Vec::with_capacity(workspace.valid.len());
+
+    for krate in &workspace.valid {
+        executor
+            .execute_request(&krate.check_request(), force_run)
+            .map_err(|e| CliError::Other(e.to_string()))?;
+        crates.push(
+            dump_crate(krate, temp_store.base_dir())
+                .map_err(|e| CliError::Other(format!("{}: {e}", krate.name)))?,
+        );
+    }
+
+    Ok(DumpReport {
+        schema_version: DUMP_SCHEMA_VERSION,
+        crates,
+    })
+}
+
+fn dump_crate(krate: &CrateInfo, temp_dir: &Path) -> anyhow::Result<DumpCrate> {
+    let fallback_language = LocaleContext::from_crate(krate, false)?.fallback;
+    let inventory = read_inventory_data(temp_dir, &krate.name)?;
+
+    Ok(DumpCrate {
+        name: krate.name.to_string(),
+        fallback_language,
+        keys: inventory
+            .expected_keys
+            .into_iter()
+            .map(|expected| DumpKey::from_expected(krate.name.as_str(), expected))
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::FakeRunnerBehavior;
+    use std::fs;
+
+    const TYPED_INVENTORY: &str = r#"{
+  "expected_keys": [
+    {
+      "key": "hello",
+      "variables": ["name"],
+      "resource": {
+        "key": "test-app/ui",
+        "locale_relative_path": "test-app/ui.ftl",
+        "required": true
+      },
+      "source_file": "src/lib.rs",
+      "source_line": 3,
+      "type_name": "Hello",
+      "type_kind": "struct"
+    },
+    {
+      "key": "legacy",
+      "variables": [],
+      "source_file": null,
+      "source_line": null
+    }
+  ]
+}"#;
+
+    fn discover(temp: &tempfile::TempDir) -> WorkspaceCrates {
+        WorkspaceCrates::discover(WorkspaceArgs {
+            path: Some(temp.path().to_path_buf()),
+            package: None,
+        })
+        .expect("discover workspace")
+    }
+
+    #[test]
+    fn collect_dump_report_serializes_the_versioned_key_catalog() {
+        let temp = crate::test_fixtures::create_test_crate_workspace();
+        crate::test_fixtures::setup_fake_runner_and_cache(
+            &temp,
+            FakeRunnerBehavior::silent_success(),
+        );
+        let package = es_fluent_runner::PackageName::try_new("test-app").expect("package");
+        let inventory_path =
+            RunnerMetadataStore::new(temp.path().join(".es-fluent")).inventory_path(&package);
+        fs::create_dir_all(inventory_path.parent().unwrap()).expect("create inventory dir");
+        fs::write(&inventory_path, TYPED_INVENTORY).expect("write inventory");
+
+        let report = collect_dump_report(&discover(&temp), false).expect("dump report");
+        let json = serde_json::to_value(&report).expect("serialize report");
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "schema_version": DUMP_SCHEMA_VERSION,
+                "crates": [{
+                    "name": "test-app",
+                    "fallback_language": "en",
+                    "keys": [
+                        {
+                            "key": "hello",
+                            "type_name": "Hello",
+                            "type_kind": "struct",
+                            "variables": ["name"],
+                            "domain": "test-app",
+                            "resource": "test-app/ui.ftl",
+                            "source_file": "src/lib.rs",
+                            "source_line": 3
+                        },
+                        {
+                            "key": "legacy",
+                            "type_name": null,
+                            "type_kind": null,
+                            "variables": [],
+                            "domain": "test-app",
+                            "resource": "test-app.ftl",
+                            "source_file": null,
+                            "source_line": null
+                        }
+                    ]
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn run_dump_rejects_crates_without_a_library_target() {
+        let temp = crate::test_fixtures::create_binary_only_i18n_workspace();
+
+        let error = run_dump(DumpArgs {
+            workspace: WorkspaceArgs {
+                path: Some(temp.path().to_path_buf()),
+                package: None,
+            },
+            force_run: false,
+        })
+        .expect_err("binary-only crate cannot be dumped");
+
+        assert!(error.to_string().contains("missing a Cargo library target"));
+    }
+}
@@ -6,7 +6,7 @@
 use super::common::{OutputFormat, WorkspaceArgs, WorkspaceCrates};
 use super::dry_run::{DryRunDiff, DryRunSummary};
 use crate::core::{CliError, CrateInfo, FormatError, FormatReport};
-use crate::ftl::{CrateFtlLayout, LocaleContext};
+use crate::ftl::LocaleContext;
 use crate::utils::ui;
 use anyhow::Result;
 use clap::Parser;
@@ -268,7 +268,7 @@ pub(crate) fn format_crate(
     }
 
     let fallback_dir = ctx.locale_dir(&ctx.fallback);
-    if !fallback_dir.is_dir() {
+    if !ctx.has_locale(&ctx.fallback) || !ctx.locale_root(&ctx.fallback).is_dir() {
         return Ok(vec![FormatResult::error(
             &fallback_dir,
             format!(
@@ -298,7 +298,13 @@ pub(crate) fn format_crate(
     }
 
     for locale in &ctx.locales {
-        let locale_dir = ctx.locale_dir(locale);
+        // With the domain-first layout other crates can hold locales this
+        // crate has no files for; there is nothing to format there.
+        if !ctx.has_locale(locale) {
+            continue;
+        }
+
+        let locale_dir = ctx.locale_root(locale);
         if !locale_dir.is_dir() {
             results.push(FormatResult::error(
                 &locale_dir,
@@ -308,8 +314,7 @@ pub(crate) fn format_crate(
         }
 
         // Format main + namespaced files for this crate.
-        let ftl_files = CrateFtlLayout::from_assets_dir(&ctx.assets_dir, locale, &ctx.crate_name)
-            .discover_files()?;
+        let ftl_files = ctx.crate_layout(locale).discover_files()?;
         for file_info in ftl_files {
             let ftl_file = fs::canonicalize(&file_info.abs_path).unwrap_or(file_info.abs_path);
             let result = format_ftl_file(&ftl_file, check_only);
@@ -342,9 +347,10 @@ fn format_ftl_file(path: &Path, check_only: bool) -> FormatResult {
         );
     }
 
-    // Use shared formatting logic from es-fluent-generate
-    let formatted = es_fluent_generate::formatting::sort_ftl_resource(&resource);
-    let formatted_content = format!("{}\n", formatted.trim_end());
+    // Use shared formatting logic from es-fluent-generate, keeping labeled
+    // groups together the way `generate` places them.
+    let formatted = es_fluent_generate::formatting::sort_ftl_file_resource(&resource);
+    let formatted_content = es_fluent_generate::formatting::normalize_blank_lines(&formatted);
 
     if content == formatted_content {
         return FormatResult::unchanged(path);
@@ -516,6 +522,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_crate_domain_first_checks_the_crate_locale_files() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let krate = write_test_crate(temp.path());
+        std::fs::write(
+            temp.path().join("i18n.toml"),
+            format!("{I18N_TOML}layout = \"domain_first\"\n"),
+        )
+        .expect("write i18n.toml");
+        std::fs::remove_dir_all(temp.path().join("i18n/en")).expect("remove language-first dir");
+        let domain_dir = temp.path().join("i18n/test-app");
+        crate::test_fixtures::write_file(&domain_dir.join("en.ftl"), HELLO_FTL);
+        crate::test_fixtures::write_file(&domain_dir.join("en/ui.ftl"), UI_UNSORTED_FTL);
+        // Another crate's domain holds a locale this crate has no files for.
+        crate::test_fixtures::write_file(&temp.path().join("i18n/other/fr.ftl"), HELLO_FTL);
+
+        let results = format_crate(&krate, true, false).expect("format crate");
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|result| result.error.is_none()));
+        let content = std::fs::read_to_string(domain_dir.join("en/ui.ftl")).expect("read ui");
+        assert!(content.starts_with("alpha = A\nzeta = Z"));
+
+        // The shared domain directory alone does not count as the fallback.
+        std::fs::remove_file(domain_dir.join("en.ftl")).expect("remove main file");
+        std::fs::remove_dir_all(domain_dir.join("en")).expect("remove namespace dir");
+        let results = format_crate(&krate, false, false).expect("format crate");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].path, domain_dir.join("en"));
+        assert!(
+            results[0]
+                .error
+                .as_deref()
+                .is_some_and(|error| error.contains("missing"))
+        );
+    }
+
     #[test]
     fn format_crate_errors_when_fallback_locale_path_is_file() {
         let temp = tempfile::tempdir().expect("tempdir");
@@ -601,6 +643,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_ftl_file_keeps_generated_group_labels_in_place() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let ftl = temp.path().join("demo.ftl");
+        // What `generate` writes for two types sharing
+        // `#[fluent(group = "User Facing Errors")]` next to an `App` type.
+        let generated = "## App\n\napp = App\n\n## User Facing Errors\n\napp_error-Timeout = Timeout\nauth_error-Denied = Denied\n";
+        std::fs::write(&ftl, generated).expect("write ftl");
+
+        let result = format_ftl_file(&ftl, false);
+
+        assert!(result.error.is_none(), "{:?}", result.error);
+        assert!(!result.changed);
+        assert_eq!(std::fs::read_to_string(&ftl).expect("read ftl"), generated);
+    }
+
+    #[test]
+    fn format_ftl_file_normalizes_blank_lines_like_generate() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let ftl = temp.path().join("demo.ftl");
+        std::fs::write(
+            &ftl,
+            "## App\n\napp = App\n\n\n\n## Errors\n\nerror = Error\n\n\n",
+        )
+        .expect("write ftl");
+
+        let result = format_ftl_file(&ftl, false);
+
+        assert!(result.error.is_none(), "{:?}", result.error);
+        let formatted = std::fs::read_to_string(&ftl).expect("read ftl");
+        assert_eq!(
+            formatted,
+            es_fluent_generate::formatting::normalize_blank_lines(&formatted)
+        );
+        assert!(!formatted.contains("\n\n\n"));
+        assert!(!format_ftl_file(&ftl, false).changed);
+    }
+
     #[test]
     fn relative_format_path_strips_workspace_paths_for_json() {
         let temp = tempfile::tempdir().expect("tempdir");
@@ -21,6 +21,11 @@ pub struct GenerateArgs {
     /// Run the generated runner through Cargo, ignoring the staleness cache.
     #[arg(long)]
     pub force_run: bool,
+
+    /// Fail without writing anything when the fallback locale has keys that
+    /// no registered type produces, listing each one with its group.
+    #[arg(long)]
+    pub error_on_orphans: bool,
 }
 
 /// Run the generate command.
@@ -33,6 +38,7 @@ pub fn run_generate(args: GenerateArgs) -> Result<(), CliError> {
         },
         args.force_run,
         args.dry_run,
+        args.error_on_orphans,
         GenerationVerb::Generate,
     )
 }
@@ -54,6 +60,7 @@ mod tests {
             mode: FluentParseMode::default(),
             dry_run: false,
             force_run: false,
+            error_on_orphans: false,
         });
 
         assert!(
@@ -72,6 +79,7 @@ mod tests {
             mode: FluentParseMode::default(),
             dry_run: false,
             force_run: false,
+            error_on_orphans: false,
         });
 
         assert!(
@@ -90,6 +98,7 @@ mod tests {
             mode: FluentParseMode::default(),
             dry_run: false,
             force_run: false,
+            error_on_orphans: false,
         });
 
         assert!(matches!(result, Err(CliError::Other(message)) if message.contains("'bin-app'")));
@@ -109,6 +118,7 @@ mod tests {
             mode: FluentParseMode::default(),
             dry_run: false,
             force_run: false,
+            error_on_orphans: false,
         });
 
         assert!(
@@ -141,6 +151,7 @@ mod tests {
             mode: FluentParseMode::default(),
             dry_run: false,
             force_run: false,
+            error_on_orphans: false,
         });
 
         assert!(
@@ -172,6 +183,7 @@ mod tests {
             mode: FluentParseMode::default(),
             dry_run: false,
             force_run: false,
+            error_on_orphans: false,
         });
 
         assert!(
@@ -203,8 +215,76 @@ mod tests {
             mode: FluentParseMode::default(),
             dry_run: false,
             force_run: false,
+            error_on_orphans: false,
         });
 
         assert!(result.is_ok());
     }
+
+    fn write_runner_report(temp: &tempfile::TempDir, report: es_fluent_runner::GenerateReport) {
+        let package = es_fluent_runner::PackageName::try_new("test-app").expect("package");
+        let store = es_fluent_runner::RunnerMetadataStore::new(temp.path().join(".es-fluent"));
+        store
+            .write_result(
+                &package,
+                &es_fluent_runner::RunnerResult {
+                    changed: !report.removed.is_empty(),
+                    report,
+                    check: None,
+                },
+            )
+            .expect("write runner result");
+    }
+
+    fn generate_erroring_on_orphans(temp: &tempfile::TempDir) -> Result<(), CliError> {
+        run_generate(GenerateArgs {
+            workspace: WorkspaceArgs {
+                path: Some(temp.path().to_path_buf()),
+                package: None,
+            },
+            mode: FluentParseMode::default(),
+            dry_run: false,
+            force_run: false,
+            error_on_orphans: true,
+        })
+    }
+
+    #[test]
+    fn run_generate_error_on_orphans_fails_and_lists_the_orphans() {
+        let temp = crate::test_fixtures::create_test_crate_workspace();
+        crate::test_fixtures::setup_fake_runner_and_cache(
+            &temp,
+            FakeRunnerBehavior::silent_success(),
+        );
+        write_runner_report(
+            &temp,
+            es_fluent_runner::GenerateReport {
+                removed: vec!["old_enum-Gone".to_string(), "stale".to_string()],
+                removed_groups: [("old_enum-Gone".to_string(), "OldEnum".to_string())].into(),
+                ..Default::default()
+            },
+        );
+        let ftl_path = temp.path().join("i18n/en/test-app.ftl");
+        let before = fs::read_to_string(&ftl_path).expect("read ftl");
+
+        let result = generate_erroring_on_orphans(&temp);
+
+        assert!(
+            matches!(&result, Err(CliError::Other(message)) if message.contains("found 2 orphan key(s)")),
+            "unexpected result: {result:?}"
+        );
+        assert_eq!(fs::read_to_string(&ftl_path).expect("read ftl"), before);
+    }
+
+    #[test]
+    fn run_generate_error_on_orphans_generates_when_no_orphans_exist() {
+        let temp = crate::test_fixtures::create_test_crate_workspace();
+        crate::test_fixtures::setup_fake_runner_and_cache(
+            &temp,
+            FakeRunnerBehavior::silent_success(),
+        );
+        write_runner_report(&temp, es_fluent_runner::GenerateReport::default());
+
+        assert!(generate_erroring_on_orphans(&temp).is_ok());
+    }
 }
@@ -5,11 +5,14 @@ mod check;
 mod clean;
 mod common;
 mod dry_run;
+mod dump;
 mod format;
 mod generate;
+mod rename;
 mod status;
 mod sync;
 mod tree;
+mod validate;
 mod watch;
 
 pub(crate) use add_locale::{AddLocaleArgs, run_add_locale};
@@ -17,9 +20,12 @@ pub(crate) use check::{CheckArgs, run_check};
 pub(crate) use clean::{CleanArgs, run_clean};
 #[cfg(test)]
 pub(crate) use common::{OutputFormat, WorkspaceArgs};
+pub(crate) use dump::{DumpArgs, run_dump};
 pub(crate) use format::{FormatArgs, run_format};
 pub(crate) use generate::{GenerateArgs, run_generate};
+pub(crate) use rename::{RenameArgs, run_rename};
 pub(crate) use status::{StatusArgs, run_status};
 pub(crate) use sync::{SyncArgs, run_sync};
 pub(crate) use tree::{TreeArgs, run_tree};
+pub(crate) use validate::{ValidateArgs, run_validate};
 pub(crate) use watch::{WatchArgs, run_watch};
@@ -0,0 +1,297 @@
+//! Rename command for carrying translations over when a Rust type is renamed.
+//!
+//! Every locale's FTL files are rewritten so the old type's group comment,
+//! message ids and references use the new type's name. All files are planned
+//! before any is written, so a conflict or parse error leaves every locale
+//! untouched.
+
+use super::common::{WorkspaceArgs, WorkspaceCrates};
+use super::dry_run::{DryRunDiff, DryRunSummary};
+use crate::core::{CliError, CrateInfo};
+use crate::ftl::LocaleContext;
+use crate::utils::ui;
+use anyhow::Result;
+use clap::Parser;
+use es_fluent_generate::rename::{TypeRename, rename_in_resource};
+use es_fluent_shared::namer::KeyCase;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Arguments for the rename command.
+#[derive(Debug, Parser)]
+pub struct RenameArgs {
+    #[command(flatten)]
+    pub workspace: WorkspaceArgs,
+
+    /// The type's previous Rust name, such as `LoginError`.
+    #[arg(long, value_name = "TYPE")]
+    pub from: String,
+
+    /// The type's new Rust name, such as `SignInError`.
+    #[arg(long, value_name = "TYPE")]
+    pub to: String,
+
+    /// Key casing the type uses through `#[fluent(case = "...")]` (snake, kebab, or camel).
+    #[arg(long)]
+    pub case: Option<KeyCase>,
+
+    /// Dry run - show what would be renamed without making changes.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// A planned rewrite of one FTL file.
+#[derive(Debug)]
+struct RenamePlan {
+    path: PathBuf,
+    renamed: usize,
+    diff: DryRunDiff,
+    content: String,
+}
+
+/// Run the rename command.
+pub fn run_rename(args: RenameArgs) -> Result<(), CliError> {
+    let rename = TypeRename::new(&args.from, &args.to, args.case);
+    if rename.from_group() == rename.to_group() {
+        return Err(CliError::Other(format!(
+            "--from and --to both name `{}`",
+            rename.from_group()
+        )));
+    }
+
+    let workspace = WorkspaceCrates::discover(args.workspace)?;
+    if !workspace.print_discovery(ui::Ui::print_rename_header) {
+        return workspace.require_non_empty_selection();
+    }
+    workspace.require_non_empty_selection()?;
+
+    let mut plans = Vec::new();
+    let mut errors = Vec::new();
+    for krate in &workspace.crates {
+        match plan_crate(krate, &rename) {
+            Ok((crate_plans, crate_errors)) => {
+                plans.extend(crate_plans);
+                errors.extend(crate_errors);
+            },
+            Err(error) => errors.push(format!("{}: {error}", krate.name)),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(CliError::Other(format!(
+            "no files were renamed:\n{}",
+            errors
+                .iter()
+                .map(|error| format!("  {error}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        )));
+    }
+
+    let mut total_renamed = 0;
+    for plan in &plans {
+        let display_path = std::env::current_dir()
+            .ok()
+            .and_then(|cwd| plan.path.strip_prefix(&cwd).ok())
+            .unwrap_or(&plan.path);
+
+        if args.dry_run {
+            ui::Ui::print_would_rename(display_path, plan.renamed);
+            plan.diff.print();
+        } else {
+            fs::write(&plan.path, &plan.content).map_err(|error| {
+                CliError::Other(format!("Failed to write {}: {error}", plan.path.display()))
+            })?;
+            ui::Ui::print_renamed(display_path, plan.renamed);
+        }
+        total_renamed += plan.renamed;
+    }
+
+    if args.dry_run && !plans.is_empty() {
+        DryRunSummary::Rename {
+            keys: total_renamed,
+            files: plans.len(),
+        }
+        .print();
+    } else {
+        ui::Ui::print_rename_summary(rename.from_group(), total_renamed, plans.len());
+    }
+    Ok(())
+}
+
+/// Plan the rename for every locale of a crate.
+///
+/// Returns the files that would change and the per-file errors, such as parse
+/// errors or ids the rename would collide with.
+fn plan_crate(krate: &CrateInfo, rename: &TypeRename) -> Result<(Vec<RenamePlan>, Vec<String>)> {
+    let ctx = LocaleContext::from_crate(krate, true)?;
+    let mut plans = Vec::new();
+    let mut errors = Vec::new();
+
+    for locale in &ctx.locales {
+        if !ctx.has_locale(locale) || !ctx.locale_root(locale).is_dir() {
+            continue;
+        }
+
+        let ftl_files = ctx.crate_layout(locale).discover_files()?;
+        for file_info in ftl_files {
+            let path = fs::canonicalize(&file_info.abs_path).unwrap_or(file_info.abs_path);
+            match plan_ftl_file(&path, rename) {
+                Ok(Some(plan)) => plans.push(plan),
+                Ok(None) => {},
+                Err(error) => errors.push(format!("{}: {error}", path.display())),
+            }
+        }
+    }
+
+    Ok((plans, errors))
+}
+
+/// Plan the rename for a single FTL file, or `None` when nothing in it changes.
+fn plan_ftl_file(path: &Path, rename: &TypeRename) -> Result<Option<RenamePlan>, String> {
+    let content =
+        fs::read_to_string(path).map_err(|error| format!("Failed to read file: {error}"))?;
+    if content.trim().is_empty() {
+        return Ok(None);
+    }
+
+    let (mut resource, errors) = es_fluent_generate::ftl::parse_ftl_content(content.clone());
+    if !errors.is_empty() {
+        return Err(format!(
+            "Refusing to rename in file with parse errors: {}",
+            es_fluent_generate::ftl::format_parse_errors(&errors)
+        ));
+    }
+
+    let report = rename_in_resource(&mut resource, rename).map_err(|error| error.to_string())?;
+    if report.is_empty() {
+        return Ok(None);
+    }
+
+    let renamed_content = es_fluent_generate::formatting::normalize_blank_lines(
+        &fluent_syntax::serializer::serialize(&resource),
+    );
+    Ok(Some(RenamePlan {
+        path: path.to_path_buf(),
+        renamed: report.renamed.len(),
+        diff: DryRunDiff::new(content, renamed_content.clone()),
+        content: renamed_content,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::create_workspace_with_locales;
+
+    const EN_FTL: &str = "\
+## LoginError
+
+login_error-InvalidPassword = Invalid password
+login_error-Locked = Locked: { login_error-InvalidPassword }
+
+## Other
+
+other = Other
+";
+
+    const FR_FTL: &str = "\
+## LoginError
+
+login_error-InvalidPassword = Mot de passe invalide
+login_error-Locked = Verrouillé : { login_error-InvalidPassword }
+";
+
+    fn rename_args(temp: &Path, dry_run: bool) -> RenameArgs {
+        RenameArgs {
+            workspace: WorkspaceArgs {
+                path: Some(temp.to_path_buf()),
+                package: None,
+            },
+            from: "LoginError".to_string(),
+            to: "SignInError".to_string(),
+            case: None,
+            dry_run,
+        }
+    }
+
+    #[test]
+    fn run_rename_rewrites_every_locale() {
+        let temp = create_workspace_with_locales(&[("en", EN_FTL), ("fr", FR_FTL)]);
+
+        run_rename(rename_args(temp.path(), false)).expect("rename");
+
+        let en = fs::read_to_string(temp.path().join("i18n/en/test-app.ftl")).expect("read en");
+        assert_eq!(
+            en,
+            "\
+## SignInError
+
+sign_in_error-InvalidPassword = Invalid password
+sign_in_error-Locked = Locked: { sign_in_error-InvalidPassword }
+
+## Other
+
+other = Other
+"
+        );
+        let fr = fs::read_to_string(temp.path().join("i18n/fr/test-app.ftl")).expect("read fr");
+        assert_eq!(
+            fr,
+            "\
+## SignInError
+
+sign_in_error-InvalidPassword = Mot de passe invalide
+sign_in_error-Locked = Verrouillé : { sign_in_error-InvalidPassword }
+"
+        );
+    }
+
+    #[test]
+    fn run_rename_dry_run_keeps_files_unchanged() {
+        let temp = create_workspace_with_locales(&[("en", EN_FTL), ("fr", FR_FTL)]);
+
+        run_rename(rename_args(temp.path(), true)).expect("dry run");
+
+        let fr = fs::read_to_string(temp.path().join("i18n/fr/test-app.ftl")).expect("read fr");
+        assert_eq!(fr, FR_FTL);
+    }
+
+    #[test]
+    fn run_rename_changes_nothing_when_one_locale_conflicts() {
+        let fr_with_new_key = format!("{FR_FTL}sign_in_error-Locked = Déjà là\n");
+        let temp = create_workspace_with_locales(&[("en", EN_FTL), ("fr", &fr_with_new_key)]);
+
+        let result = run_rename(rename_args(temp.path(), false));
+
+        assert!(
+            matches!(&result, Err(CliError::Other(message)) if message.contains("sign_in_error-Locked")),
+            "expected a conflict error, got {result:?}"
+        );
+        let en = fs::read_to_string(temp.path().join("i18n/en/test-app.ftl")).expect("read en");
+        assert_eq!(en, EN_FTL, "no locale should be written when one conflicts");
+    }
+
+    #[test]
+    fn run_rename_rejects_identical_names() {
+        let temp = create_workspace_with_locales(&[("en", EN_FTL)]);
+        let mut args = rename_args(temp.path(), false);
+        args.to = "LoginError".to_string();
+
+        let result = run_rename(args);
+
+        assert!(matches!(result, Err(CliError::Other(message)) if message.contains("LoginError")));
+    }
+
+    #[test]
+    fn plan_ftl_file_refuses_files_with_parse_errors() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let path = temp.path().join("invalid.ftl");
+        fs::write(&path, "login_error-Locked = { $name\n").expect("write invalid");
+
+        let error = plan_ftl_file(&path, &TypeRename::new("LoginError", "SignInError", None))
+            .expect_err("parse error");
+
+        assert!(error.contains("Refusing to rename in file with parse errors"));
+    }
+}
@@ -262,7 +262,7 @@ fn collect_status_setup_errors(workspace: &WorkspaceCrates) -> Vec<String> {
         };
 
         let fallback_dir = ctx.locale_dir(&ctx.fallback);
-        let fallback_path_invalid = !crate::ftl::is_real_locale_directory(&fallback_dir);
+        let fallback_path_invalid = !ctx.has_locale_dir(&ctx.fallback);
         if fallback_path_invalid {
             setup_errors.push(format!(
                 "{}: fallback locale directory '{}' is missing or not a directory: {}",
@@ -292,18 +292,11 @@ fn collect_status_setup_errors(workspace: &WorkspaceCrates) -> Vec<String> {
         }
 
         for locale in &ctx.locales {
-            let locale_dir = ctx.locale_dir(locale);
-            if !crate::ftl::is_real_locale_directory(&locale_dir) {
+            if !ctx.has_locale_dir(locale) {
                 continue;
             }
 
-            if let Err(error) = crate::ftl::CrateFtlLayout::from_assets_dir(
-                &ctx.assets_dir,
-                locale,
-                &ctx.crate_name,
-            )
-            .discover_files()
-            {
+            if let Err(error) = ctx.crate_layout(locale).discover_files() {
                 setup_errors.push(format!("{}: {}", krate.name, error));
             }
         }
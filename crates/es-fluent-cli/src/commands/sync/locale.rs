@@ -26,6 +26,7 @@ pub(crate) struct SyncLocaleResult {
 struct SyncLocalePlan {
     locale: String,
     locale_dir: PathBuf,
+    files: CrateFtlLayout,
     locale_created: bool,
 }
 
@@ -48,9 +49,9 @@ fn build_sync_crate_plan(
         );
     }
 
-    let fallback_dir = ctx.locale_dir(&ctx.fallback);
+    let fallback_dir = ctx.locale_root(&ctx.fallback);
 
-    if !fallback_dir.is_dir() {
+    if !ctx.has_locale(&ctx.fallback) || !fallback_dir.is_dir() {
         bail!(
             "fallback locale directory '{}' is missing or not a directory for {}: {}; create the directory manually",
             ctx.fallback,
@@ -60,9 +61,7 @@ fn build_sync_crate_plan(
     }
 
     // Discover all FTL files in the fallback locale (including namespaced ones)
-    let fallback_files =
-        CrateFtlLayout::from_assets_dir(&ctx.assets_dir, &ctx.fallback, &ctx.crate_name)
-            .discover_and_load_files()?;
+    let fallback_files = ctx.crate_layout(&ctx.fallback).discover_and_load_files()?;
 
     let mut plans = Vec::new();
     let mut locales: Vec<String> = match target_locales {
@@ -77,8 +76,8 @@ fn build_sync_crate_plan(
             continue;
         }
 
-        let locale_dir = ctx.locale_dir(locale);
-        let locale_path_exists = fs::symlink_metadata(&locale_dir).is_ok();
+        let locale_dir = ctx.locale_root(locale);
+        let locale_path_exists = ctx.has_locale(locale);
         if locale_path_exists && !crate::ftl::is_real_locale_directory(&locale_dir) {
             bail!(
                 "target locale directory '{locale}' is not a directory for {}: {}",
@@ -95,6 +94,7 @@ fn build_sync_crate_plan(
         plans.push(SyncLocalePlan {
             locale: locale.to_string(),
             locale_dir,
+            files: ctx.crate_layout(locale),
             locale_created,
         });
     }
@@ -150,7 +150,7 @@ pub(crate) fn sync_crate(
         for (index, ftl_info) in fallback_files.iter().enumerate() {
             let mut result = sync_locale_file(
                 &plan.locale_dir,
-                &ftl_info.relative_path,
+                &plan.files.file_path(&ftl_info.relative_path),
                 &plan.locale,
                 &ftl_info.resource,
                 &ftl_info.keys,
@@ -171,7 +171,7 @@ fn preflight_sync_targets_parse(
 ) -> Result<()> {
     for plan in plans {
         for ftl_info in fallback_files {
-            let ftl_file = plan.locale_dir.join(&ftl_info.relative_path);
+            let ftl_file = plan.files.file_path(&ftl_info.relative_path);
             validate_sync_target_path(&plan.locale_dir, &ftl_file)?;
             if let Some(parent_dir) = ftl_file.parent()
                 && parent_dir.exists()
@@ -214,6 +214,11 @@ fn preflight_sync_targets_parse(
 fn validate_sync_target_path(locale_dir: &Path, ftl_file: &Path) -> Result<()> {
     let mut current = Some(ftl_file);
     while let Some(path) = current {
+        // Domain-first namespace files can sit beside the locale directory
+        // rather than below it; stop at the assets directory either way.
+        if Some(path) == locale_dir.parent() {
+            break;
+        }
         match fs::symlink_metadata(path) {
             Ok(metadata) => {
                 if metadata.file_type().is_symlink() {
@@ -247,14 +252,13 @@ fn validate_sync_target_path(locale_dir: &Path, ftl_file: &Path) -> Result<()> {
 /// Sync a single FTL file (main or namespaced) with missing keys from the fallback.
 fn sync_locale_file(
     locale_dir: &Path,
-    relative_ftl_path: &Path,
+    ftl_file: &Path,
     locale: &str,
     fallback_resource: &ast::Resource<String>,
     fallback_keys: &HashSet<String>,
     dry_run: bool,
 ) -> Result<SyncLocaleResult> {
-    let ftl_file = locale_dir.join(relative_ftl_path);
-    validate_sync_target_path(locale_dir, &ftl_file)?;
+    validate_sync_target_path(locale_dir, ftl_file)?;
 
     // Ensure the parent directory exists (handles namespaced subdirectories)
     let parent_dir = ftl_file.parent().unwrap_or(locale_dir);
@@ -265,7 +269,7 @@ fn sync_locale_file(
     // Parse existing locale file
     // Read content first to allow diffing later
     let existing_content = if ftl_file.exists() {
-        fs::read_to_string(&ftl_file)?
+        fs::read_to_string(ftl_file)?
     } else {
         String::new()
     };
@@ -312,7 +316,7 @@ fn sync_locale_file(
     let final_content = format!("{}\n", content.trim_end());
 
     if !dry_run {
-        fs::write(&ftl_file, &final_content)?;
+        fs::write(ftl_file, &final_content)?;
     }
 
     // If dry run and we have changes (missing_keys was not empty), compute diff
@@ -381,7 +385,7 @@ mod tests {
         let fallback_keys = crate::ftl::extract_message_keys(&fallback_resource);
         let result = sync_locale_file(
             &locale_dir,
-            &relative_path,
+            &locale_dir.join(&relative_path),
             "es",
             &fallback_resource,
             &fallback_keys,
@@ -408,7 +412,7 @@ mod tests {
         let fallback_keys = crate::ftl::extract_message_keys(&fallback_resource);
         let result = sync_locale_file(
             &locale_dir,
-            &relative_path,
+            &locale_dir.join(&relative_path),
             "es",
             &fallback_resource,
             &fallback_keys,
@@ -435,7 +439,7 @@ mod tests {
         let fallback_keys = crate::ftl::extract_message_keys(&fallback_resource);
         let result = sync_locale_file(
             &locale_dir,
-            &relative_path,
+            &locale_dir.join(&relative_path),
             "es",
             &fallback_resource,
             &fallback_keys,
@@ -465,7 +469,7 @@ mod tests {
         let fallback_keys = crate::ftl::extract_message_keys(&fallback_resource);
         let err = sync_locale_file(
             &locale_dir,
-            &relative_path,
+            &locale_dir.join(&relative_path),
             "es",
             &fallback_resource,
             &fallback_keys,
@@ -493,7 +497,7 @@ mod tests {
         let fallback_keys = crate::ftl::extract_message_keys(&fallback_resource);
         let err = sync_locale_file(
             &locale_dir,
-            Path::new("test-crate.ftl"),
+            &locale_dir.join("test-crate.ftl"),
             "es",
             &fallback_resource,
             &fallback_keys,
@@ -527,7 +531,7 @@ mod tests {
         let fallback_keys = crate::ftl::extract_message_keys(&fallback_resource);
         let err = sync_locale_file(
             &locale_dir,
-            Path::new("test-crate/ui.ftl"),
+            &locale_dir.join("test-crate/ui.ftl"),
             "es",
             &fallback_resource,
             &fallback_keys,
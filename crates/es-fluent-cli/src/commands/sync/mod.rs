@@ -258,8 +258,8 @@ fn validate_explicit_target_locales_exist(
         let ctx = crate::ftl::LocaleContext::from_crate(krate, false)
             .map_err(|error| CliError::Other(error.to_string()))?;
         for target in targets {
-            let target_dir = ctx.locale_dir(target);
-            let target_path_exists = fs::symlink_metadata(&target_dir).is_ok();
+            let target_dir = ctx.locale_root(target);
+            let target_path_exists = ctx.has_locale(target);
             if target_path_exists && !crate::ftl::is_real_locale_directory(&target_dir) {
                 not_directories.push(format!(
                     "{target} for {}: {}",
@@ -308,8 +308,7 @@ fn validate_created_target_locales_visible_to_all_scans(
         }
 
         for target in targets {
-            let target_dir = ctx.locale_dir(target);
-            if !target_dir.exists()
+            if !ctx.has_locale(target)
                 && es_fluent_toml::crate_root_asset_ignored_dir_names().contains(&target.as_str())
             {
                 hidden_targets.push(format!("{target} for {}", krate.name));
@@ -1423,6 +1422,49 @@ world = World"#;
         assert!(fr_content.contains("world = World"));
     }
 
+    #[test]
+    fn run_sync_domain_first_requires_create_for_locales_the_crate_lacks() {
+        let temp = crate::test_fixtures::create_test_crate_workspace_without_ftl();
+        fs::write(
+            temp.path().join("i18n.toml"),
+            "fallback_language = \"en\"\nassets_dir = \"i18n\"\nlayout = \"domain_first\"\n",
+        )
+        .expect("write i18n.toml");
+        fs::remove_dir_all(temp.path().join("i18n/en")).expect("remove language-first dir");
+        crate::test_fixtures::write_file(
+            &temp.path().join("i18n/test-app/en.ftl"),
+            "hello = Hello\nworld = World\n",
+        );
+        // Another crate's domain already has French.
+        crate::test_fixtures::write_file(
+            &temp.path().join("i18n/other/fr.ftl"),
+            "hello = Bonjour\n",
+        );
+        let fr_path = temp.path().join("i18n/test-app/fr.ftl");
+        let args = |create| SyncArgs {
+            workspace: WorkspaceArgs {
+                path: Some(temp.path().to_path_buf()),
+                package: None,
+            },
+            locale: vec!["fr".to_string()],
+            all: false,
+            create,
+            dry_run: false,
+            output: OutputFormat::Text,
+        };
+
+        let result = run_sync(args(false));
+        assert!(
+            matches!(result, Err(CliError::Other(ref message)) if message.contains("do not exist") && message.contains("fr for test-app")),
+            "{result:?}"
+        );
+        assert!(!fr_path.exists());
+
+        run_sync(args(true)).expect("sync with --create");
+        let fr_content = fs::read_to_string(&fr_path).expect("read created locale");
+        assert!(fr_content.contains("world = World"));
+    }
+
     #[test]
     fn run_sync_create_preflights_selected_workspace_before_writing() {
         let temp = tempfile::tempdir().expect("workspace tempdir");
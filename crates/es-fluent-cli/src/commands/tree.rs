@@ -5,7 +5,7 @@
 
 use super::common::{OutputFormat, WorkspaceArgs, WorkspaceCrates};
 use crate::core::{CliError, WorkspaceInfo};
-use crate::ftl::{CrateFtlLayout, LocaleContext};
+use crate::ftl::LocaleContext;
 use crate::generation::MonolithicExecutor;
 use crate::utils::ui;
 use anyhow::{Context as _, Result};
@@ -814,10 +814,13 @@ fn validate_tree_workspace_setup(
             .map_err(|error| CliError::Other(format!("{}: {}", krate.name, error)))?;
 
         for locale in &ctx.locales {
-            let locale_dir = ctx.locale_dir(locale);
+            if !ctx.has_locale(locale) {
+                continue;
+            }
+            let locale_dir = ctx.locale_root(locale);
             validate_tree_locale_dir(locale, &locale_dir)
                 .map_err(|error| CliError::Other(format!("{}: {}", krate.name, error)))?;
-            CrateFtlLayout::from_assets_dir(&ctx.assets_dir, locale, &ctx.crate_name)
+            ctx.crate_layout(locale)
                 .discover_files()
                 .map_err(|error| CliError::Other(format!("{}: {}", krate.name, error)))?;
         }
@@ -837,11 +840,13 @@ fn build_crate_tree_json(
     let mut locales = Vec::new();
 
     for locale in &ctx.locales {
-        let locale_dir = ctx.locale_dir(locale);
+        if !ctx.has_locale(locale) {
+            continue;
+        }
+        let locale_dir = ctx.locale_root(locale);
         validate_tree_locale_dir(locale, &locale_dir)?;
 
-        let ftl_files = CrateFtlLayout::from_assets_dir(&ctx.assets_dir, locale, &ctx.crate_name)
-            .discover_files()?;
+        let ftl_files = ctx.crate_layout(locale).discover_files()?;
         let files = ftl_files
             .iter()
             .map(|file_info| {
@@ -924,8 +929,15 @@ fn validate_tree_locale_setup(ctx: &LocaleContext, all_locales: bool) -> Result<
         },
     }
 
-    let fallback_dir = ctx.locale_dir(&ctx.fallback);
+    let fallback_dir = ctx.locale_root(&ctx.fallback);
     validate_tree_locale_dir(&ctx.fallback, &fallback_dir)?;
+    if !ctx.has_locale(&ctx.fallback) {
+        anyhow::bail!(
+            "locale directory '{}' is missing or not a directory: {}",
+            ctx.fallback,
+            ctx.locale_dir(&ctx.fallback).display()
+        );
+    }
 
     if !all_locales {
         return Ok(());
@@ -1054,11 +1066,13 @@ fn print_crate_tree(
     let mut locale_trees: Vec<Tree> = Vec::new();
 
     for locale in &ctx.locales {
-        let locale_dir = ctx.locale_dir(locale);
+        if !ctx.has_locale(locale) {
+            continue;
+        }
+        let locale_dir = ctx.locale_root(locale);
         validate_tree_locale_dir(locale, &locale_dir)?;
 
-        let ftl_files = CrateFtlLayout::from_assets_dir(&ctx.assets_dir, locale, &ctx.crate_name)
-            .discover_files()?;
+        let ftl_files = ctx.crate_layout(locale).discover_files()?;
 
         let file_trees: Vec<Tree> = ftl_files
             .iter()
@@ -1446,6 +1460,8 @@ mod tests {
                     )),
                     source_file: es_fluent_shared::source::SourceFile::new("src/lib.rs"),
                     source_line: Some(es_fluent_shared::source::SourceLine::new(42)),
+                    type_name: Some("Greeting".to_string()),
+                    type_kind: Some(es_fluent_shared::meta::TypeKind::Struct),
                 }],
             },
         );
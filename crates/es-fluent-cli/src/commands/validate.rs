@@ -0,0 +1,619 @@
+//! Validate command for comparing every locale against the fallback locale.
+//!
+//! The fallback locale's FTL files are the reference: every other locale is
+//! checked for missing keys, extra keys, and messages whose variable references
+//! differ from the fallback message. Every locale, the fallback included, is
+//! also checked for `{ message }` and `{ -term }` references to keys it does
+//! not define; the fallback is only listed when it has such references.
+//! Unlike `check`, no runner is involved unless `--registered-args` asks for
+//! the fallback messages to be compared with the arguments of their Rust types.
+
+use super::common::{OutputFormat, WorkspaceArgs, WorkspaceCrates};
+use crate::core::{CliError, CrateInfo, WorkspaceInfo};
+use crate::ftl::{LoadedFtlFile, LocaleContext};
+use crate::generation::MonolithicExecutor;
+use crate::utils::ui;
+use anyhow::{Result, bail};
+use clap::Parser;
+use es_fluent_generate::validation::{VariableDrift, dangling_references};
+use fluent_syntax::ast;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+
+/// Arguments for the validate command.
+#[derive(Debug, Parser)]
+pub struct ValidateArgs {
+    #[command(flatten)]
+    pub workspace: WorkspaceArgs,
+
+    /// Also compare the fallback messages' variables with the arguments their
+    /// Rust types register. Runs the inventory runner like `check`.
+    #[arg(long)]
+    pub registered_args: bool,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = OutputFormat::default())]
+    pub output: OutputFormat,
+}
+
+/// Message keys mapped to the variables their value and attributes reference.
+type MessageVariables = BTreeMap<String, BTreeSet<String>>;
+
+/// Result of comparing one locale against the fallback locale of a crate.
+#[derive(Debug, Serialize)]
+struct LocaleValidation {
+    crate_name: String,
+    locale: String,
+    /// Fallback keys the locale does not define.
+    missing_keys: Vec<String>,
+    /// Locale keys the fallback does not define.
+    extra_keys: Vec<String>,
+    /// Shared keys whose variable references differ from the fallback. For
+    /// the fallback itself, with `--registered-args`, keys whose variable
+    /// references differ from the arguments registered by their Rust type.
+    variable_mismatches: Vec<VariableMismatch>,
+    /// Message or term references to keys the locale does not define.
+    dangling_references: Vec<DanglingReference>,
+}
+
+impl LocaleValidation {
+    fn is_complete(&self) -> bool {
+        self.missing_keys.is_empty()
+            && self.extra_keys.is_empty()
+            && self.variable_mismatches.is_empty()
+            && self.dangling_references.is_empty()
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+struct VariableMismatch {
+    key: String,
+    /// Variables the reference provides but the message does not reference.
+    missing_variables: Vec<String>,
+    /// Variables the message references but the reference does not provide.
+    unexpected_variables: Vec<String>,
+}
+
+/// A dangling reference as reported in `--json` output.
+#[derive(Debug, Eq, PartialEq, Serialize)]
+struct DanglingReference {
+    /// Key of the message or term holding the reference.
+    key: String,
+    /// Referenced key that the locale does not define.
+    reference: String,
+}
+
+impl From<es_fluent_generate::validation::DanglingReference> for DanglingReference {
+    fn from(dangling: es_fluent_generate::validation::DanglingReference) -> Self {
+        Self {
+            key: dangling.entry,
+            reference: dangling.target,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ValidateJsonReport {
+    crates_discovered: usize,
+    locales_checked: usize,
+    incomplete_locales: usize,
+    errors: Vec<String>,
+    locales: Vec<LocaleValidation>,
+    complete: bool,
+}
+
+impl ValidateJsonReport {
+    fn error(crates_discovered: usize, error: impl ToString) -> Self {
+        Self {
+            crates_discovered,
+            locales_checked: 0,
+            incomplete_locales: 0,
+            errors: vec![error.to_string()],
+            locales: Vec::new(),
+            complete: false,
+        }
+    }
+}
+
+/// Run the validate command.
+pub fn run_validate(args: ValidateArgs) -> Result<(), CliError> {
+    let output = args.output;
+    let workspace = match WorkspaceCrates::discover(args.workspace) {
+        Ok(workspace) => workspace,
+        Err(error) if output.is_json() => {
+            output.print_json(&ValidateJsonReport::error(0, error))?;
+            return Err(CliError::Exit(1));
+        },
+        Err(error) => return Err(error),
+    };
+    let show_text = !output.is_json();
+
+    if show_text && !workspace.print_discovery(ui::Ui::print_validate_header) {
+        return workspace.require_non_empty_selection();
+    }
+
+    if let Err(error) = workspace.require_non_empty_selection() {
+        output.print_json(&ValidateJsonReport::error(0, error))?;
+        return Err(CliError::Exit(1));
+    }
+
+    let root_dir = &workspace.workspace_info.root_dir;
+    let registered = if args.registered_args {
+        match collect_registered_args(&workspace) {
+            Ok(registered) => Some(registered),
+            Err(error) if output.is_json() => {
+                output.print_json(&ValidateJsonReport::error(workspace.crates.len(), error))?;
+                return Err(CliError::Exit(1));
+            },
+            Err(error) => return Err(error),
+        }
+    } else {
+        None
+    };
+    let mut locales = Vec::new();
+    let mut errors = Vec::new();
+
+    for krate in &workspace.crates {
+        let crate_registered = registered
+            .as_ref()
+            .and_then(|registered| registered.get(krate.name.as_str()));
+        match validate_crate(krate, crate_registered) {
+            Ok(results) => locales.extend(results),
+            Err(error) => {
+                let message = relative_validate_message(&error.to_string(), root_dir);
+                if show_text {
+                    ui::Ui::print_validate_error(krate.name.as_str(), &message);
+                }
+                errors.push(format!("{}: {}", krate.name, message));
+            },
+        }
+    }
+
+    let incomplete_locales = locales.iter().filter(|r| !r.is_complete()).count();
+
+    if show_text {
+        for result in &locales {
+            print_locale_validation(result);
+        }
+        ui::Ui::print_validate_summary(incomplete_locales, locales.len());
+    }
+
+    let report = ValidateJsonReport {
+        crates_discovered: workspace.crates.len(),
+        locales_checked: locales.len(),
+        incomplete_locales,
+        complete: incomplete_locales == 0 && errors.is_empty(),
+        errors,
+        locales,
+    };
+    output.print_json(&report)?;
+
+    if report.complete {
+        Ok(())
+    } else {
+        Err(CliError::Exit(1))
+    }
+}
+
+fn print_locale_validation(result: &LocaleValidation) {
+    if result.is_complete() {
+        ui::Ui::print_locale_complete(&result.locale, &result.crate_name);
+        return;
+    }
+
+    ui::Ui::print_locale_incomplete(
+        &result.locale,
+        &result.crate_name,
+        result.missing_keys.len(),
+        result.extra_keys.len(),
+        result.variable_mismatches.len(),
+        result.dangling_references.len(),
+    );
+    for key in &result.missing_keys {
+        ui::Ui::print_validate_entry("missing", key);
+    }
+    for key in &result.extra_keys {
+        ui::Ui::print_validate_entry("extra", key);
+    }
+    for mismatch in &result.variable_mismatches {
+        let mut details = Vec::new();
+        if !mismatch.missing_variables.is_empty() {
+            details.push(format!(
+                "missing ${}",
+                mismatch.missing_variables.join(", $")
+            ));
+        }
+        if !mismatch.unexpected_variables.is_empty() {
+            details.push(format!(
+                "unexpected ${}",
+                mismatch.unexpected_variables.join(", $")
+            ));
+        }
+        ui::Ui::print_validate_entry(
+            "variables",
+            &format!("{} ({})", mismatch.key, details.join("; ")),
+        );
+    }
+    for dangling in &result.dangling_references {
+        ui::Ui::print_validate_entry(
+            "reference",
+            &format!("{} -> {{ {} }}", dangling.key, dangling.reference),
+        );
+    }
+}
+
+fn relative_validate_message(message: &str, base: &Path) -> String {
+    crate::utils::paths::relative_slash_message(message, base)
+}
+
+/// Run the inventory runner and collect, per crate, the variables each
+/// registered key's Rust type passes.
+fn collect_registered_args(
+    workspace: &WorkspaceCrates,
+) -> Result<HashMap<String, MessageVariables>, CliError> {
+    workspace.require_all_crates_valid()?;
+
+    let runner_workspace = WorkspaceInfo {
+        root_dir: workspace.workspace_info.root_dir.clone(),
+        target_dir: workspace.workspace_info.target_dir.clone(),
+        crates: workspace.valid.clone(),
+    };
+
+    let _runner_lock =
+        crate::generation::acquire_monolithic_runner_lock(&runner_workspace.root_dir)
+            .map_err(|error| CliError::Other(error.to_string()))?;
+    crate::generation::prepare_monolithic_runner_crate(&runner_workspace)
+        .map_err(|error| CliError::Other(error.to_string()))?;
+
+    let temp_store =
+        es_fluent_runner::RunnerMetadataStore::temp_for_workspace(&runner_workspace.root_dir);
+    let executor = MonolithicExecutor::new(&runner_workspace);
+    let mut registered = HashMap::new();
+
+    for krate in &workspace.valid {
+        executor
+            .execute_request(&krate.check_request(), false)
+            .map_err(|error| CliError::Other(error.to_string()))?;
+
+        let inventory = temp_store
+            .read_inventory(&krate.name)
+            .map_err(|error| CliError::Other(error.to_string()))?;
+        registered.insert(krate.name.to_string(), registered_variables(inventory));
+    }
+
+    Ok(registered)
+}
+
+/// Map each registered key to the argument names its Rust type passes.
+fn registered_variables(inventory: es_fluent_runner::InventoryData) -> MessageVariables {
+    inventory
+        .expected_keys
+        .into_iter()
+        .map(|expected| {
+            (
+                expected.key.to_string(),
+                expected
+                    .variables
+                    .iter()
+                    .map(|variable| variable.to_string())
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+/// Compare every non-fallback locale of a crate against its fallback locale.
+///
+/// With `registered`, the fallback messages are also compared with the
+/// arguments their Rust types register.
+fn validate_crate(
+    krate: &CrateInfo,
+    registered: Option<&MessageVariables>,
+) -> Result<Vec<LocaleValidation>> {
+    let ctx = LocaleContext::from_crate(krate, true)?;
+    if !ctx.assets_dir.is_dir() {
+        bail!(
+            "assets_dir for {} is missing or not a directory: {}",
+            krate.name,
+            ctx.assets_dir.display()
+        );
+    }
+
+    let fallback_dir = ctx.locale_dir(&ctx.fallback);
+    if !ctx.has_locale_dir(&ctx.fallback) {
+        bail!(
+            "fallback locale directory '{}' is missing or not a directory for {}: {}",
+            ctx.fallback,
+            krate.name,
+            fallback_dir.display()
+        );
+    }
+
+    let fallback_files = ctx.crate_layout(&ctx.fallback).discover_and_load_files()?;
+    let reference = collect_message_variables(&fallback_files);
+
+    let mut results = Vec::new();
+    let fallback = LocaleValidation {
+        crate_name: krate.name.to_string(),
+        locale: ctx.fallback.clone(),
+        missing_keys: Vec::new(),
+        extra_keys: Vec::new(),
+        variable_mismatches: registered
+            .map(|registered| variable_mismatches(registered, &reference))
+            .unwrap_or_default(),
+        dangling_references: collect_dangling_references(&fallback_files),
+    };
+    if !fallback.is_complete() {
+        results.push(fallback);
+    }
+    for (locale, _) in ctx.iter_non_fallback() {
+        let locale_dir = ctx.locale_root(locale);
+        if !crate::ftl::is_real_locale_directory(&locale_dir) {
+            bail!(
+                "locale directory '{locale}' is not a directory for {}: {}",
+                krate.name,
+                locale_dir.display()
+            );
+        }
+
+        let files = ctx.crate_layout(locale).discover_and_load_files()?;
+        let actual = collect_message_variables(&files);
+        let mut result = compare_locale(krate.name.as_str(), locale, &reference, &actual);
+        result.dangling_references = collect_dangling_references(&files);
+        results.push(result);
+    }
+
+    Ok(results)
+}
+
+/// Collect message variables across files; the first definition of a key wins.
+fn collect_message_variables(files: &[LoadedFtlFile]) -> MessageVariables {
+    let mut messages = MessageVariables::new();
+
+    for file in files {
+        for entry in &file.resource.body {
+            let ast::Entry::Message(msg) = entry else {
+                continue;
+            };
+            messages.entry(msg.id.name.clone()).or_insert_with(|| {
+                crate::ftl::extract_variables_from_message(msg)
+                    .into_iter()
+                    .collect()
+            });
+        }
+    }
+
+    messages
+}
+
+/// Collect references to keys that none of a locale's files define.
+fn collect_dangling_references(files: &[LoadedFtlFile]) -> Vec<DanglingReference> {
+    let known_keys: BTreeSet<String> = files
+        .iter()
+        .flat_map(|file| &file.resource.body)
+        .filter_map(|entry| es_fluent_generate::ftl::entry_key(entry).map(|key| key.into_owned()))
+        .collect();
+
+    files
+        .iter()
+        .flat_map(|file| dangling_references(&file.resource, &known_keys))
+        .map(DanglingReference::from)
+        .collect()
+}
+
+fn compare_locale(
+    crate_name: &str,
+    locale: &str,
+    reference: &MessageVariables,
+    actual: &MessageVariables,
+) -> LocaleValidation {
+    let missing_keys = reference
+        .keys()
+        .filter(|key| !actual.contains_key(*key))
+        .cloned()
+        .collect();
+    let extra_keys = actual
+        .keys()
+        .filter(|key| !reference.contains_key(*key))
+        .cloned()
+        .collect();
+
+    LocaleValidation {
+        crate_name: crate_name.to_string(),
+        locale: locale.to_string(),
+        missing_keys,
+        extra_keys,
+        variable_mismatches: variable_mismatches(reference, actual),
+        dangling_references: Vec::new(),
+    }
+}
+
+/// Report the keys defined on both sides whose variables differ.
+fn variable_mismatches(
+    reference: &MessageVariables,
+    actual: &MessageVariables,
+) -> Vec<VariableMismatch> {
+    reference
+        .iter()
+        .filter_map(|(key, expected)| {
+            let found = actual.get(key)?;
+            let drift = VariableDrift::new(expected.iter().cloned(), found.iter().cloned());
+            (!drift.is_empty()).then(|| VariableMismatch {
+                key: key.clone(),
+                missing_variables: drift.unreferenced.into_iter().collect(),
+                unexpected_variables: drift.unknown.into_iter().collect(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::common::WorkspaceArgs;
+
+    fn workspace_args(path: &Path) -> WorkspaceArgs {
+        WorkspaceArgs {
+            path: Some(path.to_path_buf()),
+            package: None,
+        }
+    }
+
+    fn messages(entries: &[(&str, &[&str])]) -> MessageVariables {
+        entries
+            .iter()
+            .map(|(key, variables)| {
+                (
+                    (*key).to_string(),
+                    variables.iter().map(|v| (*v).to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn compare_locale_reports_missing_extra_and_variable_mismatches() {
+        let reference = messages(&[
+            ("hello", &["name"]),
+            ("goodbye", &[]),
+            ("items", &["count"]),
+        ]);
+        let actual = messages(&[("hello", &["nom"]), ("items", &["count"]), ("stale", &[])]);
+
+        let result = compare_locale("test-app", "fr", &reference, &actual);
+
+        assert!(!result.is_complete());
+        assert_eq!(result.missing_keys, ["goodbye"]);
+        assert_eq!(result.extra_keys, ["stale"]);
+        assert_eq!(
+            result.variable_mismatches,
+            [VariableMismatch {
+                key: "hello".to_string(),
+                missing_variables: vec!["name".to_string()],
+                unexpected_variables: vec!["nom".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn compare_locale_is_complete_for_matching_messages() {
+        let reference = messages(&[("hello", &["name"])]);
+
+        assert!(compare_locale("test-app", "fr", &reference, &reference).is_complete());
+    }
+
+    #[test]
+    fn validate_crate_compares_each_non_fallback_locale() {
+        let temp = crate::test_fixtures::create_workspace_with_locales(&[
+            ("en", "hello = Hello { $name }\ngoodbye = Bye\n"),
+            ("es", "hello = Hola { $name }\ngoodbye = Adiós\n"),
+            ("fr", "hello = Bonjour { $nom }\nstale = Vieux\n"),
+        ]);
+        let workspace = WorkspaceCrates::discover(workspace_args(temp.path())).expect("discover");
+
+        let results = validate_crate(&workspace.crates[0], None).expect("validate");
+        let locales: Vec<_> = results.iter().map(|r| r.locale.as_str()).collect();
+
+        assert_eq!(locales, ["es", "fr"]);
+        assert!(results[0].is_complete());
+        assert_eq!(results[1].missing_keys, ["goodbye"]);
+        assert_eq!(results[1].extra_keys, ["stale"]);
+        assert_eq!(results[1].variable_mismatches.len(), 1);
+    }
+
+    #[test]
+    fn validate_crate_reports_dangling_references_in_every_locale() {
+        let temp = crate::test_fixtures::create_workspace_with_locales(&[
+            ("en", "hello = Hello\nwelcome = { hello }, { greeting }\n"),
+            ("es", "hello = Hola\nwelcome = { hello }\n"),
+            (
+                "fr",
+                "-brand = Acme\nhello = Bonjour\nwelcome = { -brand } { -produit }\n",
+            ),
+        ]);
+        let workspace = WorkspaceCrates::discover(workspace_args(temp.path())).expect("discover");
+
+        let results = validate_crate(&workspace.crates[0], None).expect("validate");
+        let locales: Vec<_> = results.iter().map(|r| r.locale.as_str()).collect();
+
+        assert_eq!(locales, ["en", "es", "fr"]);
+        assert_eq!(
+            results[0].dangling_references,
+            [DanglingReference {
+                key: "welcome".to_string(),
+                reference: "greeting".to_string(),
+            }]
+        );
+        assert!(!results[0].is_complete());
+        assert!(results[1].is_complete());
+        assert_eq!(
+            results[2].dangling_references,
+            [DanglingReference {
+                key: "welcome".to_string(),
+                reference: "-produit".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn validate_crate_compares_fallback_with_registered_args() {
+        let temp = crate::test_fixtures::create_workspace_with_locales(&[
+            ("en", "hello = Hello { $nam }\ngoodbye = Bye\n"),
+            ("es", "hello = Hola { $nam }\ngoodbye = Adiós\n"),
+        ]);
+        let workspace = WorkspaceCrates::discover(workspace_args(temp.path())).expect("discover");
+        let registered = messages(&[("hello", &["name"]), ("goodbye", &[])]);
+
+        let results = validate_crate(&workspace.crates[0], Some(&registered)).expect("validate");
+        let locales: Vec<_> = results.iter().map(|r| r.locale.as_str()).collect();
+
+        assert_eq!(locales, ["en", "es"]);
+        assert_eq!(
+            results[0].variable_mismatches,
+            [VariableMismatch {
+                key: "hello".to_string(),
+                missing_variables: vec!["name".to_string()],
+                unexpected_variables: vec!["nam".to_string()],
+            }]
+        );
+        assert!(results[1].is_complete());
+    }
+
+    #[test]
+    fn run_validate_exits_non_zero_for_incomplete_locale() {
+        let temp = crate::test_fixtures::create_workspace_with_locales(&[
+            ("en", "hello = Hello\n"),
+            ("es", "hello = Hola\n"),
+        ]);
+        assert!(
+            run_validate(ValidateArgs {
+                workspace: workspace_args(temp.path()),
+                registered_args: false,
+                output: OutputFormat::Json,
+            })
+            .is_ok()
+        );
+
+        crate::test_fixtures::write_file(&temp.path().join("i18n/es/test-app.ftl"), "");
+        let result = run_validate(ValidateArgs {
+            workspace: workspace_args(temp.path()),
+            registered_args: false,
+            output: OutputFormat::Text,
+        });
+        assert!(matches!(result, Err(CliError::Exit(1))));
+    }
+
+    #[test]
+    fn validate_crate_rejects_fallback_parse_errors() {
+        let temp = crate::test_fixtures::create_workspace_with_locales(&[
+            ("en", "hello = {\n"),
+            ("es", "hello = Hola\n"),
+        ]);
+        let workspace = WorkspaceCrates::discover(workspace_args(temp.path())).expect("discover");
+
+        let error = validate_crate(&workspace.crates[0], None).expect_err("parse error");
+
+        assert!(error.to_string().contains("Fluent parse errors"));
+    }
+}
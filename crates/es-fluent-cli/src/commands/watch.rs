@@ -4,6 +4,7 @@ use super::common::{WorkspaceArgs, WorkspaceCrates, validate_generation_paths};
 use crate::core::{CliError, FluentParseMode};
 use crate::utils::ui;
 use clap::Parser;
+use std::time::Duration;
 
 /// Arguments for the watch command.
 #[derive(Parser)]
@@ -14,6 +15,10 @@ pub struct WatchArgs {
     /// Parse mode for repeated FTL generation; aggressive overwrites existing translations.
     #[arg(short, long, value_enum, default_value_t = FluentParseMode::default())]
     pub mode: FluentParseMode,
+
+    /// Milliseconds to wait for file events to settle before regenerating affected crates.
+    #[arg(long, value_name = "MS", default_value_t = crate::tui::DEFAULT_DEBOUNCE_MS)]
+    pub debounce_ms: u64,
 }
 
 /// Run the watch command.
@@ -26,8 +31,13 @@ pub fn run_watch(args: WatchArgs) -> Result<(), CliError> {
     workspace.require_all_crates_valid()?;
     validate_generation_paths(&workspace.valid, true)?;
 
-    crate::tui::watch_all(&workspace.crates, &workspace.workspace_info, &args.mode)
-        .map_err(CliError::from)
+    crate::tui::watch_all(
+        &workspace.crates,
+        &workspace.workspace_info,
+        &args.mode,
+        Duration::from_millis(args.debounce_ms),
+    )
+    .map_err(CliError::from)
 }
 
 #[cfg(test)]
@@ -45,6 +55,7 @@ mod tests {
                 package: Some("missing-crate".to_string()),
             },
             mode: FluentParseMode::default(),
+            debounce_ms: crate::tui::DEFAULT_DEBOUNCE_MS,
         });
 
         assert!(
@@ -62,6 +73,7 @@ mod tests {
                 package: None,
             },
             mode: FluentParseMode::default(),
+            debounce_ms: crate::tui::DEFAULT_DEBOUNCE_MS,
         });
 
         assert!(
@@ -79,6 +91,7 @@ mod tests {
                 package: None,
             },
             mode: FluentParseMode::default(),
+            debounce_ms: crate::tui::DEFAULT_DEBOUNCE_MS,
         });
 
         assert!(matches!(result, Err(CliError::Other(message)) if message.contains("'bin-app'")));
@@ -92,6 +105,7 @@ mod tests {
                 package: None,
             },
             mode: FluentParseMode::default(),
+            debounce_ms: crate::tui::DEFAULT_DEBOUNCE_MS,
         });
 
         assert!(result.is_err());
@@ -109,6 +123,7 @@ mod tests {
                 package: None,
             },
             mode: FluentParseMode::default(),
+            debounce_ms: crate::tui::DEFAULT_DEBOUNCE_MS,
         });
 
         assert!(
@@ -132,6 +147,7 @@ mod tests {
                 package: None,
             },
             mode: FluentParseMode::default(),
+            debounce_ms: crate::tui::DEFAULT_DEBOUNCE_MS,
         });
 
         assert!(
@@ -158,6 +174,7 @@ mod tests {
                 package: None,
             },
             mode: FluentParseMode::default(),
+            debounce_ms: crate::tui::DEFAULT_DEBOUNCE_MS,
         });
 
         assert!(result.is_err());
@@ -1,4 +1,4 @@
-use es_fluent_runner::PackageName;
+use es_fluent_runner::{GenerateReport, PackageName};
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
@@ -106,6 +106,8 @@ pub struct GenerateResult {
     pub output: Option<String>,
     /// Whether any files were changed.
     pub changed: bool,
+    /// Keys and groups that were, or in dry-run mode would be, added or removed.
+    pub report: GenerateReport,
 }
 
 impl GenerateResult {
@@ -124,9 +126,16 @@ impl GenerateResult {
             error: None,
             output,
             changed,
+            report: GenerateReport::default(),
         }
     }
 
+    /// Attach the key-level report read back from the runner.
+    pub fn with_report(mut self, report: GenerateReport) -> Self {
+        self.report = report;
+        self
+    }
+
     /// Create a new error result.
     pub fn failure(name: PackageName, duration: Duration, error: String) -> Self {
         Self {
@@ -136,6 +145,7 @@ impl GenerateResult {
             error: Some(error),
             output: None,
             changed: false,
+            report: GenerateReport::default(),
         }
     }
 }
@@ -1,25 +1,27 @@
 //! FTL file layout and discovery utilities.
 use anyhow::{Result, anyhow};
+use es_fluent_toml::AssetsLayout;
 use fluent_syntax::ast;
 use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-/// Build the path to the main FTL file for a crate in a locale.
-pub fn main_ftl_path(assets_dir: &Path, locale: &str, crate_name: &str) -> PathBuf {
-    assets_dir.join(locale).join(format!("{}.ftl", crate_name))
-}
-
 /// Build a path to the locale output directory.
 pub fn locale_output_dir(assets_dir: &Path, locale: &str) -> PathBuf {
     assets_dir.join(locale)
 }
 
 /// Shared file layout for a crate within a locale directory.
+///
+/// `locale_dir` is always the language-first `{assets_dir}/{locale}` path;
+/// the assets layout decides where each file below it actually lives.
 #[derive(Clone, Debug)]
 pub struct CrateFtlLayout {
     locale_dir: PathBuf,
     crate_name: String,
+    /// Base file name without `.ftl`, from the config's `ftl_file_name`.
+    file_stem: String,
+    layout: AssetsLayout,
 }
 
 impl CrateFtlLayout {
@@ -33,22 +35,116 @@ impl CrateFtlLayout {
         Self {
             locale_dir,
             crate_name: crate_name.to_string(),
+            file_stem: crate_name.to_string(),
+            layout: AssetsLayout::LanguageFirst,
         }
     }
 
+    /// Use `file_stem` instead of the crate name for the main file.
+    pub fn with_file_stem(mut self, file_stem: impl Into<String>) -> Self {
+        self.file_stem = file_stem.into();
+        self
+    }
+
+    /// Place files according to `layout` instead of the language-first default.
+    pub fn with_layout(mut self, layout: AssetsLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Returns the path of a locale-relative file, such as `my-crate.ftl` or
+    /// `my-crate/ui.ftl`, for this locale.
+    pub fn file_path(&self, relative_path: impl AsRef<Path>) -> PathBuf {
+        self.layout
+            .resolve_in_locale_dir(&self.locale_dir, relative_path)
+    }
+
     /// Returns the main file for this crate in the locale.
     pub fn main_file(&self) -> PathBuf {
-        self.locale_dir.join(format!("{}.ftl", self.crate_name))
+        self.file_path(self.main_file_name())
+    }
+
+    fn main_file_name(&self) -> String {
+        format!("{}.ftl", self.file_stem)
     }
 
     /// Returns the namespaced crate directory for this locale.
     pub fn crate_dir(&self) -> PathBuf {
-        self.locale_dir.join(&self.crate_name)
+        self.file_path(&self.crate_name)
+    }
+
+    /// Returns the directory that must exist for this locale to have files:
+    /// `{assets_dir}/{locale}`, or the main file's domain directory with the
+    /// domain-first layout.
+    pub fn locale_root(&self) -> PathBuf {
+        match self.layout {
+            AssetsLayout::LanguageFirst => self.locale_dir.clone(),
+            AssetsLayout::DomainFirst => self
+                .main_file()
+                .parent()
+                .map_or_else(|| self.locale_dir.clone(), Path::to_path_buf),
+        }
+    }
+
+    /// Returns whether this locale exists for the crate: `{assets_dir}/{locale}`
+    /// is present, or with the domain-first layout the crate's own
+    /// `{locale}.ftl` or `{locale}/` is. Symlinks and other non-files count as
+    /// present so callers can reject them instead of skipping them.
+    pub fn has_locale(&self) -> bool {
+        let present = |path: PathBuf| fs::symlink_metadata(path).is_ok();
+        match self.layout {
+            AssetsLayout::LanguageFirst => present(self.locale_dir.clone()),
+            AssetsLayout::DomainFirst => present(self.main_file()) || present(self.crate_dir()),
+        }
+    }
+
+    /// Lists the namespaced files below the crate directory, with paths
+    /// relative to the locale (`{crate}/...`).
+    fn discover_namespace_files(&self, crate_dir: &Path) -> Result<Vec<FtlFileInfo>> {
+        Ok(discover_nested_ftl_files(crate_dir, crate_dir)?
+            .into_iter()
+            .map(|file| {
+                FtlFileInfo::new(
+                    file.abs_path,
+                    Path::new(&self.crate_name).join(file.relative_path),
+                )
+            })
+            .collect())
     }
 
-    /// Discover all FTL files for this crate in the locale.
+    /// Discover all FTL files for this crate in the locale, including main
+    /// and namespaced files.
     pub fn discover_files(&self) -> Result<Vec<FtlFileInfo>> {
-        discover_crate_ftl_files_in_locale_dir(&self.locale_dir, &self.crate_name)
+        let locale_root = self.locale_root();
+        if locale_root.exists() {
+            ensure_ftl_directory_is_real(&locale_root)?;
+        }
+
+        let mut files = Vec::new();
+
+        let main_file = self.main_file();
+        if main_file.exists() {
+            ensure_ftl_path_is_file(&main_file)?;
+            files.push(FtlFileInfo::new(
+                main_file,
+                PathBuf::from(self.main_file_name()),
+            ));
+        }
+
+        let crate_subdir = self.crate_dir();
+        if crate_subdir.exists() {
+            ensure_ftl_directory_is_real(&crate_subdir)?;
+            if !crate_subdir.is_dir() {
+                return Err(anyhow!(
+                    "Expected crate namespace path to be a directory: {}",
+                    crate_subdir.display()
+                ));
+            }
+
+            files.extend(self.discover_namespace_files(&crate_subdir)?);
+        }
+
+        Ok(files)
     }
 
     /// Discover and load all FTL files for this crate in the locale.
@@ -61,11 +157,10 @@ impl CrateFtlLayout {
         &self,
         fallback: &CrateFtlLayout,
     ) -> Result<HashSet<PathBuf>> {
-        if self.locale_dir.exists() {
-            ensure_ftl_directory_is_real(&self.locale_dir)?;
-        }
-        if fallback.locale_dir.exists() {
-            ensure_ftl_directory_is_real(&fallback.locale_dir)?;
+        for locale_root in [self.locale_root(), fallback.locale_root()] {
+            if locale_root.exists() {
+                ensure_ftl_directory_is_real(&locale_root)?;
+            }
         }
 
         let mut expected = HashSet::new();
@@ -86,10 +181,8 @@ impl CrateFtlLayout {
                 ));
             }
 
-            for fallback_file in
-                discover_nested_ftl_files(&fallback_crate_dir, &fallback.locale_dir)?
-            {
-                expected.insert(self.locale_dir.join(fallback_file.relative_path));
+            for fallback_file in fallback.discover_namespace_files(&fallback_crate_dir)? {
+                expected.insert(self.file_path(fallback_file.relative_path));
             }
         }
 
@@ -129,58 +222,71 @@ pub struct LoadedFtlFile {
     pub keys: HashSet<String>,
 }
 
-/// Discover every FTL file under a locale directory.
-pub fn discover_locale_ftl_files(locale_dir: &Path) -> Result<Vec<FtlFileInfo>> {
-    discover_nested_ftl_files(locale_dir, locale_dir)
-}
-
-/// Discover all FTL files for a given locale and crate, including main and namespaced files.
-#[cfg(test)]
-pub fn discover_ftl_files(
-    assets_dir: &Path,
-    locale: &str,
-    crate_name: &str,
-) -> Result<Vec<FtlFileInfo>> {
-    CrateFtlLayout::from_assets_dir(assets_dir, locale, crate_name).discover_files()
-}
-
-/// Discover all FTL files for a crate within a concrete locale directory.
-pub fn discover_crate_ftl_files_in_locale_dir(
+/// Discover every FTL file of a locale, given its language-first
+/// `{assets_dir}/{locale}` directory.
+///
+/// With the domain-first layout the files are gathered from every
+/// `{assets_dir}/{domain}/` directory instead. Relative paths are
+/// locale-relative (`{domain}.ftl`, `{domain}/...`) under either layout.
+pub fn discover_locale_ftl_files(
     locale_dir: &Path,
-    crate_name: &str,
+    layout: AssetsLayout,
 ) -> Result<Vec<FtlFileInfo>> {
-    if locale_dir.exists() {
-        ensure_ftl_directory_is_real(locale_dir)?;
-    }
+    let (AssetsLayout::DomainFirst, Some(assets_dir), Some(locale)) =
+        (layout, locale_dir.parent(), locale_dir.file_name())
+    else {
+        return discover_nested_ftl_files(locale_dir, locale_dir);
+    };
 
-    let layout = CrateFtlLayout::new(locale_dir.to_path_buf(), crate_name);
     let mut files = Vec::new();
-
-    let main_file = layout.main_file();
-    if main_file.exists() {
-        ensure_ftl_path_is_file(&main_file)?;
-        files.push(FtlFileInfo::new(
-            main_file,
-            PathBuf::from(format!("{}.ftl", crate_name)),
-        ));
+    if !assets_dir.is_dir() {
+        return Ok(files);
     }
 
-    let crate_subdir = layout.crate_dir();
-    if crate_subdir.exists() {
-        ensure_ftl_directory_is_real(&crate_subdir)?;
-        if !crate_subdir.is_dir() {
-            return Err(anyhow!(
-                "Expected crate namespace path to be a directory: {}",
-                crate_subdir.display()
-            ));
+    let mut main_file_name = locale.to_os_string();
+    main_file_name.push(".ftl");
+    for entry in fs::read_dir(assets_dir)? {
+        let entry = entry?;
+        let domain_dir = entry.path();
+        if entry.file_type()?.is_symlink() && domain_dir.is_dir() {
+            ensure_ftl_directory_is_real(&domain_dir)?;
+        }
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+
+        let domain = entry.file_name();
+        let main_file = domain_dir.join(&main_file_name);
+        if fs::symlink_metadata(&main_file).is_ok() {
+            ensure_ftl_path_is_file(&main_file)?;
+            let mut relative_path = domain.clone();
+            relative_path.push(".ftl");
+            files.push(FtlFileInfo::new(main_file, PathBuf::from(relative_path)));
         }
 
-        files.extend(discover_nested_ftl_files(&crate_subdir, locale_dir)?);
+        let namespace_dir = domain_dir.join(locale);
+        for file in discover_nested_ftl_files(&namespace_dir, &namespace_dir)? {
+            files.push(FtlFileInfo::new(
+                file.abs_path,
+                Path::new(&domain).join(file.relative_path),
+            ));
+        }
     }
 
+    files.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
     Ok(files)
 }
 
+/// Discover all FTL files for a given locale and crate, including main and namespaced files.
+#[cfg(test)]
+pub fn discover_ftl_files(
+    assets_dir: &Path,
+    locale: &str,
+    crate_name: &str,
+) -> Result<Vec<FtlFileInfo>> {
+    CrateFtlLayout::from_assets_dir(assets_dir, locale, crate_name).discover_files()
+}
+
 /// Recursively discover FTL files under `dir`, returning paths relative to `base_dir`.
 pub fn discover_nested_ftl_files(dir: &Path, base_dir: &Path) -> Result<Vec<FtlFileInfo>> {
     let mut files = Vec::new();
@@ -269,6 +375,7 @@ pub fn load_ftl_files(files: Vec<FtlFileInfo>) -> Result<Vec<LoadedFtlFile>> {
 }
 
 /// Discover and load all FTL files for a locale and crate.
+#[cfg(test)]
 pub fn discover_and_load_ftl_files(
     assets_dir: &Path,
     locale: &str,
@@ -463,7 +570,7 @@ mod tests {
         fs::write(locale_dir.join("app.ftl"), "hello = Hello").unwrap();
         fs::write(locale_dir.join("app/forms/input.ftl"), "input = Input").unwrap();
 
-        let files = discover_locale_ftl_files(&locale_dir).unwrap();
+        let files = discover_locale_ftl_files(&locale_dir, AssetsLayout::LanguageFirst).unwrap();
         assert_eq!(files.len(), 2);
         assert!(
             files
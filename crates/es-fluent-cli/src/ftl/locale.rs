@@ -4,8 +4,9 @@
 //! over locale directories with `--all` flag support.
 
 use crate::core::CrateInfo;
+use crate::ftl::CrateFtlLayout;
 use anyhow::Result;
-use es_fluent_toml::ResolvedI18nLayout;
+use es_fluent_toml::{AssetsLayout, ResolvedI18nLayout};
 use fs_err as fs;
 use std::path::{Path, PathBuf};
 
@@ -67,8 +68,12 @@ pub struct LocaleContext {
     pub locales: Vec<String>,
     /// The crate name (for constructing FTL file paths).
     pub crate_name: String,
+    /// The crate's main FTL file name without `.ftl`, from `ftl_file_name`.
+    pub ftl_file_stem: String,
     /// Whether fallback-copy warnings are enabled by this crate's i18n.toml.
     pub check_fallback_copies: bool,
+    /// How locale files are arranged below `assets_dir`.
+    pub layout: AssetsLayout,
 }
 
 impl LocaleContext {
@@ -97,20 +102,57 @@ impl LocaleContext {
             fallback,
             locales,
             crate_name: krate.name.to_string(),
+            ftl_file_stem: layout.config.ftl_file_stem(krate.name.as_str()),
             check_fallback_copies: layout.config.check_fallback_copies,
+            layout: layout.config.layout,
         })
     }
 
     /// Get the FTL file path for a specific locale.
     pub fn ftl_path(&self, locale: &str) -> PathBuf {
-        self.assets_dir
-            .join(locale)
-            .join(format!("{}.ftl", self.crate_name))
+        self.crate_layout(locale).main_file()
+    }
+
+    /// Get the crate's FTL file layout in a locale.
+    pub fn crate_layout(&self, locale: &str) -> CrateFtlLayout {
+        CrateFtlLayout::from_assets_dir(&self.assets_dir, locale, &self.crate_name)
+            .with_file_stem(&self.ftl_file_stem)
+            .with_layout(self.layout)
     }
 
     /// Get the locale directory path.
+    ///
+    /// With the domain-first layout this is the crate's
+    /// `{assets_dir}/{crate}/{locale}` namespace directory, which sits next
+    /// to its `{locale}.ftl` and may not exist when the locale has no
+    /// namespaced files.
     pub fn locale_dir(&self, locale: &str) -> PathBuf {
-        self.assets_dir.join(locale)
+        let layout = self.crate_layout(locale);
+        match self.layout {
+            AssetsLayout::LanguageFirst => layout.locale_root(),
+            AssetsLayout::DomainFirst => layout.crate_dir(),
+        }
+    }
+
+    /// Returns whether `locale` exists for this crate. With the domain-first
+    /// layout the crate's `{locale}.ftl` or `{locale}/` must exist, since the
+    /// domain directory holding them is shared by every locale.
+    pub fn has_locale(&self, locale: &str) -> bool {
+        self.crate_layout(locale).has_locale()
+    }
+
+    /// Returns whether `locale` exists for this crate below a real
+    /// directory: `{assets_dir}/{locale}`, or with the domain-first layout
+    /// the crate's domain directory.
+    pub fn has_locale_dir(&self, locale: &str) -> bool {
+        self.has_locale(locale) && is_real_locale_directory(&self.locale_root(locale))
+    }
+
+    /// Get the directory that must exist for `locale` to have files:
+    /// `{assets_dir}/{locale}`, or the crate's domain directory with the
+    /// domain-first layout.
+    pub fn locale_root(&self, locale: &str) -> PathBuf {
+        self.crate_layout(locale).locale_root()
     }
 
     /// Iterate over locales, yielding (locale, ftl_path) pairs.
@@ -119,8 +161,7 @@ impl LocaleContext {
     #[cfg(test)]
     pub fn iter(&self) -> impl Iterator<Item = (&str, PathBuf)> {
         self.locales.iter().filter_map(|locale| {
-            let locale_dir = self.locale_dir(locale);
-            if locale_dir.exists() {
+            if self.has_locale(locale) {
                 Some((locale.as_str(), self.ftl_path(locale)))
             } else {
                 None
@@ -136,8 +177,7 @@ impl LocaleContext {
             if locale == &self.fallback {
                 return None;
             }
-            let locale_dir = self.locale_dir(locale);
-            if locale_dir.exists() {
+            if self.has_locale(locale) {
                 Some((locale.as_str(), self.ftl_path(locale)))
             } else {
                 None
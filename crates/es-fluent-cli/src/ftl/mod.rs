@@ -7,10 +7,7 @@ mod files;
 mod locale;
 mod parse;
 
-pub use files::{
-    CrateFtlLayout, LoadedFtlFile, discover_and_load_ftl_files,
-    discover_crate_ftl_files_in_locale_dir, discover_locale_ftl_files, main_ftl_path,
-};
+pub use files::{CrateFtlLayout, LoadedFtlFile, discover_locale_ftl_files};
 pub use locale::LocaleContext;
 pub(crate) use locale::{is_real_locale_directory, locale_named_non_directory_paths};
 pub use parse::{
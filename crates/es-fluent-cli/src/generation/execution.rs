@@ -1,12 +1,14 @@
 use crate::core::{CrateInfo, GenerateResult, GenerationAction, WorkspaceInfo};
+use crate::ftl::CrateFtlLayout;
 use anyhow::{Result, bail};
-use es_fluent_runner::{I18nTomlPath, RunnerMetadataStore, RunnerRequest};
+use es_fluent_runner::{GenerateReport, I18nTomlPath, RunnerMetadataStore, RunnerRequest};
 use std::time::Instant;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RunnerExecution {
     pub output: String,
     pub changed: bool,
+    pub report: GenerateReport,
 }
 
 impl GenerationAction {
@@ -42,6 +44,23 @@ impl CrateInfo {
         }
     }
 
+    /// The crate's main FTL file name without `.ftl`, from its `i18n.toml`.
+    pub(crate) fn ftl_file_stem(&self) -> Result<String> {
+        let config = es_fluent_toml::I18nConfig::read_from_path(&self.i18n_config_path)?;
+        Ok(config.ftl_file_stem(self.name.as_str()))
+    }
+
+    /// The crate's FTL files in `ftl_output_dir`, named and placed as its
+    /// `i18n.toml` configures them.
+    pub(crate) fn ftl_layout(&self) -> Result<CrateFtlLayout> {
+        let config = es_fluent_toml::I18nConfig::read_from_path(&self.i18n_config_path)?;
+        Ok(
+            CrateFtlLayout::new(self.ftl_output_dir.to_path_buf(), self.name.as_str())
+                .with_file_stem(config.ftl_file_stem(self.name.as_str()))
+                .with_layout(config.layout),
+        )
+    }
+
     fn ensure_inventory_library_target(&self) -> Result<()> {
         if !self.has_lib_rs {
             bail!(
@@ -73,15 +92,20 @@ impl<'a> MonolithicExecutor<'a> {
         force_run: bool,
     ) -> Result<RunnerExecution> {
         let output = super::runner::run_monolithic(self.workspace, request, force_run)?;
-        let changed = match request {
+        let (changed, report) = match request {
             RunnerRequest::Generate { crate_name, .. }
-            | RunnerRequest::Clean { crate_name, .. } => {
-                self.metadata_store.result_changed(crate_name)
-            },
-            RunnerRequest::Check { .. } => false,
+            | RunnerRequest::Clean { crate_name, .. } => (
+                self.metadata_store.result_changed(crate_name),
+                self.metadata_store.result_report(crate_name),
+            ),
+            RunnerRequest::Check { .. } => (false, GenerateReport::default()),
         };
 
-        Ok(RunnerExecution { output, changed })
+        Ok(RunnerExecution {
+            output,
+            changed,
+            report,
+        })
     }
 
     pub(crate) fn execute_generation_action(
@@ -98,10 +122,13 @@ impl<'a> MonolithicExecutor<'a> {
             Ok(execution) => GenerateResult::success(
                 krate.name.clone(),
                 duration,
-                crate::utils::count_ftl_resources(&krate.ftl_output_dir, krate.name.as_str()),
+                krate
+                    .ftl_layout()
+                    .map_or(0, |files| crate::utils::count_ftl_resources(&files)),
                 normalize_output(execution.output),
                 execution.changed,
-            ),
+            )
+            .with_report(execution.report),
             Err(error) => {
                 GenerateResult::failure(krate.name.clone(), duration, format!("{error:#}"))
             },
@@ -2,8 +2,8 @@
 
 use clap::{Parser, Subcommand};
 use commands::{
-    AddLocaleArgs, CheckArgs, CleanArgs, FormatArgs, GenerateArgs, StatusArgs, SyncArgs, TreeArgs,
-    WatchArgs,
+    AddLocaleArgs, CheckArgs, CleanArgs, DumpArgs, FormatArgs, GenerateArgs, RenameArgs,
+    StatusArgs, SyncArgs, TreeArgs, ValidateArgs, WatchArgs,
 };
 use miette::Result as MietteResult;
 
@@ -67,8 +67,17 @@ enum Commands {
     /// Create locale directories and seed them from the fallback language
     AddLocale(AddLocaleArgs),
 
+    /// Move a renamed type's translations to its new keys in every locale
+    Rename(RenameArgs),
+
     /// Display a tree view of FTL items for each crate
     Tree(TreeArgs),
+
+    /// Compare every locale against the fallback locale's keys and variables
+    Validate(ValidateArgs),
+
+    /// Print the Rust-derived key catalog as versioned JSON for external tools
+    Dump(DumpArgs),
 }
 
 #[doc(hidden)]
@@ -146,7 +155,10 @@ fn dispatch(command: Commands) -> Result<(), CliError> {
         Commands::Status(args) => commands::run_status(args),
         Commands::Sync(args) => commands::run_sync(args),
         Commands::AddLocale(args) => commands::run_add_locale(args),
+        Commands::Rename(args) => commands::run_rename(args),
         Commands::Tree(args) => commands::run_tree(args),
+        Commands::Validate(args) => commands::run_validate(args),
+        Commands::Dump(args) => commands::run_dump(args),
     }
 }
 
@@ -175,7 +187,10 @@ mod tests {
         "status",
         "sync",
         "add-locale",
+        "rename",
         "tree",
+        "validate",
+        "dump",
     ];
 
     fn missing_package_workspace_args(path: &std::path::Path) -> WorkspaceArgs {
@@ -205,7 +220,10 @@ mod tests {
             Commands::Status(_) => "status",
             Commands::Sync(_) => "sync",
             Commands::AddLocale(_) => "add-locale",
+            Commands::Rename(_) => "rename",
             Commands::Tree(_) => "tree",
+            Commands::Validate(_) => "validate",
+            Commands::Dump(_) => "dump",
         }
     }
 
@@ -308,7 +326,10 @@ mod tests {
             (&["status"], "status"),
             (&["sync", "--all"], "sync"),
             (&["add-locale", "fr-FR"], "add-locale"),
+            (&["rename", "--from", "Old", "--to", "New"], "rename"),
             (&["tree"], "tree"),
+            (&["validate"], "validate"),
+            (&["dump"], "dump"),
         ];
 
         let parsed = cases
@@ -373,6 +394,31 @@ mod tests {
         assert_eq!(args.locale, ["es", " fr-FR"]);
     }
 
+    #[test]
+    fn cli_parses_rename_key_case() {
+        let cli = Cli::try_parse_from([
+            "cargo",
+            "es-fluent",
+            "rename",
+            "--from",
+            "LoginError",
+            "--to",
+            "SignInError",
+            "--case",
+            "kebab",
+        ])
+        .expect("parse");
+        let CargoCommand::EsFluent { command, e2e } = cli.command;
+        assert!(!e2e);
+
+        let Commands::Rename(args) = command else {
+            panic!("expected rename command");
+        };
+        assert_eq!(args.from, "LoginError");
+        assert_eq!(args.to, "SignInError");
+        assert_eq!(args.case, Some(es_fluent_shared::namer::KeyCase::Kebab));
+    }
+
     #[test]
     fn cli_parses_status_force_run_flag() {
         let cli =
@@ -386,6 +432,22 @@ mod tests {
         assert!(args.force_run);
     }
 
+    #[test]
+    fn cli_parses_watch_debounce_interval() {
+        let parse_watch = |args: &[&str]| {
+            let cli = Cli::try_parse_from(["cargo", "es-fluent", "watch"].iter().chain(args))
+                .expect("parse");
+            let CargoCommand::EsFluent { command, .. } = cli.command;
+            let Commands::Watch(args) = command else {
+                panic!("expected watch command");
+            };
+            args.debounce_ms
+        };
+
+        assert_eq!(parse_watch(&[]), crate::tui::DEFAULT_DEBOUNCE_MS);
+        assert_eq!(parse_watch(&["--debounce-ms", "50"]), 50);
+    }
+
     #[test]
     fn cli_rejects_generate_only_flags_for_watch() {
         for flag in ["--dry-run", "--force-run"] {
@@ -412,12 +474,14 @@ mod tests {
                 mode: FluentParseMode::default(),
                 dry_run: true,
                 force_run: false,
+                error_on_orphans: false,
             }))
             .is_ok()
         );
         let watch_result = dispatch(Commands::Watch(WatchArgs {
             workspace: missing_workspace.clone(),
             mode: FluentParseMode::default(),
+            debounce_ms: crate::tui::DEFAULT_DEBOUNCE_MS,
         }));
         assert!(watch_result.is_err());
 
@@ -464,6 +528,7 @@ mod tests {
             mode: FluentParseMode::default(),
             dry_run: false,
             force_run: false,
+            error_on_orphans: false,
         }));
         assert!(generate_result.is_err());
 
@@ -506,8 +571,19 @@ mod tests {
             .is_ok()
         );
 
+        let rename_result = dispatch(Commands::Rename(RenameArgs {
+            workspace: missing_workspace.clone(),
+            from: "LoginError".to_string(),
+            to: "SignInError".to_string(),
+            case: None,
+            dry_run: true,
+        }));
+        assert!(
+            matches!(rename_result, Err(CliError::Other(message)) if message.contains("missing-package"))
+        );
+
         let tree_result = dispatch(Commands::Tree(TreeArgs {
-            workspace: missing_workspace,
+            workspace: missing_workspace.clone(),
             all: false,
             attributes: true,
             variables: true,
@@ -515,6 +591,19 @@ mod tests {
             output: OutputFormat::Text,
         }));
         assert!(matches!(tree_result, Err(CliError::Exit(1))));
+
+        let validate_result = dispatch(Commands::Validate(ValidateArgs {
+            workspace: missing_workspace.clone(),
+            registered_args: false,
+            output: OutputFormat::Json,
+        }));
+        assert!(matches!(validate_result, Err(CliError::Exit(1))));
+
+        let dump_result = dispatch(Commands::Dump(DumpArgs {
+            workspace: missing_workspace,
+            force_run: false,
+        }));
+        assert!(dump_result.is_err());
     }
 
     #[test]
@@ -529,6 +618,7 @@ mod tests {
             mode: FluentParseMode::default(),
             dry_run: false,
             force_run: false,
+            error_on_orphans: false,
         }));
 
         assert!(result.is_err());
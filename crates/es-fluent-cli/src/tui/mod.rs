@@ -7,4 +7,4 @@ mod watcher;
 #[allow(unused_imports)]
 pub use app::{TuiApp, draw, poll_quit_event};
 pub use message::Message;
-pub use watcher::watch_all;
+pub use watcher::{DEFAULT_DEBOUNCE_MS, watch_all};
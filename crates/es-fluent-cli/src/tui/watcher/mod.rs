@@ -17,11 +17,18 @@ use notify_debouncer_full::{DebounceEventResult, RecommendedCache};
 use ratatui::{Terminal, backend::Backend};
 use std::time::Duration;
 
+/// Default time to wait for file events to settle before regenerating.
+pub const DEFAULT_DEBOUNCE_MS: u64 = 300;
+
 /// Watch for changes and regenerate FTL files for all discovered crates.
+///
+/// File events are coalesced for `debounce` and mapped to their owning crate,
+/// so a burst of saves only regenerates the crates whose inputs changed.
 pub fn watch_all(
     crates: &[CrateInfo],
     workspace: &WorkspaceInfo,
     mode: &FluentParseMode,
+    debounce: Duration,
 ) -> Result<()> {
     if crates.is_empty() {
         anyhow::bail!("No crates to watch");
@@ -34,7 +41,7 @@ pub fn watch_all(
         crate::generation::prepare_monolithic_runner_crate(&runner_workspace)?;
     }
 
-    run_watch_terminal(crates, &runner_workspace, mode)
+    run_watch_terminal(crates, &runner_workspace, mode, debounce)
 }
 
 pub(super) fn workspace_for_crates(
@@ -53,10 +60,12 @@ fn run_watch_terminal(
     crates: &[CrateInfo],
     workspace: &WorkspaceInfo,
     mode: &FluentParseMode,
+    debounce: Duration,
 ) -> Result<()> {
     let mut terminal = ratatui::init();
     let poll = tui::poll_quit_event;
-    let result = run_watch_loop_with_poll(&mut terminal, crates, workspace, mode, poll, None);
+    let result =
+        run_watch_loop_with_poll(&mut terminal, crates, workspace, mode, debounce, poll, None);
     ratatui::restore();
 
     result
@@ -67,6 +76,7 @@ fn run_watch_terminal(
     crates: &[CrateInfo],
     workspace: &WorkspaceInfo,
     mode: &FluentParseMode,
+    debounce: Duration,
 ) -> Result<()> {
     let backend = ratatui::backend::TestBackend::new(80, 20);
     let mut terminal = Terminal::new(backend)?;
@@ -75,6 +85,7 @@ fn run_watch_terminal(
         crates,
         workspace,
         mode,
+        debounce,
         quit_immediately,
         Some(1),
     )
@@ -90,13 +101,14 @@ fn run_watch_loop_with_poll<B: Backend>(
     crates: &[CrateInfo],
     workspace: &WorkspaceInfo,
     mode: &FluentParseMode,
+    debounce: Duration,
     poll_quit: fn(Duration) -> std::io::Result<bool>,
     max_iterations: Option<usize>,
 ) -> Result<()> {
     let mut app = TuiApp::new(crates);
     let mut runtime = WatchRuntime::new(crates, workspace, mode);
     let (_debouncer, file_rx) =
-        configure_file_watcher(runtime.valid_crates(), &workspace.root_dir)?;
+        configure_file_watcher(runtime.valid_crates(), &workspace.root_dir, debounce)?;
     run_watch_loop_with_runtime(
         terminal,
         &mut app,
@@ -190,14 +202,14 @@ fn run_watch_loop_with_runtime<B: Backend>(
 fn configure_file_watcher(
     valid_crates: &[&CrateInfo],
     workspace_root: &std::path::Path,
+    debounce: Duration,
 ) -> Result<(
     notify_debouncer_full::Debouncer<RecommendedWatcher, RecommendedCache>,
     Receiver<DebounceEventResult>,
 )> {
     let (file_tx, file_rx) = crossbeam_channel::unbounded();
-    let mut debouncer =
-        notify_debouncer_full::new_debouncer(Duration::from_millis(300), None, file_tx)
-            .context("Failed to create file watcher")?;
+    let mut debouncer = notify_debouncer_full::new_debouncer(debounce, None, file_tx)
+        .context("Failed to create file watcher")?;
 
     debouncer
         .watch(workspace_root, RecursiveMode::NonRecursive)
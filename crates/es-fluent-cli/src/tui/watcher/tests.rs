@@ -13,6 +13,8 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 use toml::Value;
 
+const DEBOUNCE: Duration = Duration::from_millis(super::DEFAULT_DEBOUNCE_MS);
+
 fn test_crate(name: &str, has_lib_rs: bool) -> CrateInfo {
     CrateInfo {
         name: es_fluent_runner::PackageName::try_new(name).expect("valid package name"),
@@ -315,7 +317,7 @@ fn watch_all_errors_when_no_crates_provided() {
         crates: Vec::new(),
     };
 
-    let result = super::watch_all(&[], &workspace, &FluentParseMode::default());
+    let result = super::watch_all(&[], &workspace, &FluentParseMode::default(), DEBOUNCE);
     assert!(result.is_err());
 }
 
@@ -406,6 +408,7 @@ fn run_watch_loop_with_poll_handles_non_library_crates() {
         &[crate_without_lib],
         &workspace,
         &FluentParseMode::default(),
+        DEBOUNCE,
         always_quit,
         Some(2),
     );
@@ -424,6 +427,7 @@ fn run_watch_loop_with_poll_processes_initial_generation_for_valid_crate() {
         &[krate],
         &workspace,
         &FluentParseMode::default(),
+        DEBOUNCE,
         quit_after_three_polls,
         Some(10),
     );
@@ -530,7 +534,7 @@ fn configure_file_watcher_reports_invalid_watch_roots() {
         fluent_features: Vec::new(),
     };
 
-    let err = super::configure_file_watcher(&[&krate], temp.path())
+    let err = super::configure_file_watcher(&[&krate], temp.path(), DEBOUNCE)
         .expect_err("missing watch roots should fail watcher setup");
     assert!(err.to_string().contains("Failed to watch"));
 }
@@ -556,7 +560,7 @@ fn configure_file_watcher_reports_invalid_workspace_watch_root() {
         fluent_features: Vec::new(),
     };
 
-    let err = super::configure_file_watcher(&[&krate], &workspace_root)
+    let err = super::configure_file_watcher(&[&krate], &workspace_root, DEBOUNCE)
         .expect_err("invalid workspace root should fail watcher setup");
     assert!(err.to_string().contains("Failed to watch"));
 }
@@ -583,7 +587,7 @@ fn configure_file_watcher_reports_invalid_manifest_watch_root() {
         fluent_features: Vec::new(),
     };
 
-    let err = super::configure_file_watcher(&[&krate], temp.path())
+    let err = super::configure_file_watcher(&[&krate], temp.path(), DEBOUNCE)
         .expect_err("missing manifest watch root should fail watcher setup");
     assert!(err.to_string().contains("Failed to watch"));
 }
@@ -698,6 +702,7 @@ fn run_watch_loop_with_poll_processes_file_change_events() {
         std::slice::from_ref(&krate),
         &workspace,
         &FluentParseMode::default(),
+        DEBOUNCE,
         quit_after_event_window,
         Some(120),
     );
@@ -716,6 +721,7 @@ fn run_watch_loop_with_poll_respects_zero_iteration_limit() {
         &[krate],
         &workspace,
         &FluentParseMode::default(),
+        DEBOUNCE,
         always_quit,
         Some(0),
     );
@@ -748,7 +754,7 @@ fn watch_all_propagates_runner_preparation_errors() {
         crates: vec![krate.clone()],
     };
 
-    let err = super::watch_all(&[krate], &workspace, &FluentParseMode::default())
+    let err = super::watch_all(&[krate], &workspace, &FluentParseMode::default(), DEBOUNCE)
         .expect_err("invalid workspace root should fail before entering the TUI loop");
     let error = err.to_string();
     assert!(
@@ -762,7 +768,7 @@ fn watch_all_propagates_runner_preparation_errors() {
 fn watch_all_uses_test_terminal_for_valid_workspace() {
     let (_temp, workspace, krate) = create_valid_workspace_with_fake_runner();
 
-    let result = super::watch_all(&[krate], &workspace, &FluentParseMode::default());
+    let result = super::watch_all(&[krate], &workspace, &FluentParseMode::default(), DEBOUNCE);
 
     assert!(result.is_ok());
 }
@@ -854,6 +860,7 @@ fn watch_all_links_only_watched_crates() {
         std::slice::from_ref(&watched_crate),
         &workspace,
         &FluentParseMode::default(),
+        DEBOUNCE,
     );
 
     assert!(result.is_ok());
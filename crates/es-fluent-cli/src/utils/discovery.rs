@@ -5,7 +5,7 @@ use crate::core::{
 use anyhow::{Context as _, Result};
 use cargo_metadata::{MetadataCommand, TargetKind};
 use es_fluent_runner::PackageName;
-use es_fluent_toml::ResolvedI18nLayout;
+use es_fluent_toml::{I18nConfig, ResolvedI18nLayout};
 use std::path::{Path, PathBuf};
 
 pub(crate) enum DiscoveryScope<'a> {
@@ -18,7 +18,9 @@ pub(crate) enum DiscoveryScope<'a> {
     },
 }
 
-/// Discovers workspace information including root, target dir, and all crates with i18n.toml.
+/// Discovers workspace information including root, target dir, and all crates
+/// an `i18n.toml` applies to: their own, the workspace root's, or the one named
+/// by `ES_FLUENT_CONFIG`.
 /// This is used by the monolithic temp crate approach for efficient inventory collection.
 #[allow(dead_code)]
 pub fn discover_workspace(root_dir: &Path) -> Result<WorkspaceInfo> {
@@ -77,10 +79,9 @@ pub(crate) fn discover_workspace_scoped(
             continue;
         }
 
-        let i18n_config_path = manifest_dir.join("i18n.toml");
-        if !i18n_config_path.exists() {
+        let Some(i18n_config_path) = I18nConfig::config_path_for_manifest_dir(&manifest_dir) else {
             continue;
-        }
+        };
 
         let layout = ResolvedI18nLayout::from_config_path(&i18n_config_path).map_err(|error| {
             anyhow::anyhow!(
@@ -163,7 +164,7 @@ pub(crate) fn discover_i18n_package_names(root_dir: &Path) -> Result<Vec<String>
             let manifest_dir_raw: PathBuf = package.manifest_path.parent().unwrap().into();
             let manifest_dir: PathBuf =
                 crate::utils::paths::normalize_windows_verbatim_path(&manifest_dir_raw);
-            manifest_dir.join("i18n.toml").exists()
+            I18nConfig::config_path_for_manifest_dir(&manifest_dir).is_some()
         })
         .map(|package| package.name.to_string())
         .collect::<Vec<_>>();
@@ -237,9 +238,11 @@ fn workspace_relative_path(path: &Path, workspace_root: &Path) -> String {
 }
 
 /// Counts the number of FTL resources (message keys) for a specific crate.
-pub fn count_ftl_resources(ftl_output_dir: &Path, crate_name: &str) -> usize {
-    let Ok(files) = crate::ftl::discover_crate_ftl_files_in_locale_dir(ftl_output_dir, crate_name)
-    else {
+///
+/// `files` is the crate's layout in its fallback locale, as
+/// [`crate::core::CrateInfo::ftl_layout`] resolves it.
+pub fn count_ftl_resources(files: &crate::ftl::CrateFtlLayout) -> usize {
+    let Ok(files) = files.discover_files() else {
         return 0;
     };
 
@@ -283,13 +286,22 @@ mod tests {
     #[test]
     fn test_count_ftl_resources_empty() {
         let temp = tempfile::tempdir().unwrap();
-        assert_eq!(count_ftl_resources(temp.path(), "test-crate"), 0);
+        assert_eq!(
+            count_ftl_resources(&crate::ftl::CrateFtlLayout::new(
+                temp.path().to_path_buf(),
+                "test-crate"
+            )),
+            0
+        );
     }
 
     #[test]
     fn test_count_ftl_resources_nonexistent() {
         assert_eq!(
-            count_ftl_resources(Path::new("/nonexistent/path"), "test-crate"),
+            count_ftl_resources(&crate::ftl::CrateFtlLayout::new(
+                PathBuf::from("/nonexistent/path"),
+                "test-crate"
+            )),
             0
         );
     }
@@ -346,6 +358,49 @@ mod tests {
         assert_eq!(ws.crates[0].src_dir.as_path(), expected_src_dir.as_path());
     }
 
+    #[test]
+    fn discover_workspace_uses_workspace_root_config_for_members() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        fs::write(
+            temp.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"app\"]\n",
+        )
+        .expect("write workspace Cargo.toml");
+        fs::write(
+            temp.path().join("i18n.toml"),
+            "fallback_language = \"en\"\nassets_dir = \"i18n\"\n",
+        )
+        .expect("write workspace i18n.toml");
+        let crate_dir = temp.path().join("app");
+        fs::create_dir_all(crate_dir.join("src")).expect("create src");
+        fs::write(
+            crate_dir.join("Cargo.toml"),
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+        )
+        .expect("write crate Cargo.toml");
+        fs::write(crate_dir.join("src/lib.rs"), LIB_RS).expect("write lib.rs");
+
+        let ws = discover_workspace(temp.path()).expect("discover workspace");
+        let root = crate::utils::paths::normalize_windows_verbatim_path(
+            &temp.path().canonicalize().expect("canonical tempdir"),
+        );
+
+        assert_eq!(ws.crates.len(), 1);
+        assert_eq!(ws.crates[0].name, "app");
+        assert_eq!(
+            ws.crates[0].i18n_config_path.as_path(),
+            root.join("i18n.toml").as_path()
+        );
+        assert_eq!(
+            ws.crates[0].ftl_output_dir.as_path(),
+            root.join("i18n").join("en").as_path()
+        );
+        assert_eq!(
+            discover_i18n_package_names(temp.path()).expect("package names"),
+            ["app"]
+        );
+    }
+
     #[test]
     fn discover_crates_ignores_crates_without_i18n_toml() {
         let temp = create_workspace_without_i18n_toml();
@@ -365,7 +420,13 @@ mod tests {
         .expect("write ftl");
 
         // Count logic is line-based and should count `hello`, `-world`, and `plain`.
-        assert_eq!(count_ftl_resources(&locale_dir, "test-crate"), 3);
+        assert_eq!(
+            count_ftl_resources(&crate::ftl::CrateFtlLayout::new(
+                locale_dir.clone(),
+                "test-crate"
+            )),
+            3
+        );
     }
 
     #[test]
@@ -389,6 +450,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn discover_workspace_resolves_domain_first_ftl_paths() {
+        let temp = crate::test_fixtures::create_test_crate_workspace();
+        let config_path = temp.path().join("i18n.toml");
+        let config = fs::read_to_string(&config_path).expect("read i18n.toml");
+        fs::write(&config_path, format!("{config}layout = \"domain_first\"\n"))
+            .expect("write i18n.toml");
+        fs::remove_dir_all(temp.path().join("i18n/en")).expect("remove language-first dir");
+        crate::test_fixtures::write_file(
+            &temp.path().join("i18n/test-app/en.ftl"),
+            "hello = Hello\n",
+        );
+        crate::test_fixtures::write_file(
+            &temp.path().join("i18n/test-app/en/ui.ftl"),
+            "button = Button\n",
+        );
+
+        let ws = discover_workspace(temp.path()).expect("discover domain_first workspace");
+        let krate = &ws.crates[0];
+        assert_eq!(
+            count_ftl_resources(&krate.ftl_layout().expect("ftl layout")),
+            2
+        );
+
+        let ctx = crate::ftl::LocaleContext::from_crate(krate, true).expect("locale context");
+        assert_eq!(ctx.locales, vec!["en".to_string()]);
+        let files = ctx.crate_layout("en");
+        assert_eq!(
+            files.main_file(),
+            ctx.assets_dir.join("test-app").join("en.ftl")
+        );
+        assert_eq!(
+            files.file_path("test-app/ui.ftl"),
+            ctx.assets_dir.join("test-app").join("en").join("ui.ftl")
+        );
+        assert_eq!(
+            ctx.locale_dir("en"),
+            ctx.assets_dir.join("test-app").join("en")
+        );
+        assert!(ctx.has_locale("en"));
+        assert!(!ctx.has_locale("fr"));
+    }
+
     #[test]
     fn discover_workspace_collects_fluent_features_and_sorts_crates() {
         let temp = tempfile::tempdir().expect("tempdir");
@@ -427,6 +531,12 @@ mod tests {
         let locale_dir = temp.path().join("en");
         fs::create_dir_all(locale_dir.join("test-crate.ftl")).expect("create fake ftl dir");
 
-        assert_eq!(count_ftl_resources(&locale_dir, "test-crate"), 0);
+        assert_eq!(
+            count_ftl_resources(&crate::ftl::CrateFtlLayout::new(
+                locale_dir.clone(),
+                "test-crate"
+            )),
+            0
+        );
     }
 }
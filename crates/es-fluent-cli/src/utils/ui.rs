@@ -3,6 +3,7 @@
 
 use crate::core::CrateInfo;
 use colored::Colorize as _;
+use es_fluent_runner::GenerateReport;
 use indicatif::{ProgressBar, ProgressStyle};
 use std::io::IsTerminal as _;
 use std::path::Path;
@@ -273,6 +274,44 @@ impl Ui {
         println!("  {} {}", "->".dimmed(), key);
     }
 
+    /// Print the keys and groups a dry-run generate or clean would change.
+    pub fn print_generate_report(report: &GenerateReport) {
+        for (verb, noun, entries) in [
+            ("Would add", "key(s)", &report.added),
+            ("Would remove", "key(s)", &report.removed),
+            ("Would add", "group(s)", &report.groups_added),
+        ] {
+            if entries.is_empty() {
+                continue;
+            }
+            println!("{} {} {}", verb.yellow(), entries.len(), noun);
+            for entry in entries {
+                Self::print_synced_key(entry);
+            }
+        }
+    }
+
+    /// Print the orphan keys `generate --error-on-orphans` found in a crate.
+    pub fn print_orphan_keys(crate_name: &str, report: &GenerateReport) {
+        println!(
+            "{} {} orphan key(s) in {}",
+            "Found".red(),
+            report.removed.len(),
+            crate_name.white().bold()
+        );
+        for key in &report.removed {
+            match report.removed_groups.get(key) {
+                Some(group) => println!(
+                    "  {} {} {}",
+                    "->".dimmed(),
+                    key,
+                    format!("({group})").dimmed()
+                ),
+                None => Self::print_synced_key(key),
+            }
+        }
+    }
+
     pub fn print_all_in_sync() {
         println!("{}", "All locales are in sync!".green());
     }
@@ -320,6 +359,113 @@ impl Ui {
         );
     }
 
+    pub fn print_rename_header() {
+        println!("{}", "Fluent FTL Rename".dimmed());
+    }
+
+    pub fn print_would_rename(path: &Path, keys: usize) {
+        println!(
+            "{} {} key(s) in {}",
+            "Would rename".yellow(),
+            keys,
+            path.display()
+        );
+    }
+
+    pub fn print_renamed(path: &Path, keys: usize) {
+        println!(
+            "{} {} key(s) in {}",
+            "Renamed".green(),
+            keys,
+            path.display()
+        );
+    }
+
+    pub fn print_rename_dry_run_summary(keys: usize, files: usize) {
+        println!(
+            "{} {} key(s) across {} file(s)",
+            "Would rename".yellow(),
+            keys,
+            files
+        );
+    }
+
+    pub fn print_rename_summary(type_name: &str, keys: usize, files: usize) {
+        if files == 0 {
+            println!(
+                "{} no entries for {} found",
+                "Done:".green(),
+                type_name.bold()
+            );
+        } else {
+            println!(
+                "{} {} key(s) renamed in {} file(s)",
+                "Done:".green(),
+                keys,
+                files
+            );
+        }
+    }
+
+    pub fn print_validate_header() {
+        println!("{}", "Fluent FTL Validate".dimmed());
+    }
+
+    pub fn print_locale_complete(locale: &str, crate_name: &str) {
+        println!(
+            "{} {} ({})",
+            "Complete".green(),
+            locale.cyan(),
+            crate_name.bold()
+        );
+    }
+
+    pub fn print_locale_incomplete(
+        locale: &str,
+        crate_name: &str,
+        missing: usize,
+        extra: usize,
+        mismatched: usize,
+        dangling: usize,
+    ) {
+        println!(
+            "{} {} ({}): {} missing, {} extra, {} variable mismatch(es), {} dangling reference(s)",
+            "Incomplete".red(),
+            locale.cyan(),
+            crate_name.bold(),
+            missing,
+            extra,
+            mismatched,
+            dangling
+        );
+    }
+
+    pub fn print_validate_entry(label: &str, entry: &str) {
+        println!("  {} {}", format!("{label}:").dimmed(), entry);
+    }
+
+    pub fn print_validate_error(crate_name: &str, error: &str) {
+        eprintln!(
+            "{} {}: {}",
+            "Validation failed for".red(),
+            crate_name.white().bold(),
+            error
+        );
+    }
+
+    pub fn print_validate_summary(incomplete: usize, checked: usize) {
+        if incomplete == 0 {
+            println!("{} {} locale(s) complete", "Done:".green(), checked);
+        } else {
+            println!(
+                "{} {} of {} locale(s) incomplete",
+                "Failed:".red(),
+                incomplete,
+                checked
+            );
+        }
+    }
+
     pub fn print_no_crates_found() {
         eprintln!("{}", "No crates with i18n.toml found.".red());
     }
@@ -487,11 +633,42 @@ mod tests {
         Ui::print_would_add_keys(2, "es", "crate-a");
         Ui::print_added_keys(2, "es");
         Ui::print_synced_key("hello_world");
+        Ui::print_generate_report(&GenerateReport::default());
+        Ui::print_generate_report(&GenerateReport {
+            changed: true,
+            added: vec!["hello-New".to_string()],
+            removed: vec!["hello-Old".to_string()],
+            groups_added: vec!["Hello".to_string()],
+            ..GenerateReport::default()
+        });
+        Ui::print_orphan_keys(
+            "crate-a",
+            &GenerateReport {
+                removed: vec!["hello-Old".to_string(), "loose".to_string()],
+                removed_groups: [("hello-Old".to_string(), "Hello".to_string())].into(),
+                ..GenerateReport::default()
+            },
+        );
         Ui::print_all_in_sync();
         Ui::print_no_locale_changes_needed();
         Ui::print_sync_dry_run_summary(3, 2);
         Ui::print_sync_summary(3, 2);
         Ui::print_add_locale_summary(3, 2);
+
+        Ui::print_rename_header();
+        Ui::print_would_rename(Path::new("i18n/en/test.ftl"), 2);
+        Ui::print_renamed(Path::new("i18n/en/test.ftl"), 2);
+        Ui::print_rename_dry_run_summary(4, 2);
+        Ui::print_rename_summary("LoginError", 4, 2);
+        Ui::print_rename_summary("LoginError", 0, 0);
+
+        Ui::print_validate_header();
+        Ui::print_locale_complete("es", "crate-a");
+        Ui::print_locale_incomplete("fr", "crate-a", 1, 2, 3, 4);
+        Ui::print_validate_entry("missing", "hello_world");
+        Ui::print_validate_error("crate-a", "bad validate");
+        Ui::print_validate_summary(0, 2);
+        Ui::print_validate_summary(1, 2);
         Ui::print_no_crates_found();
 
         Ui::print_diff("a = 1\nb = 2\n", "a = 1\nc = 3\n");
@@ -13,7 +13,9 @@ const SUBCOMMANDS: &[&str] = &[
     "status",
     "sync",
     "add-locale",
+    "rename",
     "tree",
+    "validate",
 ];
 
 #[test]
@@ -419,7 +421,21 @@ fn binary_every_command_has_a_noninteractive_success_path() {
             "add-locale",
             &["add-locale", "--path", workspace, "--dry-run", "fr-FR"],
         ),
+        (
+            "rename",
+            &[
+                "rename",
+                "--path",
+                workspace,
+                "--from",
+                "OldType",
+                "--to",
+                "NewType",
+                "--dry-run",
+            ],
+        ),
         ("tree", &["tree", "--path", workspace]),
+        ("validate", &["validate", "--path", workspace]),
     ];
 
     assert_eq!(
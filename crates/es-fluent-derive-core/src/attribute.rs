@@ -63,6 +63,7 @@ mod tests {
     fn enum_variant_location_allows_only_variant_keys() {
         let skip: Meta = parse_quote!(skip);
         let key: Meta = parse_quote!(key = "custom");
+        let namespace: Meta = parse_quote!(namespace = "network");
         let arg: Meta = parse_quote!(arg = "name");
         let value: Meta = parse_quote!(value = |x| x);
 
@@ -72,6 +73,10 @@ mod tests {
         assert!(
             invalid_fluent_meta_item_for_location(&key, AttributeLocation::EnumVariant).is_none()
         );
+        assert!(
+            invalid_fluent_meta_item_for_location(&namespace, AttributeLocation::EnumVariant)
+                .is_none()
+        );
         assert_eq!(
             invalid_fluent_meta_item_for_location(&arg, AttributeLocation::EnumVariant)
                 .expect("arg is invalid")
@@ -120,6 +125,24 @@ mod tests {
             AttributeLocation::MessageField,
             FluentAttributeKey::Selector,
         );
+        assert_allowed(
+            parse_quote!(term),
+            AttributeName::Fluent,
+            AttributeLocation::MessageField,
+            FluentAttributeKey::Term,
+        );
+        assert_allowed(
+            parse_quote!(plural),
+            AttributeName::Fluent,
+            AttributeLocation::MessageField,
+            FluentAttributeKey::Plural,
+        );
+        assert_allowed(
+            parse_quote!(custom),
+            AttributeName::Fluent,
+            AttributeLocation::MessageField,
+            FluentAttributeKey::Custom,
+        );
         assert_allowed(
             parse_quote!(skip),
             AttributeName::Fluent,
@@ -132,6 +155,12 @@ mod tests {
             AttributeLocation::EnumVariant,
             FluentAttributeKey::Key,
         );
+        assert_allowed(
+            parse_quote!(namespace = "network"),
+            AttributeName::Fluent,
+            AttributeLocation::EnumVariant,
+            FluentAttributeKey::Namespace,
+        );
         assert_allowed(
             parse_quote!(id = "auth_error"),
             AttributeName::Fluent,
@@ -150,6 +179,54 @@ mod tests {
             AttributeLocation::MessageStructContainer,
             FluentAttributeKey::Namespace,
         );
+        assert_allowed(
+            parse_quote!(case = "kebab"),
+            AttributeName::Fluent,
+            AttributeLocation::MessageEnumContainer,
+            FluentAttributeKey::Case,
+        );
+        assert_allowed(
+            parse_quote!(doc_as_comment),
+            AttributeName::Fluent,
+            AttributeLocation::MessageStructContainer,
+            FluentAttributeKey::DocAsComment,
+        );
+        assert_allowed(
+            parse_quote!(group = "User Facing Errors"),
+            AttributeName::Fluent,
+            AttributeLocation::MessageEnumContainer,
+            FluentAttributeKey::Group,
+        );
+        assert_allowed(
+            parse_quote!(skip_generate),
+            AttributeName::Fluent,
+            AttributeLocation::MessageStructContainer,
+            FluentAttributeKey::SkipGenerate,
+        );
+        assert_allowed(
+            parse_quote!(export_keys),
+            AttributeName::Fluent,
+            AttributeLocation::MessageEnumContainer,
+            FluentAttributeKey::ExportKeys,
+        );
+        assert_allowed(
+            parse_quote!(test_all_keys),
+            AttributeName::Fluent,
+            AttributeLocation::MessageEnumContainer,
+            FluentAttributeKey::TestAllKeys,
+        );
+        assert_allowed(
+            parse_quote!(attributes = ["label", "placeholder"]),
+            AttributeName::Fluent,
+            AttributeLocation::EnumVariant,
+            FluentAttributeKey::Attributes,
+        );
+        assert_allowed(
+            parse_quote!(as_terms),
+            AttributeName::Fluent,
+            AttributeLocation::MessageStructContainer,
+            FluentAttributeKey::AsTerms,
+        );
         assert_allowed(
             parse_quote!(derive(Debug, Clone)),
             AttributeName::FluentVariants,
@@ -174,6 +251,12 @@ mod tests {
             AttributeLocation::LanguageContainer,
             FluentAttributeKey::Custom,
         );
+        assert_allowed(
+            parse_quote!(locales = ["en", "fr"]),
+            AttributeName::EsFluentLanguage,
+            AttributeLocation::LanguageContainer,
+            FluentAttributeKey::Locales,
+        );
 
         let keys: Meta = parse_quote!(keys = ["label"]);
         let bad = invalid_attribute_meta_item_for_location(
@@ -361,7 +444,16 @@ mod tests {
             (
                 AttributeFamily::Fluent,
                 AttributeLocation::MessageStructContainer,
-                &[FluentAttributeKey::Namespace][..],
+                &[
+                    FluentAttributeKey::Namespace,
+                    FluentAttributeKey::Case,
+                    FluentAttributeKey::DocAsComment,
+                    FluentAttributeKey::Group,
+                    FluentAttributeKey::SkipGenerate,
+                    FluentAttributeKey::ExportKeys,
+                    FluentAttributeKey::AsTerms,
+                    FluentAttributeKey::Attributes,
+                ][..],
             ),
             (
                 AttributeFamily::Fluent,
@@ -370,6 +462,14 @@ mod tests {
                     FluentAttributeKey::Id,
                     FluentAttributeKey::Domain,
                     FluentAttributeKey::Namespace,
+                    FluentAttributeKey::Case,
+                    FluentAttributeKey::DocAsComment,
+                    FluentAttributeKey::Group,
+                    FluentAttributeKey::SkipGenerate,
+                    FluentAttributeKey::ExportKeys,
+                    FluentAttributeKey::TestAllKeys,
+                    FluentAttributeKey::AsTerms,
+                    FluentAttributeKey::Attributes,
                 ][..],
             ),
             (
@@ -378,6 +478,9 @@ mod tests {
                 &[
                     FluentAttributeKey::Skip,
                     FluentAttributeKey::Selector,
+                    FluentAttributeKey::Term,
+                    FluentAttributeKey::Plural,
+                    FluentAttributeKey::Custom,
                     FluentAttributeKey::Arg,
                     FluentAttributeKey::Value,
                 ][..],
@@ -385,7 +488,12 @@ mod tests {
             (
                 AttributeFamily::Fluent,
                 AttributeLocation::EnumVariant,
-                &[FluentAttributeKey::Skip, FluentAttributeKey::Key][..],
+                &[
+                    FluentAttributeKey::Skip,
+                    FluentAttributeKey::Key,
+                    FluentAttributeKey::Namespace,
+                    FluentAttributeKey::Attributes,
+                ][..],
             ),
             // Parent #[fluent(...)] inherited by EsFluentLabel and EsFluentVariants.
             (
@@ -440,11 +548,20 @@ mod tests {
                 AttributeLocation::ChoiceContainer,
                 &[FluentAttributeKey::RenameAll][..],
             ),
+            (
+                AttributeFamily::FluentChoice,
+                AttributeLocation::ChoiceVariant,
+                &[FluentAttributeKey::Rename][..],
+            ),
             // es_fluent_language and locale field marker options.
             (
                 AttributeFamily::EsFluentLanguage,
                 AttributeLocation::LanguageContainer,
-                &[FluentAttributeKey::Builtin, FluentAttributeKey::Custom][..],
+                &[
+                    FluentAttributeKey::Builtin,
+                    FluentAttributeKey::Custom,
+                    FluentAttributeKey::Locales,
+                ][..],
             ),
             (
                 AttributeFamily::Locale,
@@ -479,6 +596,7 @@ mod tests {
             AttributeLocation::VariantsVariant,
             AttributeLocation::LabelContainer,
             AttributeLocation::ChoiceContainer,
+            AttributeLocation::ChoiceVariant,
             AttributeLocation::LanguageContainer,
             AttributeLocation::LocaleNamedStructField,
             AttributeLocation::LocaleNamedEnumVariantField,
@@ -525,7 +643,9 @@ mod tests {
                 let key = key_ident(rule.key);
                 syn::parse_quote!(#key)
             },
-            AttributeValueShape::StringLiteral | AttributeValueShape::ChoiceCaseStyle => {
+            AttributeValueShape::StringLiteral
+            | AttributeValueShape::ChoiceCaseStyle
+            | AttributeValueShape::MessageKeyCase => {
                 let key = key_ident(rule.key);
                 let value = string_value_for_rule(rule);
                 syn::parse_quote!(#key = #value)
@@ -546,6 +666,10 @@ mod tests {
                 let key = key_ident(rule.key);
                 syn::parse_quote!(#key = ["label"])
             },
+            AttributeValueShape::LocaleList => {
+                let key = key_ident(rule.key);
+                syn::parse_quote!(#key = ["en", "fr"])
+            },
         }
     }
 
@@ -568,16 +692,28 @@ mod tests {
             AttributeKey::Arg => "arg",
             AttributeKey::Value => "value",
             AttributeKey::Selector => "selector",
+            AttributeKey::Term => "term",
+            AttributeKey::Plural => "plural",
             AttributeKey::Skip => "skip",
             AttributeKey::Key => "key",
             AttributeKey::Id => "id",
             AttributeKey::Domain => "domain",
             AttributeKey::Namespace => "namespace",
+            AttributeKey::Case => "case",
+            AttributeKey::DocAsComment => "doc_as_comment",
+            AttributeKey::Group => "group",
+            AttributeKey::SkipGenerate => "skip_generate",
+            AttributeKey::ExportKeys => "export_keys",
+            AttributeKey::TestAllKeys => "test_all_keys",
+            AttributeKey::AsTerms => "as_terms",
+            AttributeKey::Attributes => "attributes",
             AttributeKey::Derive => "derive",
             AttributeKey::Keys => "keys",
             AttributeKey::RenameAll => "rename_all",
+            AttributeKey::Rename => "rename",
             AttributeKey::Builtin => "builtin",
             AttributeKey::Custom => "custom",
+            AttributeKey::Locales => "locales",
             AttributeKey::Locale => "locale",
         }
     }
@@ -585,6 +721,7 @@ mod tests {
     fn string_value_for_rule(rule: &AttributeRule) -> &'static str {
         match rule.shape {
             AttributeValueShape::ChoiceCaseStyle => "snake_case",
+            AttributeValueShape::MessageKeyCase => "kebab",
             _ => "value",
         }
     }
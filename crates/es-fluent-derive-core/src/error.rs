@@ -18,6 +18,7 @@ pub enum AttrContext {
     VariantsVariant,
     LabelContainer,
     ChoiceContainer,
+    ChoiceVariant,
     LanguageContainer,
     LocaleField,
 }
@@ -35,6 +36,7 @@ impl fmt::Display for AttrContext {
             Self::VariantsVariant => "variants variant",
             Self::LabelContainer => "label container",
             Self::ChoiceContainer => "choice container",
+            Self::ChoiceVariant => "choice variant",
             Self::LanguageContainer => "language container",
             Self::LocaleField => "locale field",
         };
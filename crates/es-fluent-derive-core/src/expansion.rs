@@ -11,7 +11,8 @@ use crate::{
     lowered,
     namespace::{SpannedNamespaceRule, SpannedNamespaceRuleRef},
     options::{
-        EnumDataOptions as _, FluentField, GeneratedVariantsOptions, VariantFields as _,
+        EnumDataOptions as _, FluentField, GeneratedVariantsOptions, MessageAttributeList,
+        VariantFields as _,
         choice::{CaseStyle, ChoiceOpts},
         r#enum::{EnumOpts, EnumVariantsOpts},
         label::LabelOpts,
@@ -269,6 +270,13 @@ impl EsFluentStructExpansion {
                 .map(|field| field.argument().clone())
                 .collect(),
             crate::semantic::SourceLocation::new(model.message_id().span()),
+        )
+        .with_doc(opts.doc().filter(|_| opts.attr_args().doc_as_comment()))
+        .with_attributes(
+            opts.attr_args()
+                .attributes()
+                .map(MessageAttributeList::names)
+                .unwrap_or_default(),
         );
         let message_model = MessageModel::new(
             RustTypeName::from_ident(container_context.source_ident()),
@@ -280,7 +288,15 @@ impl EsFluentStructExpansion {
                 .cloned(),
             vec![message_entry.clone()],
             None,
-        );
+        )
+        .with_group_label(
+            opts.attr_args()
+                .group()
+                .map(|group| group.as_str().to_string()),
+        )
+        .with_skip_generate(opts.attr_args().skip_generate())
+        .with_export_keys(opts.attr_args().export_keys())
+        .with_term(opts.attr_args().as_terms());
 
         Ok(Self {
             ident: container_context.source_ident().clone(),
@@ -375,10 +391,19 @@ impl EsFluentEnumExpansion {
 
         let model = lowered::MessageEnumModel::from_options(opts)?;
         let domain = container_context.fluent_domain().cloned();
+        let doc_as_comment = opts.attr_args().doc_as_comment();
         let variants = model
             .variants()
             .iter()
-            .map(enum_variant_expansion)
+            .zip(opts.variants())
+            .map(|(variant, variant_opts)| {
+                enum_variant_expansion(
+                    variant,
+                    variant_opts.doc().filter(|_| doc_as_comment),
+                    variant_opts.namespace(),
+                    variant_opts.attributes().or(opts.attr_args().attributes()),
+                )
+            })
             .collect::<Result<Vec<_>, EsFluentCoreError>>()?;
         let messages = variants
             .iter()
@@ -395,7 +420,16 @@ impl EsFluentEnumExpansion {
                 .cloned(),
             messages,
             None,
-        );
+        )
+        .with_group_label(
+            opts.attr_args()
+                .group()
+                .map(|group| group.as_str().to_string()),
+        )
+        .with_skip_generate(opts.attr_args().skip_generate())
+        .with_export_keys(opts.attr_args().export_keys())
+        .with_test_all_keys(opts.attr_args().test_all_keys())
+        .with_term(opts.attr_args().as_terms());
 
         Ok(Self {
             ident: container_context.source_ident().clone(),
@@ -601,7 +635,13 @@ impl EsFluentNamedField {
 
 fn enum_variant_expansion(
     variant: &lowered::MessageEnumVariant<'_>,
+    doc: Option<&str>,
+    namespace: Option<&SpannedNamespaceRule>,
+    attributes: Option<&MessageAttributeList>,
 ) -> Result<EsFluentMessageVariant, EsFluentCoreError> {
+    if let Some(namespace) = namespace {
+        validate_namespace(Some(namespace.rule()), namespace.span())?;
+    }
     let ident = variant.ident().clone();
     let shape = enum_variant_shape(variant)?;
 
@@ -617,6 +657,13 @@ fn enum_variant_expansion(
         variant.message_id().clone(),
         enum_variant_arguments(&shape),
         crate::semantic::SourceLocation::new(variant.message_id().span()),
+    )
+    .with_doc(doc.map(str::to_owned))
+    .with_namespace(namespace.map(SpannedNamespaceRule::rule).cloned())
+    .with_attributes(
+        attributes
+            .map(MessageAttributeList::names)
+            .unwrap_or_default(),
     );
 
     Ok(EsFluentMessageVariant::Localized(
@@ -715,9 +762,12 @@ impl EsFluentChoiceExpansion {
         let opts = ChoiceOpts::from_derive_input(input)?;
         let lowered = lowered::ChoiceModel::from_options(&opts)?;
         let enum_ident = lowered.ident();
-        let choice = ChoiceModel::from_variant_idents(
+        let choice = ChoiceModel::from_variant_sources(
             enum_ident,
-            lowered.variants().iter().map(|variant| variant.ident()),
+            lowered
+                .variants()
+                .iter()
+                .map(|variant| ChoiceVariantSource::new(variant.ident(), variant.rename())),
             *opts.attr_args().rename_all(),
         )?;
 
@@ -1399,6 +1449,71 @@ mod tests {
         assert_eq!(expansion.choice().variants()[1].value().as_str(), "low");
     }
 
+    #[test]
+    fn choice_expansion_prefers_variant_rename_over_rename_all() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[fluent_choice(rename_all = "SCREAMING_SNAKE_CASE")]
+            enum Priority {
+                VeryHigh,
+                #[fluent_choice(rename = "lowest")]
+                VeryLow,
+            }
+        };
+
+        let expansion = EsFluentChoiceExpansion::from_derive_input(&input)
+            .expect("choice expansion should build");
+
+        let values = expansion
+            .choice()
+            .variants()
+            .iter()
+            .map(|variant| variant.value().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(values, ["VERY_HIGH", "lowest"]);
+    }
+
+    #[test]
+    fn choice_expansion_rejects_invalid_variant_renames() {
+        let input: syn::DeriveInput = parse_quote! {
+            enum Priority {
+                #[fluent_choice(rename = "very high")]
+                VeryHigh,
+            }
+        };
+        let Err(ExpansionError::Core(err)) = EsFluentChoiceExpansion::from_derive_input(&input)
+        else {
+            panic!("rename with a space should fail");
+        };
+        assert!(err.to_string().contains("choice variant"), "{err}");
+
+        let input: syn::DeriveInput = parse_quote! {
+            enum Priority {
+                #[fluent_choice(rename_all = "snake_case")]
+                VeryHigh,
+            }
+        };
+        let Err(ExpansionError::Core(err)) = EsFluentChoiceExpansion::from_derive_input(&input)
+        else {
+            panic!("rename_all on a variant should fail attribute validation");
+        };
+        assert!(err.to_string().contains("choice variant"), "{err}");
+    }
+
+    #[test]
+    fn es_fluent_rejects_fluent_choice_on_enum_variants() {
+        let input: syn::DeriveInput = parse_quote! {
+            enum Priority {
+                #[fluent_choice(rename = "top")]
+                High,
+            }
+        };
+
+        let Err(ExpansionError::Core(err)) = EsFluentExpansion::from_derive_input(&input) else {
+            panic!("fluent_choice on an EsFluent variant should fail");
+        };
+        assert!(err.to_string().contains("fluent(key"), "{err:?}");
+    }
+
     #[test]
     fn choice_expansion_reports_darling_shape_errors() {
         let input: syn::DeriveInput = parse_quote! {
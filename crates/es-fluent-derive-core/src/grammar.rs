@@ -4,7 +4,7 @@ use crate::error::{AttrContext, AttrError, EsFluentCoreError, EsFluentCoreResult
 use proc_macro2::Span;
 use std::marker::PhantomData;
 use syn::{
-    Expr, ExprLit, Lit, Meta, Token, parse::Parser as _, punctuated::Punctuated,
+    Expr, ExprLit, Lit, LitStr, Meta, Token, parse::Parser as _, punctuated::Punctuated,
     spanned::Spanned as _,
 };
 
@@ -59,6 +59,7 @@ pub enum AttributeLocation {
     VariantsVariant,
     LabelContainer,
     ChoiceContainer,
+    ChoiceVariant,
     LanguageContainer,
     LocaleNamedStructField,
     LocaleNamedEnumVariantField,
@@ -84,6 +85,7 @@ impl AttributeLocation {
             Self::VariantsVariant => AttrContext::VariantsVariant,
             Self::LabelContainer => AttrContext::LabelContainer,
             Self::ChoiceContainer => AttrContext::ChoiceContainer,
+            Self::ChoiceVariant => AttrContext::ChoiceVariant,
             Self::LanguageContainer => AttrContext::LanguageContainer,
             Self::LocaleNamedStructField
             | Self::LocaleNamedEnumVariantField
@@ -98,16 +100,28 @@ pub enum AttributeKey {
     Arg,
     Value,
     Selector,
+    Term,
+    Plural,
     Skip,
     Key,
     Id,
     Domain,
     Namespace,
+    Case,
+    DocAsComment,
+    Group,
+    SkipGenerate,
+    ExportKeys,
+    TestAllKeys,
+    AsTerms,
+    Attributes,
     Derive,
     Keys,
     RenameAll,
+    Rename,
     Builtin,
     Custom,
+    Locales,
     Locale,
 }
 
@@ -129,6 +143,10 @@ impl AttributeKey {
             Some(Self::Value)
         } else if path.is_ident("selector") {
             Some(Self::Selector)
+        } else if path.is_ident("term") {
+            Some(Self::Term)
+        } else if path.is_ident("plural") {
+            Some(Self::Plural)
         } else if path.is_ident("skip") {
             Some(Self::Skip)
         } else if path.is_ident("key") {
@@ -139,16 +157,36 @@ impl AttributeKey {
             Some(Self::Domain)
         } else if path.is_ident("namespace") {
             Some(Self::Namespace)
+        } else if path.is_ident("case") {
+            Some(Self::Case)
+        } else if path.is_ident("doc_as_comment") {
+            Some(Self::DocAsComment)
+        } else if path.is_ident("group") {
+            Some(Self::Group)
+        } else if path.is_ident("skip_generate") {
+            Some(Self::SkipGenerate)
+        } else if path.is_ident("export_keys") {
+            Some(Self::ExportKeys)
+        } else if path.is_ident("test_all_keys") {
+            Some(Self::TestAllKeys)
+        } else if path.is_ident("as_terms") {
+            Some(Self::AsTerms)
+        } else if path.is_ident("attributes") {
+            Some(Self::Attributes)
         } else if path.is_ident("derive") {
             Some(Self::Derive)
         } else if path.is_ident("keys") {
             Some(Self::Keys)
         } else if path.is_ident("rename_all") {
             Some(Self::RenameAll)
+        } else if path.is_ident("rename") {
+            Some(Self::Rename)
         } else if path.is_ident("builtin") {
             Some(Self::Builtin)
         } else if path.is_ident("custom") {
             Some(Self::Custom)
+        } else if path.is_ident("locales") {
+            Some(Self::Locales)
         } else if path.is_ident("locale") {
             Some(Self::Locale)
         } else {
@@ -173,7 +211,9 @@ pub enum AttributeValueShape {
     NamespaceRule,
     PathList,
     GeneratedKeyList,
+    LocaleList,
     ChoiceCaseStyle,
+    MessageKeyCase,
     Marker,
 }
 
@@ -191,7 +231,9 @@ impl AttributeValueShape {
         match self {
             Self::Flag => matches!(meta, Meta::Path(_)),
             Self::Marker => matches!(meta, Meta::Path(_)),
-            Self::StringLiteral | Self::ChoiceCaseStyle => is_name_value_string_literal(meta),
+            Self::StringLiteral | Self::ChoiceCaseStyle | Self::MessageKeyCase => {
+                is_name_value_string_literal(meta)
+            },
             Self::RustExpression => {
                 matches!(meta, Meta::NameValue(_)) && !is_name_value_string_literal(meta)
             },
@@ -207,7 +249,7 @@ impl AttributeValueShape {
                     )
             ),
             Self::PathList => matches!(meta, Meta::List(_)),
-            Self::GeneratedKeyList => matches!(
+            Self::GeneratedKeyList | Self::LocaleList => matches!(
                 meta,
                 Meta::NameValue(name_value) if matches!(name_value.value, Expr::Array(_))
             ),
@@ -235,9 +277,15 @@ impl AttributeValueShape {
             Self::GeneratedKeyList => {
                 format!("use a string array, for example `{key_name} = [\"label\"]`")
             },
+            Self::LocaleList => {
+                format!("use a locale array, for example `{key_name} = [\"en\", \"fr\"]`")
+            },
             Self::ChoiceCaseStyle => {
                 format!("use a case style string, for example `{key_name} = \"kebab-case\"`")
             },
+            Self::MessageKeyCase => {
+                format!("use a key case string, for example `{key_name} = \"kebab\"`")
+            },
         }
     }
 }
@@ -289,7 +337,14 @@ pub(crate) trait AttributeSpec {
             && location == AttributeLocation::EnumVariant
             && matches!(
                 item.key(),
-                Some(AttributeKey::Arg | AttributeKey::Value | AttributeKey::Selector)
+                Some(
+                    AttributeKey::Arg
+                        | AttributeKey::Value
+                        | AttributeKey::Selector
+                        | AttributeKey::Term
+                        | AttributeKey::Plural
+                        | AttributeKey::Custom
+                )
             )
         {
             let variant_ident = owner
@@ -350,6 +405,13 @@ impl AttributeSpec for FluentLabelSpec {
 
 impl AttributeSpec for FluentChoiceSpec {
     const FAMILY: AttributeFamily = AttributeFamily::FluentChoice;
+
+    fn help_for_location(location: AttributeLocation) -> &'static str {
+        if location == AttributeLocation::EnumVariant {
+            return FLUENT_CHOICE_MESSAGE_VARIANT_HELP;
+        }
+        help_for_location(Self::FAMILY, location)
+    }
 }
 
 impl AttributeSpec for LanguageSpec {
@@ -367,7 +429,7 @@ impl AttributeSpec for LanguageSpec {
             span: Some(span),
             note: None,
             help: Some(
-                "use #[es_fluent_language] for builtin mode or #[es_fluent_language(custom)] for custom mode"
+                "use #[es_fluent_language] for builtin mode or #[es_fluent_language(custom)] for custom mode, optionally with locales = [\"en\", ...]"
                     .to_string(),
             ),
         }
@@ -638,17 +700,22 @@ pub(crate) fn attribute_rule(
         .find(|rule| rule.family == family && rule.location == location && rule.key == key)
 }
 
-const FLUENT_STRUCT_HELP: &str = "accepted key here is namespace";
-const FLUENT_ENUM_HELP: &str = "accepted keys here are id, domain, and namespace";
+const FLUENT_STRUCT_HELP: &str = "accepted keys here are namespace, case, doc_as_comment, group, skip_generate, export_keys, as_terms, and attributes";
+const FLUENT_ENUM_HELP: &str = "accepted keys here are id, domain, namespace, case, doc_as_comment, group, skip_generate, export_keys, test_all_keys, as_terms, and attributes";
 const FLUENT_STRUCT_PARENT_HELP: &str = "accepted parent key here is namespace";
 const FLUENT_ENUM_PARENT_HELP: &str = "accepted parent keys here are domain and namespace";
-const FLUENT_FIELD_HELP: &str = "accepted keys here are skip, selector, arg, and value";
-const FLUENT_VARIANT_HELP: &str = "move field-only attributes to a field inside the variant; accepted variant keys are skip and key, but they cannot be combined";
+const FLUENT_FIELD_HELP: &str =
+    "accepted keys here are skip, selector, term, plural, custom, arg, and value";
+const FLUENT_VARIANT_HELP: &str = "move field-only attributes to a field inside the variant; accepted variant keys are skip, key, namespace, and attributes, but skip cannot be combined with the others";
 const VARIANTS_CONTAINER_HELP: &str = "accepted keys here are keys, derive, and namespace";
 const VARIANTS_FIELD_HELP: &str = "accepted key here is skip";
 const LABEL_CONTAINER_HELP: &str = "accepted key here is namespace";
 const CHOICE_CONTAINER_HELP: &str = "accepted key here is rename_all";
-const LANGUAGE_CONTAINER_HELP: &str = "accepted flags here are builtin and custom";
+const CHOICE_VARIANT_HELP: &str = "accepted key here is rename";
+const FLUENT_CHOICE_MESSAGE_VARIANT_HELP: &str =
+    "use #[fluent(key = \"...\")] to change an EsFluent variant's selector value";
+const LANGUAGE_CONTAINER_HELP: &str =
+    "accepted flags here are builtin and custom, plus locales = [\"en\", ...]";
 const LOCALE_FIELD_HELP: &str = "use #[locale] on a named struct field or named enum variant field";
 const LOCALE_TUPLE_FIELD_HELP: &str =
     "move #[locale] to a named struct field or named enum variant field";
@@ -661,6 +728,55 @@ pub(crate) const ATTRIBUTE_RULES: &[AttributeRule] = &[
         shape: AttributeValueShape::NamespaceRule,
         location_help: FLUENT_STRUCT_HELP,
     },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageStructContainer,
+        key: AttributeKey::Case,
+        shape: AttributeValueShape::MessageKeyCase,
+        location_help: FLUENT_STRUCT_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageStructContainer,
+        key: AttributeKey::DocAsComment,
+        shape: AttributeValueShape::Flag,
+        location_help: FLUENT_STRUCT_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageStructContainer,
+        key: AttributeKey::Group,
+        shape: AttributeValueShape::StringLiteral,
+        location_help: FLUENT_STRUCT_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageStructContainer,
+        key: AttributeKey::SkipGenerate,
+        shape: AttributeValueShape::Flag,
+        location_help: FLUENT_STRUCT_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageStructContainer,
+        key: AttributeKey::ExportKeys,
+        shape: AttributeValueShape::Flag,
+        location_help: FLUENT_STRUCT_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageStructContainer,
+        key: AttributeKey::AsTerms,
+        shape: AttributeValueShape::Flag,
+        location_help: FLUENT_STRUCT_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageStructContainer,
+        key: AttributeKey::Attributes,
+        shape: AttributeValueShape::GeneratedKeyList,
+        location_help: FLUENT_STRUCT_HELP,
+    },
     AttributeRule {
         family: AttributeFamily::Fluent,
         location: AttributeLocation::MessageEnumContainer,
@@ -682,6 +798,62 @@ pub(crate) const ATTRIBUTE_RULES: &[AttributeRule] = &[
         shape: AttributeValueShape::NamespaceRule,
         location_help: FLUENT_ENUM_HELP,
     },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageEnumContainer,
+        key: AttributeKey::Case,
+        shape: AttributeValueShape::MessageKeyCase,
+        location_help: FLUENT_ENUM_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageEnumContainer,
+        key: AttributeKey::DocAsComment,
+        shape: AttributeValueShape::Flag,
+        location_help: FLUENT_ENUM_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageEnumContainer,
+        key: AttributeKey::Group,
+        shape: AttributeValueShape::StringLiteral,
+        location_help: FLUENT_ENUM_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageEnumContainer,
+        key: AttributeKey::SkipGenerate,
+        shape: AttributeValueShape::Flag,
+        location_help: FLUENT_ENUM_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageEnumContainer,
+        key: AttributeKey::ExportKeys,
+        shape: AttributeValueShape::Flag,
+        location_help: FLUENT_ENUM_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageEnumContainer,
+        key: AttributeKey::TestAllKeys,
+        shape: AttributeValueShape::Flag,
+        location_help: FLUENT_ENUM_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageEnumContainer,
+        key: AttributeKey::AsTerms,
+        shape: AttributeValueShape::Flag,
+        location_help: FLUENT_ENUM_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageEnumContainer,
+        key: AttributeKey::Attributes,
+        shape: AttributeValueShape::GeneratedKeyList,
+        location_help: FLUENT_ENUM_HELP,
+    },
     AttributeRule {
         family: AttributeFamily::Fluent,
         location: AttributeLocation::LabelStructParentContainer,
@@ -738,6 +910,27 @@ pub(crate) const ATTRIBUTE_RULES: &[AttributeRule] = &[
         shape: AttributeValueShape::Flag,
         location_help: FLUENT_FIELD_HELP,
     },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageField,
+        key: AttributeKey::Term,
+        shape: AttributeValueShape::Flag,
+        location_help: FLUENT_FIELD_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageField,
+        key: AttributeKey::Plural,
+        shape: AttributeValueShape::Flag,
+        location_help: FLUENT_FIELD_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::MessageField,
+        key: AttributeKey::Custom,
+        shape: AttributeValueShape::Flag,
+        location_help: FLUENT_FIELD_HELP,
+    },
     AttributeRule {
         family: AttributeFamily::Fluent,
         location: AttributeLocation::MessageField,
@@ -766,6 +959,20 @@ pub(crate) const ATTRIBUTE_RULES: &[AttributeRule] = &[
         shape: AttributeValueShape::StringLiteral,
         location_help: FLUENT_VARIANT_HELP,
     },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::EnumVariant,
+        key: AttributeKey::Namespace,
+        shape: AttributeValueShape::NamespaceRule,
+        location_help: FLUENT_VARIANT_HELP,
+    },
+    AttributeRule {
+        family: AttributeFamily::Fluent,
+        location: AttributeLocation::EnumVariant,
+        key: AttributeKey::Attributes,
+        shape: AttributeValueShape::GeneratedKeyList,
+        location_help: FLUENT_VARIANT_HELP,
+    },
     AttributeRule {
         family: AttributeFamily::FluentVariants,
         location: AttributeLocation::VariantsContainer,
@@ -815,6 +1022,13 @@ pub(crate) const ATTRIBUTE_RULES: &[AttributeRule] = &[
         shape: AttributeValueShape::ChoiceCaseStyle,
         location_help: CHOICE_CONTAINER_HELP,
     },
+    AttributeRule {
+        family: AttributeFamily::FluentChoice,
+        location: AttributeLocation::ChoiceVariant,
+        key: AttributeKey::Rename,
+        shape: AttributeValueShape::StringLiteral,
+        location_help: CHOICE_VARIANT_HELP,
+    },
     AttributeRule {
         family: AttributeFamily::EsFluentLanguage,
         location: AttributeLocation::LanguageContainer,
@@ -829,6 +1043,13 @@ pub(crate) const ATTRIBUTE_RULES: &[AttributeRule] = &[
         shape: AttributeValueShape::Flag,
         location_help: LANGUAGE_CONTAINER_HELP,
     },
+    AttributeRule {
+        family: AttributeFamily::EsFluentLanguage,
+        location: AttributeLocation::LanguageContainer,
+        key: AttributeKey::Locales,
+        shape: AttributeValueShape::LocaleList,
+        location_help: LANGUAGE_CONTAINER_HELP,
+    },
     AttributeRule {
         family: AttributeFamily::Locale,
         location: AttributeLocation::LocaleNamedStructField,
@@ -845,26 +1066,38 @@ pub(crate) const ATTRIBUTE_RULES: &[AttributeRule] = &[
     },
 ];
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum LanguageMode {
+    #[default]
     Builtin,
     Custom,
 }
 
 impl LanguageMode {
+    pub fn is_custom(self) -> bool {
+        matches!(self, Self::Custom)
+    }
+}
+
+const LANGUAGE_ARGS_HELP: &str =
+    "#[es_fluent_language] expects no arguments, `builtin`, `custom`, or `locales = [...]`";
+
+/// Parsed `#[es_fluent_language(...)]` arguments.
+#[derive(Clone, Debug, Default)]
+pub struct LanguageArgs {
+    mode: LanguageMode,
+    locales: Option<Vec<LitStr>>,
+}
+
+impl LanguageArgs {
     pub fn parse(attr: proc_macro2::TokenStream) -> EsFluentCoreResult<Self> {
         if attr.is_empty() {
-            return Ok(Self::Builtin);
+            return Ok(Self::default());
         }
 
         let items = Punctuated::<Meta, Token![,]>::parse_terminated
             .parse2(attr)
-            .map_err(|err| {
-                language_attr_error(
-                    "#[es_fluent_language] expects no arguments, `builtin`, or `custom`",
-                    Some(err.span()),
-                )
-            })?;
+            .map_err(|err| language_attr_error(LANGUAGE_ARGS_HELP, Some(err.span())))?;
 
         AttributeSet::<LanguageSpec>::validate_items(
             items.iter(),
@@ -872,30 +1105,75 @@ impl LanguageMode {
             None,
         )?;
 
-        if items.is_empty() {
-            return Ok(Self::Builtin);
+        let mut args = Self::default();
+        let mut has_mode = false;
+        for item in &items {
+            match item {
+                Meta::Path(path) if path.is_ident("builtin") || path.is_ident("custom") => {
+                    if has_mode {
+                        return Err(language_attr_error(
+                            "#[es_fluent_language] accepts at most one mode flag",
+                            Some(item.span()),
+                        ));
+                    }
+                    has_mode = true;
+                    args.mode = if path.is_ident("custom") {
+                        LanguageMode::Custom
+                    } else {
+                        LanguageMode::Builtin
+                    };
+                },
+                Meta::NameValue(name_value) if name_value.path.is_ident("locales") => {
+                    args.locales = Some(parse_locale_list(&name_value.value)?);
+                },
+                other => return Err(language_attr_error(LANGUAGE_ARGS_HELP, Some(other.span()))),
+            }
         }
 
-        if let Some(extra) = items.iter().nth(1) {
-            return Err(language_attr_error(
-                "#[es_fluent_language] accepts at most one mode flag",
-                Some(extra.span()),
-            ));
-        }
+        Ok(args)
+    }
+
+    pub fn mode(&self) -> LanguageMode {
+        self.mode
+    }
+
+    /// Returns the explicit `locales = [...]` list, if one was given.
+    pub fn locales(&self) -> Option<&[LitStr]> {
+        self.locales.as_deref()
+    }
+}
 
-        match items.first() {
-            Some(Meta::Path(path)) if path.is_ident("builtin") => Ok(Self::Builtin),
-            Some(Meta::Path(path)) if path.is_ident("custom") => Ok(Self::Custom),
+fn parse_locale_list(expr: &Expr) -> EsFluentCoreResult<Vec<LitStr>> {
+    let Expr::Array(array) = expr else {
+        return Err(language_attr_error(
+            "`locales` expects a string array, for example `locales = [\"en\", \"fr\"]`",
+            Some(expr.span()),
+        ));
+    };
+
+    let locales = array
+        .elems
+        .iter()
+        .map(|elem| match elem {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(locale),
+                ..
+            }) => Ok(locale.clone()),
             other => Err(language_attr_error(
-                "#[es_fluent_language] expects no arguments, `builtin`, or `custom`",
-                other.map(|meta| meta.span()),
+                "`locales` expects string literals, for example `locales = [\"en\", \"fr\"]`",
+                Some(other.span()),
             )),
-        }
-    }
+        })
+        .collect::<EsFluentCoreResult<Vec<_>>>()?;
 
-    pub fn is_custom(self) -> bool {
-        matches!(self, Self::Custom)
+    if locales.is_empty() {
+        return Err(language_attr_error(
+            "`locales` must list at least one locale",
+            Some(array.span()),
+        ));
     }
+
+    Ok(locales)
 }
 
 fn language_attr_error(message: impl Into<String>, span: Option<Span>) -> EsFluentCoreError {
@@ -1026,16 +1304,28 @@ mod tests {
             AttributeKey::Arg,
             AttributeKey::Value,
             AttributeKey::Selector,
+            AttributeKey::Term,
+            AttributeKey::Plural,
             AttributeKey::Skip,
             AttributeKey::Key,
             AttributeKey::Id,
             AttributeKey::Domain,
             AttributeKey::Namespace,
+            AttributeKey::Case,
+            AttributeKey::DocAsComment,
+            AttributeKey::Group,
+            AttributeKey::SkipGenerate,
+            AttributeKey::ExportKeys,
+            AttributeKey::TestAllKeys,
+            AttributeKey::AsTerms,
+            AttributeKey::Attributes,
             AttributeKey::Derive,
             AttributeKey::Keys,
             AttributeKey::RenameAll,
+            AttributeKey::Rename,
             AttributeKey::Builtin,
             AttributeKey::Custom,
+            AttributeKey::Locales,
         ] {
             assert_eq!(AttributeValueShape::for_key(key), shapes[&key]);
         }
@@ -1064,4 +1354,31 @@ mod tests {
             AttributeLocation::LanguageContainer
         ));
     }
+
+    #[test]
+    fn language_args_parse_mode_and_explicit_locales() {
+        let default = LanguageArgs::parse(proc_macro2::TokenStream::new()).expect("no args");
+        assert_eq!(default.mode(), LanguageMode::Builtin);
+        assert!(default.locales().is_none());
+
+        let args = LanguageArgs::parse(quote::quote!(custom, locales = ["en", "fr-CA"]))
+            .expect("custom with locales");
+        assert_eq!(args.mode(), LanguageMode::Custom);
+        let locales = args
+            .locales()
+            .expect("explicit locales")
+            .iter()
+            .map(LitStr::value)
+            .collect::<Vec<_>>();
+        assert_eq!(locales, ["en", "fr-CA"]);
+
+        for (attr, message) in [
+            (quote::quote!(locales = []), "must list at least one locale"),
+            (quote::quote!(locales = [en]), "expects string literals"),
+            (quote::quote!(builtin, custom), "at most one mode flag"),
+        ] {
+            let error = LanguageArgs::parse(attr).expect_err("invalid language args");
+            assert!(error.to_string().contains(message), "{error}");
+        }
+    }
 }
@@ -3,19 +3,21 @@
 use crate::{
     error::{AttrContext, AttrError, EsFluentCoreError, EsFluentCoreResult},
     options::{
-        EnumDataOptions as _, FilteredEnumDataOptions as _, FluentField, SkipDirective as _,
-        Skippable as _, StructDataOptions as _, VariantFields as _,
+        EnumDataOptions as _, FieldArgumentDirective, FieldValueDirective,
+        FilteredEnumDataOptions as _, FluentField, SkipDirective as _, Skippable as _,
+        StructDataOptions as _, VariantFields as _,
         choice::ChoiceOpts,
         r#enum::{EnumOpts, EnumVariantsOpts, VariantOpts},
         label::LabelOpts,
         r#struct::{StructFieldOpts, StructOpts, StructVariantsOpts},
     },
     semantic::{
-        ArgumentValueStrategy, FluentMessageId, SpannedValue, label_message_id_for_ident,
-        message_id_for_ident, variant_message_id,
+        ArgumentValueStrategy, FluentMessageId, SpannedValue, VariantKey,
+        label_message_id_for_ident, message_id_for_ident, parse_variant_key_in_context,
+        variant_message_id,
     },
 };
-use es_fluent_shared::meta::TypeKind;
+use es_fluent_shared::{meta::TypeKind, namer::KeyCase};
 
 pub use crate::index::{
     DeclarationIndex, ExposedArgumentIndex, FieldArgumentIndex, TupleFieldIndex,
@@ -38,7 +40,11 @@ impl<'a> MessageStructModel<'a> {
         };
 
         Ok(Self {
-            message_id: message_id_for_ident(opts.ident(), AttrContext::MessageContainer)?,
+            message_id: message_id_for_ident(
+                opts.ident(),
+                opts.attr_args().key_case(),
+                AttrContext::MessageContainer,
+            )?,
             fields,
         })
     }
@@ -138,9 +144,10 @@ impl<'a> MessageEnumModel<'a> {
         let base_key = opts
             .base_message_id(AttrContext::MessageContainer)?
             .into_value();
+        let case = opts.attr_args().key_case();
         let variants = variants
             .iter()
-            .map(|variant| MessageEnumVariant::from_options(variant, &base_key))
+            .map(|variant| MessageEnumVariant::from_options(variant, &base_key, case))
             .collect::<EsFluentCoreResult<Vec<_>>>()?;
 
         Ok(Self { variants })
@@ -182,6 +189,7 @@ impl<'a> MessageEnumVariant<'a> {
     fn from_options(
         variant_opt: &'a VariantOpts,
         base_key: &FluentMessageId,
+        case: Option<KeyCase>,
     ) -> EsFluentCoreResult<Self> {
         let ident = variant_opt.ident();
         let skipped = variant_opt.directive().is_skipped();
@@ -190,6 +198,7 @@ impl<'a> MessageEnumVariant<'a> {
             base_key,
             ident,
             variant_key.as_ref().map(|key| key.value()),
+            case,
             AttrContext::MessageContainer,
         )?;
 
@@ -523,16 +532,30 @@ impl<'a> ChoiceModel<'a> {
         let variants = variants
             .iter()
             .map(|variant| {
-                if !matches!(variant.fields, syn::Fields::Unit) {
+                if !variant.fields().is_unit() {
                     return Err(internal_shape_error(
                         AttrContext::ChoiceContainer,
                         "EsFluentChoice variants must be unit variants",
-                        variant.ident.span(),
+                        variant.ident().span(),
                     ));
                 }
 
+                let rename = variant
+                    .rename()
+                    .as_ref()
+                    .map(|rename| {
+                        parse_variant_key_in_context(
+                            rename.value(),
+                            rename.span(),
+                            AttrContext::ChoiceVariant,
+                        )
+                        .map(|key| SpannedValue::new(key, rename.span()))
+                    })
+                    .transpose()?;
+
                 Ok(ChoiceVariant {
-                    ident: &variant.ident,
+                    ident: variant.ident(),
+                    rename,
                 })
             })
             .collect::<EsFluentCoreResult<Vec<_>>>()?;
@@ -552,15 +575,21 @@ impl<'a> ChoiceModel<'a> {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct ChoiceVariant<'a> {
     ident: &'a syn::Ident,
+    rename: Option<SpannedValue<VariantKey>>,
 }
 
 impl<'a> ChoiceVariant<'a> {
     pub fn ident(&self) -> &'a syn::Ident {
         self.ident
     }
+
+    /// The `#[fluent_choice(rename = "...")]` value, if any.
+    pub fn rename(&self) -> Option<&SpannedValue<VariantKey>> {
+        self.rename.as_ref()
+    }
 }
 
 pub fn field_value_strategy(
@@ -583,10 +612,21 @@ fn field_argument_model(
 ) -> EsFluentCoreResult<crate::semantic::ArgumentModel> {
     let value_strategy = field_value_strategy(field, span)?;
     let name = field.fluent_arg_name(index, AttrContext::MessageField)?;
-    Ok(crate::semantic::ArgumentModel::new_with_value_strategy(
-        name,
-        value_strategy,
-    ))
+    // A `value = ...` transform replaces the field, so only its declared
+    // output type describes the argument.
+    let rust_type = match field
+        .directive()
+        .argument()
+        .map(FieldArgumentDirective::value)
+    {
+        Some(FieldValueDirective::Transform(transform)) => transform.output_type(),
+        _ => Some(field.ty()),
+    };
+    Ok(
+        crate::semantic::ArgumentModel::new_with_value_strategy(name, value_strategy)
+            .with_plural(field.is_plural())
+            .with_rust_type(rust_type),
+    )
 }
 
 fn internal_shape_error(
@@ -640,6 +680,41 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn transformed_fields_record_the_closure_output_type() {
+        let input: syn::DeriveInput = parse_quote! {
+            struct Basket {
+                plain: Vec<String>,
+                #[fluent(value = |items: &Vec<String>| -> usize { items.len() })]
+                declared: Vec<String>,
+                #[fluent(value = |items: &Vec<String>| items.len())]
+                inferred: Vec<String>,
+            }
+        };
+        let opts = StructOpts::from_derive_input(&input).expect("struct opts");
+        let model = MessageStructModel::from_options(&opts).expect("message model");
+        let rust_types: Vec<_> = model
+            .fields()
+            .iter()
+            .map(|field| {
+                field
+                    .argument_model()
+                    .expect("argument")
+                    .rust_type()
+                    .map(str::to_owned)
+            })
+            .collect();
+
+        assert_eq!(
+            rust_types,
+            [
+                Some("Vec<String>".to_string()),
+                Some("usize".to_string()),
+                None
+            ]
+        );
+    }
+
     #[test]
     fn message_struct_model_preserves_declaration_indexes_for_skipped_tuple_fields() {
         let input: syn::DeriveInput = parse_quote! {
@@ -5,7 +5,7 @@ use proc_macro2::TokenStream;
 use crate::{
     attribute::{AttributeLocation, AttributeName, validate_attribute_for_location},
     error::EsFluentCoreError,
-    grammar::LanguageMode,
+    grammar::LanguageArgs,
 };
 
 /// Validated inputs for attribute-like and argument-free macros.
@@ -13,8 +13,8 @@ pub struct ValidatedMacroInput;
 
 impl ValidatedMacroInput {
     /// Parses and validates `#[es_fluent_language(...)]` arguments.
-    pub fn language_mode(attr: TokenStream) -> Result<LanguageMode, EsFluentCoreError> {
-        LanguageMode::parse(attr)
+    pub fn language_args(attr: TokenStream) -> Result<LanguageArgs, EsFluentCoreError> {
+        LanguageArgs::parse(attr)
     }
 
     /// Returns true when `attr` is a valid bare `#[locale]` marker for `location`.
@@ -1,4 +1,4 @@
-use darling::{FromDeriveInput, FromMeta};
+use darling::{FromDeriveInput, FromMeta, FromVariant};
 use getset::Getters;
 use heck::{
     ToKebabCase as _, ToLowerCamelCase as _, ToPascalCase as _, ToShoutyKebabCase as _,
@@ -13,11 +13,23 @@ use strum::{Display, EnumIter, EnumString, IntoStaticStr};
 pub struct ChoiceOpts {
     ident: syn::Ident,
     generics: syn::Generics,
-    data: darling::ast::Data<syn::Variant, darling::util::Ignored>,
+    data: darling::ast::Data<ChoiceVariantOpts, darling::util::Ignored>,
     #[darling(flatten)]
     attr_args: ChoiceAttributeArgs,
 }
 
+/// Options for one `EsFluentChoice` variant.
+#[derive(Clone, Debug, FromVariant, Getters)]
+#[darling(attributes(fluent_choice))]
+#[getset(get = "pub")]
+pub struct ChoiceVariantOpts {
+    ident: syn::Ident,
+    fields: darling::ast::Fields<darling::util::Ignored>,
+    /// Choice value used instead of the `rename_all`-cased variant name.
+    #[darling(default)]
+    rename: Option<syn::LitStr>,
+}
+
 #[derive(Default, FromMeta, Getters)]
 #[getset(get = "pub")]
 pub struct ChoiceAttributeArgs {
@@ -178,7 +190,8 @@ mod tests {
         let darling::ast::Data::Enum(variants) = &mut opts.data else {
             panic!("expected enum data");
         };
-        variants[0].fields = syn::Fields::Unnamed(parse_quote!((u8)));
+        variants[0].fields =
+            darling::ast::Fields::new(darling::ast::Style::Tuple, vec![darling::util::Ignored]);
 
         let err = crate::lowered::ChoiceModel::from_options(&opts)
             .expect_err("lowering rejects non-unit variants");
@@ -4,6 +4,7 @@ use crate::options::{
 };
 use crate::{
     error::{AttrContext, EsFluentCoreResult},
+    namespace::SpannedNamespaceRule,
     semantic::{
         DomainName, FluentMessageId, SpannedValue, VariantKey, spanned_message_id_from_value,
     },
@@ -21,6 +22,9 @@ pub struct VariantOpts {
     ident: syn::Ident,
     fields: darling::ast::Fields<super::FluentFieldOpts>,
     directive: MessageVariantDirective,
+    namespace: Option<SpannedNamespaceRule>,
+    attributes: Option<super::MessageAttributeList>,
+    doc: Option<String>,
 }
 
 #[derive(Clone, Debug, FromVariant, Getters)]
@@ -43,11 +47,26 @@ impl FromVariant for VariantOpts {
             )
             .with_span(variant));
         }
+        if raw.attr_args.is_skipped() && raw.attr_args.namespace_spec().is_some() {
+            return Err(darling::Error::custom(
+                "Cannot use #[fluent(namespace = ...)] on a skipped variant",
+            )
+            .with_span(variant));
+        }
+        if raw.attr_args.is_skipped() && raw.attr_args.attributes().is_some() {
+            return Err(darling::Error::custom(
+                "Cannot use #[fluent(attributes = [...])] on a skipped variant",
+            )
+            .with_span(variant));
+        }
 
         Ok(Self {
             ident: raw.ident,
             fields: raw.fields,
             directive: raw.attr_args.directive(),
+            namespace: raw.attr_args.namespace_spec().cloned(),
+            attributes: raw.attr_args.attributes().cloned(),
+            doc: super::doc_comment(&variant.attrs),
         })
     }
 }
@@ -64,6 +83,21 @@ impl VariantOpts {
     pub fn directive(&self) -> &MessageVariantDirective {
         &self.directive
     }
+
+    /// Returns the namespace overriding the enum's for this variant's key.
+    pub fn namespace(&self) -> Option<&SpannedNamespaceRule> {
+        self.namespace.as_ref()
+    }
+
+    /// Returns the message attributes overriding the enum's for this variant.
+    pub fn attributes(&self) -> Option<&super::MessageAttributeList> {
+        self.attributes.as_ref()
+    }
+
+    /// Returns the variant's doc comment, if any.
+    pub fn doc(&self) -> Option<&str> {
+        self.doc.as_deref()
+    }
 }
 
 impl VariantFields for VariantOpts {
@@ -113,7 +147,8 @@ impl EnumOpts {
         }
 
         spanned_message_id_from_value(
-            namer::FluentKey::from(self.ident()).to_string(),
+            namer::FluentKey::from_ident_with_case(self.ident(), self.attr_args().key_case())
+                .to_string(),
             self.ident().span(),
             context,
         )
@@ -137,6 +172,30 @@ pub struct FluentEnumAttributeArgs {
     domain: Option<SpannedValue<DomainName>>,
     #[darling(flatten)]
     namespace_args: super::NamespacedAttributeArgs,
+    /// Optional casing for the enum and variant segments of message ids.
+    #[darling(default)]
+    case: Option<namer::KeyCase>,
+    /// Whether variant doc comments become FTL message comments.
+    #[darling(default)]
+    doc_as_comment: bool,
+    /// Optional `## Group` header text used instead of the type name.
+    #[darling(default)]
+    group: Option<super::GroupLabel>,
+    /// Whether generation skips the type, leaving its keys to hand-authored FTL.
+    #[darling(default)]
+    skip_generate: bool,
+    /// Whether the derive emits an `ALL_FTL_KEYS` associated const.
+    #[darling(default)]
+    export_keys: bool,
+    /// Whether the derive emits an `__assert_all_ftl_keys_present` function.
+    #[darling(default)]
+    test_all_keys: bool,
+    /// Whether the type's entries are generated as Fluent terms.
+    #[darling(default)]
+    as_terms: bool,
+    /// Message attributes scaffolded on every variant's entry.
+    #[darling(default)]
+    attributes: Option<super::MessageAttributeList>,
 }
 
 impl FluentEnumAttributeArgs {
@@ -164,6 +223,46 @@ impl FluentEnumAttributeArgs {
     pub fn namespace_span(&self) -> Option<proc_macro2::Span> {
         self.namespace_args.namespace_span()
     }
+
+    /// Returns the message id casing if provided.
+    pub fn key_case(&self) -> Option<namer::KeyCase> {
+        self.case
+    }
+
+    /// Returns whether variant doc comments are emitted as FTL comments.
+    pub fn doc_as_comment(&self) -> bool {
+        self.doc_as_comment
+    }
+
+    /// Returns the group header text if provided.
+    pub fn group(&self) -> Option<&super::GroupLabel> {
+        self.group.as_ref()
+    }
+
+    /// Returns whether FTL generation skips the type.
+    pub fn skip_generate(&self) -> bool {
+        self.skip_generate
+    }
+
+    /// Returns whether the derive exports the type's message ids.
+    pub fn export_keys(&self) -> bool {
+        self.export_keys
+    }
+
+    /// Returns whether the derive emits a translation completeness check.
+    pub fn test_all_keys(&self) -> bool {
+        self.test_all_keys
+    }
+
+    /// Returns whether generation writes the type's entries as Fluent terms.
+    pub fn as_terms(&self) -> bool {
+        self.as_terms
+    }
+
+    /// Returns the message attributes applied to every variant if provided.
+    pub fn attributes(&self) -> Option<&super::MessageAttributeList> {
+        self.attributes.as_ref()
+    }
 }
 
 /// Options for an enum variant in EsFluentVariants context.
@@ -374,6 +473,19 @@ mod tests {
                 .contains("Cannot use #[fluent(key = \"...\")] on a skipped variant")
         );
 
+        let invalid_input: DeriveInput = parse_quote! {
+            enum Invalid {
+                #[fluent(skip, namespace = "hidden")]
+                Hidden,
+            }
+        };
+        let err = EnumOpts::from_derive_input(&invalid_input)
+            .expect_err("skip and namespace should conflict");
+        assert!(
+            err.to_string()
+                .contains("Cannot use #[fluent(namespace = ...)] on a skipped variant")
+        );
+
         let no_resource_input: DeriveInput = parse_quote! {
             enum HttpStatus {
                 Ok
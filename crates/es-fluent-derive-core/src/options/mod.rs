@@ -5,8 +5,9 @@ use crate::index::{DeclarationIndex, FieldArgumentIndex};
 use crate::namespace::SpannedNamespaceRule;
 use crate::semantic::{
     ArgName, ArgumentValueStrategy, DomainName, FluentMessageId, GeneratedKeyIdent,
-    GeneratedKeyName, SpannedValue, ValueTransform, VariantKey, parse_arg_name_in_context,
-    parse_domain_name_in_context, parse_fluent_message_id_in_context, parse_variant_key_in_context,
+    GeneratedKeyName, MessageAttributeName, SpannedValue, ValueTransform, VariantKey,
+    parse_arg_name_in_context, parse_domain_name_in_context, parse_fluent_message_id_in_context,
+    parse_message_attribute_name_in_context, parse_variant_key_in_context,
 };
 use bon::Builder;
 use darling::{FromField, FromMeta};
@@ -108,6 +109,30 @@ impl FromMeta for SpannedValue<DomainName> {
     }
 }
 
+/// Text of the `## Group` header written above a type's generated entries.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupLabel(String);
+
+impl GroupLabel {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromMeta for GroupLabel {
+    fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+        let (value, _) = string_literal_value(item)?;
+        let value = value.trim();
+        if value.is_empty() || value.contains(['\n', '\r']) {
+            return Err(
+                darling::Error::custom("group label must be a non-empty single line")
+                    .with_span(item),
+            );
+        }
+        Ok(Self(value.to_string()))
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct GeneratedKeyList {
     keys: Vec<SpannedValue<GeneratedKeyName>>,
@@ -177,6 +202,69 @@ impl FromMeta for GeneratedKeyList {
     }
 }
 
+impl FromMeta for SpannedValue<MessageAttributeName> {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        let syn::Lit::Str(value) = value else {
+            return Err(darling::Error::unexpected_lit_type(value));
+        };
+        let name = parse_message_attribute_name_in_context(
+            value.value(),
+            value.span(),
+            AttrContext::MessageContainer,
+        )
+        .map_err(|error| darling::Error::custom(error.to_string()).with_span(value))?;
+        Ok(SpannedValue::new(name, value.span()))
+    }
+}
+
+/// Message attribute names from `#[fluent(attributes = [...])]`.
+#[derive(Clone, Debug, Default)]
+pub struct MessageAttributeList {
+    names: Vec<SpannedValue<MessageAttributeName>>,
+}
+
+impl MessageAttributeList {
+    pub fn as_slice(&self) -> &[SpannedValue<MessageAttributeName>] {
+        &self.names
+    }
+
+    /// Returns the attribute names without their spans.
+    pub fn names(&self) -> Vec<MessageAttributeName> {
+        self.names.iter().map(|name| name.value().clone()).collect()
+    }
+}
+
+impl FromMeta for MessageAttributeList {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        let expr_array = syn::ExprArray::from_value(value)?;
+        Self::from_expr(&syn::Expr::Array(expr_array))
+    }
+
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        match expr {
+            syn::Expr::Array(expr_array) => {
+                let mut seen = std::collections::HashSet::new();
+                let mut names = Vec::with_capacity(expr_array.elems.len());
+                for elem in &expr_array.elems {
+                    let name = <SpannedValue<MessageAttributeName> as FromMeta>::from_expr(elem)?;
+                    if !seen.insert(name.value().clone()) {
+                        return Err(darling::Error::custom(format!(
+                            "duplicate attribute '{}' in #[fluent(attributes = [...])]",
+                            name.value().as_str()
+                        ))
+                        .with_span(elem));
+                    }
+                    names.push(name);
+                }
+                Ok(Self { names })
+            },
+            syn::Expr::Lit(expr_lit) => Self::from_value(&expr_lit.lit),
+            syn::Expr::Group(group) => Self::from_expr(&group.expr),
+            _ => Err(darling::Error::unexpected_expr_type(expr)),
+        }
+    }
+}
+
 pub fn keyed_variant_idents(
     ident: &syn::Ident,
     keys: Option<&[SpannedValue<GeneratedKeyName>]>,
@@ -211,6 +299,42 @@ pub fn variants_enum_ident(ident: &syn::Ident, suffix: &str) -> syn::Ident {
     )
 }
 
+/// Joins the `///` lines of an item into one doc string.
+///
+/// Strips the single space rustdoc keeps after `///`, trailing whitespace,
+/// and leading or trailing blank lines. Returns `None` for undocumented items.
+pub fn doc_comment(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(value),
+                        ..
+                    }),
+                ..
+            }) => Some(value.value()),
+            _ => None,
+        })
+        .flat_map(|doc| {
+            doc.split('\n')
+                .map(|line| {
+                    line.strip_prefix(' ')
+                        .unwrap_or(line)
+                        .trim_end()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let first = lines.iter().position(|line| !line.is_empty())?;
+    let last = lines.iter().rposition(|line| !line.is_empty())?;
+
+    Some(lines[first..=last].join("\n"))
+}
+
 pub fn collect_items<T>(items: &[T]) -> Vec<&T> {
     items.iter().collect()
 }
@@ -466,6 +590,13 @@ pub trait FluentField {
         self.directive().argument_value_strategy(span)
     }
 
+    /// Returns `true` if generated FTL selects on this field's plural category.
+    fn is_plural(&self) -> bool {
+        self.directive()
+            .argument()
+            .is_some_and(FieldArgumentDirective::is_plural)
+    }
+
     /// Returns the explicit field argument name as a typed value if provided.
     fn arg_name(&self) -> Option<&SpannedValue<ArgName>> {
         self.directive().arg_name()
@@ -586,6 +717,15 @@ struct FluentFieldAttributeArgs {
     /// Whether this field is a selector for a Fluent select expression.
     #[darling(default)]
     selector: Option<PresentFlag>,
+    /// Whether this field's `EsFluent` type is linked from generated FTL as terms.
+    #[darling(default)]
+    term: Option<PresentFlag>,
+    /// Whether generated FTL scaffolds a plural select on this field.
+    #[darling(default)]
+    plural: Option<PresentFlag>,
+    /// Whether this field is passed to Fluent as a custom `FluentType` value.
+    #[darling(default)]
+    custom: Option<PresentFlag>,
     /// A value transformation expression.
     #[darling(default)]
     value: Option<ValueAttr>,
@@ -603,6 +743,18 @@ impl FluentFieldAttributeArgs {
         self.selector.is_some_and(PresentFlag::is_present)
     }
 
+    fn is_term(&self) -> bool {
+        self.term.is_some_and(PresentFlag::is_present)
+    }
+
+    fn is_plural(&self) -> bool {
+        self.plural.is_some_and(PresentFlag::is_present)
+    }
+
+    fn is_custom(&self) -> bool {
+        self.custom.is_some_and(PresentFlag::is_present)
+    }
+
     fn value(&self) -> Option<&syn::Expr> {
         self.value.as_ref().map(|value| &value.0)
     }
@@ -614,6 +766,8 @@ impl FluentFieldAttributeArgs {
     ) -> EsFluentCoreResult<FieldDirective> {
         let is_skipped = self.is_skipped();
         let is_selector = self.is_selector();
+        let is_term = self.is_term();
+        let is_plural = self.is_plural();
         let has_value = self.value().is_some();
         let has_arg = self.arg.is_some();
 
@@ -630,6 +784,24 @@ impl FluentFieldAttributeArgs {
                     span,
                 ));
             }
+            if is_term {
+                return Err(field_strategy_error(
+                    "Cannot use #[fluent(term)] on a skipped field",
+                    span,
+                ));
+            }
+            if is_plural {
+                return Err(field_strategy_error(
+                    "Cannot use #[fluent(plural)] on a skipped field",
+                    span,
+                ));
+            }
+            if self.is_custom() {
+                return Err(field_strategy_error(
+                    "Cannot use #[fluent(custom)] on a skipped field",
+                    span,
+                ));
+            }
             if has_value {
                 return Err(field_strategy_error(
                     "Cannot use #[fluent(value = ...)] on a skipped field",
@@ -640,6 +812,35 @@ impl FluentFieldAttributeArgs {
             return Ok(FieldDirective::Skip);
         }
 
+        if is_plural && (is_selector || is_term) {
+            return Err(field_strategy_error(
+                "Cannot combine #[fluent(plural)] with #[fluent(selector)] or #[fluent(term)] on the same field",
+                span,
+            ));
+        }
+
+        if is_plural && self.is_custom() {
+            return Err(field_strategy_error(
+                "Cannot combine #[fluent(plural)] with #[fluent(custom)]: custom Fluent values have no plural category",
+                span,
+            ));
+        }
+
+        Ok(FieldDirective::Argument(Box::new(FieldArgumentDirective {
+            name: self.arg.clone(),
+            value: self.value_directive(ty, span)?,
+            plural: is_plural,
+        })))
+    }
+
+    fn value_directive(
+        &self,
+        ty: &syn::Type,
+        span: proc_macro2::Span,
+    ) -> EsFluentCoreResult<FieldValueDirective> {
+        let is_selector = self.is_selector();
+        let has_value = self.value().is_some();
+
         if is_selector && has_value {
             return Err(field_strategy_error(
                 "Cannot combine #[fluent(selector)] and #[fluent(value = ...)] on the same field",
@@ -647,50 +848,72 @@ impl FluentFieldAttributeArgs {
             ));
         }
 
+        if self.is_custom() {
+            if is_selector || self.is_term() || has_value {
+                return Err(field_strategy_error(
+                    "Cannot combine #[fluent(custom)] with #[fluent(selector)], #[fluent(term)], or #[fluent(value = ...)] on the same field",
+                    span,
+                ));
+            }
+            if option_inner_type(ty).is_some() {
+                return Err(field_strategy_error(
+                    "Cannot use #[fluent(custom)] on an Option field",
+                    span,
+                ));
+            }
+
+            return Ok(FieldValueDirective::Custom { span });
+        }
+
+        if self.is_term() {
+            if is_selector || has_value {
+                return Err(field_strategy_error(
+                    "Cannot combine #[fluent(term)] with #[fluent(selector)] or #[fluent(value = ...)] on the same field",
+                    span,
+                ));
+            }
+            if option_inner_type(ty).is_some() {
+                return Err(field_strategy_error(
+                    "Cannot use #[fluent(term)] on an Option field",
+                    span,
+                ));
+            }
+
+            return Ok(FieldValueDirective::Term {
+                span,
+                ty: ty.clone(),
+            });
+        }
+
         if is_selector {
             if let Some(inner_ty) = option_inner_type(ty) {
-                return Ok(FieldDirective::Argument(Box::new(FieldArgumentDirective {
-                    name: self.arg.clone(),
-                    value: FieldValueDirective::OptionalChoice {
-                        span: ty.span(),
-                        inner_ty: inner_ty.clone(),
-                    },
-                })));
+                return Ok(FieldValueDirective::OptionalChoice {
+                    span: ty.span(),
+                    inner_ty: inner_ty.clone(),
+                });
             }
 
-            return Ok(FieldDirective::Argument(Box::new(FieldArgumentDirective {
-                name: self.arg.clone(),
-                value: FieldValueDirective::Choice {
-                    span,
-                    ty: ty.clone(),
-                },
-            })));
+            return Ok(FieldValueDirective::Choice {
+                span,
+                ty: ty.clone(),
+            });
         }
 
         if let Some(expr) = self.value() {
-            return Ok(FieldDirective::Argument(Box::new(FieldArgumentDirective {
-                name: self.arg.clone(),
-                value: FieldValueDirective::Transform(ValueTransform::new(
-                    expr.clone(),
-                    expr.span(),
-                )),
-            })));
+            return Ok(FieldValueDirective::Transform(ValueTransform::new(
+                expr.clone(),
+                expr.span(),
+            )));
         }
 
         if let Some(inner_ty) = option_inner_type(ty) {
-            return Ok(FieldDirective::Argument(Box::new(FieldArgumentDirective {
-                name: self.arg.clone(),
-                value: FieldValueDirective::Optional {
-                    span: ty.span(),
-                    inner_ty: inner_ty.clone(),
-                },
-            })));
+            return Ok(FieldValueDirective::Optional {
+                span: ty.span(),
+                inner_ty: inner_ty.clone(),
+            });
         }
 
-        Ok(FieldDirective::Argument(Box::new(FieldArgumentDirective {
-            name: self.arg.clone(),
-            value: FieldValueDirective::Borrowed { span },
-        })))
+        Ok(FieldValueDirective::Borrowed { span })
     }
 }
 
@@ -737,6 +960,7 @@ impl FieldDirective {
 pub struct FieldArgumentDirective {
     name: Option<SpannedValue<ArgName>>,
     value: FieldValueDirective,
+    plural: bool,
 }
 
 impl FieldArgumentDirective {
@@ -744,6 +968,11 @@ impl FieldArgumentDirective {
         self.name.as_ref()
     }
 
+    /// Whether generated FTL selects on this argument's CLDR plural category.
+    pub fn is_plural(&self) -> bool {
+        self.plural
+    }
+
     pub fn value(&self) -> &FieldValueDirective {
         &self.value
     }
@@ -769,6 +998,13 @@ pub enum FieldValueDirective {
         span: proc_macro2::Span,
         inner_ty: syn::Type,
     },
+    /// Borrow an `EsFluent` field value and link its messages as Fluent terms.
+    Term {
+        span: proc_macro2::Span,
+        ty: syn::Type,
+    },
+    /// Box the field's `FluentType` into a custom Fluent value.
+    Custom { span: proc_macro2::Span },
     /// Apply an explicit field-level transform expression.
     Transform(ValueTransform),
 }
@@ -789,6 +1025,11 @@ impl FieldValueDirective {
                 span: *span,
                 ty: Box::new(inner_ty.clone()),
             },
+            Self::Term { span, ty } => ArgumentValueStrategy::Term {
+                span: *span,
+                ty: Box::new(ty.clone()),
+            },
+            Self::Custom { span } => ArgumentValueStrategy::Custom { span: *span },
             Self::Transform(transform) => {
                 ArgumentValueStrategy::Transform(Box::new(transform.clone()))
             },
@@ -960,6 +1201,12 @@ struct KeyedVariantAttributeArgs {
     /// Overrides the localization key suffix for this variant.
     #[darling(default)]
     key: Option<SpannedValue<VariantKey>>,
+    /// Routes this variant's key to another FTL file than the enum's.
+    #[darling(default)]
+    namespace: Option<SpannedNamespaceRule>,
+    /// Message attributes replacing the enum's for this variant.
+    #[darling(default)]
+    attributes: Option<MessageAttributeList>,
 }
 
 impl KeyedVariantAttributeArgs {
@@ -974,6 +1221,14 @@ impl KeyedVariantAttributeArgs {
         self.key.as_ref()
     }
 
+    pub(super) fn namespace_spec(&self) -> Option<&SpannedNamespaceRule> {
+        self.namespace.as_ref()
+    }
+
+    pub(super) fn attributes(&self) -> Option<&MessageAttributeList> {
+        self.attributes.as_ref()
+    }
+
     fn directive(&self) -> MessageVariantDirective {
         if self.is_skipped() {
             MessageVariantDirective::Skipped
@@ -1336,6 +1591,123 @@ mod tests {
             })
             .contains("selector")
         );
+        assert!(
+            err_for(syn::parse_quote! {
+                #[fluent(skip, term)]
+                state: UsaState
+            })
+            .contains("term")
+        );
+        assert!(
+            err_for(syn::parse_quote! {
+                #[fluent(term, selector)]
+                state: UsaState
+            })
+            .contains("term")
+        );
+        assert!(
+            err_for(syn::parse_quote! {
+                #[fluent(term)]
+                state: Option<UsaState>
+            })
+            .contains("Option")
+        );
+        assert!(
+            err_for(syn::parse_quote! {
+                #[fluent(skip, plural)]
+                count: u32
+            })
+            .contains("plural")
+        );
+        assert!(
+            err_for(syn::parse_quote! {
+                #[fluent(plural, selector)]
+                count: u32
+            })
+            .contains("plural")
+        );
+        assert!(
+            err_for(syn::parse_quote! {
+                #[fluent(plural, custom)]
+                price: Money
+            })
+            .contains("plural category")
+        );
+        assert!(
+            err_for(syn::parse_quote! {
+                #[fluent(skip, custom)]
+                price: Money
+            })
+            .contains("custom")
+        );
+        assert!(
+            err_for(syn::parse_quote! {
+                #[fluent(custom, value = |money: &Money| money.cents)]
+                price: Money
+            })
+            .contains("custom")
+        );
+        assert!(
+            err_for(syn::parse_quote! {
+                #[fluent(custom)]
+                price: Option<Money>
+            })
+            .contains("Option")
+        );
+    }
+
+    #[test]
+    fn field_directive_records_plural_alongside_value_strategy() {
+        let field: syn::Field = syn::parse_quote! {
+            #[fluent(plural, value = |items: &Vec<String>| items.len())]
+            items: Vec<String>
+        };
+        let opts = FluentFieldOpts::from_field(&field).expect("plural field should parse");
+        let argument = opts
+            .directive()
+            .argument()
+            .expect("plural field is an argument");
+
+        assert!(argument.is_plural());
+        assert!(matches!(
+            argument.value(),
+            FieldValueDirective::Transform(_)
+        ));
+    }
+
+    #[test]
+    fn field_directive_records_term_link_type() {
+        let field: syn::Field = syn::parse_quote! {
+            #[fluent(term)]
+            state: UsaState
+        };
+        let opts = FluentFieldOpts::from_field(&field).expect("term field should parse");
+
+        let Some(FieldValueDirective::Term { ty, .. }) = opts
+            .directive()
+            .argument()
+            .map(FieldArgumentDirective::value)
+        else {
+            panic!("#[fluent(term)] should record the linked type");
+        };
+
+        assert_eq!(quote::quote!(#ty).to_string(), "UsaState");
+    }
+
+    #[test]
+    fn field_directive_records_custom_value_strategy() {
+        let field: syn::Field = syn::parse_quote! {
+            #[fluent(custom)]
+            price: Money
+        };
+        let opts = FluentFieldOpts::from_field(&field).expect("custom field should parse");
+
+        assert!(matches!(
+            opts.directive()
+                .argument()
+                .map(FieldArgumentDirective::value),
+            Some(FieldValueDirective::Custom { .. })
+        ));
     }
 
     #[test]
@@ -1,4 +1,6 @@
-use darling::{FromDeriveInput, FromField};
+use bon::Builder;
+use darling::{FromDeriveInput, FromField, FromMeta};
+use es_fluent_shared::{namer, namespace::NamespaceRule};
 use getset::Getters;
 
 use crate::options::{FieldDirective, FluentField, GeneratedVariantsOptions, StructDataOptions};
@@ -70,7 +72,11 @@ impl FluentField for StructFieldOpts {
 
 /// Options for a struct.
 #[derive(Clone, Debug, FromDeriveInput, Getters)]
-#[darling(supports(struct_named, struct_tuple, struct_unit), attributes(fluent))]
+#[darling(
+    supports(struct_named, struct_tuple, struct_unit),
+    attributes(fluent),
+    forward_attrs(doc)
+)]
 #[getset(get = "pub")]
 pub struct StructOpts {
     /// The identifier of the struct.
@@ -79,7 +85,91 @@ pub struct StructOpts {
     generics: syn::Generics,
     data: darling::ast::Data<darling::util::Ignored, StructFieldOpts>,
     #[darling(flatten)]
-    attr_args: super::NamespacedAttributeArgs,
+    attr_args: FluentStructAttributeArgs,
+    #[getset(skip)]
+    attrs: Vec<syn::Attribute>,
+}
+
+/// Attribute arguments for a struct.
+#[derive(Builder, Clone, Debug, Default, FromMeta)]
+pub struct FluentStructAttributeArgs {
+    #[darling(flatten)]
+    namespace_args: super::NamespacedAttributeArgs,
+    /// Optional casing for the struct segment of the message id.
+    #[darling(default)]
+    case: Option<namer::KeyCase>,
+    /// Whether the struct doc comment becomes the FTL message comment.
+    #[darling(default)]
+    doc_as_comment: bool,
+    /// Optional `## Group` header text used instead of the type name.
+    #[darling(default)]
+    group: Option<super::GroupLabel>,
+    /// Whether generation skips the type, leaving its keys to hand-authored FTL.
+    #[darling(default)]
+    skip_generate: bool,
+    /// Whether the derive emits an `ALL_FTL_KEYS` associated const.
+    #[darling(default)]
+    export_keys: bool,
+    /// Whether the type's entries are generated as Fluent terms.
+    #[darling(default)]
+    as_terms: bool,
+    /// Message attributes scaffolded on the struct's entry.
+    #[darling(default)]
+    attributes: Option<super::MessageAttributeList>,
+}
+
+impl FluentStructAttributeArgs {
+    /// Returns the namespace value if provided.
+    pub fn namespace(&self) -> Option<&NamespaceRule> {
+        self.namespace_args.namespace()
+    }
+
+    /// Returns the span of the namespace value if provided.
+    pub fn namespace_span(&self) -> Option<proc_macro2::Span> {
+        self.namespace_args.namespace_span()
+    }
+
+    /// Returns the message id casing if provided.
+    pub fn key_case(&self) -> Option<namer::KeyCase> {
+        self.case
+    }
+
+    /// Returns whether the struct doc comment is emitted as an FTL comment.
+    pub fn doc_as_comment(&self) -> bool {
+        self.doc_as_comment
+    }
+
+    /// Returns the group header text if provided.
+    pub fn group(&self) -> Option<&super::GroupLabel> {
+        self.group.as_ref()
+    }
+
+    /// Returns whether FTL generation skips the type.
+    pub fn skip_generate(&self) -> bool {
+        self.skip_generate
+    }
+
+    /// Returns whether the derive exports the type's message ids.
+    pub fn export_keys(&self) -> bool {
+        self.export_keys
+    }
+
+    /// Returns whether generation writes the type's entries as Fluent terms.
+    pub fn as_terms(&self) -> bool {
+        self.as_terms
+    }
+
+    /// Returns the message attributes if provided.
+    pub fn attributes(&self) -> Option<&super::MessageAttributeList> {
+        self.attributes.as_ref()
+    }
+}
+
+impl StructOpts {
+    /// Returns the struct's doc comment, if any.
+    pub fn doc(&self) -> Option<String> {
+        super::doc_comment(&self.attrs)
+    }
 }
 
 impl StructDataOptions for StructOpts {
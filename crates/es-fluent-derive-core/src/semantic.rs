@@ -6,7 +6,7 @@ use crate::{
 };
 use es_fluent_shared::{
     fluent::{
-        FluentArgumentName, FluentDomain, FluentIdentifierError,
+        FluentArgumentName, FluentAttributeName, FluentDomain, FluentIdentifierError,
         FluentMessageId as SharedMessageId, FluentVariantKey,
     },
     meta::TypeKind,
@@ -14,13 +14,13 @@ use es_fluent_shared::{
     namespace::NamespaceRule,
 };
 use heck::{ToPascalCase as _, ToSnakeCase as _};
-use proc_macro2::Span;
+use proc_macro2::{Delimiter, Spacing, Span, TokenStream, TokenTree};
 use quote::ToTokens as _;
 use syn::spanned::Spanned as _;
 
 pub use es_fluent_shared::fluent::{
-    FluentArgumentName as ArgName, FluentDomain as DomainName, FluentMessageId,
-    FluentVariantKey as VariantKey,
+    FluentArgumentName as ArgName, FluentAttributeName as MessageAttributeName,
+    FluentDomain as DomainName, FluentMessageId, FluentVariantKey as VariantKey,
 };
 
 /// A value paired with the best source span available for diagnostics or code emission.
@@ -68,6 +68,14 @@ pub fn parse_variant_key_in_context(
     FluentVariantKey::try_new(value).map_err(|error| semantic_error(error, span, context))
 }
 
+pub fn parse_message_attribute_name_in_context(
+    value: impl Into<String>,
+    span: Span,
+    context: AttrContext,
+) -> EsFluentCoreResult<MessageAttributeName> {
+    FluentAttributeName::try_new(value).map_err(|error| semantic_error(error, span, context))
+}
+
 pub fn parse_domain_name_in_context(
     value: impl Into<String>,
     span: Span,
@@ -103,9 +111,14 @@ pub fn message_id_from_fluent_key(
 
 pub fn message_id_for_ident(
     ident: &syn::Ident,
+    case: Option<namer::KeyCase>,
     context: AttrContext,
 ) -> EsFluentCoreResult<SpannedValue<FluentMessageId>> {
-    message_id_from_fluent_key(namer::FluentKey::from(ident), ident.span(), context)
+    message_id_from_fluent_key(
+        namer::FluentKey::from_ident_with_case(ident, case),
+        ident.span(),
+        context,
+    )
 }
 
 pub fn label_message_id_for_ident(
@@ -119,12 +132,14 @@ pub fn variant_message_id(
     base_key: &FluentMessageId,
     variant_ident: &syn::Ident,
     override_key: Option<&VariantKey>,
+    case: Option<namer::KeyCase>,
     context: AttrContext,
 ) -> EsFluentCoreResult<SpannedValue<FluentMessageId>> {
+    // Explicit `key = "..."` overrides are used verbatim, even with a case.
     let variant_key_suffix = override_key
         .map(VariantKey::as_str)
         .map(str::to_owned)
-        .unwrap_or_else(|| namer::rust_ident_name(variant_ident));
+        .unwrap_or_else(|| namer::FluentKey::variant_segment(variant_ident, case));
     message_id_from_fluent_key(
         namer::FluentKey::from(base_key.as_str()).join(&variant_key_suffix),
         variant_ident.span(),
@@ -424,6 +439,8 @@ impl SourceLocation {
 pub struct ArgumentModel {
     name: SpannedValue<ArgName>,
     value_strategy: ArgumentValueStrategy,
+    plural: bool,
+    rust_type: Option<String>,
 }
 
 impl ArgumentModel {
@@ -439,9 +456,23 @@ impl ArgumentModel {
         Self {
             name,
             value_strategy,
+            plural: false,
+            rust_type: None,
         }
     }
 
+    /// Marks this argument as the subject of a scaffolded plural select.
+    pub fn with_plural(mut self, plural: bool) -> Self {
+        self.plural = plural;
+        self
+    }
+
+    /// Records the Rust type of the value passed as this argument.
+    pub fn with_rust_type(mut self, ty: Option<&syn::Type>) -> Self {
+        self.rust_type = ty.map(rust_type_name);
+        self
+    }
+
     pub fn name(&self) -> &ArgName {
         self.name.value()
     }
@@ -453,6 +484,77 @@ impl ArgumentModel {
     pub fn value_strategy(&self) -> &ArgumentValueStrategy {
         &self.value_strategy
     }
+
+    pub fn is_plural(&self) -> bool {
+        self.plural
+    }
+
+    /// Returns the argument's Rust type as written in source, such as
+    /// `Option<u32>`, when it is known.
+    pub fn rust_type(&self) -> Option<&str> {
+        self.rust_type.as_deref()
+    }
+}
+
+/// Renders a type close to how it is written in source: `Option<u32>`
+/// rather than the token stream's `Option < u32 >`.
+fn rust_type_name(ty: &syn::Type) -> String {
+    let mut name = String::new();
+    write_type_tokens(ty.to_token_stream(), &mut name);
+    name
+}
+
+/// Writes type tokens with source spacing: a space only between adjacent
+/// words, after `,` and `;`, and around `=`, `+`, and `->`.
+fn write_type_tokens(tokens: TokenStream, out: &mut String) {
+    let mut after_word = false;
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(_) | TokenTree::Literal(_) => {
+                if after_word {
+                    out.push(' ');
+                }
+                out.push_str(&token.to_string());
+                after_word = true;
+            },
+            TokenTree::Punct(punct) => {
+                let is_arrow = punct.as_char() == '-'
+                    && punct.spacing() == Spacing::Joint
+                    && matches!(tokens.peek(), Some(TokenTree::Punct(next)) if next.as_char() == '>');
+                match punct.as_char() {
+                    '\'' if after_word => out.push_str(" '"),
+                    _ if is_arrow => {
+                        tokens.next();
+                        out.push_str(" -> ");
+                    },
+                    '=' | '+' => {
+                        out.push(' ');
+                        out.push(punct.as_char());
+                        out.push(' ');
+                    },
+                    ',' | ';' => {
+                        out.push(punct.as_char());
+                        out.push(' ');
+                    },
+                    other => out.push(other),
+                }
+                after_word = false;
+            },
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::None => ("", ""),
+                };
+                out.push_str(open);
+                write_type_tokens(group.stream(), out);
+                out.push_str(close);
+                after_word = false;
+            },
+        }
+    }
 }
 
 /// Runtime value strategy for one generated Fluent argument.
@@ -466,6 +568,10 @@ pub enum ArgumentValueStrategy {
     Choice { span: Span, ty: Box<syn::Type> },
     /// Convert an optional field value through `EsFluentChoice`.
     OptionalChoice { span: Span, ty: Box<syn::Type> },
+    /// Borrow an `EsFluent` field value whose messages are linked as terms.
+    Term { span: Span, ty: Box<syn::Type> },
+    /// Box the borrowed field's `FluentType` into `FluentValue::Custom`.
+    Custom { span: Span },
     /// Apply an explicit field-level transform expression.
     Transform(Box<ValueTransform>),
 }
@@ -476,7 +582,9 @@ impl ArgumentValueStrategy {
             Self::Borrowed { span }
             | Self::Optional { span }
             | Self::Choice { span, .. }
-            | Self::OptionalChoice { span, .. } => *span,
+            | Self::OptionalChoice { span, .. }
+            | Self::Term { span, .. }
+            | Self::Custom { span } => *span,
             Self::Transform(transform) => transform.span(),
         }
     }
@@ -498,6 +606,19 @@ impl ValueTransform {
         &self.expr
     }
 
+    /// Returns the declared return type of a closure transform such as
+    /// `|v: &String| -> usize { v.len() }`. Other transforms have no type
+    /// that can be read without type checking.
+    pub fn output_type(&self) -> Option<&syn::Type> {
+        match &self.expr {
+            syn::Expr::Closure(closure) => match &closure.output {
+                syn::ReturnType::Type(_, ty) => Some(ty),
+                syn::ReturnType::Default => None,
+            },
+            _ => None,
+        }
+    }
+
     pub fn span(&self) -> Span {
         self.span
     }
@@ -510,6 +631,9 @@ pub struct MessageEntryModel {
     message_id: SpannedValue<FluentMessageId>,
     arguments: Vec<ArgumentModel>,
     source_location: SourceLocation,
+    doc: Option<String>,
+    namespace: Option<NamespaceRule>,
+    attributes: Vec<MessageAttributeName>,
 }
 
 impl MessageEntryModel {
@@ -524,9 +648,30 @@ impl MessageEntryModel {
             message_id,
             arguments,
             source_location,
+            doc: None,
+            namespace: None,
+            attributes: Vec::new(),
         }
     }
 
+    /// Attaches the source doc comment emitted as the FTL message comment.
+    pub fn with_doc(mut self, doc: Option<String>) -> Self {
+        self.doc = doc;
+        self
+    }
+
+    /// Routes this entry to a namespace other than its type's.
+    pub fn with_namespace(mut self, namespace: Option<NamespaceRule>) -> Self {
+        self.namespace = namespace;
+        self
+    }
+
+    /// Attaches the message attributes scaffolded in generated FTL.
+    pub fn with_attributes(mut self, attributes: Vec<MessageAttributeName>) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
     pub fn source_name(&self) -> &str {
         self.source_name.as_str()
     }
@@ -551,6 +696,19 @@ impl MessageEntryModel {
         &self.arguments
     }
 
+    pub fn doc(&self) -> Option<&str> {
+        self.doc.as_deref()
+    }
+
+    /// Returns the per-entry namespace override, if any.
+    pub fn namespace(&self) -> Option<&NamespaceRule> {
+        self.namespace.as_ref()
+    }
+
+    pub fn attributes(&self) -> &[MessageAttributeName] {
+        &self.attributes
+    }
+
     pub fn argument_names(&self) -> Vec<ArgName> {
         self.arguments
             .iter()
@@ -568,6 +726,11 @@ pub struct MessageModel {
     namespace: Option<NamespaceRule>,
     messages: Vec<MessageEntryModel>,
     label: Option<MessageEntryModel>,
+    group_label: Option<String>,
+    skip_generate: bool,
+    export_keys: bool,
+    test_all_keys: bool,
+    is_term: bool,
 }
 
 impl MessageModel {
@@ -586,9 +749,44 @@ impl MessageModel {
             namespace,
             messages,
             label,
+            group_label: None,
+            skip_generate: false,
+            export_keys: false,
+            test_all_keys: false,
+            is_term: false,
         }
     }
 
+    /// Attaches the `## Group` header text used instead of the type name.
+    pub fn with_group_label(mut self, group_label: Option<String>) -> Self {
+        self.group_label = group_label;
+        self
+    }
+
+    /// Sets whether FTL generation skips the type.
+    pub fn with_skip_generate(mut self, skip_generate: bool) -> Self {
+        self.skip_generate = skip_generate;
+        self
+    }
+
+    /// Sets whether the derive exports the type's message ids.
+    pub fn with_export_keys(mut self, export_keys: bool) -> Self {
+        self.export_keys = export_keys;
+        self
+    }
+
+    /// Sets whether the derive emits a translation completeness check.
+    pub fn with_test_all_keys(mut self, test_all_keys: bool) -> Self {
+        self.test_all_keys = test_all_keys;
+        self
+    }
+
+    /// Sets whether generation writes the type's entries as Fluent terms.
+    pub fn with_term(mut self, is_term: bool) -> Self {
+        self.is_term = is_term;
+        self
+    }
+
     pub fn source_type(&self) -> &str {
         self.source_type.as_str()
     }
@@ -612,6 +810,26 @@ impl MessageModel {
     pub fn label(&self) -> Option<&MessageEntryModel> {
         self.label.as_ref()
     }
+
+    pub fn group_label(&self) -> Option<&str> {
+        self.group_label.as_deref()
+    }
+
+    pub fn skip_generate(&self) -> bool {
+        self.skip_generate
+    }
+
+    pub fn export_keys(&self) -> bool {
+        self.export_keys
+    }
+
+    pub fn test_all_keys(&self) -> bool {
+        self.test_all_keys
+    }
+
+    pub fn is_term(&self) -> bool {
+        self.is_term
+    }
 }
 
 /// A validated derive path for a generated enum.
@@ -872,30 +1090,43 @@ impl ChoiceModel {
         rename_all: Option<CaseStyle>,
     ) -> EsFluentCoreResult<Self> {
         let rename_all = rename_all.unwrap_or(CaseStyle::KebabCase);
-        let variants = variant_sources
-            .into_iter()
-            .map(|source| {
-                let value = if let Some(value_override) = source.value_override {
-                    FluentChoiceValue::try_new(
-                        value_override.value().as_str().to_string(),
-                        value_override.span(),
-                        AttrContext::ChoiceContainer,
-                    )?
-                } else {
-                    let variant_name = es_fluent_shared::namer::rust_ident_name(source.ident);
-                    let value = rename_all.apply(&variant_name);
-                    FluentChoiceValue::try_new(
-                        value,
-                        source.ident.span(),
-                        AttrContext::ChoiceContainer,
-                    )?
-                };
-                Ok(ChoiceVariantModel::new(
-                    source.ident.clone(),
-                    SpannedValue::new(value, source.ident.span()),
+        let mut variants: Vec<ChoiceVariantModel> = Vec::new();
+        for source in variant_sources {
+            let (value, span) = if let Some(value_override) = source.value_override {
+                (
+                    value_override.value().as_str().to_string(),
+                    value_override.span(),
+                )
+            } else {
+                let variant_name = es_fluent_shared::namer::rust_ident_name(source.ident);
+                (rename_all.apply(&variant_name), source.ident.span())
+            };
+            let value = FluentChoiceValue::try_new(value, span, AttrContext::ChoiceContainer)?;
+
+            if let Some(earlier) = variants
+                .iter()
+                .find(|variant| variant.value().as_str() == value.as_str())
+            {
+                return Err(EsFluentCoreError::StructuredAttributeError(AttrError::new(
+                    AttrContext::ChoiceVariant,
+                    format!(
+                        "choice value '{}' of `{}` duplicates the value of `{}`",
+                        value.as_str(),
+                        source.ident,
+                        earlier.ident()
+                    ),
+                    Some(span),
                 ))
-            })
-            .collect::<EsFluentCoreResult<Vec<_>>>()?;
+                .with_help(
+                    "give one of the variants a distinct #[fluent_choice(rename = \"...\")]"
+                        .to_string(),
+                ));
+            }
+            variants.push(ChoiceVariantModel::new(
+                source.ident.clone(),
+                SpannedValue::new(value, source.ident.span()),
+            ));
+        }
 
         Ok(Self {
             ident: ident.clone(),
@@ -986,7 +1217,7 @@ mod tests {
         let username: syn::Ident = syn::parse_quote!(Username);
 
         assert_eq!(
-            message_id_for_ident(&login_form, AttrContext::MessageContainer)
+            message_id_for_ident(&login_form, None, AttrContext::MessageContainer)
                 .expect("struct message id")
                 .value()
                 .as_str(),
@@ -1000,11 +1231,11 @@ mod tests {
             "login_form_label"
         );
 
-        let base = message_id_for_ident(&login_error, AttrContext::MessageContainer)
+        let base = message_id_for_ident(&login_error, None, AttrContext::MessageContainer)
             .expect("enum base")
             .into_value();
         assert_eq!(
-            variant_message_id(&base, &failed, None, AttrContext::EnumVariant)
+            variant_message_id(&base, &failed, None, None, AttrContext::EnumVariant)
                 .expect("variant message id")
                 .value()
                 .as_str(),
@@ -1019,6 +1250,7 @@ mod tests {
                 &base,
                 &failed,
                 Some(&override_key),
+                None,
                 AttrContext::EnumVariant
             )
             .expect("overridden variant message id")
@@ -1027,6 +1259,38 @@ mod tests {
             "login_error-custom-key"
         );
 
+        let kebab = Some(namer::KeyCase::Kebab);
+        let kebab_base = message_id_for_ident(&login_error, kebab, AttrContext::MessageContainer)
+            .expect("kebab enum base")
+            .into_value();
+        assert_eq!(kebab_base.as_str(), "login-error");
+        assert_eq!(
+            variant_message_id(
+                &kebab_base,
+                &username,
+                None,
+                kebab,
+                AttrContext::EnumVariant
+            )
+            .expect("kebab variant message id")
+            .value()
+            .as_str(),
+            "login-error-username"
+        );
+        assert_eq!(
+            variant_message_id(
+                &kebab_base,
+                &failed,
+                Some(&override_key),
+                kebab,
+                AttrContext::EnumVariant
+            )
+            .expect("overrides ignore the case")
+            .value()
+            .as_str(),
+            "login-error-custom-key"
+        );
+
         let generated_base = namer::FluentKey::from("login_form_label_variants");
         assert_eq!(
             generated_variant_message_id(
@@ -1252,4 +1516,59 @@ mod tests {
         let message = err.to_string();
         assert!(message.contains("choice container"), "{message}");
     }
+
+    #[test]
+    fn choice_model_rejects_duplicate_selector_values() {
+        let choice_ident: syn::Ident = syn::parse_quote!(SeverityChoice);
+        let high_ident: syn::Ident = syn::parse_quote!(High);
+        let urgent_ident: syn::Ident = syn::parse_quote!(Urgent);
+        let rename = SpannedValue::new(
+            VariantKey::try_new("high").expect("valid variant key"),
+            Span::call_site(),
+        );
+
+        let err = ChoiceModel::from_variant_sources(
+            &choice_ident,
+            [
+                ChoiceVariantSource::new(&high_ident, None),
+                ChoiceVariantSource::new(&urgent_ident, Some(&rename)),
+            ],
+            None,
+        )
+        .expect_err("two variants share the selector value 'high'");
+
+        let message = err.to_string();
+        assert!(message.contains("choice variant"), "{message}");
+        assert!(
+            message.contains("choice value 'high' of `Urgent` duplicates the value of `High`"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn rust_type_name_matches_source_spelling() {
+        let cases: [(syn::Type, &str); 9] = [
+            (syn::parse_quote!(u32), "u32"),
+            (syn::parse_quote!(Option<Vec<u32>>), "Option<Vec<u32>>"),
+            (syn::parse_quote!(&'a str), "&'a str"),
+            (syn::parse_quote!(&mut String), "&mut String"),
+            (
+                syn::parse_quote!(std::collections::BTreeMap<String, u8>),
+                "std::collections::BTreeMap<String, u8>",
+            ),
+            (syn::parse_quote!([u8; 4]), "[u8; 4]"),
+            (syn::parse_quote!((u8, &'static str)), "(u8, &'static str)"),
+            (
+                syn::parse_quote!(Box<dyn Fn(u8) -> u8 + Send>),
+                "Box<dyn Fn(u8) -> u8 + Send>",
+            ),
+            (
+                syn::parse_quote!(impl Iterator<Item = u8>),
+                "impl Iterator<Item = u8>",
+            ),
+        ];
+        for (ty, expected) in cases {
+            assert_eq!(rust_type_name(&ty), expected);
+        }
+    }
 }
@@ -123,10 +123,10 @@ const ES_FLUENT_CONTAINER_ATTRIBUTES: &[PolicyAttribute] = &[
         enum_location: AttributeLocation::ChoiceContainer,
     },
 ];
-const ES_FLUENT_VARIANT_ATTRIBUTES: &[PolicyAttribute] = &[PolicyAttribute::same_location(
-    AttributeName::Fluent,
-    AttributeLocation::EnumVariant,
-)];
+const ES_FLUENT_VARIANT_ATTRIBUTES: &[PolicyAttribute] = &[
+    PolicyAttribute::same_location(AttributeName::Fluent, AttributeLocation::EnumVariant),
+    PolicyAttribute::same_location(AttributeName::FluentChoice, AttributeLocation::EnumVariant),
+];
 const ES_FLUENT_FIELD_ATTRIBUTES: &[PolicyAttribute] = &[PolicyAttribute::same_location(
     AttributeName::Fluent,
     AttributeLocation::MessageField,
@@ -174,6 +174,10 @@ const ES_FLUENT_CHOICE_CONTAINER_ATTRIBUTES: &[PolicyAttribute] =
         AttributeName::FluentChoice,
         AttributeLocation::ChoiceContainer,
     )];
+const ES_FLUENT_CHOICE_VARIANT_ATTRIBUTES: &[PolicyAttribute] = &[PolicyAttribute::same_location(
+    AttributeName::FluentChoice,
+    AttributeLocation::ChoiceVariant,
+)];
 
 const DERIVE_ATTRIBUTE_POLICIES: &[DeriveAttributePolicy] = &[
     DeriveAttributePolicy {
@@ -200,7 +204,7 @@ const DERIVE_ATTRIBUTE_POLICIES: &[DeriveAttributePolicy] = &[
     DeriveAttributePolicy {
         family: DeriveAttributeFamily::Choice,
         container_attributes: ES_FLUENT_CHOICE_CONTAINER_ATTRIBUTES,
-        variant_attributes: &[],
+        variant_attributes: ES_FLUENT_CHOICE_VARIANT_ATTRIBUTES,
         field_attributes: &[],
         inherited_parent_keys: &[],
     },
@@ -338,9 +342,24 @@ pub fn validate_es_fluent_choice_attribute_context(input: &DeriveInput) -> EsFlu
 }
 
 pub fn validate_struct(opts: &StructOpts) -> EsFluentCoreResult<()> {
+    if opts.attr_args().as_terms() && opts.attr_args().export_keys() {
+        return Err(as_terms_conflict("export_keys", opts.ident()));
+    }
+
     validate_message_struct_model(&MessageStructModel::from_options(opts)?)
 }
 
+/// Rejects container keys that need runtime message lookups on an `as_terms`
+/// type, which emits no `FluentMessage` impl.
+fn as_terms_conflict(key: &str, ident: &syn::Ident) -> EsFluentCoreError {
+    EsFluentCoreError::AttributeError {
+        message: format!(
+            "`{key}` cannot be combined with `as_terms` because terms are not looked up at runtime"
+        ),
+        span: Some(ident.span()),
+    }
+}
+
 pub(crate) fn validate_message_struct_model(
     model: &MessageStructModel<'_>,
 ) -> EsFluentCoreResult<()> {
@@ -364,6 +383,15 @@ pub(crate) fn validate_message_struct_model(
 
 /// Validates enum-specific attributes.
 pub fn validate_enum(opts: &EnumOpts) -> EsFluentCoreResult<()> {
+    if opts.attr_args().as_terms() {
+        if opts.attr_args().test_all_keys() {
+            return Err(as_terms_conflict("test_all_keys", opts.ident()));
+        }
+        if opts.attr_args().export_keys() {
+            return Err(as_terms_conflict("export_keys", opts.ident()));
+        }
+    }
+
     let model = MessageEnumModel::from_options(opts)?;
     validate_message_enum_model(&model)?;
     validate_message_enum_ids(&model)
@@ -559,7 +587,7 @@ pub fn validate_namespace(
     }
 
     // Try to read the config; if it doesn't exist, skip allowlist validation
-    let config = match I18nConfig::read_from_manifest_dir() {
+    let config = match I18nConfig::read_from_workspace_root() {
         Ok(c) => c,
         Err(I18nConfigError::NotFound) => return Ok(()),
         Err(error) => {
@@ -58,7 +58,9 @@ mod attribute_context_tests {
                     .expect_err("struct-only context should reject enum-only keys");
             let message = err.to_string();
             assert!(message.contains("message struct container"));
-            assert!(message.contains("accepted key here is namespace"));
+            assert!(message.contains(
+                "accepted keys here are namespace, case, doc_as_comment, group, skip_generate, export_keys, as_terms, and attributes"
+            ));
         }
     }
 
@@ -77,7 +79,9 @@ mod attribute_context_tests {
         let message = err.to_string();
         assert!(message.contains("#[fluent(default)]"));
         assert!(message.contains("message field"));
-        assert!(message.contains("accepted keys here are skip, selector, arg, and value"));
+        assert!(message.contains(
+            "accepted keys here are skip, selector, term, plural, custom, arg, and value"
+        ));
     }
 
     #[test]
@@ -93,7 +97,9 @@ mod attribute_context_tests {
             es_fluent_derive_core::validation::validate_es_fluent_attribute_context(&struct_input)
                 .expect_err("unknown struct key should fail");
         assert!(err.to_string().contains("message struct container"));
-        assert!(err.to_string().contains("accepted key here is namespace"));
+        assert!(err.to_string().contains(
+            "accepted keys here are namespace, case, doc_as_comment, group, skip_generate, export_keys, as_terms, and attributes"
+        ));
 
         let enum_input: DeriveInput = parse_quote! {
             #[derive(EsFluent)]
@@ -106,10 +112,9 @@ mod attribute_context_tests {
             es_fluent_derive_core::validation::validate_es_fluent_attribute_context(&enum_input)
                 .expect_err("unknown enum key should fail");
         assert!(err.to_string().contains("message enum container"));
-        assert!(
-            err.to_string()
-                .contains("accepted keys here are id, domain, and namespace")
-        );
+        assert!(err.to_string().contains(
+            "accepted keys here are id, domain, namespace, case, doc_as_comment, group, skip_generate, export_keys, test_all_keys, as_terms, and attributes"
+        ));
     }
 
     #[test]
@@ -109,6 +109,10 @@ pub fn derive_es_fluent_variants(input: proc_macro::TokenStream) -> proc_macro::
 ///
 /// - `#[fluent_choice(rename_all = "...")]`: Overrides the default kebab-case variant serialization.
 /// - Derived variants return validated `StaticFluentVariantKey` values instead of raw selector strings.
+///
+/// # Variant Attributes
+///
+/// - `#[fluent_choice(rename = "...")]`: Uses the given selector value for this variant instead of the `rename_all` casing.
 #[proc_macro_derive(EsFluentChoice, attributes(fluent_choice))]
 pub fn derive_fluent_choice(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     macros::derive_fluent_choice::from(input)
@@ -202,7 +202,7 @@ fn generate(context: &CodegenContext, expansion: &EsFluentEnumExpansion) -> Toke
         .map(|variant_model| match variant_model {
             EsFluentMessageVariant::Skipped(model) => MessageVariantToken::Skipped(model),
             EsFluentMessageVariant::Localized(model) => {
-                let entry = variant_message_entry(context, model);
+                let entry = Box::new(variant_message_entry(context, model));
                 MessageVariantToken::Localized { model, entry }
             },
         })
@@ -236,6 +236,7 @@ fn generate(context: &CodegenContext, expansion: &EsFluentEnumExpansion) -> Toke
     } else {
         crate::macros::utils::message_inventory_output(
             original_ident,
+            expansion.generics(),
             "inventory",
             expansion.message_model(),
         )
@@ -247,6 +248,7 @@ fn generate(context: &CodegenContext, expansion: &EsFluentEnumExpansion) -> Toke
         expansion.generics(),
         fluent_message_body,
         inventory_output,
+        expansion.message_model().export_keys(),
     );
 
     let choice_output =
@@ -261,10 +263,19 @@ fn generate(context: &CodegenContext, expansion: &EsFluentEnumExpansion) -> Toke
                 )
             });
 
+    let all_keys_check = crate::macros::utils::generate_all_keys_check_impl(
+        context,
+        original_ident,
+        expansion.generics(),
+        expansion.message_model(),
+    );
+
     quote! {
         #message_output
 
         #choice_output
+
+        #all_keys_check
     }
 }
 
@@ -272,7 +283,7 @@ enum MessageVariantToken<'a> {
     Skipped(&'a EsFluentSkippedVariant),
     Localized {
         model: &'a EsFluentLocalizedVariant,
-        entry: MessageEntrySpec,
+        entry: Box<MessageEntrySpec>,
     },
 }
 
@@ -317,8 +328,12 @@ mod tests {
         );
 
         let runtime_tokens = entry.localize_with_expr(&context, None).to_string();
-        let inventory_tokens =
-            inventory_variant_tokens_for_model(&context, &entry.metadata).to_string();
+        let inventory_tokens = inventory_variant_tokens_for_model(
+            &context,
+            &entry.metadata,
+            &syn::Generics::default(),
+        )
+        .to_string();
 
         assert!(runtime_tokens.contains("\"login_error-Failed\""));
         assert!(runtime_tokens.contains("\"display_name\""));
@@ -152,6 +152,28 @@ mod tests {
         assert!(tokens.contains("\"match\""));
     }
 
+    #[test]
+    fn expand_es_fluent_registers_as_terms_types_without_a_message_impl() {
+        for input in [
+            parse_quote! {
+                #[fluent(as_terms)]
+                struct BrandName;
+            },
+            parse_quote! {
+                #[fluent(as_terms)]
+                enum Platform {
+                    Desktop,
+                }
+            },
+        ] {
+            let tokens =
+                crate::snapshot_support::pretty_file_tokens(super::expand_es_fluent(input));
+
+            assert!(tokens.contains(".with_term()"));
+            assert!(!tokens.contains("FluentMessage for"));
+        }
+    }
+
     #[test]
     #[cfg_attr(not(target_os = "linux"), ignore = "insta snapshots are Linux-only")]
     fn expand_es_fluent_returns_compile_errors_for_attribute_parse_failures() {
@@ -259,6 +281,56 @@ mod tests {
         assert_snapshot!("expand_es_fluent_emits_field_level_tuple_arg", tokens);
     }
 
+    #[test]
+    #[cfg_attr(not(target_os = "linux"), ignore = "insta snapshots are Linux-only")]
+    fn expand_es_fluent_links_term_field_type_messages() {
+        let enum_input: syn::DeriveInput = parse_quote! {
+            enum Country {
+                USA(#[fluent(term)] USAState),
+                Canada(CanadaProvince),
+            }
+        };
+
+        let tokens =
+            crate::snapshot_support::pretty_file_tokens(super::expand_es_fluent(enum_input));
+        assert_snapshot!("expand_es_fluent_links_term_field_type_messages", tokens);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_os = "linux"), ignore = "insta snapshots are Linux-only")]
+    fn expand_es_fluent_registers_plural_fields() {
+        let struct_input: syn::DeriveInput = parse_quote! {
+            struct Cart {
+                #[fluent(plural)]
+                count: u32,
+                owner: String,
+            }
+        };
+
+        let tokens =
+            crate::snapshot_support::pretty_file_tokens(super::expand_es_fluent(struct_input));
+        assert_snapshot!("expand_es_fluent_registers_plural_fields", tokens);
+    }
+
+    #[test]
+    #[cfg_attr(not(target_os = "linux"), ignore = "insta snapshots are Linux-only")]
+    fn expand_es_fluent_boxes_custom_fields() {
+        let enum_input: syn::DeriveInput = parse_quote! {
+            enum Invoice {
+                Due(#[fluent(custom)] Money),
+                Paid {
+                    #[fluent(custom)]
+                    amount: Money,
+                    payer: String,
+                },
+            }
+        };
+
+        let tokens =
+            crate::snapshot_support::pretty_file_tokens(super::expand_es_fluent(enum_input));
+        assert_snapshot!("expand_es_fluent_boxes_custom_fields", tokens);
+    }
+
     #[test]
     #[cfg_attr(not(target_os = "linux"), ignore = "insta snapshots are Linux-only")]
     fn tuple_default_names_survive_arg_override() {
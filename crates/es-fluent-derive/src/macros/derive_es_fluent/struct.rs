@@ -48,17 +48,23 @@ fn generate(context: &CodegenContext, expansion: &EsFluentStructExpansion) -> To
     // and doesn't depend on generic type parameters
     let inventory_output = crate::macros::utils::message_inventory_output(
         original_ident,
+        expansion.generics(),
         "inventory",
         expansion.message_model(),
     );
 
-    crate::macros::utils::emit_message_inventory_impls(
+    let message_output = crate::macros::utils::emit_message_inventory_impls(
         context,
         original_ident,
         expansion.generics(),
         fluent_message_body,
         inventory_output,
-    )
+        expansion.message_model().export_keys(),
+    );
+
+    quote! {
+        #message_output
+    }
 }
 
 #[cfg(test)]
@@ -95,4 +101,33 @@ mod tests {
         assert!(tokens.contains("\"display_name\""));
         assert!(tokens.contains("\"attempts\""));
     }
+
+    #[test]
+    fn struct_selector_fields_register_static_choice_types() {
+        let input: syn::DeriveInput = parse_quote! {
+            struct Greeting<'a, T> {
+                name: &'a str,
+                #[fluent(selector)]
+                gender: Option<&'a GenderChoice>,
+                #[fluent(selector)]
+                mood: T,
+            }
+        };
+        let expansion =
+            es_fluent_derive_core::expansion::EsFluentExpansion::from_derive_input(&input)
+                .expect("expansion");
+        let es_fluent_derive_core::expansion::EsFluentExpansion::Struct(expansion) = expansion
+        else {
+            panic!("expected struct expansion");
+        };
+
+        let context = CodegenContext::fallback();
+        let tokens = generate(&context, &expansion).to_string();
+
+        assert!(tokens.contains("with_selectors"));
+        assert!(
+            tokens.contains("< GenderChoice as :: es_fluent :: EsFluentChoice > :: fluent_choices")
+        );
+        assert!(!tokens.contains("< T as :: es_fluent :: EsFluentChoice >"));
+    }
 }
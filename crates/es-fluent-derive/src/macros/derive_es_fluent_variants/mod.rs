@@ -169,8 +169,12 @@ mod tests {
 
         let context = CodegenContext::fallback();
         let runtime_tokens = entry.localize_with_match_arm(&context, None).to_string();
-        let inventory_tokens =
-            inventory_variant_tokens_for_model(&context, &entry.message_entry.metadata).to_string();
+        let inventory_tokens = inventory_variant_tokens_for_model(
+            &context,
+            &entry.message_entry.metadata,
+            &syn::Generics::default(),
+        )
+        .to_string();
 
         assert!(runtime_tokens.contains("\"login_form_variants-username\""));
         assert!(inventory_tokens.contains("static_entry_id"));
@@ -71,6 +71,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn expand_choice_uses_variant_rename_in_match_and_choice_list() {
+        let input: syn::DeriveInput = parse_quote! {
+            #[fluent_choice(rename_all = "camelCase")]
+            enum ChoiceRenamed {
+                VeryHigh,
+                #[fluent_choice(rename = "LOW")]
+                Low,
+            }
+        };
+
+        let tokens = crate::snapshot_support::pretty_file_tokens(super::expand_choice(input));
+        assert_snapshot!(
+            "expand_choice_uses_variant_rename_in_match_and_choice_list",
+            tokens
+        );
+    }
+
     #[test]
     fn expand_choice_emits_compile_error_for_invalid_rename_all() {
         let input: syn::DeriveInput = parse_quote! {
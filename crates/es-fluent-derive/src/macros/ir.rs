@@ -1,6 +1,6 @@
 use es_fluent_derive_core::semantic::{
-    ArgName, ArgumentModel, DomainName, FluentMessageId, GeneratedDocName, MessageEntryModel,
-    RustSourceName, SourceLocation,
+    ArgName, ArgumentModel, ArgumentValueStrategy, DomainName, FluentMessageId, GeneratedDocName,
+    MessageEntryModel, RustSourceName, SourceLocation,
 };
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
@@ -66,16 +66,84 @@ impl MessageEntrySpec {
 pub(crate) fn inventory_variant_tokens_for_model(
     context: &CodegenContext,
     metadata: &MessageEntryModel,
+    generics: &syn::Generics,
 ) -> TokenStream {
+    let selectors = metadata
+        .arguments()
+        .iter()
+        .filter_map(|argument| {
+            let ty = match argument.value_strategy() {
+                ArgumentValueStrategy::Choice { ty, .. }
+                | ArgumentValueStrategy::OptionalChoice { ty, .. } => ty,
+                _ => return None,
+            };
+            static_field_type(ty, generics).map(|ty| (argument.name().clone(), ty.clone()))
+        })
+        .collect();
+    let term_links = metadata
+        .arguments()
+        .iter()
+        .filter_map(|argument| {
+            let ArgumentValueStrategy::Term { ty, .. } = argument.value_strategy() else {
+                return None;
+            };
+            static_field_type(ty, generics).map(|ty| (argument.name().clone(), ty.clone()))
+        })
+        .collect();
+
     InventoryVariantSpec {
         name: metadata.rust_source_name().clone(),
         ftl_key: metadata.message_id().clone(),
         arg_names: metadata.argument_names(),
+        arg_types: metadata
+            .arguments()
+            .iter()
+            .map(|argument| argument.rust_type().map(str::to_owned))
+            .collect(),
+        selectors,
+        term_links,
+        plurals: metadata
+            .arguments()
+            .iter()
+            .filter(|argument| argument.is_plural())
+            .map(|argument| argument.name().clone())
+            .collect(),
+        doc: metadata.doc().map(str::to_owned),
+        attributes: metadata
+            .attributes()
+            .iter()
+            .map(|attribute| attribute.as_str().to_owned())
+            .collect(),
         source_location: metadata.source_location().clone(),
     }
     .tokens(context)
 }
 
+/// Returns the choice or term type behind references when it can be named
+/// from the inventory module, which has no access to the deriving type's
+/// generics.
+fn static_field_type<'a>(ty: &'a syn::Type, generics: &syn::Generics) -> Option<&'a syn::Type> {
+    let path = match ty {
+        syn::Type::Reference(reference) => return static_field_type(&reference.elem, generics),
+        syn::Type::Paren(paren) => return static_field_type(&paren.elem, generics),
+        syn::Type::Path(type_path) if type_path.qself.is_none() => &type_path.path,
+        _ => return None,
+    };
+
+    let first = path.segments.first()?;
+    let has_arguments = path
+        .segments
+        .iter()
+        .any(|segment| !segment.arguments.is_empty());
+    let names_generic = path.leading_colon.is_none()
+        && (first.ident == "Self"
+            || generics
+                .type_params()
+                .any(|param| first.ident == param.ident));
+
+    (!has_arguments && !names_generic).then_some(ty)
+}
+
 pub(crate) struct LocalizeCallSpec {
     pub(crate) domain_override: Option<DomainName>,
     pub(crate) ftl_key: FluentMessageId,
@@ -114,6 +182,13 @@ pub(crate) struct InventoryVariantSpec {
     pub(crate) name: RustSourceName,
     pub(crate) ftl_key: FluentMessageId,
     pub(crate) arg_names: Vec<ArgName>,
+    /// Rust argument types parallel to `arg_names`, `None` where unknown.
+    pub(crate) arg_types: Vec<Option<String>>,
+    pub(crate) selectors: Vec<(ArgName, syn::Type)>,
+    pub(crate) term_links: Vec<(ArgName, syn::Type)>,
+    pub(crate) plurals: Vec<ArgName>,
+    pub(crate) doc: Option<String>,
+    pub(crate) attributes: Vec<String>,
     pub(crate) source_location: SourceLocation,
 }
 
@@ -129,8 +204,7 @@ impl InventoryVariantSpec {
         let entry_id = static_entry_id_tokens(context, &self.ftl_key);
         let source_span = self.source_location.span();
         let source_line = quote_spanned! { source_span=> line!() };
-
-        quote! {
+        let mut variant = quote! {
             #es_fluent::registry::__macro::ftl_variant(
                 #name,
                 #entry_id,
@@ -138,7 +212,60 @@ impl InventoryVariantSpec {
                 module_path!(),
                 #source_line,
             )
+        };
+
+        if let Some(doc) = &self.doc {
+            variant = quote! { #variant.with_doc(#doc) };
+        }
+
+        if !self.attributes.is_empty() {
+            let attributes = &self.attributes;
+            variant = quote! { #variant.with_attributes(&[#(#attributes),*]) };
         }
+
+        if self.arg_types.iter().any(Option::is_some) {
+            let arg_types = self.arg_types.iter().map(|ty| match ty {
+                Some(ty) => quote! { Some(#ty) },
+                None => quote! { None },
+            });
+            variant = quote! { #variant.with_arg_types(&[#(#arg_types),*]) };
+        }
+
+        if !self.selectors.is_empty() {
+            let selector_tokens = self.selectors.iter().map(|(arg, ty)| {
+                let arg = static_argument_name_tokens(context, arg);
+                quote! {
+                    #es_fluent::registry::__macro::ftl_selector(
+                        #arg,
+                        <#ty as #es_fluent::EsFluentChoice>::fluent_choices,
+                    )
+                }
+            });
+            variant = quote! { #variant.with_selectors(&[#(#selector_tokens),*]) };
+        }
+
+        if !self.term_links.is_empty() {
+            let term_link_tokens = self.term_links.iter().map(|(arg, ty)| {
+                let arg = static_argument_name_tokens(context, arg);
+                quote! {
+                    #es_fluent::registry::__macro::ftl_term_link(
+                        #arg,
+                        <#ty as #es_fluent::FluentMessage>::fluent_entry_ids,
+                    )
+                }
+            });
+            variant = quote! { #variant.with_term_links(&[#(#term_link_tokens),*]) };
+        }
+
+        if !self.plurals.is_empty() {
+            let plural_tokens = self
+                .plurals
+                .iter()
+                .map(|arg| static_argument_name_tokens(context, arg));
+            variant = quote! { #variant.with_plurals(&[#(#plural_tokens),*]) };
+        }
+
+        variant
     }
 }
 
@@ -37,10 +37,14 @@ impl CodegenContext {
 
 pub struct InventoryModuleInput<'a> {
     pub ident: &'a syn::Ident,
+    pub generics: &'a syn::Generics,
     pub module_name_prefix: &'a str,
     pub type_kind: TypeKind,
     pub entries: Vec<MessageEntryModel>,
     pub namespace: Option<NamespaceRule>,
+    pub group_label: Option<String>,
+    pub skip_generate: bool,
+    pub is_term: bool,
 }
 
 pub enum InventoryOutput<'a> {
@@ -167,7 +171,12 @@ pub fn generate_field_value_expr(
                 #es_fluent::__private::FluentOptionalArgumentValue::new((#transform_arg_expr).as_ref())
             }
         },
-        ArgumentValueStrategy::Borrowed { span } => {
+        ArgumentValueStrategy::Custom { span } => {
+            quote_spanned! { *span=>
+                #es_fluent::__private::FluentCustomArgumentValue::new(#transform_arg_expr)
+            }
+        },
+        ArgumentValueStrategy::Borrowed { span } | ArgumentValueStrategy::Term { span, .. } => {
             quote_spanned! { *span=>
                 #es_fluent::__private::FluentBorrowedArgumentValue::new(#transform_arg_expr)
             }
@@ -212,14 +221,71 @@ pub fn generate_field_argument(
     }
 }
 
+/// Emits the `FluentMessage` impl and, when `export_keys` is set, the
+/// `ALL_FTL_KEYS` const requested by `#[fluent(export_keys)]`.
+///
+/// Both read one entry id list, so the exported keys always match what
+/// `fluent_entry_ids()` reports.
 pub fn generate_fluent_message_impl(
     context: &CodegenContext,
     ident: &syn::Ident,
     generics: &syn::Generics,
     body: TokenStream,
+    entries: &[MessageEntryModel],
+    export_keys: bool,
 ) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let es_fluent = context.facade_path().tokens();
+    let entry_ids = entries
+        .iter()
+        .map(|entry| static_entry_id_tokens(context, entry.message_id()));
+    let entry_ids = quote! {
+        &[#(#entry_ids),*]
+    };
+
+    let (entry_ids_fn, inherent_impl) = match (entries.is_empty(), export_keys) {
+        (true, false) => (quote! {}, quote! {}),
+        (true, true) => (
+            quote! {},
+            quote! {
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// Every Fluent message id this type generates, in declaration order.
+                    pub const ALL_FTL_KEYS: &'static [&'static str] = &[];
+                }
+            },
+        ),
+        (false, false) => (
+            quote! {
+                fn fluent_entry_ids() -> &'static [#es_fluent::registry::StaticFluentEntryId] {
+                    const ENTRY_IDS: &[#es_fluent::registry::StaticFluentEntryId] = #entry_ids;
+                    ENTRY_IDS
+                }
+            },
+            quote! {},
+        ),
+        (false, true) => {
+            let key_count = entries.len();
+            (
+                quote! {
+                    fn fluent_entry_ids() -> &'static [#es_fluent::registry::StaticFluentEntryId] {
+                        Self::__FLUENT_ENTRY_IDS
+                    }
+                },
+                quote! {
+                    impl #impl_generics #ident #ty_generics #where_clause {
+                        const __FLUENT_ENTRY_IDS: &'static [#es_fluent::registry::StaticFluentEntryId] =
+                            #entry_ids;
+
+                        /// Every Fluent message id this type generates, in declaration order.
+                        pub const ALL_FTL_KEYS: &'static [&'static str] =
+                            &#es_fluent::__private::entry_id_strs::<#key_count>(
+                                Self::__FLUENT_ENTRY_IDS,
+                            );
+                    }
+                },
+            )
+        },
+    };
 
     quote! {
         impl #impl_generics #es_fluent::FluentMessage for #ident #ty_generics #where_clause {
@@ -229,6 +295,46 @@ pub fn generate_fluent_message_impl(
             ) -> String {
                 #body
             }
+
+            #entry_ids_fn
+        }
+
+        #inherent_impl
+    }
+}
+
+/// Emits the `__assert_all_ftl_keys_present` function requested by
+/// `#[fluent(test_all_keys)]`, checking the same ids the message impl looks up.
+pub fn generate_all_keys_check_impl(
+    context: &CodegenContext,
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    model: &MessageModel,
+) -> TokenStream {
+    if !model.test_all_keys() {
+        return quote! {};
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let es_fluent = context.facade_path().tokens();
+    let type_name = ident.to_string();
+    let domain_expr = static_domain_tokens(context, model.domain());
+
+    quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Panics unless `localizer` has a translation for every message id
+            /// this type generates, naming each id that would echo instead.
+            pub fn __assert_all_ftl_keys_present<L>(localizer: &L)
+            where
+                L: #es_fluent::FluentLocalizer + ?Sized,
+            {
+                #es_fluent::__private::assert_all_ftl_keys_present(
+                    localizer,
+                    #type_name,
+                    #domain_expr,
+                    <Self as #es_fluent::FluentMessage>::fluent_entry_ids(),
+                );
+            }
         }
     }
 }
@@ -241,17 +347,27 @@ pub fn generate_fluent_choice_impl(
 ) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let es_fluent = context.facade_path().tokens();
-    let match_arms = choice.variants().iter().map(|variant| {
-        let variant_ident = variant.ident();
-        let choice_value = es_fluent_derive_core::macro_support::static_variant_key_tokens(
-            es_fluent,
-            variant.value(),
-        );
+    let choice_values: Vec<_> = choice
+        .variants()
+        .iter()
+        .map(|variant| {
+            es_fluent_derive_core::macro_support::static_variant_key_tokens(
+                es_fluent,
+                variant.value(),
+            )
+        })
+        .collect();
+    let match_arms = choice
+        .variants()
+        .iter()
+        .zip(&choice_values)
+        .map(|(variant, choice_value)| {
+            let variant_ident = variant.ident();
 
-        quote! {
-            Self::#variant_ident => #choice_value
-        }
-    });
+            quote! {
+                Self::#variant_ident => #choice_value
+            }
+        });
 
     quote! {
         impl #impl_generics #es_fluent::EsFluentChoice for #ident #ty_generics #where_clause {
@@ -260,6 +376,13 @@ pub fn generate_fluent_choice_impl(
                     #(#match_arms),*
                 }
             }
+
+            fn fluent_choices() -> &'static [#es_fluent::registry::StaticFluentVariantKey] {
+                const CHOICES: &[#es_fluent::registry::StaticFluentVariantKey] = &[
+                    #(#choice_values),*
+                ];
+                CHOICES
+            }
         }
     }
 }
@@ -336,22 +459,32 @@ pub fn emit_generated_unit_enum(
                 #(#localize_with_match_arms),*
             }
         },
+        model.messages(),
+        false,
     );
     let choice_impl = generate_fluent_choice_impl(context, ident, &empty_generics, choice);
     let inventory_output = InventoryOutput::GeneratedEnum {
         messages: InventoryModuleInput {
             ident,
+            generics: &empty_generics,
             module_name_prefix: "inventory",
             type_kind: TypeKind::Enum,
             entries: model.messages().to_vec(),
             namespace: model.namespace().cloned(),
+            group_label: None,
+            skip_generate: false,
+            is_term: false,
         },
         label: InventoryModuleInput {
             ident,
+            generics: &empty_generics,
             module_name_prefix: "label_inventory",
             type_kind: TypeKind::Enum,
             entries: vec![label_entry.clone()],
             namespace: model.namespace().cloned(),
+            group_label: None,
+            skip_generate: false,
+            is_term: false,
         },
     };
     let inventory_submit = emit_inventory_output(context, inventory_output);
@@ -381,8 +514,29 @@ pub fn emit_message_inventory_impls(
     generics: &syn::Generics,
     fluent_message_body: TokenStream,
     inventory_output: InventoryOutput<'_>,
+    export_keys: bool,
 ) -> TokenStream {
-    let message_impl = generate_fluent_message_impl(context, ident, generics, fluent_message_body);
+    // `as_terms` types write Fluent terms, which bundles cannot format on
+    // their own, so they get no `FluentMessage` impl to look them up with.
+    let message_impl = match &inventory_output {
+        InventoryOutput::MessageEntries(input) if input.is_term => quote! {},
+        InventoryOutput::MessageEntries(input) => generate_fluent_message_impl(
+            context,
+            ident,
+            generics,
+            fluent_message_body,
+            &input.entries,
+            export_keys,
+        ),
+        _ => generate_fluent_message_impl(
+            context,
+            ident,
+            generics,
+            fluent_message_body,
+            &[],
+            export_keys,
+        ),
+    };
     let inventory_submit = emit_inventory_output(context, inventory_output);
 
     quote! {
@@ -394,30 +548,40 @@ pub fn emit_message_inventory_impls(
 
 pub fn message_inventory_output<'a>(
     ident: &'a syn::Ident,
+    generics: &'a syn::Generics,
     module_name_prefix: &'a str,
     model: &MessageModel,
 ) -> InventoryOutput<'a> {
     InventoryOutput::MessageEntries(InventoryModuleInput {
         ident,
+        generics,
         module_name_prefix,
         type_kind: *model.type_kind(),
         entries: model.messages().to_vec(),
         namespace: model.namespace().cloned(),
+        group_label: model.group_label().map(str::to_owned),
+        skip_generate: model.skip_generate(),
+        is_term: model.is_term(),
     })
 }
 
 pub fn label_inventory_output<'a>(
     ident: &'a syn::Ident,
+    generics: &'a syn::Generics,
     type_kind: TypeKind,
     namespace: Option<NamespaceRule>,
     label_entry: MessageEntryModel,
 ) -> InventoryOutput<'a> {
     InventoryOutput::LabelEntry(InventoryModuleInput {
         ident,
+        generics,
         module_name_prefix: "label_inventory",
         type_kind,
         entries: vec![label_entry],
         namespace,
+        group_label: None,
+        skip_generate: false,
+        is_term: false,
     })
 }
 
@@ -447,50 +611,103 @@ fn type_kind_tokens(context: &CodegenContext, type_kind: &TypeKind) -> TokenStre
     }
 }
 
+/// Splits entries by their effective namespace, type namespace group first,
+/// so each group registers as its own type info and lands in its own file.
+fn entries_by_namespace(
+    entries: Vec<MessageEntryModel>,
+    namespace: Option<NamespaceRule>,
+) -> Vec<(Option<NamespaceRule>, Vec<MessageEntryModel>)> {
+    let mut groups = vec![(namespace.clone(), Vec::new())];
+    for entry in entries {
+        let entry_namespace = entry.namespace().cloned().or_else(|| namespace.clone());
+        match groups
+            .iter_mut()
+            .find(|(group_namespace, _)| *group_namespace == entry_namespace)
+        {
+            Some((_, group)) => group.push(entry),
+            None => groups.push((entry_namespace, vec![entry])),
+        }
+    }
+    if groups.len() > 1 && groups[0].1.is_empty() {
+        groups.remove(0);
+    }
+    groups
+}
+
 fn generate_inventory_module(
     context: &CodegenContext,
     input: InventoryModuleInput<'_>,
 ) -> TokenStream {
     let InventoryModuleInput {
         ident,
+        generics,
         module_name_prefix,
         type_kind,
         entries,
         namespace,
+        group_label,
+        skip_generate,
+        is_term,
     } = input;
 
     let type_name = namer::rust_ident_name(ident);
-    let mod_name = format_ident!("__es_fluent_{}_{}", module_name_prefix, type_name);
     let es_fluent = context.facade_path().tokens();
     let type_kind = type_kind_tokens(context, &type_kind);
-    let variants: Vec<_> = entries
-        .iter()
-        .map(|metadata| inventory_variant_tokens_for_model(context, metadata))
-        .collect();
-    let namespace_expr = namespace_rule_tokens(context, namespace.as_ref());
-
-    quote! {
-        #[doc(hidden)]
-        #[allow(non_snake_case)]
-        mod #mod_name {
-            use super::*;
-
-            static VARIANTS: &[#es_fluent::registry::FtlVariant] = &[
-                #(#variants),*
-            ];
-
-            static TYPE_INFO: #es_fluent::registry::FtlTypeInfo =
+    let source_line = quote_spanned! { ident.span()=> line!() };
+    let modules = entries_by_namespace(entries, namespace)
+        .into_iter()
+        .enumerate()
+        .map(|(index, (namespace, entries))| {
+            let mod_name = if index == 0 {
+                format_ident!("__es_fluent_{}_{}", module_name_prefix, type_name)
+            } else {
+                format_ident!("__es_fluent_{}_{}_{}", module_name_prefix, type_name, index)
+            };
+            let variants: Vec<_> = entries
+                .iter()
+                .map(|metadata| inventory_variant_tokens_for_model(context, metadata, generics))
+                .collect();
+            let namespace_expr = namespace_rule_tokens(context, namespace.as_ref());
+            let mut type_info = quote! {
                 #es_fluent::registry::__macro::ftl_type_info(
                     #type_kind,
                     #type_name,
                     VARIANTS,
                     file!(),
+                    #source_line,
                     module_path!(),
                     #namespace_expr,
-                );
+                )
+            };
+            if let Some(group_label) = &group_label {
+                type_info = quote! { #type_info.with_group_label(#group_label) };
+            }
+            if skip_generate {
+                type_info = quote! { #type_info.with_skip_generate() };
+            }
+            if is_term {
+                type_info = quote! { #type_info.with_term() };
+            }
 
-            #es_fluent::__inventory::submit!(#es_fluent::registry::RegisteredFtlType(&TYPE_INFO));
-        }
+            quote! {
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                mod #mod_name {
+                    use super::*;
+
+                    static VARIANTS: &[#es_fluent::registry::FtlVariant] = &[
+                        #(#variants),*
+                    ];
+
+                    static TYPE_INFO: #es_fluent::registry::FtlTypeInfo = #type_info;
+
+                    #es_fluent::__inventory::submit!(#es_fluent::registry::RegisteredFtlType(&TYPE_INFO));
+                }
+            }
+        });
+
+    quote! {
+        #(#modules)*
     }
 }
 
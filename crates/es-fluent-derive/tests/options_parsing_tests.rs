@@ -8,6 +8,7 @@ use es_fluent_derive_core::options::{
     r#enum::EnumOpts,
     r#struct::{StructOpts, StructVariantsOpts},
 };
+use es_fluent_shared::namer::KeyCase;
 use es_fluent_shared::namespace::NamespaceRule;
 use syn::{DeriveInput, parse_quote};
 
@@ -414,6 +415,420 @@ fn struct_fluent_with_namespace_file_relative() {
     ));
 }
 
+#[test]
+fn fluent_case_parses_on_struct_and_enum() {
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        #[fluent(case = "camel")]
+        struct ShippingNotice {
+            carrier: String,
+        }
+    };
+    let opts = StructOpts::from_derive_input(&input).expect("StructOpts should parse");
+    assert_eq!(opts.attr_args().key_case(), Some(KeyCase::Camel));
+
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        #[fluent(case = "kebab", namespace = "ui")]
+        enum PaymentStatus {
+            AwaitingPayment,
+        }
+    };
+    let opts = EnumOpts::from_derive_input(&input).expect("EnumOpts should parse");
+    assert_eq!(opts.attr_args().key_case(), Some(KeyCase::Kebab));
+}
+
+#[test]
+fn fluent_doc_as_comment_captures_doc_strings() {
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        #[fluent(doc_as_comment)]
+        enum SyncStatus {
+            /// This variant is missing from the FTL file.
+            ///
+            /// Shown next to the file name.
+            Missing,
+            #[doc = " Raw doc attributes are captured too."]
+            Stale,
+            Fresh,
+        }
+    };
+    let opts = EnumOpts::from_derive_input(&input).expect("EnumOpts should parse");
+    assert!(opts.attr_args().doc_as_comment());
+    assert_eq!(
+        opts.variants()[0].doc(),
+        Some("This variant is missing from the FTL file.\n\nShown next to the file name.")
+    );
+
+    let EsFluentExpansion::Enum(expansion) =
+        EsFluentExpansion::from_derive_input(&input).expect("expansion")
+    else {
+        panic!("expected enum expansion");
+    };
+    let docs: Vec<_> = expansion
+        .message_model()
+        .messages()
+        .iter()
+        .map(|message| message.doc())
+        .collect();
+    assert_eq!(
+        docs,
+        [
+            Some("This variant is missing from the FTL file.\n\nShown next to the file name."),
+            Some("Raw doc attributes are captured too."),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn fluent_doc_comments_are_ignored_without_doc_as_comment() {
+    let input: DeriveInput = parse_quote! {
+        /// Shown when the upload finishes.
+        #[derive(EsFluent)]
+        struct UploadComplete {
+            file_name: String,
+        }
+    };
+    let opts = StructOpts::from_derive_input(&input).expect("StructOpts should parse");
+    assert!(!opts.attr_args().doc_as_comment());
+    assert_eq!(
+        opts.doc().as_deref(),
+        Some("Shown when the upload finishes.")
+    );
+
+    let EsFluentExpansion::Struct(expansion) =
+        EsFluentExpansion::from_derive_input(&input).expect("expansion")
+    else {
+        panic!("expected struct expansion");
+    };
+    assert_eq!(expansion.message_entry().doc(), None);
+}
+
+#[test]
+fn fluent_group_sets_the_message_model_group_label() {
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        #[fluent(group = "User Facing Errors")]
+        enum AppError {
+            Timeout,
+        }
+    };
+    let opts = EnumOpts::from_derive_input(&input).expect("EnumOpts should parse");
+    assert_eq!(
+        opts.attr_args().group().map(|group| group.as_str()),
+        Some("User Facing Errors")
+    );
+    let EsFluentExpansion::Enum(expansion) =
+        EsFluentExpansion::from_derive_input(&input).expect("expansion")
+    else {
+        panic!("expected enum expansion");
+    };
+    assert_eq!(
+        expansion.message_model().group_label(),
+        Some("User Facing Errors")
+    );
+
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        struct UploadComplete {
+            file_name: String,
+        }
+    };
+    let EsFluentExpansion::Struct(expansion) =
+        EsFluentExpansion::from_derive_input(&input).expect("expansion")
+    else {
+        panic!("expected struct expansion");
+    };
+    assert_eq!(expansion.message_model().group_label(), None);
+}
+
+#[test]
+fn fluent_group_must_be_a_non_empty_single_line() {
+    for input in [
+        parse_quote! {
+            #[derive(EsFluent)]
+            #[fluent(group = "  ")]
+            struct Blank;
+        },
+        parse_quote! {
+            #[derive(EsFluent)]
+            #[fluent(group = "Errors\nand warnings")]
+            struct MultiLine;
+        },
+    ] {
+        let err = StructOpts::from_derive_input(&input).expect_err("group label should fail");
+        assert!(
+            err.to_string()
+                .contains("group label must be a non-empty single line"),
+            "Unexpected error message: {err}"
+        );
+    }
+}
+
+#[test]
+fn fluent_skip_generate_sets_the_message_model_flag() {
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        #[fluent(skip_generate)]
+        struct SharedNotice;
+    };
+    let opts = StructOpts::from_derive_input(&input).expect("StructOpts should parse");
+    assert!(opts.attr_args().skip_generate());
+    let EsFluentExpansion::Struct(expansion) =
+        EsFluentExpansion::from_derive_input(&input).expect("expansion")
+    else {
+        panic!("expected struct expansion");
+    };
+    assert!(expansion.message_model().skip_generate());
+
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        #[fluent(skip_generate)]
+        enum SharedStatus {
+            Online,
+        }
+    };
+    let EsFluentExpansion::Enum(expansion) =
+        EsFluentExpansion::from_derive_input(&input).expect("expansion")
+    else {
+        panic!("expected enum expansion");
+    };
+    assert!(expansion.message_model().skip_generate());
+
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        enum Status {
+            Online,
+        }
+    };
+    let EsFluentExpansion::Enum(expansion) =
+        EsFluentExpansion::from_derive_input(&input).expect("expansion")
+    else {
+        panic!("expected enum expansion");
+    };
+    assert!(!expansion.message_model().skip_generate());
+}
+
+#[test]
+fn fluent_as_terms_sets_the_message_model_flag() {
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        #[fluent(as_terms)]
+        struct BrandName;
+    };
+    let opts = StructOpts::from_derive_input(&input).expect("StructOpts should parse");
+    assert!(opts.attr_args().as_terms());
+    let EsFluentExpansion::Struct(expansion) =
+        EsFluentExpansion::from_derive_input(&input).expect("expansion")
+    else {
+        panic!("expected struct expansion");
+    };
+    assert!(expansion.message_model().is_term());
+
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        #[fluent(as_terms)]
+        enum Platform {
+            Desktop,
+        }
+    };
+    let EsFluentExpansion::Enum(expansion) =
+        EsFluentExpansion::from_derive_input(&input).expect("expansion")
+    else {
+        panic!("expected enum expansion");
+    };
+    assert!(expansion.message_model().is_term());
+
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        enum Status {
+            Online,
+        }
+    };
+    let EsFluentExpansion::Enum(expansion) =
+        EsFluentExpansion::from_derive_input(&input).expect("expansion")
+    else {
+        panic!("expected enum expansion");
+    };
+    assert!(!expansion.message_model().is_term());
+}
+
+#[test]
+fn fluent_export_keys_sets_the_message_model_flag() {
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        #[fluent(export_keys)]
+        struct Banner;
+    };
+    let opts = StructOpts::from_derive_input(&input).expect("StructOpts should parse");
+    assert!(opts.attr_args().export_keys());
+    let EsFluentExpansion::Struct(expansion) =
+        EsFluentExpansion::from_derive_input(&input).expect("expansion")
+    else {
+        panic!("expected struct expansion");
+    };
+    assert!(expansion.message_model().export_keys());
+
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        enum Status {
+            Online,
+        }
+    };
+    let EsFluentExpansion::Enum(expansion) =
+        EsFluentExpansion::from_derive_input(&input).expect("expansion")
+    else {
+        panic!("expected enum expansion");
+    };
+    assert!(!expansion.message_model().export_keys());
+}
+
+#[test]
+fn fluent_test_all_keys_sets_the_enum_message_model_flag() {
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        #[fluent(test_all_keys)]
+        enum Status {
+            Online,
+        }
+    };
+    let opts = EnumOpts::from_derive_input(&input).expect("EnumOpts should parse");
+    assert!(opts.attr_args().test_all_keys());
+    let EsFluentExpansion::Enum(expansion) =
+        EsFluentExpansion::from_derive_input(&input).expect("expansion")
+    else {
+        panic!("expected enum expansion");
+    };
+    assert!(expansion.message_model().test_all_keys());
+    assert!(!expansion.message_model().export_keys());
+
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        #[fluent(test_all_keys, as_terms)]
+        enum Brand {
+            Acme,
+        }
+    };
+    let Err(error) = EsFluentExpansion::from_derive_input(&input) else {
+        panic!("term enums have no runtime lookups to check");
+    };
+    assert!(
+        error
+            .to_string()
+            .contains("`test_all_keys` cannot be combined with `as_terms`")
+    );
+
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        #[fluent(export_keys, as_terms)]
+        struct BrandName;
+    };
+    let Err(error) = EsFluentExpansion::from_derive_input(&input) else {
+        panic!("term types have no message ids to export");
+    };
+    assert!(
+        error
+            .to_string()
+            .contains("`export_keys` cannot be combined with `as_terms`")
+    );
+}
+
+#[test]
+fn fluent_attributes_set_message_entry_attributes() {
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        #[fluent(attributes = ["title"])]
+        enum Toolbar {
+            Save,
+            #[fluent(attributes = ["title", "aria-label"])]
+            Close,
+            #[fluent(attributes = [])]
+            Spacer,
+        }
+    };
+    let EsFluentExpansion::Enum(expansion) =
+        EsFluentExpansion::from_derive_input(&input).expect("expansion")
+    else {
+        panic!("expected enum expansion");
+    };
+    let attributes: Vec<Vec<&str>> = expansion
+        .message_model()
+        .messages()
+        .iter()
+        .map(|entry| {
+            entry
+                .attributes()
+                .iter()
+                .map(|attribute| attribute.as_str())
+                .collect()
+        })
+        .collect();
+    assert_eq!(
+        attributes,
+        [
+            vec!["title"],
+            vec!["title", "aria-label"],
+            Vec::<&str>::new()
+        ]
+    );
+
+    let input: DeriveInput = parse_quote! {
+        #[derive(EsFluent)]
+        #[fluent(attributes = ["label"])]
+        struct SearchField;
+    };
+    let EsFluentExpansion::Struct(expansion) =
+        EsFluentExpansion::from_derive_input(&input).expect("expansion")
+    else {
+        panic!("expected struct expansion");
+    };
+    assert_eq!(expansion.message_entry().attributes()[0].as_str(), "label");
+}
+
+#[test]
+fn fluent_attributes_reject_invalid_duplicate_and_skipped_uses() {
+    for (input, message) in [
+        (
+            parse_quote! {
+                #[derive(EsFluent)]
+                #[fluent(attributes = ["1label"])]
+                enum Invalid {
+                    A,
+                }
+            },
+            "Fluent attribute name must start with an ASCII letter",
+        ),
+        (
+            parse_quote! {
+                #[derive(EsFluent)]
+                #[fluent(attributes = ["label", "label"])]
+                enum Duplicate {
+                    A,
+                }
+            },
+            "duplicate attribute 'label'",
+        ),
+        (
+            parse_quote! {
+                #[derive(EsFluent)]
+                enum Skipped {
+                    #[fluent(skip, attributes = ["label"])]
+                    A,
+                }
+            },
+            "Cannot use #[fluent(attributes = [...])] on a skipped variant",
+        ),
+    ] {
+        let input: DeriveInput = input;
+        let err = EnumOpts::from_derive_input(&input).expect_err("attributes should fail");
+        assert!(
+            err.to_string().contains(message),
+            "Unexpected error message: {err}"
+        );
+    }
+}
+
 #[test]
 fn struct_fluent_with_namespace_folder() {
     let input: DeriveInput = parse_quote! {
@@ -0,0 +1,94 @@
+use es_fluent::EsFluentChoice as _;
+use es_fluent_derive::EsFluentChoice;
+
+#[derive(EsFluentChoice)]
+pub enum DefaultKebab {
+    VeryHigh,
+}
+
+#[derive(EsFluentChoice)]
+#[fluent_choice(rename_all = "snake_case")]
+pub enum Snake {
+    VeryHigh,
+}
+
+#[derive(EsFluentChoice)]
+#[fluent_choice(rename_all = "PascalCase")]
+pub enum Pascal {
+    VeryHigh,
+}
+
+#[derive(EsFluentChoice)]
+#[fluent_choice(rename_all = "camelCase")]
+pub enum Camel {
+    VeryHigh,
+}
+
+#[derive(EsFluentChoice)]
+#[fluent_choice(rename_all = "kebab-case")]
+pub enum Kebab {
+    VeryHigh,
+}
+
+#[derive(EsFluentChoice)]
+#[fluent_choice(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ScreamingSnake {
+    VeryHigh,
+}
+
+#[derive(EsFluentChoice)]
+#[fluent_choice(rename_all = "SCREAMING-KEBAB-CASE")]
+pub enum ScreamingKebab {
+    VeryHigh,
+}
+
+#[derive(EsFluentChoice)]
+#[fluent_choice(rename_all = "Train-Case")]
+pub enum Train {
+    VeryHigh,
+}
+
+#[derive(EsFluentChoice)]
+#[fluent_choice(rename_all = "lowercase")]
+pub enum Lower {
+    VeryHigh,
+}
+
+#[derive(EsFluentChoice)]
+#[fluent_choice(rename_all = "UPPERCASE")]
+pub enum Upper {
+    VeryHigh,
+}
+
+#[derive(EsFluentChoice)]
+#[fluent_choice(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Renamed {
+    VeryHigh,
+    #[fluent_choice(rename = "other")]
+    Fallback,
+}
+
+fn choice<T: es_fluent::EsFluentChoice>(value: T) -> &'static str {
+    value.as_fluent_choice().as_str()
+}
+
+fn main() {
+    assert_eq!(choice(DefaultKebab::VeryHigh), "very-high");
+    assert_eq!(choice(Snake::VeryHigh), "very_high");
+    assert_eq!(choice(Pascal::VeryHigh), "VeryHigh");
+    assert_eq!(choice(Camel::VeryHigh), "veryHigh");
+    assert_eq!(choice(Kebab::VeryHigh), "very-high");
+    assert_eq!(choice(ScreamingSnake::VeryHigh), "VERY_HIGH");
+    assert_eq!(choice(ScreamingKebab::VeryHigh), "VERY-HIGH");
+    assert_eq!(choice(Train::VeryHigh), "Very-High");
+    assert_eq!(choice(Lower::VeryHigh), "veryhigh");
+    assert_eq!(choice(Upper::VeryHigh), "VERYHIGH");
+
+    assert_eq!(choice(Renamed::VeryHigh), "VERY_HIGH");
+    assert_eq!(choice(Renamed::Fallback), "other");
+    let choices = Renamed::fluent_choices()
+        .iter()
+        .map(|choice| choice.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(choices, ["VERY_HIGH", "other"]);
+}
@@ -0,0 +1,10 @@
+use es_fluent_derive::EsFluentChoice;
+
+#[derive(EsFluentChoice)]
+pub enum Severity {
+    High,
+    #[fluent_choice(rename = "high")]
+    Urgent,
+}
+
+fn main() {}
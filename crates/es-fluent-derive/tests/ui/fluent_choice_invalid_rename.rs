@@ -0,0 +1,9 @@
+use es_fluent_derive::EsFluentChoice;
+
+#[derive(EsFluentChoice)]
+pub enum Severity {
+    #[fluent_choice(rename = "very high")]
+    VeryHigh,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use es_fluent_derive::EsFluent;
+
+#[derive(EsFluent)]
+pub enum Severity {
+    #[fluent_choice(rename = "top")]
+    High,
+}
+
+fn main() {}
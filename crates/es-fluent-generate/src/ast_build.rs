@@ -1,46 +1,376 @@
 use crate::model::{OwnedVariant, compare_type_infos};
 use crate::value::ValueFormatter;
 use es_fluent_shared::EsFluentResult;
-use es_fluent_shared::registry::FtlTypeInfo;
+use es_fluent_shared::fluent::{FluentArgumentName, FluentEntryId, FluentVariantKey};
+use es_fluent_shared::registry::{FtlTypeInfo, FtlVariant};
 use fluent_syntax::ast;
+use heck::ToTitleCase as _;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+/// Comment line prefix of the provenance written by [`annotate_sources`].
+///
+/// The `@` keeps it apart from comments translators write, so only lines with
+/// this exact prefix are ever replaced or removed.
+const SOURCE_ANNOTATION_PREFIX: &str = "@source: ";
 
 /// Create a group comment entry for a type section.
-pub(crate) fn create_group_comment_entry(type_name: &str) -> ast::Entry<String> {
+pub(crate) fn create_group_comment_entry(group_name: &str) -> ast::Entry<String> {
     ast::Entry::GroupComment(ast::Comment {
-        content: vec![type_name.to_owned()],
+        content: vec![group_name.to_owned()],
+    })
+}
+
+/// First comment line of a header written by [`insert_file_header`], which
+/// marks the banner as generated so a later run can replace it.
+const FILE_HEADER_SENTINEL: &str = "@generated by es-fluent";
+
+/// Put `header` at the top of `resource` as a `###` resource comment, one
+/// comment line per header line after [`FILE_HEADER_SENTINEL`].
+///
+/// A header written by an earlier run is replaced, whatever its text was.
+/// Unmarked resource comments with the header's text are also removed, so a
+/// banner from before the sentinel existed is not duplicated.
+pub(crate) fn insert_file_header(resource: &mut ast::Resource<String>, header: &str) {
+    let unmarked: Vec<String> = header.lines().map(str::to_owned).collect();
+    resource.body.retain(|entry| {
+        !is_file_header(entry)
+            && !matches!(entry, ast::Entry::ResourceComment(existing) if existing.content == unmarked)
+    });
+    let content = std::iter::once(FILE_HEADER_SENTINEL.to_owned())
+        .chain(unmarked)
+        .collect();
+    resource
+        .body
+        .insert(0, ast::Entry::ResourceComment(ast::Comment { content }));
+}
+
+/// Remove a header written by [`insert_file_header`], for targets that no
+/// longer configure one.
+pub(crate) fn remove_file_header(resource: &mut ast::Resource<String>) {
+    resource.body.retain(|entry| !is_file_header(entry));
+}
+
+fn is_file_header(entry: &ast::Entry<String>) -> bool {
+    matches!(
+        entry,
+        ast::Entry::ResourceComment(comment)
+            if comment.content.first().map(String::as_str) == Some(FILE_HEADER_SENTINEL)
+    )
+}
+
+/// Remove the terms named `-{id}` for every id in `ids`.
+pub(crate) fn remove_terms(resource: &mut ast::Resource<String>, ids: &HashSet<FluentEntryId>) {
+    resource.body.retain(|entry| match entry {
+        ast::Entry::Term(term) => !ids.iter().any(|id| id.as_str() == term.id.name),
+        _ => true,
+    });
+}
+
+/// Create the resource entry for an owned variant, a term or a message.
+pub(crate) fn create_entry(variant: &OwnedVariant) -> ast::Entry<String> {
+    if variant.links_message {
+        create_term_link_entry(variant)
+    } else if variant.is_term {
+        create_term_entry(variant)
+    } else {
+        create_message_entry(variant)
+    }
+}
+
+/// Create a term that references the message of the same name.
+///
+/// Message authors can then use `{ -usa_state-A }` where the linked type's
+/// translated text is needed.
+fn create_term_link_entry(variant: &OwnedVariant) -> ast::Entry<String> {
+    let entry_id = variant.entry_id().as_str();
+
+    ast::Entry::Term(ast::Term {
+        id: ast::Identifier {
+            name: entry_id.to_string(),
+        },
+        value: ast::Pattern {
+            elements: vec![ast::PatternElement::Placeable {
+                expression: ast::Expression::Inline(ast::InlineExpression::MessageReference {
+                    id: ast::Identifier {
+                        name: entry_id.to_string(),
+                    },
+                    attribute: None,
+                }),
+            }],
+        },
+        attributes: Vec::new(),
+        comment: None,
+    })
+}
+
+/// Create a term entry for a variant of a `#[fluent(as_terms)]` type.
+///
+/// The value is scaffolded like a message's, so `-brand_name = Brand Name`.
+fn create_term_entry(variant: &OwnedVariant) -> ast::Entry<String> {
+    ast::Entry::Term(ast::Term {
+        id: ast::Identifier {
+            name: variant.entry_id().as_str().to_string(),
+        },
+        value: create_pattern(variant),
+        attributes: create_attributes(variant),
+        comment: create_doc_comment(variant),
     })
 }
 
 /// Create a message entry from an owned variant definition.
+///
+/// Placeables follow `variant.args` exactly, which derive metadata records in
+/// field declaration order (`$f0`, `$f1`, ... for tuple fields), so the same
+/// variant always renders the same line. A captured doc comment becomes the
+/// message's `#` comment, and declared attributes follow the value.
 pub(crate) fn create_message_entry(variant: &OwnedVariant) -> ast::Entry<String> {
-    let message_id = ast::Identifier {
-        name: variant.entry_id().as_str().to_string(),
+    ast::Entry::Message(ast::Message {
+        id: ast::Identifier {
+            name: variant.entry_id().as_str().to_string(),
+        },
+        value: Some(create_pattern(variant)),
+        attributes: create_attributes(variant),
+        comment: create_doc_comment(variant),
+    })
+}
+
+/// Append the declared attributes that an existing message or term does not
+/// define yet. Its value and the attributes it already has stay untouched.
+pub(crate) fn add_missing_attributes(entry: &mut ast::Entry<String>, variant: &OwnedVariant) {
+    let attributes = match entry {
+        ast::Entry::Message(message) => &mut message.attributes,
+        ast::Entry::Term(term) => &mut term.attributes,
+        _ => return,
     };
+    for attribute in create_attributes(variant) {
+        if !attributes
+            .iter()
+            .any(|existing| existing.id.name == attribute.id.name)
+        {
+            attributes.push(attribute);
+        }
+    }
+}
+
+/// Scaffold one `.name = ...` attribute per `#[fluent(attributes = [...])]`
+/// entry, so `.placeholder` on `LoginForm` reads `Login Form Placeholder`.
+fn create_attributes(variant: &OwnedVariant) -> Vec<ast::Attribute<String>> {
+    variant
+        .attributes
+        .iter()
+        .map(|attribute| ast::Attribute {
+            id: ast::Identifier {
+                name: attribute.to_string(),
+            },
+            value: ast::Pattern {
+                elements: vec![ast::PatternElement::TextElement {
+                    value: format!(
+                        "{} {}",
+                        ValueFormatter::expand(&variant.name),
+                        attribute.as_str().to_title_case()
+                    ),
+                }],
+            },
+        })
+        .collect()
+}
 
+fn create_pattern(variant: &OwnedVariant) -> ast::Pattern<String> {
     let base_value = ValueFormatter::expand(&variant.name);
     let mut elements = vec![ast::PatternElement::TextElement { value: base_value }];
 
     for arg_name in &variant.args {
+        let reference = ast::InlineExpression::VariableReference {
+            id: ast::Identifier {
+                name: arg_name.to_string(),
+            },
+        };
+        let expression = match variant.selector_choices(arg_name) {
+            Some(choices) => create_select_expression(reference, choices),
+            None => ast::Expression::Inline(reference),
+        };
+
         elements.push(ast::PatternElement::TextElement { value: " ".into() });
-        elements.push(ast::PatternElement::Placeable {
-            expression: ast::Expression::Inline(ast::InlineExpression::VariableReference {
-                id: ast::Identifier {
-                    name: arg_name.to_string(),
-                },
-            }),
-        });
+        elements.push(ast::PatternElement::Placeable { expression });
     }
 
-    let pattern = ast::Pattern { elements };
+    let mut pattern = ast::Pattern { elements };
+    for arg_name in variant.plurals.iter().rev() {
+        pattern = create_plural_pattern(arg_name, pattern);
+    }
+    pattern
+}
 
-    ast::Entry::Message(ast::Message {
-        id: message_id,
-        value: Some(pattern),
-        attributes: Vec::new(),
-        comment: None,
+fn create_doc_comment(variant: &OwnedVariant) -> Option<ast::Comment<String>> {
+    variant.doc.as_ref().map(|doc| ast::Comment {
+        content: doc.lines().map(str::to_owned).collect(),
     })
 }
 
+/// Record the Rust item behind each generated entry as a
+/// `# @source: module::Type::Variant` comment line after any doc comment.
+///
+/// A term link is attributed to the linked item when it is among `items`, and
+/// otherwise to the first item linking to it. An annotation from a previous
+/// run is replaced, so the line follows a type that moved. Keys in `keep` and
+/// entries that no item produces are left untouched.
+pub(crate) fn annotate_sources(
+    resource: &mut ast::Resource<String>,
+    items: &[&FtlTypeInfo],
+    keep: &HashSet<String>,
+) {
+    let mut sources: HashMap<String, String> = items
+        .iter()
+        .flat_map(|info| {
+            info.variants().iter().map(move |variant| {
+                let key = if info.is_term() {
+                    format!("-{}", variant.entry_id().as_str())
+                } else {
+                    variant.entry_id().as_str().to_string()
+                };
+                (key, source_annotation(info, variant))
+            })
+        })
+        .collect();
+    for info in items {
+        for variant in info.variants() {
+            for link in variant.term_links() {
+                for entry_id in link.entry_ids() {
+                    let source = sources
+                        .get(entry_id.as_str())
+                        .cloned()
+                        .unwrap_or_else(|| source_annotation(info, variant));
+                    sources
+                        .entry(format!("-{}", entry_id.as_str()))
+                        .or_insert(source);
+                }
+            }
+        }
+    }
+
+    for entry in &mut resource.body {
+        let Some(key) = crate::ftl::entry_key(entry).map(Cow::into_owned) else {
+            continue;
+        };
+        let Some(source) = sources.get(&key) else {
+            continue;
+        };
+        if keep.contains(&key) {
+            continue;
+        }
+        let Some(comment) = entry_comment_mut(entry) else {
+            continue;
+        };
+        let mut content = comment
+            .take()
+            .map(|comment| comment.content)
+            .unwrap_or_default();
+        content.retain(|line| !line.starts_with(SOURCE_ANNOTATION_PREFIX));
+        content.push(format!("{SOURCE_ANNOTATION_PREFIX}{source}"));
+        *comment = Some(ast::Comment { content });
+    }
+}
+
+/// Remove the lines written by [`annotate_sources`] from every message and
+/// term, for targets that no longer annotate sources.
+pub(crate) fn strip_source_annotations(resource: &mut ast::Resource<String>) {
+    for entry in &mut resource.body {
+        let Some(comment) = entry_comment_mut(entry) else {
+            continue;
+        };
+        if let Some(existing) = comment {
+            existing
+                .content
+                .retain(|line| !line.starts_with(SOURCE_ANNOTATION_PREFIX));
+            if existing.content.is_empty() {
+                *comment = None;
+            }
+        }
+    }
+}
+
+fn entry_comment_mut(entry: &mut ast::Entry<String>) -> Option<&mut Option<ast::Comment<String>>> {
+    match entry {
+        ast::Entry::Message(message) => Some(&mut message.comment),
+        ast::Entry::Term(term) => Some(&mut term.comment),
+        _ => None,
+    }
+}
+
+/// `module::Type::Variant`, or `module::Type` for struct and label entries
+/// whose variant is the type itself.
+fn source_annotation(info: &FtlTypeInfo, variant: &FtlVariant) -> String {
+    let type_path = format!("{}::{}", info.module_path(), info.type_name());
+    if variant.name() == info.type_name() {
+        type_path
+    } else {
+        format!("{type_path}::{}", variant.name())
+    }
+}
+
+/// Wrap `pattern` in a plural select on `arg_name`.
+///
+/// Both the `[one]` and the default `[other]` branch start from the base
+/// pattern, so translators only adjust the wording per CLDR category.
+fn create_plural_pattern(
+    arg_name: &FluentArgumentName,
+    pattern: ast::Pattern<String>,
+) -> ast::Pattern<String> {
+    let selector = ast::InlineExpression::VariableReference {
+        id: ast::Identifier {
+            name: arg_name.to_string(),
+        },
+    };
+    let variants = ["one", "other"]
+        .into_iter()
+        .map(|category| ast::Variant {
+            key: ast::VariantKey::Identifier {
+                name: category.to_string(),
+            },
+            value: pattern.clone(),
+            default: category == "other",
+        })
+        .collect();
+
+    ast::Pattern {
+        elements: vec![ast::PatternElement::Placeable {
+            expression: ast::Expression::Select { selector, variants },
+        }],
+    }
+}
+
+/// Scaffold a select expression with one variant per choice key.
+///
+/// The `other` key is the default when present, otherwise the last key.
+fn create_select_expression(
+    selector: ast::InlineExpression<String>,
+    choices: &[FluentVariantKey],
+) -> ast::Expression<String> {
+    let default_index = choices
+        .iter()
+        .position(|choice| choice.as_str() == "other")
+        .unwrap_or(choices.len().saturating_sub(1));
+
+    let variants = choices
+        .iter()
+        .enumerate()
+        .map(|(index, choice)| ast::Variant {
+            key: ast::VariantKey::Identifier {
+                name: choice.as_str().to_string(),
+            },
+            value: ast::Pattern {
+                elements: vec![ast::PatternElement::TextElement {
+                    value: choice.as_str().to_title_case(),
+                }],
+            },
+            default: index == default_index,
+        })
+        .collect();
+
+    ast::Expression::Select { selector, variants }
+}
+
 /// Build a full target resource from the current registered type infos.
 pub(crate) fn build_target_resource(
     items: &[&FtlTypeInfo],
@@ -51,10 +381,10 @@ pub(crate) fn build_target_resource(
     sorted_items.sort_by(compare_type_infos);
 
     for info in &sorted_items {
-        body.push(create_group_comment_entry(&info.type_name));
+        body.push(create_group_comment_entry(&info.group_name));
 
         for variant in &info.variants {
-            body.push(create_message_entry(variant));
+            body.push(create_entry(variant));
         }
     }
 
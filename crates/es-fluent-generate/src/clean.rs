@@ -1,27 +1,43 @@
-use es_fluent_shared::EsFluentResult;
+use crate::FtlTarget;
 use es_fluent_shared::registry::FtlTypeInfo;
 use es_fluent_shared::resource::ModuleResourceSpec;
+use es_fluent_shared::{EsFluentResult, GenerateReport};
 use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 /// Cleans a Fluent translation file by removing unused orphan keys while preserving existing translations.
-pub fn clean<P: AsRef<Path>, M: AsRef<Path>, I: AsRef<FtlTypeInfo>>(
-    crate_name: &str,
+pub fn clean<'a, P: AsRef<Path>, M: AsRef<Path>, I: AsRef<FtlTypeInfo>>(
+    target: impl Into<FtlTarget<'a>>,
     i18n_path: P,
     manifest_dir: M,
     items: &[I],
     dry_run: bool,
 ) -> EsFluentResult<bool> {
+    clean_report(target, i18n_path, manifest_dir, items, dry_run).map(|report| report.changed)
+}
+
+/// Cleans Fluent translation files like [`clean`] and reports which keys were
+/// removed, including keys in stale files that are deleted.
+pub fn clean_report<'a, P: AsRef<Path>, M: AsRef<Path>, I: AsRef<FtlTypeInfo>>(
+    target: impl Into<FtlTarget<'a>>,
+    i18n_path: P,
+    manifest_dir: M,
+    items: &[I],
+    dry_run: bool,
+) -> EsFluentResult<GenerateReport> {
+    let target = target.into();
     let i18n_path = i18n_path.as_ref();
     let manifest_dir = manifest_dir.as_ref();
-    let mut any_changed = false;
+    let mut report = GenerateReport::default();
 
     let operation = crate::pipeline::OutputOperation::Clean;
-    let planned_outputs =
-        crate::pipeline::plan_outputs(crate_name, i18n_path, manifest_dir, items)?;
-    let main_resource = ModuleResourceSpec::base(crate_name, true);
-    let main_file_path = i18n_path.join(main_resource.locale_relative_path.as_str());
+    let planned_outputs = crate::pipeline::plan_outputs(target, i18n_path, manifest_dir, items)?;
+    let main_resource =
+        ModuleResourceSpec::base_with_file_stem(target.crate_name(), target.base_file_stem(), true);
+    let main_file_path = target
+        .layout()
+        .resolve_in_locale_dir(i18n_path, main_resource.locale_relative_path.as_str());
     let has_main_output = planned_outputs.iter().any(|output| output.route.is_base());
     let expected_namespace_files = planned_outputs
         .iter()
@@ -30,24 +46,33 @@ pub fn clean<P: AsRef<Path>, M: AsRef<Path>, I: AsRef<FtlTypeInfo>>(
         .collect::<HashSet<_>>();
 
     for output in planned_outputs {
-        if crate::pipeline::apply_output_operation(output, &operation, dry_run)? {
-            any_changed = true;
-        }
-    }
-    if !has_main_output && remove_stale_main_file(&main_file_path, dry_run)? {
-        any_changed = true;
+        report.merge(crate::pipeline::apply_output_operation(
+            output,
+            &operation,
+            &[],
+            dry_run,
+        )?);
     }
-    if remove_stale_namespace_files(crate_name, i18n_path, &expected_namespace_files, dry_run)? {
-        any_changed = true;
+    if !has_main_output {
+        report.merge(remove_stale_main_file(&main_file_path, dry_run)?);
     }
-
-    Ok(any_changed)
+    let namespace_root = target
+        .layout()
+        .resolve_in_locale_dir(i18n_path, target.crate_name());
+    report.merge(remove_stale_namespace_files(
+        &namespace_root,
+        &expected_namespace_files,
+        dry_run,
+    )?);
+
+    Ok(report)
 }
 
-fn remove_stale_main_file(file_path: &Path, dry_run: bool) -> EsFluentResult<bool> {
+fn remove_stale_main_file(file_path: &Path, dry_run: bool) -> EsFluentResult<GenerateReport> {
     if !file_path.is_file() {
-        return Ok(false);
+        return Ok(GenerateReport::default());
     }
+    let report = crate::report::removed_file(file_path)?;
 
     if dry_run {
         let display_path = fs::canonicalize(file_path).unwrap_or_else(|_| file_path.to_path_buf());
@@ -55,26 +80,24 @@ fn remove_stale_main_file(file_path: &Path, dry_run: bool) -> EsFluentResult<boo
             "Would remove stale main FTL file: {}",
             display_path.display()
         );
-        return Ok(true);
+        return Ok(report);
     }
 
     fs::remove_file(file_path)?;
-    Ok(true)
+    Ok(report)
 }
 
 fn remove_stale_namespace_files(
-    crate_name: &str,
-    i18n_path: &Path,
+    namespace_root: &Path,
     expected_namespace_files: &HashSet<PathBuf>,
     dry_run: bool,
-) -> EsFluentResult<bool> {
-    let namespace_root = i18n_path.join(crate_name);
+) -> EsFluentResult<GenerateReport> {
     if !namespace_root.is_dir() {
-        return Ok(false);
+        return Ok(GenerateReport::default());
     }
 
-    let mut changed = false;
-    let mut pending = vec![namespace_root.clone()];
+    let mut report = GenerateReport::default();
+    let mut pending = vec![namespace_root.to_path_buf()];
 
     while let Some(dir) = pending.pop() {
         for entry in fs::read_dir(&dir)? {
@@ -92,18 +115,18 @@ fn remove_stale_namespace_files(
                 continue;
             }
 
+            report.merge(crate::report::removed_file(&path)?);
             if !dry_run {
                 fs::remove_file(&path)?;
             }
-            changed = true;
         }
     }
 
-    if changed && !dry_run {
-        remove_empty_namespace_dirs(&namespace_root)?;
+    if report.changed && !dry_run {
+        remove_empty_namespace_dirs(namespace_root)?;
     }
 
-    Ok(changed)
+    Ok(report)
 }
 
 fn remove_empty_namespace_dirs(root: &Path) -> EsFluentResult<()> {
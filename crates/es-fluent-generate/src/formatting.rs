@@ -1,6 +1,105 @@
 use es_fluent_shared::namer::FluentKey;
 use fluent_syntax::{ast, serializer};
 use heck::ToSnakeCase as _;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Sort an FTL resource's entries alphabetically and serialize the result.
+///
+/// See [`sort_ftl_entries`] for the ordering rules.
+pub fn sort_ftl_resource(resource: &ast::Resource<String>) -> String {
+    serializer::serialize(&sort_ftl_entries(resource))
+}
+
+/// Sort an existing FTL file's entries like `generate` does and serialize the
+/// result, without the Rust type metadata `generate` has.
+///
+/// The group-to-type mapping `generate` sorts with is recovered from the file
+/// itself: see [`labeled_group_type_names`].
+pub fn sort_ftl_file_resource(resource: &ast::Resource<String>) -> String {
+    serializer::serialize(&sort_ftl_entries_with_groups(
+        resource,
+        &labeled_group_type_names(resource),
+    ))
+}
+
+/// Map each labeled `## Group` header of a resource to the type segments of
+/// the entries it holds.
+///
+/// A header that matches none of its entries is taken to be a
+/// `#[fluent(group = "...")]` label. Mapping it to the type segments of its
+/// entries (`app_error` for `app_error-Timeout`) keeps those entries in that
+/// section, as the type names behind the label do for `generate`.
+fn labeled_group_type_names(
+    resource: &ast::Resource<String>,
+) -> BTreeMap<String, BTreeSet<String>> {
+    let mut sections: Vec<(String, Vec<&str>)> = Vec::new();
+    for entry in &resource.body {
+        let key = match entry {
+            ast::Entry::GroupComment(comment) => {
+                sections.push((group_name(comment), Vec::new()));
+                continue;
+            },
+            ast::Entry::Message(msg) => msg.id.name.as_str(),
+            ast::Entry::Term(term) => term.id.name.as_str(),
+            _ => continue,
+        };
+        if let Some((_, keys)) = sections.last_mut() {
+            keys.push(key);
+        }
+    }
+
+    let mut groups: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for (name, keys) in sections {
+        let (full_matcher_key, fallback_matcher_key) = group_matcher(&name);
+        let matches_header = |key: &&str| {
+            let key = normalize_matcher(key);
+            key.starts_with(&full_matcher_key)
+                || fallback_matcher_key
+                    .as_deref()
+                    .is_some_and(|fallback| key.starts_with(fallback))
+        };
+        if full_matcher_key.is_empty() || keys.is_empty() || keys.iter().any(matches_header) {
+            continue;
+        }
+
+        groups
+            .entry(name)
+            .or_default()
+            .extend(keys.into_iter().map(|key| {
+                let segment = key.split(FluentKey::DELIMITER).next().unwrap_or(key);
+                segment
+                    .strip_suffix(FluentKey::LABEL_SUFFIX)
+                    .unwrap_or(segment)
+                    .to_string()
+            }));
+    }
+    groups
+}
+
+/// The text of a group comment, used as its section name and sort key.
+fn group_name(comment: &ast::Comment<String>) -> String {
+    comment.content.iter().map(|s| s.trim()).collect::<String>()
+}
+
+/// Normalize a string for key matching (keep alphanumerics, lowercase).
+fn normalize_matcher(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+/// The normalized matcher for a group or type name (e.g. "buttonstate"), with
+/// an optional fallback that strips "_variants".
+fn group_matcher(name: &str) -> (String, Option<String>) {
+    let name_snake = name.to_snake_case();
+    let full_matcher_key = normalize_matcher(&name_snake);
+    let fallback_matcher_key = name_snake
+        .strip_suffix("_variants")
+        .map(normalize_matcher)
+        .filter(|fallback| *fallback != full_matcher_key);
+    (full_matcher_key, fallback_matcher_key)
+}
 
 /// Sort an FTL resource's entries alphabetically.
 ///
@@ -11,18 +110,27 @@ use heck::ToSnakeCase as _;
 /// to section headers (heuristically matching snake_case keys to PascalCase headers).
 ///
 /// Keys ending in `_label` (defined by `FluentKey::LABEL_SUFFIX`) are sorted to the top
-/// of their respective sections.
-pub fn sort_ftl_resource(resource: &ast::Resource<String>) -> String {
+/// of their respective sections. Junk entries are dropped.
+pub fn sort_ftl_entries(resource: &ast::Resource<String>) -> ast::Resource<String> {
+    sort_ftl_entries_with_groups(resource, &BTreeMap::new())
+}
+
+/// Sort an FTL resource's entries like [`sort_ftl_entries`], also matching keys
+/// against the Rust type names behind each labeled group header.
+pub(crate) fn sort_ftl_entries_with_groups(
+    resource: &ast::Resource<String>,
+    group_type_names: &BTreeMap<String, BTreeSet<String>>,
+) -> ast::Resource<String> {
     #[derive(Debug, Default)]
     struct Section {
         /// The group comments (## Header) and any associated logic
         header: Vec<ast::Entry<String>>,
         /// The sort key derived from the header comment (e.g. "ButtonState")
         header_sort_key: String,
-        /// Normalized sort key for matching (e.g. "buttonstate")
-        full_matcher_key: String,
-        /// Optional fallback matcher (e.g. stripping "_variants")
-        fallback_matcher_key: Option<String>,
+        /// Normalized matchers for the header and the type names behind it
+        /// (e.g. "buttonstate"), each with an optional fallback (e.g.
+        /// stripping "_variants")
+        matchers: Vec<(String, Option<String>)>,
         /// Messages in this section
         messages: Vec<MessageEntry>,
     }
@@ -39,19 +147,6 @@ pub fn sort_ftl_resource(resource: &ast::Resource<String>) -> String {
     let mut current_section = Section::default();
     let mut current_comments: Vec<ast::Entry<String>> = Vec::new();
 
-    // Helper to extract text from a GroupComment for sorting
-    let get_group_name = |comment: &ast::Comment<String>| -> String {
-        comment.content.iter().map(|s| s.trim()).collect::<String>()
-    };
-
-    // Helper to normalize strings for matching (remove non-alphanumeric, lowercase)
-    let normalize = |s: &str| -> String {
-        s.chars()
-            .filter(|c| c.is_alphanumeric())
-            .flat_map(|c| c.to_lowercase())
-            .collect()
-    };
-
     for entry in &resource.body {
         match entry {
             ast::Entry::GroupComment(comment) => {
@@ -60,16 +155,17 @@ pub fn sort_ftl_resource(resource: &ast::Resource<String>) -> String {
 
                 // Start new section with this header
                 current_section.header.push(entry.clone());
-                let name = get_group_name(comment);
-                let name_snake = name.to_snake_case();
-                let full_matcher_key = normalize(&name_snake);
-                let fallback_matcher_key = name_snake
-                    .strip_suffix("_variants")
-                    .map(normalize)
-                    .filter(|fallback| *fallback != full_matcher_key);
-                current_section.header_sort_key = name.clone();
-                current_section.full_matcher_key = full_matcher_key;
-                current_section.fallback_matcher_key = fallback_matcher_key;
+                let name = group_name(comment);
+                let type_names = group_type_names
+                    .get(&name)
+                    .into_iter()
+                    .flatten()
+                    .map(String::as_str);
+                current_section.matchers = std::iter::once(name.as_str())
+                    .chain(type_names)
+                    .map(group_matcher)
+                    .collect();
+                current_section.header_sort_key = name;
 
                 // Adopt pending comments
                 if !current_comments.is_empty() {
@@ -123,31 +219,38 @@ pub fn sort_ftl_resource(resource: &ast::Resource<String>) -> String {
     }
 
     for msg in all_messages {
-        let msg_clean = normalize(&msg.key);
+        let msg_clean = normalize_matcher(&msg.key);
 
         let mut best_score = 0;
         let mut best_section_idx = None;
 
         let mut matched_full = false;
         for (idx, section) in sections.iter().enumerate() {
-            if section.full_matcher_key.is_empty() {
-                continue;
-            }
-            if msg_clean.starts_with(&section.full_matcher_key) {
-                matched_full = true;
-                let score = section.full_matcher_key.len();
-                if score > best_score {
-                    best_score = score;
-                    best_section_idx = Some(idx);
+            for (full_matcher_key, _) in &section.matchers {
+                if full_matcher_key.is_empty() {
+                    continue;
+                }
+                if msg_clean.starts_with(full_matcher_key) {
+                    matched_full = true;
+                    let score = full_matcher_key.len();
+                    if score > best_score {
+                        best_score = score;
+                        best_section_idx = Some(idx);
+                    }
                 }
             }
         }
 
         if !matched_full {
             for (idx, section) in sections.iter().enumerate() {
-                if let Some(fallback) = section.fallback_matcher_key.as_deref()
-                    && msg_clean.starts_with(fallback)
+                for fallback in section
+                    .matchers
+                    .iter()
+                    .filter_map(|(_, fallback)| fallback.as_deref())
                 {
+                    if !msg_clean.starts_with(fallback) {
+                        continue;
+                    }
                     let score = fallback.len();
                     if score > best_score {
                         best_score = score;
@@ -223,8 +326,48 @@ pub fn sort_ftl_resource(resource: &ast::Resource<String>) -> String {
     // Append any final trailing comments (rare)
     sorted_body.extend(current_comments);
 
-    let sorted_resource = ast::Resource { body: sorted_body };
-    serializer::serialize(&sorted_resource)
+    ast::Resource { body: sorted_body }
+}
+
+/// Normalize blank lines in serialized FTL content.
+///
+/// `fluent-syntax` does not model blank lines, so the serializer output is
+/// post-processed to keep exactly one blank line before each group comment
+/// (`## Group`) and none at the end of the file. Other blank lines are left
+/// alone because they may belong to multiline patterns.
+pub fn normalize_blank_lines(content: &str) -> String {
+    let is_group_comment = |line: &str| line.starts_with("##") && !line.starts_with("###");
+
+    let mut lines: Vec<&str> = Vec::new();
+    for line in content.lines() {
+        let starts_group = is_group_comment(line)
+            && !lines
+                .last()
+                .is_some_and(|previous| is_group_comment(previous));
+        if starts_group {
+            while lines
+                .last()
+                .is_some_and(|previous| previous.trim().is_empty())
+            {
+                lines.pop();
+            }
+            if !lines.is_empty() {
+                lines.push("");
+            }
+        }
+        lines.push(line);
+    }
+
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    if lines.is_empty() {
+        return String::new();
+    }
+    let mut normalized = lines.join("\n");
+    normalized.push('\n');
+    normalized
 }
 
 /// Compare two items, prioritizing those marked as "label".
@@ -344,6 +487,28 @@ user_name = Name"#;
         assert!(msg_pos > header_pos);
     }
 
+    #[test]
+    fn normalize_blank_lines_keeps_one_blank_line_before_groups() {
+        let content = "## First\n## continued\n\nfirst = One\n\n\n\n## Second\n\nsecond = Two\n\n## Empty\n\n";
+
+        assert_eq!(
+            normalize_blank_lines(content),
+            "## First\n## continued\n\nfirst = One\n\n## Second\n\nsecond = Two\n\n## Empty\n"
+        );
+        assert_eq!(
+            normalize_blank_lines("a = A\n## Tight\nb = B"),
+            "a = A\n\n## Tight\nb = B\n"
+        );
+        assert_eq!(normalize_blank_lines("\n\n"), "");
+    }
+
+    #[test]
+    fn normalize_blank_lines_leaves_pattern_blank_lines_alone() {
+        let content = "### Resource\n\nmulti =\n    First\n\n    Second\n";
+
+        assert_eq!(normalize_blank_lines(content), content);
+    }
+
     #[test]
     fn test_sort_ftl_skips_junk_entries_from_partial_parses() {
         let content = "## Group\nvalid = ok\nbroken = {\n";
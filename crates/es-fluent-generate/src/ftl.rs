@@ -28,13 +28,31 @@ pub fn format_parse_errors(errors: &[fluent_syntax::parser::ParserError]) -> Str
     }
 }
 
+/// Strip a leading UTF-8 BOM and convert CRLF line endings to LF.
+///
+/// Files saved by Windows editors otherwise parse the BOM as `Junk`.
+pub fn normalize_ftl_source(content: String) -> String {
+    let content = match content.strip_prefix('\u{feff}') {
+        Some(stripped) => stripped.to_string(),
+        None => content,
+    };
+    if content.contains('\r') {
+        content.replace("\r\n", "\n")
+    } else {
+        content
+    }
+}
+
 /// Parse raw FTL content, returning a partial resource plus any parse errors.
+///
+/// The content is passed through [`normalize_ftl_source`] first.
 pub fn parse_ftl_content(
     content: String,
 ) -> (
     ast::Resource<String>,
     Vec<fluent_syntax::parser::ParserError>,
 ) {
+    let content = normalize_ftl_source(content);
     if content.trim().is_empty() {
         return (empty_resource(), Vec::new());
     }
@@ -219,6 +237,24 @@ mod tests {
         assert!(!errors.is_empty());
     }
 
+    #[test]
+    fn parse_ftl_content_ignores_bom_and_crlf() {
+        let (resource, errors) =
+            parse_ftl_content("\u{feff}hello = Hello\r\nworld = World\r\n".to_string());
+        assert!(errors.is_empty());
+        assert_eq!(resource.body.len(), 2);
+        assert!(
+            resource
+                .body
+                .iter()
+                .all(|entry| matches!(entry, ast::Entry::Message(_)))
+        );
+        assert_eq!(
+            extract_message_keys(&resource),
+            HashSet::from(["hello".to_string(), "world".to_string()])
+        );
+    }
+
     #[test]
     fn parse_ftl_file_rejects_parse_errors() {
         let temp = tempfile::tempdir().expect("tempdir");
@@ -37,18 +37,25 @@ pub(crate) fn read_existing_resource(file_path: &Path) -> EsFluentResult<ast::Re
     crate::ftl::parse_ftl_file(file_path).map_err(Into::into)
 }
 
-/// Write an updated resource to disk, handling change detection and dry-run mode.
-pub(crate) fn write_updated_resource(
+/// A resource rendered as it would be written, alongside the file's current content.
+pub(crate) struct PlannedWrite {
+    pub(crate) current_content: String,
+    pub(crate) final_content: String,
+    pub(crate) is_empty: bool,
+    pub(crate) has_changed: bool,
+}
+
+/// Render `resource` for `file_path` and compare it with the content on disk.
+pub(crate) fn plan_write(
     file_path: &Path,
     resource: &ast::Resource<String>,
-    dry_run: bool,
     formatter: impl Fn(&ast::Resource<String>) -> String,
-) -> EsFluentResult<bool> {
+) -> EsFluentResult<PlannedWrite> {
     let is_empty = resource.body.is_empty();
     let final_content = if is_empty {
         String::new()
     } else {
-        formatter(resource)
+        crate::formatting::normalize_blank_lines(&formatter(resource))
     };
 
     let current_content = if file_path.exists() {
@@ -62,16 +69,33 @@ pub(crate) fn write_updated_resource(
         false => current_content.trim() != final_content.trim(),
     };
 
-    if !has_changed {
-        log_unchanged(file_path, is_empty, dry_run);
+    Ok(PlannedWrite {
+        current_content,
+        final_content,
+        is_empty,
+        has_changed,
+    })
+}
+
+/// Write an updated resource to disk, handling change detection and dry-run mode.
+pub(crate) fn write_updated_resource(
+    file_path: &Path,
+    resource: &ast::Resource<String>,
+    dry_run: bool,
+    formatter: impl Fn(&ast::Resource<String>) -> String,
+) -> EsFluentResult<bool> {
+    let planned = plan_write(file_path, resource, formatter)?;
+
+    if !planned.has_changed {
+        log_unchanged(file_path, planned.is_empty, dry_run);
         return Ok(false);
     }
 
     write_or_preview(
         file_path,
-        &current_content,
-        &final_content,
-        is_empty,
+        &planned.current_content,
+        &planned.final_content,
+        planned.is_empty,
         dry_run,
     )?;
     Ok(true)
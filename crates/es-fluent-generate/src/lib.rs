@@ -1,23 +1,29 @@
 #![doc = include_str!("../README.md")]
 
 use es_fluent_shared::EsFluentResult;
-pub use es_fluent_shared::FluentParseMode;
 use es_fluent_shared::registry::FtlTypeInfo;
-use std::path::Path;
+pub use es_fluent_shared::resource::AssetsLayout;
+pub use es_fluent_shared::{FluentParseMode, GenerateReport};
+use fluent_syntax::{ast, serializer};
+use std::path::{Path, PathBuf};
 
 mod ast_build;
 pub mod ftl;
 mod io;
+mod locale_stubs;
 mod merge;
 mod model;
 mod pipeline;
+mod report;
 
 pub mod clean;
 pub mod error;
 pub mod formatting;
+pub mod rename;
+pub mod validation;
 pub mod value;
 
-use pipeline::OutputOperation;
+use pipeline::{OutputOperation, PlannedOutput};
 
 #[cfg(test)]
 pub(crate) use ast_build::{create_group_comment_entry, create_message_entry};
@@ -33,27 +39,359 @@ pub(crate) use merge::{
 #[cfg(test)]
 pub(crate) use model::{OwnedTypeInfo, OwnedVariant};
 
+/// The crate whose FTL files are generated, and the name of its base file.
+///
+/// Converting from a crate name keeps the default `{crate}.ftl` base file;
+/// [`FtlTarget::with_base_file_stem`] selects another one, such as the stem
+/// resolved from the `ftl_file_name` setting in `i18n.toml`. Namespaced files
+/// always stay under `{crate}/`, and [`FtlTarget::with_layout`] decides where
+/// those files sit relative to the locale directory.
+///
+/// Equality compares every setting except the
+/// [`FtlTarget::with_post_process`] hook: function pointers have no reliable
+/// identity, so two targets that differ only in their hook compare equal.
+#[derive(Clone, Copy, Debug)]
+pub struct FtlTarget<'a> {
+    crate_name: &'a str,
+    base_file_stem: &'a str,
+    post_process: Option<PostProcess>,
+    sort_mode: SortMode,
+    header: Option<&'a str>,
+    layout: AssetsLayout,
+    annotate_source: bool,
+}
+
+/// Where [`FluentParseMode::Merge`] places keys it adds to an existing group.
+///
+/// Aggressive and conservative generation always sort each group by key, with
+/// `_label` keys first, so the setting only changes merge output.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SortMode {
+    /// Append new keys after the group's existing entries.
+    #[default]
+    SourceOrder,
+    /// Insert each new key before the first existing key that sorts after it,
+    /// using the same order as the other modes. Existing entries still keep
+    /// their place, so hand-ordered groups are not reshuffled.
+    Alphabetical,
+}
+
+/// A transformation applied to each merged resource before it is serialized.
+///
+/// See [`FtlTarget::with_post_process`].
+pub type PostProcess = fn(&mut ast::Resource<String>);
+
+impl<'a> FtlTarget<'a> {
+    /// Targets `crate_name` with its default `{crate_name}.ftl` base file.
+    pub fn new(crate_name: &'a str) -> Self {
+        Self {
+            crate_name,
+            base_file_stem: crate_name,
+            post_process: None,
+            sort_mode: SortMode::SourceOrder,
+            header: None,
+            layout: AssetsLayout::LanguageFirst,
+            annotate_source: false,
+        }
+    }
+
+    /// Uses `{base_file_stem}.ftl` as the base file.
+    pub fn with_base_file_stem(mut self, base_file_stem: &'a str) -> Self {
+        self.base_file_stem = base_file_stem;
+        self
+    }
+
+    /// Runs `post_process` on every merged resource before it is serialized.
+    ///
+    /// The callback runs once per file: the base file and each namespaced file
+    /// get their own call, after the merge and sort and before the result is
+    /// compared with the file on disk. [`stale_files`] and [`clean`] apply it
+    /// too, so house-style rules such as alphabetized groups or key prefixes
+    /// do not make the output look stale. Locale stubs copy the processed
+    /// entries.
+    ///
+    /// The merge reads back what the previous run wrote, so the callback should
+    /// be idempotent.
+    pub fn with_post_process(mut self, post_process: PostProcess) -> Self {
+        self.post_process = Some(post_process);
+        self
+    }
+
+    /// Selects where merge mode inserts new keys; see [`SortMode`].
+    pub fn with_sort_mode(mut self, sort_mode: SortMode) -> Self {
+        self.sort_mode = sort_mode;
+        self
+    }
+
+    /// Writes `header` as a `###` resource comment at the top of every file.
+    ///
+    /// The comment starts with an `@generated by es-fluent` line, and each
+    /// line of `header` becomes one more comment line. That first line marks
+    /// the banner as generated, so regenerating replaces it even after the
+    /// header text changes, and a target without a header removes it. The
+    /// header is added before any [`FtlTarget::with_post_process`] callback
+    /// runs.
+    pub fn with_header(mut self, header: &'a str) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    /// Arranges files by `layout` instead of below the locale directory.
+    ///
+    /// The `i18n_path` and locale directories passed to [`generate`] and
+    /// [`clean`] keep their `{assets_dir}/{language}` form; under
+    /// [`AssetsLayout::DomainFirst`] the base file is written to
+    /// `{assets_dir}/{base_file_stem}/{language}.ftl` and namespaced files
+    /// below `{assets_dir}/{crate}/{language}/`.
+    pub fn with_layout(mut self, layout: AssetsLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Adds a `# @source: module::Type::Variant` comment to every generated
+    /// message and term, after any doc comment, naming the Rust item that
+    /// produced it.
+    ///
+    /// Conservative and aggressive generation rewrite the line on every run,
+    /// so it follows a type that moved to another module. Merge mode only
+    /// annotates the keys it adds, and [`clean`] leaves comments alone. With
+    /// the option off, generation removes existing `@source:` lines; other
+    /// comment lines are never touched. The `@` keeps the marker apart from a
+    /// plain `# from:` note a translator might write, which a `from:` prefix
+    /// would have replaced.
+    pub fn with_annotate_source(mut self, annotate_source: bool) -> Self {
+        self.annotate_source = annotate_source;
+        self
+    }
+
+    /// The crate name, which is also the resource domain.
+    pub fn crate_name(&self) -> &'a str {
+        self.crate_name
+    }
+
+    /// The base file name without its `.ftl` extension.
+    pub fn base_file_stem(&self) -> &'a str {
+        self.base_file_stem
+    }
+
+    /// The callback set with [`FtlTarget::with_post_process`].
+    pub fn post_process(&self) -> Option<PostProcess> {
+        self.post_process
+    }
+
+    /// The mode set with [`FtlTarget::with_sort_mode`].
+    pub fn sort_mode(&self) -> SortMode {
+        self.sort_mode
+    }
+
+    /// The banner set with [`FtlTarget::with_header`].
+    pub fn header(&self) -> Option<&'a str> {
+        self.header
+    }
+
+    /// The layout set with [`FtlTarget::with_layout`].
+    pub fn layout(&self) -> AssetsLayout {
+        self.layout
+    }
+
+    /// Whether [`FtlTarget::with_annotate_source`] is enabled.
+    pub fn annotate_source(&self) -> bool {
+        self.annotate_source
+    }
+}
+
+// Function pointers have no stable identity, so equality ignores
+// `post_process`, as documented on the type.
+impl PartialEq for FtlTarget<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.crate_name == other.crate_name
+            && self.base_file_stem == other.base_file_stem
+            && self.sort_mode == other.sort_mode
+            && self.header == other.header
+            && self.layout == other.layout
+            && self.annotate_source == other.annotate_source
+    }
+}
+
+impl Eq for FtlTarget<'_> {}
+
+impl<'a> From<&'a str> for FtlTarget<'a> {
+    fn from(crate_name: &'a str) -> Self {
+        Self::new(crate_name)
+    }
+}
+
+impl<'a> From<&'a String> for FtlTarget<'a> {
+    fn from(crate_name: &'a String) -> Self {
+        Self::new(crate_name)
+    }
+}
+
 /// Generates a Fluent translation file from a list of `FtlTypeInfo` objects.
-pub fn generate<P: AsRef<Path>, M: AsRef<Path>, I: AsRef<FtlTypeInfo>>(
-    crate_name: &str,
+///
+/// `target` is usually just the crate name; pass an [`FtlTarget`] to write the
+/// base file under a different name.
+///
+/// Types marked `#[fluent(skip_generate)]` are left out: their keys are
+/// expected to be written by hand, and files holding only such types are not
+/// touched. In a file shared with generated types, every mode keeps their
+/// keys, and [`clean`] still treats them as in use.
+pub fn generate<'a, P: AsRef<Path>, M: AsRef<Path>, I: AsRef<FtlTypeInfo>>(
+    target: impl Into<FtlTarget<'a>>,
     i18n_path: P,
     manifest_dir: M,
     items: &[I],
     mode: FluentParseMode,
     dry_run: bool,
 ) -> EsFluentResult<bool> {
+    generate_report(target, i18n_path, manifest_dir, items, mode, dry_run)
+        .map(|report| report.changed)
+}
+
+/// Generates Fluent translation files like [`generate`] and reports which keys
+/// and groups were added or removed.
+///
+/// In dry-run mode nothing is written and the report describes what a real
+/// run would change.
+pub fn generate_report<'a, P: AsRef<Path>, M: AsRef<Path>, I: AsRef<FtlTypeInfo>>(
+    target: impl Into<FtlTarget<'a>>,
+    i18n_path: P,
+    manifest_dir: M,
+    items: &[I],
+    mode: FluentParseMode,
+    dry_run: bool,
+) -> EsFluentResult<GenerateReport> {
+    generate_report_with_locale_stubs(
+        target,
+        i18n_path,
+        &[] as &[&Path],
+        manifest_dir,
+        items,
+        mode,
+        dry_run,
+    )
+}
+
+/// Generates like [`generate_report`], then stubs new keys into other locales.
+///
+/// Each generated file is mirrored below every directory in `locale_dirs`:
+/// messages and terms that the locale file lacks are appended with the
+/// fallback value as a placeholder and a `# TODO: translate` comment. Existing
+/// translations in those files are left untouched.
+pub fn generate_report_with_locale_stubs<
+    'a,
+    P: AsRef<Path>,
+    L: AsRef<Path>,
+    M: AsRef<Path>,
+    I: AsRef<FtlTypeInfo>,
+>(
+    target: impl Into<FtlTarget<'a>>,
+    i18n_path: P,
+    locale_dirs: &[L],
+    manifest_dir: M,
+    items: &[I],
+    mode: FluentParseMode,
+    dry_run: bool,
+) -> EsFluentResult<GenerateReport> {
+    let target = target.into();
     let i18n_path = i18n_path.as_ref();
     let manifest_dir = manifest_dir.as_ref();
-    let mut any_changed = false;
+    let locale_dirs: Vec<&Path> = locale_dirs.iter().map(AsRef::as_ref).collect();
+    let mut report = GenerateReport::default();
+
+    let operation = OutputOperation::Generate(mode);
+    for output in pipeline::plan_outputs(target, i18n_path, manifest_dir, items)?
+        .into_iter()
+        .filter(PlannedOutput::has_generated_items)
+    {
+        report.merge(pipeline::apply_output_operation(
+            output,
+            &operation,
+            &locale_dirs,
+            dry_run,
+        )?);
+    }
+
+    Ok(report)
+}
 
+/// Returns the FTL files that [`generate`] would rewrite, without writing or
+/// printing anything.
+///
+/// Each file is merged in memory exactly as [`merge_into_resource`] would and
+/// compared with its current content, so an empty result means the generated
+/// FTL on disk is up to date. Locale stubs are not considered.
+pub fn stale_files<'a, P: AsRef<Path>, M: AsRef<Path>, I: AsRef<FtlTypeInfo>>(
+    target: impl Into<FtlTarget<'a>>,
+    i18n_path: P,
+    manifest_dir: M,
+    items: &[I],
+    mode: FluentParseMode,
+) -> EsFluentResult<Vec<PathBuf>> {
     let operation = OutputOperation::Generate(mode);
-    for output in pipeline::plan_outputs(crate_name, i18n_path, manifest_dir, items)? {
-        if pipeline::apply_output_operation(output, &operation, dry_run)? {
-            any_changed = true;
+    let mut stale = Vec::new();
+
+    for output in pipeline::plan_outputs(
+        target.into(),
+        i18n_path.as_ref(),
+        manifest_dir.as_ref(),
+        items,
+    )?
+    .into_iter()
+    .filter(PlannedOutput::has_generated_items)
+    {
+        let existing = io::read_existing_resource(&output.file_path)?;
+        let resource = output.finish(operation.render_resource(
+            existing,
+            &output.items,
+            output.sort_mode,
+            output.annotate_source,
+        )?);
+        if io::plan_write(&output.file_path, &resource, serializer::serialize)?.has_changed {
+            stale.push(output.file_path);
         }
     }
 
-    Ok(any_changed)
+    Ok(stale)
+}
+
+/// Merges `items` into an already-parsed resource without touching the
+/// filesystem.
+///
+/// This is the in-memory core of [`generate`]: the returned resource is exactly
+/// what `generate` would write for `target` to a file whose current contents
+/// parse to `existing`, including the target's sort mode, source annotations,
+/// header and post-processing hook. Serializing it with
+/// [`fluent_syntax::serializer::serialize`] and passing the text through
+/// [`formatting::normalize_blank_lines`] reproduces the file. Merges compose,
+/// which makes it convenient for testing merge behaviour or for tools that
+/// keep FTL in memory.
+///
+/// Items marked `#[fluent(skip_generate)]` get no entries, but their keys in
+/// `existing` are kept in every mode.
+///
+/// Fails when two items share a message id or an id is not a valid Fluent
+/// identifier.
+pub fn merge_into_resource<'a, I: AsRef<FtlTypeInfo>>(
+    target: impl Into<FtlTarget<'a>>,
+    existing: ast::Resource<String>,
+    items: &[I],
+    mode: FluentParseMode,
+) -> EsFluentResult<ast::Resource<String>> {
+    let target = target.into();
+    let items: Vec<&FtlTypeInfo> = items.iter().map(AsRef::as_ref).collect();
+    model::validate_ftl_identifiers(&items)?;
+    let resource = OutputOperation::Generate(mode).render_resource(
+        existing,
+        &items,
+        target.sort_mode(),
+        target.annotate_source(),
+    )?;
+    Ok(pipeline::finish_resource(
+        resource,
+        target.header(),
+        target.post_process(),
+    ))
 }
 
 #[cfg(test)]
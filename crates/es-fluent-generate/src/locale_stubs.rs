@@ -0,0 +1,89 @@
+//! Placeholder entries for keys that a non-fallback locale file lacks.
+
+use es_fluent_shared::{EsFluentResult, GenerateReport};
+use fluent_syntax::{ast, serializer};
+use indexmap::IndexMap;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Comment line written above every stub so translators can find them.
+pub(crate) const TODO_COMMENT: &str = "TODO: translate";
+
+/// Append every fallback message or term missing from `file_path`.
+///
+/// Stubs keep the fallback value and gain a `# TODO: translate` comment. They
+/// join the end of their type's group when the locale file already has it,
+/// otherwise a new group is appended. Existing entries are never rewritten.
+pub(crate) fn stub_locale_file(
+    file_path: &Path,
+    fallback: &ast::Resource<String>,
+    dry_run: bool,
+) -> EsFluentResult<GenerateReport> {
+    let existing = crate::io::read_existing_resource(file_path)?;
+    let existing_keys: HashSet<String> = existing
+        .body
+        .iter()
+        .filter_map(crate::ftl::entry_key)
+        .map(|key| key.into_owned())
+        .collect();
+
+    let mut pending: IndexMap<Option<String>, Vec<ast::Entry<String>>> = IndexMap::new();
+    let mut fallback_group = None;
+    for entry in &fallback.body {
+        if let ast::Entry::GroupComment(comment) = entry {
+            fallback_group = crate::ftl::group_comment_name(comment);
+        } else if let Some(key) = crate::ftl::entry_key(entry)
+            && !existing_keys.contains(key.as_ref())
+        {
+            pending
+                .entry(fallback_group.clone())
+                .or_default()
+                .push(stub_entry(entry.clone()));
+        }
+    }
+
+    if pending.is_empty() {
+        return Ok(GenerateReport::default());
+    }
+
+    let mut body = Vec::with_capacity(existing.body.len());
+    let mut current_group = None;
+    for entry in &existing.body {
+        if let ast::Entry::GroupComment(comment) = entry {
+            if let Some(stubs) = pending.shift_remove(&current_group) {
+                body.extend(stubs);
+            }
+            current_group = crate::ftl::group_comment_name(comment);
+        }
+        body.push(entry.clone());
+    }
+    if let Some(stubs) = pending.shift_remove(&current_group) {
+        body.extend(stubs);
+    }
+    for (group, stubs) in pending {
+        if let Some(group) = group {
+            body.push(crate::ast_build::create_group_comment_entry(&group));
+        }
+        body.extend(stubs);
+    }
+
+    let stubbed = ast::Resource { body };
+    let mut report = crate::report::diff_resources(&existing, &stubbed);
+    report.changed =
+        crate::io::write_updated_resource(file_path, &stubbed, dry_run, serializer::serialize)?;
+    Ok(report)
+}
+
+fn stub_entry(mut entry: ast::Entry<String>) -> ast::Entry<String> {
+    let comment = match &mut entry {
+        ast::Entry::Message(message) => &mut message.comment,
+        ast::Entry::Term(term) => &mut term.comment,
+        _ => return entry,
+    };
+    let mut content = vec![TODO_COMMENT.to_string()];
+    if let Some(existing) = comment.take() {
+        content.extend(existing.content);
+    }
+    *comment = Some(ast::Comment { content });
+    entry
+}
@@ -1,10 +1,11 @@
-use crate::model::OwnedTypeInfo;
+use crate::SortMode;
+use crate::model::{OwnedTypeInfo, OwnedVariant};
 use es_fluent_shared::EsFluentResult;
 use es_fluent_shared::namer::FluentKey;
 use es_fluent_shared::registry::FtlTypeInfo;
 use fluent_syntax::ast;
 use indexmap::IndexMap;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub(crate) enum MergeBehavior {
@@ -21,15 +22,16 @@ pub(crate) fn smart_merge(
 ) -> EsFluentResult<ast::Resource<String>> {
     let mut pending_items = crate::model::merge_ftl_type_infos(items)?;
     pending_items.sort_by(crate::model::compare_type_infos);
+    let group_aliases = crate::model::legacy_group_aliases(items);
 
     let mut item_map: IndexMap<String, _> = pending_items
         .into_iter()
-        .map(|info| (info.type_name.clone(), info))
+        .map(|info| (info.group_name.clone(), info))
         .collect();
     let mut key_to_group: IndexMap<String, String> = IndexMap::new();
     for (group_name, info) in &item_map {
         for variant in &info.variants {
-            key_to_group.insert(variant.entry_id().as_str().to_string(), group_name.clone());
+            key_to_group.insert(variant.key().into_owned(), group_name.clone());
         }
     }
     let mut relocated_by_group: IndexMap<String, Vec<ast::Entry<String>>> = IndexMap::new();
@@ -46,7 +48,6 @@ pub(crate) fn smart_merge(
     for entry in existing.body {
         match entry {
             ast::Entry::GroupComment(ref comment) => {
-                new_body.append(&mut pending_comments);
                 if let Some(ref old_group) = current_group_name
                     && let Some(info) = item_map.get_mut(old_group)
                 {
@@ -56,22 +57,26 @@ pub(crate) fn smart_merge(
                         }
                         if !info.variants.is_empty() {
                             for variant in &info.variants {
-                                if !existing_keys.contains(variant.entry_id().as_str()) {
-                                    seen_keys.insert(variant.entry_id().as_str().to_string());
-                                    new_body.push(crate::ast_build::create_message_entry(variant));
+                                if !existing_keys.contains(variant.key().as_ref()) {
+                                    seen_keys.insert(variant.key().into_owned());
+                                    new_body.push(crate::ast_build::create_entry(variant));
                                 }
                             }
                         }
                     }
                     info.variants.clear();
                 }
+                // Free-standing comments above a group header stay attached to
+                // that header instead of drifting above keys appended to the
+                // previous group.
+                new_body.append(&mut pending_comments);
 
-                current_group_name = comment
-                    .content
-                    .first()
-                    .map(|content| content.trim())
-                    .filter(|content| !content.is_empty())
-                    .map(ToOwned::to_owned);
+                let header = group_comment_name(comment);
+                let renamed_to = header
+                    .as_ref()
+                    .and_then(|header| group_aliases.get(header))
+                    .cloned();
+                current_group_name = renamed_to.clone().or(header);
 
                 let keep_group = if let Some(ref group_name) = current_group_name {
                     !cleanup || item_map.contains_key(group_name)
@@ -80,7 +85,12 @@ pub(crate) fn smart_merge(
                 };
 
                 if keep_group {
-                    new_body.push(entry);
+                    // A type name header from before the type gained a group
+                    // label takes the label, so its keys stay in place.
+                    new_body.push(match renamed_to {
+                        Some(group_name) => rename_group_comment(comment, group_name),
+                        None => entry,
+                    });
                 }
 
                 if let Some(ref group_name) = current_group_name {
@@ -148,9 +158,9 @@ pub(crate) fn smart_merge(
             }
             if !info.variants.is_empty() {
                 for variant in &info.variants {
-                    if !existing_keys.contains(variant.entry_id().as_str()) {
-                        seen_keys.insert(variant.entry_id().as_str().to_string());
-                        new_body.push(crate::ast_build::create_message_entry(variant));
+                    if !existing_keys.contains(variant.key().as_ref()) {
+                        seen_keys.insert(variant.key().into_owned());
+                        new_body.push(crate::ast_build::create_entry(variant));
                     }
                 }
             }
@@ -162,21 +172,21 @@ pub(crate) fn smart_merge(
         let mut remaining_groups: Vec<_> = item_map.into_iter().collect();
         remaining_groups.sort_by(|(_, a), (_, b)| crate::model::compare_type_infos(a, b));
 
-        for (type_name, info) in remaining_groups {
-            let relocated = relocated_by_group.shift_remove(&type_name);
+        for (group_name, info) in remaining_groups {
+            let relocated = relocated_by_group.shift_remove(&group_name);
             let has_missing = info
                 .variants
                 .iter()
-                .any(|variant| !existing_keys.contains(variant.entry_id().as_str()));
+                .any(|variant| !existing_keys.contains(variant.key().as_ref()));
             if has_missing || relocated.is_some() {
-                new_body.push(crate::ast_build::create_group_comment_entry(&type_name));
+                new_body.push(crate::ast_build::create_group_comment_entry(&group_name));
                 if let Some(entries) = relocated {
                     new_body.extend(entries);
                 }
                 for variant in info.variants {
-                    if !existing_keys.contains(variant.entry_id().as_str()) {
-                        seen_keys.insert(variant.entry_id().as_str().to_string());
-                        new_body.push(crate::ast_build::create_message_entry(&variant));
+                    if !existing_keys.contains(variant.key().as_ref()) {
+                        seen_keys.insert(variant.key().into_owned());
+                        new_body.push(crate::ast_build::create_entry(&variant));
                     }
                 }
             }
@@ -195,9 +205,123 @@ pub(crate) fn smart_merge(
     }
 }
 
+/// Insert keys that are absent from `existing` under their group header,
+/// leaving every existing entry in place. Existing entries only gain the
+/// declared attributes they are missing.
+///
+/// [`SortMode::Alphabetical`] slots each key before the first existing key in
+/// its group that sorts after it; otherwise new keys go at the group's end.
+pub(crate) fn fill_missing_keys(
+    existing: ast::Resource<String>,
+    items: &[&FtlTypeInfo],
+    sort_mode: SortMode,
+) -> EsFluentResult<ast::Resource<String>> {
+    let mut pending_items = crate::model::merge_ftl_type_infos(items)?;
+    pending_items.sort_by(crate::model::compare_type_infos);
+    let group_aliases = crate::model::legacy_group_aliases(items);
+
+    let existing_keys = collect_existing_keys(&existing);
+    let mut body = existing.body;
+
+    let variants_by_key: HashMap<_, _> = pending_items
+        .iter()
+        .flat_map(|info| &info.variants)
+        .filter(|variant| !variant.attributes.is_empty())
+        .map(|variant| (variant.key(), variant))
+        .collect();
+    for entry in &mut body {
+        let variant =
+            crate::ftl::entry_key(entry).and_then(|key| variants_by_key.get(&key).copied());
+        if let Some(variant) = variant {
+            crate::ast_build::add_missing_attributes(entry, variant);
+        }
+    }
+
+    let mut group_ranges: IndexMap<String, (usize, usize)> = IndexMap::new();
+    let mut current_group: Option<(String, usize)> = None;
+    for (idx, entry) in body.iter().enumerate() {
+        if let ast::Entry::GroupComment(comment) = entry {
+            if let Some((name, start)) = current_group.take() {
+                group_ranges.entry(name).or_insert((start, idx));
+            }
+            current_group = group_comment_name(comment)
+                .map(|name| group_aliases.get(&name).cloned().unwrap_or(name))
+                .map(|name| (name, idx + 1));
+        }
+    }
+    if let Some((name, start)) = current_group {
+        group_ranges.entry(name).or_insert((start, body.len()));
+    }
+
+    let mut insertions: BTreeMap<usize, Vec<ast::Entry<String>>> = BTreeMap::new();
+    let mut new_groups: Vec<ast::Entry<String>> = Vec::new();
+    for info in &pending_items {
+        let mut missing: Vec<_> = info
+            .variants
+            .iter()
+            .filter(|variant| !existing_keys.contains(variant.key().as_ref()))
+            .collect();
+        if missing.is_empty() {
+            continue;
+        }
+
+        match group_ranges.get(&info.group_name) {
+            Some(&(start, end)) => {
+                if sort_mode == SortMode::Alphabetical {
+                    missing.sort_by(|a, b| compare_keys(&a.key(), &b.key()));
+                }
+                for variant in missing {
+                    let before = match sort_mode {
+                        SortMode::SourceOrder => end,
+                        SortMode::Alphabetical => body[start..end]
+                            .iter()
+                            .position(|entry| {
+                                crate::ftl::entry_key(entry)
+                                    .is_some_and(|key| compare_keys(&key, &variant.key()).is_gt())
+                            })
+                            .map_or(end, |offset| start + offset),
+                    };
+                    // Keep comments attached to the entry or header that
+                    // follows the insertion point.
+                    let mut position = before;
+                    while position > start && matches!(body[position - 1], ast::Entry::Comment(_)) {
+                        position -= 1;
+                    }
+                    insertions
+                        .entry(position)
+                        .or_default()
+                        .push(crate::ast_build::create_entry(variant));
+                }
+            },
+            None => {
+                new_groups.push(crate::ast_build::create_group_comment_entry(
+                    &info.group_name,
+                ));
+                new_groups.extend(missing.into_iter().map(crate::ast_build::create_entry));
+            },
+        }
+    }
+
+    for (position, entries) in insertions.into_iter().rev() {
+        body.splice(position..position, entries);
+    }
+    body.extend(new_groups);
+
+    Ok(ast::Resource { body })
+}
+
+fn compare_keys(a: &str, b: &str) -> std::cmp::Ordering {
+    crate::formatting::compare_with_label_priority(
+        a.ends_with(FluentKey::LABEL_SUFFIX),
+        a,
+        b.ends_with(FluentKey::LABEL_SUFFIX),
+        b,
+    )
+}
+
 fn process_keyed_bundle(
     key: String,
-    bundle: Vec<ast::Entry<String>>,
+    mut bundle: Vec<ast::Entry<String>>,
     context: &mut BundleProcessingContext<'_>,
 ) {
     if context.seen_keys.contains(&key) {
@@ -206,16 +330,24 @@ fn process_keyed_bundle(
 
     let mut relocate_to: Option<String> = None;
 
-    let handled = if let Some(expected_group) = context.key_to_group.get(&key).cloned() {
+    // A key is kept in Clean mode only when a current type produces it;
+    // sharing a group with live keys does not keep an orphan alive.
+    let produced = if let Some(expected_group) = context.key_to_group.get(&key).cloned() {
         if context.current_group_name != Some(expected_group.as_str())
             && matches!(context.behavior, MergeBehavior::Append)
         {
             relocate_to = Some(expected_group.clone());
         }
-        remove_variant_from_group(context.item_map, &expected_group, &key);
+        let variant = remove_variant_from_group(context.item_map, &expected_group, &key);
+        if let Some(variant) = variant
+            && matches!(context.behavior, MergeBehavior::Append)
+            && let Some(entry) = bundle.last_mut()
+        {
+            crate::ast_build::add_missing_attributes(entry, &variant);
+        }
         true
     } else {
-        remove_variant_from_any_group(context.item_map, &key)
+        false
     };
 
     if let Some(group_name) = relocate_to {
@@ -233,7 +365,7 @@ fn process_keyed_bundle(
                 .or_default()
                 .extend(bundle);
         }
-    } else if handled || !context.cleanup {
+    } else if produced || !context.cleanup {
         context.seen_keys.insert(key);
         context.new_body.extend(bundle);
     }
@@ -256,36 +388,23 @@ fn remove_variant_from_group(
     item_map: &mut IndexMap<String, OwnedTypeInfo>,
     group_name: &str,
     key: &str,
-) -> bool {
-    if let Some(info) = item_map.get_mut(group_name)
-        && let Some(idx) = info
-            .variants
-            .iter()
-            .position(|variant| variant.entry_id().as_str() == key)
-    {
-        info.variants.remove(idx);
-        return true;
-    }
-
-    false
+) -> Option<OwnedVariant> {
+    let info = item_map.get_mut(group_name)?;
+    let idx = info
+        .variants
+        .iter()
+        .position(|variant| variant.key() == key)?;
+    Some(info.variants.remove(idx))
 }
 
-fn remove_variant_from_any_group(
-    item_map: &mut IndexMap<String, OwnedTypeInfo>,
-    key: &str,
-) -> bool {
-    for info in item_map.values_mut() {
-        if let Some(idx) = info
-            .variants
-            .iter()
-            .position(|variant| variant.entry_id().as_str() == key)
-        {
-            info.variants.remove(idx);
-            return true;
-        }
+/// Replace the header line of a group comment, keeping any further lines.
+fn rename_group_comment(comment: &ast::Comment<String>, group_name: String) -> ast::Entry<String> {
+    let mut content = comment.content.clone();
+    match content.first_mut() {
+        Some(header) => *header = group_name,
+        None => content.push(group_name),
     }
-
-    false
+    ast::Entry::GroupComment(ast::Comment { content })
 }
 
 pub(crate) fn group_comment_name(comment: &ast::Comment<String>) -> Option<String> {
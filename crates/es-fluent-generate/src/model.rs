@@ -1,8 +1,12 @@
 use crate::formatting;
-use es_fluent_shared::fluent::{FluentArgumentName, FluentEntryId};
+use es_fluent_shared::fluent::{
+    FluentArgumentName, FluentAttributeName, FluentEntryId, FluentIdentifierError, FluentMessageId,
+    FluentVariantKey,
+};
 use es_fluent_shared::namer::FluentKey;
 use es_fluent_shared::registry::{FtlTypeInfo, FtlVariant};
 use es_fluent_shared::{EsFluentError, EsFluentResult};
+use std::borrow::Cow;
 
 /// Internal owned variant model used during merge and generation.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -10,6 +14,20 @@ pub(crate) struct OwnedVariant {
     pub(crate) name: String,
     pub(crate) ftl_key: FluentEntryId,
     pub(crate) args: Vec<FluentArgumentName>,
+    /// Select variant keys for arguments backed by choice types.
+    pub(crate) selectors: Vec<(FluentArgumentName, Vec<FluentVariantKey>)>,
+    /// Arguments whose CLDR plural category selects the message text.
+    pub(crate) plurals: Vec<FluentArgumentName>,
+    /// Source doc comment written as the message comment.
+    pub(crate) doc: Option<String>,
+    /// Attributes scaffolded as `.name = ...` lines under the entry.
+    pub(crate) attributes: Vec<FluentAttributeName>,
+    /// Message ids of `#[fluent(term)]` argument types, linked as Fluent terms.
+    pub(crate) term_links: Vec<FluentEntryId>,
+    /// Whether this entry is written as the Fluent term `-{ftl_key}`.
+    pub(crate) is_term: bool,
+    /// Whether this term's value references the message `ftl_key`.
+    pub(crate) links_message: bool,
 }
 
 impl OwnedVariant {
@@ -36,41 +54,123 @@ impl OwnedVariant {
             name: name.into(),
             ftl_key: entry_id,
             args,
+            selectors: Vec::new(),
+            plurals: Vec::new(),
+            doc: None,
+            attributes: Vec::new(),
+            term_links: Vec::new(),
+            is_term: false,
+            links_message: false,
         })
     }
 
+    /// Creates the generated term `-{entry_id} = { entry_id }`.
+    pub(crate) fn term_link(entry_id: FluentEntryId) -> Self {
+        Self {
+            name: format!("{}{}", FluentKey::DELIMITER, entry_id.as_str()),
+            ftl_key: entry_id,
+            args: Vec::new(),
+            selectors: Vec::new(),
+            plurals: Vec::new(),
+            doc: None,
+            attributes: Vec::new(),
+            term_links: Vec::new(),
+            is_term: true,
+            links_message: true,
+        }
+    }
+
     pub(crate) fn from_ftl_variant(variant: &FtlVariant) -> EsFluentResult<Self> {
         Ok(Self {
             name: variant.name().to_string(),
             ftl_key: variant.entry_id(),
             args: variant.argument_names(),
+            selectors: variant
+                .selectors()
+                .iter()
+                .map(|selector| {
+                    (
+                        selector.arg().argument_name(),
+                        selector
+                            .choices()
+                            .iter()
+                            .map(|choice| choice.variant_key())
+                            .collect(),
+                    )
+                })
+                .filter(|(_, choices): &(_, Vec<_>)| !choices.is_empty())
+                .collect(),
+            plurals: variant
+                .plurals()
+                .iter()
+                .map(|arg| arg.argument_name())
+                .collect(),
+            doc: variant.doc().map(str::to_owned),
+            attributes: variant
+                .attributes()
+                .iter()
+                .map(|attribute| {
+                    FluentAttributeName::try_new(*attribute).map_err(|err| {
+                        EsFluentError::invalid_fluent_identifier(*attribute, err.to_string())
+                    })
+                })
+                .collect::<EsFluentResult<_>>()?,
+            term_links: variant
+                .term_links()
+                .iter()
+                .flat_map(|link| link.entry_ids())
+                .map(|entry_id| entry_id.entry_id())
+                .collect(),
+            is_term: false,
+            links_message: false,
         })
     }
 
+    pub(crate) fn selector_choices(&self, arg: &FluentArgumentName) -> Option<&[FluentVariantKey]> {
+        self.selectors
+            .iter()
+            .find(|(name, _)| name == arg)
+            .map(|(_, choices)| choices.as_slice())
+    }
+
     pub(crate) fn entry_id(&self) -> &FluentEntryId {
         &self.ftl_key
     }
 
+    /// Returns the resource key, which carries a `-` prefix for terms.
+    pub(crate) fn key(&self) -> Cow<'_, str> {
+        if self.is_term {
+            Cow::Owned(format!("{}{}", FluentKey::DELIMITER, self.ftl_key.as_str()))
+        } else {
+            Cow::Borrowed(self.ftl_key.as_str())
+        }
+    }
+
     pub(crate) fn is_label(&self) -> bool {
-        self.entry_id().as_str().ends_with(FluentKey::LABEL_SUFFIX)
+        !self.is_term && self.entry_id().as_str().ends_with(FluentKey::LABEL_SUFFIX)
     }
 }
 
 /// Internal owned type info model used during merge and generation.
 #[derive(Clone, Debug)]
 pub(crate) struct OwnedTypeInfo {
-    pub(crate) type_name: String,
+    /// The `## Group` header text: the type's group label, or its type name.
+    pub(crate) group_name: String,
     pub(crate) variants: Vec<OwnedVariant>,
 }
 
 impl OwnedTypeInfo {
     pub(crate) fn from_ftl_type_info(info: &FtlTypeInfo) -> EsFluentResult<Self> {
         Ok(Self {
-            type_name: info.type_name().to_string(),
+            group_name: info.group_name().to_string(),
             variants: info
                 .variants()
                 .iter()
-                .map(OwnedVariant::from_ftl_variant)
+                .map(|variant| {
+                    let mut owned = OwnedVariant::from_ftl_variant(variant)?;
+                    owned.is_term = info.is_term();
+                    Ok(owned)
+                })
                 .collect::<EsFluentResult<Vec<_>>>()?,
         })
     }
@@ -81,36 +181,196 @@ pub(crate) fn compare_type_infos(a: &OwnedTypeInfo, b: &OwnedTypeInfo) -> std::c
     let a_is_label = a.variants.iter().any(OwnedVariant::is_label);
     let b_is_label = b.variants.iter().any(OwnedVariant::is_label);
 
-    formatting::compare_with_label_priority(a_is_label, &a.type_name, b_is_label, &b.type_name)
+    formatting::compare_with_label_priority(a_is_label, &a.group_name, b_is_label, &b.group_name)
+}
+
+/// Map each `## Group` header to the Rust type names whose entries it holds.
+///
+/// Types without `#[fluent(group = "...")]` map their type name to itself.
+pub(crate) fn group_type_names(
+    items: &[&FtlTypeInfo],
+) -> std::collections::BTreeMap<String, std::collections::BTreeSet<String>> {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    let mut groups: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for item in items {
+        groups
+            .entry(item.group_name().to_string())
+            .or_default()
+            .insert(item.type_name().to_string());
+    }
+    groups
+}
+
+/// Map type names that headed a group before it gained a label to that label.
+///
+/// A type name that is itself a current group header is not an alias.
+pub(crate) fn legacy_group_aliases(
+    items: &[&FtlTypeInfo],
+) -> std::collections::HashMap<String, String> {
+    let groups = group_type_names(items);
+    groups
+        .iter()
+        .flat_map(|(group_name, type_names)| {
+            type_names
+                .iter()
+                .filter(|type_name| !groups.contains_key(type_name.as_str()))
+                .map(move |type_name| (type_name.clone(), group_name.clone()))
+        })
+        .collect()
+}
+
+/// Reject metadata whose ids would serialize as invalid Fluent identifiers.
+///
+/// Derive output is validated during macro expansion, but registry metadata
+/// can also be built by hand. Checking again here reports the offending type
+/// and variant instead of writing a file that fails to parse on the next run.
+pub(crate) fn validate_ftl_identifiers(items: &[&FtlTypeInfo]) -> EsFluentResult<()> {
+    for info in items {
+        for variant in info.variants() {
+            let invalid = |value: &str, error: FluentIdentifierError| {
+                EsFluentError::invalid_fluent_identifier(
+                    value,
+                    format!("{error} ({})", info.source_description_for(variant)),
+                )
+            };
+            let check_message_id = |value: &str| {
+                FluentMessageId::try_new(value)
+                    .map(drop)
+                    .map_err(|error| invalid(value, error))
+            };
+            let check_argument = |value: &str| {
+                FluentArgumentName::try_new(value)
+                    .map(drop)
+                    .map_err(|error| invalid(value, error))
+            };
+
+            check_message_id(variant.entry_id().as_str())?;
+            for arg in variant.args().iter().chain(variant.plurals()) {
+                check_argument(arg.as_str())?;
+            }
+            for selector in variant.selectors() {
+                check_argument(selector.arg().as_str())?;
+                for choice in selector.choices() {
+                    FluentVariantKey::try_new(choice.as_str())
+                        .map_err(|error| invalid(choice.as_str(), error))?;
+                }
+            }
+            for link in variant.term_links() {
+                for entry_id in link.entry_ids() {
+                    check_message_id(entry_id.as_str())?;
+                }
+            }
+        }
+    }
+
+    Ok(())
 }
 
 pub(crate) fn validate_no_duplicate_ftl_keys(items: &[&FtlTypeInfo]) -> EsFluentResult<()> {
     use std::collections::BTreeMap;
 
-    let mut seen: BTreeMap<FluentEntryId, (&FtlTypeInfo, &FtlVariant)> = BTreeMap::new();
+    let mut first_seen = Vec::new();
+    let mut seen: BTreeMap<FluentEntryId, Vec<(&FtlTypeInfo, &FtlVariant)>> = BTreeMap::new();
 
     for info in items {
         for variant in info.variants() {
             let key = variant.entry_id();
-            if let Some((first_info, first_variant)) = seen.get(&key) {
-                return Err(EsFluentError::duplicate_generated_ftl_key(
-                    key.as_str(),
-                    first_info.source_description_for(first_variant),
-                    info.source_description_for(variant),
-                ));
+            let sources = seen.entry(key.clone()).or_default();
+            if sources.is_empty() {
+                first_seen.push(key);
             }
+            sources.push((*info, variant));
+        }
+    }
 
-            seen.insert(key, (*info, variant));
+    // Report the earliest colliding key with every type that produced it.
+    for key in first_seen {
+        let sources = &seen[&key];
+        if sources.len() > 1 {
+            return Err(EsFluentError::duplicate_generated_ftl_key(
+                key.as_str(),
+                sources
+                    .iter()
+                    .map(|(info, variant)| info.source_description_for(variant)),
+            ));
         }
     }
 
     Ok(())
 }
 
-/// Merge duplicate `FtlTypeInfo` entries into a stable owned representation.
+/// Reject a type registered under more than one kind, e.g. an enum in one
+/// `cfg` state and a struct in another, instead of merging whichever came first.
+pub(crate) fn validate_consistent_type_kinds(items: &[&FtlTypeInfo]) -> EsFluentResult<()> {
+    use std::collections::BTreeMap;
+
+    let mut seen: BTreeMap<(&str, &str), Vec<&FtlTypeInfo>> = BTreeMap::new();
+    for info in items {
+        seen.entry((info.module_path(), info.type_name()))
+            .or_default()
+            .push(*info);
+    }
+
+    for ((_, type_name), infos) in seen {
+        let Some(first) = infos.first() else {
+            continue;
+        };
+        if infos
+            .iter()
+            .any(|info| info.type_kind() != first.type_kind())
+        {
+            return Err(EsFluentError::conflicting_type_kind(
+                type_name,
+                infos.iter().map(|info| info.source_description()),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Add one generated term per linked message id.
+///
+/// A term joins the group that owns its message when that type is in the same
+/// resource, and otherwise the group of the first type linking to it.
+fn add_term_links(grouped: &mut std::collections::BTreeMap<String, Vec<OwnedVariant>>) {
+    use std::collections::{BTreeSet, HashMap};
+
+    let owners: HashMap<FluentEntryId, String> = grouped
+        .iter()
+        .flat_map(|(group_name, variants)| {
+            variants
+                .iter()
+                .map(move |variant| (variant.entry_id().clone(), group_name.clone()))
+        })
+        .collect();
+
+    let mut seen: BTreeSet<FluentEntryId> = BTreeSet::new();
+    let mut terms: Vec<(String, OwnedVariant)> = Vec::new();
+    for (group_name, variants) in grouped.iter() {
+        for entry_id in variants.iter().flat_map(|variant| &variant.term_links) {
+            if seen.insert(entry_id.clone()) {
+                let group = owners.get(entry_id).unwrap_or(group_name).clone();
+                terms.push((group, OwnedVariant::term_link(entry_id.clone())));
+            }
+        }
+    }
+
+    for (group, term) in terms {
+        grouped.entry(group).or_default().push(term);
+    }
+}
+
+/// Merge `FtlTypeInfo` entries sharing a group header into a stable owned
+/// representation.
+///
+/// Callers validate ids with [`validate_ftl_identifiers`] once for the whole
+/// run, before any output is rendered.
 pub(crate) fn merge_ftl_type_infos(items: &[&FtlTypeInfo]) -> EsFluentResult<Vec<OwnedTypeInfo>> {
     use std::collections::BTreeMap;
 
+    validate_consistent_type_kinds(items)?;
     validate_no_duplicate_ftl_keys(items)?;
 
     let mut grouped: BTreeMap<String, Vec<OwnedVariant>> = BTreeMap::new();
@@ -118,22 +378,25 @@ pub(crate) fn merge_ftl_type_infos(items: &[&FtlTypeInfo]) -> EsFluentResult<Vec
     for item in items {
         let owned = OwnedTypeInfo::from_ftl_type_info(item)?;
         grouped
-            .entry(owned.type_name)
+            .entry(owned.group_name)
             .or_default()
             .extend(owned.variants);
     }
 
+    add_term_links(&mut grouped);
+
     Ok(grouped
         .into_iter()
-        .map(|(type_name, mut variants)| {
+        .map(|(group_name, mut variants)| {
             variants.sort_by(|a, b| {
                 let a_is_label = a.is_label();
                 let b_is_label = b.is_label();
                 formatting::compare_with_label_priority(a_is_label, &a.name, b_is_label, &b.name)
+                    .then_with(|| a.ftl_key.cmp(&b.ftl_key))
             });
 
             OwnedTypeInfo {
-                type_name,
+                group_name,
                 variants,
             }
         })
@@ -1,12 +1,18 @@
+use crate::AssetsLayout;
 use crate::FluentParseMode;
+use crate::FtlTarget;
+use crate::PostProcess;
+use crate::SortMode;
 use crate::formatting;
 use crate::merge::MergeBehavior;
-use es_fluent_shared::EsFluentResult;
+use es_fluent_shared::fluent::FluentEntryId;
 use es_fluent_shared::namespace::ResolvedNamespace;
 use es_fluent_shared::registry::FtlTypeInfo;
-use es_fluent_shared::resource::ResourceRoute;
+use es_fluent_shared::resource::{ModuleResourceSpec, ResourceRoute};
+use es_fluent_shared::{EsFluentResult, GenerateReport};
 use fluent_syntax::{ast, serializer};
 use indexmap::IndexMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::io::{Error, ErrorKind};
 use std::path::{Path, PathBuf};
@@ -14,7 +20,51 @@ use std::path::{Path, PathBuf};
 pub(crate) struct PlannedOutput<'a> {
     pub(crate) route: ResourceRoute,
     pub(crate) file_path: PathBuf,
+    /// Path of the file below any locale directory.
+    pub(crate) relative_path: PathBuf,
     pub(crate) items: Vec<&'a FtlTypeInfo>,
+    pub(crate) post_process: Option<PostProcess>,
+    pub(crate) sort_mode: SortMode,
+    pub(crate) header: Option<String>,
+    pub(crate) layout: AssetsLayout,
+    pub(crate) annotate_source: bool,
+    /// Term-link ids that another file of the domain writes. A domain loads
+    /// into one bundle, so this file drops its copy of those `-id` terms.
+    pub(crate) foreign_term_links: HashSet<FluentEntryId>,
+}
+
+impl PlannedOutput<'_> {
+    /// Whether any item routed here is generated. Files holding only
+    /// `#[fluent(skip_generate)]` types are hand-authored and left alone.
+    pub(crate) fn has_generated_items(&self) -> bool {
+        self.items.iter().any(|info| !info.skip_generate())
+    }
+
+    /// Drop the term links another file of the domain writes, then finish
+    /// the rendered resource with [`finish_resource`].
+    pub(crate) fn finish(&self, mut resource: ast::Resource<String>) -> ast::Resource<String> {
+        if !self.foreign_term_links.is_empty() {
+            crate::ast_build::remove_terms(&mut resource, &self.foreign_term_links);
+        }
+        finish_resource(resource, self.header.as_deref(), self.post_process)
+    }
+}
+
+/// Apply a target's file header and post-processing hook to a rendered
+/// resource. A generated header is removed when the target has none.
+pub(crate) fn finish_resource(
+    mut resource: ast::Resource<String>,
+    header: Option<&str>,
+    post_process: Option<PostProcess>,
+) -> ast::Resource<String> {
+    match header {
+        Some(header) => crate::ast_build::insert_file_header(&mut resource, header),
+        None => crate::ast_build::remove_file_header(&mut resource),
+    }
+    if let Some(post_process) = post_process {
+        post_process(&mut resource);
+    }
+    resource
 }
 
 pub(crate) enum OutputOperation {
@@ -23,39 +73,121 @@ pub(crate) enum OutputOperation {
 }
 
 impl OutputOperation {
-    fn render_resource(
+    /// Produce the resource this operation writes for `items`, in final entry
+    /// order. Merge and clean keep the existing order; the other generate modes
+    /// sort entries. `sort_mode` decides where merge inserts new keys.
+    ///
+    /// Generate modes write entries for items that are not marked
+    /// `#[fluent(skip_generate)]`. Their hand-authored keys stay in the file:
+    /// aggressive mode carries them over from `existing_resource`.
+    ///
+    /// With `annotate_source`, generated entries get a `# @source:` provenance
+    /// line. Merge mode only annotates the keys it adds. Without it, generate
+    /// modes remove those lines, and clean leaves comments as they are.
+    pub(crate) fn render_resource(
         &self,
         existing_resource: ast::Resource<String>,
         items: &[&FtlTypeInfo],
+        sort_mode: SortMode,
+        annotate_source: bool,
     ) -> EsFluentResult<ast::Resource<String>> {
+        if matches!(self, Self::Clean) {
+            return self.render_entries(existing_resource, items, sort_mode);
+        }
+        if !annotate_source {
+            let mut resource = self.render_entries(existing_resource, items, sort_mode)?;
+            crate::ast_build::strip_source_annotations(&mut resource);
+            return Ok(resource);
+        }
+
+        let keep = match self {
+            Self::Generate(FluentParseMode::Merge) => {
+                crate::merge::collect_existing_keys(&existing_resource)
+            },
+            _ => HashSet::new(),
+        };
+        let mut resource = self.render_entries(existing_resource, items, sort_mode)?;
+        let (generated, _) = split_skip_generate(items);
+        crate::ast_build::annotate_sources(&mut resource, &generated, &keep);
+        Ok(resource)
+    }
+
+    fn render_entries(
+        &self,
+        existing_resource: ast::Resource<String>,
+        items: &[&FtlTypeInfo],
+        sort_mode: SortMode,
+    ) -> EsFluentResult<ast::Resource<String>> {
+        crate::model::validate_no_duplicate_ftl_keys(items)?;
+        let group_type_names = crate::model::group_type_names(items);
+        let (generated, kept) = split_skip_generate(items);
+
         match self {
             Self::Generate(FluentParseMode::Aggressive) => {
-                crate::ast_build::build_target_resource(items)
+                let mut resource = crate::ast_build::build_target_resource(&generated)?;
+                if !kept.is_empty() {
+                    // Hand-authored keys of skipped types survive the rebuild.
+                    let kept_resource =
+                        crate::merge::smart_merge(existing_resource, &kept, MergeBehavior::Clean)?;
+                    resource
+                        .body
+                        .extend(kept_resource.body.into_iter().filter(|entry| {
+                            !matches!(
+                                entry,
+                                ast::Entry::ResourceComment(_) | ast::Entry::Junk { .. }
+                            )
+                        }));
+                }
+                Ok(formatting::sort_ftl_entries_with_groups(
+                    &resource,
+                    &group_type_names,
+                ))
             },
             Self::Generate(FluentParseMode::Conservative) => {
-                crate::merge::smart_merge(existing_resource, items, MergeBehavior::Append)
+                Ok(formatting::sort_ftl_entries_with_groups(
+                    &crate::merge::smart_merge(
+                        existing_resource,
+                        &generated,
+                        MergeBehavior::Append,
+                    )?,
+                    &group_type_names,
+                ))
+            },
+            Self::Generate(FluentParseMode::Merge) => {
+                crate::merge::fill_missing_keys(existing_resource, &generated, sort_mode)
             },
             Self::Clean => {
                 crate::merge::smart_merge(existing_resource, items, MergeBehavior::Clean)
             },
         }
     }
+}
 
-    fn formatter(&self) -> fn(&ast::Resource<String>) -> String {
-        match self {
-            Self::Generate(_) => formatting::sort_ftl_resource,
-            Self::Clean => serializer::serialize,
-        }
-    }
+/// Splits `items` into generated types and types marked
+/// `#[fluent(skip_generate)]`, whose keys are hand-authored.
+fn split_skip_generate<'a>(
+    items: &[&'a FtlTypeInfo],
+) -> (Vec<&'a FtlTypeInfo>, Vec<&'a FtlTypeInfo>) {
+    items
+        .iter()
+        .copied()
+        .partition(|info| !info.skip_generate())
 }
 
+/// Route `items` to their output files.
+///
+/// Every item's ids are validated here, before any output is rendered, so an
+/// invalid id fails the run without leaving some files written.
 pub(crate) fn plan_outputs<'a, I: AsRef<FtlTypeInfo>>(
-    crate_name: &str,
+    target: FtlTarget<'_>,
     i18n_path: &Path,
     manifest_dir: &Path,
     items: &'a [I],
 ) -> EsFluentResult<Vec<PlannedOutput<'a>>> {
+    ModuleResourceSpec::try_base_with_file_stem(target.crate_name(), target.base_file_stem(), true)
+        .map_err(|error| Error::new(ErrorKind::InvalidInput, error.to_string()))?;
     let items_ref: Vec<&'a FtlTypeInfo> = items.iter().map(|item| item.as_ref()).collect();
+    crate::model::validate_ftl_identifiers(&items_ref)?;
 
     let mut namespaced: IndexMap<Option<ResolvedNamespace>, Vec<&'a FtlTypeInfo>> = IndexMap::new();
     for item in &items_ref {
@@ -76,40 +208,123 @@ pub(crate) fn plan_outputs<'a, I: AsRef<FtlTypeInfo>>(
         namespaced.entry(namespace).or_default().push(item);
     }
 
-    Ok(namespaced
+    let mut outputs: Vec<PlannedOutput<'a>> = namespaced
         .into_iter()
         .map(|(namespace, items)| {
             let route = ResourceRoute::from_namespace(namespace);
-            let resource = route.resource_spec(crate_name, true);
-            let file_path = i18n_path.join(resource.locale_relative_path.as_str());
+            let resource = route.resource_spec_with_base_file(
+                target.crate_name(),
+                target.base_file_stem(),
+                true,
+            );
+            let relative_path = PathBuf::from(resource.locale_relative_path.as_str());
+            let file_path = target
+                .layout()
+                .resolve_in_locale_dir(i18n_path, &relative_path);
 
             PlannedOutput {
                 route,
                 file_path,
+                relative_path,
                 items,
+                post_process: target.post_process(),
+                sort_mode: target.sort_mode(),
+                header: target.header().map(str::to_owned),
+                layout: target.layout(),
+                annotate_source: target.annotate_source(),
+                foreign_term_links: HashSet::new(),
             }
         })
-        .collect())
+        .collect();
+    assign_term_links(&mut outputs);
+
+    Ok(outputs)
 }
 
+/// Pick one file per term link so a domain never holds the same `-id` twice.
+///
+/// The file of a `#[fluent(as_terms)]` type that already writes the term keeps
+/// it; otherwise the linking file with the smallest relative path does. Every
+/// other linking file records the id in its `foreign_term_links`.
+fn assign_term_links(outputs: &mut [PlannedOutput<'_>]) {
+    let mut writer: HashMap<FluentEntryId, usize> = HashMap::new();
+    for (index, output) in outputs.iter().enumerate() {
+        for info in output.items.iter().filter(|info| info.is_term()) {
+            for variant in info.variants() {
+                writer.entry(variant.entry_id()).or_insert(index);
+            }
+        }
+    }
+
+    let links: Vec<BTreeSet<FluentEntryId>> = outputs
+        .iter()
+        .map(|output| {
+            output
+                .items
+                .iter()
+                .filter(|info| !info.skip_generate())
+                .flat_map(|info| info.variants())
+                .flat_map(|variant| variant.term_links())
+                .flat_map(|link| link.entry_ids())
+                .map(|entry_id| entry_id.entry_id())
+                .collect()
+        })
+        .collect();
+    let mut linkers: Vec<usize> = (0..outputs.len()).collect();
+    linkers.sort_by(|a, b| outputs[*a].relative_path.cmp(&outputs[*b].relative_path));
+    for index in linkers {
+        for entry_id in &links[index] {
+            writer.entry(entry_id.clone()).or_insert(index);
+        }
+    }
+
+    for (index, output) in outputs.iter_mut().enumerate() {
+        output.foreign_term_links = links[index]
+            .iter()
+            .filter(|entry_id| writer.get(*entry_id) != Some(&index))
+            .cloned()
+            .collect();
+    }
+}
+
+/// Render one planned output and, for each of `locale_stub_dirs`, stub the
+/// rendered keys that the matching locale file lacks.
 pub(crate) fn apply_output_operation(
     output: PlannedOutput<'_>,
     operation: &OutputOperation,
+    locale_stub_dirs: &[&Path],
     dry_run: bool,
-) -> EsFluentResult<bool> {
-    crate::model::validate_no_duplicate_ftl_keys(&output.items)?;
-
+) -> EsFluentResult<GenerateReport> {
     if !dry_run && let Some(parent) = output.file_path.parent() {
         fs::create_dir_all(parent)?;
     }
 
     let existing_resource = crate::io::read_existing_resource(&output.file_path)?;
-    let final_resource = operation.render_resource(existing_resource, &output.items)?;
+    let final_resource = output.finish(operation.render_resource(
+        existing_resource.clone(),
+        &output.items,
+        output.sort_mode,
+        output.annotate_source,
+    )?);
+    let mut report = crate::report::diff_resources(&existing_resource, &final_resource);
+    crate::report::log_key_sources(&output.file_path, &report, &output.items);
 
-    crate::io::write_updated_resource(
+    report.changed = crate::io::write_updated_resource(
         &output.file_path,
         &final_resource,
         dry_run,
-        operation.formatter(),
-    )
+        serializer::serialize,
+    )?;
+
+    for locale_dir in locale_stub_dirs {
+        report.merge(crate::locale_stubs::stub_locale_file(
+            &output
+                .layout
+                .resolve_in_locale_dir(locale_dir, &output.relative_path),
+            &final_resource,
+            dry_run,
+        )?);
+    }
+
+    Ok(report)
 }
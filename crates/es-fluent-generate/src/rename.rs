@@ -0,0 +1,589 @@
+//! Carrying existing translations over when a Rust type is renamed.
+//!
+//! Renaming `LoginError` to `SignInError` changes every id the derive
+//! generates (`login_error-…` becomes `sign_in_error-…`) and the `## Group`
+//! comment above them. Without help, the next generate run treats the old
+//! entries as orphans and a clean run deletes them. [`rename_in_resource`]
+//! rewrites an already-parsed resource so the translations follow the type.
+
+use es_fluent_shared::namer::{FluentKey, KeyCase};
+use fluent_syntax::ast;
+use heck::ToSnakeCase as _;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// The group and id prefix changes for one renamed type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TypeRename {
+    from_group: String,
+    to_group: String,
+    from_prefix: String,
+    to_prefix: String,
+    from_label: String,
+    to_label: String,
+    group_scoped: bool,
+}
+
+impl TypeRename {
+    /// Describes renaming the Rust type `from` to `to`.
+    ///
+    /// The id prefixes follow the derive's naming: the type name is
+    /// snake_cased, or cased with `case` for types that use
+    /// `#[fluent(case = "...")]`. Label ids are always snake_cased, as the
+    /// derive writes them. A leading `r#` is ignored.
+    ///
+    /// Kebab-case ids join words and variants with the same `-`, so
+    /// `login-error-details-reason` could be a `LoginError` variant or an id of
+    /// `LoginErrorDetails`. With `KeyCase::Kebab` only entries under the old
+    /// type's `## Group` comment, and references to them, are renamed.
+    pub fn new(from: &str, to: &str, case: Option<KeyCase>) -> Self {
+        let type_name = |name: &str| name.strip_prefix("r#").unwrap_or(name).to_string();
+        let prefix = |name: &str| match case {
+            Some(case) => case.apply(name),
+            None => name.to_snake_case(),
+        };
+        let label = |name: &str| format!("{}{}", name.to_snake_case(), FluentKey::LABEL_SUFFIX);
+        let from_group = type_name(from);
+        let to_group = type_name(to);
+
+        Self {
+            from_prefix: prefix(&from_group),
+            to_prefix: prefix(&to_group),
+            from_label: label(&from_group),
+            to_label: label(&to_group),
+            from_group,
+            to_group,
+            group_scoped: case == Some(KeyCase::Kebab),
+        }
+    }
+
+    /// The `## Group` name the old type's entries sit under.
+    pub fn from_group(&self) -> &str {
+        &self.from_group
+    }
+
+    /// The `## Group` name the entries move to.
+    pub fn to_group(&self) -> &str {
+        &self.to_group
+    }
+
+    /// Returns the new id for a message or term id the old type generated, or
+    /// `None` when `id` belongs to another type.
+    ///
+    /// The type's own id, its variant ids (`prefix-Variant`) and its label ids
+    /// (`prefix_label`, `prefix_label-…`) are renamed. Ids that only share the
+    /// leading text, such as `login_error_details` for `LoginError`, are not.
+    /// Kebab-case ids cannot be told apart this way, so [`rename_in_resource`]
+    /// also checks which group a kebab-case entry sits under.
+    pub fn rename_id(&self, id: &str) -> Option<String> {
+        for (from, to) in [
+            (&self.from_label, &self.to_label),
+            (&self.from_prefix, &self.to_prefix),
+        ] {
+            if id == from {
+                return Some(to.clone());
+            }
+            if let Some(rest) = id
+                .strip_prefix(from)
+                .and_then(|rest| rest.strip_prefix(FluentKey::DELIMITER))
+            {
+                return Some(format!("{to}{}{rest}", FluentKey::DELIMITER));
+            }
+        }
+        None
+    }
+
+    /// Returns the new id for an entry sitting under the `group` comment, or
+    /// `None` when the entry is not the old type's.
+    fn rename_entry_id(&self, id: &str, group: Option<&str>) -> Option<String> {
+        if self.group_scoped && group != Some(self.from_group.as_str()) {
+            return None;
+        }
+        self.rename_id(id)
+    }
+}
+
+/// What [`rename_in_resource`] changed.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RenameReport {
+    /// Old and new ids of the renamed entries, in file order. Term ids keep
+    /// their leading `-`.
+    pub renamed: Vec<(String, String)>,
+    /// Number of `## Group` comments that were renamed.
+    pub groups: usize,
+    /// Number of message and term references inside patterns that were
+    /// rewritten to the new ids.
+    pub references: usize,
+}
+
+impl RenameReport {
+    /// Returns `true` when the resource was left unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.renamed.is_empty() && self.groups == 0 && self.references == 0
+    }
+}
+
+/// A rename that would give an entry an id the resource already uses.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RenameConflict {
+    /// The ids that already exist, sorted. Term ids keep their leading `-`.
+    pub existing: BTreeSet<String>,
+}
+
+impl std::fmt::Display for RenameConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ids = self
+            .existing
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "renamed ids already exist: {ids}")
+    }
+}
+
+impl std::error::Error for RenameConflict {}
+
+/// Renames the entries, group comments and references of one type inside
+/// `resource`.
+///
+/// Translations, comments and attributes are kept as they are. When a new id
+/// is already taken, for example because a generate run after the refactor
+/// scaffolded the new type, nothing is changed and the clashing ids are
+/// returned so the caller can decide which copy to keep.
+pub fn rename_in_resource(
+    resource: &mut ast::Resource<String>,
+    rename: &TypeRename,
+) -> Result<RenameReport, RenameConflict> {
+    let renames = renamed_entries(resource, rename);
+    let existing: HashSet<String> = resource
+        .body
+        .iter()
+        .filter_map(crate::ftl::entry_key)
+        .map(|key| key.into_owned())
+        .collect();
+    let existing_conflicts: BTreeSet<String> = renames
+        .iter()
+        .map(|renamed| renamed.new_key())
+        .filter(|new_key| existing.contains(new_key))
+        .collect();
+    if !existing_conflicts.is_empty() {
+        return Err(RenameConflict {
+            existing: existing_conflicts,
+        });
+    }
+
+    let references = ReferenceRenames::new(rename, &renames);
+    let mut report = RenameReport::default();
+    let mut pending = renames.iter().peekable();
+    for (index, entry) in resource.body.iter_mut().enumerate() {
+        let new_id = pending
+            .next_if(|renamed| renamed.index == index)
+            .map(|renamed| renamed.new_id.clone());
+        match entry {
+            ast::Entry::GroupComment(comment) => {
+                if crate::ftl::group_comment_name(comment).as_deref() == Some(rename.from_group())
+                    && let Some(first) = comment.content.first_mut()
+                {
+                    *first = rename.to_group().to_string();
+                    report.groups += 1;
+                }
+            },
+            ast::Entry::Message(message) => {
+                if let Some(new_id) = new_id {
+                    let old_id = std::mem::replace(&mut message.id.name, new_id.clone());
+                    report.renamed.push((old_id, new_id));
+                }
+                if let Some(value) = &mut message.value {
+                    report.references += rename_in_pattern(value, &references);
+                }
+                for attribute in &mut message.attributes {
+                    report.references += rename_in_pattern(&mut attribute.value, &references);
+                }
+            },
+            ast::Entry::Term(term) => {
+                if let Some(new_id) = new_id {
+                    let old_id = std::mem::replace(&mut term.id.name, new_id.clone());
+                    report
+                        .renamed
+                        .push((format!("-{old_id}"), format!("-{new_id}")));
+                }
+                report.references += rename_in_pattern(&mut term.value, &references);
+                for attribute in &mut term.attributes {
+                    report.references += rename_in_pattern(&mut attribute.value, &references);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    Ok(report)
+}
+
+/// One message or term the rename applies to.
+struct RenamedEntry {
+    /// Position of the entry in the resource body.
+    index: usize,
+    is_term: bool,
+    old_id: String,
+    new_id: String,
+}
+
+impl RenamedEntry {
+    /// The new resource key, which carries a `-` prefix for terms.
+    fn new_key(&self) -> String {
+        if self.is_term {
+            format!("-{}", self.new_id)
+        } else {
+            self.new_id.clone()
+        }
+    }
+}
+
+/// Lists the entries of `resource` the rename applies to, in file order.
+fn renamed_entries(resource: &ast::Resource<String>, rename: &TypeRename) -> Vec<RenamedEntry> {
+    let mut group = None;
+    let mut renames = Vec::new();
+    for (index, entry) in resource.body.iter().enumerate() {
+        let (id, is_term) = match entry {
+            ast::Entry::GroupComment(comment) => {
+                group = crate::ftl::group_comment_name(comment);
+                continue;
+            },
+            ast::Entry::Message(message) => (&message.id.name, false),
+            ast::Entry::Term(term) => (&term.id.name, true),
+            _ => continue,
+        };
+        if let Some(new_id) = rename.rename_entry_id(id, group.as_deref()) {
+            renames.push(RenamedEntry {
+                index,
+                is_term,
+                old_id: id.clone(),
+                new_id,
+            });
+        }
+    }
+    renames
+}
+
+/// Decides which message and term references follow the rename.
+///
+/// References to entries renamed in this resource always follow. Other
+/// references are matched by id prefix, unless the rename is group scoped and
+/// the prefix alone cannot tell the old type's ids apart.
+struct ReferenceRenames<'a> {
+    rename: &'a TypeRename,
+    messages: HashMap<&'a str, &'a str>,
+    terms: HashMap<&'a str, &'a str>,
+}
+
+impl<'a> ReferenceRenames<'a> {
+    fn new(rename: &'a TypeRename, renames: &'a [RenamedEntry]) -> Self {
+        let mut messages = HashMap::new();
+        let mut terms = HashMap::new();
+        for renamed in renames {
+            let ids = if renamed.is_term {
+                &mut terms
+            } else {
+                &mut messages
+            };
+            ids.insert(renamed.old_id.as_str(), renamed.new_id.as_str());
+        }
+        Self {
+            rename,
+            messages,
+            terms,
+        }
+    }
+
+    fn new_id(&self, id: &str, is_term: bool) -> Option<String> {
+        let ids = if is_term { &self.terms } else { &self.messages };
+        if let Some(new_id) = ids.get(id) {
+            return Some((*new_id).to_string());
+        }
+        if self.rename.group_scoped {
+            return None;
+        }
+        self.rename.rename_id(id)
+    }
+}
+
+fn rename_in_pattern(pattern: &mut ast::Pattern<String>, rename: &ReferenceRenames<'_>) -> usize {
+    pattern
+        .elements
+        .iter_mut()
+        .map(|element| match element {
+            ast::PatternElement::Placeable { expression } => {
+                rename_in_expression(expression, rename)
+            },
+            ast::PatternElement::TextElement { .. } => 0,
+        })
+        .sum()
+}
+
+fn rename_in_expression(
+    expression: &mut ast::Expression<String>,
+    rename: &ReferenceRenames<'_>,
+) -> usize {
+    match expression {
+        ast::Expression::Inline(inline) => rename_in_inline(inline, rename),
+        ast::Expression::Select { selector, variants } => {
+            rename_in_inline(selector, rename)
+                + variants
+                    .iter_mut()
+                    .map(|variant| rename_in_pattern(&mut variant.value, rename))
+                    .sum::<usize>()
+        },
+    }
+}
+
+fn rename_in_inline(
+    inline: &mut ast::InlineExpression<String>,
+    rename: &ReferenceRenames<'_>,
+) -> usize {
+    match inline {
+        ast::InlineExpression::MessageReference { id, .. } => rename_identifier(id, false, rename),
+        ast::InlineExpression::TermReference { id, arguments, .. } => {
+            rename_identifier(id, true, rename)
+                + arguments
+                    .as_mut()
+                    .map_or(0, |arguments| rename_in_arguments(arguments, rename))
+        },
+        ast::InlineExpression::FunctionReference { arguments, .. } => {
+            rename_in_arguments(arguments, rename)
+        },
+        ast::InlineExpression::Placeable { expression } => rename_in_expression(expression, rename),
+        _ => 0,
+    }
+}
+
+fn rename_in_arguments(
+    arguments: &mut ast::CallArguments<String>,
+    rename: &ReferenceRenames<'_>,
+) -> usize {
+    arguments
+        .positional
+        .iter_mut()
+        .map(|argument| rename_in_inline(argument, rename))
+        .chain(
+            arguments
+                .named
+                .iter_mut()
+                .map(|argument| rename_in_inline(&mut argument.value, rename)),
+        )
+        .sum()
+}
+
+fn rename_identifier(
+    id: &mut ast::Identifier<String>,
+    is_term: bool,
+    rename: &ReferenceRenames<'_>,
+) -> usize {
+    match rename.new_id(&id.name, is_term) {
+        Some(new_id) => {
+            id.name = new_id;
+            1
+        },
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ftl::parse_ftl_content;
+    use fluent_syntax::serializer;
+
+    fn rename_source(source: &str, rename: &TypeRename) -> (String, RenameReport) {
+        let (mut resource, errors) = parse_ftl_content(source.to_string());
+        assert!(errors.is_empty(), "{errors:?}");
+        let report = rename_in_resource(&mut resource, rename).expect("rename");
+        (serializer::serialize(&resource), report)
+    }
+
+    #[test]
+    fn rename_id_matches_the_type_variants_and_label_only() {
+        let rename = TypeRename::new("LoginError", "SignInError", None);
+
+        assert_eq!(
+            rename.rename_id("login_error").as_deref(),
+            Some("sign_in_error")
+        );
+        assert_eq!(
+            rename.rename_id("login_error-InvalidPassword").as_deref(),
+            Some("sign_in_error-InvalidPassword")
+        );
+        assert_eq!(
+            rename.rename_id("login_error_label").as_deref(),
+            Some("sign_in_error_label")
+        );
+        assert_eq!(rename.rename_id("login_error_details"), None);
+        assert_eq!(rename.rename_id("login_error_details-Field"), None);
+        assert_eq!(rename.rename_id("other-login_error"), None);
+    }
+
+    #[test]
+    fn rename_id_uses_the_derive_case() {
+        let rename = TypeRename::new("LoginError", "SignInError", Some(KeyCase::Kebab));
+
+        assert_eq!(
+            rename.rename_id("login-error-invalid-password").as_deref(),
+            Some("sign-in-error-invalid-password")
+        );
+        assert_eq!(
+            rename.rename_id("login_error_label").as_deref(),
+            Some("sign_in_error_label")
+        );
+        assert_eq!(rename.rename_id("login_error-Other"), None);
+    }
+
+    #[test]
+    fn rename_in_resource_keeps_translations_comments_and_other_groups() {
+        let rename = TypeRename::new("LoginError", "SignInError", None);
+        let source = "\
+## LoginError
+
+# Shown under the password field.
+login_error-InvalidPassword = Mot de passe invalide
+login_error-Locked = Compte bloqué : { login_error-InvalidPassword }
+    .title = { -login_error-Brand }
+
+## LoginErrorDetails
+
+login_error_details-Reason = Raison
+";
+
+        let (output, report) = rename_source(source, &rename);
+
+        assert_eq!(
+            output,
+            "\
+## SignInError
+
+# Shown under the password field.
+sign_in_error-InvalidPassword = Mot de passe invalide
+sign_in_error-Locked = Compte bloqué : { sign_in_error-InvalidPassword }
+    .title = { -sign_in_error-Brand }
+
+## LoginErrorDetails
+
+login_error_details-Reason = Raison
+"
+        );
+        assert_eq!(
+            report.renamed,
+            vec![
+                (
+                    "login_error-InvalidPassword".to_string(),
+                    "sign_in_error-InvalidPassword".to_string()
+                ),
+                (
+                    "login_error-Locked".to_string(),
+                    "sign_in_error-Locked".to_string()
+                ),
+            ]
+        );
+        assert_eq!(report.groups, 1);
+        assert_eq!(report.references, 2);
+    }
+
+    #[test]
+    fn rename_in_resource_keeps_kebab_ids_of_other_groups() {
+        let rename = TypeRename::new("LoginError", "SignInError", Some(KeyCase::Kebab));
+        let source = "\
+## LoginError
+
+login-error-invalid-password = Mot de passe invalide
+login-error-locked = { login-error-invalid-password } ({ login-error-details-reason })
+
+## LoginErrorDetails
+
+login-error-details-reason = Raison
+login-error-details-hint = { login-error-locked }
+";
+
+        let (output, report) = rename_source(source, &rename);
+
+        assert_eq!(
+            output,
+            "\
+## SignInError
+
+sign-in-error-invalid-password = Mot de passe invalide
+sign-in-error-locked = { sign-in-error-invalid-password } ({ login-error-details-reason })
+
+## LoginErrorDetails
+
+login-error-details-reason = Raison
+login-error-details-hint = { sign-in-error-locked }
+"
+        );
+        assert_eq!(
+            report.renamed,
+            vec![
+                (
+                    "login-error-invalid-password".to_string(),
+                    "sign-in-error-invalid-password".to_string()
+                ),
+                (
+                    "login-error-locked".to_string(),
+                    "sign-in-error-locked".to_string()
+                ),
+            ]
+        );
+        assert_eq!(report.references, 2);
+    }
+
+    #[test]
+    fn rename_in_resource_renames_terms_with_their_dash() {
+        let rename = TypeRename::new("BrandName", "ProductName", None);
+        let (output, report) = rename_source(
+            "## BrandName\n\n-brand_name = Acme\nwelcome = Welcome to { -brand_name }\n",
+            &rename,
+        );
+
+        assert_eq!(
+            output,
+            "## ProductName\n\n-product_name = Acme\nwelcome = Welcome to { -product_name }\n"
+        );
+        assert_eq!(
+            report.renamed,
+            vec![("-brand_name".to_string(), "-product_name".to_string())]
+        );
+    }
+
+    #[test]
+    fn rename_in_resource_refuses_to_overwrite_existing_ids() {
+        let rename = TypeRename::new("LoginError", "SignInError", None);
+        let source = "\
+login_error-Locked = Verrouillé
+login_error-Expired = Expiré
+sign_in_error-Locked = Locked
+";
+        let (mut resource, _) = parse_ftl_content(source.to_string());
+        let before = resource.clone();
+
+        let conflict = rename_in_resource(&mut resource, &rename).expect_err("conflict");
+
+        assert_eq!(
+            conflict.existing,
+            BTreeSet::from(["sign_in_error-Locked".to_string()])
+        );
+        assert_eq!(
+            conflict.to_string(),
+            "renamed ids already exist: sign_in_error-Locked"
+        );
+        assert_eq!(
+            resource, before,
+            "a conflicting rename must not edit the resource"
+        );
+    }
+
+    #[test]
+    fn rename_in_resource_reports_nothing_for_unrelated_resources() {
+        let rename = TypeRename::new("LoginError", "SignInError", None);
+        let (output, report) = rename_source("## Other\n\nother-Key = Value\n", &rename);
+
+        assert_eq!(output, "## Other\n\nother-Key = Value\n");
+        assert!(report.is_empty());
+    }
+}
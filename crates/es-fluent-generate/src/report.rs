@@ -0,0 +1,124 @@
+use crate::merge::group_comment_name;
+use es_fluent_shared::registry::FtlTypeInfo;
+use es_fluent_shared::{EsFluentResult, GenerateReport};
+use fluent_syntax::ast;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+
+/// Describe the keys and groups that differ between an existing resource and
+/// the resource that would replace it.
+pub(crate) fn diff_resources(
+    before: &ast::Resource<String>,
+    after: &ast::Resource<String>,
+) -> GenerateReport {
+    let before_keys = crate::ftl::extract_message_keys(before);
+    let after_keys = crate::ftl::extract_message_keys(after);
+    let before_groups = group_names(before);
+
+    let removed: Vec<String> = before_keys.difference(&after_keys).cloned().collect();
+
+    let mut report = GenerateReport {
+        changed: false,
+        added: after_keys.difference(&before_keys).cloned().collect(),
+        removed_groups: message_groups(before, &removed),
+        removed,
+        groups_added: group_names(after)
+            .difference(&before_groups)
+            .cloned()
+            .collect(),
+    };
+    report.normalize();
+    report
+}
+
+/// Map each key produced by `items` to the Rust source that registers it.
+pub(crate) fn key_sources(items: &[&FtlTypeInfo]) -> HashMap<String, String> {
+    items
+        .iter()
+        .flat_map(|info| {
+            info.variants().iter().map(|variant| {
+                (
+                    variant.entry_id().to_string(),
+                    info.source_description_for(variant),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Log, at debug level, where each added or removed key of `file_path` comes
+/// from so an unexpected regeneration can be traced back to its derive.
+pub(crate) fn log_key_sources(file_path: &Path, report: &GenerateReport, items: &[&FtlTypeInfo]) {
+    if (report.added.is_empty() && report.removed.is_empty())
+        || !tracing::enabled!(tracing::Level::DEBUG)
+    {
+        return;
+    }
+
+    let sources = key_sources(items);
+    let changes = report
+        .added
+        .iter()
+        .map(|key| ("added", key))
+        .chain(report.removed.iter().map(|key| ("removed", key)));
+    for (change, key) in changes {
+        let source = sources
+            .get(key)
+            .map_or("no registered type", String::as_str);
+        tracing::debug!(
+            "{} key '{}' in {}: {}",
+            change,
+            key,
+            file_path.display(),
+            source
+        );
+    }
+}
+
+/// Report every message in a stale file that is about to be removed.
+///
+/// Parse errors are tolerated so that a broken stale file can still be
+/// deleted; only the messages that parsed are listed.
+pub(crate) fn removed_file(file_path: &Path) -> EsFluentResult<GenerateReport> {
+    let (resource, _errors) = crate::ftl::parse_ftl_file_with_errors(file_path)?;
+    let removed: Vec<String> = crate::ftl::extract_message_keys(&resource)
+        .into_iter()
+        .collect();
+    let mut report = GenerateReport {
+        changed: true,
+        removed_groups: message_groups(&resource, &removed),
+        removed,
+        ..GenerateReport::default()
+    };
+    report.normalize();
+    Ok(report)
+}
+
+/// Map each of `keys` to the `## Group` header it sits under in `resource`.
+fn message_groups(resource: &ast::Resource<String>, keys: &[String]) -> BTreeMap<String, String> {
+    let mut groups = BTreeMap::new();
+    let mut current_group = None;
+    for entry in &resource.body {
+        match entry {
+            ast::Entry::GroupComment(comment) => current_group = group_comment_name(comment),
+            ast::Entry::Message(msg) if keys.contains(&msg.id.name) => {
+                if let Some(group) = &current_group {
+                    groups.insert(msg.id.name.clone(), group.clone());
+                }
+            },
+            _ => {},
+        }
+    }
+    groups
+}
+
+fn group_names(resource: &ast::Resource<String>) -> HashSet<String> {
+    resource
+        .body
+        .iter()
+        .filter_map(|entry| match entry {
+            ast::Entry::GroupComment(comment) => group_comment_name(comment),
+            _ => None,
+        })
+        .collect()
+}
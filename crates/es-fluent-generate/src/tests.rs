@@ -4,7 +4,7 @@ use es_fluent_shared::registry::{
     __macro, FtlTypeInfo, FtlVariant, NamespaceRule, ResolvedNamespace, StaticFluentArgumentName,
     StaticFluentEntryId,
 };
-use fluent_syntax::{ast, parser};
+use fluent_syntax::{ast, parser, serializer};
 use fs_err as fs;
 use indexmap::IndexMap;
 use std::path::PathBuf;
@@ -78,7 +78,7 @@ fn owned_type_info_and_entry_helpers_work() {
     );
 
     let owned = OwnedTypeInfo::from_ftl_type_info(&info).expect("owned type info");
-    assert_eq!(owned.type_name, "Greeter");
+    assert_eq!(owned.group_name, "Greeter");
     assert_eq!(owned.variants.len(), 1);
     assert_eq!(owned.variants[0].entry_id().as_str(), "greeter-hello_name");
 
@@ -96,6 +96,1010 @@ fn owned_type_info_and_entry_helpers_work() {
     ));
 }
 
+#[test]
+fn create_message_entry_scaffolds_select_expression_for_choice_selectors() {
+    fn gender_choices() -> &'static [es_fluent_shared::registry::StaticFluentVariantKey] {
+        const CHOICES: &[es_fluent_shared::registry::StaticFluentVariantKey] = &[
+            __macro::static_variant_key("male"),
+            __macro::static_variant_key("female"),
+            __macro::static_variant_key("other"),
+        ];
+        CHOICES
+    }
+
+    static SELECTORS: &[es_fluent_shared::registry::FtlSelector] = &[__macro::ftl_selector(
+        __macro::static_argument_name("gender"),
+        gender_choices,
+    )];
+    let variant =
+        test_variant("Greeting", "greeting", &["name", "gender"]).with_selectors(SELECTORS);
+    let owned = OwnedVariant::from_ftl_variant(&variant).expect("owned variant");
+
+    let resource = ast::Resource {
+        body: vec![create_message_entry(&owned)],
+    };
+    let content = fluent_syntax::serializer::serialize(&resource);
+
+    assert_eq!(
+        content,
+        "greeting =\n    Greeting { $name } { $gender ->\n        [male] Male\n        [female] Female\n       *[other] Other\n    }\n"
+    );
+    assert!(parser::parse(content).is_ok());
+}
+
+#[test]
+fn create_message_entry_scaffolds_plural_select_for_plural_arguments() {
+    static PLURALS: &[StaticFluentArgumentName] = &[__macro::static_argument_name("count")];
+    let variant = test_variant("Cart", "cart", &["count", "owner"]).with_plurals(PLURALS);
+    let owned = OwnedVariant::from_ftl_variant(&variant).expect("owned variant");
+
+    let resource = ast::Resource {
+        body: vec![create_message_entry(&owned)],
+    };
+    let content = fluent_syntax::serializer::serialize(&resource);
+
+    assert_eq!(
+        content,
+        "cart =\n    { $count ->\n        [one] Cart { $count } { $owner }\n       *[other] Cart { $count } { $owner }\n    }\n"
+    );
+    assert!(parser::parse(content).is_ok());
+}
+
+#[test]
+fn create_message_entry_writes_captured_doc_as_message_comment() {
+    let variant = test_variant("Missing", "status-Missing", &[])
+        .with_doc("This variant is missing from the FTL file.\n\nShown in the status bar.");
+    let owned = OwnedVariant::from_ftl_variant(&variant).expect("owned variant");
+
+    let resource = ast::Resource {
+        body: vec![
+            create_message_entry(&owned),
+            create_message_entry(&owned_variant("Ready", "status-Ready", &[])),
+        ],
+    };
+    let content = fluent_syntax::serializer::serialize(&resource);
+
+    assert_eq!(
+        content,
+        "# This variant is missing from the FTL file.\n#\n# Shown in the status bar.\nstatus-Missing = Missing\nstatus-Ready = Ready\n"
+    );
+    assert!(parser::parse(content).is_ok());
+}
+
+#[test]
+fn create_message_entry_scaffolds_declared_attributes() {
+    let variant = test_variant("LoginForm", "login_form", &["user"])
+        .with_attributes(&["label", "aria-placeholder"]);
+    let owned = OwnedVariant::from_ftl_variant(&variant).expect("owned variant");
+
+    let resource = ast::Resource {
+        body: vec![create_message_entry(&owned)],
+    };
+    let content = fluent_syntax::serializer::serialize(&resource);
+
+    assert_eq!(
+        content,
+        "login_form = Login Form { $user }\n    .label = Login Form Label\n    .aria-placeholder = Login Form Aria Placeholder\n"
+    );
+    assert!(parser::parse(content).is_ok());
+}
+
+#[test]
+fn conservative_and_merge_generate_add_missing_attributes_to_existing_messages() {
+    for mode in [FluentParseMode::Conservative, FluentParseMode::Merge] {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let i18n = temp.path().join("i18n");
+        let items = vec![test_type(
+            "LoginForm",
+            vec![
+                test_variant("LoginForm", "login_form", &[])
+                    .with_attributes(&["label", "placeholder"]),
+            ],
+        )];
+        let file_path = i18n.join("demo.ftl");
+        fs::create_dir_all(&i18n).expect("create i18n");
+        fs::write(
+            &file_path,
+            "## LoginForm\n\nlogin_form = Sign in\n    .label = Your account\n",
+        )
+        .expect("write existing file");
+
+        generate("demo", &i18n, temp.path(), &items, mode, false).expect("generate");
+        assert_eq!(
+            fs::read_to_string(&file_path).expect("read"),
+            "## LoginForm\n\nlogin_form = Sign in\n    .label = Your account\n    .placeholder = Login Form Placeholder\n",
+            "{mode:?}"
+        );
+    }
+}
+
+#[test]
+fn owned_variant_rejects_invalid_attribute_names() {
+    let variant = test_variant("LoginForm", "login_form", &[]).with_attributes(&["1label"]);
+
+    let error = OwnedVariant::from_ftl_variant(&variant).expect_err("invalid attribute");
+    assert!(error.to_string().contains("1label"), "{error}");
+}
+
+#[test]
+fn generate_output_is_byte_identical_regardless_of_collection_order() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let items = vec![
+        test_type(
+            "LoginError",
+            vec![
+                test_variant("Something", "login_error-Something", &["f0", "f1", "f2"]),
+                test_variant("Locked", "login_error-Locked", &["user", "until"]),
+            ],
+        ),
+        test_type(
+            "Profile",
+            vec![test_variant(
+                "Profile",
+                "profile",
+                &["name", "age", "email"],
+            )],
+        ),
+    ];
+    let reversed = items.iter().rev().cloned().collect::<Vec<_>>();
+
+    let render = |dir: &str, items: &[FtlTypeInfo]| {
+        let output = temp.path().join(dir);
+        generate(
+            "demo",
+            &output,
+            temp.path(),
+            items,
+            FluentParseMode::Conservative,
+            false,
+        )
+        .expect("generate");
+        fs::read(output.join("demo.ftl")).expect("read generated file")
+    };
+
+    let first = render("first", &items);
+    let second = render("second", &items);
+    let reordered = render("reordered", &reversed);
+
+    assert_eq!(first, second);
+    assert_eq!(first, reordered);
+    let content = String::from_utf8(first).expect("utf-8 output");
+    assert!(content.contains("login_error-Something = Something { $f0 } { $f1 } { $f2 }\n"));
+    assert!(content.contains("login_error-Locked = Locked { $user } { $until }\n"));
+    assert!(content.contains("profile = Profile { $name } { $age } { $email }\n"));
+}
+
+#[test]
+fn generate_writes_one_blank_line_before_each_group() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let items = vec![
+        test_type(
+            "LoginError",
+            vec![
+                test_variant("Locked", "login_error-Locked", &[]),
+                test_variant("Expired", "login_error-Expired", &[]),
+            ],
+        ),
+        test_type("Profile", vec![test_variant("Profile", "profile", &[])]),
+    ];
+    let expected = "## LoginError\n\nlogin_error-Expired = Expired\nlogin_error-Locked = Locked\n\n## Profile\n\nprofile = Profile\n";
+
+    generate(
+        "demo",
+        &i18n,
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("generate");
+    let file_path = i18n.join("demo.ftl");
+    assert_eq!(fs::read_to_string(&file_path).expect("read"), expected);
+
+    fs::write(
+        &file_path,
+        "## LoginError\n\nlogin_error-Expired = Expired\nlogin_error-Locked = Locked\n\n\n\n## Profile\n\nprofile = Profile\n\n\n",
+    )
+    .expect("write messy spacing");
+    generate(
+        "demo",
+        &i18n,
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("regenerate");
+    assert_eq!(fs::read_to_string(&file_path).expect("read"), expected);
+}
+
+#[test]
+fn generate_rewrites_bom_and_crlf_files_as_clean_lf() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let items = vec![test_type(
+        "LoginError",
+        vec![
+            test_variant("Locked", "login_error-Locked", &[]),
+            test_variant("Expired", "login_error-Expired", &[]),
+        ],
+    )];
+    let file_path = i18n.join("demo.ftl");
+    fs::create_dir_all(&i18n).expect("mkdir");
+    fs::write(
+        &file_path,
+        "\u{feff}## LoginError\r\n\r\nlogin_error-Locked = Account locked\r\n",
+    )
+    .expect("write windows ftl");
+    let expected =
+        "## LoginError\n\nlogin_error-Expired = Expired\nlogin_error-Locked = Account locked\n";
+
+    for _ in 0..2 {
+        generate(
+            "demo",
+            &i18n,
+            temp.path(),
+            &items,
+            FluentParseMode::Conservative,
+            false,
+        )
+        .expect("generate");
+        assert_eq!(fs::read_to_string(&file_path).expect("read"), expected);
+    }
+}
+
+#[test]
+fn stale_files_reports_files_generate_would_rewrite_without_writing() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let items = vec![test_type(
+        "Profile",
+        vec![test_variant("Profile", "profile", &[])],
+    )];
+    let file_path = i18n.join("demo.ftl");
+
+    let stale = crate::stale_files(
+        "demo",
+        &i18n,
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+    )
+    .expect("stale files");
+    assert_eq!(stale, vec![file_path.clone()]);
+    assert!(!file_path.exists());
+
+    generate(
+        "demo",
+        &i18n,
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("generate");
+    let stale = crate::stale_files(
+        "demo",
+        &i18n,
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+    )
+    .expect("stale files");
+    assert!(stale.is_empty());
+
+    let items = vec![test_type(
+        "Profile",
+        vec![
+            test_variant("Profile", "profile", &[]),
+            test_variant("Avatar", "profile-avatar", &[]),
+        ],
+    )];
+    let before = fs::read_to_string(&file_path).expect("read");
+    let stale = crate::stale_files(
+        "demo",
+        &i18n,
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+    )
+    .expect("stale files");
+    assert_eq!(stale, vec![file_path.clone()]);
+    assert_eq!(fs::read_to_string(&file_path).expect("read"), before);
+}
+
+#[test]
+fn generate_and_clean_use_the_target_base_file_stem() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let items = vec![test_type(
+        "Profile",
+        vec![test_variant("Profile", "profile", &[])],
+    )];
+    let target = FtlTarget::new("demo").with_base_file_stem("demo-messages");
+    let file_path = i18n.join("demo-messages.ftl");
+
+    generate(
+        target,
+        &i18n,
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("generate");
+    assert_eq!(
+        fs::read_to_string(&file_path).expect("read"),
+        "## Profile\n\nprofile = Profile\n"
+    );
+    assert!(!i18n.join("demo.ftl").exists());
+    assert!(
+        crate::stale_files(
+            target,
+            &i18n,
+            temp.path(),
+            &items,
+            FluentParseMode::Conservative
+        )
+        .expect("stale files")
+        .is_empty()
+    );
+
+    crate::clean::clean(target, &i18n, temp.path(), &[] as &[FtlTypeInfo], false).expect("clean");
+    assert!(!file_path.exists());
+
+    let error = generate(
+        FtlTarget::new("demo").with_base_file_stem("../demo"),
+        &i18n,
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect_err("invalid base file stem");
+    assert!(error.to_string().contains("../demo.ftl"));
+}
+
+#[test]
+fn generate_and_clean_follow_the_domain_first_layout() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let assets = temp.path().join("i18n");
+    let base = test_type("Profile", vec![test_variant("Profile", "profile", &[])]);
+    let namespaced = test_type_at_with_namespace(
+        "Button",
+        vec![test_variant("Button", "button", &[])],
+        "",
+        Some(NamespaceRule::Literal(
+            ResolvedNamespace::new("ui").expect("valid test namespace"),
+        )),
+    );
+    let target = FtlTarget::new("demo").with_layout(AssetsLayout::DomainFirst);
+
+    generate_report_with_locale_stubs(
+        target,
+        assets.join("en"),
+        &[assets.join("fr")],
+        temp.path(),
+        &[&base, &namespaced],
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("generate");
+    assert!(assets.join("demo/en.ftl").is_file());
+    assert!(assets.join("demo/en/ui.ftl").is_file());
+    assert!(assets.join("demo/fr.ftl").is_file());
+    assert!(assets.join("demo/fr/ui.ftl").is_file());
+    assert!(!assets.join("en").exists());
+
+    crate::clean::clean(target, assets.join("en"), temp.path(), &[&base], false).expect("clean");
+    assert!(assets.join("demo/en.ftl").is_file());
+    assert!(!assets.join("demo/en").exists());
+}
+
+fn strip_group_comments(resource: &mut ast::Resource<String>) {
+    resource
+        .body
+        .retain(|entry| !matches!(entry, ast::Entry::GroupComment(_)));
+}
+
+#[test]
+fn target_post_process_rewrites_each_file_before_serialization() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let items = vec![test_type(
+        "Profile",
+        vec![test_variant("Profile", "profile", &[])],
+    )];
+    let target = FtlTarget::new("demo").with_post_process(strip_group_comments);
+    let file_path = i18n.join("demo.ftl");
+
+    for expected_change in [true, false] {
+        let changed = generate(
+            target,
+            &i18n,
+            temp.path(),
+            &items,
+            FluentParseMode::Conservative,
+            false,
+        )
+        .expect("generate");
+        assert_eq!(changed, expected_change);
+        assert_eq!(
+            fs::read_to_string(&file_path).expect("read"),
+            "profile = Profile\n"
+        );
+    }
+    assert!(
+        crate::stale_files(
+            target,
+            &i18n,
+            temp.path(),
+            &items,
+            FluentParseMode::Conservative
+        )
+        .expect("stale files")
+        .is_empty()
+    );
+    assert_eq!(
+        crate::stale_files(
+            "demo",
+            &i18n,
+            temp.path(),
+            &items,
+            FluentParseMode::Conservative
+        )
+        .expect("stale files"),
+        vec![file_path]
+    );
+    assert_eq!(target, FtlTarget::new("demo"));
+}
+
+#[test]
+fn target_header_survives_regeneration_without_duplicating() {
+    let items = vec![test_type(
+        "Profile",
+        vec![test_variant("Profile", "profile", &[])],
+    )];
+    let target = FtlTarget::new("demo").with_header("Generated by es-fluent.\nDo not edit.");
+    let expected = "### @generated by es-fluent\n### Generated by es-fluent.\n### Do not edit.\n\n## Profile\n\nprofile = Profile\n";
+
+    for mode in [
+        FluentParseMode::Conservative,
+        FluentParseMode::Aggressive,
+        FluentParseMode::Merge,
+    ] {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let i18n = temp.path().join("i18n");
+        let file_path = i18n.join("demo.ftl");
+
+        for expected_change in [true, false] {
+            let changed =
+                generate(target, &i18n, temp.path(), &items, mode, false).expect("generate");
+            assert_eq!(changed, expected_change, "{mode:?}");
+            assert_eq!(
+                fs::read_to_string(&file_path).expect("read"),
+                expected,
+                "{mode:?}"
+            );
+        }
+        assert!(
+            crate::stale_files(target, &i18n, temp.path(), &items, mode)
+                .expect("stale files")
+                .is_empty()
+        );
+    }
+    assert_ne!(target, FtlTarget::new("demo"));
+}
+
+#[test]
+fn target_header_is_replaced_when_its_text_changes_and_removed_when_unset() {
+    let items = vec![test_type(
+        "Profile",
+        vec![test_variant("Profile", "profile", &[])],
+    )];
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let file_path = i18n.join("demo.ftl");
+    let body = "## Profile\n\nprofile = Profile\n";
+    // A banner written before the marker line existed is not duplicated.
+    fs::create_dir_all(&i18n).expect("create i18n dir");
+    fs::write(&file_path, format!("### Old banner.\n\n{body}")).expect("write legacy banner");
+
+    for (header, expected) in [
+        (
+            Some("Old banner."),
+            format!("### @generated by es-fluent\n### Old banner.\n\n{body}"),
+        ),
+        (
+            Some("New banner."),
+            format!("### @generated by es-fluent\n### New banner.\n\n{body}"),
+        ),
+        (None, body.to_string()),
+    ] {
+        let target = match header {
+            Some(header) => FtlTarget::new("demo").with_header(header),
+            None => FtlTarget::new("demo"),
+        };
+        generate(
+            target,
+            &i18n,
+            temp.path(),
+            &items,
+            FluentParseMode::Merge,
+            false,
+        )
+        .expect("generate");
+        assert_eq!(fs::read_to_string(&file_path).expect("read"), expected);
+    }
+}
+
+#[test]
+fn target_annotate_source_records_and_updates_the_generating_type() {
+    let status_in = |module_path: &'static str| {
+        FtlTypeInfo::new(
+            TypeKind::Enum,
+            "Status",
+            leak_slice(vec![
+                test_variant("Ready", "status-Ready", &[]).with_doc("Shown when idle."),
+            ]),
+            "src/status.rs",
+            module_path,
+            None,
+        )
+    };
+    let profile = FtlTypeInfo::new(
+        TypeKind::Struct,
+        "Profile",
+        leak_slice(vec![test_variant("Profile", "profile", &[])]),
+        "src/profile.rs",
+        "demo::profile",
+        None,
+    );
+    let target = FtlTarget::new("demo").with_annotate_source(true);
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let file_path = i18n.join("demo.ftl");
+
+    generate(
+        target,
+        &i18n,
+        temp.path(),
+        &[profile.clone(), status_in("demo::ui")],
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("generate");
+    assert_eq!(
+        fs::read_to_string(&file_path).expect("read"),
+        "## Profile\n\n# @source: demo::profile::Profile\nprofile = Profile\n\n## Status\n\n# Shown when idle.\n# @source: demo::ui::Status::Ready\nstatus-Ready = Ready\n"
+    );
+
+    // A translated entry keeps its value while the annotation follows the
+    // type to its new module.
+    fs::write(
+        &file_path,
+        "## Profile\n\n# @source: demo::profile::Profile\nprofile = Profil\n\n## Status\n\n# Shown when idle.\n# @source: demo::ui::Status::Ready\nstatus-Ready = Bereit\n",
+    )
+    .expect("write");
+    let moved = [profile, status_in("demo::settings")];
+    generate(
+        target,
+        &i18n,
+        temp.path(),
+        &moved,
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("regenerate");
+    assert_eq!(
+        fs::read_to_string(&file_path).expect("read"),
+        "## Profile\n\n# @source: demo::profile::Profile\nprofile = Profil\n\n## Status\n\n# Shown when idle.\n# @source: demo::settings::Status::Ready\nstatus-Ready = Bereit\n"
+    );
+    assert!(
+        crate::stale_files(
+            target,
+            &i18n,
+            temp.path(),
+            &moved,
+            FluentParseMode::Conservative
+        )
+        .expect("stale files")
+        .is_empty()
+    );
+
+    // Merge mode leaves existing entries alone and annotates only new keys.
+    fs::write(&file_path, "## Profile\n\nprofile = Profil\n").expect("write");
+    generate(
+        target,
+        &i18n,
+        temp.path(),
+        &moved,
+        FluentParseMode::Merge,
+        false,
+    )
+    .expect("merge");
+    assert_eq!(
+        fs::read_to_string(&file_path).expect("read"),
+        "## Profile\n\nprofile = Profil\n\n## Status\n\n# Shown when idle.\n# @source: demo::settings::Status::Ready\nstatus-Ready = Ready\n"
+    );
+    assert_ne!(target, FtlTarget::new("demo"));
+
+    // Turning the option off removes only the annotation lines.
+    fs::write(
+        &file_path,
+        "## Profile\n\n# from: the design team\n# @source: demo::profile::Profile\nprofile = Profil\n",
+    )
+    .expect("write");
+    generate(
+        "demo",
+        &i18n,
+        temp.path(),
+        &moved[..1],
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("generate without annotations");
+    assert_eq!(
+        fs::read_to_string(&file_path).expect("read"),
+        "## Profile\n\n# from: the design team\nprofile = Profil\n"
+    );
+}
+
+#[test]
+fn target_annotate_source_annotates_term_links() {
+    fn state_ids() -> &'static [StaticFluentEntryId] {
+        const IDS: &[StaticFluentEntryId] = &[
+            __macro::static_entry_id("usa_state-Texas"),
+            __macro::static_entry_id("city-Austin"),
+        ];
+        IDS
+    }
+
+    static STATE_LINKS: &[es_fluent_shared::registry::FtlTermLink] = &[__macro::ftl_term_link(
+        __macro::static_argument_name("f0"),
+        state_ids,
+    )];
+    let country = FtlTypeInfo::new(
+        TypeKind::Struct,
+        "Country",
+        leak_slice(vec![
+            test_variant("Country", "country", &["f0"]).with_term_links(STATE_LINKS),
+        ]),
+        "src/lib.rs",
+        "demo",
+        None,
+    );
+    let state = FtlTypeInfo::new(
+        TypeKind::Enum,
+        "UsaState",
+        leak_slice(vec![test_variant("Texas", "usa_state-Texas", &[])]),
+        "src/lib.rs",
+        "demo::geo",
+        None,
+    );
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+
+    generate(
+        FtlTarget::new("demo").with_annotate_source(true),
+        &i18n,
+        temp.path(),
+        &[country, state],
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("generate");
+
+    let content = fs::read_to_string(i18n.join("demo.ftl")).expect("read");
+    assert!(
+        content.contains("# @source: demo::geo::UsaState::Texas\n-usa_state-Texas ="),
+        "{content}"
+    );
+    assert!(
+        content.contains("# @source: demo::Country\n-city-Austin ="),
+        "{content}"
+    );
+}
+
+#[test]
+fn generate_skips_types_marked_skip_generate_but_clean_keeps_their_keys() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let items = vec![
+        test_type("Profile", vec![test_variant("Profile", "profile", &[])]),
+        test_type("Shared", vec![test_variant("Shared", "shared", &[])]).with_skip_generate(),
+    ];
+    let file_path = i18n.join("demo.ftl");
+
+    generate(
+        "demo",
+        &i18n,
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("generate");
+    assert_eq!(
+        fs::read_to_string(&file_path).expect("read"),
+        "## Profile\n\nprofile = Profile\n"
+    );
+
+    fs::write(
+        &file_path,
+        "## Profile\n\nprofile = Profile\n\n# Hand-authored\nshared = Shared by hand\n",
+    )
+    .expect("write hand-authored key");
+    crate::clean::clean("demo", &i18n, temp.path(), &items, false).expect("clean");
+    assert!(
+        fs::read_to_string(&file_path)
+            .expect("read")
+            .contains("shared = Shared by hand")
+    );
+
+    let skipped_only =
+        vec![test_type("Shared", vec![test_variant("Shared", "shared", &[])]).with_skip_generate()];
+    let changed = generate(
+        "demo",
+        &i18n,
+        temp.path(),
+        &skipped_only,
+        FluentParseMode::Aggressive,
+        false,
+    )
+    .expect("generate skipped only");
+    assert!(!changed);
+    assert!(
+        fs::read_to_string(&file_path)
+            .expect("read")
+            .contains("shared = Shared by hand")
+    );
+}
+
+#[test]
+fn aggressive_generate_keeps_skip_generate_keys_in_a_shared_file() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let items = vec![
+        test_type("Profile", vec![test_variant("Profile", "profile", &[])]),
+        test_type("Shared", vec![test_variant("Shared", "shared", &[])]).with_skip_generate(),
+    ];
+    let file_path = i18n.join("demo.ftl");
+    fs::create_dir_all(&i18n).expect("create i18n");
+    fs::write(
+        &file_path,
+        "## Profile\n\nprofile = Old profile\nstale = Stale\n\n## Shared\n\n# Hand-authored\nshared = Shared by hand\n",
+    )
+    .expect("write shared file");
+
+    generate(
+        "demo",
+        &i18n,
+        temp.path(),
+        &items,
+        FluentParseMode::Aggressive,
+        false,
+    )
+    .expect("generate");
+    assert_eq!(
+        fs::read_to_string(&file_path).expect("read"),
+        "## Profile\n\nprofile = Profile\n\n## Shared\n\n# Hand-authored\nshared = Shared by hand\n"
+    );
+    assert!(
+        stale_files(
+            "demo",
+            &i18n,
+            temp.path(),
+            &items,
+            FluentParseMode::Aggressive
+        )
+        .expect("stale files")
+        .is_empty()
+    );
+
+    let existing =
+        parser::parse(fs::read_to_string(&file_path).expect("read")).expect("parse generated file");
+    let merged = merge_into_resource("demo", existing, &items, FluentParseMode::Aggressive)
+        .expect("merge into resource");
+    assert!(serializer::serialize(&merged).contains("shared = Shared by hand"));
+}
+
+#[test]
+fn generate_links_term_field_types_as_terms_in_their_own_group() {
+    fn usa_state_ids() -> &'static [StaticFluentEntryId] {
+        const IDS: &[StaticFluentEntryId] = &[
+            __macro::static_entry_id("usa_state-Alabama"),
+            __macro::static_entry_id("usa_state-Texas"),
+        ];
+        IDS
+    }
+    fn empty_ids() -> &'static [StaticFluentEntryId] {
+        &[]
+    }
+
+    static USA_LINKS: &[es_fluent_shared::registry::FtlTermLink] = &[__macro::ftl_term_link(
+        __macro::static_argument_name("f0"),
+        usa_state_ids,
+    )];
+    static EMPTY_LINKS: &[es_fluent_shared::registry::FtlTermLink] = &[__macro::ftl_term_link(
+        __macro::static_argument_name("f0"),
+        empty_ids,
+    )];
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let items = vec![
+        test_type(
+            "Country",
+            vec![
+                test_variant("USA", "country-USA", &["f0"]).with_term_links(USA_LINKS),
+                test_variant("Canada", "country-Canada", &["f0"]).with_term_links(EMPTY_LINKS),
+            ],
+        ),
+        test_type(
+            "UsaState",
+            vec![
+                test_variant("Alabama", "usa_state-Alabama", &[]),
+                test_variant("Texas", "usa_state-Texas", &[]),
+            ],
+        ),
+    ];
+    let expected = "## Country\n\ncountry-Canada = Canada { $f0 }\ncountry-USA = Usa { $f0 }\n\n## UsaState\n\n-usa_state-Alabama = { usa_state-Alabama }\n-usa_state-Texas = { usa_state-Texas }\nusa_state-Alabama = Alabama\nusa_state-Texas = Texas\n";
+
+    for _ in 0..2 {
+        generate(
+            "demo",
+            &i18n,
+            temp.path(),
+            &items,
+            FluentParseMode::Conservative,
+            false,
+        )
+        .expect("generate");
+        let content = fs::read_to_string(i18n.join("demo.ftl")).expect("read");
+        assert_eq!(content, expected);
+    }
+
+    // Without the linked type in this resource, terms join the linking group.
+    let output = temp.path().join("alone");
+    generate(
+        "demo",
+        &output,
+        temp.path(),
+        &items[..1],
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("generate");
+    let content = fs::read_to_string(output.join("demo.ftl")).expect("read");
+    assert_eq!(
+        content,
+        "## Country\n\n-usa_state-Alabama = { usa_state-Alabama }\n-usa_state-Texas = { usa_state-Texas }\ncountry-Canada = Canada { $f0 }\ncountry-USA = Usa { $f0 }\n"
+    );
+}
+
+#[test]
+fn generate_writes_each_term_link_once_per_domain() {
+    fn state_ids() -> &'static [StaticFluentEntryId] {
+        const IDS: &[StaticFluentEntryId] = &[__macro::static_entry_id("usa_state-Texas")];
+        IDS
+    }
+
+    static STATE_LINKS: &[es_fluent_shared::registry::FtlTermLink] = &[__macro::ftl_term_link(
+        __macro::static_argument_name("f0"),
+        state_ids,
+    )];
+    let namespace = |name: &str| {
+        Some(NamespaceRule::Literal(
+            ResolvedNamespace::new(name).expect("valid test namespace"),
+        ))
+    };
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let items = vec![
+        test_type_at_with_namespace(
+            "Shipping",
+            vec![test_variant("Shipping", "shipping", &["f0"]).with_term_links(STATE_LINKS)],
+            "",
+            namespace("orders"),
+        ),
+        test_type_at_with_namespace(
+            "Country",
+            vec![test_variant("Country", "country", &["f0"]).with_term_links(STATE_LINKS)],
+            "",
+            namespace("geo"),
+        ),
+        test_type(
+            "UsaState",
+            vec![test_variant("Texas", "usa_state-Texas", &[])],
+        ),
+    ];
+    // A stale copy from an earlier run must not survive next to the kept one.
+    fs::create_dir_all(i18n.join("demo")).expect("create namespace dir");
+    fs::write(
+        i18n.join("demo/orders.ftl"),
+        "-usa_state-Texas = { usa_state-Texas }\nshipping = Shipping { $f0 }\n",
+    )
+    .expect("write stale namespace file");
+
+    generate(
+        "demo",
+        &i18n,
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("generate");
+
+    assert_eq!(
+        fs::read_to_string(i18n.join("demo/geo.ftl")).expect("read geo"),
+        "## Country\n\n-usa_state-Texas = { usa_state-Texas }\ncountry = Country { $f0 }\n"
+    );
+    assert_eq!(
+        fs::read_to_string(i18n.join("demo/orders.ftl")).expect("read orders"),
+        "## Shipping\n\nshipping = Shipping { $f0 }\n"
+    );
+    assert!(
+        !fs::read_to_string(i18n.join("demo.ftl"))
+            .expect("read base")
+            .contains("-usa_state-Texas")
+    );
+}
+
+#[test]
+fn generate_writes_term_types_as_terms_and_merge_keeps_their_values() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let file_path = i18n.join("demo.ftl");
+    let items = vec![
+        test_type(
+            "Brand",
+            vec![
+                test_variant("Product", "brand-Product", &[]),
+                test_variant("Company", "brand-Company", &[]),
+            ],
+        )
+        .with_term(),
+    ];
+    let expected = "## Brand\n\n-brand-Company = Company\n-brand-Product = Product\n";
+
+    for _ in 0..2 {
+        generate(
+            "demo",
+            &i18n,
+            temp.path(),
+            &items,
+            FluentParseMode::Conservative,
+            false,
+        )
+        .expect("generate");
+        assert_eq!(fs::read_to_string(&file_path).expect("read"), expected);
+    }
+
+    let translated = expected.replace("-brand-Product = Product", "-brand-Product = Acme");
+    fs::write(&file_path, &translated).expect("write translated term");
+    let changed = generate(
+        "demo",
+        &i18n,
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("regenerate");
+    assert!(!changed);
+    assert_eq!(fs::read_to_string(&file_path).expect("read"), translated);
+
+    fs::write(
+        &file_path,
+        format!("{translated}-brand-Retired = Retired\n"),
+    )
+    .expect("write stale term");
+    crate::clean::clean("demo", &i18n, temp.path(), &items, false).expect("clean");
+    assert_eq!(fs::read_to_string(&file_path).expect("read"), translated);
+}
+
 #[test]
 fn generate_rejects_duplicate_keys_within_one_type_before_writing() {
     let temp = tempfile::tempdir().expect("tempdir");
@@ -129,6 +1133,79 @@ fn generate_rejects_duplicate_keys_within_one_type_before_writing() {
     assert!(!output.join("demo.ftl").exists());
 }
 
+#[test]
+fn generate_rejects_invalid_fluent_identifiers_before_writing() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let output = temp.path().join("i18n");
+    let item = test_type_at(
+        "Menu",
+        vec![__macro::ftl_variant(
+            "Open",
+            __macro::static_entry_id("menu.open file"),
+            &[],
+            "test",
+            7,
+        )],
+        "src/menu.rs",
+    );
+
+    let err = generate(
+        "demo",
+        &output,
+        temp.path(),
+        &[item],
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect_err("invalid identifier should fail");
+
+    assert!(matches!(
+        err,
+        es_fluent_shared::EsFluentError::InvalidFluentIdentifier { .. }
+    ));
+    let message = err.to_string();
+    assert!(message.contains("'menu.open file'"));
+    assert!(message.contains("invalid character '.'"));
+    assert!(message.contains("type 'Menu' variant 'Open' at src/menu.rs:7"));
+    assert!(!output.join("demo.ftl").exists());
+}
+
+#[test]
+fn generate_rejects_invalid_fluent_identifiers_before_writing_any_file() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let output = temp.path().join("i18n");
+    let items = vec![
+        test_type("App", vec![test_variant("App", "app", &[])]),
+        test_type_at_with_namespace(
+            "Menu",
+            vec![__macro::ftl_variant(
+                "Open",
+                __macro::static_entry_id("menu.open file"),
+                &[],
+                "test",
+                7,
+            )],
+            "src/menu.rs",
+            Some(NamespaceRule::Literal(
+                ResolvedNamespace::new("menu").expect("valid test namespace"),
+            )),
+        ),
+    ];
+
+    generate(
+        "demo",
+        &output,
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect_err("invalid identifier should fail");
+
+    assert!(!output.join("demo.ftl").exists());
+    assert!(!output.join("demo").exists());
+}
+
 #[test]
 fn generate_rejects_duplicate_keys_across_types() {
     let temp = tempfile::tempdir().expect("tempdir");
@@ -161,6 +1238,43 @@ fn generate_rejects_duplicate_keys_across_types() {
     assert!(message.contains("src/cancel.rs:9"));
 }
 
+#[test]
+fn generate_lists_every_type_sharing_a_duplicate_key() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let items = ["Alpha", "Beta", "Gamma"]
+        .into_iter()
+        .enumerate()
+        .map(|(index, type_name)| {
+            test_type_at(
+                type_name,
+                vec![test_variant_at(type_name, "ok", &[], index as u32 + 1)],
+                "src/status.rs",
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let err = generate(
+        "demo",
+        temp.path().join("i18n"),
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+        true,
+    )
+    .expect_err("duplicate key should fail");
+
+    assert!(matches!(
+        &err,
+        es_fluent_shared::EsFluentError::DuplicateGeneratedFtlKey { key, sources }
+            if key == "ok" && sources.len() == 3
+    ));
+    let message = err.to_string();
+    assert!(message.contains("Alpha"));
+    assert!(message.contains("Beta"));
+    assert!(message.contains("Gamma"));
+    assert!(message.contains("src/status.rs:3"));
+}
+
 #[test]
 fn generate_rejects_label_key_colliding_with_message_key() {
     let temp = tempfile::tempdir().expect("tempdir");
@@ -425,6 +1539,50 @@ fn smart_merge_moves_leading_comments_with_relocated_messages_and_preserves_term
     assert!(term_comment_pos < term_pos);
 }
 
+#[test]
+fn smart_merge_preserves_translator_comments_and_free_standing_comments() {
+    let group_a = test_type(
+        "GroupA",
+        vec![
+            test_variant("A1", "group_a-A1", &[]),
+            test_variant("A2", "group_a-A2", &[]),
+        ],
+    );
+    let group_b = test_type("GroupB", vec![test_variant("B1", "group_b-B1", &[])]);
+    let items = vec![&group_a, &group_b];
+
+    let existing = parse_resource_allowing_errors(
+        "## GroupA\n# Keep under 20 chars\ngroup_a-A1 = Translated\n\n# GroupB notes\n\n## GroupB\ngroup_b-B1 = Other\n",
+    );
+    let merged = smart_merge(existing, &items, MergeBehavior::Append).expect("merge");
+
+    let retained = merged
+        .body
+        .iter()
+        .find_map(|entry| match entry {
+            ast::Entry::Message(msg) if msg.id.name == "group_a-A1" => Some(msg),
+            _ => None,
+        })
+        .expect("retained message");
+    assert_eq!(
+        retained
+            .comment
+            .as_ref()
+            .map(|comment| comment.content.clone()),
+        Some(vec!["Keep under 20 chars".to_string()])
+    );
+
+    let content = formatting::sort_ftl_resource(&merged);
+    let appended_pos = content.find("group_a-A2").expect("appended key");
+    let notes_pos = content
+        .find("# GroupB notes")
+        .expect("free-standing comment");
+    let group_b_pos = content.find("## GroupB").expect("group b");
+    assert!(content.contains("# Keep under 20 chars\ngroup_a-A1 = Translated"));
+    assert!(appended_pos < notes_pos);
+    assert!(notes_pos < group_b_pos);
+}
+
 #[test]
 fn smart_merge_covers_relocation_junk_and_cleanup_modes() {
     let group_a = test_type("GroupA", vec![test_variant("A1", "group_a-A1", &[])]);
@@ -453,6 +1611,26 @@ fn smart_merge_covers_relocation_junk_and_cleanup_modes() {
     assert!(!merged_clean_text.contains("group_a-A1"));
 }
 
+#[test]
+fn smart_merge_clean_drops_orphans_inside_a_live_group() {
+    let greeting = test_type(
+        "Greeting",
+        vec![test_variant("Hello", "greeting-Hello", &[])],
+    );
+    let farewell = test_type("Farewell", vec![test_variant("Bye", "farewell-Bye", &[])]);
+    let items = vec![&greeting, &farewell];
+
+    let existing = parse_resource_allowing_errors(
+        "## Greeting\n\ngreeting-Hello = Hi\n# Retired wording\ngreeting-Goodbye = Bye\n\n## Farewell\n\nfarewell-Bye = Later\n",
+    );
+    let merged = smart_merge(existing, &items, MergeBehavior::Clean).expect("clean merge");
+
+    assert_eq!(
+        formatting::sort_ftl_resource(&merged),
+        "## Farewell\n\nfarewell-Bye = Later\n\n## Greeting\n\ngreeting-Hello = Hi\n"
+    );
+}
+
 #[test]
 fn smart_merge_handles_duplicates_empty_group_headers_and_comment_entries() {
     let group_a = test_type("GroupA", vec![test_variant("A1", "dup-key", &[])]);
@@ -547,8 +1725,9 @@ fn plan_outputs_uses_canonical_resource_specs_for_paths() {
     );
     let items = vec![&base, &namespaced];
 
-    let outputs = crate::pipeline::plan_outputs("crate-name", &i18n_root, temp.path(), &items)
-        .expect("planned outputs");
+    let outputs =
+        crate::pipeline::plan_outputs("crate-name".into(), &i18n_root, temp.path(), &items)
+            .expect("planned outputs");
     let base_output = outputs
         .iter()
         .find(|output| output.route.is_base())
@@ -679,3 +1858,371 @@ fn static_registry_wrappers_reject_invalid_manual_keys_and_arguments() {
             .contains("Fluent argument name contains invalid character")
     );
 }
+
+#[test]
+fn generate_with_locale_stubs_appends_todo_entries_without_touching_translations() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let en = i18n.join("en");
+    let fr = i18n.join("fr");
+    let de = i18n.join("de");
+    fs::create_dir_all(&fr).expect("create fr");
+    fs::write(
+        fr.join("demo.ftl"),
+        "## LoginError\n\n# Keep this wording.\nlogin_error-Locked = Compte verrouillé\n",
+    )
+    .expect("write fr");
+    let items = vec![
+        test_type(
+            "LoginError",
+            vec![
+                test_variant("Locked", "login_error-Locked", &[]),
+                test_variant("Expired", "login_error-Expired", &[]),
+            ],
+        ),
+        test_type("Profile", vec![test_variant("Profile", "profile", &[])]),
+    ];
+
+    let report = generate_report_with_locale_stubs(
+        "demo",
+        &en,
+        &[&fr, &de],
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("generate with locale stubs");
+
+    assert!(report.changed);
+    assert_eq!(
+        fs::read_to_string(en.join("demo.ftl")).expect("read en"),
+        "## LoginError\n\nlogin_error-Expired = Expired\nlogin_error-Locked = Locked\n\n## Profile\n\nprofile = Profile\n"
+    );
+    assert_eq!(
+        fs::read_to_string(fr.join("demo.ftl")).expect("read fr"),
+        "## LoginError\n\n# Keep this wording.\nlogin_error-Locked = Compte verrouillé\n# TODO: translate\nlogin_error-Expired = Expired\n\n## Profile\n\n# TODO: translate\nprofile = Profile\n"
+    );
+    assert_eq!(
+        fs::read_to_string(de.join("demo.ftl")).expect("read de"),
+        "## LoginError\n\n# TODO: translate\nlogin_error-Expired = Expired\n# TODO: translate\nlogin_error-Locked = Locked\n\n## Profile\n\n# TODO: translate\nprofile = Profile\n"
+    );
+
+    let rerun = generate_report_with_locale_stubs(
+        "demo",
+        &en,
+        &[&fr, &de],
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("regenerate with locale stubs");
+    assert!(!rerun.changed);
+}
+
+#[test]
+fn merge_into_resource_reproduces_generated_file_in_memory() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let items = vec![
+        test_type(
+            "Profile",
+            vec![test_variant("Profile", "profile", &["name"])],
+        ),
+        test_type(
+            "LoginError",
+            vec![
+                test_variant("Locked", "login_error-Locked", &[]),
+                test_variant("Expired", "login_error-Expired", &[]),
+            ],
+        ),
+    ];
+
+    let plain = FtlTarget::new("demo");
+    let finished = FtlTarget::new("demo")
+        .with_header("Generated.")
+        .with_annotate_source(true)
+        .with_post_process(strip_group_comments);
+
+    for (target, mode) in [plain, finished].into_iter().flat_map(|target| {
+        [
+            FluentParseMode::Conservative,
+            FluentParseMode::Aggressive,
+            FluentParseMode::Merge,
+        ]
+        .map(|mode| (target, mode))
+    }) {
+        generate(target, &i18n, temp.path(), &items, mode, false).expect("generate");
+        let written = fs::read_to_string(i18n.join("demo.ftl")).expect("read");
+        fs::remove_file(i18n.join("demo.ftl")).expect("reset");
+
+        let merged = merge_into_resource(target, ast::Resource { body: Vec::new() }, &items, mode)
+            .expect("merge");
+        assert_eq!(
+            crate::formatting::normalize_blank_lines(&serializer::serialize(&merged)),
+            written,
+            "{mode:?}"
+        );
+
+        let remerged = merge_into_resource(target, merged.clone(), &items, mode).expect("remerge");
+        assert_eq!(remerged, merged);
+    }
+}
+
+#[test]
+fn merge_into_resource_applies_each_mode_to_existing_translations() {
+    let existing = parser::parse(
+        "## Profile\n\nprofile = Profil { $name }\nprofile-stale = Old\n".to_string(),
+    )
+    .expect("parse existing");
+    let items = vec![
+        test_type(
+            "Profile",
+            vec![test_variant("Profile", "profile", &["name"])],
+        ),
+        test_type("Banner", vec![test_variant("Banner", "banner", &[])]),
+    ];
+    let render = |mode| {
+        let merged = merge_into_resource("demo", existing.clone(), &items, mode).expect("merge");
+        serializer::serialize(&merged)
+    };
+
+    let conservative = render(FluentParseMode::Conservative);
+    assert!(conservative.contains("profile = Profil { $name }\n"));
+    assert!(conservative.contains("profile-stale = Old\n"));
+    assert!(conservative.contains("banner = Banner\n"));
+
+    let aggressive = render(FluentParseMode::Aggressive);
+    assert!(aggressive.contains("profile = Profile { $name }\n"));
+    assert!(!aggressive.contains("profile-stale"));
+    assert!(aggressive.contains("banner = Banner\n"));
+
+    let merge = render(FluentParseMode::Merge);
+    assert!(merge.starts_with("## Profile\n\nprofile = Profil { $name }\nprofile-stale = Old\n"));
+    assert!(merge.contains("banner = Banner\n"));
+}
+
+#[test]
+fn merge_into_resource_rejects_duplicate_keys() {
+    let items = vec![
+        test_type("Profile", vec![test_variant("Profile", "shared", &[])]),
+        test_type("Banner", vec![test_variant("Banner", "shared", &[])]),
+    ];
+
+    let err = merge_into_resource(
+        "demo",
+        ast::Resource { body: Vec::new() },
+        &items,
+        FluentParseMode::Conservative,
+    )
+    .expect_err("duplicate key should fail");
+    assert!(
+        err.to_string()
+            .contains("Duplicate generated FTL key 'shared'")
+    );
+}
+
+#[test]
+fn merge_rejects_a_type_registered_with_conflicting_kinds() {
+    let as_struct = test_type_at(
+        "Status",
+        vec![test_variant_at("Status", "status", &[], 3)],
+        "src/status.rs",
+    )
+    .with_line(3);
+    let as_enum = FtlTypeInfo::new(
+        TypeKind::Enum,
+        "Status",
+        leak_slice(vec![test_variant_at("Ready", "status-Ready", &[], 8)]),
+        "src/status.rs",
+        "test",
+        None,
+    )
+    .with_line(7);
+
+    let err = merge_into_resource(
+        "demo",
+        ast::Resource { body: Vec::new() },
+        &[as_struct, as_enum],
+        FluentParseMode::Conservative,
+    )
+    .expect_err("conflicting kinds should fail");
+
+    assert!(matches!(
+        &err,
+        es_fluent_shared::EsFluentError::ConflictingTypeKind { type_name, sources }
+            if type_name == "Status" && sources.len() == 2
+    ));
+    assert_eq!(
+        err.to_string(),
+        "Conflicting kinds for type 'Status' from struct 'Status' at src/status.rs:3 and enum 'Status' at src/status.rs:7"
+    );
+}
+
+#[test]
+fn merge_output_does_not_depend_on_registration_order() {
+    let first = test_type("First", vec![test_variant("Shared", "first-Shared", &[])])
+        .with_group_label("Shared Group");
+    let second = test_type("Second", vec![test_variant("Shared", "second-Shared", &[])])
+        .with_group_label("Shared Group");
+
+    let forward = serializer::serialize(
+        &merge_into_resource(
+            "demo",
+            ast::Resource { body: Vec::new() },
+            &[first.clone(), second.clone()],
+            FluentParseMode::Conservative,
+        )
+        .expect("merge"),
+    );
+    let reversed = serializer::serialize(
+        &merge_into_resource(
+            "demo",
+            ast::Resource { body: Vec::new() },
+            &[second, first],
+            FluentParseMode::Conservative,
+        )
+        .expect("merge"),
+    );
+
+    assert_eq!(forward, reversed);
+    assert_eq!(
+        forward,
+        "## Shared Group\n\nfirst-Shared = Shared\nsecond-Shared = Shared\n"
+    );
+}
+
+#[test]
+fn generate_writes_group_labels_as_group_headers() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let items = vec![
+        test_type("App", vec![test_variant("App", "app", &[])]),
+        test_type(
+            "AppError",
+            vec![test_variant("Timeout", "app_error-Timeout", &[])],
+        )
+        .with_group_label("User Facing Errors"),
+        test_type(
+            "AuthError",
+            vec![test_variant("Denied", "auth_error-Denied", &[])],
+        )
+        .with_group_label("User Facing Errors"),
+    ];
+
+    for mode in [FluentParseMode::Conservative, FluentParseMode::Aggressive] {
+        generate("demo", &i18n, temp.path(), &items, mode, false).expect("generate");
+        assert_eq!(
+            fs::read_to_string(i18n.join("demo.ftl")).expect("read"),
+            "## App\n\napp = App\n\n## User Facing Errors\n\napp_error-Timeout = Timeout\nauth_error-Denied = Denied\n",
+            "{mode:?}"
+        );
+    }
+}
+
+#[test]
+fn sorting_a_generated_file_with_group_labels_is_a_fixed_point() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let i18n = temp.path().join("i18n");
+    let items = vec![
+        test_type("App", vec![test_variant("App", "app", &[])]),
+        test_type(
+            "AppError",
+            vec![test_variant("Timeout", "app_error-Timeout", &[])],
+        )
+        .with_group_label("User Facing Errors"),
+    ];
+
+    generate(
+        "demo",
+        &i18n,
+        temp.path(),
+        &items,
+        FluentParseMode::Conservative,
+        false,
+    )
+    .expect("generate");
+    let generated = fs::read_to_string(i18n.join("demo.ftl")).expect("read");
+    let resource = parser::parse(generated.clone()).expect("parse generated");
+
+    assert_eq!(
+        formatting::normalize_blank_lines(&formatting::sort_ftl_file_resource(&resource)),
+        generated
+    );
+}
+
+#[test]
+fn merge_renames_type_name_group_headers_to_group_labels() {
+    let existing = parser::parse(
+        "## AppError\n## Shown in toasts.\n\napp_error-Timeout = Zeitüberschreitung\n".to_string(),
+    )
+    .expect("parse existing");
+    let items = vec![
+        test_type(
+            "AppError",
+            vec![
+                test_variant("Timeout", "app_error-Timeout", &[]),
+                test_variant("Offline", "app_error-Offline", &[]),
+            ],
+        )
+        .with_group_label("User Facing Errors"),
+    ];
+
+    let conservative = serializer::serialize(
+        &merge_into_resource(
+            "demo",
+            existing.clone(),
+            &items,
+            FluentParseMode::Conservative,
+        )
+        .expect("merge"),
+    );
+    assert_eq!(
+        conservative,
+        "## User Facing Errors\n## Shown in toasts.\n\napp_error-Offline = Offline\napp_error-Timeout = Zeitüberschreitung\n"
+    );
+
+    let clean = crate::merge::smart_merge(
+        existing.clone(),
+        &[&items[0]],
+        crate::merge::MergeBehavior::Clean,
+    )
+    .expect("clean");
+    assert!(serializer::serialize(&clean).starts_with("## User Facing Errors\n"));
+
+    // Merge mode leaves existing entries untouched, headers included, but
+    // still files new keys under the old header.
+    let merge = serializer::serialize(
+        &merge_into_resource("demo", existing, &items, FluentParseMode::Merge).expect("merge"),
+    );
+    assert_eq!(
+        merge,
+        "## AppError\n## Shown in toasts.\n\napp_error-Timeout = Zeitüberschreitung\napp_error-Offline = Offline\n"
+    );
+}
+
+#[test]
+fn key_sources_point_each_key_at_its_deriving_type() {
+    let items = [
+        test_type_at(
+            "Greeting",
+            vec![test_variant_at("Hello", "greeting-Hello", &[], 7)],
+            "src/greeting.rs",
+        ),
+        test_type("Farewell", vec![test_variant("Bye", "farewell-Bye", &[])]),
+    ];
+    let refs: Vec<&FtlTypeInfo> = items.iter().collect();
+
+    let sources = crate::report::key_sources(&refs);
+
+    assert_eq!(
+        sources.get("greeting-Hello").map(String::as_str),
+        Some("type 'Greeting' variant 'Hello' at src/greeting.rs:7")
+    );
+    assert_eq!(
+        sources.get("farewell-Bye").map(String::as_str),
+        Some("type 'Farewell' variant 'Bye' at line 0")
+    );
+    assert!(!sources.contains_key("stale-Key"));
+}
@@ -0,0 +1,318 @@
+//! Reference checks for FTL messages.
+//!
+//! A translator who edits `{ $name }` into `{ $nam }` produces a message that
+//! still parses but can never be filled in at runtime. These helpers compare
+//! the `$variables` a message references with the arguments its Rust type
+//! passes, so build scripts and the CLI can flag that drift.
+//!
+//! Likewise, a hand-written `{ other-message }` or `{ -term }` reference keeps
+//! parsing after its target is renamed or removed, and Fluent renders it as an
+//! error placeholder. [`dangling_references`] finds those references.
+
+use crate::ftl::{entry_key, extract_variables_from_message};
+use es_fluent_shared::registry::{FtlTypeInfo, FtlVariant};
+use fluent_syntax::ast;
+use std::collections::BTreeSet;
+
+/// Variable references that differ between an FTL message and its arguments.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VariableDrift {
+    /// Variables the message references that no argument provides.
+    pub unknown: BTreeSet<String>,
+    /// Arguments the message never references.
+    pub unreferenced: BTreeSet<String>,
+}
+
+impl VariableDrift {
+    /// Compares the known argument names with the variables a message
+    /// references.
+    pub fn new<A, R>(args: A, referenced: R) -> Self
+    where
+        A: IntoIterator,
+        A::Item: Into<String>,
+        R: IntoIterator,
+        R::Item: Into<String>,
+    {
+        let args: BTreeSet<String> = args.into_iter().map(Into::into).collect();
+        let referenced: BTreeSet<String> = referenced.into_iter().map(Into::into).collect();
+
+        Self {
+            unknown: referenced.difference(&args).cloned().collect(),
+            unreferenced: args.difference(&referenced).cloned().collect(),
+        }
+    }
+
+    /// Compares the known argument names with the variables referenced by the
+    /// message value and its attributes.
+    pub fn between<A>(args: A, message: &ast::Message<String>) -> Self
+    where
+        A: IntoIterator,
+        A::Item: Into<String>,
+    {
+        Self::new(args, extract_variables_from_message(message))
+    }
+
+    /// Returns `true` when the message and its arguments agree.
+    pub fn is_empty(&self) -> bool {
+        self.unknown.is_empty() && self.unreferenced.is_empty()
+    }
+}
+
+/// Returns the variable drift between `message` and the variant of
+/// `type_info` registered under the same message id.
+///
+/// Returns `None` when `message` does not belong to `type_info`.
+pub fn variable_drift(
+    type_info: &FtlTypeInfo,
+    message: &ast::Message<String>,
+) -> Option<VariableDrift> {
+    type_info
+        .variants()
+        .iter()
+        .find(|variant| variant.entry_id().as_str() == message.id.name)
+        .map(|variant| variant_variable_drift(variant, message))
+}
+
+/// Returns the variable drift between `message` and a single registered
+/// variant, without checking that the message id matches.
+pub fn variant_variable_drift(
+    variant: &FtlVariant,
+    message: &ast::Message<String>,
+) -> VariableDrift {
+    VariableDrift::between(variant.args().iter().map(|arg| arg.as_str()), message)
+}
+
+/// A message or term reference whose target is not defined.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct DanglingReference {
+    /// Key of the message or term holding the reference.
+    pub entry: String,
+    /// Key the reference names.
+    pub target: String,
+}
+
+/// Returns the message and term references in `resource` whose target is not
+/// among `known_keys`.
+///
+/// Keys use the [`entry_key`] form: message ids as written and term ids with a
+/// leading `-`. An attribute reference such as `{ login.title }` is checked by
+/// its message id. Each dangling target is reported once per entry, in source
+/// order.
+pub fn dangling_references(
+    resource: &ast::Resource<String>,
+    known_keys: &BTreeSet<String>,
+) -> Vec<DanglingReference> {
+    let mut dangling = Vec::new();
+
+    for entry in &resource.body {
+        let (value, attributes) = match entry {
+            ast::Entry::Message(message) => (message.value.as_ref(), &message.attributes),
+            ast::Entry::Term(term) => (Some(&term.value), &term.attributes),
+            _ => continue,
+        };
+        let Some(key) = entry_key(entry) else {
+            continue;
+        };
+
+        let mut targets = Vec::new();
+        for pattern in value
+            .into_iter()
+            .chain(attributes.iter().map(|attr| &attr.value))
+        {
+            collect_references_from_pattern(pattern, &mut targets);
+        }
+
+        let mut seen = BTreeSet::new();
+        for target in targets {
+            if !known_keys.contains(&target) && seen.insert(target.clone()) {
+                dangling.push(DanglingReference {
+                    entry: key.to_string(),
+                    target,
+                });
+            }
+        }
+    }
+
+    dangling
+}
+
+fn collect_references_from_pattern(pattern: &ast::Pattern<String>, targets: &mut Vec<String>) {
+    for element in &pattern.elements {
+        if let ast::PatternElement::Placeable { expression } = element {
+            collect_references_from_expression(expression, targets);
+        }
+    }
+}
+
+fn collect_references_from_expression(
+    expression: &ast::Expression<String>,
+    targets: &mut Vec<String>,
+) {
+    match expression {
+        ast::Expression::Inline(inline) => collect_references_from_inline(inline, targets),
+        ast::Expression::Select { selector, variants } => {
+            collect_references_from_inline(selector, targets);
+            for variant in variants {
+                collect_references_from_pattern(&variant.value, targets);
+            }
+        },
+    }
+}
+
+fn collect_references_from_inline(
+    inline: &ast::InlineExpression<String>,
+    targets: &mut Vec<String>,
+) {
+    match inline {
+        ast::InlineExpression::MessageReference { id, .. } => targets.push(id.name.clone()),
+        ast::InlineExpression::TermReference { id, .. } => {
+            targets.push(format!("-{}", id.name));
+        },
+        ast::InlineExpression::FunctionReference { arguments, .. } => {
+            for arg in &arguments.positional {
+                collect_references_from_inline(arg, targets);
+            }
+            for arg in &arguments.named {
+                collect_references_from_inline(&arg.value, targets);
+            }
+        },
+        ast::InlineExpression::Placeable { expression } => {
+            collect_references_from_expression(expression, targets);
+        },
+        _ => {},
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use es_fluent_shared::meta::TypeKind;
+    use es_fluent_shared::registry::{StaticFluentArgumentName, StaticFluentEntryId};
+    use fluent_syntax::parser;
+
+    static ARGS: &[StaticFluentArgumentName] = &[
+        es_fluent_shared::registry::__macro::static_argument_name("name"),
+        es_fluent_shared::registry::__macro::static_argument_name("count"),
+    ];
+
+    fn user_greeting() -> FtlTypeInfo {
+        let variants = vec![FtlVariant::new(
+            "UserGreeting",
+            StaticFluentEntryId::try_new("user_greeting").expect("valid id"),
+            ARGS,
+            "test",
+            0,
+        )];
+
+        FtlTypeInfo::new(
+            TypeKind::Struct,
+            "UserGreeting",
+            variants.leak(),
+            "",
+            "test",
+            None,
+        )
+    }
+
+    fn parse_message(source: &str) -> ast::Message<String> {
+        let resource = parser::parse(source.to_string()).expect("valid ftl");
+        match resource.body.into_iter().next() {
+            Some(ast::Entry::Message(message)) => message,
+            other => panic!("expected a message, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn variable_drift_reports_unknown_and_unreferenced_variables() {
+        let message = parse_message("user_greeting = Hello { $nam }, you have { $count }\n");
+
+        let drift = variable_drift(&user_greeting(), &message).expect("registered message");
+
+        assert!(!drift.is_empty());
+        assert_eq!(drift.unknown, BTreeSet::from(["nam".to_string()]));
+        assert_eq!(drift.unreferenced, BTreeSet::from(["name".to_string()]));
+    }
+
+    #[test]
+    fn variable_drift_checks_selectors_and_attributes() {
+        let message = parse_message(
+            "user_greeting = { $count ->\n    [one] One item\n   *[other] Items\n}\n    .title = { $name }\n",
+        );
+
+        let drift = variable_drift(&user_greeting(), &message).expect("registered message");
+
+        assert!(drift.is_empty());
+    }
+
+    #[test]
+    fn variable_drift_ignores_messages_from_other_types() {
+        let message = parse_message("other_message = { $anything }\n");
+
+        assert_eq!(variable_drift(&user_greeting(), &message), None);
+    }
+
+    fn known_keys(keys: &[&str]) -> BTreeSet<String> {
+        keys.iter().map(|key| (*key).to_string()).collect()
+    }
+
+    #[test]
+    fn dangling_references_reports_missing_message_and_term_targets() {
+        let resource = parser::parse(
+            "\
+-brand = Acme
+login = Log in to { -brand }
+    .title = { login_title }
+welcome = { $count ->
+    [one] { greeting } and { -product }
+   *[other] { greeting } and { NUMBER(missing_count) }
+}
+-tagline = { -brand } { login.title } { removed }
+"
+            .to_string(),
+        )
+        .expect("valid ftl");
+
+        let dangling = dangling_references(
+            &resource,
+            &known_keys(&["-brand", "login", "welcome", "-tagline"]),
+        );
+
+        let pairs: Vec<_> = dangling
+            .iter()
+            .map(|reference| (reference.entry.as_str(), reference.target.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                ("login", "login_title"),
+                ("welcome", "greeting"),
+                ("welcome", "-product"),
+                ("welcome", "missing_count"),
+                ("-tagline", "removed"),
+            ]
+        );
+    }
+
+    #[test]
+    fn dangling_references_is_empty_when_every_target_is_known() {
+        let resource = parser::parse(
+            "hello = Hello
+welcome = { hello }, { -brand }
+"
+            .to_string(),
+        )
+        .expect("valid ftl");
+
+        assert!(
+            dangling_references(&resource, &known_keys(&["hello", "welcome", "-brand"])).is_empty()
+        );
+    }
+
+    #[test]
+    fn variable_drift_new_compares_plain_name_sets() {
+        let drift = VariableDrift::new(["name"], ["name", "extra"]);
+
+        assert_eq!(drift.unknown, BTreeSet::from(["extra".to_string()]));
+        assert!(drift.unreferenced.is_empty());
+    }
+}
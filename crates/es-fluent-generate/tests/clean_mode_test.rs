@@ -230,6 +230,21 @@ fn test_clean_dry_run_reports_stale_main_file_without_deleting_it() {
         stale_main_file.exists(),
         "dry-run should not delete stale main file"
     );
+
+    let report = es_fluent_generate::clean::clean_report(
+        crate_name,
+        &i18n_path,
+        temp_dir.path(),
+        std::slice::from_ref(&item),
+        true,
+    )
+    .unwrap();
+
+    assert!(report.changed);
+    assert_eq!(report.removed, ["stale-Old"]);
+    assert_eq!(report.removed_groups["stale-Old"], "Stale");
+    assert!(report.added.is_empty());
+    assert!(stale_main_file.exists());
 }
 
 #[test]
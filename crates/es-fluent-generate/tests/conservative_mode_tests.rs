@@ -49,3 +49,42 @@ fn test_conservative_mode_new_key_placement() {
     // Verify they are close to each other (optional, but good for "respective parents")
     // If Key2 ended up at the very bottom far away, that might be the bug.
 }
+
+#[test]
+fn test_conservative_mode_slots_new_keys_into_sorted_position() {
+    let temp_dir = TempDir::new().unwrap();
+    let i18n_path = temp_dir.path().join("i18n");
+    let crate_name = "test_crate";
+    let ftl_file_path = i18n_path.join(format!("{}.ftl", crate_name));
+
+    fs::create_dir_all(&i18n_path).unwrap();
+    fs::write(
+        &ftl_file_path,
+        "## GroupA\n\ngroup_a-Alpha = A\ngroup_a-Charlie = C\n",
+    )
+    .unwrap();
+
+    let group_a = common::enum_type(
+        "GroupA",
+        ["Alpha", "Bravo", "Charlie", "Delta"]
+            .into_iter()
+            .map(|key| common::variant(key, &common::ftl_key("GroupA", key)))
+            .collect(),
+    );
+
+    es_fluent_generate::generate(
+        crate_name,
+        &i18n_path,
+        temp_dir.path(),
+        std::slice::from_ref(&group_a),
+        FluentParseMode::Conservative,
+        false,
+    )
+    .unwrap();
+
+    let content = fs::read_to_string(&ftl_file_path).unwrap();
+    assert_eq!(
+        content,
+        "## GroupA\n\ngroup_a-Alpha = A\ngroup_a-Bravo = Bravo\ngroup_a-Charlie = C\ngroup_a-Delta = Delta\n"
+    );
+}
@@ -31,6 +31,48 @@ fn test_generate_empty_items() {
     assert!(!ftl_file_path.exists());
 }
 
+#[test]
+fn test_generate_report_dry_run_lists_added_and_removed_keys() {
+    let temp_dir = TempDir::new().unwrap();
+    let i18n_path = temp_dir.path().join("i18n");
+    let ftl_file_path = i18n_path.join("test_crate.ftl");
+    let existing = "## OldEnum\n\nold_enum-Gone = Gone\n\n## TestEnum\n\ntest_enum-Kept = Kept\n";
+    fs::create_dir_all(&i18n_path).unwrap();
+    fs::write(&ftl_file_path, existing).unwrap();
+
+    let type_info = common::enum_type(
+        "TestEnum",
+        vec![
+            common::variant("Kept", &common::ftl_key("TestEnum", "Kept")),
+            common::variant("Fresh", &common::ftl_key("TestEnum", "Fresh")),
+        ],
+    );
+    let new_type = common::enum_type(
+        "NewEnum",
+        vec![common::variant("Only", &common::ftl_key("NewEnum", "Only"))],
+    );
+
+    let report = es_fluent_generate::generate_report(
+        "test_crate",
+        &i18n_path,
+        temp_dir.path(),
+        &[type_info, new_type],
+        FluentParseMode::Aggressive,
+        true,
+    )
+    .unwrap();
+
+    assert!(report.changed);
+    assert_eq!(report.added, ["new_enum-Only", "test_enum-Fresh"]);
+    assert_eq!(report.removed, ["old_enum-Gone"]);
+    assert_eq!(
+        report.removed_groups,
+        std::collections::BTreeMap::from([("old_enum-Gone".to_string(), "OldEnum".to_string())])
+    );
+    assert_eq!(report.groups_added, ["NewEnum"]);
+    assert_eq!(read_ftl(&ftl_file_path), existing);
+}
+
 #[test]
 #[cfg_attr(not(target_os = "linux"), ignore = "insta snapshots are Linux-only")]
 fn test_generate_with_items() {
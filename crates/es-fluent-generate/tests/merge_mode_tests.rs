@@ -0,0 +1,198 @@
+mod common;
+use es_fluent_generate::{FluentParseMode, FtlTarget, SortMode};
+use fs_err as fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_merge_mode_restores_deleted_keys_in_their_group() {
+    let temp_dir = TempDir::new().unwrap();
+    let i18n_path = temp_dir.path().join("i18n");
+    let crate_name = "test_crate";
+    let ftl_file_path = i18n_path.join(format!("{}.ftl", crate_name));
+
+    fs::create_dir_all(&i18n_path).unwrap();
+
+    // GroupA lost Key2 by hand; both groups carry translated values.
+    let existing = "\
+## GroupB
+
+group_b-Key1 = Translated B1
+
+## GroupA
+
+# Keep short
+group_a-Key1 = Translated A1
+group_a-Key3 = Translated A3
+";
+    fs::write(&ftl_file_path, existing).unwrap();
+
+    let group_a = common::enum_type(
+        "GroupA",
+        vec![
+            common::variant("Key1", &common::ftl_key("GroupA", "Key1")),
+            common::variant("Key2", &common::ftl_key("GroupA", "Key2")),
+            common::variant("Key3", &common::ftl_key("GroupA", "Key3")),
+        ],
+    );
+    let group_b = common::enum_type(
+        "GroupB",
+        vec![common::variant("Key1", &common::ftl_key("GroupB", "Key1"))],
+    );
+
+    let changed = es_fluent_generate::generate(
+        crate_name,
+        &i18n_path,
+        temp_dir.path(),
+        &[group_a, group_b],
+        FluentParseMode::Merge,
+        false,
+    )
+    .unwrap();
+    assert!(changed);
+
+    let content = fs::read_to_string(&ftl_file_path).unwrap();
+
+    // Existing entries keep their values, comments, and original order.
+    assert!(content.contains("# Keep short\ngroup_a-Key1 = Translated A1"));
+    assert!(content.contains("group_a-Key3 = Translated A3"));
+    assert!(content.contains("group_b-Key1 = Translated B1"));
+    let group_b_pos = content.find("## GroupB").unwrap();
+    let group_a_pos = content.find("## GroupA").unwrap();
+    assert!(group_b_pos < group_a_pos);
+
+    // The deleted key is re-added inside its own group.
+    let key2_pos = content
+        .find("group_a-Key2 = Key2")
+        .expect("deleted key should be restored");
+    assert!(key2_pos > group_a_pos);
+    assert_eq!(content.matches("group_a-Key2").count(), 1);
+}
+
+#[test]
+fn test_merge_mode_adds_missing_groups_and_is_idempotent() {
+    let temp_dir = TempDir::new().unwrap();
+    let i18n_path = temp_dir.path().join("i18n");
+    let crate_name = "test_crate";
+    let ftl_file_path = i18n_path.join(format!("{}.ftl", crate_name));
+
+    fs::create_dir_all(&i18n_path).unwrap();
+    fs::write(
+        &ftl_file_path,
+        "## GroupA\n\ngroup_a-Key1 = Translated A1\n\n## Manual\n\nmanual-only = Kept\n",
+    )
+    .unwrap();
+
+    let items = [
+        common::enum_type(
+            "GroupA",
+            vec![common::variant("Key1", &common::ftl_key("GroupA", "Key1"))],
+        ),
+        common::enum_type(
+            "GroupB",
+            vec![common::variant("Key1", &common::ftl_key("GroupB", "Key1"))],
+        ),
+    ];
+
+    es_fluent_generate::generate(
+        crate_name,
+        &i18n_path,
+        temp_dir.path(),
+        &items,
+        FluentParseMode::Merge,
+        false,
+    )
+    .unwrap();
+
+    let content = fs::read_to_string(&ftl_file_path).unwrap();
+    assert!(content.contains("manual-only = Kept"));
+    let manual_pos = content.find("## Manual").unwrap();
+    let group_b_pos = content.find("## GroupB").expect("missing group appended");
+    assert!(manual_pos < group_b_pos);
+    assert!(content.contains("group_b-Key1 = Key1"));
+
+    let changed = es_fluent_generate::generate(
+        crate_name,
+        &i18n_path,
+        temp_dir.path(),
+        &items,
+        FluentParseMode::Merge,
+        false,
+    )
+    .unwrap();
+    assert!(!changed, "second merge run should be a no-op");
+}
+
+fn merge_group_a(existing: &str, sort_mode: SortMode, keys: &[&str]) -> String {
+    let temp_dir = TempDir::new().unwrap();
+    let i18n_path = temp_dir.path().join("i18n");
+    let crate_name = "test_crate";
+    let ftl_file_path = i18n_path.join(format!("{}.ftl", crate_name));
+
+    fs::create_dir_all(&i18n_path).unwrap();
+    fs::write(&ftl_file_path, existing).unwrap();
+
+    let group_a = common::enum_type(
+        "GroupA",
+        keys.iter()
+            .map(|key| common::variant(key, &common::ftl_key("GroupA", key)))
+            .collect(),
+    );
+
+    es_fluent_generate::generate(
+        FtlTarget::new(crate_name).with_sort_mode(sort_mode),
+        &i18n_path,
+        temp_dir.path(),
+        std::slice::from_ref(&group_a),
+        FluentParseMode::Merge,
+        false,
+    )
+    .unwrap();
+
+    fs::read_to_string(&ftl_file_path).unwrap()
+}
+
+#[test]
+fn test_merge_mode_source_order_appends_new_keys_to_the_group() {
+    let content = merge_group_a(
+        "## GroupA\n\ngroup_a-Bravo = B\ngroup_a-Delta = D\n\n## GroupB\n\ngroup_b-Key1 = B1\n",
+        SortMode::SourceOrder,
+        &["Alpha", "Bravo", "Charlie", "Delta"],
+    );
+
+    assert_eq!(
+        content,
+        "## GroupA\n\ngroup_a-Bravo = B\ngroup_a-Delta = D\ngroup_a-Alpha = Alpha\ngroup_a-Charlie = Charlie\n\n## GroupB\n\ngroup_b-Key1 = B1\n"
+    );
+}
+
+#[test]
+fn test_merge_mode_alphabetical_slots_new_keys_into_sorted_position() {
+    let content = merge_group_a(
+        "## GroupA\n\ngroup_a-Bravo = B\n# Translator note\ngroup_a-Delta = D\n\n## GroupB\n\ngroup_b-Key1 = B1\n",
+        SortMode::Alphabetical,
+        &["Alpha", "Bravo", "Charlie", "Delta", "Echo"],
+    );
+
+    // New keys land between their neighbours without splitting a comment from
+    // the entry it describes; the last one goes at the end of the group.
+    assert_eq!(
+        content,
+        "## GroupA\n\ngroup_a-Alpha = Alpha\ngroup_a-Bravo = B\ngroup_a-Charlie = Charlie\n# Translator note\ngroup_a-Delta = D\ngroup_a-Echo = Echo\n\n## GroupB\n\ngroup_b-Key1 = B1\n"
+    );
+}
+
+#[test]
+fn test_merge_mode_alphabetical_keeps_hand_ordered_entries_in_place() {
+    let content = merge_group_a(
+        "## GroupA\n\ngroup_a-Delta = D\ngroup_a-Bravo = B\n",
+        SortMode::Alphabetical,
+        &["Bravo", "Charlie", "Delta"],
+    );
+
+    // Existing entries are never reordered, so the new key goes before the
+    // first existing key that sorts after it.
+    assert_eq!(
+        content,
+        "## GroupA\n\ngroup_a-Charlie = Charlie\ngroup_a-Delta = D\ngroup_a-Bravo = B\n"
+    );
+}
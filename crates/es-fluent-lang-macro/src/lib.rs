@@ -59,6 +59,10 @@ impl CratePaths {
 /// - NOT link to the built-in `es-fluent-lang` runtime (you provide your own translations)
 /// - Register the enum with inventory (so it appears in generated FTL files)
 /// - Make your FTL files the source of truth for language labels
+///
+/// Add `locales = ["en", "fr"]` to generate exactly those variants instead of
+/// scanning the assets directory. The configured fallback language is always
+/// included.
 #[proc_macro_attribute]
 pub fn es_fluent_language(attr: TokenStream, item: TokenStream) -> TokenStream {
     expand_es_fluent_language(attr.into(), item.into()).into()
@@ -68,8 +72,8 @@ fn expand_es_fluent_language(
     attr: proc_macro2::TokenStream,
     item: proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
-    let mode = match ValidatedMacroInput::language_mode(attr) {
-        Ok(mode) => mode,
+    let args = match ValidatedMacroInput::language_args(attr) {
+        Ok(args) => args,
         Err(err) => return core_error_to_compile_error(err),
     };
 
@@ -96,7 +100,7 @@ fn expand_es_fluent_language(
         .to_compile_error();
     }
 
-    let config = match es_fluent_toml::I18nConfig::read_from_manifest_dir() {
+    let config = match es_fluent_toml::I18nConfig::read_from_workspace_root() {
         Ok(config) => config,
         Err(err) => {
             return syn::Error::new(
@@ -107,15 +111,18 @@ fn expand_es_fluent_language(
         },
     };
 
-    let mut languages = match config.available_languages() {
-        Ok(languages) => languages,
-        Err(err) => {
-            return syn::Error::new(
+    let languages = match args.locales() {
+        Some(locales) => explicit_languages(locales),
+        None => config.available_languages().map_err(|err| {
+            syn::Error::new(
                 enum_span,
                 format!("failed to collect available languages: {err}"),
             )
-            .to_compile_error();
-        },
+        }),
+    };
+    let mut languages = match languages {
+        Ok(languages) => languages,
+        Err(err) => return err.to_compile_error(),
     };
 
     let fallback_language = match config.fallback_language_identifier() {
@@ -143,18 +150,37 @@ fn expand_es_fluent_language(
 
     input_enum.attrs = add_default_language_derives(remove_es_fluent_derive(input_enum.attrs));
 
-    let expansion =
-        match LanguageExpansion::new(enum_ident, enum_span, mode, languages, fallback_language) {
-            Ok(expansion) => expansion,
-            Err(err) => {
-                let span = err.span().unwrap_or(enum_span);
-                return syn::Error::new(span, err.to_string()).to_compile_error();
-            },
-        };
+    let expansion = match LanguageExpansion::new(
+        enum_ident,
+        enum_span,
+        args.mode(),
+        languages,
+        fallback_language,
+    ) {
+        Ok(expansion) => expansion,
+        Err(err) => {
+            let span = err.span().unwrap_or(enum_span);
+            return syn::Error::new(span, err.to_string()).to_compile_error();
+        },
+    };
 
     emit_language_expansion(input_enum, &expansion)
 }
 
+fn explicit_languages(locales: &[LitStr]) -> syn::Result<Vec<LanguageIdentifier>> {
+    locales
+        .iter()
+        .map(|locale| {
+            locale.value().parse::<LanguageIdentifier>().map_err(|err| {
+                syn::Error::new(
+                    locale.span(),
+                    format!("invalid locale `{}` in `locales`: {err}", locale.value()),
+                )
+            })
+        })
+        .collect()
+}
+
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 struct CanonicalLanguageId {
     canonical: String,
@@ -334,6 +360,18 @@ fn emit_language_expansion(
         .iter()
         .map(|entry| &entry.literal)
         .collect();
+    let next_variant_idents: Vec<_> = variant_idents
+        .iter()
+        .cycle()
+        .skip(1)
+        .take(variant_idents.len())
+        .collect();
+    let previous_variant_idents: Vec<_> = variant_idents
+        .iter()
+        .cycle()
+        .skip(variant_idents.len() - 1)
+        .take(variant_idents.len())
+        .collect();
     let force_link_keepalive = if expansion.link_builtin {
         quote! {
             #[cfg(target_arch = "wasm32")]
@@ -404,11 +442,13 @@ fn emit_language_expansion(
             type Error = #conversion_error_ident;
 
             fn try_from(lang: &#es_fluent::unic_langid::LanguageIdentifier) -> Result<Self, Self::Error> {
-                let lang_str = lang.to_string();
-                match lang_str.as_str() {
-                    #( #language_literals => Ok(#enum_ident::#variant_idents), )*
-                    _ => Err(#conversion_error_ident::UnsupportedLanguageIdentifier(lang.clone())),
-                }
+                #es_fluent::__manager_core::locale_candidates(lang)
+                    .iter()
+                    .find_map(|candidate| match candidate.to_string().as_str() {
+                        #( #language_literals => Some(#enum_ident::#variant_idents), )*
+                        _ => None,
+                    })
+                    .ok_or_else(|| #conversion_error_ident::UnsupportedLanguageIdentifier(lang.clone()))
             }
         }
 
@@ -439,6 +479,36 @@ fn emit_language_expansion(
                 #enum_ident::#fallback_variant_ident
             }
         }
+
+        impl #enum_ident {
+            /// Returns the variant for `lang`, or the fallback language when
+            /// `lang` is not supported.
+            pub fn from_locale_or_default(lang: &#es_fluent::unic_langid::LanguageIdentifier) -> Self {
+                Self::try_from(lang).unwrap_or_default()
+            }
+
+            /// Returns every supported language, including the fallback, in
+            /// variant order (sorted by locale tag).
+            pub const fn all() -> &'static [Self] {
+                &[#( #enum_ident::#variant_idents ),*]
+            }
+
+            /// Returns the language after `self` in [`Self::all`], wrapping
+            /// around to the first one.
+            pub const fn next(self) -> Self {
+                match self {
+                    #( #enum_ident::#variant_idents => #enum_ident::#next_variant_idents, )*
+                }
+            }
+
+            /// Returns the language before `self` in [`Self::all`], wrapping
+            /// around to the last one.
+            pub const fn previous(self) -> Self {
+                match self {
+                    #( #enum_ident::#variant_idents => #enum_ident::#previous_variant_idents, )*
+                }
+            }
+        }
     }
 }
 
@@ -570,6 +640,7 @@ fn generate_inventory_submit(
     let type_name = enum_ident.to_string().trim_start_matches("r#").to_string();
     let module_suffix = type_name.to_snake_case();
     let mod_name = format_ident!("__es_fluent_language_inventory_{module_suffix}");
+    let source_line = quote_spanned! { enum_ident.span()=> line!() };
     let variants = inventory
         .messages()
         .iter()
@@ -591,6 +662,7 @@ fn generate_inventory_submit(
                     #type_name,
                     VARIANTS,
                     file!(),
+                    #source_line,
                     module_path!(),
                     None,
                 );
@@ -859,4 +931,31 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn macro_uses_explicit_locales_instead_of_locale_directories() {
+        with_manifest_dir(
+            Some("fallback_language = \"en\"\nassets_dir = \"missing\"\n"),
+            &["zz"],
+            |_| {
+                let output = run_macro("locales = [\"fr\", \"de\"]", "enum Languages {}");
+                assert_snapshot!("macro_uses_explicit_locales", pretty_tokens(&output));
+
+                let custom_output = pretty_tokens(&run_macro(
+                    "custom, locales = [\"fr\", \"de\"]",
+                    "enum CustomLanguages {}",
+                ));
+                assert!(custom_output.contains("__es_fluent_language_inventory_custom_languages"));
+                assert!(custom_output.contains("CustomLanguages::De"));
+                assert!(!custom_output.contains("Zz"));
+
+                let invalid =
+                    run_macro("locales = [\"en\", \"not a locale\"]", "enum Languages {}");
+                assert_snapshot!(
+                    "macro_rejects_invalid_explicit_locale",
+                    pretty_tokens(&invalid)
+                );
+            },
+        );
+    }
 }
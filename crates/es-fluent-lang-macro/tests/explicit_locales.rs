@@ -0,0 +1,119 @@
+use std::process::Command;
+
+use path_slash::PathExt as _;
+use tempfile::TempDir;
+
+fn toml_path(path: &std::path::Path) -> String {
+    path.to_slash_lossy().into_owned()
+}
+
+#[test]
+fn language_macro_generates_explicit_locales_without_locale_directories() {
+    let temp = TempDir::new().expect("create temp crate");
+    let crate_dir = temp.path();
+    let src_dir = crate_dir.join("src");
+    std::fs::create_dir_all(src_dir).expect("create src dir");
+    std::fs::create_dir_all(crate_dir.join("i18n/en")).expect("create locale dir");
+
+    let workspace_root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .ancestors()
+        .nth(2)
+        .expect("workspace root");
+    let facade_path = workspace_root.join("crates/es-fluent");
+    let lang_path = workspace_root.join("crates/es-fluent-lang");
+
+    std::fs::write(
+        crate_dir.join("Cargo.toml"),
+        format!(
+            r#"
+[package]
+name = "explicit-locales-fixture"
+version = "0.0.0"
+edition = "2024"
+
+[dependencies]
+es-fluent = {{ path = "{}" }}
+es-fluent-lang = {{ path = "{}" }}
+"#,
+            toml_path(&facade_path),
+            toml_path(&lang_path)
+        ),
+    )
+    .expect("write Cargo.toml");
+
+    std::fs::write(
+        crate_dir.join("i18n.toml"),
+        r#"
+fallback_language = "en"
+assets_dir = "i18n"
+"#,
+    )
+    .expect("write i18n.toml");
+
+    // Only `i18n/en` exists on disk; `fr` and `de` come from the attribute,
+    // and the exhaustive match fails to compile if the variant set differs.
+    // The fixture's own tests check region-insensitive conversions and the
+    // generated `all`/`next`/`previous` order at runtime.
+    std::fs::write(
+        crate_dir.join("src/lib.rs"),
+        r#"
+use es_fluent_lang::es_fluent_language;
+
+#[es_fluent_language(locales = ["en", "fr", "de"])]
+pub enum Languages {}
+
+pub fn locale_tag(language: Languages) -> &'static str {
+    match language {
+        Languages::De => "de",
+        Languages::En => "en",
+        Languages::Fr => "fr",
+    }
+}
+
+pub fn fallback_language() -> es_fluent::unic_langid::LanguageIdentifier {
+    Languages::default().into()
+}
+
+pub fn browser_language(lang: &es_fluent::unic_langid::LanguageIdentifier) -> Languages {
+    Languages::from_locale_or_default(lang)
+}
+
+#[test]
+fn regional_locales_resolve_to_parent_variants() {
+    assert_eq!("fr".parse::<Languages>().unwrap(), Languages::Fr);
+    assert_eq!("en-GB".parse::<Languages>().unwrap(), Languages::En);
+    assert_eq!("de-CH".parse::<Languages>().unwrap(), Languages::De);
+    assert!("ja-JP".parse::<Languages>().is_err());
+    assert_eq!(
+        browser_language(&"ja-JP".parse().unwrap()),
+        Languages::default()
+    );
+}
+
+#[test]
+fn languages_cycle_in_variant_order() {
+    assert_eq!(Languages::all(), &[Languages::De, Languages::En, Languages::Fr]);
+    assert_eq!(Languages::En.next(), Languages::Fr);
+    assert_eq!(Languages::Fr.next(), Languages::De);
+    assert_eq!(Languages::De.previous(), Languages::Fr);
+    assert_eq!(Languages::Fr.previous(), Languages::En);
+}
+"#,
+    )
+    .expect("write lib.rs");
+
+    let output = Command::new("cargo")
+        .arg("test")
+        .arg("--manifest-path")
+        .arg(crate_dir.join("Cargo.toml"))
+        .output()
+        .expect("run cargo test");
+
+    assert!(
+        output.status.success(),
+        "cargo test failed\nstatus: {}\nstdout:\n{}\nstderr:\n{}",
+        output.status,
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
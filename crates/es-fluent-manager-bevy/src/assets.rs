@@ -1,9 +1,9 @@
 use bevy::asset::{Asset, AssetLoader, AsyncReadExt as _, LoadContext};
 use bevy::prelude::*;
 use es_fluent_manager_core::{
-    FluentArgumentMap, FluentDomain, FluentManager, LocaleLoadReport, LocalizationError,
-    ModuleResourceSpec, ResourceKey, ResourceLoadError, StaticFluentDomain, StaticFluentEntryId,
-    SyncFluentBundle,
+    BundleOptions, FluentArgumentMap, FluentDomain, FluentManager, LocaleLoadReport,
+    LocalizationError, ModuleResourceSpec, ResourceKey, ResourceLoadError, StaticFluentDomain,
+    StaticFluentEntryId, SyncFluentBundle,
 };
 use fluent_bundle::FluentResource;
 use serde::{Deserialize, Serialize};
@@ -31,10 +31,11 @@ impl AssetLoader for FtlAssetLoader {
         &self,
         reader: &mut dyn bevy::asset::io::Reader,
         _settings: &Self::Settings,
-        _load_context: &mut LoadContext<'_>,
+        load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut content = String::new();
         reader.read_to_string(&mut content).await?;
+        check_ftl_syntax(&load_context.asset_path().to_string(), &content)?;
         Ok(FtlAsset { content })
     }
 
@@ -43,6 +44,39 @@ impl AssetLoader for FtlAssetLoader {
     }
 }
 
+/// Rejects malformed Fluent source so Bevy reports the failure against the
+/// asset path instead of when bundles are built later.
+pub(crate) fn check_ftl_syntax(path: &str, content: &str) -> std::io::Result<()> {
+    let Err((_, errors)) = FluentResource::try_new(content.to_string()) else {
+        return Ok(());
+    };
+
+    let details = errors
+        .iter()
+        .map(|error| {
+            let line = error_line(content, error.pos.start);
+            format!("line {line}: {error}")
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    Err(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("Fluent parse errors in '{path}': {details}"),
+    ))
+}
+
+/// Returns the 1-based line of a parser byte offset.
+///
+/// Bytes are counted because the offset need not be a char boundary.
+pub(crate) fn error_line(content: &str, pos: usize) -> usize {
+    content.as_bytes()[..pos.min(content.len())]
+        .iter()
+        .filter(|byte| **byte == b'\n')
+        .count()
+        + 1
+}
+
 /// A Bevy resource that manages the loading of `FtlAsset`s.
 #[derive(Clone, Default, Resource)]
 pub struct I18nAssets {
@@ -232,6 +266,23 @@ impl I18nAssets {
     pub fn available_languages(&self) -> Vec<LanguageIdentifier> {
         es_fluent_manager_core::collect_available_languages(&self.resource_specs)
     }
+
+    /// Forgets every resource registered for `lang` and drops its asset
+    /// handles so Bevy can free the FTL assets.
+    ///
+    /// The language is no longer available afterwards. The active language
+    /// and its fallbacks must not be unloaded. Returns whether anything was
+    /// registered for `lang`.
+    pub fn unload_language(&mut self, lang: &LanguageIdentifier) -> bool {
+        let registered = self.resource_specs.len();
+        self.resource_specs
+            .retain(|(language, _), _| language != lang);
+        self.assets.retain(|(language, _), _| language != lang);
+        self.loaded_resources
+            .retain(|(language, _), _| language != lang);
+        self.load_errors.retain(|(language, _), _| language != lang);
+        self.resource_specs.len() != registered
+    }
 }
 
 impl I18nBundle {
@@ -346,6 +397,7 @@ pub struct I18nResource {
     active_language: LanguageIdentifier,
     resolved_language: LanguageIdentifier,
     fallback_manager: Option<Arc<FluentManager>>,
+    bundle_options: BundleOptions,
 }
 
 impl I18nResource {
@@ -355,6 +407,7 @@ impl I18nResource {
             active_language: initial_language.clone(),
             resolved_language: initial_language,
             fallback_manager: None,
+            bundle_options: BundleOptions::default(),
         }
     }
 
@@ -368,6 +421,7 @@ impl I18nResource {
             active_language,
             resolved_language,
             fallback_manager: None,
+            bundle_options: BundleOptions::default(),
         }
     }
 
@@ -379,6 +433,15 @@ impl I18nResource {
         self
     }
 
+    /// Formats Bevy asset messages with `bundle_options`.
+    ///
+    /// A fallback manager attached with [`Self::with_fallback_manager`] keeps
+    /// its own options.
+    pub fn with_bundle_options(mut self, bundle_options: BundleOptions) -> Self {
+        self.bundle_options = bundle_options;
+        self
+    }
+
     /// Returns the current published active `LanguageIdentifier`.
     pub fn active_language(&self) -> &LanguageIdentifier {
         &self.active_language
@@ -445,27 +508,43 @@ impl I18nResource {
         i18n_bundle: &I18nBundle,
     ) -> Option<String> {
         let locale_resources = i18n_bundle.fallback_locale_resources(&self.active_language);
-        let (value, errors) = es_fluent_manager_core::localize_with_fallback_resources(
+        es_fluent_manager_core::format_message_with_fallback_resources(
             locale_resources.as_slice(),
             id,
             args,
-        );
-        if es_fluent_manager_core::fallback_errors_are_fatal(&errors) {
-            error!(
-                "Fluent fallback formatting errors for '{}': {:?}",
-                id.as_str(),
-                errors
-            );
-            return None;
-        }
-
-        value.or_else(|| {
+            &self.bundle_options,
+        )
+        .or_else(|| {
             self.fallback_manager
                 .as_ref()
                 .and_then(|manager| manager.localize(id, args))
         })
     }
 
+    /// Localizes a message attribute, such as `.aria-label`, by message ID,
+    /// attribute name, and arguments against the requested locale fallback
+    /// chain.
+    ///
+    /// Returns `None` if no cached locale in that chain defines both the
+    /// message and the attribute.
+    pub fn localize_attribute<'a>(
+        &self,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&FluentArgumentMap<'a>>,
+        i18n_bundle: &I18nBundle,
+    ) -> Option<String> {
+        let locale_resources = i18n_bundle.fallback_locale_resources(&self.active_language);
+        es_fluent_manager_core::localize_attribute_with_fallback_resources(
+            locale_resources.as_slice(),
+            id,
+            attribute,
+            args,
+            &self.bundle_options,
+        )
+        .and_then(|formatted| es_fluent_manager_core::accept_formatted_value(id, formatted))
+    }
+
     #[doc(hidden)]
     pub fn localize_with_fallback<'a>(
         &self,
@@ -475,7 +554,7 @@ impl I18nResource {
     ) -> String {
         self.localize(id, args, i18n_bundle).unwrap_or_else(|| {
             warn!("Translation for '{}' not found", id.as_str());
-            id.as_str().to_string()
+            es_fluent::format_missing_key(id.as_str()).unwrap_or_else(|| id.as_str().to_string())
         })
     }
 
@@ -489,27 +568,73 @@ impl I18nResource {
     ) -> Option<String> {
         let locale_resources =
             i18n_domain_bundles.fallback_locale_resources(&self.active_language, domain.as_str());
-        let (value, errors) = es_fluent_manager_core::localize_with_fallback_resources(
+        es_fluent_manager_core::format_message_with_fallback_resources(
             locale_resources.as_slice(),
             id,
             args,
-        );
-        if es_fluent_manager_core::fallback_errors_are_fatal(&errors) {
-            error!(
-                "Fluent fallback formatting errors for '{}' in domain '{}': {:?}",
-                id.as_str(),
-                domain.as_str(),
-                errors
-            );
-            return None;
-        }
-
-        value.or_else(|| {
+            &self.bundle_options,
+        )
+        .or_else(|| {
             self.fallback_manager
                 .as_ref()
                 .and_then(|manager| manager.localize_in_domain(domain, id, args))
         })
     }
+
+    #[doc(hidden)]
+    pub(crate) fn localize_attribute_in_domain<'a>(
+        &self,
+        i18n_domain_bundles: &I18nDomainBundles,
+        domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&FluentArgumentMap<'a>>,
+    ) -> Option<String> {
+        let locale_resources =
+            i18n_domain_bundles.fallback_locale_resources(&self.active_language, domain.as_str());
+        es_fluent_manager_core::localize_attribute_with_fallback_resources(
+            locale_resources.as_slice(),
+            id,
+            attribute,
+            args,
+            &self.bundle_options,
+        )
+        .and_then(|formatted| es_fluent_manager_core::accept_formatted_value(id, formatted))
+        .or_else(|| {
+            self.fallback_manager.as_ref().and_then(|manager| {
+                manager.localize_attribute_in_domain(domain, id, attribute, args)
+            })
+        })
+    }
+
+    pub(crate) fn has_message_in_domain(
+        &self,
+        i18n_domain_bundles: &I18nDomainBundles,
+        domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+    ) -> bool {
+        let locale_resources =
+            i18n_domain_bundles.fallback_locale_resources(&self.active_language, domain.as_str());
+        es_fluent_manager_core::has_message_with_fallback_resources(locale_resources.as_slice(), id)
+            || self
+                .fallback_manager
+                .as_ref()
+                .is_some_and(|manager| manager.has_message_in_domain(domain, id))
+    }
+
+    pub(crate) fn has_domain(
+        &self,
+        i18n_domain_bundles: &I18nDomainBundles,
+        domain: StaticFluentDomain,
+    ) -> bool {
+        !i18n_domain_bundles
+            .fallback_locale_resources(&self.active_language, domain.as_str())
+            .is_empty()
+            || self
+                .fallback_manager
+                .as_ref()
+                .is_some_and(|manager| manager.has_domain(domain))
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,55 @@
 use bevy::prelude::*;
-use es_fluent::FluentMessage;
+use es_fluent::registry::StaticFluentArgumentName;
+use es_fluent::{FluentArgs, FluentMessage, FluentMessageLookup, FluentValue};
+use fluent_bundle::types::FluentNumber;
+use std::collections::HashMap;
+
+/// An owned Fluent argument value stored on a [`FluentText`] component.
+///
+/// Only strings and numbers are supported so the component stays `Send` and
+/// `Sync`; build values with `From`, for example `FluentTextArg::from(42)`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FluentTextArg {
+    /// A string argument.
+    String(String),
+    /// A numeric argument, formatted by Fluent for the active locale.
+    Number(FluentNumber),
+}
+
+impl FluentTextArg {
+    fn to_fluent_value(&self) -> FluentValue<'static> {
+        match self {
+            Self::String(value) => FluentValue::from(value.clone()),
+            Self::Number(value) => FluentValue::Number(value.clone()),
+        }
+    }
+}
+
+impl From<String> for FluentTextArg {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for FluentTextArg {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+macro_rules! fluent_text_arg_from_number {
+    ($($num:ty)+) => {
+        $(
+            impl From<$num> for FluentTextArg {
+                fn from(value: $num) -> Self {
+                    Self::Number(FluentNumber::from(value))
+                }
+            }
+        )+
+    };
+}
+
+fluent_text_arg_from_number!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize f32 f64);
 
 /// A Bevy component that holds localized text content.
 ///
@@ -11,6 +61,12 @@ use es_fluent::FluentMessage;
 /// manual registration, `FluentText` updates Bevy `Text` components when the
 /// application locale changes.
 ///
+/// Per-entity arguments added with [`FluentText::with_arg`] or
+/// [`FluentText::set_arg`] are merged into every lookup the message makes and
+/// take precedence over arguments the message passes itself. Updating them
+/// through `Query<&mut FluentText<T>>` marks the component as changed, so the
+/// entity's `Text` is re-rendered on the next text update.
+///
 /// # Examples
 ///
 /// ```ignore
@@ -34,6 +90,7 @@ use es_fluent::FluentMessage;
 pub struct FluentText<T: FluentMessage + Clone> {
     /// The localized text content.
     pub value: T,
+    args: HashMap<StaticFluentArgumentName, FluentTextArg>,
 }
 
 impl<T: FluentMessage + Clone> FluentText<T> {
@@ -60,7 +117,66 @@ impl<T: FluentMessage + Clone> FluentText<T> {
     /// let text = FluentText::new(Message { content: "Hello".to_string() });
     /// ```
     pub fn new(value: T) -> Self {
-        Self { value }
+        Self {
+            value,
+            args: HashMap::new(),
+        }
+    }
+
+    /// Adds a per-entity Fluent argument and returns the component for
+    /// chaining.
+    ///
+    /// ```no_run
+    /// use es_fluent::EsFluent;
+    /// use es_fluent_manager_bevy::FluentText;
+    ///
+    /// // score = Score: { $points }
+    /// #[derive(Clone, EsFluent)]
+    /// struct Score;
+    ///
+    /// let text = FluentText::new(Score).with_arg("points", 0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not a valid Fluent identifier.
+    pub fn with_arg(mut self, name: &'static str, value: impl Into<FluentTextArg>) -> Self {
+        self.set_arg(name, value);
+        self
+    }
+
+    /// Sets or replaces a per-entity Fluent argument.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not a valid Fluent identifier.
+    pub fn set_arg(&mut self, name: &'static str, value: impl Into<FluentTextArg>) {
+        let name = StaticFluentArgumentName::try_new(name)
+            .unwrap_or_else(|err| panic!("invalid Fluent argument name `{name}`: {err}"));
+        self.args.insert(name, value.into());
+    }
+
+    /// Returns the per-entity argument stored under `name`, if any.
+    pub fn arg(&self, name: &'static str) -> Option<&FluentTextArg> {
+        let name = StaticFluentArgumentName::try_new(name).ok()?;
+        self.args.get(&name)
+    }
+}
+
+impl<T: FluentMessage + Clone> FluentMessage for FluentText<T> {
+    fn to_fluent_string_with(&self, localize: &mut FluentMessageLookup<'_>) -> String {
+        if self.args.is_empty() {
+            return self.value.to_fluent_string_with(localize);
+        }
+
+        self.value
+            .to_fluent_string_with(&mut |domain, id, message_args| {
+                let mut args = message_args.cloned().unwrap_or_else(FluentArgs::new);
+                for (name, value) in &self.args {
+                    args.insert(*name, value.to_fluent_value());
+                }
+                localize(domain, id, Some(&args))
+            })
     }
 }
 
@@ -118,4 +234,59 @@ mod tests {
             "hello"
         );
     }
+
+    #[derive(Clone)]
+    struct ArgsMessage;
+
+    impl FluentMessage for ArgsMessage {
+        fn to_fluent_string_with(
+            &self,
+            localize: &mut es_fluent::FluentMessageLookup<'_>,
+        ) -> String {
+            let args = FluentArgs::new().arg("points", 1).arg("player", "Ada");
+            localize(
+                es_fluent::registry::__macro::static_domain("app"),
+                es_fluent::registry::__macro::static_entry_id("score"),
+                Some(&args),
+            )
+        }
+    }
+
+    #[test]
+    fn fluent_text_merges_entity_args_over_message_args() {
+        let component = FluentText::new(ArgsMessage)
+            .with_arg("points", 42)
+            .with_arg("bonus", "double");
+        let mut localize = |_domain: es_fluent::registry::StaticFluentDomain,
+                            _id: es_fluent::registry::StaticFluentEntryId,
+                            args: Option<&FluentArgs<'_>>| {
+            let mut rendered = args
+                .expect("merged args")
+                .as_raw()
+                .iter()
+                .map(|(name, value)| format!("{}={value:?}", name.as_str()))
+                .collect::<Vec<_>>();
+            rendered.sort();
+            rendered.join(",")
+        };
+
+        assert_eq!(
+            component.to_fluent_string_with(&mut localize),
+            format!(
+                "bonus={:?},player={:?},points={:?}",
+                FluentValue::from("double"),
+                FluentValue::from("Ada"),
+                FluentValue::from(42),
+            )
+        );
+    }
+
+    #[test]
+    fn fluent_text_set_arg_replaces_existing_value() {
+        let mut component = FluentText::new(FakeMessage("hello")).with_arg("points", 1);
+        component.set_arg("points", 2);
+
+        assert_eq!(component.arg("points"), Some(&FluentTextArg::from(2)));
+        assert_eq!(component.arg("missing"), None);
+    }
 }
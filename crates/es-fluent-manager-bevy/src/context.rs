@@ -42,6 +42,24 @@ impl<'w> BevyI18n<'w> {
     {
         FluentLocalizerExt::localize_message(self, message)
     }
+
+    /// Renders a Fluent message attribute, such as `.aria-label`, through this
+    /// Bevy context.
+    ///
+    /// Returns `None` when the message or attribute is missing.
+    pub fn localize_attribute<'a>(
+        &self,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&FluentArgs<'a>>,
+    ) -> Option<String> {
+        self.i18n_resource.localize_attribute(
+            id,
+            attribute,
+            args.map(FluentArgs::as_raw),
+            &self.i18n_bundle,
+        )
+    }
 }
 
 impl<'w> FluentLocalizer for BevyI18n<'w> {
@@ -67,4 +85,30 @@ impl<'w> FluentLocalizer for BevyI18n<'w> {
             args.map(FluentArgs::as_raw),
         )
     }
+
+    fn localize_attribute_in_domain<'a>(
+        &self,
+        domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&'a FluentArgs<'a>>,
+    ) -> Option<String> {
+        self.i18n_resource.localize_attribute_in_domain(
+            &self.i18n_domain_bundles,
+            domain,
+            id,
+            attribute,
+            args.map(FluentArgs::as_raw),
+        )
+    }
+
+    fn has_message_in_domain(&self, domain: StaticFluentDomain, id: StaticFluentEntryId) -> bool {
+        self.i18n_resource
+            .has_message_in_domain(&self.i18n_domain_bundles, domain, id)
+    }
+
+    fn has_domain(&self, domain: StaticFluentDomain) -> bool {
+        self.i18n_resource
+            .has_domain(&self.i18n_domain_bundles, domain)
+    }
 }
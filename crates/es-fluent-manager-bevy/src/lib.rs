@@ -32,11 +32,12 @@ mod registration;
 mod schedule;
 mod systems;
 
-pub use components::FluentText;
+pub use components::{FluentText, FluentTextArg};
 pub use context::BevyI18n;
+pub use es_fluent_manager_core::{BundleOptions, FluentFunction};
 pub use locale::{
     ActiveLanguageId, FromLocale, LocaleChangeEvent, LocaleChangedEvent, RefreshForLocale,
-    RequestedLanguageId,
+    RequestedLanguageId, UnloadLanguageEvent,
 };
 pub use plugin::{I18nPlugin, I18nPluginConfig, I18nPluginStartupError};
 pub use registration::FluentTextRegistration;
@@ -78,6 +78,14 @@ where
 #[derive(Clone, Message)]
 pub struct LocaleChangeEvent(pub LanguageIdentifier);
 
+/// A Bevy `Message` sent to unload a locale's FTL resources and bundles.
+///
+/// The locale is no longer available afterwards. Requests for the active or
+/// requested locale, or for any locale in their fallback chain, are ignored
+/// with a warning, so switch away before unloading.
+#[derive(Clone, Message)]
+pub struct UnloadLanguageEvent(pub LanguageIdentifier);
+
 /// A Bevy `Message` sent after the active locale has been successfully published.
 #[derive(Clone, Message)]
 pub struct LocaleChangedEvent(pub LanguageIdentifier);
@@ -3,7 +3,8 @@ mod setup;
 
 use crate::{BundleBuildFailures, FtlAsset, FtlAssetLoader, I18nBundle, I18nDomainBundles};
 use bevy::prelude::*;
-use es_fluent_manager_core::ModuleDiscoveryError;
+use es_fluent_manager_core::{BundleOptions, FluentManager, ModuleDiscoveryError};
+use std::sync::Arc;
 use unic_langid::LanguageIdentifier;
 
 /// Configuration for [`I18nPlugin`].
@@ -20,6 +21,13 @@ pub struct I18nPluginConfig {
     pub initial_language: LanguageIdentifier,
     /// Locale asset path relative to Bevy's asset root for custom asset-backed resources.
     pub asset_path: String,
+    /// Isolation and custom functions applied when formatting messages.
+    ///
+    /// Used for Bevy asset messages and for the runtime modules the plugin
+    /// discovers itself; a manager passed to [`I18nPlugin::with_manager`]
+    /// keeps its own options.
+    #[builder(default)]
+    pub bundle_options: BundleOptions,
 }
 
 impl Default for I18nPluginConfig {
@@ -27,6 +35,7 @@ impl Default for I18nPluginConfig {
         Self {
             initial_language: unic_langid::langid!("en-US"),
             asset_path: "locales".to_string(),
+            bundle_options: BundleOptions::default(),
         }
     }
 }
@@ -46,6 +55,12 @@ impl I18nPluginConfig {
         self.asset_path = asset_path.into();
         self
     }
+
+    /// Sets the isolation and custom functions used when formatting messages.
+    pub fn with_bundle_options(mut self, bundle_options: BundleOptions) -> Self {
+        self.bundle_options = bundle_options;
+        self
+    }
 }
 
 /// Startup failure captured when the plugin cannot safely initialize i18n.
@@ -84,19 +99,39 @@ impl std::error::Error for I18nPluginStartupError {}
 #[derive(Default)]
 pub struct I18nPlugin {
     config: I18nPluginConfig,
+    manager: Option<Arc<FluentManager>>,
 }
 
 impl I18nPlugin {
     /// Create a plugin from a full config.
     pub fn new(config: I18nPluginConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            manager: None,
+        }
     }
 
     /// Create a plugin with a specific initial language.
     ///
     pub fn with_language(initial_language: LanguageIdentifier) -> Self {
+        Self::new(I18nPluginConfig::new(initial_language))
+    }
+
+    /// Create a plugin that shares an already constructed [`FluentManager`].
+    ///
+    /// The manager replaces the runtime modules the plugin would otherwise
+    /// discover from inventory, so translations loaded once can serve both the
+    /// Bevy world and other subsystems. Bevy asset-backed modules are still
+    /// discovered and loaded into the plugin's Fluent bundles, which are
+    /// consulted before the shared manager. Locale changes select the new
+    /// language on the shared manager as well.
+    pub fn with_manager(
+        manager: impl Into<Arc<FluentManager>>,
+        initial_language: LanguageIdentifier,
+    ) -> Self {
         Self {
-            config: I18nPluginConfig::new(initial_language),
+            manager: Some(manager.into()),
+            ..Self::with_language(initial_language)
         }
     }
 
@@ -105,9 +140,7 @@ impl I18nPlugin {
         initial_language: LanguageIdentifier,
         asset_path: impl Into<String>,
     ) -> Self {
-        Self {
-            config: I18nPluginConfig::new(initial_language).with_asset_path(asset_path),
-        }
+        Self::new(I18nPluginConfig::new(initial_language).with_asset_path(asset_path))
     }
 
     /// Create a plugin from a full config.
@@ -135,11 +168,22 @@ impl Plugin for I18nPlugin {
             &self.config.initial_language,
             &discovery.asset_languages,
         );
-        let i18n_resource = match setup::initialize_i18n_resource(
-            &self.config.initial_language,
-            &resolved_language,
-        ) {
-            Ok(i18n_resource) => i18n_resource,
+        let i18n_resource = match &self.manager {
+            Some(manager) => setup::initialize_i18n_resource_with_fallback_manager(
+                &self.config.initial_language,
+                &resolved_language,
+                Some(Arc::clone(manager)),
+            ),
+            None => setup::initialize_i18n_resource(
+                &self.config.initial_language,
+                &resolved_language,
+                &self.config.bundle_options,
+            ),
+        };
+        let i18n_resource = match i18n_resource {
+            Ok(i18n_resource) => {
+                i18n_resource.with_bundle_options(self.config.bundle_options.clone())
+            },
             Err(error) => {
                 insert_startup_error(app, format_initialization_startup_error(&error));
                 return;
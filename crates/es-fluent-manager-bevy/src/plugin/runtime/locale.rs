@@ -1,6 +1,7 @@
 use crate::{
-    ActiveLanguageId, BundleBuildFailures, I18nAssets, I18nBundle, I18nResource, LanguageSelection,
-    LocaleChangeEvent, LocaleChangedEvent, PendingLanguageChange, RequestedLanguageId,
+    ActiveLanguageId, BundleBuildFailures, I18nAssets, I18nBundle, I18nDomainBundles, I18nResource,
+    LanguageSelection, LocaleChangeEvent, LocaleChangedEvent, PendingLanguageChange,
+    RequestedLanguageId, UnloadLanguageEvent,
 };
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
@@ -195,6 +196,60 @@ pub(crate) fn handle_locale_changes(
     }
 }
 
+#[derive(SystemParam)]
+pub(crate) struct LanguageUnloadParams<'w> {
+    i18n_assets: ResMut<'w, I18nAssets>,
+    i18n_bundle: ResMut<'w, I18nBundle>,
+    i18n_domain_bundles: ResMut<'w, I18nDomainBundles>,
+    bundle_build_failures: ResMut<'w, BundleBuildFailures>,
+    i18n_resource: Res<'w, I18nResource>,
+    requested_language_id: Res<'w, RequestedLanguageId>,
+    pending_language_change: Res<'w, PendingLanguageChange>,
+}
+
+impl LanguageUnloadParams<'_> {
+    /// Whether `lang` backs the active, requested, or pending locale, directly
+    /// or as a fallback.
+    fn is_in_use(&self, lang: &LanguageIdentifier) -> bool {
+        let mut in_use = vec![
+            self.i18n_resource.active_language(),
+            self.i18n_resource.resolved_language(),
+            &self.requested_language_id.0,
+        ];
+        if let Some(pending) = &self.pending_language_change.0 {
+            in_use.extend([&pending.requested, &pending.resolved]);
+        }
+
+        in_use
+            .into_iter()
+            .any(|language| es_fluent_manager_core::locale_candidates(language).contains(lang))
+    }
+}
+
+#[doc(hidden)]
+pub(crate) fn handle_language_unloads(
+    mut unload_language_events: MessageReader<UnloadLanguageEvent>,
+    mut params: LanguageUnloadParams,
+) {
+    for event in unload_language_events.read() {
+        let lang = &event.0;
+        if params.is_in_use(lang) {
+            warn!(
+                "Ignoring request to unload locale '{}' because the active or requested locale depends on it",
+                lang
+            );
+            continue;
+        }
+
+        if params.i18n_assets.unload_language(lang) {
+            params.i18n_bundle.remove(lang);
+            params.i18n_domain_bundles.remove(lang);
+            params.bundle_build_failures.0.remove(lang);
+            info!("Unloaded locale: {}", lang);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -598,4 +653,77 @@ mod tests {
         assert!(app.world().resource::<ObservedLocaleChanges>().0.is_empty());
         assert!(app.world().resource::<PendingLanguageChange>().0.is_none());
     }
+
+    fn app_with_unload_system(i18n_bundle: I18nBundle, i18n_assets: I18nAssets) -> App {
+        let mut app = app_with_locale_system(
+            i18n_bundle,
+            i18n_assets,
+            BundleBuildFailures::default(),
+            PendingLanguageChange::default(),
+        );
+        app.add_message::<UnloadLanguageEvent>()
+            .insert_resource(I18nDomainBundles::default())
+            .add_systems(Update, handle_language_unloads.after(handle_locale_changes));
+        app
+    }
+
+    fn ready_languages(app: &App) -> HashSet<LanguageIdentifier> {
+        app.world()
+            .resource::<I18nBundle>()
+            .languages()
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn handle_language_unloads_frees_inactive_locales_only() {
+        let (en, fr) = (langid!("en"), langid!("fr"));
+        let mut i18n_bundle = I18nBundle::default();
+        let mut i18n_assets = I18nAssets::new();
+        for lang in [&en, &fr] {
+            insert_ready_bundle(&mut i18n_bundle, lang.clone());
+            i18n_assets.add_asset(lang.clone(), "app".to_string(), Handle::default());
+        }
+        let mut app = app_with_unload_system(i18n_bundle, i18n_assets);
+
+        app.world_mut()
+            .write_message(UnloadLanguageEvent(fr.clone()));
+        app.world_mut()
+            .write_message(UnloadLanguageEvent(en.clone()));
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<I18nAssets>().available_languages(),
+            vec![en.clone()]
+        );
+        assert_eq!(ready_languages(&app), HashSet::from([en.clone()]));
+
+        app.world_mut().write_message(LocaleChangeEvent(fr));
+        app.update();
+
+        assert_eq!(app.world().resource::<ActiveLanguageId>().0, en);
+    }
+
+    #[test]
+    fn handle_language_unloads_runs_after_same_tick_locale_changes() {
+        let (en, fr) = (langid!("en"), langid!("fr"));
+        let mut i18n_bundle = I18nBundle::default();
+        let mut i18n_assets = I18nAssets::new();
+        for lang in [&en, &fr] {
+            insert_ready_bundle(&mut i18n_bundle, lang.clone());
+            i18n_assets.add_asset(lang.clone(), "app".to_string(), Handle::default());
+        }
+        let mut app = app_with_unload_system(i18n_bundle, i18n_assets);
+
+        app.world_mut().write_message(LocaleChangeEvent(fr.clone()));
+        app.world_mut().write_message(UnloadLanguageEvent(en));
+        app.update();
+
+        assert_eq!(app.world().resource::<ActiveLanguageId>().0, fr);
+        assert_eq!(
+            app.world().resource::<I18nAssets>().available_languages(),
+            vec![fr.clone()]
+        );
+        assert_eq!(ready_languages(&app), HashSet::from([fr]));
+    }
 }
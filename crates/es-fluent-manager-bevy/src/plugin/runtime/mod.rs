@@ -5,5 +5,5 @@ mod sync;
 
 pub(crate) use assets::handle_asset_loading;
 pub(crate) use bundles::build_fluent_bundles;
-pub(crate) use locale::handle_locale_changes;
+pub(crate) use locale::{handle_language_unloads, handle_locale_changes};
 pub(crate) use sync::sync_locale_state;
@@ -1,17 +1,18 @@
 use super::runtime::{
-    build_fluent_bundles, handle_asset_loading, handle_locale_changes, sync_locale_state,
+    build_fluent_bundles, handle_asset_loading, handle_language_unloads, handle_locale_changes,
+    sync_locale_state,
 };
 #[cfg(feature = "file_watcher")]
 use crate::BevyI18nEmbeddedAsset;
 use crate::{
     ActiveLanguageId, BevyFluentTextRegistration, BevyI18nAssetRegistration, FtlAsset, I18nAssets,
     I18nResource, I18nSet, LocaleChangeEvent, LocaleChangedEvent, PendingLanguageChange,
-    RequestedLanguageId,
+    RequestedLanguageId, UnloadLanguageEvent,
 };
 use bevy::prelude::*;
 use es_fluent_manager_core::{
-    FluentManager, I18nModuleRegistration, LocalizationError, ModuleData, ModuleDiscoveryError,
-    ModuleRegistrationKind, ResourceKey,
+    BundleOptions, FluentManager, I18nModuleRegistration, LocalizationError, ModuleData,
+    ModuleDiscoveryError, ModuleRegistrationKind, ResourceKey,
 };
 use std::{collections::HashSet, sync::Arc};
 #[cfg(feature = "file_watcher")]
@@ -264,15 +265,16 @@ pub(super) fn resolve_initial_language(
 pub(super) fn initialize_i18n_resource(
     requested_language: &LanguageIdentifier,
     resolved_language: &LanguageIdentifier,
+    bundle_options: &BundleOptions,
 ) -> Result<I18nResource, String> {
     let discovered =
         FluentManager::try_discover_runtime_modules().map_err(format_module_discovery_errors)?;
     let fallback_manager = if discovered.is_empty() {
         None
     } else {
-        Some(Arc::new(FluentManager::from_discovered_modules(
-            &discovered,
-        )))
+        let mut manager = FluentManager::from_discovered_modules(&discovered);
+        manager.set_bundle_options(bundle_options.clone());
+        Some(Arc::new(manager))
     };
 
     initialize_i18n_resource_with_fallback_manager(
@@ -282,7 +284,7 @@ pub(super) fn initialize_i18n_resource(
     )
 }
 
-fn initialize_i18n_resource_with_fallback_manager(
+pub(super) fn initialize_i18n_resource_with_fallback_manager(
     requested_language: &LanguageIdentifier,
     resolved_language: &LanguageIdentifier,
     fallback_manager: Option<Arc<FluentManager>>,
@@ -457,7 +459,8 @@ pub(super) fn configure_app(
         .insert_resource(ActiveLanguageId(active_language))
         .insert_resource(PendingLanguageChange::default())
         .add_message::<LocaleChangeEvent>()
-        .add_message::<LocaleChangedEvent>();
+        .add_message::<LocaleChangedEvent>()
+        .add_message::<UnloadLanguageEvent>();
 
     app.configure_sets(
         Update,
@@ -482,6 +485,9 @@ pub(super) fn configure_app(
             handle_asset_loading.in_set(I18nSet::AssetLoading),
             build_fluent_bundles.in_set(I18nSet::BundleRebuild),
             handle_locale_changes.in_set(I18nSet::LocaleChange),
+            handle_language_unloads
+                .in_set(I18nSet::LocaleChange)
+                .after(handle_locale_changes),
             sync_locale_state.in_set(I18nSet::LocaleSync),
         ),
     );
@@ -493,6 +499,9 @@ pub(super) fn configure_app(
             handle_asset_loading.in_set(I18nSet::AssetLoading),
             build_fluent_bundles.in_set(I18nSet::BundleRebuild),
             handle_locale_changes.in_set(I18nSet::LocaleChange),
+            handle_language_unloads
+                .in_set(I18nSet::LocaleChange)
+                .after(handle_locale_changes),
             sync_locale_state.in_set(I18nSet::LocaleSync),
         ),
     );
@@ -835,8 +844,9 @@ mod tests {
     #[test]
     fn initialize_i18n_resource_keeps_fallback_manager_after_initial_rejection() {
         let unsupported = langid!("zz");
-        let i18n_resource = initialize_i18n_resource(&unsupported, &unsupported)
-            .expect("unsupported runtime fallback language should not block Bevy startup");
+        let i18n_resource =
+            initialize_i18n_resource(&unsupported, &unsupported, &BundleOptions::default())
+                .expect("unsupported runtime fallback language should not block Bevy startup");
 
         assert_eq!(i18n_resource.active_language(), &unsupported);
         assert_eq!(i18n_resource.resolved_language(), &unsupported);
@@ -1,6 +1,7 @@
 use super::*;
 use bevy::asset::AssetPlugin;
 use es_fluent_manager_core::ModuleDiscoveryError;
+use std::collections::HashMap;
 use unic_langid::langid;
 
 #[test]
@@ -113,3 +114,38 @@ fn startup_error_helpers_format_and_store_diagnostics() {
     log_registered_fluent_text_count(0);
     log_registered_fluent_text_count(2);
 }
+
+#[test]
+fn i18n_plugin_build_uses_provided_manager_for_runtime_lookups() {
+    let manager = FluentManager::from_resources(HashMap::from([(
+        langid!("en"),
+        vec![(
+            "shared-manager".to_string(),
+            "shared-greeting = Hello from the shared manager\n".to_string(),
+        )],
+    )]));
+    let manager = Arc::new(manager);
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.add_plugins(AssetPlugin::default());
+
+    let plugin = I18nPlugin::with_manager(Arc::clone(&manager), langid!("en"));
+    assert_eq!(plugin.config.initial_language, langid!("en"));
+    assert_eq!(plugin.config.asset_path, "locales");
+    plugin.build(&mut app);
+
+    let world = app.world();
+    let localized = world.resource::<crate::I18nResource>().localize(
+        es_fluent_manager_core::__macro::static_entry_id("shared-greeting"),
+        None,
+        world.resource::<I18nBundle>(),
+    );
+    assert_eq!(localized.as_deref(), Some("Hello from the shared manager"));
+    assert_eq!(
+        manager.localize(
+            es_fluent_manager_core::__macro::static_entry_id("shared-greeting"),
+            None
+        ),
+        localized
+    );
+}
@@ -14,7 +14,8 @@ pub enum I18nSet {
     AssetLoading,
     /// `Update`: rebuilds ready Fluent bundle caches from loaded resources.
     BundleRebuild,
-    /// `Update`: handles [`crate::LocaleChangeEvent`] requests.
+    /// `Update`: handles [`crate::LocaleChangeEvent`] and
+    /// [`crate::UnloadLanguageEvent`] requests.
     LocaleChange,
     /// `Update`: publishes pending locale changes once bundles become ready.
     LocaleSync,
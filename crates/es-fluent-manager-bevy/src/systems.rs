@@ -19,7 +19,7 @@ pub fn update_fluent_text_system<T: FluentMessage + Clone + Send + Sync + 'stati
         return;
     }
     for (entity, fluent_text, children) in fluent_text_query.iter() {
-        update_text_for_entity(&mut text_query, entity, children, &fluent_text.value, &i18n);
+        update_text_for_entity(&mut text_query, entity, children, fluent_text, &i18n);
     }
 }
 
@@ -41,7 +41,7 @@ pub fn update_all_fluent_text_on_locale_change<T: FluentMessage + Clone + Send +
     if should_update && i18n_assets.is_language_loaded(i18n.resolved_language()) {
         // Perform a full update of all FluentText components
         for (entity, fluent_text, children) in fluent_text_query.iter() {
-            update_text_for_entity(&mut text_query, entity, children, &fluent_text.value, &i18n);
+            update_text_for_entity(&mut text_query, entity, children, fluent_text, &i18n);
         }
         // Wake up the event loop to ensure UI updates are visible immediately,
         // especially when using WinitSettings::desktop_app() which only
@@ -185,6 +185,65 @@ mod tests {
         assert_eq!(child_text, "new text");
     }
 
+    #[test]
+    fn update_fluent_text_system_renders_per_entity_args() {
+        let lang = langid!("en-US");
+        let score = resource("score = Score: { $points }");
+        let mut assets = I18nAssets::new();
+        assets.add_asset(
+            lang.clone(),
+            "app".to_string(),
+            Handle::<FtlAsset>::default(),
+        );
+        assets.loaded_resources.insert(
+            (lang.clone(), ResourceKey::from_static_path("app")),
+            score.clone(),
+        );
+        let mut domain_bundles = I18nDomainBundles::default();
+        domain_bundles.set_bundles(
+            lang.clone(),
+            HashMap::from([(domain("app"), bundle_for(&lang, score.clone()))]),
+        );
+        domain_bundles
+            .set_locale_resources(lang.clone(), HashMap::from([(domain("app"), vec![score])]));
+
+        let mut app = App::new();
+        app.insert_resource(assets);
+        app.insert_resource(I18nResource::new(lang.clone()));
+        app.insert_resource(I18nBundle::default());
+        app.insert_resource(RequestedLanguageId(lang.clone()));
+        app.insert_resource(ActiveLanguageId(lang));
+        app.insert_resource(domain_bundles);
+        app.add_systems(Update, update_fluent_text_system::<DomainLookupMessage>);
+
+        let message = DomainLookupMessage {
+            domain: "app",
+            id: "score",
+        };
+        let entity = app
+            .world_mut()
+            .spawn((
+                FluentText::new(message).with_arg("points", 10),
+                Text::new("old"),
+            ))
+            .id();
+        app.update();
+        assert_eq!(
+            &app.world().get::<Text>(entity).expect("text").0,
+            "Score: \u{2068}10\u{2069}"
+        );
+
+        app.world_mut()
+            .get_mut::<FluentText<DomainLookupMessage>>(entity)
+            .expect("fluent text")
+            .set_arg("points", 25);
+        app.update();
+        assert_eq!(
+            &app.world().get::<Text>(entity).expect("text").0,
+            "Score: \u{2068}25\u{2069}"
+        );
+    }
+
     #[test]
     fn update_all_fluent_text_on_locale_change_updates_all_entities() {
         let lang = langid!("en-US");
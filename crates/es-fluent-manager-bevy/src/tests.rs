@@ -109,6 +109,38 @@ fn ftl_asset_loader_reports_ftl_extension() {
     assert_eq!(loader.extensions(), &["ftl"]);
 }
 
+#[test]
+fn check_ftl_syntax_reports_path_and_parse_errors() {
+    assert!(crate::assets::check_ftl_syntax("i18n/en/app.ftl", "hello = Hello\n").is_ok());
+
+    let error = crate::assets::check_ftl_syntax("i18n/en/app.ftl", "hello = Hello\nbroken = {\n")
+        .expect_err("malformed FTL");
+
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    let message = error.to_string();
+    assert!(message.contains("i18n/en/app.ftl"), "{message}");
+    assert!(message.contains("line 2"), "{message}");
+}
+
+#[test]
+fn check_ftl_syntax_reports_lines_after_multibyte_text() {
+    let error = crate::assets::check_ftl_syntax(
+        "i18n/ja/app.ftl",
+        "greeting = こんにちは、世界\nbroken = { ¿\n",
+    )
+    .expect_err("malformed FTL");
+
+    let message = error.to_string();
+    assert!(message.contains("i18n/ja/app.ftl"), "{message}");
+    assert!(message.contains("line 2"), "{message}");
+
+    // An offset inside a multibyte character must not panic.
+    let content = "a = é\nb = ü\n";
+    assert_eq!(crate::assets::error_line(content, 5), 1);
+    assert_eq!(crate::assets::error_line(content, 12), 2);
+    assert_eq!(crate::assets::error_line(content, usize::MAX), 3);
+}
+
 #[test]
 fn i18n_assets_track_loaded_resources_and_languages() {
     let mut assets = I18nAssets::new();
@@ -128,6 +160,27 @@ fn i18n_assets_track_loaded_resources_and_languages() {
     assert_eq!(assets.get_language_resources(&lang).len(), 1);
 }
 
+#[test]
+fn i18n_assets_unload_language_drops_only_that_language() {
+    let mut assets = I18nAssets::new();
+    let (en, fr) = (langid!("en"), langid!("fr"));
+    for lang in [&en, &fr] {
+        assets.add_asset(lang.clone(), "app".to_string(), Handle::default());
+        assets.loaded_resources.insert(
+            (lang.clone(), ResourceKey::from_static_path("app")),
+            Arc::new(FluentResource::try_new("hello = hi".to_string()).expect("ftl")),
+        );
+    }
+
+    assert!(assets.unload_language(&fr));
+    assert!(!assets.unload_language(&fr));
+
+    assert_eq!(assets.available_languages(), vec![en.clone()]);
+    assert!(assets.is_language_loaded(&en));
+    assert!(assets.get_language_resources(&fr).is_empty());
+    assert!(assets.assets.keys().all(|(lang, _)| lang == &en));
+}
+
 #[test]
 fn i18n_assets_namespace_contract_matrix() {
     let mut assets = I18nAssets::new();
@@ -291,6 +344,55 @@ fn i18n_resource_uses_resolved_bundle_when_requested_locale_is_unavailable() {
     );
 }
 
+#[test]
+fn i18n_resource_localizes_message_attributes_through_the_fallback_chain() {
+    let requested = langid!("en-US");
+    let resolved = langid!("en");
+    let requested_resource = Arc::new(
+        FluentResource::try_new(
+            "login-button = Log in\n    .aria-label = Log in as { $name }".to_string(),
+        )
+        .expect("ftl"),
+    );
+    let resolved_resource = Arc::new(
+        FluentResource::try_new(
+            "login-button = Log in\n    .title = Sign in\nplain = Plain".to_string(),
+        )
+        .expect("ftl"),
+    );
+
+    let mut i18n_bundle = I18nBundle::default();
+    i18n_bundle.set_locale_resources(requested.clone(), vec![requested_resource]);
+    i18n_bundle.set_locale_resources(resolved.clone(), vec![resolved_resource]);
+    let i18n_resource = I18nResource::new_with_resolved_language(requested, resolved);
+
+    let mut args = FluentArgumentMap::default();
+    args.insert(static_arg("name"), FluentValue::from("Mark"));
+    let label = i18n_resource
+        .localize_attribute(
+            static_entry("login-button"),
+            "aria-label",
+            Some(&args),
+            &i18n_bundle,
+        )
+        .expect("localized attribute");
+    assert!(label.contains("Log in as"));
+    assert!(label.contains("Mark"));
+
+    assert_eq!(
+        i18n_resource.localize_attribute(static_entry("login-button"), "title", None, &i18n_bundle),
+        Some("Sign in".to_string())
+    );
+    assert_eq!(
+        i18n_resource.localize_attribute(static_entry("plain"), "title", None, &i18n_bundle),
+        None
+    );
+    assert_eq!(
+        i18n_resource.localize_attribute(static_entry("missing"), "title", None, &i18n_bundle),
+        None
+    );
+}
+
 #[test]
 fn i18n_resource_prefers_partial_requested_locale_resources_over_resolved_parent_bundle() {
     let requested = langid!("en-US");
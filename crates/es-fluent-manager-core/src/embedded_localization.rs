@@ -4,9 +4,10 @@ use crate::asset_localization::{
     I18nModuleDescriptor, ModuleData, ModuleResourceSpec, ResourceLoadStatus, ResourcePlan,
 };
 use crate::localization::{
-    FluentArgumentMap, I18nModule, LocalizationError, Localizer, SyncFluentBundle,
+    BundleOptions, FluentArgumentMap, I18nModule, LocalizationError, Localizer, SyncFluentBundle,
 };
 use es_fluent_shared::registry::StaticFluentEntryId;
+use es_fluent_shared::resource::AssetsLayout;
 use fluent_bundle::{FluentError, FluentResource};
 use parking_lot::{Mutex, RwLock};
 use rust_embed::RustEmbed;
@@ -27,6 +28,14 @@ pub trait EmbeddedAssets: RustEmbed + Send + Sync + 'static {
         &[]
     }
 
+    /// Returns how the embedded files are arranged below the assets root.
+    ///
+    /// Macro-generated modules override this when `i18n.toml` selects the
+    /// `domain_first` layout.
+    fn layout() -> AssetsLayout {
+        AssetsLayout::LanguageFirst
+    }
+
     /// Returns the exact resource plan for a locale when the embedded asset tree
     /// can prove that only part of the module's global namespace set exists for
     /// that locale.
@@ -41,9 +50,11 @@ pub trait EmbeddedAssets: RustEmbed + Send + Sync + 'static {
         let mut found_namespaces = BTreeSet::new();
 
         for file_path in Self::iter() {
-            let file_path_str = file_path.as_ref();
+            let Some(file_path) = Self::layout().language_first_path(file_path.as_ref()) else {
+                continue;
+            };
             let Some((file_lang, namespace)) =
-                embedded_resource_from_asset_path(file_path_str, domain.as_str(), namespaces)
+                embedded_resource_from_asset_path(&file_path, domain.as_str(), namespaces)
             else {
                 continue;
             };
@@ -89,6 +100,7 @@ pub trait EmbeddedAssets: RustEmbed + Send + Sync + 'static {
 pub struct EmbeddedLocalizer<T: EmbeddedAssets> {
     data: &'static ModuleData,
     state: RwLock<EmbeddedLocalizerState>,
+    bundle_options: RwLock<BundleOptions>,
     selection_lock: Mutex<()>,
     _phantom: std::marker::PhantomData<T>,
 }
@@ -165,6 +177,7 @@ impl<T: EmbeddedAssets> EmbeddedLocalizer<T> {
         Self {
             data,
             state: RwLock::new(EmbeddedLocalizerState::default()),
+            bundle_options: RwLock::default(),
             selection_lock: Mutex::new(()),
             _phantom: std::marker::PhantomData,
         }
@@ -178,8 +191,10 @@ impl<T: EmbeddedAssets> EmbeddedLocalizer<T> {
             T::resource_plan_for_language(lang).unwrap_or_else(|| self.data.resource_plan());
         let (resources, report) =
             crate::asset_localization::load_locale_resources(&resource_plan, |spec| {
-                let file_path = spec.locale_path(lang);
+                let file_path =
+                    T::layout().locale_path(&lang.to_string(), spec.locale_relative_path.as_str());
 
+                // Assets embedded with `#[compression]` are inflated by `get`.
                 match T::get(&file_path) {
                     Some(file_data) => {
                         match crate::asset_localization::parse_fluent_resource_bytes(
@@ -241,7 +256,11 @@ impl<T: EmbeddedAssets> Localizer for EmbeddedLocalizer<T> {
 
             if let Ok(resources) = self.load_resource_for_language(&candidate) {
                 let (mut candidate_bundle, add_errors) =
-                    crate::localization::build_sync_bundle(&candidate, resources.clone());
+                    crate::localization::build_sync_bundle_with_options(
+                        &candidate,
+                        resources.clone(),
+                        &self.bundle_options.read(),
+                    );
                 if !add_errors.is_empty() {
                     if locale_resources.is_empty() {
                         let error =
@@ -296,37 +315,82 @@ impl<T: EmbeddedAssets> Localizer for EmbeddedLocalizer<T> {
         };
 
         if let Some(bundle) = bundle.as_ref()
-            && let Some((value, errors)) =
+            && let Some(formatted) =
                 crate::localization::localize_with_bundle(bundle.as_ref(), id, args)
         {
-            if !errors.is_empty() {
-                tracing::error!(
-                    "Fluent formatting errors for id '{}': {:?}",
-                    id.as_str(),
-                    errors
-                );
-                return None;
-            }
-
-            return Some(value);
+            return crate::localization::accept_formatted_value(id, formatted);
         }
 
-        let (value, errors) = crate::localization::localize_with_fallback_resources(
+        crate::localization::format_message_with_fallback_resources(
             locale_resources.as_slice(),
             id,
             args,
-        );
+            &self.bundle_options.read(),
+        )
+    }
 
-        if crate::localization::fallback_errors_are_fatal(&errors) {
-            tracing::error!(
-                "Fluent fallback formatting errors for id '{}': {:?}",
-                id.as_str(),
-                errors
-            );
-            return None;
+    fn has_message(&self, id: StaticFluentEntryId) -> bool {
+        crate::localization::has_message_with_fallback_resources(
+            &self.state.read().current_locale_resources,
+            id,
+        )
+    }
+
+    fn localize_attribute<'a>(
+        &self,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&FluentArgumentMap<'a>>,
+    ) -> Option<String> {
+        let (bundle, locale_resources) = {
+            let state = self.state.read();
+            (
+                state.current_bundle.clone(),
+                state.current_locale_resources.clone(),
+            )
+        };
+
+        if let Some(bundle) = bundle.as_ref()
+            && let Some(formatted) = crate::localization::localize_attribute_with_bundle(
+                bundle.as_ref(),
+                id,
+                attribute,
+                args,
+            )
+        {
+            return crate::localization::accept_formatted_value(id, formatted);
         }
 
-        value
+        crate::localization::localize_attribute_with_fallback_resources(
+            locale_resources.as_slice(),
+            id,
+            attribute,
+            args,
+            &self.bundle_options.read(),
+        )
+        .and_then(|formatted| crate::localization::accept_formatted_value(id, formatted))
+    }
+
+    fn set_bundle_options(&self, options: &BundleOptions) {
+        let _selection_guard = self.selection_lock.lock();
+        *self.bundle_options.write() = options.clone();
+
+        // Rebuild the selected bundle so the options apply without waiting for
+        // the next language change. Its resources already assembled once.
+        let mut state = self.state.write();
+        let Some(lang) = state.current_lang.clone() else {
+            return;
+        };
+        let Some((first_lang, resources)) = state.current_locale_resources.first() else {
+            return;
+        };
+        let (mut bundle, _) = crate::localization::build_sync_bundle_with_options(
+            first_lang,
+            resources.clone(),
+            options,
+        );
+        bundle.locales = crate::fallback::locale_candidates(&lang);
+        state.current_bundle = Some(Arc::new(bundle));
     }
 }
 
@@ -387,9 +451,11 @@ impl<T: EmbeddedAssets> EmbeddedI18nModule<T> {
         let mut seen = HashSet::new();
 
         for file_path in T::iter() {
-            let file_path_str = file_path.as_ref();
+            let Some(file_path) = T::layout().language_first_path(file_path.as_ref()) else {
+                continue;
+            };
             if let Some((lang_id, _)) =
-                embedded_resource_from_asset_path(file_path_str, domain.as_str(), namespaces)
+                embedded_resource_from_asset_path(&file_path, domain.as_str(), namespaces)
                 && seen.insert(lang_id.clone())
             {
                 languages.push(lang_id);
@@ -524,6 +590,24 @@ mod tests {
         }
     }
 
+    #[derive(RustEmbed)]
+    #[folder = "tests/fixtures/embedded_i18n_domain_first"]
+    struct DomainFirstAssets;
+
+    impl EmbeddedAssets for DomainFirstAssets {
+        fn domain() -> crate::StaticFluentDomain {
+            crate::__macro::static_domain("test-domain")
+        }
+
+        fn namespaces() -> &'static [&'static str] {
+            &["ui"]
+        }
+
+        fn layout() -> AssetsLayout {
+            AssetsLayout::DomainFirst
+        }
+    }
+
     struct OptionalOnlyAssets;
 
     impl RustEmbed for OptionalOnlyAssets {
@@ -661,6 +745,25 @@ mod tests {
         assert_eq!(languages, vec![langid!("en")]);
     }
 
+    #[test]
+    fn domain_first_assets_discover_and_load_locale_resources() {
+        let languages = EmbeddedI18nModule::<DomainFirstAssets>::discover_languages();
+        assert_eq!(languages, vec![langid!("en"), langid!("fr")]);
+
+        let localizer = EmbeddedLocalizer::<DomainFirstAssets>::new(&MODULE_DATA);
+        localizer
+            .select_language(&langid!("en"))
+            .expect("domain-first resources should make the locale ready");
+        assert_eq!(
+            localizer.localize(static_entry("base-only"), None),
+            Some("Hello main".to_string())
+        );
+        assert_eq!(
+            localizer.localize(static_entry("ui-title"), None),
+            Some("UI Title".to_string())
+        );
+    }
+
     #[test]
     fn embedded_language_discovery_only_accepts_canonical_resources() {
         assert_eq!(
@@ -776,6 +879,46 @@ mod tests {
             .expect("re-selecting exactly the active language should no-op");
     }
 
+    #[test]
+    fn embedded_localizer_applies_bundle_options_to_the_selected_bundle() {
+        let localizer = EmbeddedLocalizer::<TestAssets>::new(&MODULE_DATA);
+        localizer
+            .select_language(&langid!("en"))
+            .expect("en should load");
+
+        let mut args = FluentArgumentMap::default();
+        args.insert(static_arg("name"), FluentValue::from("Mark"));
+        assert_eq!(
+            localizer
+                .localize(static_entry("welcome"), Some(&args))
+                .as_deref(),
+            Some("Welcome, \u{2068}Mark\u{2069}!")
+        );
+
+        let mut options = BundleOptions::default();
+        options.set_use_isolating(false);
+        localizer.set_bundle_options(&options);
+        assert_eq!(
+            localizer
+                .localize(static_entry("welcome"), Some(&args))
+                .as_deref(),
+            Some("Welcome, Mark!")
+        );
+    }
+
+    #[test]
+    fn embedded_localizer_has_message_does_not_need_arguments() {
+        let localizer = EmbeddedLocalizer::<TestAssets>::new(&MODULE_DATA);
+        assert!(!localizer.has_message(static_entry("welcome")));
+
+        localizer
+            .select_language(&langid!("en"))
+            .expect("en should load");
+        assert_eq!(localizer.localize(static_entry("welcome"), None), None);
+        assert!(localizer.has_message(static_entry("welcome")));
+        assert!(!localizer.has_message(static_entry("missing-message")));
+    }
+
     #[test]
     fn embedded_localizer_preserves_requested_locale_in_bundle_metadata() {
         let localizer = EmbeddedLocalizer::<TestAssets>::new(&MODULE_DATA);
@@ -74,6 +74,29 @@ pub fn resolve_fallback_language(
         .find(|candidate| available.iter().any(|lang| lang == candidate))
 }
 
+/// Negotiates the best available language for an ordered preference list.
+///
+/// Each requested language is tried in order, as browsers and operating
+/// systems report them. A request matches its own fallback chain first
+/// (`de-AT` finds `de-AT`, then `de`), then any available locale with the same
+/// language subtag (`de-AT` finds `de-DE`), before the next preference is
+/// considered.
+pub fn negotiate_language(
+    requested: &[LanguageIdentifier],
+    available: &[LanguageIdentifier],
+) -> Option<LanguageIdentifier> {
+    let available = sorted_languages(available);
+
+    requested.iter().find_map(|lang| {
+        resolve_fallback_language(lang, &available).or_else(|| {
+            available
+                .iter()
+                .find(|candidate| candidate.language == lang.language)
+                .cloned()
+        })
+    })
+}
+
 /// Resolves the first matching locale in the fallback chain by availability category.
 ///
 /// Category precedence is global across the entire fallback chain:
@@ -175,6 +198,42 @@ mod tests {
         assert_eq!(resolve_fallback_language(&requested, &available), None);
     }
 
+    #[test]
+    fn negotiate_language_walks_preferences_in_order() {
+        let available = vec![langid!("en-US"), langid!("fr"), langid!("de-DE")];
+
+        assert_eq!(
+            negotiate_language(
+                &[langid!("de-AT"), langid!("fr"), langid!("en")],
+                &available
+            ),
+            Some(langid!("de-DE"))
+        );
+        assert_eq!(
+            negotiate_language(
+                &[langid!("it"), langid!("fr-CA"), langid!("en")],
+                &available
+            ),
+            Some(langid!("fr"))
+        );
+        assert_eq!(
+            negotiate_language(&[langid!("pt-BR"), langid!("en")], &available),
+            Some(langid!("en-US"))
+        );
+        assert_eq!(negotiate_language(&[langid!("ja")], &available), None);
+        assert_eq!(negotiate_language(&[], &available), None);
+    }
+
+    #[test]
+    fn negotiate_language_prefers_fallback_chain_over_sibling_regions() {
+        let available = vec![langid!("de-CH"), langid!("de")];
+
+        assert_eq!(
+            negotiate_language(&[langid!("de-AT")], &available),
+            Some(langid!("de"))
+        );
+    }
+
     #[test]
     fn resolve_ready_locale_prefers_ready_candidates() {
         let requested = langid!("en-US");
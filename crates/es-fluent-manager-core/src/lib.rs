@@ -24,14 +24,18 @@ pub use es_fluent_shared::registry::__macro;
 pub use es_fluent_shared::registry::{
     StaticFluentArgumentName, StaticFluentDomain, StaticFluentEntryId, StaticFluentVariantKey,
 };
+pub use es_fluent_shared::resource::AssetsLayout;
 pub use fallback::{
-    FallbackChainAvailability, fallback_locales, locale_candidates,
+    FallbackChainAvailability, fallback_locales, locale_candidates, negotiate_language,
     resolve_fallback_chain_availability, resolve_fallback_language, resolve_ready_locale,
 };
 pub use localization::{
-    DiscoveredRuntimeI18nModules, FluentArgumentMap, FluentManager, I18nModule,
-    I18nModuleRegistration, LanguageSelectionPolicy, LocalizationError, Localizer,
-    ModuleDiscoveryError, ModuleRegistrationKind, SyncFluentBundle, add_resources_to_bundle,
-    build_fluent_args, build_sync_bundle, fallback_errors_are_fatal, localize_with_bundle,
-    localize_with_fallback_resources, try_filter_module_registry,
+    BundleOptions, DiscoveredRuntimeI18nModules, FluentArgumentMap, FluentFunction, FluentManager,
+    I18nModule, I18nModuleRegistration, LanguageChangedCallback, LanguageSelectionPolicy,
+    LocalizationError, Localizer, ModuleDiscoveryError, ModuleRegistrationKind, SyncFluentBundle,
+    accept_formatted_value, add_resources_to_bundle, build_fluent_args, build_sync_bundle,
+    build_sync_bundle_with_options, fallback_errors_are_fatal, format_message,
+    format_message_with_fallback_resources, has_message_with_fallback_resources,
+    localize_attribute_with_bundle, localize_attribute_with_fallback_resources,
+    localize_with_bundle, localize_with_fallback_resources, try_filter_module_registry,
 };
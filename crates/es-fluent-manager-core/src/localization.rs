@@ -1,6 +1,7 @@
 //! This module provides the core types for managing translations.
 
 mod bundle;
+mod in_memory;
 mod manager;
 mod registry;
 
@@ -16,11 +17,14 @@ use std::collections::HashMap;
 use unic_langid::LanguageIdentifier;
 
 pub use bundle::{
-    LocalizationError, SyncFluentBundle, add_resources_to_bundle, build_fluent_args,
-    build_sync_bundle, fallback_errors_are_fatal, localize_with_bundle,
+    BundleOptions, FluentFunction, LocalizationError, SyncFluentBundle, accept_formatted_value,
+    add_resources_to_bundle, build_fluent_args, build_sync_bundle, build_sync_bundle_with_options,
+    fallback_errors_are_fatal, format_message, format_message_with_fallback_resources,
+    has_message_with_fallback_resources, localize_attribute_with_bundle,
+    localize_attribute_with_fallback_resources, localize_with_bundle,
     localize_with_fallback_resources,
 };
-pub use manager::{DiscoveredRuntimeI18nModules, FluentManager};
+pub use manager::{DiscoveredRuntimeI18nModules, FluentManager, LanguageChangedCallback};
 pub use registry::{ModuleDiscoveryError, ModuleRegistrationKind, try_filter_module_registry};
 
 pub type LocalizationErrorResult<T> = Result<T, LocalizationError>;
@@ -40,12 +44,63 @@ pub trait Localizer: Send + Sync {
     /// need the same behavior should perform that resolution here before
     /// returning [`LocalizationError::LanguageNotSupported`].
     fn select_language(&self, lang: &LanguageIdentifier) -> es_fluent_shared::EsFluentResult<()>;
+    /// Selects the first language in `requested` that the localizer supports.
+    ///
+    /// `requested` is an ordered preference list such as `navigator.languages`.
+    /// The default tries each entry with [`Self::select_language`] and only
+    /// moves on after [`LocalizationError::LanguageNotSupported`]; other errors
+    /// are returned immediately.
+    fn select_languages(
+        &self,
+        requested: &[LanguageIdentifier],
+    ) -> es_fluent_shared::EsFluentResult<()> {
+        let mut first_unsupported = None;
+        for lang in requested {
+            match self.select_language(lang) {
+                Ok(()) => return Ok(()),
+                Err(error @ LocalizationError::LanguageNotSupported(_)) => {
+                    first_unsupported.get_or_insert(error);
+                },
+                Err(error) => return Err(error),
+            }
+        }
+        Err(first_unsupported.unwrap_or_else(|| {
+            LocalizationError::LanguageNotSupported(LanguageIdentifier::default())
+        }))
+    }
     /// Localizes a message by its validated static ID.
     fn localize<'a>(
         &self,
         id: StaticFluentEntryId,
         args: Option<&FluentArgumentMap<'a>>,
     ) -> Option<String>;
+    /// Localizes the `attribute` of a message by its validated static ID.
+    ///
+    /// The default reports every attribute as missing; localizers backed by
+    /// Fluent bundles override it.
+    fn localize_attribute<'a>(
+        &self,
+        _id: StaticFluentEntryId,
+        _attribute: &str,
+        _args: Option<&FluentArgumentMap<'a>>,
+    ) -> Option<String> {
+        None
+    }
+    /// Returns whether message `id` resolves to a value in the selected
+    /// language, without formatting it.
+    ///
+    /// The default formats the message without arguments, so messages that
+    /// reference variables report as missing; localizers backed by Fluent
+    /// bundles override it.
+    fn has_message(&self, id: StaticFluentEntryId) -> bool {
+        self.localize(id, None).is_some()
+    }
+    /// Applies `options` to the Fluent bundles this localizer formats with,
+    /// including a bundle already built for the selected language.
+    ///
+    /// The default ignores them; localizers backed by Fluent bundles override
+    /// it.
+    fn set_bundle_options(&self, _options: &BundleOptions) {}
 }
 
 /// Unified inventory contract for all module registrations.
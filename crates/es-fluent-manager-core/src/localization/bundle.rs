@@ -1,7 +1,7 @@
 use es_fluent_shared::EsFluentError;
 use es_fluent_shared::registry::StaticFluentEntryId;
 use fluent_bundle::{
-    FluentArgs as BundleFluentArgs, FluentError, FluentResource, bundle::FluentBundle,
+    FluentArgs as BundleFluentArgs, FluentError, FluentResource, FluentValue, bundle::FluentBundle,
     memoizer::MemoizerKind,
 };
 use fluent_fallback::{
@@ -22,6 +22,99 @@ pub type SyncFluentBundle =
     FluentBundle<Arc<FluentResource>, intl_memoizer::concurrent::IntlLangMemoizer>;
 type FallbackFluentBundle = fluent_bundle::FluentBundle<Arc<FluentResource>>;
 
+/// A custom function callable from Fluent messages, such as `DATETIME`.
+pub type FluentFunction =
+    Arc<dyn for<'a> Fn(&[FluentValue<'a>], &BundleFluentArgs) -> FluentValue<'a> + Send + Sync>;
+
+/// Settings applied to every Fluent bundle a manager builds.
+///
+/// The default matches `fluent-bundle`: placeables are wrapped in Unicode
+/// isolation marks (U+2068/U+2069) and no custom functions are registered.
+#[derive(Clone)]
+pub struct BundleOptions {
+    use_isolating: bool,
+    functions: Vec<(String, FluentFunction)>,
+}
+
+impl Default for BundleOptions {
+    fn default() -> Self {
+        Self {
+            use_isolating: true,
+            functions: Vec::new(),
+        }
+    }
+}
+
+impl std::fmt::Debug for BundleOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BundleOptions")
+            .field("use_isolating", &self.use_isolating)
+            .field(
+                "functions",
+                &self
+                    .functions
+                    .iter()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl BundleOptions {
+    /// Sets whether placeables are wrapped in Unicode isolation marks.
+    ///
+    /// Disabling isolation suits plain-text output where the invisible marks
+    /// would leak into logs, terminals, or text comparisons.
+    pub fn set_use_isolating(&mut self, use_isolating: bool) {
+        self.use_isolating = use_isolating;
+    }
+
+    /// Returns whether placeables are wrapped in Unicode isolation marks.
+    pub fn use_isolating(&self) -> bool {
+        self.use_isolating
+    }
+
+    /// Registers `function` under `name` for every bundle, replacing an earlier
+    /// function with the same name.
+    pub fn add_function<F>(&mut self, name: impl Into<String>, function: F)
+    where
+        F: for<'a> Fn(&[FluentValue<'a>], &BundleFluentArgs) -> FluentValue<'a>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let name = name.into();
+        self.functions.retain(|(existing, _)| *existing != name);
+        self.functions.push((name, Arc::new(function)));
+    }
+
+    /// Returns the names of the registered functions in registration order.
+    pub fn function_names(&self) -> impl Iterator<Item = &str> {
+        self.functions.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Applies these settings to `bundle`.
+    ///
+    /// Call this before adding resources: functions share the bundle's entry
+    /// namespace, so a function is rejected when a message of the same name
+    /// is already present.
+    pub fn apply<R, M>(&self, bundle: &mut FluentBundle<R, M>)
+    where
+        M: MemoizerKind,
+    {
+        bundle.set_use_isolating(self.use_isolating);
+        for (name, function) in &self.functions {
+            let function = Arc::clone(function);
+            if let Err(error) =
+                bundle.add_function(name, move |positional, named| function(positional, named))
+            {
+                tracing::warn!("Failed to register Fluent function '{name}': {error}");
+            }
+        }
+    }
+}
+
 #[derive(Clone)]
 struct OrderedLocales(Vec<LanguageIdentifier>);
 
@@ -36,12 +129,17 @@ impl LocalesProvider for OrderedLocales {
 #[derive(Clone, Default)]
 struct StaticBundleGenerator {
     resources_by_locale: HashMap<LanguageIdentifier, Vec<Arc<FluentResource>>>,
+    options: BundleOptions,
 }
 
 impl StaticBundleGenerator {
-    fn new(locale_resources: &[(LanguageIdentifier, Vec<Arc<FluentResource>>)]) -> Self {
+    fn new(
+        locale_resources: &[(LanguageIdentifier, Vec<Arc<FluentResource>>)],
+        options: &BundleOptions,
+    ) -> Self {
         Self {
             resources_by_locale: locale_resources.iter().cloned().collect(),
+            options: options.clone(),
         }
     }
 
@@ -51,6 +149,7 @@ impl StaticBundleGenerator {
     ) -> Option<FluentBundleResult<Arc<FluentResource>>> {
         let resources = self.resources_by_locale.get(locale)?.clone();
         let mut bundle = FallbackFluentBundle::new(crate::fallback::locale_candidates(locale));
+        self.options.apply(&mut bundle);
         let mut errors = Vec::new();
 
         for resource in resources {
@@ -115,8 +214,19 @@ where
 pub fn build_sync_bundle(
     lang: &LanguageIdentifier,
     resources: impl IntoIterator<Item = Arc<FluentResource>>,
+) -> (SyncFluentBundle, Vec<Vec<FluentError>>) {
+    build_sync_bundle_with_options(lang, resources, &BundleOptions::default())
+}
+
+/// Builds a concurrent `FluentBundle` like [`build_sync_bundle`], applying
+/// `options` before the resources are added.
+pub fn build_sync_bundle_with_options(
+    lang: &LanguageIdentifier,
+    resources: impl IntoIterator<Item = Arc<FluentResource>>,
+    options: &BundleOptions,
 ) -> (SyncFluentBundle, Vec<Vec<FluentError>>) {
     let mut bundle = FluentBundle::new_concurrent(crate::fallback::locale_candidates(lang));
+    options.apply(&mut bundle);
     let add_errors = add_resources_to_bundle(&mut bundle, resources);
     (bundle, add_errors)
 }
@@ -153,11 +263,91 @@ where
     Some((value.into_owned(), errors))
 }
 
+/// Applies the shared formatting-error policy to a formatted message.
+///
+/// Formatting errors are logged and discard the value, so a message with a
+/// broken placeable never renders partially.
+pub fn accept_formatted_value(
+    id: StaticFluentEntryId,
+    (value, errors): (String, Vec<FluentError>),
+) -> Option<String> {
+    if errors.is_empty() {
+        return Some(value);
+    }
+
+    tracing::error!(
+        "Fluent formatting errors for id '{}': {:?}",
+        id.as_str(),
+        errors
+    );
+    None
+}
+
+/// Formats a message value from an already-built Fluent bundle.
+///
+/// Returns `None` when the message or value is missing, or when formatting
+/// reports errors.
+pub fn format_message<'a, R, M>(
+    bundle: &FluentBundle<R, M>,
+    id: StaticFluentEntryId,
+    args: Option<&FluentArgumentMap<'a>>,
+) -> Option<String>
+where
+    R: Borrow<FluentResource>,
+    M: MemoizerKind,
+{
+    localize_with_bundle(bundle, id, args)
+        .and_then(|formatted| accept_formatted_value(id, formatted))
+}
+
+/// Formats a message value against ordered locale resources.
+///
+/// Returns `None` when no locale defines the message, or when bundle assembly
+/// or formatting reports fatal errors.
+pub fn format_message_with_fallback_resources<'a>(
+    locale_resources: &[(LanguageIdentifier, Vec<Arc<FluentResource>>)],
+    id: StaticFluentEntryId,
+    args: Option<&FluentArgumentMap<'a>>,
+    options: &BundleOptions,
+) -> Option<String> {
+    let (value, errors) = localize_with_fallback_resources(locale_resources, id, args, options);
+    if fallback_errors_are_fatal(&errors) {
+        tracing::error!(
+            "Fluent fallback formatting errors for id '{}': {:?}",
+            id.as_str(),
+            errors
+        );
+        return None;
+    }
+
+    value
+}
+
+/// Returns whether any of the ordered locale resources gives message `id` a
+/// value, without formatting it.
+pub fn has_message_with_fallback_resources(
+    locale_resources: &[(LanguageIdentifier, Vec<Arc<FluentResource>>)],
+    id: StaticFluentEntryId,
+) -> bool {
+    locale_resources
+        .iter()
+        .flat_map(|(_, resources)| resources)
+        .flat_map(|resource| resource.entries())
+        .any(|entry| {
+            matches!(
+                entry,
+                fluent_syntax::ast::Entry::Message(message)
+                    if message.id.name == id.as_str() && message.value.is_some()
+            )
+        })
+}
+
 #[doc(hidden)]
 pub fn localize_with_fallback_resources<'a>(
     locale_resources: &[(LanguageIdentifier, Vec<Arc<FluentResource>>)],
     id: StaticFluentEntryId,
     args: Option<&FluentArgumentMap<'a>>,
+    options: &BundleOptions,
 ) -> (Option<String>, Vec<FallbackLocalizationError>) {
     if locale_resources.is_empty() {
         return (None, Vec::new());
@@ -169,7 +359,7 @@ pub fn localize_with_fallback_resources<'a>(
             .map(|(locale, _)| locale.clone())
             .collect(),
     );
-    let generator = StaticBundleGenerator::new(locale_resources);
+    let generator = StaticBundleGenerator::new(locale_resources, options);
     let localization = Localization::with_env(
         Vec::<fluent_fallback::types::ResourceId>::new(),
         true,
@@ -194,6 +384,51 @@ pub fn localize_with_fallback_resources<'a>(
     (value, errors)
 }
 
+/// Localizes a message attribute from an already-built Fluent bundle.
+///
+/// Returns `None` when the message or attribute is missing.
+/// Returns the formatted attribute and collected formatting errors otherwise.
+pub fn localize_attribute_with_bundle<'a, R, M>(
+    bundle: &FluentBundle<R, M>,
+    id: StaticFluentEntryId,
+    attribute: &str,
+    args: Option<&FluentArgumentMap<'a>>,
+) -> Option<(String, Vec<FluentError>)>
+where
+    R: Borrow<FluentResource>,
+    M: MemoizerKind,
+{
+    let message = bundle.get_message(id.as_str())?;
+    let pattern = message.get_attribute(attribute)?.value();
+    let fluent_args = build_fluent_args(args);
+    let mut errors = Vec::new();
+    let value = bundle.format_pattern(pattern, fluent_args.as_ref(), &mut errors);
+    Some((value.into_owned(), errors))
+}
+
+/// Localizes a message attribute against ordered locale resources.
+///
+/// The first locale whose message defines `attribute` wins. Resource-add
+/// errors for that locale are returned with its formatting errors.
+pub fn localize_attribute_with_fallback_resources<'a>(
+    locale_resources: &[(LanguageIdentifier, Vec<Arc<FluentResource>>)],
+    id: StaticFluentEntryId,
+    attribute: &str,
+    args: Option<&FluentArgumentMap<'a>>,
+    options: &BundleOptions,
+) -> Option<(String, Vec<FluentError>)> {
+    locale_resources.iter().find_map(|(locale, resources)| {
+        let mut bundle = FallbackFluentBundle::new(crate::fallback::locale_candidates(locale));
+        options.apply(&mut bundle);
+        let add_errors = add_resources_to_bundle(&mut bundle, resources.iter().cloned());
+        let (value, errors) = localize_attribute_with_bundle(&bundle, id, attribute, args)?;
+        Some((
+            value,
+            add_errors.into_iter().flatten().chain(errors).collect(),
+        ))
+    })
+}
+
 #[doc(hidden)]
 pub fn fallback_errors_are_fatal(errors: &[FallbackLocalizationError]) -> bool {
     errors.iter().any(|error| {
@@ -245,13 +480,16 @@ mod tests {
     fn static_bundle_generator_builds_success_error_and_iter_results() {
         let en = langid!("en");
         let fr = langid!("fr");
-        let generator = StaticBundleGenerator::new(&[
-            (en.clone(), vec![resource("hello = Hello")]),
-            (
-                fr.clone(),
-                vec![resource("dupe = first"), resource("dupe = second")],
-            ),
-        ]);
+        let generator = StaticBundleGenerator::new(
+            &[
+                (en.clone(), vec![resource("hello = Hello")]),
+                (
+                    fr.clone(),
+                    vec![resource("dupe = first"), resource("dupe = second")],
+                ),
+            ],
+            &BundleOptions::default(),
+        );
 
         assert!(
             generator
@@ -308,6 +546,28 @@ mod tests {
         assert!(!errors.is_empty());
         assert!(localize_with_bundle(&bundle, static_entry("missing"), None).is_none());
         assert!(localize_with_bundle(&bundle, static_entry("attr-only"), None).is_none());
+        assert_eq!(
+            format_message(&bundle, static_entry("hello"), Some(&args)).as_deref(),
+            Some("Hello \u{2068}Mark\u{2069}")
+        );
+        assert_eq!(
+            format_message(&bundle, static_entry("needs-name"), None),
+            None
+        );
+        assert_eq!(format_message(&bundle, static_entry("missing"), None), None);
+        let (label, errors) =
+            localize_attribute_with_bundle(&bundle, static_entry("attr-only"), "label", None)
+                .expect("attribute should be present");
+        assert_eq!(label, "Label");
+        assert!(errors.is_empty());
+        assert!(
+            localize_attribute_with_bundle(&bundle, static_entry("attr-only"), "title", None)
+                .is_none()
+        );
+        assert!(
+            localize_attribute_with_bundle(&bundle, static_entry("missing"), "label", None)
+                .is_none()
+        );
 
         let (sync_bundle, sync_errors) = build_sync_bundle(&lang, vec![resource("sync = Sync")]);
         assert!(sync_errors.is_empty());
@@ -323,8 +583,12 @@ mod tests {
             (langid!("en"), vec![resource("fallback-only = Fallback")]),
         ];
 
-        let (value, errors) =
-            localize_with_fallback_resources(&locale_resources, static_entry("hello"), Some(&args));
+        let (value, errors) = localize_with_fallback_resources(
+            &locale_resources,
+            static_entry("hello"),
+            Some(&args),
+            &BundleOptions::default(),
+        );
         let value = value.expect("localized value should be present");
         assert!(value.contains("Howdy"));
         assert!(value.contains("Mark"));
@@ -334,18 +598,168 @@ mod tests {
             &locale_resources,
             static_entry("fallback-only"),
             None,
+            &BundleOptions::default(),
         );
         assert_eq!(fallback, Some("Fallback".to_string()));
         assert!(!fallback_errors_are_fatal(&errors));
 
-        let (missing, errors) =
-            localize_with_fallback_resources(&locale_resources, static_entry("missing"), None);
+        let (missing, errors) = localize_with_fallback_resources(
+            &locale_resources,
+            static_entry("missing"),
+            None,
+            &BundleOptions::default(),
+        );
         assert_eq!(missing, None);
         assert!(!fallback_errors_are_fatal(&errors));
 
-        let (empty, errors) = localize_with_fallback_resources(&[], static_entry("hello"), None);
+        let (empty, errors) = localize_with_fallback_resources(
+            &[],
+            static_entry("hello"),
+            None,
+            &BundleOptions::default(),
+        );
         assert_eq!(empty, None);
         assert!(errors.is_empty());
         assert!(!fallback_errors_are_fatal(&[]));
+
+        assert_eq!(
+            format_message_with_fallback_resources(
+                &locale_resources,
+                static_entry("fallback-only"),
+                None,
+                &BundleOptions::default()
+            ),
+            Some("Fallback".to_string())
+        );
+        assert_eq!(
+            format_message_with_fallback_resources(
+                &locale_resources,
+                static_entry("hello"),
+                None,
+                &BundleOptions::default()
+            ),
+            None
+        );
+        assert_eq!(
+            format_message_with_fallback_resources(
+                &locale_resources,
+                static_entry("missing"),
+                None,
+                &BundleOptions::default()
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn fallback_attribute_localization_uses_first_locale_with_the_attribute() {
+        let mut args = FluentArgumentMap::default();
+        args.insert(static_arg("name"), FluentValue::from("Mark"));
+        let locale_resources = vec![
+            (
+                langid!("en-US"),
+                vec![resource(
+                    "login = Log in\n    .aria-label = Log in as { $name }",
+                )],
+            ),
+            (
+                langid!("en"),
+                vec![resource(
+                    "login = Log in\n    .title = Sign in\n    .aria-label = Fallback",
+                )],
+            ),
+        ];
+
+        let (value, errors) = localize_attribute_with_fallback_resources(
+            &locale_resources,
+            static_entry("login"),
+            "aria-label",
+            Some(&args),
+            &BundleOptions::default(),
+        )
+        .expect("attribute should be present");
+        assert!(value.contains("Log in as"));
+        assert!(value.contains("Mark"));
+        assert!(errors.is_empty());
+
+        let (value, _errors) = localize_attribute_with_fallback_resources(
+            &locale_resources,
+            static_entry("login"),
+            "title",
+            None,
+            &BundleOptions::default(),
+        )
+        .expect("fallback attribute should be present");
+        assert_eq!(value, "Sign in");
+
+        assert!(
+            localize_attribute_with_fallback_resources(
+                &locale_resources,
+                static_entry("login"),
+                "missing",
+                None,
+                &BundleOptions::default(),
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn bundle_options_control_isolation_and_custom_functions() {
+        let lang = langid!("en-US");
+        let mut options = BundleOptions::default();
+        assert!(options.use_isolating());
+        options.set_use_isolating(false);
+        options.add_function("SHOUT", |positional, _named| match positional.first() {
+            Some(FluentValue::String(value)) => FluentValue::from(value.to_uppercase()),
+            _ => FluentValue::Error,
+        });
+        options.add_function("SHOUT", |_positional, _named| FluentValue::from("replaced"));
+        assert_eq!(options.function_names().collect::<Vec<_>>(), ["SHOUT"]);
+
+        let mut args = FluentArgumentMap::default();
+        args.insert(static_arg("name"), FluentValue::from("Mark"));
+        let (bundle, errors) = build_sync_bundle_with_options(
+            &lang,
+            vec![resource(
+                "hello = Hello { $name }\nshout = { SHOUT($name) }",
+            )],
+            &options,
+        );
+        assert!(errors.is_empty());
+        assert_eq!(
+            format_message(&bundle, static_entry("hello"), Some(&args)).as_deref(),
+            Some("Hello Mark")
+        );
+        assert_eq!(
+            format_message(&bundle, static_entry("shout"), Some(&args)).as_deref(),
+            Some("replaced")
+        );
+
+        let locale_resources = vec![(
+            lang,
+            vec![resource(
+                "hello = Hello { $name }\n    .title = Hi { $name }",
+            )],
+        )];
+        assert_eq!(
+            format_message_with_fallback_resources(
+                &locale_resources,
+                static_entry("hello"),
+                Some(&args),
+                &options,
+            )
+            .as_deref(),
+            Some("Hello Mark")
+        );
+        let (title, _errors) = localize_attribute_with_fallback_resources(
+            &locale_resources,
+            static_entry("hello"),
+            "title",
+            Some(&args),
+            &options,
+        )
+        .expect("attribute should be present");
+        assert_eq!(title, "Hi Mark");
     }
 }
@@ -0,0 +1,272 @@
+//! Runtime modules built from in-memory Fluent sources.
+//!
+//! These back [`super::FluentManager::from_resources`], which lets tests
+//! exercise manager behavior against known FTL without inventory discovery.
+
+use super::{
+    BundleOptions, FluentArgumentMap, I18nModule, I18nModuleRegistration, LocalizationError,
+    LocalizationErrorResult, Localizer, SyncFluentBundle,
+};
+use crate::asset_localization::{I18nModuleDescriptor, ModuleData};
+use es_fluent_shared::registry::{StaticFluentDomain, StaticFluentEntryId};
+use fluent_bundle::FluentResource;
+use parking_lot::{Mutex, RwLock};
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::sync::{Arc, LazyLock};
+use unic_langid::LanguageIdentifier;
+
+type LocaleResources = Vec<(LanguageIdentifier, Vec<Arc<FluentResource>>)>;
+
+/// A domain and its FTL sources per language, sorted by language.
+type ModuleKey = (String, Vec<(String, Vec<String>)>);
+
+/// Modules already built from identical sources.
+///
+/// Managers hold `'static` registrations, so every module is leaked. Tests
+/// tend to build the same resources once per test, so each distinct domain and
+/// source set is leaked once and shared by later managers.
+static MODULES: LazyLock<Mutex<HashMap<ModuleKey, &'static InMemoryI18nModule>>> =
+    LazyLock::new(Mutex::default);
+
+/// Builds one runtime module per domain from `(domain, ftl_source)` pairs.
+///
+/// Modules are returned in domain name order. Building the same domain from
+/// the same sources again returns the module built the first time.
+pub(super) fn in_memory_modules(
+    lang_to_ftl: HashMap<LanguageIdentifier, Vec<(String, String)>>,
+) -> LocalizationErrorResult<Vec<&'static dyn I18nModuleRegistration>> {
+    let mut domains: BTreeMap<String, BTreeMap<String, (LanguageIdentifier, Vec<String>)>> =
+        BTreeMap::new();
+
+    for (lang, sources) in lang_to_ftl {
+        for (domain, content) in sources {
+            domains
+                .entry(domain)
+                .or_default()
+                .entry(lang.to_string())
+                .or_insert_with(|| (lang.clone(), Vec::new()))
+                .1
+                .push(content);
+        }
+    }
+
+    let mut built = MODULES.lock();
+    let mut modules: Vec<&'static dyn I18nModuleRegistration> = Vec::with_capacity(domains.len());
+    for (domain, sources) in domains {
+        let key = (
+            domain,
+            sources
+                .iter()
+                .map(|(lang, (_, contents))| (lang.clone(), contents.clone()))
+                .collect::<Vec<_>>(),
+        );
+        if let Some(module) = built.get(&key) {
+            modules.push(*module);
+            continue;
+        }
+
+        let mut resources = HashMap::new();
+        for (lang, contents) in sources.into_values() {
+            let lang_resources = contents
+                .into_iter()
+                .map(|content| {
+                    FluentResource::try_new(content)
+                        .map(Arc::new)
+                        .map_err(|(_, errors)| LocalizationError::FluentParseError(errors))
+                })
+                .collect::<LocalizationErrorResult<Vec<_>>>()?;
+            resources.insert(lang, lang_resources);
+        }
+
+        let module: &'static InMemoryI18nModule =
+            Box::leak(Box::new(InMemoryI18nModule::new(key.0.clone(), resources)?));
+        built.insert(key, module);
+        modules.push(module);
+    }
+
+    Ok(modules)
+}
+
+struct InMemoryI18nModule {
+    data: &'static ModuleData,
+    resources: Arc<HashMap<LanguageIdentifier, Vec<Arc<FluentResource>>>>,
+}
+
+impl InMemoryI18nModule {
+    fn new(
+        domain: String,
+        resources: HashMap<LanguageIdentifier, Vec<Arc<FluentResource>>>,
+    ) -> LocalizationErrorResult<Self> {
+        for (lang, lang_resources) in &resources {
+            let (_, add_errors) = super::build_sync_bundle(lang, lang_resources.clone());
+            if !add_errors.is_empty() {
+                let diagnostics = add_errors
+                    .iter()
+                    .flatten()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                return Err(io::Error::other(format!(
+                    "failed to build a Fluent bundle for domain '{domain}' and language '{lang}': {diagnostics}"
+                ))
+                .into());
+            }
+        }
+
+        let domain: &'static str = domain.leak();
+        let static_domain = StaticFluentDomain::try_new(domain).map_err(|error| {
+            LocalizationError::invalid_fluent_identifier(domain, error.to_string())
+        })?;
+        let mut supported_languages = resources.keys().cloned().collect::<Vec<_>>();
+        supported_languages.sort_by_key(ToString::to_string);
+        let data = Box::leak(Box::new(ModuleData {
+            name: domain,
+            domain: static_domain,
+            supported_languages: supported_languages.leak(),
+            namespaces: &[],
+        }));
+
+        Ok(Self {
+            data,
+            resources: Arc::new(resources),
+        })
+    }
+}
+
+impl I18nModuleDescriptor for InMemoryI18nModule {
+    fn data(&self) -> &'static ModuleData {
+        self.data
+    }
+}
+
+impl I18nModule for InMemoryI18nModule {
+    fn create_localizer(&self) -> Box<dyn Localizer> {
+        Box::new(InMemoryLocalizer {
+            data: self.data,
+            resources: Arc::clone(&self.resources),
+            options: RwLock::default(),
+            state: RwLock::default(),
+        })
+    }
+}
+
+struct InMemoryLocalizer {
+    data: &'static ModuleData,
+    resources: Arc<HashMap<LanguageIdentifier, Vec<Arc<FluentResource>>>>,
+    options: RwLock<BundleOptions>,
+    state: RwLock<Option<Arc<SelectedBundles>>>,
+}
+
+/// Bundles for the selected language, built once per selection.
+struct SelectedBundles {
+    lang: LanguageIdentifier,
+    locale_resources: LocaleResources,
+    /// One bundle per entry of `locale_resources`, in fallback order. The
+    /// first one formats with the selected language's locale candidates.
+    bundles: Vec<SyncFluentBundle>,
+}
+
+impl SelectedBundles {
+    fn new(
+        lang: LanguageIdentifier,
+        locale_resources: LocaleResources,
+        options: &BundleOptions,
+    ) -> Self {
+        let bundles = locale_resources
+            .iter()
+            .enumerate()
+            .map(|(index, (locale, resources))| {
+                // Resources were validated against a bundle when the module was built.
+                let (mut bundle, _) =
+                    super::build_sync_bundle_with_options(locale, resources.clone(), options);
+                if index == 0 {
+                    bundle.locales = crate::fallback::locale_candidates(&lang);
+                }
+                bundle
+            })
+            .collect();
+
+        Self {
+            lang,
+            locale_resources,
+            bundles,
+        }
+    }
+}
+
+impl InMemoryLocalizer {
+    fn selected(&self) -> Option<Arc<SelectedBundles>> {
+        self.state.read().clone()
+    }
+}
+
+impl Localizer for InMemoryLocalizer {
+    fn select_language(&self, lang: &LanguageIdentifier) -> LocalizationErrorResult<()> {
+        let mut remaining_languages = self.data.supported_languages.to_vec();
+        let mut locale_resources = Vec::new();
+
+        while let Some(candidate) =
+            crate::fallback::resolve_fallback_language(lang, &remaining_languages)
+        {
+            remaining_languages.retain(|supported| supported != &candidate);
+            if let Some(resources) = self.resources.get(&candidate) {
+                locale_resources.push((candidate, resources.clone()));
+            }
+        }
+
+        if locale_resources.is_empty() {
+            return Err(LocalizationError::LanguageNotSupported(lang.clone()));
+        }
+
+        let selected = SelectedBundles::new(lang.clone(), locale_resources, &self.options.read());
+        *self.state.write() = Some(Arc::new(selected));
+
+        Ok(())
+    }
+
+    fn set_bundle_options(&self, options: &BundleOptions) {
+        *self.options.write() = options.clone();
+
+        let mut state = self.state.write();
+        if let Some(selected) = state.as_mut() {
+            *selected = Arc::new(SelectedBundles::new(
+                selected.lang.clone(),
+                selected.locale_resources.clone(),
+                options,
+            ));
+        }
+    }
+
+    fn localize<'a>(
+        &self,
+        id: StaticFluentEntryId,
+        args: Option<&FluentArgumentMap<'a>>,
+    ) -> Option<String> {
+        let selected = self.selected()?;
+        let formatted = selected
+            .bundles
+            .iter()
+            .find_map(|bundle| super::localize_with_bundle(bundle, id, args))?;
+        super::accept_formatted_value(id, formatted)
+    }
+
+    fn has_message(&self, id: StaticFluentEntryId) -> bool {
+        self.selected().is_some_and(|selected| {
+            super::has_message_with_fallback_resources(&selected.locale_resources, id)
+        })
+    }
+
+    fn localize_attribute<'a>(
+        &self,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&FluentArgumentMap<'a>>,
+    ) -> Option<String> {
+        let selected = self.selected()?;
+        let formatted = selected.bundles.iter().find_map(|bundle| {
+            super::localize_attribute_with_bundle(bundle, id, attribute, args)
+        })?;
+        super::accept_formatted_value(id, formatted)
+    }
+}
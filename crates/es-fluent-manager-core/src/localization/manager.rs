@@ -1,15 +1,19 @@
 use super::{
-    FluentArgumentMap, I18nModuleRegistration, LanguageSelectionPolicy, Localizer,
+    BundleOptions, FluentArgumentMap, I18nModuleRegistration, LanguageSelectionPolicy, Localizer,
     ModuleDiscoveryError, ModuleRegistrationKind,
 };
 use crate::asset_localization::ModuleData;
 use es_fluent_shared::registry::{StaticFluentDomain, StaticFluentEntryId};
 use parking_lot::RwLock;
+use std::collections::{BTreeMap, HashMap};
 use std::io;
 use std::sync::Arc;
 use unic_langid::LanguageIdentifier;
 
 type ManagedLocalizer = (&'static ModuleData, Box<dyn Localizer>);
+
+/// Callback registered with [`FluentManager::on_language_changed`].
+pub type LanguageChangedCallback = Box<dyn Fn(&LanguageIdentifier) + Send + Sync>;
 const MAX_DIAGNOSTIC_LANGUAGES: usize = 6;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -18,6 +22,15 @@ enum LanguageSupportRequirement {
     RuntimeLocalizer,
 }
 
+/// The selected language and the rules it was selected under, so a reload
+/// rebuilds it the same way.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct LanguageSelection {
+    language: LanguageIdentifier,
+    policy: LanguageSelectionPolicy,
+    support_requirement: LanguageSupportRequirement,
+}
+
 /// Cached, validated runtime-capable i18n module registrations.
 ///
 /// This lets integrations pay strict inventory discovery once, then construct
@@ -49,6 +62,11 @@ impl std::fmt::Debug for DiscoveredRuntimeI18nModules {
 pub struct FluentManager {
     pub(super) modules: Vec<&'static dyn I18nModuleRegistration>,
     pub(super) localizers: RwLock<Vec<ManagedLocalizer>>,
+    pub(super) selected_language: RwLock<Option<LanguageSelection>>,
+    pub(super) fallback_language: Option<LanguageIdentifier>,
+    pub(super) fallback_localizers: RwLock<Vec<ManagedLocalizer>>,
+    pub(super) language_changed_callbacks: Vec<LanguageChangedCallback>,
+    pub(super) bundle_options: BundleOptions,
 }
 
 fn load_runtime_modules(
@@ -218,9 +236,214 @@ impl FluentManager {
         Self {
             modules: discovered.modules.iter().copied().collect(),
             localizers: RwLock::default(),
+            selected_language: RwLock::default(),
+            fallback_language: None,
+            fallback_localizers: RwLock::default(),
+            language_changed_callbacks: Vec::new(),
+            bundle_options: BundleOptions::default(),
         }
     }
 
+    /// Creates a manager from in-memory Fluent sources without inventory
+    /// discovery.
+    ///
+    /// `lang_to_ftl` maps each language to `(domain, ftl_source)` pairs. Each
+    /// domain becomes one runtime module, and several sources for the same
+    /// language and domain share one bundle. This is intended for tests that
+    /// assert lookup, fallback, and error behavior against known FTL.
+    ///
+    /// # Panics
+    ///
+    /// Panics when a source fails to parse, a domain name is invalid, or a
+    /// message is defined twice for one language and domain. Use
+    /// [`Self::try_from_resources`] to handle those errors.
+    pub fn from_resources(lang_to_ftl: HashMap<LanguageIdentifier, Vec<(String, String)>>) -> Self {
+        Self::try_from_resources(lang_to_ftl)
+            .unwrap_or_else(|error| panic!("invalid in-memory Fluent resources: {error}"))
+    }
+
+    /// Creates a manager from in-memory Fluent sources, returning parse and
+    /// bundle errors instead of panicking.
+    ///
+    /// Module metadata is leaked for the rest of the process, because managers
+    /// hold `'static` module registrations. Managers built from the same
+    /// domain and sources share one module, so repeated calls leak it once.
+    pub fn try_from_resources(
+        lang_to_ftl: HashMap<LanguageIdentifier, Vec<(String, String)>>,
+    ) -> crate::localization::LocalizationErrorResult<Self> {
+        Ok(Self {
+            modules: super::in_memory::in_memory_modules(lang_to_ftl)?,
+            localizers: RwLock::default(),
+            selected_language: RwLock::default(),
+            fallback_language: None,
+            fallback_localizers: RwLock::default(),
+            language_changed_callbacks: Vec::new(),
+            bundle_options: BundleOptions::default(),
+        })
+    }
+
+    /// Sets the language consulted when a message is missing in the selected language.
+    ///
+    /// Fallback localizers are created from the already registered modules, so
+    /// lookups never trigger additional loading. Modules that do not support
+    /// `lang` are skipped.
+    pub fn set_fallback_language(&mut self, lang: LanguageIdentifier) {
+        let fallback_localizers = self.build_fallback_localizers(&lang);
+        self.fallback_language = Some(lang);
+        *self.fallback_localizers.get_mut() = fallback_localizers;
+    }
+
+    /// Creates a localizer for `lang` from every module that supports it.
+    fn build_fallback_localizers(&self, lang: &LanguageIdentifier) -> Vec<ManagedLocalizer> {
+        let mut fallback_localizers = Vec::with_capacity(self.modules.len());
+
+        for module in &self.modules {
+            let data = module.data();
+            let Some(localizer) = self.create_localizer(*module) else {
+                tracing::error!(
+                    "Module '{}' failed to create a runtime localizer for fallback language '{}': {}",
+                    data.name,
+                    lang,
+                    unexpected_missing_localizer(data)
+                );
+                continue;
+            };
+
+            match localizer.select_language(lang) {
+                Ok(()) => fallback_localizers.push((data, localizer)),
+                Err(error) => {
+                    tracing::debug!(
+                        "Module '{}' failed to set fallback language '{}': {}",
+                        data.name,
+                        lang,
+                        error
+                    );
+                },
+            }
+        }
+
+        if fallback_localizers.is_empty() {
+            tracing::warn!(
+                "No i18n modules support fallback language '{}'; modules checked: {}",
+                lang,
+                format_module_support_list(
+                    &self
+                        .modules
+                        .iter()
+                        .map(|module| module.data())
+                        .collect::<Vec<_>>()
+                )
+            );
+        }
+
+        fallback_localizers
+    }
+
+    /// Sets whether formatted placeables are wrapped in Unicode isolation
+    /// marks (U+2068/U+2069).
+    ///
+    /// Isolation is on by default, matching `fluent-bundle`. Turning it off
+    /// suits plain-text output where the invisible marks would otherwise leak
+    /// into logs or string comparisons. Active and fallback localizers pick up
+    /// the change immediately.
+    pub fn set_use_isolating(&mut self, use_isolating: bool) {
+        self.bundle_options.set_use_isolating(use_isolating);
+        self.apply_bundle_options();
+    }
+
+    /// Registers a custom Fluent function, such as `DATETIME`, for every
+    /// bundle this manager's localizers build.
+    ///
+    /// A function registered under the same name earlier is replaced. Active
+    /// and fallback localizers pick up the change immediately.
+    pub fn add_function<F>(&mut self, name: impl Into<String>, function: F)
+    where
+        F: for<'a> Fn(
+                &[fluent_bundle::FluentValue<'a>],
+                &fluent_bundle::FluentArgs,
+            ) -> fluent_bundle::FluentValue<'a>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.bundle_options.add_function(name, function);
+        self.apply_bundle_options();
+    }
+
+    /// Replaces all bundle settings at once, for integrations that keep their
+    /// own [`BundleOptions`].
+    pub fn set_bundle_options(&mut self, options: BundleOptions) {
+        self.bundle_options = options;
+        self.apply_bundle_options();
+    }
+
+    /// Returns the settings applied to every bundle this manager builds.
+    pub fn bundle_options(&self) -> &BundleOptions {
+        &self.bundle_options
+    }
+
+    fn apply_bundle_options(&self) {
+        let localizers = self.localizers.read();
+        let fallback_localizers = self.fallback_localizers.read();
+        for (_, localizer) in localizers.iter().chain(fallback_localizers.iter()) {
+            localizer.set_bundle_options(&self.bundle_options);
+        }
+    }
+
+    /// Creates `module`'s localizer with this manager's bundle options applied.
+    fn create_localizer(&self, module: &dyn I18nModuleRegistration) -> Option<Box<dyn Localizer>> {
+        let localizer = module.create_localizer()?;
+        localizer.set_bundle_options(&self.bundle_options);
+        Some(localizer)
+    }
+
+    /// Registers `callback` to run after each successful language selection.
+    ///
+    /// Callbacks run in registration order with the selected language, once
+    /// the new localizers are active, so lookups made from a callback already
+    /// use the new language. Failed selections keep the previous language and
+    /// run no callbacks.
+    pub fn on_language_changed(&mut self, callback: LanguageChangedCallback) {
+        self.language_changed_callbacks.push(callback);
+    }
+
+    /// Returns the configured fallback language, if any.
+    pub fn fallback_language(&self) -> Option<&LanguageIdentifier> {
+        self.fallback_language.as_ref()
+    }
+
+    /// Returns the languages this manager's modules provide, sorted and
+    /// deduplicated.
+    ///
+    /// Only modules that count as application content support contribute, so
+    /// runtime follower modules such as language-name labels do not add
+    /// entries to a language picker.
+    pub fn loaded_languages(&self) -> Vec<LanguageIdentifier> {
+        let mut languages = self
+            .modules
+            .iter()
+            .filter(|module| module.contributes_to_language_selection())
+            .flat_map(|module| module.data().supported_languages.iter().cloned())
+            .collect::<Vec<_>>();
+        languages.sort_by_key(ToString::to_string);
+        languages.dedup();
+        languages
+    }
+
+    /// Returns the sorted domains whose modules declare support for `lang`.
+    pub fn loaded_domains(&self, lang: &LanguageIdentifier) -> Vec<String> {
+        let mut domains = self
+            .modules
+            .iter()
+            .map(|module| module.data())
+            .filter(|data| data.supported_languages.contains(lang))
+            .map(|data| data.domain().to_string())
+            .collect::<Vec<_>>();
+        domains.sort();
+        domains.dedup();
+        domains
+    }
+
     /// Selects a language for all localizers.
     ///
     /// By default this is best-effort: modules that report
@@ -233,6 +456,79 @@ impl FluentManager {
         self.select_language_with_policy(lang, LanguageSelectionPolicy::BestEffort)
     }
 
+    /// Parses and validates a language tag such as `"en-US"`.
+    ///
+    /// Surrounding whitespace is ignored and casing is normalized, so a locale
+    /// stored in a config file can be passed as-is. Empty or malformed input
+    /// returns [`LocalizationError::InvalidLanguageIdentifier`].
+    ///
+    /// [`LocalizationError::InvalidLanguageIdentifier`]: crate::localization::LocalizationError::InvalidLanguageIdentifier
+    pub fn parse_language(
+        lang: &str,
+    ) -> crate::localization::LocalizationErrorResult<LanguageIdentifier> {
+        let trimmed = lang.trim();
+        if trimmed.is_empty() {
+            return Err(
+                crate::localization::LocalizationError::invalid_language_identifier(
+                    lang,
+                    "language tag is empty",
+                ),
+            );
+        }
+
+        trimmed.parse::<LanguageIdentifier>().map_err(|error| {
+            crate::localization::LocalizationError::invalid_language_identifier(
+                lang,
+                error.to_string(),
+            )
+        })
+    }
+
+    /// Parses `lang` with [`Self::parse_language`] and selects it like
+    /// [`Self::select_language`].
+    pub fn select_language_str(
+        &self,
+        lang: &str,
+    ) -> crate::localization::LocalizationErrorResult<()> {
+        self.select_language(&Self::parse_language(lang)?)
+    }
+
+    /// Parses `lang` with [`Self::parse_language`] and selects it like
+    /// [`Self::select_language_strict`].
+    pub fn select_language_strict_str(
+        &self,
+        lang: &str,
+    ) -> crate::localization::LocalizationErrorResult<()> {
+        self.select_language_strict(&Self::parse_language(lang)?)
+    }
+
+    /// Negotiates a language from an ordered preference list and selects it.
+    ///
+    /// `requested` is matched against [`Self::loaded_languages`] with
+    /// [`crate::fallback::negotiate_language`], so `["de-AT", "fr", "en"]`
+    /// selects `de` or `de-DE` when either is loaded before considering French.
+    /// The negotiated language is then selected best-effort, like
+    /// [`Self::select_language`].
+    pub fn select_languages(
+        &self,
+        requested: &[LanguageIdentifier],
+    ) -> crate::localization::LocalizationErrorResult<()> {
+        let loaded = self.loaded_languages();
+        let Some(lang) = crate::fallback::negotiate_language(requested, &loaded) else {
+            tracing::warn!(
+                "No loaded language matches any requested language ({}); loaded languages: {}",
+                format_supported_languages(requested),
+                format_supported_languages(&loaded)
+            );
+            return Err(
+                crate::localization::LocalizationError::LanguageNotSupported(
+                    requested.first().cloned().unwrap_or_default(),
+                ),
+            );
+        };
+        self.select_language(&lang)
+    }
+
     /// Selects a language for all localizers and fails if any module rejects it.
     pub fn select_language_strict(
         &self,
@@ -277,6 +573,31 @@ impl FluentManager {
         policy: LanguageSelectionPolicy,
         support_requirement: LanguageSupportRequirement,
     ) -> crate::localization::LocalizationErrorResult<()> {
+        let next_localizers = self.build_localizers(lang, policy, support_requirement)?;
+
+        {
+            let mut localizers = self.localizers.write();
+            *localizers = next_localizers;
+            *self.selected_language.write() = Some(LanguageSelection {
+                language: lang.clone(),
+                policy,
+                support_requirement,
+            });
+        }
+        for callback in &self.language_changed_callbacks {
+            callback(lang);
+        }
+        Ok(())
+    }
+
+    /// Creates a localizer for every module and selects `lang` in each,
+    /// leaving the active localizers untouched.
+    fn build_localizers(
+        &self,
+        lang: &LanguageIdentifier,
+        policy: LanguageSelectionPolicy,
+        support_requirement: LanguageSupportRequirement,
+    ) -> crate::localization::LocalizationErrorResult<Vec<ManagedLocalizer>> {
         let mut next_localizers = Vec::with_capacity(self.modules.len());
         let mut selected_modules = Vec::with_capacity(self.modules.len());
         let checked_modules = self
@@ -292,7 +613,7 @@ impl FluentManager {
 
         for module in &self.modules {
             let data = module.data();
-            let Some(localizer) = module.create_localizer() else {
+            let Some(localizer) = self.create_localizer(*module) else {
                 let error = unexpected_missing_localizer(data);
                 tracing::error!(
                     "Module '{}' failed to create a runtime localizer for '{}': {}",
@@ -400,13 +721,60 @@ impl FluentManager {
             );
         }
 
-        *self.localizers.write() = next_localizers;
+        Ok(next_localizers)
+    }
+
+    /// Rebuilds the active and fallback localizers from each module's current
+    /// resources and swaps them in together.
+    ///
+    /// The new bundles are built without holding a lock, and the write locks
+    /// are only taken for the swap, so a concurrent lookup sees either the old
+    /// or the new resources, never a mix. Modules that read their FTL files
+    /// when a localizer is created pick up edits; modules whose resources are
+    /// compiled into the binary rebuild identical bundles, which makes this a
+    /// no-op for them. The selected language is kept, so
+    /// [`Self::on_language_changed`] callbacks do not run.
+    ///
+    /// The language is rebuilt under the policy it was selected with, so a
+    /// language chosen by [`Self::select_language_strict`] fails to reload if
+    /// any module now rejects it. If the selected language can no longer be
+    /// rebuilt, the previous localizers stay active and the error is returned.
+    /// A language selected on another thread while the reload runs takes
+    /// precedence over it.
+    pub fn reload(&self) -> crate::localization::LocalizationErrorResult<()> {
+        let selection = self.selected_language.read().clone();
+        let next_localizers = selection
+            .as_ref()
+            .map(|selection| {
+                self.build_localizers(
+                    &selection.language,
+                    selection.policy,
+                    selection.support_requirement,
+                )
+            })
+            .transpose()?;
+        let next_fallback_localizers = self
+            .fallback_language
+            .as_ref()
+            .map(|lang| self.build_fallback_localizers(lang));
+
+        let mut localizers = self.localizers.write();
+        let mut fallback_localizers = self.fallback_localizers.write();
+        if let Some(next_localizers) = next_localizers
+            && *self.selected_language.read() == selection
+        {
+            *localizers = next_localizers;
+        }
+        if let Some(next_fallback_localizers) = next_fallback_localizers {
+            *fallback_localizers = next_fallback_localizers;
+        }
         Ok(())
     }
 
     /// Localizes a message by its validated static ID.
     ///
-    /// This searches localizers in discovery order and returns the first match.
+    /// This searches localizers in discovery order and returns the first match,
+    /// then repeats the search in the fallback language when one is set.
     /// Use [`Self::localize_in_domain`] when the caller needs domain-scoped
     /// lookup instead of first-match behavior.
     pub fn localize<'a>(
@@ -414,12 +782,12 @@ impl FluentManager {
         id: StaticFluentEntryId,
         args: Option<&FluentArgumentMap<'a>>,
     ) -> Option<String> {
-        for (_, localizer) in self.localizers.read().iter() {
-            if let Some(message) = localizer.localize(id, args) {
-                return Some(message);
-            }
-        }
-        None
+        let localizers = self.localizers.read();
+        let fallback_localizers = self.fallback_localizers.read();
+        localizers
+            .iter()
+            .chain(fallback_localizers.iter())
+            .find_map(|(_, localizer)| localizer.localize(id, args))
     }
 
     /// Localizes a message by its validated static ID within a validated static domain.
@@ -429,21 +797,106 @@ impl FluentManager {
         id: StaticFluentEntryId,
         args: Option<&FluentArgumentMap<'a>>,
     ) -> Option<String> {
-        for (data, localizer) in self.localizers.read().iter() {
-            if data.domain == domain
-                && let Some(message) = localizer.localize(id, args)
-            {
-                return Some(message);
-            }
-        }
+        let localizers = self.localizers.read();
+        let fallback_localizers = self.fallback_localizers.read();
+        localize_in_domain_with(&localizers, &fallback_localizers, domain, id, args)
+    }
+
+    /// Returns whether a localizer for `domain` resolves message `id` to a
+    /// value in the selected or fallback language, without formatting it.
+    ///
+    /// Unlike [`Self::localize_in_domain`] this needs no arguments, so it
+    /// suits tests that check every generated id has a translation.
+    pub fn has_message_in_domain(
+        &self,
+        domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+    ) -> bool {
+        let localizers = self.localizers.read();
+        let fallback_localizers = self.fallback_localizers.read();
+        localizers
+            .iter()
+            .chain(fallback_localizers.iter())
+            .filter(|(data, _)| data.domain == domain)
+            .any(|(_, localizer)| localizer.has_message(id))
+    }
+
+    /// Returns whether a localizer for `domain` is loaded in the selected or
+    /// fallback language.
+    pub fn has_domain(&self, domain: StaticFluentDomain) -> bool {
+        let localizers = self.localizers.read();
+        let fallback_localizers = self.fallback_localizers.read();
+        localizers
+            .iter()
+            .chain(fallback_localizers.iter())
+            .any(|(data, _)| data.domain == domain)
+    }
 
-        None
+    /// Localizes the `attribute` of a message by its validated static ID.
+    ///
+    /// Localizers are searched like [`Self::localize`], so the first module
+    /// whose message defines the attribute wins.
+    pub fn localize_attribute<'a>(
+        &self,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&FluentArgumentMap<'a>>,
+    ) -> Option<String> {
+        let localizers = self.localizers.read();
+        let fallback_localizers = self.fallback_localizers.read();
+        localizers
+            .iter()
+            .chain(fallback_localizers.iter())
+            .find_map(|(_, localizer)| localizer.localize_attribute(id, attribute, args))
+    }
+
+    /// Localizes the `attribute` of a message within a validated static domain.
+    pub fn localize_attribute_in_domain<'a>(
+        &self,
+        domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&FluentArgumentMap<'a>>,
+    ) -> Option<String> {
+        let localizers = self.localizers.read();
+        let fallback_localizers = self.fallback_localizers.read();
+        localizers
+            .iter()
+            .chain(fallback_localizers.iter())
+            .filter(|(data, _)| data.domain == domain)
+            .find_map(|(_, localizer)| localizer.localize_attribute(id, attribute, args))
+    }
+
+    /// Localizes a message by its validated static ID in every loaded language.
+    ///
+    /// Each language from [`Self::loaded_languages`] is searched in discovery
+    /// order, like [`Self::localize`], but without the fallback language, so
+    /// languages that lack the message map to `None`. The active language is
+    /// left untouched. Localizers are built for each call, so this suits
+    /// translation review tooling rather than hot lookup paths.
+    pub fn localize_all<'a>(
+        &self,
+        id: StaticFluentEntryId,
+        args: Option<&FluentArgumentMap<'a>>,
+    ) -> BTreeMap<LanguageIdentifier, Option<String>> {
+        self.loaded_languages()
+            .into_iter()
+            .map(|lang| {
+                let message = self.modules.iter().find_map(|module| {
+                    let localizer = self.create_localizer(*module)?;
+                    localizer.select_language(&lang).ok()?;
+                    localizer.localize(id, args)
+                });
+                (lang, message)
+            })
+            .collect()
     }
 
     /// Runs a group of domain-scoped lookups against the current localizer set.
     ///
-    /// The active localizer list is read-locked for the entire callback so
-    /// nested typed-message lookups cannot observe a partially switched locale.
+    /// The active and fallback localizer lists are read-locked for the entire
+    /// callback so nested typed-message lookups cannot observe a partially
+    /// switched locale or reload.
     pub fn with_lookup(
         &self,
         f: &mut dyn FnMut(
@@ -455,23 +908,30 @@ impl FluentManager {
         ),
     ) {
         let localizers = self.localizers.read();
+        let fallback_localizers = self.fallback_localizers.read();
         let mut lookup = |domain: StaticFluentDomain,
                           id: StaticFluentEntryId,
                           args: Option<&FluentArgumentMap<'_>>| {
-            for (data, localizer) in localizers.iter() {
-                if data.domain == domain
-                    && let Some(message) = localizer.localize(id, args)
-                {
-                    return Some(message);
-                }
-            }
-
-            None
+            localize_in_domain_with(&localizers, &fallback_localizers, domain, id, args)
         };
         f(&mut lookup);
     }
 }
 
+fn localize_in_domain_with(
+    localizers: &[ManagedLocalizer],
+    fallback_localizers: &[ManagedLocalizer],
+    domain: StaticFluentDomain,
+    id: StaticFluentEntryId,
+    args: Option<&FluentArgumentMap<'_>>,
+) -> Option<String> {
+    localizers
+        .iter()
+        .chain(fallback_localizers)
+        .filter(|(data, _)| data.domain == domain)
+        .find_map(|(_, localizer)| localizer.localize(id, args))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -517,6 +977,27 @@ mod tests {
         supported_languages: &[langid!("en"), langid!("fr")],
         namespaces: &[],
     };
+    static MANAGER_BILINGUAL_DATA: ModuleData = ModuleData {
+        name: "manager-bilingual",
+        domain: crate::__macro::static_domain("manager-bilingual"),
+        supported_languages: &[langid!("en"), langid!("fr")],
+        namespaces: &[],
+    };
+    static MANAGER_RELOADABLE_DATA: ModuleData = ModuleData {
+        name: "manager-reloadable",
+        domain: crate::__macro::static_domain("manager-reloadable"),
+        supported_languages: &[langid!("en"), langid!("fr")],
+        namespaces: &[],
+    };
+    static MANAGER_RELOADABLE_REVISION: Mutex<&'static str> = Mutex::new("v1");
+    static MANAGER_WITHDRAWING_DATA: ModuleData = ModuleData {
+        name: "manager-withdrawing",
+        domain: crate::__macro::static_domain("manager-withdrawing"),
+        supported_languages: &[langid!("en"), langid!("fr")],
+        namespaces: &[],
+    };
+    static MANAGER_WITHDRAWING_FRENCH: std::sync::atomic::AtomicBool =
+        std::sync::atomic::AtomicBool::new(false);
     static MANAGER_INLINE_METADATA: StaticModuleDescriptor =
         StaticModuleDescriptor::new(&MANAGER_INLINE_METADATA_DATA);
 
@@ -532,6 +1013,18 @@ mod tests {
         id: &'static str,
         value: &'static str,
     }
+    struct ManagerBilingualModule;
+    #[derive(Default)]
+    struct ManagerBilingualLocalizer {
+        language: Mutex<Option<LanguageIdentifier>>,
+    }
+    struct ManagerReloadableModule;
+    struct ManagerReloadableLocalizer {
+        revision: &'static str,
+        language: Mutex<Option<LanguageIdentifier>>,
+    }
+    struct ManagerWithdrawingModule;
+    struct ManagerWithdrawingLocalizer;
     struct ManagerScopedLookupLocalizer {
         language: &'static str,
         child_seen: Option<Mutex<mpsc::Sender<()>>>,
@@ -574,6 +1067,87 @@ mod tests {
         }
     }
 
+    impl Localizer for ManagerBilingualLocalizer {
+        fn select_language(&self, lang: &LanguageIdentifier) -> Result<(), LocalizationError> {
+            if !MANAGER_BILINGUAL_DATA.supported_languages.contains(lang) {
+                return Err(LocalizationError::LanguageNotSupported(lang.clone()));
+            }
+            *self
+                .language
+                .lock()
+                .expect("test language lock should not be poisoned") = Some(lang.clone());
+            Ok(())
+        }
+
+        fn localize<'a>(
+            &self,
+            id: StaticFluentEntryId,
+            _args: Option<&FluentArgumentMap<'a>>,
+        ) -> Option<String> {
+            let language = self
+                .language
+                .lock()
+                .expect("test language lock should not be poisoned")
+                .clone()?;
+            match (language.language.as_str(), id.as_str()) {
+                ("en", "hello") => Some("Hello".to_string()),
+                ("en", "goodbye") => Some("Goodbye".to_string()),
+                ("fr", "hello") => Some("Bonjour".to_string()),
+                _ => None,
+            }
+        }
+    }
+
+    impl Localizer for ManagerWithdrawingLocalizer {
+        fn select_language(&self, lang: &LanguageIdentifier) -> Result<(), LocalizationError> {
+            let withdrawn = lang == &langid!("fr")
+                && MANAGER_WITHDRAWING_FRENCH.load(std::sync::atomic::Ordering::SeqCst);
+            if withdrawn || !MANAGER_WITHDRAWING_DATA.supported_languages.contains(lang) {
+                return Err(LocalizationError::LanguageNotSupported(lang.clone()));
+            }
+            Ok(())
+        }
+
+        fn localize<'a>(
+            &self,
+            _id: StaticFluentEntryId,
+            _args: Option<&FluentArgumentMap<'a>>,
+        ) -> Option<String> {
+            None
+        }
+    }
+
+    impl Localizer for ManagerReloadableLocalizer {
+        fn select_language(&self, lang: &LanguageIdentifier) -> Result<(), LocalizationError> {
+            if !MANAGER_RELOADABLE_DATA.supported_languages.contains(lang) {
+                return Err(LocalizationError::LanguageNotSupported(lang.clone()));
+            }
+            *self
+                .language
+                .lock()
+                .expect("test language lock should not be poisoned") = Some(lang.clone());
+            Ok(())
+        }
+
+        fn localize<'a>(
+            &self,
+            id: StaticFluentEntryId,
+            _args: Option<&FluentArgumentMap<'a>>,
+        ) -> Option<String> {
+            let language = self
+                .language
+                .lock()
+                .expect("test language lock should not be poisoned")
+                .clone()?;
+            match (language.language.as_str(), id.as_str()) {
+                ("en", "greeting" | "farewell") | ("fr", "greeting") => {
+                    Some(format!("{language}-{}-{}", id.as_str(), self.revision))
+                },
+                _ => None,
+            }
+        }
+    }
+
     impl ManagerScopedLookupLocalizer {
         fn blocking(
             language: &'static str,
@@ -656,6 +1230,47 @@ mod tests {
         }
     }
 
+    impl I18nModuleDescriptor for ManagerBilingualModule {
+        fn data(&self) -> &'static ModuleData {
+            &MANAGER_BILINGUAL_DATA
+        }
+    }
+
+    impl I18nModule for ManagerBilingualModule {
+        fn create_localizer(&self) -> Box<dyn Localizer> {
+            Box::new(ManagerBilingualLocalizer::default())
+        }
+    }
+
+    impl I18nModuleDescriptor for ManagerWithdrawingModule {
+        fn data(&self) -> &'static ModuleData {
+            &MANAGER_WITHDRAWING_DATA
+        }
+    }
+
+    impl I18nModule for ManagerWithdrawingModule {
+        fn create_localizer(&self) -> Box<dyn Localizer> {
+            Box::new(ManagerWithdrawingLocalizer)
+        }
+    }
+
+    impl I18nModuleDescriptor for ManagerReloadableModule {
+        fn data(&self) -> &'static ModuleData {
+            &MANAGER_RELOADABLE_DATA
+        }
+    }
+
+    impl I18nModule for ManagerReloadableModule {
+        fn create_localizer(&self) -> Box<dyn Localizer> {
+            Box::new(ManagerReloadableLocalizer {
+                revision: *MANAGER_RELOADABLE_REVISION
+                    .lock()
+                    .expect("test revision lock should not be poisoned"),
+                language: Mutex::new(None),
+            })
+        }
+    }
+
     impl I18nModuleDescriptor for ManagerSharedDomainModule {
         fn data(&self) -> &'static ModuleData {
             self.data
@@ -673,6 +1288,9 @@ mod tests {
 
     static MANAGER_INLINE_RUNTIME: ManagerInlineRuntimeModule = ManagerInlineRuntimeModule;
     static MANAGER_INLINE_FOLLOWER: ManagerInlineFollowerModule = ManagerInlineFollowerModule;
+    static MANAGER_BILINGUAL: ManagerBilingualModule = ManagerBilingualModule;
+    static MANAGER_RELOADABLE: ManagerReloadableModule = ManagerReloadableModule;
+    static MANAGER_WITHDRAWING: ManagerWithdrawingModule = ManagerWithdrawingModule;
     static MANAGER_SHARED_DOMAIN_FIRST: ManagerSharedDomainModule = ManagerSharedDomainModule {
         data: &MANAGER_SHARED_DOMAIN_FIRST_DATA,
         id: "first-message",
@@ -746,11 +1364,52 @@ mod tests {
         assert!(manager.modules.is_empty());
     }
 
+    #[test]
+    fn loaded_languages_and_domains_come_from_manager_modules() {
+        let manager = FluentManager {
+            modules: vec![
+                &MANAGER_BILINGUAL as &dyn I18nModuleRegistration,
+                &MANAGER_INLINE_FOLLOWER,
+                &MANAGER_SHARED_DOMAIN_FIRST,
+                &MANAGER_SHARED_DOMAIN_SECOND,
+            ],
+            localizers: RwLock::default(),
+            selected_language: RwLock::default(),
+            fallback_language: None,
+            fallback_localizers: RwLock::default(),
+            language_changed_callbacks: Vec::new(),
+            bundle_options: BundleOptions::default(),
+        };
+
+        assert_eq!(
+            manager.loaded_languages(),
+            vec![langid!("en"), langid!("fr")]
+        );
+        assert_eq!(
+            manager.loaded_domains(&langid!("en")),
+            vec![
+                "manager-bilingual".to_string(),
+                "manager-inline-follower".to_string(),
+                "manager-shared-domain".to_string(),
+            ]
+        );
+        assert_eq!(
+            manager.loaded_domains(&langid!("fr")),
+            vec!["manager-bilingual".to_string()]
+        );
+        assert!(manager.loaded_domains(&langid!("de")).is_empty());
+    }
+
     #[test]
     fn non_contributing_runtime_modules_follow_selection_without_declaring_support() {
         let manager = FluentManager {
             modules: vec![&MANAGER_INLINE_FOLLOWER as &dyn I18nModuleRegistration],
             localizers: RwLock::default(),
+            selected_language: RwLock::default(),
+            fallback_language: None,
+            fallback_localizers: RwLock::default(),
+            language_changed_callbacks: Vec::new(),
+            bundle_options: BundleOptions::default(),
         };
 
         let err = manager
@@ -766,6 +1425,11 @@ mod tests {
         let manager = FluentManager {
             modules: vec![&MANAGER_INLINE_FOLLOWER as &dyn I18nModuleRegistration],
             localizers: RwLock::default(),
+            selected_language: RwLock::default(),
+            fallback_language: None,
+            fallback_localizers: RwLock::default(),
+            language_changed_callbacks: Vec::new(),
+            bundle_options: BundleOptions::default(),
         };
 
         manager
@@ -783,6 +1447,11 @@ mod tests {
         let manager = FluentManager {
             modules: vec![&MANAGER_INLINE_RUNTIME as &dyn I18nModuleRegistration],
             localizers: RwLock::default(),
+            selected_language: RwLock::default(),
+            fallback_language: None,
+            fallback_localizers: RwLock::default(),
+            language_changed_callbacks: Vec::new(),
+            bundle_options: BundleOptions::default(),
         };
 
         manager
@@ -811,6 +1480,11 @@ mod tests {
                 &MANAGER_SHARED_DOMAIN_SECOND as &dyn I18nModuleRegistration,
             ],
             localizers: RwLock::default(),
+            selected_language: RwLock::default(),
+            fallback_language: None,
+            fallback_localizers: RwLock::default(),
+            language_changed_callbacks: Vec::new(),
+            bundle_options: BundleOptions::default(),
         };
 
         manager
@@ -835,6 +1509,193 @@ mod tests {
         );
     }
 
+    #[test]
+    fn missing_messages_fall_back_to_the_fallback_language() {
+        let mut manager = FluentManager {
+            modules: vec![&MANAGER_BILINGUAL as &dyn I18nModuleRegistration],
+            localizers: RwLock::default(),
+            selected_language: RwLock::default(),
+            fallback_language: None,
+            fallback_localizers: RwLock::default(),
+            language_changed_callbacks: Vec::new(),
+            bundle_options: BundleOptions::default(),
+        };
+        manager.set_fallback_language(langid!("en"));
+        manager
+            .select_language(&langid!("fr"))
+            .expect("bilingual module should support French");
+
+        assert_eq!(manager.fallback_language(), Some(&langid!("en")));
+        assert_eq!(
+            manager.localize(static_entry("hello"), None),
+            Some("Bonjour".to_string())
+        );
+        assert_eq!(
+            manager.localize(static_entry("goodbye"), None),
+            Some("Goodbye".to_string())
+        );
+        assert_eq!(
+            manager.localize_in_domain(
+                static_domain("manager-bilingual"),
+                static_entry("goodbye"),
+                None
+            ),
+            Some("Goodbye".to_string())
+        );
+        assert_eq!(
+            manager.localize_in_domain(
+                static_domain("manager-inline-runtime"),
+                static_entry("goodbye"),
+                None
+            ),
+            None
+        );
+        assert_eq!(manager.localize(static_entry("missing"), None), None);
+
+        let mut looked_up = None;
+        manager.with_lookup(&mut |lookup| {
+            looked_up = lookup(
+                static_domain("manager-bilingual"),
+                static_entry("goodbye"),
+                None,
+            );
+        });
+        assert_eq!(looked_up, Some("Goodbye".to_string()));
+    }
+
+    #[test]
+    fn missing_messages_stay_missing_without_a_fallback_language() {
+        let manager = FluentManager {
+            modules: vec![&MANAGER_BILINGUAL as &dyn I18nModuleRegistration],
+            localizers: RwLock::default(),
+            selected_language: RwLock::default(),
+            fallback_language: None,
+            fallback_localizers: RwLock::default(),
+            language_changed_callbacks: Vec::new(),
+            bundle_options: BundleOptions::default(),
+        };
+        manager
+            .select_language(&langid!("fr"))
+            .expect("bilingual module should support French");
+
+        assert_eq!(manager.fallback_language(), None);
+        assert_eq!(manager.localize(static_entry("goodbye"), None), None);
+    }
+
+    #[test]
+    fn reload_rebuilds_active_and_fallback_localizers_from_current_resources() {
+        let mut manager = FluentManager {
+            modules: vec![&MANAGER_RELOADABLE as &dyn I18nModuleRegistration],
+            localizers: RwLock::default(),
+            selected_language: RwLock::default(),
+            fallback_language: None,
+            fallback_localizers: RwLock::default(),
+            language_changed_callbacks: Vec::new(),
+            bundle_options: BundleOptions::default(),
+        };
+        manager.set_fallback_language(langid!("en"));
+        let callback_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let callback_count_for_hook = Arc::clone(&callback_count);
+        manager.on_language_changed(Box::new(move |_| {
+            callback_count_for_hook.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }));
+        manager
+            .select_language(&langid!("fr"))
+            .expect("reloadable module should support French");
+
+        *MANAGER_RELOADABLE_REVISION
+            .lock()
+            .expect("test revision lock should not be poisoned") = "v2";
+        assert_eq!(
+            manager.localize(static_entry("greeting"), None),
+            Some("fr-greeting-v1".to_string())
+        );
+
+        manager.reload().expect("reload should succeed");
+
+        assert_eq!(
+            manager.localize(static_entry("greeting"), None),
+            Some("fr-greeting-v2".to_string())
+        );
+        assert_eq!(
+            manager.localize(static_entry("farewell"), None),
+            Some("en-farewell-v2".to_string())
+        );
+        assert_eq!(
+            callback_count.load(std::sync::atomic::Ordering::SeqCst),
+            1,
+            "reloading keeps the language, so change callbacks should not run"
+        );
+    }
+
+    #[test]
+    fn reload_keeps_the_policy_the_language_was_selected_with() {
+        let manager = FluentManager {
+            modules: vec![
+                &MANAGER_BILINGUAL as &dyn I18nModuleRegistration,
+                &MANAGER_WITHDRAWING as &dyn I18nModuleRegistration,
+            ],
+            localizers: RwLock::default(),
+            selected_language: RwLock::default(),
+            fallback_language: None,
+            fallback_localizers: RwLock::default(),
+            language_changed_callbacks: Vec::new(),
+            bundle_options: BundleOptions::default(),
+        };
+        manager
+            .select_language_strict(&langid!("fr"))
+            .expect("every module should support French");
+
+        MANAGER_WITHDRAWING_FRENCH.store(true, std::sync::atomic::Ordering::SeqCst);
+        let result = manager.reload();
+        MANAGER_WITHDRAWING_FRENCH.store(false, std::sync::atomic::Ordering::SeqCst);
+
+        assert!(
+            result.is_err(),
+            "a strictly selected language should not reload partially"
+        );
+        assert_eq!(manager.localizers.read().len(), 2);
+        assert_eq!(
+            manager.localize(static_entry("hello"), None),
+            Some("Bonjour".to_string())
+        );
+    }
+
+    #[test]
+    fn reload_without_a_selected_language_keeps_the_manager_empty() {
+        let manager = FluentManager {
+            modules: vec![&MANAGER_BILINGUAL as &dyn I18nModuleRegistration],
+            localizers: RwLock::default(),
+            selected_language: RwLock::default(),
+            fallback_language: None,
+            fallback_localizers: RwLock::default(),
+            language_changed_callbacks: Vec::new(),
+            bundle_options: BundleOptions::default(),
+        };
+
+        manager.reload().expect("reload should succeed");
+
+        assert!(manager.localizers.read().is_empty());
+        assert_eq!(manager.localize(static_entry("hello"), None), None);
+    }
+
+    #[test]
+    fn unsupported_fallback_language_adds_no_fallback_localizers() {
+        let mut manager = FluentManager {
+            modules: vec![&MANAGER_BILINGUAL as &dyn I18nModuleRegistration],
+            localizers: RwLock::default(),
+            selected_language: RwLock::default(),
+            fallback_language: None,
+            fallback_localizers: RwLock::default(),
+            language_changed_callbacks: Vec::new(),
+            bundle_options: BundleOptions::default(),
+        };
+        manager.set_fallback_language(langid!("de"));
+
+        assert_eq!(manager.fallback_language(), Some(&langid!("de")));
+        assert!(manager.fallback_localizers.read().is_empty());
+    }
+
     #[test]
     fn with_lookup_holds_active_localizers_for_the_entire_callback() {
         let (child_seen_tx, child_seen_rx) = mpsc::channel();
@@ -849,6 +1710,11 @@ mod tests {
                     continue_child_rx,
                 )) as Box<dyn Localizer>,
             )]),
+            selected_language: RwLock::default(),
+            fallback_language: None,
+            fallback_localizers: RwLock::default(),
+            language_changed_callbacks: Vec::new(),
+            bundle_options: BundleOptions::default(),
         });
 
         let render_manager = Arc::clone(&manager);
@@ -926,4 +1792,453 @@ mod tests {
             Some("fr-parent".to_string())
         );
     }
+
+    fn in_memory_resources(
+        sources: &[(LanguageIdentifier, &str, &str)],
+    ) -> HashMap<LanguageIdentifier, Vec<(String, String)>> {
+        let mut resources: HashMap<LanguageIdentifier, Vec<(String, String)>> = HashMap::new();
+        for (lang, domain, content) in sources {
+            resources
+                .entry(lang.clone())
+                .or_default()
+                .push(((*domain).to_string(), (*content).to_string()));
+        }
+        resources
+    }
+
+    #[test]
+    fn in_memory_resources_localize_without_discovery() {
+        let manager = FluentManager::from_resources(in_memory_resources(&[
+            (
+                langid!("en"),
+                "manager-memory-app",
+                "hello = Hello, { $name }!\n",
+            ),
+            (langid!("en"), "manager-memory-app", "bye = Goodbye\n"),
+            (
+                langid!("en"),
+                "manager-memory-lib",
+                "hello = Library hello\n",
+            ),
+        ]));
+
+        assert_eq!(manager.localize(static_entry("hello"), None), None);
+        manager
+            .select_language(&langid!("en-GB"))
+            .expect("en-GB should resolve to the en resources");
+
+        let args =
+            FluentArgumentMap::from([(crate::__macro::static_argument_name("name"), "Ada".into())]);
+        assert_eq!(
+            manager.localize_in_domain(
+                static_domain("manager-memory-app"),
+                static_entry("hello"),
+                Some(&args)
+            ),
+            Some("Hello, \u{2068}Ada\u{2069}!".to_string())
+        );
+        assert_eq!(
+            manager.localize(static_entry("bye"), None),
+            Some("Goodbye".to_string())
+        );
+        assert_eq!(
+            manager.localize_in_domain(
+                static_domain("manager-memory-lib"),
+                static_entry("hello"),
+                None
+            ),
+            Some("Library hello".to_string())
+        );
+    }
+
+    #[test]
+    fn in_memory_resources_reuse_modules_built_from_the_same_sources() {
+        let sources = [(langid!("en"), "manager-memory-reuse", "hello = Hello\n")];
+        let first = FluentManager::from_resources(in_memory_resources(&sources));
+        let second = FluentManager::from_resources(in_memory_resources(&sources));
+        let changed = FluentManager::from_resources(in_memory_resources(&[(
+            langid!("en"),
+            "manager-memory-reuse",
+            "hello = Hi\n",
+        )]));
+
+        assert!(std::ptr::eq(
+            first.modules[0].data(),
+            second.modules[0].data()
+        ));
+        assert!(!std::ptr::eq(
+            first.modules[0].data(),
+            changed.modules[0].data()
+        ));
+
+        changed
+            .select_language(&langid!("en"))
+            .expect("en should be supported");
+        assert_eq!(
+            changed.localize(static_entry("hello"), None),
+            Some("Hi".to_string())
+        );
+    }
+
+    #[test]
+    fn in_memory_resources_localize_message_attributes() {
+        let mut manager = FluentManager::from_resources(in_memory_resources(&[
+            (
+                langid!("en"),
+                "manager-memory-attrs",
+                "login = Log in\n    .label = Username\n    .placeholder = { $hint }\n",
+            ),
+            (
+                langid!("fr"),
+                "manager-memory-attrs",
+                "login = Connexion\n    .label = Identifiant\n",
+            ),
+        ]));
+        manager.set_fallback_language(langid!("en"));
+        manager
+            .select_language(&langid!("fr"))
+            .expect("fr should be supported");
+
+        let domain = static_domain("manager-memory-attrs");
+        assert_eq!(
+            manager.localize_attribute(static_entry("login"), "label", None),
+            Some("Identifiant".to_string())
+        );
+        let args =
+            FluentArgumentMap::from([(crate::__macro::static_argument_name("hint"), "ada".into())]);
+        assert_eq!(
+            manager.localize_attribute_in_domain(
+                domain,
+                static_entry("login"),
+                "placeholder",
+                Some(&args)
+            ),
+            Some("ada".to_string())
+        );
+        assert_eq!(
+            manager.localize_attribute_in_domain(domain, static_entry("login"), "title", None),
+            None
+        );
+        assert_eq!(
+            manager.localize_attribute_in_domain(
+                static_domain("manager-memory-other"),
+                static_entry("login"),
+                "label",
+                None
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn in_memory_resources_exercise_fallback_and_unsupported_languages() {
+        let mut manager = FluentManager::from_resources(in_memory_resources(&[
+            (
+                langid!("en"),
+                "manager-memory",
+                "title = Title\nbody = Body\n",
+            ),
+            (langid!("fr"), "manager-memory", "title = Titre\n"),
+        ]));
+        manager.set_fallback_language(langid!("en"));
+        manager
+            .select_language(&langid!("fr"))
+            .expect("fr should be supported");
+
+        assert_eq!(
+            manager.localize(static_entry("title"), None),
+            Some("Titre".to_string())
+        );
+        assert_eq!(
+            manager.localize(static_entry("body"), None),
+            Some("Body".to_string())
+        );
+
+        let error = manager
+            .select_language(&langid!("ja"))
+            .expect_err("ja has no resources");
+        assert!(matches!(
+            error,
+            LocalizationError::LanguageNotSupported(lang) if lang == langid!("ja")
+        ));
+        assert_eq!(
+            manager.localize(static_entry("title"), None),
+            Some("Titre".to_string())
+        );
+    }
+
+    #[test]
+    fn bundle_options_reach_active_fallback_and_later_localizers() {
+        let mut manager = FluentManager::from_resources(in_memory_resources(&[
+            (
+                langid!("en"),
+                "manager-memory-options",
+                "greeting = Hi { $name }\nshout = { UPPER($name) }\n",
+            ),
+            (
+                langid!("fr"),
+                "manager-memory-options",
+                "greeting = Salut { $name }\n",
+            ),
+        ]));
+        manager.set_fallback_language(langid!("en"));
+        manager
+            .select_language(&langid!("fr"))
+            .expect("fr should be supported");
+
+        let mut args = FluentArgumentMap::default();
+        args.insert(
+            crate::__macro::static_argument_name("name"),
+            fluent_bundle::FluentValue::from("Ana"),
+        );
+        assert_eq!(
+            manager.localize(static_entry("greeting"), Some(&args)),
+            Some("Salut \u{2068}Ana\u{2069}".to_string())
+        );
+
+        manager.set_use_isolating(false);
+        manager.add_function("UPPER", |positional, _named| match positional.first() {
+            Some(fluent_bundle::FluentValue::String(value)) => {
+                fluent_bundle::FluentValue::from(value.to_uppercase())
+            },
+            _ => fluent_bundle::FluentValue::Error,
+        });
+        assert!(!manager.bundle_options().use_isolating());
+        assert_eq!(
+            manager.localize(static_entry("greeting"), Some(&args)),
+            Some("Salut Ana".to_string())
+        );
+        assert_eq!(
+            manager.localize(static_entry("shout"), Some(&args)),
+            Some("ANA".to_string())
+        );
+
+        manager
+            .select_language(&langid!("en"))
+            .expect("en should be supported");
+        assert_eq!(
+            manager.localize(static_entry("greeting"), Some(&args)),
+            Some("Hi Ana".to_string())
+        );
+
+        manager.set_bundle_options(BundleOptions::default());
+        assert_eq!(
+            manager.localize(static_entry("greeting"), Some(&args)),
+            Some("Hi \u{2068}Ana\u{2069}".to_string())
+        );
+    }
+
+    #[test]
+    fn has_message_in_domain_checks_selected_and_fallback_languages_without_arguments() {
+        let mut manager = FluentManager::from_resources(in_memory_resources(&[
+            (
+                langid!("en"),
+                "manager-memory-has",
+                "greeting = Hi { $name }\nfarewell = Bye\nlabel-only =\n    .title = Title\n",
+            ),
+            (
+                langid!("fr"),
+                "manager-memory-has",
+                "greeting = Salut { $name }\n",
+            ),
+        ]));
+        manager
+            .select_language(&langid!("fr"))
+            .expect("fr should be supported");
+        let domain = static_domain("manager-memory-has");
+
+        assert!(manager.has_message_in_domain(domain, static_entry("greeting")));
+        assert!(!manager.has_message_in_domain(domain, static_entry("farewell")));
+        assert!(!manager.has_message_in_domain(static_domain("other"), static_entry("greeting")));
+        assert!(manager.has_domain(domain));
+        assert!(!manager.has_domain(static_domain("other")));
+
+        manager.set_fallback_language(langid!("en"));
+        assert!(manager.has_message_in_domain(domain, static_entry("farewell")));
+        assert!(!manager.has_message_in_domain(domain, static_entry("label-only")));
+        assert!(!manager.has_message_in_domain(domain, static_entry("missing")));
+    }
+
+    #[test]
+    fn select_languages_negotiates_partial_matches_in_preference_order() {
+        let manager = FluentManager::from_resources(in_memory_resources(&[
+            (langid!("de-DE"), "manager-negotiate", "title = Titel\n"),
+            (langid!("fr"), "manager-negotiate", "title = Titre\n"),
+            (langid!("en"), "manager-negotiate", "title = Title\n"),
+        ]));
+
+        manager
+            .select_languages(&[langid!("de-AT"), langid!("fr"), langid!("en")])
+            .expect("de-AT should negotiate to de-DE");
+        assert_eq!(
+            manager.localize(static_entry("title"), None),
+            Some("Titel".to_string())
+        );
+
+        manager
+            .select_languages(&[langid!("it"), langid!("fr-CA"), langid!("en")])
+            .expect("fr-CA should negotiate to fr");
+        assert_eq!(
+            manager.localize(static_entry("title"), None),
+            Some("Titre".to_string())
+        );
+
+        let err = manager
+            .select_languages(&[langid!("ja"), langid!("ko")])
+            .expect_err("no requested language is loaded");
+        assert!(matches!(
+            err,
+            LocalizationError::LanguageNotSupported(lang) if lang == langid!("ja")
+        ));
+        assert_eq!(
+            manager.localize(static_entry("title"), None),
+            Some("Titre".to_string())
+        );
+    }
+
+    #[test]
+    fn select_language_str_parses_config_strings_and_rejects_malformed_tags() {
+        let manager = FluentManager::from_resources(in_memory_resources(&[
+            (langid!("en-US"), "manager-str", "title = Title\n"),
+            (langid!("fr"), "manager-str", "title = Titre\n"),
+        ]));
+
+        manager
+            .select_language_str(" fr ")
+            .expect("trimmed tag should select");
+        assert_eq!(
+            manager.localize(static_entry("title"), None),
+            Some("Titre".to_string())
+        );
+        manager
+            .select_language_strict_str("en-us")
+            .expect("casing is normalized");
+        assert_eq!(
+            manager.localize(static_entry("title"), None),
+            Some("Title".to_string())
+        );
+
+        for input in ["", "   ", "not a locale!"] {
+            let err = manager
+                .select_language_str(input)
+                .expect_err("malformed tag should fail");
+            assert!(matches!(
+                &err,
+                LocalizationError::InvalidLanguageIdentifier { identifier, .. } if identifier == input
+            ));
+            assert!(err.to_string().contains("Invalid language identifier"));
+        }
+        assert!(matches!(
+            manager.select_language_str("ja"),
+            Err(LocalizationError::LanguageNotSupported(lang)) if lang == langid!("ja")
+        ));
+        assert_eq!(
+            manager.localize(static_entry("title"), None),
+            Some("Title".to_string())
+        );
+    }
+
+    #[test]
+    fn language_changed_callbacks_run_after_successful_selection() {
+        let mut manager = FluentManager::from_resources(in_memory_resources(&[
+            (langid!("en"), "manager-changed", "title = Title\n"),
+            (langid!("fr"), "manager-changed", "title = Titre\n"),
+        ]));
+        let changes = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&changes);
+        manager.on_language_changed(Box::new(move |lang| {
+            recorded.lock().push(lang.clone());
+        }));
+
+        manager
+            .select_language(&langid!("fr"))
+            .expect("fr should be supported");
+        manager
+            .select_language(&langid!("ja"))
+            .expect_err("ja has no resources");
+        manager
+            .select_languages(&[langid!("en-GB")])
+            .expect("en-GB should negotiate to en");
+
+        assert_eq!(*changes.lock(), vec![langid!("fr"), langid!("en")]);
+    }
+
+    #[test]
+    fn localize_all_formats_the_message_in_every_loaded_language() {
+        let mut manager = FluentManager::from_resources(in_memory_resources(&[
+            (
+                langid!("en"),
+                "manager-all",
+                "greeting = Hello, { $name }!\n",
+            ),
+            (
+                langid!("fr"),
+                "manager-all",
+                "greeting = Bonjour, { $name } !\n",
+            ),
+            (langid!("de"), "manager-all", "farewell = Tschüss\n"),
+        ]));
+        manager.set_fallback_language(langid!("en"));
+        manager
+            .select_language(&langid!("de"))
+            .expect("de should be supported");
+
+        let args =
+            FluentArgumentMap::from([(crate::__macro::static_argument_name("name"), "Ada".into())]);
+        let all = manager.localize_all(static_entry("greeting"), Some(&args));
+
+        assert_eq!(
+            all,
+            BTreeMap::from([
+                (langid!("de"), None),
+                (
+                    langid!("en"),
+                    Some("Hello, \u{2068}Ada\u{2069}!".to_string())
+                ),
+                (
+                    langid!("fr"),
+                    Some("Bonjour, \u{2068}Ada\u{2069} !".to_string())
+                ),
+            ])
+        );
+        assert_eq!(
+            manager.localize(static_entry("farewell"), None),
+            Some("Tschüss".to_string())
+        );
+    }
+
+    #[test]
+    fn in_memory_resources_reject_invalid_sources() {
+        let parse_error = FluentManager::try_from_resources(in_memory_resources(&[(
+            langid!("en"),
+            "manager-memory",
+            "broken = { $\n",
+        )]))
+        .err()
+        .expect("invalid FTL should fail");
+        assert!(matches!(
+            parse_error,
+            LocalizationError::FluentParseError(_)
+        ));
+
+        let duplicate_error = FluentManager::try_from_resources(in_memory_resources(&[
+            (langid!("en"), "manager-memory", "title = One\n"),
+            (langid!("en"), "manager-memory", "title = Two\n"),
+        ]))
+        .err()
+        .expect("duplicate messages should fail");
+        assert!(duplicate_error.to_string().contains("manager-memory"));
+
+        let domain_error = FluentManager::try_from_resources(in_memory_resources(&[(
+            langid!("en"),
+            "not a domain",
+            "title = Title\n",
+        )]))
+        .err()
+        .expect("invalid domains should fail");
+        assert!(matches!(
+            domain_error,
+            LocalizationError::InvalidFluentIdentifier { .. }
+        ));
+    }
 }
@@ -483,6 +483,11 @@ fn manager_localize_returns_first_matching_message() {
             (&MODULE_OK_DATA, Box::new(LocalizerOk)),
             (&MODULE_ERR_DATA, Box::new(LocalizerErr)),
         ]),
+        selected_language: RwLock::default(),
+        fallback_language: None,
+        fallback_localizers: RwLock::default(),
+        language_changed_callbacks: Vec::new(),
+        bundle_options: BundleOptions::default(),
     };
     assert_eq!(
         manager.localize(static_entry("from-ok"), None),
@@ -520,6 +525,11 @@ fn manager_select_language_reports_runtime_module_that_creates_no_localizer() {
     let manager = FluentManager {
         modules: vec![&MISSING_LOCALIZER_MODULE as &dyn I18nModuleRegistration],
         localizers: RwLock::default(),
+        selected_language: RwLock::default(),
+        fallback_language: None,
+        fallback_localizers: RwLock::default(),
+        language_changed_callbacks: Vec::new(),
+        bundle_options: BundleOptions::default(),
     };
 
     let err = manager
@@ -540,6 +550,11 @@ fn manager_select_language_with_only_failing_localizers_returns_error() {
     let manager = FluentManager {
         modules: vec![&MODULE_ERR as &dyn I18nModuleRegistration],
         localizers: RwLock::default(),
+        selected_language: RwLock::default(),
+        fallback_language: None,
+        fallback_localizers: RwLock::default(),
+        language_changed_callbacks: Vec::new(),
+        bundle_options: BundleOptions::default(),
     };
     let err = manager
         .select_language(&langid!("en-US"))
@@ -557,6 +572,11 @@ fn manager_select_language_returns_error_on_non_unsupported_failure() {
             &HARD_FAIL_MODULE as &dyn I18nModuleRegistration,
         ],
         localizers: RwLock::default(),
+        selected_language: RwLock::default(),
+        fallback_language: None,
+        fallback_localizers: RwLock::default(),
+        language_changed_callbacks: Vec::new(),
+        bundle_options: BundleOptions::default(),
     };
 
     let err = manager
@@ -581,6 +601,11 @@ fn manager_keeps_previous_localizers_when_strict_selection_fails() {
             &STATEFUL_SUCCESS_DATA,
             Box::new(StatefulSuccessLocalizer::new(Some("en-US"))),
         )]),
+        selected_language: RwLock::default(),
+        fallback_language: None,
+        fallback_localizers: RwLock::default(),
+        language_changed_callbacks: Vec::new(),
+        bundle_options: BundleOptions::default(),
     };
 
     let err = manager
@@ -594,6 +619,56 @@ fn manager_keeps_previous_localizers_when_strict_selection_fails() {
     );
 }
 
+struct FrenchOnlyLocalizer {
+    selected: RwLock<Option<LanguageIdentifier>>,
+}
+
+impl Localizer for FrenchOnlyLocalizer {
+    fn select_language(&self, lang: &LanguageIdentifier) -> Result<(), LocalizationError> {
+        if lang.language != langid!("fr").language {
+            return Err(LocalizationError::LanguageNotSupported(lang.clone()));
+        }
+        *self.selected.write() = Some(lang.clone());
+        Ok(())
+    }
+
+    fn localize<'a>(
+        &self,
+        _id: crate::StaticFluentEntryId,
+        _args: Option<&crate::FluentArgumentMap<'a>>,
+    ) -> Option<String> {
+        None
+    }
+}
+
+#[test]
+fn localizer_select_languages_defaults_to_first_supported_language() {
+    let localizer = FrenchOnlyLocalizer {
+        selected: RwLock::new(None),
+    };
+    localizer
+        .select_languages(&[langid!("de-AT"), langid!("fr"), langid!("en")])
+        .expect("fr should be selected");
+    assert_eq!(*localizer.selected.read(), Some(langid!("fr")));
+
+    let err = localizer
+        .select_languages(&[langid!("de-AT"), langid!("en")])
+        .expect_err("no requested language is supported");
+    assert!(matches!(
+        err,
+        LocalizationError::LanguageNotSupported(lang) if lang == langid!("de-AT")
+    ));
+    assert!(matches!(
+        localizer.select_languages(&[]),
+        Err(LocalizationError::LanguageNotSupported(_))
+    ));
+
+    let err = HardFailLocalizer
+        .select_languages(&[langid!("en"), langid!("fr")])
+        .expect_err("hard failures stop negotiation");
+    assert!(matches!(err, LocalizationError::IoError(_)));
+}
+
 #[test]
 fn format_supported_languages_truncates_long_lists_for_diagnostics() {
     assert_eq!(
@@ -0,0 +1 @@
+base-only = Hello main
@@ -0,0 +1 @@
+ui-title = UI Title
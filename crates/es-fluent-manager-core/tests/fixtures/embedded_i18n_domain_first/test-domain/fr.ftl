@@ -0,0 +1 @@
+base-only = Bonjour
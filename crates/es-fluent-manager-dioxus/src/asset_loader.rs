@@ -10,14 +10,16 @@ use es_fluent::{
     registry::{StaticFluentDomain, StaticFluentEntryId},
 };
 use es_fluent_manager_core::{
-    FluentArgumentMap, FluentManager, I18nModuleDescriptor, I18nModuleRegistration,
+    BundleOptions, FluentArgumentMap, FluentManager, I18nModuleDescriptor, I18nModuleRegistration,
     LanguageSelectionPolicy, LocaleLoadReport, LocaleRelativeFtlPath, LocalizationError,
     ModuleData, ModuleDiscoveryError, ModuleRegistrationKind, ModuleResourceSpec, ResourceKey,
-    ResourceLoadError, SyncFluentBundle, build_sync_bundle, fallback_errors_are_fatal,
-    localize_with_bundle, localize_with_fallback_resources, parse_fluent_resource_bytes,
+    ResourceLoadError, SyncFluentBundle, accept_formatted_value, build_sync_bundle_with_options,
+    localize_attribute_with_bundle, localize_with_bundle, parse_fluent_resource_bytes,
+    parse_fluent_resource_content,
 };
 use fluent_bundle::FluentResource;
 use parking_lot::{Mutex, RwLock};
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::io;
 use std::sync::{Arc, OnceLock};
@@ -87,12 +89,22 @@ impl std::error::Error for DioxusAssetLoadError {
     }
 }
 
+/// Where the FTL bytes of a [`DioxusI18nAssetResource`] come from.
+#[derive(Clone, Copy)]
+pub enum DioxusI18nAssetSource {
+    /// A Dioxus `asset!`, read from disk natively and fetched over HTTP on the
+    /// web.
+    Asset(Asset),
+    /// FTL compiled into the binary, available without an async read.
+    Embedded(&'static [u8]),
+}
+
 pub struct DioxusI18nAssetResource {
     language: LanguageIdentifier,
     key: &'static str,
     locale_relative_path: &'static str,
     required: bool,
-    asset: Asset,
+    source: DioxusI18nAssetSource,
 }
 
 impl DioxusI18nAssetResource {
@@ -102,13 +114,70 @@ impl DioxusI18nAssetResource {
         locale_relative_path: &'static str,
         required: bool,
         asset: Asset,
+    ) -> Self {
+        Self::with_source(
+            language,
+            key,
+            locale_relative_path,
+            required,
+            DioxusI18nAssetSource::Asset(asset),
+        )
+    }
+
+    /// Creates a resource whose FTL is embedded in the binary, usually with
+    /// `include_bytes!`.
+    pub const fn embedded(
+        language: LanguageIdentifier,
+        key: &'static str,
+        locale_relative_path: &'static str,
+        required: bool,
+        content: &'static [u8],
+    ) -> Self {
+        Self::with_source(
+            language,
+            key,
+            locale_relative_path,
+            required,
+            DioxusI18nAssetSource::Embedded(content),
+        )
+    }
+
+    const fn with_source(
+        language: LanguageIdentifier,
+        key: &'static str,
+        locale_relative_path: &'static str,
+        required: bool,
+        source: DioxusI18nAssetSource,
     ) -> Self {
         Self {
             language,
             key,
             locale_relative_path,
             required,
-            asset,
+            source,
+        }
+    }
+
+    pub const fn source(&self) -> DioxusI18nAssetSource {
+        self.source
+    }
+
+    fn embedded_bytes(&self) -> Result<Cow<'static, [u8]>, String> {
+        match self.source {
+            DioxusI18nAssetSource::Embedded(content) => Ok(Cow::Borrowed(content)),
+            DioxusI18nAssetSource::Asset(_) => Err(format!(
+                "resource '{}' for locale '{}' is a Dioxus asset and must be loaded asynchronously",
+                self.locale_relative_path, self.language
+            )),
+        }
+    }
+
+    async fn read_bytes(&self, cache_bust: Option<u64>) -> Result<Cow<'static, [u8]>, String> {
+        match &self.source {
+            DioxusI18nAssetSource::Asset(asset) => read_dioxus_asset_bytes(asset, cache_bust)
+                .await
+                .map(Cow::Owned),
+            DioxusI18nAssetSource::Embedded(content) => Ok(Cow::Borrowed(*content)),
         }
     }
 
@@ -137,13 +206,37 @@ impl DioxusI18nAssetModule {
     async fn load_with_cache_bust(
         &'static self,
         cache_bust: Option<u64>,
+    ) -> LoadedDioxusI18nAssetModule {
+        let reads = futures::future::join_all(
+            self.resources
+                .iter()
+                .map(|resource| resource.read_bytes(cache_bust)),
+        )
+        .await;
+
+        self.loaded_from_reads(reads)
+    }
+
+    /// Loads the module without awaiting, which only succeeds for embedded
+    /// resources; `asset!` resources are recorded as load errors.
+    fn load_embedded(&'static self) -> LoadedDioxusI18nAssetModule {
+        self.loaded_from_reads(
+            self.resources
+                .iter()
+                .map(DioxusI18nAssetResource::embedded_bytes),
+        )
+    }
+
+    fn loaded_from_reads(
+        &'static self,
+        reads: impl IntoIterator<Item = Result<Cow<'static, [u8]>, String>>,
     ) -> LoadedDioxusI18nAssetModule {
         let mut loaded_resources = HashMap::new();
         let mut load_errors = HashMap::new();
         let mut resource_specs_by_language: HashMap<LanguageIdentifier, Vec<ModuleResourceSpec>> =
             HashMap::new();
 
-        for resource in self.resources {
+        for (resource, read) in self.resources.iter().zip(reads) {
             let lang = resource.language.clone();
             let spec = resource.spec();
             resource_specs_by_language
@@ -151,7 +244,7 @@ impl DioxusI18nAssetModule {
                 .or_default()
                 .push(spec.clone());
 
-            match read_dioxus_asset_bytes(&resource.asset, cache_bust).await {
+            match read {
                 Ok(bytes) => match parse_fluent_resource_bytes(&spec, &bytes) {
                     Ok(parsed) => {
                         loaded_resources.insert((lang, spec.key.clone()), parsed);
@@ -318,9 +411,10 @@ struct LoadedDioxusI18nAssetModule {
 }
 
 impl LoadedDioxusI18nAssetModule {
-    fn create_localizer(&self) -> LoadedDioxusAssetLocalizer {
+    fn create_localizer(&self, bundle_options: BundleOptions) -> LoadedDioxusAssetLocalizer {
         LoadedDioxusAssetLocalizer {
             module: self.clone(),
+            bundle_options,
             state: RwLock::default(),
             selection_lock: Mutex::new(()),
         }
@@ -337,6 +431,37 @@ impl LoadedDioxusI18nAssetModule {
             .unwrap_or_else(|| self.data.resource_plan())
     }
 
+    fn resource_spec_for(
+        &self,
+        lang: &LanguageIdentifier,
+        key: &ResourceKey,
+    ) -> Option<ModuleResourceSpec> {
+        self.resource_plan_for_language(lang)
+            .into_iter()
+            .find(|spec| &spec.key == key)
+    }
+
+    fn replace_resource(
+        &mut self,
+        lang: &LanguageIdentifier,
+        spec: ModuleResourceSpec,
+        resource: Arc<FluentResource>,
+    ) -> Option<Arc<FluentResource>> {
+        let data = self.data;
+        let key = (lang.clone(), spec.key.clone());
+
+        let specs = Arc::make_mut(&mut self.resource_specs_by_language)
+            .entry(lang.clone())
+            .or_insert_with(|| data.resource_plan());
+        if !specs.iter().any(|existing| existing.key == spec.key) {
+            specs.push(spec);
+            specs.sort_by(|left, right| left.key.cmp(&right.key));
+        }
+
+        Arc::make_mut(&mut self.load_errors).remove(&key);
+        Arc::make_mut(&mut self.loaded_resources).insert(key, resource)
+    }
+
     fn load_resource_for_language(
         &self,
         lang: &LanguageIdentifier,
@@ -382,15 +507,19 @@ fn log_unready_locale(data: &ModuleData, lang: &LanguageIdentifier, report: &Loc
     );
 }
 
+/// Bundles for the selected language, built once per language selection.
+///
+/// `current_bundles` follows the fallback chain: the first bundle formats with
+/// the requested locale and later bundles serve keys missing from it.
 #[derive(Default)]
 struct LoadedDioxusAssetLocalizerState {
-    current_bundle: Option<Arc<SyncFluentBundle>>,
+    current_bundles: Vec<Arc<SyncFluentBundle>>,
     current_lang: Option<LanguageIdentifier>,
-    current_locale_resources: Vec<(LanguageIdentifier, Vec<Arc<FluentResource>>)>,
 }
 
 struct LoadedDioxusAssetLocalizer {
     module: LoadedDioxusI18nAssetModule,
+    bundle_options: BundleOptions,
     state: RwLock<LoadedDioxusAssetLocalizerState>,
     selection_lock: Mutex<()>,
 }
@@ -404,8 +533,7 @@ impl LoadedDioxusAssetLocalizer {
         }
 
         let mut remaining_languages = self.module.data.supported_languages.to_vec();
-        let mut current_bundle = None;
-        let mut locale_resources = Vec::new();
+        let mut current_bundles = Vec::new();
 
         while let Some(candidate) =
             es_fluent_manager_core::resolve_fallback_language(lang, &remaining_languages)
@@ -413,10 +541,13 @@ impl LoadedDioxusAssetLocalizer {
             remaining_languages.retain(|supported| supported != &candidate);
 
             if let Ok(resources) = self.module.load_resource_for_language(&candidate) {
-                let (mut candidate_bundle, add_errors) =
-                    build_sync_bundle(&candidate, resources.clone());
+                let (mut candidate_bundle, add_errors) = build_sync_bundle_with_options(
+                    &candidate,
+                    resources.clone(),
+                    &self.bundle_options,
+                );
                 if !add_errors.is_empty() {
-                    if locale_resources.is_empty() {
+                    if current_bundles.is_empty() {
                         let error = io::Error::other(format!(
                             "failed to build a Fluent bundle for Dioxus asset module '{}' and language '{}': {:?}",
                             self.module.data.name, lang, add_errors
@@ -433,20 +564,18 @@ impl LoadedDioxusAssetLocalizer {
                     continue;
                 }
 
-                if current_bundle.is_none() {
+                if current_bundles.is_empty() {
                     candidate_bundle.locales = es_fluent_manager_core::locale_candidates(lang);
-                    current_bundle = Some(Arc::new(candidate_bundle));
                 }
 
-                locale_resources.push((candidate, resources));
+                current_bundles.push(Arc::new(candidate_bundle));
             }
         }
 
-        if let Some(bundle) = current_bundle {
+        if !current_bundles.is_empty() {
             *self.state.write() = LoadedDioxusAssetLocalizerState {
-                current_bundle: Some(bundle),
+                current_bundles,
                 current_lang: Some(lang.clone()),
-                current_locale_resources: locale_resources,
             };
             return Ok(());
         }
@@ -459,42 +588,42 @@ impl LoadedDioxusAssetLocalizer {
         id: StaticFluentEntryId,
         args: Option<&FluentArgumentMap<'a>>,
     ) -> Option<String> {
-        let (bundle, locale_resources) = {
-            let state = self.state.read();
-            (
-                state.current_bundle.clone(),
-                state.current_locale_resources.clone(),
-            )
-        };
+        let bundles = self.state.read().current_bundles.clone();
 
-        if let Some(bundle) = bundle.as_ref()
-            && let Some((value, errors)) = localize_with_bundle(bundle.as_ref(), id, args)
-        {
-            if !errors.is_empty() {
-                tracing::error!(
-                    "Fluent formatting errors for id '{}': {:?}",
-                    id.as_str(),
-                    errors
-                );
-                return None;
+        for bundle in &bundles {
+            if let Some(formatted) = localize_with_bundle(bundle.as_ref(), id, args) {
+                return accept_formatted_value(id, formatted);
             }
-
-            return Some(value);
         }
 
-        let (value, errors) =
-            localize_with_fallback_resources(locale_resources.as_slice(), id, args);
+        None
+    }
 
-        if fallback_errors_are_fatal(&errors) {
-            tracing::error!(
-                "Fluent fallback formatting errors for id '{}': {:?}",
-                id.as_str(),
-                errors
-            );
-            return None;
+    fn has_message(&self, id: StaticFluentEntryId) -> bool {
+        self.state.read().current_bundles.iter().any(|bundle| {
+            bundle
+                .get_message(id.as_str())
+                .is_some_and(|message| message.value().is_some())
+        })
+    }
+
+    fn localize_attribute<'a>(
+        &self,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&FluentArgumentMap<'a>>,
+    ) -> Option<String> {
+        let bundles = self.state.read().current_bundles.clone();
+
+        for bundle in &bundles {
+            if let Some(formatted) =
+                localize_attribute_with_bundle(bundle.as_ref(), id, attribute, args)
+            {
+                return accept_formatted_value(id, formatted);
+            }
         }
 
-        value
+        None
     }
 }
 
@@ -504,9 +633,10 @@ pub struct DioxusAssetI18n {
 }
 
 struct DioxusAssetI18nInner {
-    modules: Arc<[LoadedDioxusI18nAssetModule]>,
+    modules: RwLock<Arc<[LoadedDioxusI18nAssetModule]>>,
     localizers: RwLock<Vec<(&'static ModuleData, LoadedDioxusAssetLocalizer)>>,
-    runtime_followers: Option<Arc<FluentManager>>,
+    runtime_followers: RwLock<Option<Arc<FluentManager>>>,
+    bundle_options: RwLock<BundleOptions>,
     requested_language: RwLock<LanguageIdentifier>,
     selection_lock: Mutex<()>,
 }
@@ -521,12 +651,16 @@ fn runtime_follower_discovery() -> &'static RuntimeFollowerDiscovery {
         .get_or_init(|| FluentManager::try_discover_runtime_follower_modules().map_err(Into::into))
 }
 
-fn create_runtime_follower_manager() -> Result<Option<Arc<FluentManager>>, DioxusAssetLoadError> {
+fn create_runtime_follower_manager(
+    bundle_options: &BundleOptions,
+) -> Result<Option<Arc<FluentManager>>, DioxusAssetLoadError> {
     match runtime_follower_discovery() {
         Ok(discovered) if discovered.is_empty() => Ok(None),
-        Ok(discovered) => Ok(Some(Arc::new(FluentManager::from_discovered_modules(
-            discovered,
-        )))),
+        Ok(discovered) => {
+            let mut manager = FluentManager::from_discovered_modules(discovered);
+            manager.set_bundle_options(bundle_options.clone());
+            Ok(Some(Arc::new(manager)))
+        },
         Err(errors) => Err(DioxusAssetLoadError::ModuleDiscovery(errors.clone())),
     }
 }
@@ -577,26 +711,53 @@ impl DioxusAssetI18n {
     {
         let initial_language = initial_language.into();
         let modules = modules.as_slice();
-        let mut loaded_modules = Vec::with_capacity(modules.len());
-        for module in modules {
-            loaded_modules.push(module.load_with_cache_bust(cache_bust).await);
-        }
+        let loaded_modules = futures::future::join_all(
+            modules
+                .iter()
+                .map(|module| module.load_with_cache_bust(cache_bust)),
+        )
+        .await;
 
         Self::new_with_loaded_modules(loaded_modules, initial_language, selection_policy)
     }
 
+    /// Builds the localizer synchronously from modules whose resources are
+    /// embedded, as generated by `define_i18n_module!(embedded)`.
+    ///
+    /// No asset is fetched, so desktop apps can localize before the first
+    /// render. Resources declared with `asset!` are reported as load errors;
+    /// use [`Self::load_modules`] for those.
+    pub fn new_embedded<L>(
+        modules: DioxusI18nAssetModules,
+        initial_language: L,
+        selection_policy: LanguageSelectionPolicy,
+    ) -> Result<Self, DioxusAssetLoadError>
+    where
+        L: Into<LanguageIdentifier>,
+    {
+        let loaded_modules = modules
+            .as_slice()
+            .iter()
+            .map(|module| module.load_embedded())
+            .collect();
+
+        Self::new_with_loaded_modules(loaded_modules, initial_language.into(), selection_policy)
+    }
+
     fn new_with_loaded_modules(
         loaded_modules: Vec<LoadedDioxusI18nAssetModule>,
         initial_language: LanguageIdentifier,
         selection_policy: LanguageSelectionPolicy,
     ) -> Result<Self, DioxusAssetLoadError> {
-        let runtime_followers = create_runtime_follower_manager()?;
+        let bundle_options = BundleOptions::default();
+        let runtime_followers = create_runtime_follower_manager(&bundle_options)?;
         let modules_for_error = loaded_modules.clone();
         let manager = Self {
             inner: Arc::new(DioxusAssetI18nInner {
-                modules: loaded_modules.into(),
+                modules: RwLock::new(loaded_modules.into()),
                 localizers: RwLock::default(),
-                runtime_followers,
+                runtime_followers: RwLock::new(runtime_followers),
+                bundle_options: RwLock::new(bundle_options),
                 requested_language: RwLock::new(initial_language.clone()),
                 selection_lock: Mutex::new(()),
             }),
@@ -633,14 +794,24 @@ impl DioxusAssetI18n {
         lang: L,
         selection_policy: LanguageSelectionPolicy,
     ) -> Result<(), LocalizationError> {
-        let lang = lang.into();
         let _selection = self.inner.selection_lock.lock();
-        let mut next_localizers = Vec::with_capacity(self.inner.modules.len());
+        self.select_language_locked(lang.into(), selection_policy)
+    }
+
+    /// Rebuilds the localizers for `lang`; the caller must hold `selection_lock`.
+    fn select_language_locked(
+        &self,
+        lang: LanguageIdentifier,
+        selection_policy: LanguageSelectionPolicy,
+    ) -> Result<(), LocalizationError> {
+        let modules = self.inner.modules.read().clone();
+        let bundle_options = self.inner.bundle_options.read().clone();
+        let mut next_localizers = Vec::with_capacity(modules.len());
         let mut first_failure = None;
         let mut any_selected = false;
 
-        for module in self.inner.modules.iter() {
-            let localizer = module.create_localizer();
+        for module in modules.iter() {
+            let localizer = module.create_localizer(bundle_options.clone());
 
             match localizer.select_language(&lang) {
                 Ok(()) => {
@@ -671,7 +842,7 @@ impl DioxusAssetI18n {
             return Err(error);
         }
 
-        if let Some(runtime_followers) = self.inner.runtime_followers.as_ref()
+        if let Some(runtime_followers) = self.inner.runtime_followers.read().as_ref()
             && let Err(error) = runtime_followers.select_language_for_supported_locale(&lang)
         {
             tracing::warn!(
@@ -686,6 +857,120 @@ impl DioxusAssetI18n {
         Ok(())
     }
 
+    /// Replaces the base `{domain}.ftl` resource for `lang` with new FTL content.
+    ///
+    /// This is meant for development servers that push edited FTL without
+    /// reloading every asset. A resource that was never loaded for `lang` is
+    /// added as an optional resource. The active language is reselected so the
+    /// new messages take effect immediately, and the previously loaded
+    /// resource is returned.
+    pub fn reload_resource<L: Into<LanguageIdentifier>>(
+        &self,
+        lang: L,
+        domain: StaticFluentDomain,
+        content: String,
+    ) -> Result<Option<Arc<FluentResource>>, ResourceLoadError> {
+        let lang = lang.into();
+        let key = ResourceKey::from_static_domain(domain);
+        let previous = {
+            let _selection = self.inner.selection_lock.lock();
+            let mut modules = self.inner.modules.read().to_vec();
+            let module_index = modules
+                .iter()
+                .position(|module| {
+                    module.data.domain == domain && module.resource_spec_for(&lang, &key).is_some()
+                })
+                .or_else(|| {
+                    modules
+                        .iter()
+                        .position(|module| module.data.domain == domain)
+                });
+            let spec = module_index
+                .and_then(|index| modules[index].resource_spec_for(&lang, &key))
+                .unwrap_or_else(|| ModuleResourceSpec::base_for_static_domain(domain, false));
+            let Some(module_index) = module_index else {
+                return Err(ResourceLoadError::load(
+                    &spec,
+                    format!("no Dioxus asset module is registered for domain '{domain}'"),
+                ));
+            };
+
+            let resource = parse_fluent_resource_content(&spec, content)?;
+            let previous = modules[module_index].replace_resource(&lang, spec, resource);
+            *self.inner.modules.write() = modules.into();
+            previous
+        };
+
+        let requested_language = self.requested_language();
+        if let Err(error) = self.select_language(requested_language.clone()) {
+            tracing::warn!(
+                "Reloaded Dioxus asset resource '{}' for '{}' but could not reselect '{}': {}",
+                key,
+                lang,
+                requested_language,
+                error
+            );
+        }
+
+        Ok(previous)
+    }
+
+    /// Sets whether formatted placeables are wrapped in Unicode isolation
+    /// marks. See [`FluentManager::set_use_isolating`].
+    pub fn set_use_isolating(&self, use_isolating: bool) {
+        let mut options = self.bundle_options();
+        options.set_use_isolating(use_isolating);
+        self.set_bundle_options(options);
+    }
+
+    /// Registers a custom Fluent function for every bundle built by this
+    /// localizer, including runtime follower modules.
+    pub fn add_function<F>(&self, name: impl Into<String>, function: F)
+    where
+        F: for<'a> Fn(
+                &[fluent_bundle::FluentValue<'a>],
+                &fluent_bundle::FluentArgs,
+            ) -> fluent_bundle::FluentValue<'a>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let mut options = self.bundle_options();
+        options.add_function(name, function);
+        self.set_bundle_options(options);
+    }
+
+    /// Replaces the bundle settings and rebuilds the bundles of the requested
+    /// language so the change takes effect immediately.
+    pub fn set_bundle_options(&self, options: BundleOptions) {
+        let _selection = self.inner.selection_lock.lock();
+        match create_runtime_follower_manager(&options) {
+            Ok(runtime_followers) => *self.inner.runtime_followers.write() = runtime_followers,
+            Err(error) => tracing::warn!(
+                "Could not rebuild runtime follower i18n modules with new bundle options: {}",
+                error
+            ),
+        }
+        *self.inner.bundle_options.write() = options;
+        let requested_language = self.inner.requested_language.read().clone();
+
+        if let Err(error) = self.select_language_locked(
+            requested_language.clone(),
+            LanguageSelectionPolicy::BestEffort,
+        ) {
+            tracing::warn!(
+                "Updated Dioxus asset bundle options but could not reselect '{}': {}",
+                requested_language,
+                error
+            );
+        }
+    }
+
+    /// Returns the settings applied to every bundle this localizer builds.
+    pub fn bundle_options(&self) -> BundleOptions {
+        self.inner.bundle_options.read().clone()
+    }
+
     pub fn localize_message<T>(&self, message: &T) -> String
     where
         T: FluentMessage + ?Sized,
@@ -708,6 +993,7 @@ impl FluentLocalizer for DioxusAssetI18n {
 
         self.inner
             .runtime_followers
+            .read()
             .as_ref()
             .and_then(|runtime_followers| runtime_followers.localize(id, args))
     }
@@ -728,13 +1014,76 @@ impl FluentLocalizer for DioxusAssetI18n {
 
         self.inner
             .runtime_followers
+            .read()
             .as_ref()
             .and_then(|runtime_followers| runtime_followers.localize_in_domain(domain, id, args))
     }
 
+    fn localize_attribute_in_domain<'a>(
+        &self,
+        domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&FluentArgs<'a>>,
+    ) -> Option<String> {
+        for (data, localizer) in self.inner.localizers.read().iter() {
+            if data.domain == domain
+                && let Some(message) =
+                    localizer.localize_attribute(id, attribute, args.map(FluentArgs::as_raw))
+            {
+                return Some(message);
+            }
+        }
+
+        self.inner
+            .runtime_followers
+            .read()
+            .as_ref()
+            .and_then(|runtime_followers| {
+                runtime_followers.localize_attribute_in_domain(domain, id, attribute, args)
+            })
+    }
+
+    fn has_message_in_domain(&self, domain: StaticFluentDomain, id: StaticFluentEntryId) -> bool {
+        let in_assets = self
+            .inner
+            .localizers
+            .read()
+            .iter()
+            .any(|(data, localizer)| data.domain == domain && localizer.has_message(id));
+
+        in_assets
+            || self
+                .inner
+                .runtime_followers
+                .read()
+                .as_ref()
+                .is_some_and(|runtime_followers| {
+                    runtime_followers.has_message_in_domain(domain, id)
+                })
+    }
+
+    fn has_domain(&self, domain: StaticFluentDomain) -> bool {
+        let in_assets = self
+            .inner
+            .localizers
+            .read()
+            .iter()
+            .any(|(data, _)| data.domain == domain);
+
+        in_assets
+            || self
+                .inner
+                .runtime_followers
+                .read()
+                .as_ref()
+                .is_some_and(|runtime_followers| runtime_followers.has_domain(domain))
+    }
+
     fn with_lookup(&self, f: &mut dyn FnMut(&mut FluentLocalizerLookup<'_>)) {
         let localizers = self.inner.localizers.read();
-        if let Some(runtime_followers) = self.inner.runtime_followers.as_ref() {
+        let runtime_followers = self.inner.runtime_followers.read().clone();
+        if let Some(runtime_followers) = runtime_followers {
             runtime_followers.with_lookup(&mut |runtime_lookup| {
                 let mut lookup = |domain: StaticFluentDomain,
                                   id: StaticFluentEntryId,
@@ -864,7 +1213,10 @@ fn watched_dioxus_i18n_asset_paths(modules: DioxusI18nAssetModules) -> Arc<[Stri
         .as_slice()
         .iter()
         .flat_map(|module| module.resources.iter())
-        .map(|resource| resource.asset.bundled().bundled_path().to_string())
+        .filter_map(|resource| match resource.source {
+            DioxusI18nAssetSource::Asset(asset) => Some(asset.bundled().bundled_path().to_string()),
+            DioxusI18nAssetSource::Embedded(_) => None,
+        })
         .collect::<Vec<_>>()
         .into()
 }
@@ -995,6 +1347,11 @@ impl DioxusAssetI18nContext {
         *tracked.write() = value;
     }
 
+    fn notify_i18n_changed(&self) {
+        let mut i18n = self.i18n;
+        let _ = i18n.write();
+    }
+
     fn update_selection_policy(&self, selection_policy: LanguageSelectionPolicy) {
         if *self.selection_policy.peek() == selection_policy {
             return;
@@ -1010,6 +1367,8 @@ impl DioxusAssetI18nContext {
             return;
         }
 
+        i18n.set_bundle_options(self.i18n.peek().bundle_options());
+
         let requested_language = self.peek();
         if i18n.requested_language() != requested_language
             && let Err(error) = i18n.select_language_with_policy(
@@ -1070,6 +1429,43 @@ impl DioxusAssetI18nHandle {
         Ok(())
     }
 
+    /// Replaces a loaded FTL resource and re-renders components that localize
+    /// through this handle. See [`DioxusAssetI18n::reload_resource`].
+    pub fn reload_resource<L: Into<LanguageIdentifier>>(
+        &self,
+        lang: L,
+        domain: StaticFluentDomain,
+        content: String,
+    ) -> Result<Option<Arc<FluentResource>>, ResourceLoadError> {
+        let i18n = self.context.i18n();
+        let previous = i18n.reload_resource(lang, domain, content)?;
+        self.context.notify_i18n_changed();
+        Ok(previous)
+    }
+
+    /// Changes bundle isolation and re-renders components that localize
+    /// through this handle. See [`DioxusAssetI18n::set_use_isolating`].
+    pub fn set_use_isolating(&self, use_isolating: bool) {
+        self.context.i18n().set_use_isolating(use_isolating);
+        self.context.notify_i18n_changed();
+    }
+
+    /// Registers a custom Fluent function and re-renders components that
+    /// localize through this handle. See [`DioxusAssetI18n::add_function`].
+    pub fn add_function<F>(&self, name: impl Into<String>, function: F)
+    where
+        F: for<'a> Fn(
+                &[fluent_bundle::FluentValue<'a>],
+                &fluent_bundle::FluentArgs,
+            ) -> fluent_bundle::FluentValue<'a>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.context.i18n().add_function(name, function);
+        self.context.notify_i18n_changed();
+    }
+
     pub fn localize_message<T>(&self, message: &T) -> String
     where
         T: FluentMessage + ?Sized,
@@ -1102,6 +1498,30 @@ impl FluentLocalizer for DioxusAssetI18nHandle {
         FluentLocalizer::localize_in_domain(&i18n, domain, id, args)
     }
 
+    fn localize_attribute_in_domain<'a>(
+        &self,
+        domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&FluentArgs<'a>>,
+    ) -> Option<String> {
+        let _ = self.context.current();
+        let i18n = self.context.i18n();
+        FluentLocalizer::localize_attribute_in_domain(&i18n, domain, id, attribute, args)
+    }
+
+    fn has_message_in_domain(&self, domain: StaticFluentDomain, id: StaticFluentEntryId) -> bool {
+        let _ = self.context.current();
+        let i18n = self.context.i18n();
+        FluentLocalizer::has_message_in_domain(&i18n, domain, id)
+    }
+
+    fn has_domain(&self, domain: StaticFluentDomain) -> bool {
+        let _ = self.context.current();
+        let i18n = self.context.i18n();
+        FluentLocalizer::has_domain(&i18n, domain)
+    }
+
     fn with_lookup(&self, f: &mut dyn FnMut(&mut FluentLocalizerLookup<'_>)) {
         let _ = self.context.current();
         let i18n = self.context.i18n();
@@ -1297,6 +1717,25 @@ mod tests {
     static INVALID_ASSET_MODULE: DioxusI18nAssetModule =
         DioxusI18nAssetModule::new(&INVALID_ASSET_DATA, INVALID_ASSET_RESOURCES);
     static INVALID_ASSET_MODULES: &[&DioxusI18nAssetModule] = &[&INVALID_ASSET_MODULE];
+    static EMBEDDED_RESOURCES: &[DioxusI18nAssetResource] = &[
+        DioxusI18nAssetResource::embedded(
+            langid!("en"),
+            "asset-test",
+            "asset-test.ftl",
+            true,
+            include_bytes!("../tests/fixtures/dioxus_i18n/en/asset-test.ftl"),
+        ),
+        DioxusI18nAssetResource::embedded(
+            langid!("fr"),
+            "asset-test",
+            "asset-test.ftl",
+            true,
+            include_bytes!("../tests/fixtures/dioxus_i18n/fr/asset-test.ftl"),
+        ),
+    ];
+    static EMBEDDED_MODULE: DioxusI18nAssetModule =
+        DioxusI18nAssetModule::new(&ASSET_DATA, EMBEDDED_RESOURCES);
+    static EMBEDDED_MODULES: &[&DioxusI18nAssetModule] = &[&EMBEDDED_MODULE];
 
     fn resource(source: &str) -> Arc<FluentResource> {
         Arc::new(FluentResource::try_new(source.to_string()).expect("valid FTL"))
@@ -1577,12 +2016,12 @@ mod tests {
         let modules = DioxusI18nAssetModules::new(ASSET_MODULES);
         let same = DioxusI18nAssetModules::new(ASSET_MODULES);
         let different = DioxusI18nAssetModules::new(INVALID_ASSET_MODULES);
-        let resource = DioxusI18nAssetResource::new(
+        let resource = DioxusI18nAssetResource::with_source(
             langid!("en"),
             "asset-test",
             "asset-test.ftl",
             true,
-            ASSET_RESOURCES[0].asset,
+            ASSET_RESOURCES[0].source(),
         );
         let module = DioxusI18nAssetModule::new(&ASSET_DATA, &[]);
 
@@ -1688,6 +2127,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn new_embedded_selects_languages_without_reading_assets() {
+        let i18n = DioxusAssetI18n::new_embedded(
+            DioxusI18nAssetModules::new(EMBEDDED_MODULES),
+            langid!("fr"),
+            LanguageSelectionPolicy::Strict,
+        )
+        .expect("embedded module should load synchronously");
+
+        assert_eq!(
+            i18n.localize(static_entry("asset-hello"), None),
+            Some("Bonjour from asset".to_string())
+        );
+
+        let loaded = futures::executor::block_on(DioxusAssetI18n::load_modules(
+            DioxusI18nAssetModules::new(EMBEDDED_MODULES),
+            langid!("en"),
+            LanguageSelectionPolicy::BestEffort,
+        ))
+        .expect("async loading should accept embedded resources");
+        assert_eq!(
+            loaded.localize(static_entry("asset-hello"), None),
+            Some("Hello from asset".to_string())
+        );
+    }
+
+    #[test]
+    fn new_embedded_reports_asset_resources_as_load_errors() {
+        let error = match DioxusAssetI18n::new_embedded(
+            DioxusI18nAssetModules::new(ASSET_MODULES),
+            langid!("en"),
+            LanguageSelectionPolicy::BestEffort,
+        ) {
+            Ok(_) => panic!("asset! resources should need async loading"),
+            Err(error) => error,
+        };
+
+        assert_eq!(error.resource_errors().len(), 2);
+        assert!(
+            error.resource_errors()[0]
+                .to_string()
+                .contains("must be loaded asynchronously")
+        );
+    }
+
     #[test]
     fn load_modules_collects_parse_errors_for_language_selection_failures() {
         let modules = DioxusI18nAssetModules::new(INVALID_ASSET_MODULES);
@@ -1723,6 +2207,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn localizer_reuses_selected_fallback_bundles_across_lookups() {
+        let en_us = langid!("en-US");
+        let en = langid!("en");
+        let spec = ModuleResourceSpec::new(
+            ResourceKey::from_static_path("fallback-app"),
+            LocaleRelativeFtlPath::from_static_path("fallback-app.ftl"),
+            true,
+        );
+        let module = LoadedDioxusI18nAssetModule {
+            data: &FALLBACK_DATA,
+            loaded_resources: Arc::new(HashMap::from([
+                ((en_us.clone(), spec.key.clone()), resource("hello = Howdy")),
+                (
+                    (en.clone(), spec.key.clone()),
+                    resource("hello = Hello\nfallback = English fallback"),
+                ),
+            ])),
+            load_errors: Arc::new(HashMap::new()),
+            resource_specs_by_language: Arc::new(HashMap::from([
+                (en_us.clone(), vec![spec.clone()]),
+                (en, vec![spec]),
+            ])),
+        };
+        let localizer = module.create_localizer(BundleOptions::default());
+        localizer
+            .select_language(&en_us)
+            .expect("en-US should select");
+        let selected = localizer.state.read().current_bundles.clone();
+        assert_eq!(selected.len(), 2);
+
+        for index in 0..1000 {
+            let (id, expected) = if index % 2 == 0 {
+                ("hello", "Howdy")
+            } else {
+                ("fallback", "English fallback")
+            };
+            assert_eq!(
+                localizer.localize(static_entry(id), None),
+                Some(expected.to_string())
+            );
+        }
+        assert_eq!(localizer.localize(static_entry("missing"), None), None);
+
+        let state = localizer.state.read();
+        assert_eq!(state.current_bundles.len(), selected.len());
+        assert!(
+            state
+                .current_bundles
+                .iter()
+                .zip(&selected)
+                .all(|(current, selected)| Arc::ptr_eq(current, selected))
+        );
+    }
+
     #[test]
     fn strict_selection_rejects_partial_module_failures() {
         let i18n = DioxusAssetI18n::new_with_loaded_modules(
@@ -1741,10 +2280,132 @@ mod tests {
         assert_eq!(i18n.requested_language(), langid!("fr"));
     }
 
+    #[test]
+    fn reload_resource_replaces_loaded_content_and_returns_previous_resource() {
+        let i18n = DioxusAssetI18n::new_with_loaded_modules(
+            vec![loaded_module()],
+            langid!("en"),
+            LanguageSelectionPolicy::BestEffort,
+        )
+        .expect("module should load");
+
+        let previous = i18n
+            .reload_resource(
+                langid!("en"),
+                static_domain("test-app"),
+                "hello = Hi again".to_string(),
+            )
+            .expect("valid FTL should reload");
+
+        assert!(
+            previous
+                .expect("previous resource should be returned")
+                .get_entry(0)
+                .is_some()
+        );
+        assert_eq!(
+            i18n.localize(static_entry("hello"), None),
+            Some("Hi again".to_string())
+        );
+    }
+
+    #[test]
+    fn reload_resource_creates_missing_locale_resources() {
+        let i18n = DioxusAssetI18n::new_with_loaded_modules(
+            vec![loaded_module()],
+            langid!("en"),
+            LanguageSelectionPolicy::BestEffort,
+        )
+        .expect("module should load");
+        assert!(i18n.select_language(langid!("fr")).is_err());
+
+        let previous = i18n
+            .reload_resource(
+                langid!("fr"),
+                static_domain("test-app"),
+                "hello = Bonjour".to_string(),
+            )
+            .expect("new locale resource should be created");
+
+        assert!(previous.is_none());
+        i18n.select_language(langid!("fr"))
+            .expect("reloaded locale should become selectable");
+        assert_eq!(
+            i18n.localize(static_entry("hello"), None),
+            Some("Bonjour".to_string())
+        );
+    }
+
+    #[test]
+    fn reload_resource_rejects_invalid_content_and_unknown_domains() {
+        let i18n = DioxusAssetI18n::new_with_loaded_modules(
+            vec![loaded_module()],
+            langid!("en"),
+            LanguageSelectionPolicy::BestEffort,
+        )
+        .expect("module should load");
+
+        let parse_error = i18n
+            .reload_resource(
+                langid!("en"),
+                static_domain("test-app"),
+                "hello = {".to_string(),
+            )
+            .expect_err("invalid FTL should be rejected");
+        assert!(matches!(parse_error, ResourceLoadError::Parse { .. }));
+        assert_eq!(
+            i18n.localize(static_entry("hello"), None),
+            Some("Hello".to_string())
+        );
+
+        let unknown_domain = i18n
+            .reload_resource(
+                langid!("en"),
+                static_domain("unknown-app"),
+                "hello = Hi".to_string(),
+            )
+            .expect_err("unregistered domains should be rejected");
+        assert!(matches!(unknown_domain, ResourceLoadError::Load { .. }));
+    }
+
+    #[test]
+    fn bundle_options_rebuild_the_selected_locale() {
+        let i18n = DioxusAssetI18n::new_with_loaded_modules(
+            vec![loaded_module()],
+            langid!("en"),
+            LanguageSelectionPolicy::BestEffort,
+        )
+        .expect("module should load");
+        i18n.reload_resource(
+            langid!("en"),
+            static_domain("test-app"),
+            "hello = Hi { SHOUT(\"there\") }".to_string(),
+        )
+        .expect("reload should succeed");
+
+        i18n.add_function("SHOUT", |positional, _named| match positional.first() {
+            Some(fluent_bundle::FluentValue::String(value)) => {
+                fluent_bundle::FluentValue::from(value.to_uppercase())
+            },
+            _ => fluent_bundle::FluentValue::Error,
+        });
+        assert_eq!(
+            i18n.localize(static_entry("hello"), None),
+            Some("Hi \u{2068}THERE\u{2069}".to_string())
+        );
+
+        i18n.set_use_isolating(false);
+        assert!(!i18n.bundle_options().use_isolating());
+        assert_eq!(
+            i18n.localize(static_entry("hello"), None),
+            Some("Hi THERE".to_string())
+        );
+    }
+
     #[test]
     fn bundle_assembly_errors_are_returned_for_initial_locale() {
         let error = duplicate_resource_module()
-            .create_localizer()
+            .create_localizer(BundleOptions::default())
             .select_language(&langid!("en"))
             .expect_err("duplicate messages should fail the initial bundle");
 
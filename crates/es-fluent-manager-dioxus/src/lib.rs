@@ -21,11 +21,11 @@ mod error;
 pub mod ssr;
 
 pub use error::DioxusAssetI18nContextError;
-pub use es_fluent_manager_core::LanguageSelectionPolicy;
+pub use es_fluent_manager_core::{BundleOptions, FluentFunction, LanguageSelectionPolicy};
 
 pub use asset_loader::{
     DioxusAssetI18n, DioxusAssetLoadError, DioxusI18nAssetModule, DioxusI18nAssetModules,
-    DioxusI18nAssetResource,
+    DioxusI18nAssetResource, DioxusI18nAssetSource,
 };
 
 #[cfg(feature = "client")]
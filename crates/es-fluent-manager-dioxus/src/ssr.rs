@@ -151,6 +151,24 @@ impl FluentLocalizer for SsrI18n {
         FluentLocalizer::localize_in_domain(&self.i18n, domain, id, args)
     }
 
+    fn localize_attribute_in_domain<'a>(
+        &self,
+        domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&'a FluentArgs<'a>>,
+    ) -> Option<String> {
+        FluentLocalizer::localize_attribute_in_domain(&self.i18n, domain, id, attribute, args)
+    }
+
+    fn has_message_in_domain(&self, domain: StaticFluentDomain, id: StaticFluentEntryId) -> bool {
+        FluentLocalizer::has_message_in_domain(&self.i18n, domain, id)
+    }
+
+    fn has_domain(&self, domain: StaticFluentDomain) -> bool {
+        FluentLocalizer::has_domain(&self.i18n, domain)
+    }
+
     fn with_lookup(&self, f: &mut dyn FnMut(&mut FluentLocalizerLookup<'_>)) {
         FluentLocalizer::with_lookup(&self.i18n, f);
     }
@@ -240,6 +258,15 @@ mod tests {
             Some("Hello from asset".to_string())
         );
         assert_eq!(i18n.localize_message(&TestMessage), "Hello from asset");
+        assert_eq!(
+            i18n.localize_attribute_in_domain(
+                static_domain("asset-test"),
+                static_entry("asset-hello"),
+                "tooltip",
+                None
+            ),
+            Some("Asset tooltip".to_string())
+        );
 
         i18n.select_language(langid!("fr"))
             .expect("SSR request should switch language");
@@ -1 +1,2 @@
 asset-hello = Hello from asset
+    .tooltip = Asset tooltip
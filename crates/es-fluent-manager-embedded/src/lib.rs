@@ -2,11 +2,17 @@
 
 use es_fluent::{
     FluentArgs, FluentLocalizer, FluentLocalizerExt, FluentLocalizerLookup, FluentMessage,
+    LocalizedDisplay,
     registry::{StaticFluentDomain, StaticFluentEntryId},
 };
-use es_fluent_manager_core::{FluentManager, ModuleDiscoveryError};
+use es_fluent_manager_core::{
+    DiscoveredRuntimeI18nModules, FluentManager, LanguageChangedCallback, ModuleDiscoveryError,
+    resolve_fallback_language,
+};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, RwLock};
-use tracing::info;
+use tracing::{info, warn};
 use unic_langid::LanguageIdentifier;
 
 #[doc(hidden)]
@@ -24,6 +30,26 @@ pub use unic_langid as __unic_langid;
 #[cfg(feature = "macros")]
 pub use es_fluent_manager_macros::define_embedded_i18n_module as define_i18n_module;
 
+/// Expanded by `define_i18n_module!(compress = "...")`.
+#[doc(hidden)]
+#[cfg(feature = "compression")]
+#[macro_export]
+macro_rules! __require_embed_compression {
+    () => {};
+}
+
+/// Expanded by `define_i18n_module!(compress = "...")`.
+#[doc(hidden)]
+#[cfg(not(feature = "compression"))]
+#[macro_export]
+macro_rules! __require_embed_compression {
+    () => {
+        compile_error!(
+            "`define_i18n_module!(compress = ...)` requires the `compression` feature of `es-fluent-manager-embedded`"
+        );
+    };
+}
+
 pub use es_fluent_manager_core::LocalizationError;
 
 #[derive(Debug)]
@@ -70,6 +96,25 @@ struct ActiveSelection {
     policy: EmbeddedSelectionPolicy,
 }
 
+thread_local! {
+    /// Language overrides entered by [`EmbeddedI18n::with_language`] on this
+    /// thread, innermost last, keyed by the owning context's shared manager.
+    static SCOPED_MANAGERS: RefCell<Vec<(usize, Arc<FluentManager>)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// Pops the override pushed by [`EmbeddedI18n::with_language`], also when the
+/// scoped closure unwinds.
+struct ScopedManagerGuard;
+
+impl Drop for ScopedManagerGuard {
+    fn drop(&mut self) {
+        SCOPED_MANAGERS.with(|scoped| {
+            scoped.borrow_mut().pop();
+        });
+    }
+}
+
 /// Explicit embedded localization context.
 ///
 /// Construct this once during application startup, keep it in application state,
@@ -78,21 +123,88 @@ struct ActiveSelection {
 pub struct EmbeddedI18n {
     manager: Arc<FluentManager>,
     active_selection: Arc<RwLock<Option<ActiveSelection>>>,
+    discovered: DiscoveredRuntimeI18nModules,
+    scoped_managers: Arc<RwLock<HashMap<LanguageIdentifier, Arc<FluentManager>>>>,
+    language_changed_callbacks: Arc<RwLock<Vec<LanguageChangedCallback>>>,
 }
 
 impl EmbeddedI18n {
-    fn from_manager(manager: FluentManager) -> Self {
-        Self::from_manager_with_active_selection(manager, None)
+    fn from_discovered(discovered: DiscoveredRuntimeI18nModules) -> Self {
+        Self::from_discovered_with_active_selection(
+            FluentManager::from_discovered_modules(&discovered),
+            discovered,
+            None,
+        )
     }
 
-    fn from_manager_with_active_selection(
+    fn from_discovered_with_active_selection(
         manager: FluentManager,
+        discovered: DiscoveredRuntimeI18nModules,
         active_selection: Option<ActiveSelection>,
     ) -> Self {
         Self {
             manager: Arc::new(manager),
             active_selection: Arc::new(RwLock::new(active_selection)),
+            discovered,
+            scoped_managers: Arc::default(),
+            language_changed_callbacks: Arc::default(),
+        }
+    }
+
+    fn context_key(&self) -> usize {
+        Arc::as_ptr(&self.manager) as usize
+    }
+
+    /// The manager lookups go through: the innermost [`Self::with_language`]
+    /// override for this context on the current thread, or the shared one.
+    fn current_manager(&self) -> Arc<FluentManager> {
+        let key = self.context_key();
+        SCOPED_MANAGERS
+            .with(|scoped| {
+                scoped
+                    .borrow()
+                    .iter()
+                    .rev()
+                    .find(|(owner, _)| *owner == key)
+                    .map(|(_, manager)| Arc::clone(manager))
+            })
+            .unwrap_or_else(|| Arc::clone(&self.manager))
+    }
+
+    /// Returns the cached manager for `lang`, building and selecting it on
+    /// first use.
+    ///
+    /// Managers are cached under the loaded language `lang` negotiates to, so
+    /// requested tags that resolve alike (`fr-CA` and `fr-FR` with only `fr`
+    /// loaded) share one manager and the cache never outgrows the loaded
+    /// languages. Tags that resolve to nothing fail selection and are not
+    /// cached.
+    fn scoped_manager(
+        &self,
+        lang: &LanguageIdentifier,
+    ) -> Result<Arc<FluentManager>, LocalizationError> {
+        let lang = &resolve_fallback_language(lang, &self.manager.loaded_languages())
+            .unwrap_or_else(|| lang.clone());
+        if let Some(manager) = self
+            .scoped_managers
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .get(lang)
+        {
+            return Ok(Arc::clone(manager));
         }
+
+        let manager = FluentManager::from_discovered_modules(&self.discovered);
+        manager.select_language(lang)?;
+        let mut scoped_managers = self
+            .scoped_managers
+            .write()
+            .unwrap_or_else(|error| error.into_inner());
+        Ok(Arc::clone(
+            scoped_managers
+                .entry(lang.clone())
+                .or_insert_with(|| Arc::new(manager)),
+        ))
     }
 
     fn language_is_active(
@@ -122,10 +234,20 @@ impl EmbeddedI18n {
         });
     }
 
+    fn notify_language_changed(&self, lang: &LanguageIdentifier) {
+        let callbacks = self
+            .language_changed_callbacks
+            .read()
+            .unwrap_or_else(|error| error.into_inner());
+        for callback in callbacks.iter() {
+            callback(lang);
+        }
+    }
+
     /// Builds an embedded context without selecting a language.
     pub fn try_new() -> Result<Self, EmbeddedInitError> {
-        FluentManager::try_new_with_discovered_modules()
-            .map(Self::from_manager)
+        FluentManager::try_discover_runtime_modules()
+            .map(Self::from_discovered)
             .map_err(EmbeddedInitError::ModuleDiscovery)
     }
 
@@ -134,13 +256,15 @@ impl EmbeddedI18n {
         lang: L,
     ) -> Result<Self, EmbeddedInitError> {
         let lang = lang.into();
-        let manager = FluentManager::try_new_with_discovered_modules()
+        let discovered = FluentManager::try_discover_runtime_modules()
             .map_err(EmbeddedInitError::ModuleDiscovery)?;
+        let manager = FluentManager::from_discovered_modules(&discovered);
         manager
             .select_language(&lang)
             .map_err(EmbeddedInitError::LanguageSelection)?;
-        Ok(Self::from_manager_with_active_selection(
+        Ok(Self::from_discovered_with_active_selection(
             manager,
+            discovered,
             Some(ActiveSelection {
                 language: lang,
                 policy: EmbeddedSelectionPolicy::BestEffort,
@@ -154,13 +278,15 @@ impl EmbeddedI18n {
         lang: L,
     ) -> Result<Self, EmbeddedInitError> {
         let lang = lang.into();
-        let manager = FluentManager::try_new_with_discovered_modules()
+        let discovered = FluentManager::try_discover_runtime_modules()
             .map_err(EmbeddedInitError::ModuleDiscovery)?;
+        let manager = FluentManager::from_discovered_modules(&discovered);
         manager
             .select_language_strict(&lang)
             .map_err(EmbeddedInitError::LanguageSelection)?;
-        Ok(Self::from_manager_with_active_selection(
+        Ok(Self::from_discovered_with_active_selection(
             manager,
+            discovered,
             Some(ActiveSelection {
                 language: lang,
                 policy: EmbeddedSelectionPolicy::Strict,
@@ -180,7 +306,8 @@ impl EmbeddedI18n {
 
         info!("Changing locale to: {}", lang);
         self.manager.select_language(&lang)?;
-        self.store_active_language(lang, EmbeddedSelectionPolicy::BestEffort);
+        self.store_active_language(lang.clone(), EmbeddedSelectionPolicy::BestEffort);
+        self.notify_language_changed(&lang);
         Ok(())
     }
 
@@ -197,10 +324,110 @@ impl EmbeddedI18n {
 
         info!("Changing locale to: {}", lang);
         self.manager.select_language_strict(&lang)?;
-        self.store_active_language(lang, EmbeddedSelectionPolicy::Strict);
+        self.store_active_language(lang.clone(), EmbeddedSelectionPolicy::Strict);
+        self.notify_language_changed(&lang);
         Ok(())
     }
 
+    /// Parses `lang` with [`FluentManager::parse_language`] and selects it like
+    /// [`Self::select_language`], for locales stored as config strings.
+    pub fn select_language_str(&self, lang: &str) -> Result<(), LocalizationError> {
+        self.select_language(FluentManager::parse_language(lang)?)
+    }
+
+    /// Parses `lang` with [`FluentManager::parse_language`] and selects it like
+    /// [`Self::select_language_strict`].
+    pub fn select_language_strict_str(&self, lang: &str) -> Result<(), LocalizationError> {
+        self.select_language_strict(FluentManager::parse_language(lang)?)
+    }
+
+    /// Rebuilds this context's bundles from the current embedded resources,
+    /// keeping the active language and any [`Self::with_language`] caches.
+    ///
+    /// Release builds compile the FTL files into the binary, so reloading
+    /// rebuilds identical bundles. Debug builds read them from disk unless
+    /// the `debug-embed` feature is enabled, so edits show up
+    /// without restarting. Lookups keep using the previous bundles until the
+    /// new ones are swapped in, and no language-changed callbacks run. See
+    /// [`FluentManager::reload`].
+    ///
+    /// Every manager is reloaded even when an earlier one fails. The first
+    /// error is returned and the others are logged; a manager that fails keeps
+    /// its previous bundles.
+    pub fn reload(&self) -> Result<(), LocalizationError> {
+        let scoped_managers = self
+            .scoped_managers
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut errors = std::iter::once(&self.manager)
+            .chain(&scoped_managers)
+            .filter_map(|manager| manager.reload().err());
+        let first_error = errors.next();
+        for error in errors {
+            warn!("Failed to reload a scoped language: {error}");
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Registers `callback` to run after this context's active language changes.
+    ///
+    /// Callbacks are shared by every clone of the context and run in
+    /// registration order after a successful [`Self::select_language`] or
+    /// [`Self::select_language_strict`] that switches language. Reselecting the
+    /// active language, failed selections, and [`Self::with_language`] scopes
+    /// run no callbacks. A callback must not register further callbacks.
+    pub fn on_language_changed(
+        &self,
+        callback: impl Fn(&LanguageIdentifier) + Send + Sync + 'static,
+    ) {
+        self.language_changed_callbacks
+            .write()
+            .unwrap_or_else(|error| error.into_inner())
+            .push(Box::new(callback));
+    }
+
+    /// Runs `f` with lookups through this context on the current thread served
+    /// in `lang`, leaving the context's active language untouched.
+    ///
+    /// Other threads, and other clones used elsewhere, keep the active
+    /// language, so a server can render each request in its own locale from
+    /// one shared context. Bundles for each scoped language are built on first
+    /// use and cached in the context under the loaded language `lang`
+    /// resolves to. Nested calls use the innermost language.
+    ///
+    /// Fails without running `f` if no discovered module can serve `lang`.
+    /// The override is thread-local, so do not hold it across an `.await`
+    /// that may resume on another thread.
+    pub fn with_language<L, R>(
+        &self,
+        lang: L,
+        f: impl FnOnce() -> R,
+    ) -> Result<R, LocalizationError>
+    where
+        L: Into<LanguageIdentifier>,
+    {
+        let manager = self.scoped_manager(&lang.into())?;
+        SCOPED_MANAGERS.with(|scoped| scoped.borrow_mut().push((self.context_key(), manager)));
+        let _guard = ScopedManagerGuard;
+        Ok(f())
+    }
+
+    /// Localizes a message in every loaded language without changing the
+    /// active language.
+    ///
+    /// Languages that lack the message map to `None`. See
+    /// [`FluentManager::localize_all`].
+    pub fn localize_all<'a>(
+        &self,
+        id: StaticFluentEntryId,
+        args: Option<&FluentArgs<'a>>,
+    ) -> BTreeMap<LanguageIdentifier, Option<String>> {
+        self.manager.localize_all(id, args.map(FluentArgs::as_raw))
+    }
+
     /// Renders a derived typed message through this context.
     pub fn localize_message<T>(&self, message: &T) -> String
     where
@@ -208,6 +435,34 @@ impl EmbeddedI18n {
     {
         FluentLocalizerExt::localize_message(self, message)
     }
+
+    /// Renders one attribute of a derived typed message through this
+    /// context, or `None` when it is missing.
+    pub fn localize_message_attribute<T>(&self, message: &T, attribute: &str) -> Option<String>
+    where
+        T: FluentMessage + ?Sized,
+    {
+        FluentLocalizerExt::localize_message_attribute(self, message, attribute)
+    }
+
+    /// Wraps a derived typed message so it formats through this context with
+    /// `{}`, for example in `write!` or logging macros.
+    pub fn display_message<'a, T>(&'a self, message: &'a T) -> LocalizedDisplay<'a, Self, T>
+    where
+        T: FluentMessage + ?Sized,
+    {
+        FluentLocalizerExt::display_message(self, message)
+    }
+
+    /// Renders each derived typed message through this context and joins
+    /// them with `separator`.
+    pub fn localize_joined<I>(&self, messages: I, separator: &str) -> String
+    where
+        I: IntoIterator,
+        I::Item: FluentMessage,
+    {
+        FluentLocalizerExt::localize_joined(self, messages, separator)
+    }
 }
 
 impl FluentLocalizer for EmbeddedI18n {
@@ -216,7 +471,7 @@ impl FluentLocalizer for EmbeddedI18n {
         id: StaticFluentEntryId,
         args: Option<&FluentArgs<'a>>,
     ) -> Option<String> {
-        FluentManager::localize(self.manager.as_ref(), id, args.map(FluentArgs::as_raw))
+        FluentManager::localize(&self.current_manager(), id, args.map(FluentArgs::as_raw))
     }
 
     fn localize_in_domain<'a>(
@@ -226,15 +481,39 @@ impl FluentLocalizer for EmbeddedI18n {
         args: Option<&FluentArgs<'a>>,
     ) -> Option<String> {
         FluentManager::localize_in_domain(
-            self.manager.as_ref(),
+            &self.current_manager(),
+            domain,
+            id,
+            args.map(FluentArgs::as_raw),
+        )
+    }
+
+    fn localize_attribute_in_domain<'a>(
+        &self,
+        domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&FluentArgs<'a>>,
+    ) -> Option<String> {
+        FluentManager::localize_attribute_in_domain(
+            &self.current_manager(),
             domain,
             id,
+            attribute,
             args.map(FluentArgs::as_raw),
         )
     }
 
+    fn has_message_in_domain(&self, domain: StaticFluentDomain, id: StaticFluentEntryId) -> bool {
+        FluentManager::has_message_in_domain(&self.current_manager(), domain, id)
+    }
+
+    fn has_domain(&self, domain: StaticFluentDomain) -> bool {
+        FluentManager::has_domain(&self.current_manager(), domain)
+    }
+
     fn with_lookup(&self, f: &mut dyn FnMut(&mut FluentLocalizerLookup<'_>)) {
-        FluentManager::with_lookup(self.manager.as_ref(), &mut |lookup| {
+        FluentManager::with_lookup(&self.current_manager(), &mut |lookup| {
             let mut typed_lookup =
                 |domain: StaticFluentDomain,
                  id: StaticFluentEntryId,
@@ -318,6 +597,26 @@ mod tests {
 
             Some(value.to_string())
         }
+
+        fn localize_attribute<'a>(
+            &self,
+            id: StaticFluentEntryId,
+            attribute: &str,
+            _args: Option<&es_fluent_manager_core::FluentArgumentMap<'a>>,
+        ) -> Option<String> {
+            let selected = self
+                .selected
+                .lock()
+                .expect("test localizer language lock should not be poisoned")
+                .to_string();
+            let value = match (selected.as_str(), id.as_str(), attribute) {
+                ("en-US", "hello", "tooltip") => "Say hello",
+                ("fr", "hello", "tooltip") => "Dire bonjour",
+                _ => return None,
+            };
+
+            Some(value.to_string())
+        }
     }
 
     struct TestMessage;
@@ -426,6 +725,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn select_language_str_parses_tags_before_selecting() {
+        force_inventory_link();
+        let i18n = EmbeddedI18n::try_new_with_language(langid!("en-US"))
+            .expect("embedded i18n should initialize");
+        let hello = |i18n: &EmbeddedI18n| {
+            es_fluent::FluentLocalizer::localize_in_domain(
+                i18n,
+                static_domain("embedded-test-module"),
+                static_entry("hello"),
+                None,
+            )
+        };
+
+        i18n.select_language_str("fr").expect("fr should select");
+        assert_eq!(hello(&i18n), Some("Bonjour".to_string()));
+        i18n.select_language_strict_str("en-us")
+            .expect("casing is normalized");
+        assert_eq!(hello(&i18n), Some("Hello".to_string()));
+
+        assert!(matches!(
+            i18n.select_language_str("en_US!"),
+            Err(LocalizationError::InvalidLanguageIdentifier { identifier, .. })
+                if identifier == "en_US!"
+        ));
+        assert_eq!(hello(&i18n), Some("Hello".to_string()));
+    }
+
     #[test]
     fn embedded_i18n_facade_methods_delegate_to_manager_and_typed_lookup() {
         force_inventory_link();
@@ -448,6 +775,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn embedded_i18n_localizes_typed_message_attributes() {
+        force_inventory_link();
+        let i18n = EmbeddedI18n::try_new_with_language(langid!("en-US"))
+            .expect("embedded i18n should initialize");
+
+        assert_eq!(
+            i18n.localize_message_attribute(&TestMessage, "tooltip"),
+            Some("Say hello".to_string())
+        );
+        assert_eq!(i18n.localize_message_attribute(&TestMessage, "label"), None);
+
+        i18n.select_language(langid!("fr"))
+            .expect("fr should be supported");
+        assert_eq!(
+            i18n.localize_message_attribute(&TestMessage, "tooltip"),
+            Some("Dire bonjour".to_string())
+        );
+    }
+
     #[test]
     fn embedded_i18n_strict_initialization_tracks_active_language() {
         force_inventory_link();
@@ -507,6 +854,176 @@ mod tests {
         );
     }
 
+    #[test]
+    fn embedded_i18n_with_language_overrides_lookups_per_thread_and_scope() {
+        force_inventory_link();
+        let i18n = EmbeddedI18n::try_new_with_language(langid!("en-US"))
+            .expect("embedded i18n should initialize");
+        let other = EmbeddedI18n::try_new_with_language(langid!("en-US"))
+            .expect("second embedded i18n should initialize");
+
+        let rendered = i18n
+            .with_language(langid!("fr"), || {
+                let nested = i18n
+                    .with_language(langid!("en-US"), || i18n.localize_message(&TestMessage))
+                    .expect("nested scope should select en-US");
+                (
+                    i18n.localize_message(&TestMessage),
+                    nested,
+                    other.localize_message(&TestMessage),
+                )
+            })
+            .expect("scope should select fr");
+        assert_eq!(
+            rendered,
+            (
+                "Bonjour".to_string(),
+                "Hello".to_string(),
+                "Hello".to_string()
+            )
+        );
+        assert_eq!(i18n.localize_message(&TestMessage), "Hello");
+
+        std::thread::scope(|scope| {
+            let french = scope.spawn(|| {
+                i18n.with_language(langid!("fr"), || i18n.localize_message(&TestMessage))
+            });
+            assert_eq!(i18n.localize_message(&TestMessage), "Hello");
+            assert_eq!(
+                french
+                    .join()
+                    .expect("thread should not panic")
+                    .expect("fr scope"),
+                "Bonjour"
+            );
+        });
+
+        assert!(matches!(
+            i18n.with_language(langid!("de"), || unreachable!("unsupported scope runs")),
+            Err(LocalizationError::LanguageNotSupported(lang)) if lang == langid!("de")
+        ));
+
+        let unwound = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            i18n.with_language(langid!("fr"), || panic!("scoped render failed"))
+        }));
+        assert!(unwound.is_err());
+        assert_eq!(i18n.localize_message(&TestMessage), "Hello");
+    }
+
+    #[test]
+    fn embedded_i18n_with_language_caches_aliases_under_the_negotiated_language() {
+        force_inventory_link();
+        let i18n = EmbeddedI18n::try_new_with_language(langid!("en-US"))
+            .expect("embedded i18n should initialize");
+
+        for lang in [langid!("fr-CA"), langid!("fr-FR"), langid!("fr")] {
+            assert_eq!(
+                i18n.with_language(lang, || i18n.localize_message(&TestMessage))
+                    .expect("fr aliases should resolve to fr"),
+                "Bonjour"
+            );
+        }
+        i18n.with_language(langid!("de"), || ())
+            .expect_err("de should be rejected");
+
+        let scoped_managers = i18n
+            .scoped_managers
+            .read()
+            .expect("scoped manager lock should not be poisoned");
+        assert_eq!(
+            scoped_managers.keys().cloned().collect::<Vec<_>>(),
+            vec![langid!("fr")]
+        );
+    }
+
+    #[test]
+    fn embedded_i18n_language_changed_callbacks_run_on_actual_changes() {
+        force_inventory_link();
+        let i18n = EmbeddedI18n::try_new_with_language(langid!("en-US"))
+            .expect("embedded i18n should initialize");
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&changes);
+        let observer = i18n.clone();
+        i18n.on_language_changed(move |lang| {
+            recorded
+                .lock()
+                .expect("change log lock should not be poisoned")
+                .push((lang.clone(), observer.localize_message(&TestMessage)));
+        });
+
+        i18n.select_language(langid!("en-US"))
+            .expect("reselecting the active language should be a no-op");
+        i18n.select_language(langid!("fr"))
+            .expect("fr should be supported");
+        i18n.select_language(langid!("de"))
+            .expect_err("de should be rejected");
+        i18n.with_language(langid!("en-US"), || ())
+            .expect("scoped selection should work");
+        i18n.select_language_strict(langid!("en-US"))
+            .expect("en-US should be supported");
+
+        assert_eq!(
+            *changes
+                .lock()
+                .expect("change log lock should not be poisoned"),
+            vec![
+                (langid!("fr"), "Bonjour".to_string()),
+                (langid!("en-US"), "Hello".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn embedded_i18n_reload_keeps_active_and_scoped_languages() {
+        force_inventory_link();
+        let i18n = EmbeddedI18n::try_new_with_language(langid!("fr"))
+            .expect("embedded i18n should initialize");
+        let changes = Arc::new(Mutex::new(0));
+        let recorded = Arc::clone(&changes);
+        i18n.on_language_changed(move |_| {
+            *recorded
+                .lock()
+                .expect("change count lock should not be poisoned") += 1;
+        });
+        i18n.with_language(langid!("en-US"), || ())
+            .expect("scoped selection should work");
+
+        i18n.reload().expect("reload should succeed");
+
+        assert_eq!(i18n.localize_message(&TestMessage), "Bonjour");
+        assert_eq!(
+            i18n.with_language(langid!("en-US"), || i18n.localize_message(&TestMessage))
+                .expect("scoped selection should work"),
+            "Hello"
+        );
+        assert_eq!(
+            *changes
+                .lock()
+                .expect("change count lock should not be poisoned"),
+            0
+        );
+    }
+
+    #[test]
+    fn embedded_i18n_localize_all_covers_every_loaded_language() {
+        force_inventory_link();
+        let i18n = EmbeddedI18n::try_new_with_language(langid!("fr"))
+            .expect("embedded i18n should initialize");
+
+        assert_eq!(
+            i18n.localize_all(static_entry("hello"), None),
+            BTreeMap::from([
+                (langid!("en-US"), Some("Hello".to_string())),
+                (langid!("fr"), Some("Bonjour".to_string())),
+            ])
+        );
+        assert_eq!(
+            i18n.localize_all(static_entry("missing"), None),
+            BTreeMap::from([(langid!("en-US"), None), (langid!("fr"), None)])
+        );
+        assert_eq!(i18n.localize_message(&TestMessage), "Bonjour");
+    }
+
     #[test]
     fn embedded_init_error_display_and_source_match_error_kind() {
         use es_fluent_manager_core::{ModuleDiscoveryError, ModuleRegistrationKind};
@@ -0,0 +1,98 @@
+use std::process::Command;
+
+use path_slash::PathExt as _;
+use tempfile::TempDir;
+
+fn toml_path(path: &std::path::Path) -> String {
+    path.to_slash_lossy().into_owned()
+}
+
+// `debug-embed` makes the debug build embed the files like a release build
+// does; without it rust-embed reads them from disk and never compresses them.
+#[test]
+fn compressed_modules_localize_from_inflated_ftl() {
+    let temp = TempDir::new().expect("create temp dir");
+    let crate_root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+    let facade_path = crate_root
+        .parent()
+        .expect("crates directory")
+        .join("es-fluent");
+
+    for algorithm in ["deflate", "zstd"] {
+        let crate_dir = temp.path().join(algorithm);
+        std::fs::create_dir_all(crate_dir.join("src")).expect("create src dir");
+        std::fs::create_dir_all(crate_dir.join("i18n/en")).expect("create locale dir");
+
+        std::fs::write(
+            crate_dir.join("Cargo.toml"),
+            format!(
+                r#"
+[package]
+name = "compressed-module-fixture"
+version = "0.0.0"
+edition = "2024"
+
+[dependencies]
+es-fluent = {{ path = "{}" }}
+es-fluent-manager-embedded = {{ path = "{}", features = ["compression", "debug-embed"] }}
+"#,
+                toml_path(&facade_path),
+                toml_path(crate_root)
+            ),
+        )
+        .expect("write Cargo.toml");
+        std::fs::write(
+            crate_dir.join("i18n.toml"),
+            "fallback_language = \"en\"\nassets_dir = \"i18n\"\n",
+        )
+        .expect("write i18n.toml");
+        std::fs::write(
+            crate_dir.join("i18n/en/compressed-module-fixture.ftl"),
+            "hello = Hello from a compressed module\n",
+        )
+        .expect("write ftl");
+        std::fs::write(
+            crate_dir.join("src/main.rs"),
+            format!(
+                r#"
+es_fluent_manager_embedded::define_i18n_module!(compress = "{algorithm}");
+
+fn main() {{
+    let i18n = es_fluent_manager_embedded::EmbeddedI18n::try_new().expect("initialize");
+    i18n.select_language_str("en").expect("select en");
+    let message = es_fluent::FluentLocalizer::localize_in_domain(
+        &i18n,
+        es_fluent::registry::StaticFluentDomain::try_new("compressed-module-fixture")
+            .expect("domain"),
+        es_fluent::registry::StaticFluentEntryId::try_new("hello").expect("id"),
+        None,
+    );
+    print!("{{}}", message.expect("hello should be localized"));
+}}
+"#
+            ),
+        )
+        .expect("write main.rs");
+
+        let output = Command::new("cargo")
+            .arg("run")
+            .arg("--quiet")
+            .arg("--manifest-path")
+            .arg(crate_dir.join("Cargo.toml"))
+            .env("CARGO_TARGET_DIR", temp.path().join("target"))
+            .output()
+            .expect("run cargo run");
+
+        assert!(
+            output.status.success(),
+            "cargo run with compress = {algorithm:?} failed\nstatus: {}\nstdout:\n{}\nstderr:\n{}",
+            output.status,
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "Hello from a compressed module"
+        );
+    }
+}
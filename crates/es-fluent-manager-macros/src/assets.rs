@@ -1,10 +1,19 @@
+use es_fluent_shared::resource::AssetsLayout;
 pub(crate) use es_fluent_shared::resource::ModuleResourceSpec as ResourceSpec;
 use es_fluent_shared::{LanguageIdentifier, namespace::ResolvedNamespace};
+use path_slash::PathExt as _;
 use quote::quote;
-use std::{fmt, path::PathBuf};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
 
 pub(crate) struct I18nAssets {
     pub(crate) root_path: PathBuf,
+    /// Base FTL file name without `.ftl`, from `ftl_file_name` in `i18n.toml`.
+    pub(crate) base_file_stem: String,
+    /// Arrangement of locale files below `root_path`, from `layout` in `i18n.toml`.
+    pub(crate) layout: AssetsLayout,
     pub(crate) languages: Vec<LanguageIdentifier>,
     pub(crate) namespaces: Vec<ResolvedNamespace>,
     pub(crate) resource_specs_by_language: Vec<(LanguageIdentifier, Vec<ResourceSpec>)>,
@@ -30,6 +39,8 @@ impl fmt::Debug for I18nAssets {
 
         f.debug_struct("I18nAssets")
             .field("root_path", &self.root_path)
+            .field("base_file_stem", &self.base_file_stem)
+            .field("layout", &self.layout)
             .field("languages", &languages)
             .field("namespaces", &namespaces)
             .field("resource_specs_by_language", &resource_specs_by_language)
@@ -73,13 +84,21 @@ pub(crate) fn module_data_static_tokens(
     }
 }
 
+/// Fails unless the configured fallback locale directory holds at least one
+/// `.ftl` file.
+pub(crate) fn validate_fallback_present() -> syn::Result<()> {
+    es_fluent_toml::I18nConfig::read_from_workspace_root()
+        .and_then(|config| config.validate_fallback_present())
+        .map_err(|error| macro_error(format!("strict mode: {error}")))
+}
+
 impl I18nAssets {
     pub(crate) fn load(crate_name: &str) -> syn::Result<Self> {
-        let config = match es_fluent_toml::I18nConfig::read_from_manifest_dir() {
+        let config = match es_fluent_toml::I18nConfig::read_from_workspace_root() {
             Ok(config) => config,
             Err(es_fluent_toml::I18nConfigError::NotFound) => {
                 return Err(macro_error(
-                    "No i18n.toml configuration file found in project root. Please create one with the required settings.",
+                    "No i18n.toml configuration file found in the crate or workspace root. Please create one with the required settings.",
                 ));
             },
             Err(e) => {
@@ -100,6 +119,13 @@ impl I18nAssets {
             },
         };
 
+        if !i18n_root_path.exists() {
+            return Err(macro_error(format!(
+                "Assets directory '{}' does not exist. Run `cargo es-fluent generate` to create it, or call `es_fluent_build::track_i18n_assets()` from build.rs to create an empty locale tree.",
+                i18n_root_path.to_slash_lossy()
+            )));
+        }
+
         if let Err(e) = config.validate_assets_dir() {
             return Err(macro_error(format!(
                 "Assets directory validation failed: {}",
@@ -107,22 +133,77 @@ impl I18nAssets {
             )));
         }
 
+        let base_file_stem = config.ftl_file_stem(crate_name);
         let (languages, namespaces, resource_specs_by_language) =
-            es_fluent_shared::resource::ResourcePlan::sparse_from_assets(
+            es_fluent_shared::resource::ResourcePlan::sparse_from_assets_with_layout(
                 crate_name,
+                &base_file_stem,
                 &i18n_root_path,
+                config.layout,
             )
             .map_err(|error| macro_error(error.to_string()))?
             .into_parts();
 
         Ok(Self {
             root_path: i18n_root_path,
+            base_file_stem,
+            layout: config.layout,
             languages,
             namespaces,
             resource_specs_by_language,
         })
     }
 
+    /// Returns locale directories under the assets root that ship neither the
+    /// crate's base FTL file nor any of its namespaced files.
+    ///
+    /// With the domain-first layout, the locales of every domain directory are
+    /// checked instead.
+    pub(crate) fn locales_missing_crate_ftl(&self) -> syn::Result<Vec<String>> {
+        let read_dir = |dir: &Path| {
+            std::fs::read_dir(dir).map_err(|error| {
+                macro_error(format!(
+                    "Failed to read assets directory {:?}: {}",
+                    dir, error
+                ))
+            })
+        };
+
+        let mut locale_names = Vec::new();
+        for entry in read_dir(&self.root_path)?.flatten() {
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+            if self.layout == AssetsLayout::LanguageFirst {
+                locale_names.push(path);
+                continue;
+            }
+            for entry in read_dir(&path)?.flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    locale_names.push(path);
+                } else if path.extension().is_some_and(|extension| extension == "ftl") {
+                    locale_names.push(path.with_extension(""));
+                }
+            }
+        }
+
+        let mut missing = Vec::new();
+        for path in locale_names {
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if !self.languages.iter().any(|lang| *lang == name) {
+                missing.push(name.to_string());
+            }
+        }
+        missing.sort();
+        missing.dedup();
+
+        Ok(missing)
+    }
+
     pub(crate) fn language_identifier_tokens(
         &self,
         langid_path: &proc_macro2::TokenStream,
@@ -185,7 +266,6 @@ impl I18nAssets {
 mod tests {
     use super::*;
     use insta::{assert_debug_snapshot, assert_snapshot};
-    use path_slash::PathExt as _;
     use quote::quote;
     use std::collections::BTreeMap;
 
@@ -390,6 +470,23 @@ mod tests {
         });
     }
 
+    #[test]
+    fn i18n_assets_load_accepts_build_script_skeleton() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        write_manifest(temp.path(), "i18n");
+        std::fs::create_dir_all(temp.path().join("i18n/en-US")).expect("mkdir en-US");
+
+        with_env_var("CARGO_MANIFEST_DIR", temp.path().to_str(), || {
+            let assets = I18nAssets::load("my-crate").expect("skeleton should load");
+            assert!(
+                assets
+                    .resource_specs_by_language
+                    .iter()
+                    .all(|(_, specs)| specs.is_empty())
+            );
+        });
+    }
+
     #[test]
     fn i18n_assets_load_rejects_noncanonical_locale_directories() {
         let temp = tempfile::tempdir().expect("tempdir");
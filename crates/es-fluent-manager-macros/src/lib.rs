@@ -17,6 +17,12 @@ use proc_macro::TokenStream;
 /// 2.  Discover the available languages in the configured `assets_dir`.
 /// 3.  Generate a `RustEmbed` struct for the i18n assets.
 /// 4.  Generate an `EmbeddedI18nModule` for the crate.
+///
+/// Locale directories without this crate's FTL are skipped by default. Pass
+/// `strict` to report them as a compile error instead.
+///
+/// Pass `compress = "deflate"` or `compress = "zstd"` to embed the FTL files
+/// compressed; this requires the manager crate's `compression` feature.
 #[proc_macro]
 pub fn define_embedded_i18n_module(input: TokenStream) -> TokenStream {
     module_macros::define_embedded_i18n_module(input)
@@ -40,7 +46,8 @@ pub fn define_bevy_i18n_module(input: TokenStream) -> TokenStream {
 ///
 /// 1.  Read the `i18n.toml` configuration file.
 /// 2.  Discover the available languages in the configured `assets_dir`.
-/// 3.  Generate Dioxus `asset!` registrations for the i18n assets.
+/// 3.  Generate Dioxus `asset!` registrations for the i18n assets, or embed the
+///     FTL bytes directly with `define_i18n_module!(embedded)`.
 /// 4.  Generate explicit async asset loader helpers for Dioxus integrations.
 #[proc_macro]
 pub fn define_dioxus_i18n_module(input: TokenStream) -> TokenStream {
@@ -1,4 +1,5 @@
 use crate::assets::I18nAssets;
+use es_fluent_shared::resource::AssetsLayout;
 use heck::ToPascalCase as _;
 use path_slash::PathExt as _;
 use proc_macro::TokenStream;
@@ -53,8 +54,24 @@ type ModuleTokenGenerator = fn(
     syn::Ident,
     proc_macro2::TokenStream,
     &ManagerPaths,
+    &ModuleArgs,
 ) -> syn::Result<proc_macro2::TokenStream>;
 
+/// Compression algorithms `rust_embed` accepts in `#[compression = "..."]`.
+const EMBED_COMPRESSION_ALGORITHMS: &[&str] = &["deflate", "zstd"];
+
+/// Arguments accepted by `define_i18n_module!`. The embedded and Dioxus
+/// managers take different subsets; the Bevy macro always uses the defaults.
+#[derive(Default)]
+struct ModuleArgs {
+    /// Reject locale directories that lack this crate's FTL.
+    strict: bool,
+    /// `rust_embed` compression algorithm for the embedded FTL files.
+    compression: Option<syn::LitStr>,
+    /// Compile Dioxus FTL into the binary instead of declaring `asset!`s.
+    embedded: bool,
+}
+
 fn reject_unexpected_input(input: TokenStream, macro_name: &str) -> Option<TokenStream> {
     es_fluent_derive_core::macro_input::ValidatedMacroInput::reject_argument_free(
         input.into(),
@@ -64,13 +81,129 @@ fn reject_unexpected_input(input: TokenStream, macro_name: &str) -> Option<Token
     .map(|error| TokenStream::from(error.to_compile_error()))
 }
 
+/// An optional `define_i18n_module!` argument.
+#[derive(Clone, Copy, PartialEq)]
+enum ModuleArg {
+    Strict,
+    Compress,
+    Embedded,
+}
+
+impl ModuleArg {
+    /// Arguments accepted by the embedded macro.
+    const EMBEDDED: &'static [Self] = &[Self::Strict, Self::Compress];
+    /// Arguments accepted by the Dioxus macro.
+    const DIOXUS: &'static [Self] = &[Self::Embedded];
+
+    fn syntax(self) -> &'static str {
+        match self {
+            Self::Strict => "`strict`",
+            Self::Compress => "`compress = \"deflate\" | \"zstd\"`",
+            Self::Embedded => "`embedded`",
+        }
+    }
+}
+
+/// Parses the `define_i18n_module!` arguments in `accepted`, each at most once.
+fn parse_module_args(
+    input: proc_macro2::TokenStream,
+    macro_name: &str,
+    accepted: &[ModuleArg],
+) -> syn::Result<ModuleArgs> {
+    use syn::parse::Parser as _;
+
+    let unexpected = |tokens: &dyn quote::ToTokens| {
+        let names = accepted
+            .iter()
+            .map(|arg| arg.syntax())
+            .collect::<Vec<_>>()
+            .join(" and ");
+        let noun = if accepted.len() == 1 {
+            "argument"
+        } else {
+            "arguments"
+        };
+        syn::Error::new_spanned(
+            tokens,
+            format!("`{macro_name}` accepts only the {names} {noun}"),
+        )
+    };
+    let metas = syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated
+        .parse2(input.clone())
+        .map_err(|_| unexpected(&input))?;
+
+    let mut args = ModuleArgs::default();
+    for meta in metas {
+        match &meta {
+            syn::Meta::Path(path)
+                if path.is_ident("strict")
+                    && accepted.contains(&ModuleArg::Strict)
+                    && !args.strict =>
+            {
+                args.strict = true;
+            },
+            syn::Meta::Path(path)
+                if path.is_ident("embedded")
+                    && accepted.contains(&ModuleArg::Embedded)
+                    && !args.embedded =>
+            {
+                args.embedded = true;
+            },
+            syn::Meta::NameValue(name_value)
+                if name_value.path.is_ident("compress")
+                    && accepted.contains(&ModuleArg::Compress)
+                    && args.compression.is_none() =>
+            {
+                let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(algorithm),
+                    ..
+                }) = &name_value.value
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &name_value.value,
+                        "`compress` expects a string literal such as \"zstd\"",
+                    ));
+                };
+                if !EMBED_COMPRESSION_ALGORITHMS.contains(&algorithm.value().as_str()) {
+                    return Err(syn::Error::new_spanned(
+                        algorithm,
+                        format!(
+                            "unsupported `compress` algorithm {:?}; expected one of {:?}",
+                            algorithm.value(),
+                            EMBED_COMPRESSION_ALGORITHMS
+                        ),
+                    ));
+                }
+                args.compression = Some(algorithm.clone());
+            },
+            _ => return Err(unexpected(&meta)),
+        }
+    }
+
+    Ok(args)
+}
+
 fn expand_define_i18n_module_tokens(
     manager_paths: ManagerPaths,
     generate_tokens: ModuleTokenGenerator,
+    args: &ModuleArgs,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let crate_name = crate::assets::current_crate_name()?;
     let assets = I18nAssets::load(&crate_name)?;
 
+    if args.strict {
+        crate::assets::validate_fallback_present()?;
+        let missing = assets.locales_missing_crate_ftl()?;
+        if !missing.is_empty() {
+            return Err(crate::assets::macro_error(format!(
+                "strict mode: locale directories under {:?} are missing `{}.ftl`: {}",
+                assets.root_path,
+                assets.base_file_stem,
+                missing.join(", ")
+            )));
+        }
+    }
+
     let language_identifiers = assets.language_identifier_tokens(&manager_paths.langid_path);
     let namespace_strings = assets.namespace_tokens();
 
@@ -97,25 +230,28 @@ fn expand_define_i18n_module_tokens(
         module_data_name,
         module_data_static,
         &manager_paths,
+        args,
     )
 }
 
 fn expand_define_i18n_module(
     manager_paths: ManagerPaths,
     generate_tokens: ModuleTokenGenerator,
+    args: &ModuleArgs,
 ) -> TokenStream {
-    match expand_define_i18n_module_tokens(manager_paths, generate_tokens) {
+    match expand_define_i18n_module_tokens(manager_paths, generate_tokens, args) {
         Ok(tokens) => TokenStream::from(tokens),
         Err(err) => TokenStream::from(err.to_compile_error()),
     }
 }
 
 pub(crate) fn define_embedded_i18n_module(input: TokenStream) -> TokenStream {
-    if let Some(error) = reject_unexpected_input(input, "define_i18n_module!") {
-        return error;
-    }
+    let args = match parse_module_args(input.into(), "define_i18n_module!", ModuleArg::EMBEDDED) {
+        Ok(args) => args,
+        Err(error) => return TokenStream::from(error.to_compile_error()),
+    };
 
-    expand_define_i18n_module(ManagerPaths::embedded(), generate_embedded_tokens)
+    expand_define_i18n_module(ManagerPaths::embedded(), generate_embedded_tokens, &args)
 }
 
 pub(crate) fn define_bevy_i18n_module(input: TokenStream) -> TokenStream {
@@ -123,15 +259,24 @@ pub(crate) fn define_bevy_i18n_module(input: TokenStream) -> TokenStream {
         return error;
     }
 
-    expand_define_i18n_module(ManagerPaths::bevy(), generate_bevy_tokens)
+    expand_define_i18n_module(
+        ManagerPaths::bevy(),
+        generate_bevy_tokens,
+        &ModuleArgs::default(),
+    )
 }
 
 pub(crate) fn define_dioxus_i18n_module(input: TokenStream) -> TokenStream {
-    if let Some(error) = reject_unexpected_input(input, "define_i18n_module!") {
-        return error;
-    }
+    let args = match parse_module_args(input.into(), "define_i18n_module!", ModuleArg::DIOXUS) {
+        Ok(args) => args,
+        Err(error) => return TokenStream::from(error.to_compile_error()),
+    };
 
-    expand_define_i18n_module(ManagerPaths::dioxus(), generate_dioxus_asset_loader_tokens)
+    expand_define_i18n_module(
+        ManagerPaths::dioxus(),
+        generate_dioxus_asset_loader_tokens,
+        &args,
+    )
 }
 
 fn generate_embedded_tokens(
@@ -140,6 +285,7 @@ fn generate_embedded_tokens(
     module_data_name: syn::Ident,
     module_data_static: proc_macro2::TokenStream,
     manager_paths: &ManagerPaths,
+    args: &ModuleArgs,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let assets_struct_name = syn::Ident::new(
         &format!(
@@ -166,11 +312,50 @@ fn generate_embedded_tokens(
     );
     let manager_core_path = &manager_paths.manager_core_path;
     let inventory_path = quote! { #manager_path::__inventory };
+    // `rust_embed` ignores `#[compression]` unless its `compression` feature is
+    // on, so the manager crate turns a missing feature into a compile error.
+    let (compression_check, compression_attr) = match &args.compression {
+        Some(algorithm) => (
+            quote! { #manager_path::__require_embed_compression!(); },
+            quote! { #[compression = #algorithm] },
+        ),
+        None => (quote! {}, quote! {}),
+    };
+    // The runtime discovery in `EmbeddedAssets` only knows `{crate}.ftl`, so
+    // a custom `ftl_file_name` bakes the discovered plan in instead.
+    let resource_plan_override = if assets.base_file_stem == crate_name {
+        quote! {}
+    } else {
+        let langid_path = &manager_paths.langid_path;
+        let manifest_match_arms = assets.resource_plan_match_arms(manager_core_path, langid_path);
+        quote! {
+            fn resource_plan_for_language(
+                lang: &#langid_path::LanguageIdentifier,
+            ) -> Option<Vec<#manager_core_path::ModuleResourceSpec>> {
+                match lang {
+                    #(#manifest_match_arms,)*
+                    _ => None,
+                }
+            }
+        }
+    };
+
+    let layout_override = match assets.layout {
+        AssetsLayout::LanguageFirst => quote! {},
+        AssetsLayout::DomainFirst => quote! {
+            fn layout() -> #manager_core_path::AssetsLayout {
+                #manager_core_path::AssetsLayout::DomainFirst
+            }
+        },
+    };
 
     let expanded = quote! {
+        #compression_check
+
         #[derive(#rust_embed_path::RustEmbed)]
         #[crate_path = #rust_embed_attr_path]
         #[folder = #i18n_root_str]
+        #compression_attr
         struct #assets_struct_name;
 
         impl #manager_core_path::EmbeddedAssets for #assets_struct_name {
@@ -181,6 +366,10 @@ fn generate_embedded_tokens(
             fn namespaces() -> &'static [&'static str] {
                 #module_data_name.namespaces
             }
+
+            #layout_override
+
+            #resource_plan_override
         }
 
         #module_data_static
@@ -204,6 +393,7 @@ fn generate_bevy_tokens(
     module_data_name: syn::Ident,
     module_data_static: proc_macro2::TokenStream,
     manager_paths: &ManagerPaths,
+    _args: &ModuleArgs,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let registration_struct_name = syn::Ident::new(
         &format!(
@@ -330,11 +520,9 @@ fn bevy_embedded_asset_data(
         for spec in specs {
             let key = spec.key.as_str();
             let locale_relative_path = spec.locale_relative_path.as_str();
-            let source_path = assets.root_path.join(&language).join(locale_relative_path);
-            let embedded_path = Path::new(crate_name)
-                .join(relative_root)
-                .join(&language)
-                .join(locale_relative_path);
+            let locale_path = assets.layout.locale_path(&language, locale_relative_path);
+            let source_path = assets.root_path.join(&locale_path);
+            let embedded_path = Path::new(crate_name).join(relative_root).join(&locale_path);
             let embedded_path = embedded_path.to_slash_lossy().to_string();
             entries.push((
                 language.clone(),
@@ -432,6 +620,7 @@ fn generate_dioxus_asset_loader_tokens(
     module_data_name: syn::Ident,
     module_data_static: proc_macro2::TokenStream,
     manager_paths: &ManagerPaths,
+    args: &ModuleArgs,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let resources_name = syn::Ident::new(
         &format!(
@@ -458,7 +647,7 @@ fn generate_dioxus_asset_loader_tokens(
     let langid_path = &manager_paths.langid_path;
     let manager_path = manager_paths.manager_path.tokens();
     let inventory_path = quote! { #manager_path::__inventory };
-    let asset_tokens = dioxus_asset_resource_tokens(&assets, manager_paths)?;
+    let asset_tokens = dioxus_asset_resource_tokens(&assets, manager_paths, args.embedded)?;
 
     let expanded = quote! {
         #module_data_static
@@ -531,6 +720,7 @@ fn generate_dioxus_asset_loader_tokens(
 fn dioxus_asset_resource_tokens(
     assets: &I18nAssets,
     manager_paths: &ManagerPaths,
+    embedded: bool,
 ) -> syn::Result<Vec<proc_macro2::TokenStream>> {
     let manager_path = manager_paths.manager_path.tokens();
     let langid_path = &manager_paths.langid_path;
@@ -542,8 +732,29 @@ fn dioxus_asset_resource_tokens(
             let key = spec.key.as_str();
             let locale_relative_path = spec.locale_relative_path.as_str();
             let required = spec.required;
-            let asset_path = dioxus_asset_path(&assets.root_path, &language, locale_relative_path)?;
 
+            if embedded {
+                let source_path = utf8_path_literal_value(
+                    &assets
+                        .root_path
+                        .join(assets.layout.locale_path(&language, locale_relative_path)),
+                )?;
+                tokens.push(quote! {
+                    #manager_path::DioxusI18nAssetResource::embedded(
+                        #langid_path::langid!(#language),
+                        #key,
+                        #locale_relative_path,
+                        #required,
+                        include_bytes!(#source_path),
+                    )
+                });
+                continue;
+            }
+
+            let asset_path = dioxus_asset_path(
+                &assets.root_path,
+                &assets.layout.locale_path(&language, locale_relative_path),
+            )?;
             tokens.push(quote! {
                 #manager_path::DioxusI18nAssetResource::new(
                     #langid_path::langid!(#language),
@@ -563,11 +774,7 @@ fn dioxus_asset_resource_tokens(
     Ok(tokens)
 }
 
-fn dioxus_asset_path(
-    assets_root: &Path,
-    language: &str,
-    locale_relative_path: &str,
-) -> syn::Result<syn::LitStr> {
+fn dioxus_asset_path(assets_root: &Path, locale_path: &str) -> syn::Result<syn::LitStr> {
     let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
         .map(PathBuf::from)
         .map_err(|_| crate::assets::macro_error("CARGO_MANIFEST_DIR must be set"))?;
@@ -577,7 +784,7 @@ fn dioxus_asset_path(
             assets_root
         ))
     })?;
-    let path = relative_root.join(language).join(locale_relative_path);
+    let path = relative_root.join(locale_path);
     let path = format!("/{}", path.to_slash_lossy().trim_start_matches('/'));
 
     Ok(syn::LitStr::new(&path, proc_macro2::Span::call_site()))
@@ -607,6 +814,8 @@ mod tests {
     fn sample_assets(root_path: PathBuf) -> I18nAssets {
         I18nAssets {
             root_path,
+            base_file_stem: "my-crate".to_string(),
+            layout: AssetsLayout::LanguageFirst,
             languages: vec![
                 es_fluent_shared::parse_canonical_language_identifier("en-US").unwrap(),
                 es_fluent_shared::parse_canonical_language_identifier("fr").unwrap(),
@@ -689,6 +898,7 @@ mod tests {
                 module_data_name.clone(),
                 module_data_static(&module_data_name),
                 &ManagerPaths::embedded(),
+                &ModuleArgs::default(),
             )
             .expect("embedded tokens"),
         );
@@ -696,6 +906,7 @@ mod tests {
         assert!(embedded.contains("RustEmbed"));
         assert!(embedded.contains("MY_CRATE_I18N_MODULE"));
         assert!(embedded.contains("inventory"));
+        assert!(!embedded.contains("compression"));
 
         temp_env::with_var("CARGO_MANIFEST_DIR", Some(temp.path()), || {
             let bevy = format_tokens(
@@ -705,6 +916,7 @@ mod tests {
                     module_data_name.clone(),
                     module_data_static(&module_data_name),
                     &ManagerPaths::bevy(),
+                    &ModuleArgs::default(),
                 )
                 .expect("bevy tokens"),
             );
@@ -729,6 +941,7 @@ mod tests {
                     module_data_name.clone(),
                     module_data_static(&module_data_name),
                     &ManagerPaths::dioxus(),
+                    &ModuleArgs::default(),
                 )
                 .expect("dioxus tokens"),
             );
@@ -739,9 +952,66 @@ mod tests {
             assert!(dioxus.contains("submit"));
             assert!(dioxus.contains("/assets/locales/en-US/my-crate.ftl"));
             assert!(dioxus.contains("/assets/locales/fr/my-crate/ui.ftl"));
+            assert!(dioxus.contains("asset!"));
+            assert!(!dioxus.contains("include_bytes"));
         });
     }
 
+    #[test]
+    #[serial(manifest)]
+    fn embedded_dioxus_tokens_include_ftl_bytes_instead_of_assets() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        let assets_root = temp.path().join("assets/locales");
+        let module_data_name =
+            syn::Ident::new("MY_CRATE_TEST_MODULE_DATA", proc_macro2::Span::call_site());
+        let args = parse_module_args(
+            quote! { embedded },
+            "define_i18n_module!",
+            ModuleArg::DIOXUS,
+        )
+        .expect("embedded should parse");
+
+        let dioxus = format_tokens(
+            generate_dioxus_asset_loader_tokens(
+                "my-crate".to_string(),
+                sample_assets(assets_root.clone()),
+                module_data_name.clone(),
+                module_data_static(&module_data_name),
+                &ManagerPaths::dioxus(),
+                &args,
+            )
+            .expect("dioxus tokens"),
+        );
+
+        assert!(dioxus.contains("DioxusI18nAssetResource::embedded"));
+        assert!(dioxus.contains("include_bytes!"));
+        assert!(
+            dioxus.contains(
+                &assets_root
+                    .join("en-US/my-crate.ftl")
+                    .to_str()
+                    .expect("utf-8 temp path")
+                    .to_string()
+            )
+        );
+        assert!(!dioxus.contains("asset!"));
+
+        for tokens in [
+            quote! { unexpected },
+            quote! { embedded, embedded },
+            quote! { strict },
+        ] {
+            let error = parse_module_args(tokens, "define_i18n_module!", ModuleArg::DIOXUS)
+                .err()
+                .expect("invalid arguments should fail")
+                .to_string();
+            assert!(
+                error.contains("accepts only the `embedded` argument"),
+                "{error}"
+            );
+        }
+    }
+
     #[test]
     #[serial(manifest)]
     fn expand_define_i18n_module_loads_manifest_assets_and_generates_tokens() {
@@ -765,6 +1035,7 @@ mod tests {
                     expand_define_i18n_module_tokens(
                         ManagerPaths::embedded(),
                         generate_embedded_tokens,
+                        &ModuleArgs::default(),
                     )
                     .expect("expanded tokens"),
                 );
@@ -776,6 +1047,263 @@ mod tests {
         );
     }
 
+    #[test]
+    fn embedded_module_args_accept_strict_and_compress() {
+        let parse = |tokens| parse_module_args(tokens, "define_i18n_module!", ModuleArg::EMBEDDED);
+
+        let args = parse(quote! {}).expect("empty input should parse");
+        assert!(!args.strict);
+        assert!(args.compression.is_none());
+
+        assert!(
+            parse(quote! { strict })
+                .expect("strict should parse")
+                .strict
+        );
+
+        let args = parse(quote! { strict, compress = "zstd" }).expect("both should parse");
+        assert!(args.strict);
+        assert_eq!(
+            args.compression
+                .map(|algorithm| algorithm.value())
+                .as_deref(),
+            Some("zstd")
+        );
+
+        for (tokens, expected) in [
+            (
+                quote! { lenient },
+                "accepts only the `strict` and `compress",
+            ),
+            (
+                quote! { strict, strict },
+                "accepts only the `strict` and `compress",
+            ),
+            (
+                quote! { compress = "gzip" },
+                "unsupported `compress` algorithm \"gzip\"",
+            ),
+            (
+                quote! { compress = zstd },
+                "`compress` expects a string literal",
+            ),
+        ] {
+            let error = parse(tokens)
+                .err()
+                .expect("invalid arguments should fail")
+                .to_string();
+            assert!(error.contains(expected), "{error}");
+        }
+    }
+
+    #[test]
+    #[serial(manifest)]
+    fn compressed_embedded_tokens_require_the_compression_feature() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        let module_data_name =
+            syn::Ident::new("MY_CRATE_TEST_MODULE_DATA", proc_macro2::Span::call_site());
+        let args = ModuleArgs {
+            compression: Some(syn::LitStr::new("deflate", proc_macro2::Span::call_site())),
+            ..ModuleArgs::default()
+        };
+
+        let embedded = format_tokens(
+            generate_embedded_tokens(
+                "my-crate".to_string(),
+                sample_assets(temp.path().join("assets/locales")),
+                module_data_name.clone(),
+                module_data_static(&module_data_name),
+                &ManagerPaths::embedded(),
+                &args,
+            )
+            .expect("embedded tokens"),
+        );
+
+        assert!(embedded.contains("__require_embed_compression"));
+        assert!(embedded.contains("#[compression = \"deflate\"]"));
+    }
+
+    #[test]
+    #[serial(manifest)]
+    fn custom_ftl_file_name_is_baked_into_the_embedded_resource_plan() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        std::fs::write(
+            temp.path().join("i18n.toml"),
+            "fallback_language = \"en-US\"\nassets_dir = \"i18n\"\nftl_file_name = \"{crate}-messages\"\n",
+        )
+        .expect("write manifest");
+        std::fs::create_dir_all(temp.path().join("i18n/en-US")).expect("create locale dir");
+        std::fs::write(
+            temp.path().join("i18n/en-US/my-crate-messages.ftl"),
+            "hello = Hello",
+        )
+        .expect("write ftl");
+
+        temp_env::with_vars(
+            [
+                ("CARGO_MANIFEST_DIR", Some(temp.path().as_os_str())),
+                ("CARGO_PKG_NAME", Some(std::ffi::OsStr::new("my-crate"))),
+            ],
+            || {
+                let expanded = format_tokens(
+                    expand_define_i18n_module_tokens(
+                        ManagerPaths::embedded(),
+                        generate_embedded_tokens,
+                        &ModuleArgs::default(),
+                    )
+                    .expect("expanded tokens"),
+                );
+
+                assert!(expanded.contains("fn resource_plan_for_language"));
+                assert!(expanded.contains("\"my-crate-messages.ftl\""));
+            },
+        );
+
+        let embedded = format_tokens(
+            generate_embedded_tokens(
+                "my-crate".to_string(),
+                sample_assets(temp.path().join("i18n")),
+                syn::Ident::new("MY_CRATE_TEST_MODULE_DATA", proc_macro2::Span::call_site()),
+                quote! {},
+                &ManagerPaths::embedded(),
+                &ModuleArgs::default(),
+            )
+            .expect("embedded tokens"),
+        );
+        assert!(!embedded.contains("fn resource_plan_for_language"));
+    }
+
+    #[test]
+    #[serial(manifest)]
+    fn domain_first_layout_is_forwarded_to_embedded_and_dioxus_modules() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        std::fs::write(
+            temp.path().join("i18n.toml"),
+            "fallback_language = \"en-US\"\nassets_dir = \"i18n\"\nlayout = \"domain_first\"\n",
+        )
+        .expect("write manifest");
+        std::fs::create_dir_all(temp.path().join("i18n/my-crate/fr")).expect("create domain dir");
+        std::fs::write(temp.path().join("i18n/my-crate/en-US.ftl"), "hello = Hello")
+            .expect("write ftl");
+        std::fs::write(temp.path().join("i18n/my-crate/fr/ui.ftl"), "title = Titre")
+            .expect("write namespaced ftl");
+
+        temp_env::with_vars(
+            [
+                ("CARGO_MANIFEST_DIR", Some(temp.path().as_os_str())),
+                ("CARGO_PKG_NAME", Some(std::ffi::OsStr::new("my-crate"))),
+            ],
+            || {
+                let embedded = format_tokens(
+                    expand_define_i18n_module_tokens(
+                        ManagerPaths::embedded(),
+                        generate_embedded_tokens,
+                        &ModuleArgs::default(),
+                    )
+                    .expect("embedded tokens"),
+                );
+                assert!(embedded.contains("AssetsLayout::DomainFirst"));
+                assert!(embedded.contains("langid!(\"fr\")"));
+
+                let dioxus = format_tokens(
+                    expand_define_i18n_module_tokens(
+                        ManagerPaths::dioxus(),
+                        generate_dioxus_asset_loader_tokens,
+                        &ModuleArgs::default(),
+                    )
+                    .expect("dioxus tokens"),
+                );
+                assert!(dioxus.contains("\"/i18n/my-crate/en-US.ftl\""));
+                assert!(dioxus.contains("\"/i18n/my-crate/fr/ui.ftl\""));
+            },
+        );
+    }
+
+    #[test]
+    #[serial(manifest)]
+    fn strict_mode_lists_locales_missing_the_crate_ftl() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        std::fs::write(
+            temp.path().join("i18n.toml"),
+            "fallback_language = \"en-US\"\nassets_dir = \"i18n\"\n",
+        )
+        .expect("write manifest");
+        for locale in ["en-US", "fr", "de"] {
+            std::fs::create_dir_all(temp.path().join("i18n").join(locale))
+                .expect("create locale dir");
+        }
+        std::fs::write(temp.path().join("i18n/en-US/my-crate.ftl"), "hello = Hello")
+            .expect("write ftl");
+        std::fs::write(temp.path().join("i18n/fr/other-crate.ftl"), "hello = Salut")
+            .expect("write other crate ftl");
+
+        temp_env::with_vars(
+            [
+                ("CARGO_MANIFEST_DIR", Some(temp.path().as_os_str())),
+                ("CARGO_PKG_NAME", Some(std::ffi::OsStr::new("my-crate"))),
+            ],
+            || {
+                let error = expand_define_i18n_module_tokens(
+                    ManagerPaths::embedded(),
+                    generate_embedded_tokens,
+                    &ModuleArgs {
+                        strict: true,
+                        ..ModuleArgs::default()
+                    },
+                )
+                .expect_err("strict mode should reject missing locales")
+                .to_string();
+                assert!(error.contains("missing `my-crate.ftl`: de, fr"));
+
+                expand_define_i18n_module_tokens(
+                    ManagerPaths::embedded(),
+                    generate_embedded_tokens,
+                    &ModuleArgs::default(),
+                )
+                .expect("lenient mode skips missing locales");
+            },
+        );
+    }
+
+    #[test]
+    #[serial(manifest)]
+    fn strict_mode_requires_fallback_ftl() {
+        let temp = tempfile::tempdir().expect("temp dir");
+        std::fs::write(
+            temp.path().join("i18n.toml"),
+            "fallback_language = \"en-US\"\nassets_dir = \"i18n\"\n",
+        )
+        .expect("write manifest");
+        std::fs::create_dir_all(temp.path().join("i18n/en-US")).expect("create locale dir");
+
+        temp_env::with_vars(
+            [
+                ("CARGO_MANIFEST_DIR", Some(temp.path().as_os_str())),
+                ("CARGO_PKG_NAME", Some(std::ffi::OsStr::new("my-crate"))),
+            ],
+            || {
+                let error = expand_define_i18n_module_tokens(
+                    ManagerPaths::embedded(),
+                    generate_embedded_tokens,
+                    &ModuleArgs {
+                        strict: true,
+                        ..ModuleArgs::default()
+                    },
+                )
+                .expect_err("strict mode should reject an empty fallback directory")
+                .to_string();
+                assert!(error.contains("i18n/en-US' contains no .ftl files"));
+
+                expand_define_i18n_module_tokens(
+                    ManagerPaths::embedded(),
+                    generate_embedded_tokens,
+                    &ModuleArgs::default(),
+                )
+                .expect("lenient mode accepts the empty skeleton");
+            },
+        );
+    }
+
     #[test]
     #[serial(manifest)]
     fn dioxus_asset_path_formats_package_relative_paths() {
@@ -783,12 +1311,9 @@ mod tests {
         let manifest_dir = temp.path();
 
         temp_env::with_var("CARGO_MANIFEST_DIR", Some(manifest_dir), || {
-            let path = dioxus_asset_path(
-                &manifest_dir.join("assets/locales"),
-                "en-US",
-                "example/ui.ftl",
-            )
-            .expect("package-local asset path");
+            let path =
+                dioxus_asset_path(&manifest_dir.join("assets/locales"), "en-US/example/ui.ftl")
+                    .expect("package-local asset path");
 
             assert_eq!(path.value(), "/assets/locales/en-US/example/ui.ftl");
         });
@@ -802,9 +1327,8 @@ mod tests {
         std::fs::create_dir(&manifest_dir).expect("manifest dir");
 
         temp_env::with_var("CARGO_MANIFEST_DIR", Some(&manifest_dir), || {
-            let err =
-                dioxus_asset_path(&temp.path().join("outside-locales"), "en-US", "example.ftl")
-                    .expect_err("outside assets should be rejected");
+            let err = dioxus_asset_path(&temp.path().join("outside-locales"), "en-US/example.ftl")
+                .expect_err("outside assets should be rejected");
 
             assert!(err.to_string().contains("inside the crate root"));
         });
@@ -3,6 +3,7 @@
 
 use es_fluent_shared::{
     fluent::{FluentArgumentName, FluentEntryId},
+    meta::TypeKind,
     resource::ModuleResourceSpec,
     source::{SourceFile, SourceLine},
 };
@@ -12,11 +13,26 @@ use std::path::{Path, PathBuf};
 mod error;
 
 pub use error::RunnerIoError;
-pub use es_fluent_shared::FluentParseMode;
+pub use es_fluent_shared::{FluentParseMode, GenerateReport};
 
 #[derive(Clone, Debug, serde::Deserialize, Eq, PartialEq, serde::Serialize)]
 pub struct RunnerResult {
     pub changed: bool,
+    /// Keys and groups the run added or removed; empty for older runners.
+    #[serde(default)]
+    pub report: GenerateReport,
+    /// Key diff written by check runs; absent for generate and clean.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub check: Option<CheckSummary>,
+}
+
+/// Expected keys compared against the fallback locale's FTL files.
+#[derive(Clone, Debug, Default, serde::Deserialize, Eq, PartialEq, serde::Serialize)]
+pub struct CheckSummary {
+    /// Expected keys with no message in their fallback FTL file, sorted.
+    pub missing_in_ftl: Vec<String>,
+    /// Fallback FTL messages that no registered type expects, sorted.
+    pub orphan_in_ftl: Vec<String>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, Eq, PartialEq, serde::Serialize)]
@@ -27,6 +43,12 @@ pub struct ExpectedKey {
     pub resource: Option<ModuleResourceSpec>,
     pub source_file: Option<SourceFile>,
     pub source_line: Option<SourceLine>,
+    /// Rust type that registered the key; absent for older runners.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_name: Option<String>,
+    /// Whether that type is an enum or a struct; absent for older runners.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_kind: Option<TypeKind>,
 }
 
 #[derive(Clone, Debug, Default, serde::Deserialize, Eq, PartialEq, serde::Serialize)]
@@ -254,6 +276,12 @@ impl RunnerMetadataStore {
             .unwrap_or(false)
     }
 
+    pub fn result_report(&self, package_name: &PackageName) -> GenerateReport {
+        self.read_result(package_name)
+            .map(|result| result.report)
+            .unwrap_or_default()
+    }
+
     pub fn write_inventory(
         &self,
         package_name: &PackageName,
@@ -365,7 +393,15 @@ mod tests {
     #[test]
     fn write_and_read_result_round_trip() {
         let temp = tempfile::tempdir().expect("tempdir");
-        let result = RunnerResult { changed: true };
+        let result = RunnerResult {
+            changed: true,
+            report: GenerateReport {
+                changed: true,
+                added: vec!["hello".to_string()],
+                ..GenerateReport::default()
+            },
+            check: None,
+        };
         let store = RunnerMetadataStore::new(temp.path());
         let package = package("crate-x");
 
@@ -374,6 +410,51 @@ mod tests {
 
         assert_eq!(decoded, result);
         assert!(store.result_changed(&package));
+        assert_eq!(store.result_report(&package).added, ["hello"]);
+    }
+
+    #[test]
+    fn check_summary_round_trips_and_is_omitted_when_absent() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let store = RunnerMetadataStore::new(temp.path());
+        let package = package("crate-x");
+        let result = RunnerResult {
+            changed: false,
+            report: GenerateReport::default(),
+            check: Some(CheckSummary {
+                missing_in_ftl: vec!["greeting".to_string()],
+                orphan_in_ftl: vec!["old_key".to_string()],
+            }),
+        };
+
+        store.write_result(&package, &result).expect("write result");
+        let content = fs::read_to_string(store.result_path(&package)).expect("read json");
+        assert!(content.contains(r#""missing_in_ftl":["greeting"]"#));
+        assert!(content.contains(r#""orphan_in_ftl":["old_key"]"#));
+        assert_eq!(store.read_result(&package).expect("read result"), result);
+
+        let without_check = RunnerResult {
+            check: None,
+            ..result
+        };
+        assert!(
+            !serde_json::to_string(&without_check)
+                .expect("serialize")
+                .contains("check")
+        );
+    }
+
+    #[test]
+    fn read_result_accepts_results_without_report() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let store = RunnerMetadataStore::new(temp.path());
+        let package = package("crate-x");
+        store.ensure_metadata_dir(&package).expect("metadata dir");
+        fs::write(store.result_path(&package), r#"{"changed":false}"#).expect("write result");
+
+        let decoded = store.read_result(&package).expect("read result");
+        assert!(!decoded.changed);
+        assert!(decoded.report.is_empty());
     }
 
     #[test]
@@ -388,6 +469,8 @@ mod tests {
                 resource: Some(ModuleResourceSpec::base("crate-x", true)),
                 source_file: SourceFile::new("src/lib.rs"),
                 source_line: Some(SourceLine::new(7)),
+                type_name: Some("Hello".to_string()),
+                type_kind: Some(TypeKind::Struct),
             }],
         };
 
@@ -31,12 +31,17 @@ pub enum EsFluentError {
     #[error("Invalid Fluent metadata '{identifier}': {reason}")]
     InvalidFluentIdentifier { identifier: String, reason: String },
 
-    /// Duplicate generated Fluent key metadata.
-    #[error("Duplicate generated FTL key '{key}' from {first} and {second}")]
-    DuplicateGeneratedFtlKey {
-        key: String,
-        first: String,
-        second: String,
+    /// Duplicate generated Fluent key metadata, listing every conflicting
+    /// source in registration order.
+    #[error("Duplicate generated FTL key '{key}' from {}", join_sources(.sources))]
+    DuplicateGeneratedFtlKey { key: String, sources: Vec<String> },
+
+    /// The same type registered with different kinds, listing every
+    /// conflicting source in registration order.
+    #[error("Conflicting kinds for type '{type_name}' from {}", join_sources(.sources))]
+    ConflictingTypeKind {
+        type_name: String,
+        sources: Vec<String>,
     },
 
     /// Language not supported.
@@ -102,15 +107,30 @@ impl EsFluentError {
     }
 
     /// Creates a duplicate generated FTL key error.
-    pub fn duplicate_generated_ftl_key(
+    pub fn duplicate_generated_ftl_key<S>(
         key: impl Into<String>,
-        first: impl Into<String>,
-        second: impl Into<String>,
-    ) -> Self {
+        sources: impl IntoIterator<Item = S>,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
         Self::DuplicateGeneratedFtlKey {
             key: key.into(),
-            first: first.into(),
-            second: second.into(),
+            sources: sources.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Creates a conflicting type kind error.
+    pub fn conflicting_type_kind<S>(
+        type_name: impl Into<String>,
+        sources: impl IntoIterator<Item = S>,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::ConflictingTypeKind {
+            type_name: type_name.into(),
+            sources: sources.into_iter().map(Into::into).collect(),
         }
     }
 
@@ -125,6 +145,14 @@ impl EsFluentError {
 /// A result type for common es-fluent operations.
 pub type EsFluentResult<T> = Result<T, EsFluentError>;
 
+fn join_sources(sources: &[String]) -> String {
+    match sources.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {last}", rest.join(", ")),
+        Some((last, _)) => last.clone(),
+        None => String::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,6 +201,24 @@ mod tests {
 
         let missing = EsFluentError::MissingPackageName;
         assert_eq!(missing.to_string(), "Missing package name");
+
+        let duplicate = EsFluentError::duplicate_generated_ftl_key("shared", ["A", "B"]);
+        assert_eq!(
+            duplicate.to_string(),
+            "Duplicate generated FTL key 'shared' from A and B"
+        );
+
+        let duplicate = EsFluentError::duplicate_generated_ftl_key("shared", ["A", "B", "C"]);
+        assert_eq!(
+            duplicate.to_string(),
+            "Duplicate generated FTL key 'shared' from A, B and C"
+        );
+
+        let conflict = EsFluentError::conflicting_type_kind("Status", ["enum", "struct"]);
+        assert_eq!(
+            conflict.to_string(),
+            "Conflicting kinds for type 'Status' from enum and struct"
+        );
     }
 
     #[test]
@@ -100,6 +100,7 @@ fluent_string_type!(FluentArgumentName, "Fluent argument name");
 fluent_string_type!(FluentVariantKey, "Fluent variant key");
 fluent_string_type!(FluentDomain, "Fluent domain");
 fluent_string_type!(FluentMessageId, "Fluent message id");
+fluent_string_type!(FluentAttributeName, "Fluent attribute name");
 
 /// A Fluent entry identifier, covering both message IDs and term IDs.
 #[derive(
@@ -1,22 +1,46 @@
 //! Shared runtime-safe types and helpers for the es-fluent ecosystem.
+//!
+//! Without the default `std` feature only the [`meta`] and [`namer`]
+//! key-building types are available, built on `core` and `alloc`.
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(not(test), deny(clippy::panic, clippy::unwrap_used))]
 
+extern crate alloc;
+
+#[cfg(feature = "std")]
 pub mod error;
+#[cfg(feature = "std")]
 pub mod fluent;
+#[cfg(feature = "std")]
 pub mod language;
 pub mod meta;
+#[cfg(feature = "std")]
 pub mod mode;
 pub mod namer;
+#[cfg(feature = "std")]
 pub mod namespace;
+#[cfg(feature = "std")]
 mod namespace_resolver;
+#[cfg(feature = "std")]
 pub mod path_utils;
+#[cfg(feature = "std")]
 pub mod registry;
+#[cfg(feature = "std")]
+pub mod report;
+#[cfg(feature = "std")]
 pub mod resource;
+#[cfg(feature = "std")]
 pub mod source;
 
+#[cfg(feature = "std")]
 pub use error::{EsFluentError, EsFluentResult};
+#[cfg(feature = "std")]
 pub use language::{
     CanonicalLanguageIdentifierError, LanguageIdentifier, parse_canonical_language_identifier,
 };
+#[cfg(feature = "std")]
 pub use mode::FluentParseMode;
+#[cfg(feature = "std")]
 pub use path_utils::{parse_language_entry, validate_assets_dir};
+#[cfg(feature = "std")]
+pub use report::GenerateReport;
@@ -1,10 +1,20 @@
 //! This module provides types for representing the kind of a type.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(
-    Clone, Copy, Debug, strum::Display, Eq, Hash, strum::IntoStaticStr, PartialEq, Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    strum::Display,
+    Eq,
+    Hash,
+    strum::IntoStaticStr,
+    PartialEq,
+    Serialize,
 )]
+#[serde(rename_all = "snake_case")]
 #[strum(const_into_str, serialize_all = "snake_case")]
 pub enum TypeKind {
     Enum,
@@ -20,6 +20,8 @@ pub enum FluentParseMode {
     /// Preserve existing translations.
     #[default]
     Conservative,
+    /// Keep existing entries verbatim and in place, only filling in missing keys.
+    Merge,
 }
 
 impl FluentParseMode {
@@ -38,5 +40,16 @@ mod tests {
 
         assert_eq!(CONSERVATIVE_LABEL, "conservative");
         assert_eq!(FluentParseMode::Aggressive.label(), "aggressive");
+        assert_eq!(FluentParseMode::Merge.label(), "merge");
+    }
+
+    #[test]
+    fn fluent_parse_mode_parses_merge_as_value_enum() {
+        use clap::ValueEnum as _;
+
+        assert_eq!(
+            FluentParseMode::from_str("merge", false),
+            Ok(FluentParseMode::Merge)
+        );
     }
 }
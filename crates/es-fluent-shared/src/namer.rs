@@ -1,9 +1,14 @@
 //! This module provides types for naming Fluent keys and documentation.
+//!
+//! The key-building types only need `alloc`; conversions from `syn`
+//! identifiers and token generation require the `std` feature.
 
+use alloc::format;
+use alloc::string::{String, ToString as _};
 use derive_more::{Debug, Deref, Display, From};
-use heck::ToSnakeCase as _;
-use quote::format_ident;
+use heck::{ToKebabCase as _, ToLowerCamelCase as _, ToSnakeCase as _};
 
+#[cfg(feature = "std")]
 pub fn rust_ident_name(ident: &syn::Ident) -> String {
     let name = ident.to_string();
     name.strip_prefix("r#").unwrap_or(&name).to_string()
@@ -20,17 +25,94 @@ impl From<&str> for FluentKey {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<&syn::Ident> for FluentKey {
     fn from(ident: &syn::Ident) -> Self {
         Self(rust_ident_name(ident).to_snake_case())
     }
 }
 
+/// Casing applied to the type and variant segments of derived message ids,
+/// selected with `#[fluent(case = "...")]`.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+    Eq,
+    strum::IntoStaticStr,
+    PartialEq,
+)]
+#[strum(const_into_str, serialize_all = "snake_case")]
+pub enum KeyCase {
+    /// `login_error-invalid_password`
+    Snake,
+    /// `login-error-invalid-password`
+    Kebab,
+    /// `loginError-invalidPassword`
+    Camel,
+}
+
+impl KeyCase {
+    pub const fn label(self) -> &'static str {
+        self.into_str()
+    }
+
+    pub fn apply(self, value: &str) -> String {
+        match self {
+            Self::Snake => value.to_snake_case(),
+            Self::Kebab => value.to_kebab_case(),
+            Self::Camel => value.to_lower_camel_case(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl darling::FromMeta for KeyCase {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        use strum::IntoEnumIterator as _;
+
+        value.parse::<Self>().map_err(|_| {
+            let supported = Self::iter().map(Self::label).collect::<Vec<_>>().join(", ");
+            darling::Error::custom(format!(
+                "invalid #[fluent(case = ...)] value `{value}`; supported values are: {supported}"
+            ))
+        })
+    }
+}
+
 impl FluentKey {
     pub const DELIMITER: &str = "-";
     pub const LABEL_SUFFIX: &str = "_label";
 
-    pub fn join(&self, suffix: impl std::fmt::Display) -> Self {
+    /// Builds the type segment of a message id.
+    ///
+    /// Without a case the identifier is snake_cased, which is the default
+    /// naming for every derive.
+    #[cfg(feature = "std")]
+    pub fn from_ident_with_case(ident: &syn::Ident, case: Option<KeyCase>) -> Self {
+        match case {
+            Some(case) => Self(case.apply(&rust_ident_name(ident))),
+            None => Self::from(ident),
+        }
+    }
+
+    /// Builds the variant segment of a message id.
+    ///
+    /// Without a case the identifier is kept verbatim, so `Variant1` stays
+    /// `Variant1`.
+    #[cfg(feature = "std")]
+    pub fn variant_segment(ident: &syn::Ident, case: Option<KeyCase>) -> String {
+        let name = rust_ident_name(ident);
+        match case {
+            Some(case) => case.apply(&name),
+            None => name,
+        }
+    }
+
+    pub fn join(&self, suffix: impl core::fmt::Display) -> Self {
         let suffix_str = suffix.to_string();
         if suffix_str.is_empty() {
             self.clone()
@@ -39,6 +121,7 @@ impl FluentKey {
         }
     }
 
+    #[cfg(feature = "std")]
     pub fn new_label(ftl_name: &syn::Ident) -> Self {
         let label_ident =
             quote::format_ident!("{}{}", rust_ident_name(ftl_name), Self::LABEL_SUFFIX);
@@ -46,6 +129,7 @@ impl FluentKey {
     }
 }
 
+#[cfg(feature = "std")]
 impl quote::ToTokens for FluentKey {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let key_string = &self.0;
@@ -62,6 +146,7 @@ impl From<&FluentKey> for FluentDoc {
     }
 }
 
+#[cfg(feature = "std")]
 impl quote::ToTokens for FluentDoc {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let doc_string = &self.0;
@@ -73,9 +158,10 @@ impl quote::ToTokens for FluentDoc {
 #[display("f{}", _0)]
 pub struct UnnamedItem(usize);
 
+#[cfg(feature = "std")]
 impl UnnamedItem {
     pub fn to_ident(&self) -> syn::Ident {
-        format_ident!("{}", self.to_string())
+        quote::format_ident!("{}", self.to_string())
     }
 }
 
@@ -105,6 +191,38 @@ mod tests {
         assert_eq!(from_ident.join("").to_string(), "hello_world");
     }
 
+    #[test]
+    fn key_case_controls_type_and_variant_segments() {
+        let ty = syn::Ident::new("LoginError", proc_macro2::Span::call_site());
+        let variant = syn::Ident::new("InvalidPassword", proc_macro2::Span::call_site());
+
+        let cased = |case| {
+            FluentKey::from_ident_with_case(&ty, case)
+                .join(FluentKey::variant_segment(&variant, case))
+                .to_string()
+        };
+
+        assert_eq!(cased(None), "login_error-InvalidPassword");
+        assert_eq!(cased(Some(KeyCase::Snake)), "login_error-invalid_password");
+        assert_eq!(cased(Some(KeyCase::Kebab)), "login-error-invalid-password");
+        assert_eq!(cased(Some(KeyCase::Camel)), "loginError-invalidPassword");
+    }
+
+    #[test]
+    fn key_case_parses_from_meta_and_lists_supported_values() {
+        use darling::FromMeta as _;
+
+        let meta: syn::Meta = syn::parse_quote!(case = "kebab");
+        assert_eq!(KeyCase::from_meta(&meta).expect("kebab"), KeyCase::Kebab);
+
+        let meta: syn::Meta = syn::parse_quote!(case = "kebab-case");
+        let err = KeyCase::from_meta(&meta).expect_err("unknown case");
+        assert!(
+            err.to_string()
+                .contains("supported values are: snake, kebab, camel")
+        );
+    }
+
     #[test]
     fn fluent_key_label_and_token_generation_work() {
         let label_key =
@@ -86,7 +86,7 @@ impl StaticFluentEntryId {
         Ok(Self(value))
     }
 
-    pub fn as_str(self) -> &'static str {
+    pub const fn as_str(self) -> &'static str {
         self.0
     }
 
@@ -193,12 +193,129 @@ impl PartialEq<&str> for StaticFluentVariantKey {
     }
 }
 
+/// A selector argument and the choice values its type can produce.
+#[derive(Clone, Copy, Debug)]
+pub struct FtlSelector {
+    arg: StaticFluentArgumentName,
+    /// Resolved lazily because the choice type's keys live in its own derive.
+    choices: fn() -> &'static [StaticFluentVariantKey],
+}
+
+impl FtlSelector {
+    /// Creates selector metadata for an argument backed by an `EsFluentChoice` type.
+    pub const fn new(
+        arg: StaticFluentArgumentName,
+        choices: fn() -> &'static [StaticFluentVariantKey],
+    ) -> Self {
+        Self { arg, choices }
+    }
+
+    pub fn arg(&self) -> StaticFluentArgumentName {
+        self.arg
+    }
+
+    /// Returns the select variant keys in declaration order.
+    pub fn choices(&self) -> &'static [StaticFluentVariantKey] {
+        (self.choices)()
+    }
+}
+
+impl PartialEq for FtlSelector {
+    fn eq(&self, other: &Self) -> bool {
+        self.arg == other.arg && self.choices() == other.choices()
+    }
+}
+
+impl Eq for FtlSelector {}
+
+impl std::hash::Hash for FtlSelector {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.arg.hash(state);
+        self.choices().hash(state);
+    }
+}
+
+/// An argument whose type's messages are linked from generated FTL as terms.
+#[derive(Clone, Copy, Debug)]
+pub struct FtlTermLink {
+    arg: StaticFluentArgumentName,
+    /// Resolved lazily because the linked type's ids live in its own derive.
+    entry_ids: fn() -> &'static [StaticFluentEntryId],
+}
+
+impl FtlTermLink {
+    /// Creates term link metadata for an argument backed by an `EsFluent` type.
+    pub const fn new(
+        arg: StaticFluentArgumentName,
+        entry_ids: fn() -> &'static [StaticFluentEntryId],
+    ) -> Self {
+        Self { arg, entry_ids }
+    }
+
+    pub fn arg(&self) -> StaticFluentArgumentName {
+        self.arg
+    }
+
+    /// Returns the linked type's message ids in declaration order.
+    pub fn entry_ids(&self) -> &'static [StaticFluentEntryId] {
+        (self.entry_ids)()
+    }
+}
+
+impl PartialEq for FtlTermLink {
+    fn eq(&self, other: &Self) -> bool {
+        self.arg == other.arg && self.entry_ids() == other.entry_ids()
+    }
+}
+
+impl Eq for FtlTermLink {}
+
+impl std::hash::Hash for FtlTermLink {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.arg.hash(state);
+        self.entry_ids().hash(state);
+    }
+}
+
+/// A Fluent argument paired with the Rust type of the field behind it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FtlArg {
+    name: StaticFluentArgumentName,
+    ty: Option<&'static str>,
+}
+
+impl FtlArg {
+    pub fn name(&self) -> StaticFluentArgumentName {
+        self.name
+    }
+
+    /// Returns the argument's Rust type as written in source, such as
+    /// `Option<u32>`, or `None` when it is unknown: for metadata built
+    /// without types, or a `value = ...` transform without a declared
+    /// return type.
+    pub fn ty(&self) -> Option<&'static str> {
+        self.ty
+    }
+}
+
 /// A variant representing a single FTL key entry.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct FtlVariant {
     name: &'static str,
     ftl_key: StaticFluentEntryId,
     args: &'static [StaticFluentArgumentName],
+    /// Rust argument types, parallel to `args`; `None` where unknown.
+    arg_types: &'static [Option<&'static str>],
+    /// Arguments that drive a Fluent select expression.
+    selectors: &'static [FtlSelector],
+    /// Arguments whose types' messages are linked as Fluent terms.
+    term_links: &'static [FtlTermLink],
+    /// Arguments that drive a scaffolded CLDR plural select.
+    plurals: &'static [StaticFluentArgumentName],
+    /// Source doc comment emitted as the message comment in generated FTL.
+    doc: Option<&'static str>,
+    /// Message attributes scaffolded as `.name = ...` lines in generated FTL.
+    attributes: &'static [&'static str],
     /// The module path from `module_path!()`.
     module_path: &'static str,
     /// The line number from `line!()` macro.
@@ -218,11 +335,54 @@ impl FtlVariant {
             name,
             ftl_key,
             args,
+            arg_types: &[],
+            selectors: &[],
+            term_links: &[],
+            plurals: &[],
+            doc: None,
+            attributes: &[],
             module_path,
             line,
         }
     }
 
+    /// Attaches the Rust type of each argument, in `args` order, with `None`
+    /// for an argument whose type is unknown.
+    pub const fn with_arg_types(mut self, arg_types: &'static [Option<&'static str>]) -> Self {
+        self.arg_types = arg_types;
+        self
+    }
+
+    /// Attaches selector metadata for arguments backed by choice types.
+    pub const fn with_selectors(mut self, selectors: &'static [FtlSelector]) -> Self {
+        self.selectors = selectors;
+        self
+    }
+
+    /// Attaches term link metadata for `#[fluent(term)]` arguments.
+    pub const fn with_term_links(mut self, term_links: &'static [FtlTermLink]) -> Self {
+        self.term_links = term_links;
+        self
+    }
+
+    /// Attaches the `#[fluent(plural)]` arguments.
+    pub const fn with_plurals(mut self, plurals: &'static [StaticFluentArgumentName]) -> Self {
+        self.plurals = plurals;
+        self
+    }
+
+    /// Attaches the source doc comment written into generated FTL files.
+    pub const fn with_doc(mut self, doc: &'static str) -> Self {
+        self.doc = Some(doc);
+        self
+    }
+
+    /// Attaches the `#[fluent(attributes = [...])]` message attribute names.
+    pub const fn with_attributes(mut self, attributes: &'static [&'static str]) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
     pub fn name(&self) -> &'static str {
         self.name
     }
@@ -231,6 +391,43 @@ impl FtlVariant {
         self.args
     }
 
+    /// Returns each argument with its Rust field type, in `args` order.
+    pub fn typed_args(&self) -> impl Iterator<Item = FtlArg> + '_ {
+        self.args.iter().enumerate().map(|(index, name)| FtlArg {
+            name: *name,
+            ty: self.arg_types.get(index).copied().flatten(),
+        })
+    }
+
+    pub fn selectors(&self) -> &'static [FtlSelector] {
+        self.selectors
+    }
+
+    /// Returns the selector metadata for `arg`, if that argument is a choice.
+    pub fn selector_for(&self, arg: &str) -> Option<&'static FtlSelector> {
+        self.selectors
+            .iter()
+            .find(|selector| selector.arg.as_str() == arg)
+    }
+
+    pub fn term_links(&self) -> &'static [FtlTermLink] {
+        self.term_links
+    }
+
+    pub fn plurals(&self) -> &'static [StaticFluentArgumentName] {
+        self.plurals
+    }
+
+    /// Returns the source doc comment captured with `#[fluent(doc_as_comment)]`.
+    pub fn doc(&self) -> Option<&'static str> {
+        self.doc
+    }
+
+    /// Returns the message attribute names, in declaration order.
+    pub fn attributes(&self) -> &'static [&'static str] {
+        self.attributes
+    }
+
     pub fn module_path(&self) -> &'static str {
         self.module_path
     }
@@ -265,9 +462,17 @@ pub struct FtlTypeInfo {
     file_path: &'static str,
     /// The module path where this type is defined (from `module_path!()` macro).
     module_path: &'static str,
+    /// The line of the type's name (from `line!()` macro), when recorded.
+    line: Option<u32>,
     /// Optional namespace for FTL file output. If Some, the type will be written to
     /// `{lang}/{crate}/{namespace}.ftl` instead of `{lang}/{crate}.ftl`.
     namespace: Option<NamespaceRule>,
+    /// Optional `## Group` header text from `#[fluent(group = "...")]`.
+    group_label: Option<&'static str>,
+    /// Whether `#[fluent(skip_generate)]` keeps the type out of generated FTL.
+    skip_generate: bool,
+    /// Whether `#[fluent(as_terms)]` generates the type's entries as Fluent terms.
+    is_term: bool,
 }
 
 impl AsRef<FtlTypeInfo> for FtlTypeInfo {
@@ -292,10 +497,38 @@ impl FtlTypeInfo {
             variants,
             file_path,
             module_path,
+            line: None,
             namespace,
+            group_label: None,
+            skip_generate: false,
+            is_term: false,
         }
     }
 
+    /// Attaches the line where the type is defined.
+    pub const fn with_line(mut self, line: u32) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Attaches the `## Group` header text written instead of the type name.
+    pub const fn with_group_label(mut self, group_label: &'static str) -> Self {
+        self.group_label = Some(group_label);
+        self
+    }
+
+    /// Marks the type as looked up at runtime only, never generated.
+    pub const fn with_skip_generate(mut self) -> Self {
+        self.skip_generate = true;
+        self
+    }
+
+    /// Marks the type's entries as Fluent terms (`-key = ...`).
+    pub const fn with_term(mut self) -> Self {
+        self.is_term = true;
+        self
+    }
+
     pub fn type_kind(&self) -> &TypeKind {
         &self.type_kind
     }
@@ -320,16 +553,56 @@ impl FtlTypeInfo {
         self.namespace.as_ref()
     }
 
+    /// Returns the group header text set with `#[fluent(group = "...")]`.
+    pub fn group_label(&self) -> Option<&'static str> {
+        self.group_label
+    }
+
+    /// Returns whether generation leaves this type's keys to hand-authored FTL.
+    pub fn skip_generate(&self) -> bool {
+        self.skip_generate
+    }
+
+    /// Returns whether generation writes this type's entries as Fluent terms.
+    pub fn is_term(&self) -> bool {
+        self.is_term
+    }
+
+    /// Returns the `## Group` header text: the group label, or the type name.
+    pub fn group_name(&self) -> &'static str {
+        self.group_label.unwrap_or(self.type_name)
+    }
+
     /// Returns typed source file metadata when this type has a recorded file path.
     pub fn source_file(&self) -> Option<SourceFile> {
         SourceFile::new(self.file_path)
     }
 
+    /// Returns typed source location metadata for the type definition.
+    pub fn source_location(&self) -> Option<SourceLocation> {
+        SourceLocation::new(self.file_path, self.line?)
+    }
+
     /// Returns typed source location metadata for the given variant.
     pub fn source_location_for(&self, variant: &FtlVariant) -> Option<SourceLocation> {
         SourceLocation::new(self.file_path, variant.line)
     }
 
+    /// Returns a stable human-readable description of the type definition,
+    /// including its kind, for diagnostics.
+    pub fn source_description(&self) -> String {
+        let item = format!("{} '{}'", self.type_kind.label(), self.type_name);
+        match self.source_location() {
+            Some(location) => format!(
+                "{item} at {}:{}",
+                location.file().as_str(),
+                location.line().get()
+            ),
+            None if self.file_path.is_empty() => item,
+            None => format!("{item} in {}", self.file_path),
+        }
+    }
+
     /// Returns a stable human-readable source description for diagnostics.
     pub fn source_description_for(&self, variant: &FtlVariant) -> String {
         let item = format!("type '{}' variant '{}'", self.type_name, variant.name);
@@ -369,8 +642,8 @@ impl FtlTypeInfo {
 #[doc(hidden)]
 pub mod __macro {
     use super::{
-        FtlTypeInfo, FtlVariant, NamespaceRule, ResolvedNamespace, StaticFluentArgumentName,
-        StaticFluentDomain, StaticFluentEntryId, StaticFluentVariantKey,
+        FtlSelector, FtlTermLink, FtlTypeInfo, FtlVariant, NamespaceRule, ResolvedNamespace,
+        StaticFluentArgumentName, StaticFluentDomain, StaticFluentEntryId, StaticFluentVariantKey,
     };
     use crate::meta::TypeKind;
 
@@ -404,11 +677,26 @@ pub mod __macro {
         FtlVariant::new(name, ftl_key, args, module_path, line)
     }
 
+    pub const fn ftl_selector(
+        arg: StaticFluentArgumentName,
+        choices: fn() -> &'static [StaticFluentVariantKey],
+    ) -> FtlSelector {
+        FtlSelector::new(arg, choices)
+    }
+
+    pub const fn ftl_term_link(
+        arg: StaticFluentArgumentName,
+        entry_ids: fn() -> &'static [StaticFluentEntryId],
+    ) -> FtlTermLink {
+        FtlTermLink::new(arg, entry_ids)
+    }
+
     pub const fn ftl_type_info(
         type_kind: TypeKind,
         type_name: &'static str,
         variants: &'static [FtlVariant],
         file_path: &'static str,
+        line: u32,
         module_path: &'static str,
         namespace: Option<NamespaceRule>,
     ) -> FtlTypeInfo {
@@ -420,14 +708,15 @@ pub mod __macro {
             module_path,
             namespace,
         )
+        .with_line(line)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        FtlTypeInfo, NamespacePathError, NamespaceRule, StaticFluentArgumentName,
-        StaticFluentDomain, StaticFluentEntryId, StaticFluentVariantKey,
+        FtlSelector, FtlTermLink, FtlTypeInfo, NamespacePathError, NamespaceRule,
+        StaticFluentArgumentName, StaticFluentDomain, StaticFluentEntryId, StaticFluentVariantKey,
     };
     use crate::meta::TypeKind;
     use crate::registry::FtlVariant;
@@ -613,6 +902,131 @@ mod tests {
         let location = info.source_location_for(&VARIANTS[0]).unwrap();
         assert_eq!(location.file().as_str(), "src/status.rs");
         assert_eq!(location.line().get(), 42);
+
+        assert!(info.source_location().is_none());
+        let location = info.with_line(40).source_location().unwrap();
+        assert_eq!(location.file().as_str(), "src/status.rs");
+        assert_eq!(location.line().get(), 40);
+    }
+
+    #[test]
+    fn ftl_type_info_group_name_prefers_group_label() {
+        let info = FtlTypeInfo::new(TypeKind::Enum, "AppError", &[], "src/lib.rs", "demo", None);
+        assert_eq!(info.group_label(), None);
+        assert_eq!(info.group_name(), "AppError");
+
+        let info = info.with_group_label("User Facing Errors");
+        assert_eq!(info.group_label(), Some("User Facing Errors"));
+        assert_eq!(info.group_name(), "User Facing Errors");
+    }
+
+    #[test]
+    fn ftl_type_info_skip_generate_defaults_to_false() {
+        let info = FtlTypeInfo::new(TypeKind::Struct, "Shared", &[], "src/lib.rs", "demo", None);
+        assert!(!info.skip_generate());
+        assert!(info.with_skip_generate().skip_generate());
+    }
+
+    #[test]
+    fn ftl_type_info_is_term_defaults_to_false() {
+        let info = FtlTypeInfo::new(TypeKind::Struct, "Brand", &[], "src/lib.rs", "demo", None);
+        assert!(!info.is_term());
+        assert!(info.with_term().is_term());
+    }
+
+    #[test]
+    fn ftl_variant_exposes_selector_choices_by_argument() {
+        fn gender_choices() -> &'static [StaticFluentVariantKey] {
+            const CHOICES: &[StaticFluentVariantKey] = &[
+                StaticFluentVariantKey::new_unchecked("male"),
+                StaticFluentVariantKey::new_unchecked("other"),
+            ];
+            CHOICES
+        }
+
+        static SELECTORS: &[FtlSelector] = &[FtlSelector::new(
+            StaticFluentArgumentName::new_unchecked("gender"),
+            gender_choices,
+        )];
+        static ARGS: &[StaticFluentArgumentName] = &[
+            StaticFluentArgumentName::new_unchecked("name"),
+            StaticFluentArgumentName::new_unchecked("gender"),
+        ];
+        let variant = FtlVariant::new(
+            "Greeting",
+            StaticFluentEntryId::new_unchecked("greeting"),
+            ARGS,
+            "demo",
+            1,
+        )
+        .with_selectors(SELECTORS);
+
+        assert!(variant.selector_for("name").is_none());
+        let selector = variant.selector_for("gender").expect("gender selector");
+        assert_eq!(selector.arg().as_str(), "gender");
+        assert_eq!(selector.choices(), &["male", "other"]);
+    }
+
+    #[test]
+    fn ftl_variant_pairs_args_with_their_rust_types() {
+        static ARGS: &[StaticFluentArgumentName] = &[
+            StaticFluentArgumentName::new_unchecked("name"),
+            StaticFluentArgumentName::new_unchecked("count"),
+        ];
+        let variant = FtlVariant::new(
+            "Greeting",
+            StaticFluentEntryId::new_unchecked("greeting"),
+            ARGS,
+            "demo",
+            1,
+        );
+        let untyped: Vec<_> = variant.typed_args().map(|arg| arg.ty()).collect();
+        assert_eq!(untyped, [None, None]);
+
+        let variant = variant.with_arg_types(&[Some("&'a str"), Some("Option<u32>")]);
+        let typed: Vec<_> = variant
+            .typed_args()
+            .map(|arg| (arg.name().as_str(), arg.ty()))
+            .collect();
+        assert_eq!(
+            typed,
+            [("name", Some("&'a str")), ("count", Some("Option<u32>"))]
+        );
+
+        let variant = variant.with_arg_types(&[None, Some("usize")]);
+        let typed: Vec<_> = variant.typed_args().map(|arg| arg.ty()).collect();
+        assert_eq!(typed, [None, Some("usize")]);
+    }
+
+    #[test]
+    fn ftl_variant_exposes_term_links_lazily() {
+        fn state_entry_ids() -> &'static [StaticFluentEntryId] {
+            const IDS: &[StaticFluentEntryId] = &[
+                StaticFluentEntryId::new_unchecked("usa_state-A"),
+                StaticFluentEntryId::new_unchecked("usa_state-B"),
+            ];
+            IDS
+        }
+
+        static TERM_LINKS: &[FtlTermLink] = &[FtlTermLink::new(
+            StaticFluentArgumentName::new_unchecked("f0"),
+            state_entry_ids,
+        )];
+        static ARGS: &[StaticFluentArgumentName] = &[StaticFluentArgumentName::new_unchecked("f0")];
+        let variant = FtlVariant::new(
+            "USA",
+            StaticFluentEntryId::new_unchecked("country-USA"),
+            ARGS,
+            "demo",
+            1,
+        )
+        .with_term_links(TERM_LINKS);
+
+        let [link] = variant.term_links() else {
+            panic!("expected one term link");
+        };
+        assert_eq!(link.arg().as_str(), "f0");
+        assert_eq!(link.entry_ids(), &["usa_state-A", "usa_state-B"]);
     }
 
     #[test]
@@ -628,6 +1042,7 @@ mod tests {
 
         assert!(info.source_file().is_none());
         assert!(info.source_location_for(&VARIANTS[0]).is_none());
+        assert!(info.with_line(40).source_location().is_none());
     }
 
     #[test]
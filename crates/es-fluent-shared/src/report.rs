@@ -0,0 +1,94 @@
+//! Structured summaries of FTL generation runs.
+
+use std::collections::BTreeMap;
+
+/// Keys and groups that a generate or clean run changed, or would change in
+/// dry-run mode.
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct GenerateReport {
+    /// Whether any FTL file was, or would be, rewritten or removed.
+    #[serde(default)]
+    pub changed: bool,
+    /// Message IDs that are new in the rendered output.
+    #[serde(default)]
+    pub added: Vec<String>,
+    /// Message IDs that the rendered output drops, including keys in removed
+    /// stale files.
+    #[serde(default)]
+    pub removed: Vec<String>,
+    /// The `## Group` header each removed message ID sat under, for IDs that
+    /// were inside a group.
+    #[serde(default)]
+    pub removed_groups: BTreeMap<String, String>,
+    /// Group comments (one per type) that are new in the rendered output.
+    #[serde(default)]
+    pub groups_added: Vec<String>,
+}
+
+impl GenerateReport {
+    /// Returns `true` when no key or group was added or removed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.groups_added.is_empty()
+    }
+
+    /// Folds another report into this one, keeping every list sorted and
+    /// without duplicates.
+    pub fn merge(&mut self, other: Self) {
+        self.changed |= other.changed;
+        self.added.extend(other.added);
+        self.removed.extend(other.removed);
+        self.removed_groups.extend(other.removed_groups);
+        self.groups_added.extend(other.groups_added);
+        self.normalize();
+    }
+
+    /// Sorts and deduplicates every list.
+    pub fn normalize(&mut self) {
+        for list in [&mut self.added, &mut self.removed, &mut self.groups_added] {
+            list.sort();
+            list.dedup();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GenerateReport;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn merge_combines_sorted_unique_entries() {
+        let mut report = GenerateReport {
+            changed: false,
+            added: vec!["b".to_string()],
+            removed: Vec::new(),
+            removed_groups: BTreeMap::new(),
+            groups_added: vec!["B".to_string()],
+        };
+
+        report.merge(GenerateReport {
+            changed: true,
+            added: vec!["a".to_string(), "b".to_string()],
+            removed: vec!["old".to_string()],
+            removed_groups: BTreeMap::from([("old".to_string(), "Old".to_string())]),
+            groups_added: vec!["A".to_string()],
+        });
+
+        assert!(report.changed);
+        assert_eq!(report.added, ["a", "b"]);
+        assert_eq!(report.removed, ["old"]);
+        assert_eq!(report.removed_groups["old"], "Old");
+        assert_eq!(report.groups_added, ["A", "B"]);
+        assert!(!report.is_empty());
+        assert!(GenerateReport::default().is_empty());
+    }
+
+    #[test]
+    fn report_deserializes_with_missing_fields() {
+        let report: GenerateReport = toml::from_str(r#"added = ["hello"]"#).expect("report");
+
+        assert_eq!(report.added, ["hello"]);
+        assert!(!report.changed);
+        assert!(report.removed.is_empty());
+    }
+}
@@ -109,6 +109,14 @@ pub enum SparseAssetResourcePlanError {
         /// Namespace path without the `.ftl` extension.
         path: PathBuf,
     },
+    /// The configured base file name does not form a valid locale-relative path.
+    #[error("invalid base FTL file name '{file_stem}': {details}")]
+    InvalidBaseFileName {
+        /// Base file name without the `.ftl` extension.
+        file_stem: String,
+        /// Validation details.
+        details: LocaleRelativeFtlPathError,
+    },
     /// A discovered namespace is not a canonical namespace path.
     #[error("Discovered invalid namespace '{namespace}' in assets for crate '{domain}': {details}")]
     InvalidNamespace {
@@ -352,6 +360,108 @@ fn validate_locale_relative_ftl_path(path: &str) -> Result<(), LocaleRelativeFtl
     Ok(())
 }
 
+/// How locale files are arranged below the assets directory.
+///
+/// Resource specs always describe a file by its locale-relative path, such as
+/// `my-crate.ftl` or `my-crate/ui.ftl`; the layout decides where that file
+/// lives for a given language.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetsLayout {
+    /// `{assets_dir}/{language}/{domain}.ftl`, with namespaced files under
+    /// `{assets_dir}/{language}/{domain}/`.
+    #[default]
+    LanguageFirst,
+    /// `{assets_dir}/{domain}/{language}.ftl`, with namespaced files under
+    /// `{assets_dir}/{domain}/{language}/`.
+    DomainFirst,
+}
+
+impl AssetsLayout {
+    /// Returns the path below the assets directory of a locale-relative file
+    /// (e.g., `en/my-crate/ui.ftl` or `my-crate/en/ui.ftl`).
+    pub fn locale_path(self, lang: &str, locale_relative_path: &str) -> String {
+        match self {
+            Self::LanguageFirst => format!("{lang}/{locale_relative_path}"),
+            Self::DomainFirst => match locale_relative_path.split_once('/') {
+                Some((domain, rest)) => format!("{domain}/{lang}/{rest}"),
+                None => match locale_relative_path.strip_suffix(".ftl") {
+                    Some(stem) => format!("{stem}/{lang}.ftl"),
+                    None => format!("{locale_relative_path}/{lang}"),
+                },
+            },
+        }
+    }
+
+    /// Resolves a locale-relative file or directory for the locale whose
+    /// language-first directory is `locale_dir` (`{assets_dir}/{language}`).
+    ///
+    /// Under [`Self::DomainFirst`] the result is placed below the parent of
+    /// `locale_dir` instead, so callers can keep passing locale directories.
+    pub fn resolve_in_locale_dir(
+        self,
+        locale_dir: &Path,
+        locale_relative_path: impl AsRef<Path>,
+    ) -> PathBuf {
+        let locale_relative_path = locale_relative_path.as_ref();
+        let (Self::DomainFirst, Some(lang)) = (self, locale_dir.file_name()) else {
+            return locale_dir.join(locale_relative_path);
+        };
+        let assets_root = locale_dir.parent().unwrap_or_else(|| Path::new(""));
+
+        let mut components = locale_relative_path.components();
+        let Some(first) = components.next() else {
+            return locale_dir.to_path_buf();
+        };
+        let first = Path::new(first.as_os_str());
+        let rest = components.as_path();
+
+        if !rest.as_os_str().is_empty() {
+            return assets_root.join(first).join(lang).join(rest);
+        }
+        match first.file_stem() {
+            Some(stem) if first.extension().is_some_and(|ext| ext == "ftl") => {
+                let mut file_name = lang.to_os_string();
+                file_name.push(".ftl");
+                assets_root.join(stem).join(file_name)
+            },
+            _ => assets_root.join(first).join(lang),
+        }
+    }
+
+    /// Maps a path below the assets directory back to its language-first
+    /// form (`{language}/{locale_relative_path}`).
+    ///
+    /// Returns `None` when a domain-first path has no language segment.
+    pub fn language_first_path(self, asset_path: &str) -> Option<String> {
+        match self {
+            Self::LanguageFirst => Some(asset_path.to_string()),
+            Self::DomainFirst => {
+                let (domain, rest) = asset_path.split_once('/')?;
+                match rest.split_once('/') {
+                    Some((lang, tail)) => Some(format!("{lang}/{domain}/{tail}")),
+                    None => {
+                        let lang = rest.strip_suffix(".ftl")?;
+                        Some(format!("{lang}/{domain}.ftl"))
+                    },
+                }
+            },
+        }
+    }
+}
+
 /// Canonical route for a crate resource before it is expanded for a locale.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum ResourceRoute {
@@ -382,6 +492,22 @@ impl ResourceRoute {
         self.resource_spec_for_domain(&domain, required)
     }
 
+    /// Builds the concrete module resource specification for a crate domain
+    /// whose base file is `{base_file_stem}.ftl` instead of `{domain}.ftl`.
+    ///
+    /// Namespaced routes are unaffected and stay under `{domain}/`.
+    pub fn resource_spec_with_base_file(
+        &self,
+        domain: &str,
+        base_file_stem: &str,
+        required: bool,
+    ) -> ModuleResourceSpec {
+        match self {
+            Self::Base => ModuleResourceSpec::base_with_file_stem(domain, base_file_stem, required),
+            Self::Namespaced(_) => self.resource_spec(domain, required),
+        }
+    }
+
     /// Builds the concrete module resource specification for a validated crate domain.
     pub fn resource_spec_for_static_domain(
         &self,
@@ -467,6 +593,23 @@ impl ModuleResourceSpec {
         Self::base_for_domain(&domain, required)
     }
 
+    /// Creates the base domain resource specification for a base file named
+    /// `{file_stem}.ftl`, keeping the domain as its resource key.
+    pub fn try_base_with_file_stem(
+        domain: &str,
+        file_stem: &str,
+        required: bool,
+    ) -> Result<Self, ResourcePlanError> {
+        Self::try_new(domain, format!("{file_stem}.ftl"), required)
+    }
+
+    /// Creates the base domain resource specification for a validated base
+    /// file name.
+    pub fn base_with_file_stem(domain: &str, file_stem: &str, required: bool) -> Self {
+        Self::try_base_with_file_stem(domain, file_stem, required)
+            .expect("base FTL file name should be validated")
+    }
+
     /// Creates the base domain resource specification from a validated static domain.
     pub fn base_for_static_domain(domain: StaticFluentDomain, required: bool) -> Self {
         Self::new(
@@ -745,6 +888,45 @@ impl ResourcePlan {
         domain: &str,
         assets_root: &Path,
     ) -> Result<SparseAssetResourcePlans, SparseAssetResourcePlanError> {
+        Self::sparse_from_assets_with_base_file(domain, domain, assets_root)
+    }
+
+    /// Discovers sparse per-language resource plans like
+    /// [`Self::sparse_from_assets`], with the base resource read from
+    /// `{base_file_stem}.ftl` instead of `{domain}.ftl`.
+    pub fn sparse_from_assets_with_base_file(
+        domain: &str,
+        base_file_stem: &str,
+        assets_root: &Path,
+    ) -> Result<SparseAssetResourcePlans, SparseAssetResourcePlanError> {
+        Self::sparse_from_assets_with_layout(
+            domain,
+            base_file_stem,
+            assets_root,
+            AssetsLayout::LanguageFirst,
+        )
+    }
+
+    /// Discovers sparse per-language resource plans like
+    /// [`Self::sparse_from_assets_with_base_file`] from an assets tree
+    /// arranged by `layout`.
+    ///
+    /// Under [`AssetsLayout::DomainFirst`] the base resources are
+    /// `{base_file_stem}/{language}.ftl` and namespaced resources live under
+    /// `{domain}/{language}/`.
+    pub fn sparse_from_assets_with_layout(
+        domain: &str,
+        base_file_stem: &str,
+        assets_root: &Path,
+        layout: AssetsLayout,
+    ) -> Result<SparseAssetResourcePlans, SparseAssetResourcePlanError> {
+        let base_file =
+            LocaleRelativeFtlPath::try_new(format!("{base_file_stem}.ftl")).map_err(|details| {
+                SparseAssetResourcePlanError::InvalidBaseFileName {
+                    file_stem: base_file_stem.to_string(),
+                    details,
+                }
+            })?;
         let entries = std::fs::read_dir(assets_root).map_err(|source| {
             SparseAssetResourcePlanError::ReadAssetsRoot {
                 path: assets_root.to_path_buf(),
@@ -757,65 +939,94 @@ impl ResourcePlan {
         let mut discovered_languages = BTreeSet::new();
         let mut namespaces_by_language: BTreeMap<LanguageIdentifier, BTreeSet<ResolvedNamespace>> =
             BTreeMap::new();
+        let mut record =
+            |canonical_lang: LanguageIdentifier,
+             has_base_file: bool,
+             discovered_namespaces: BTreeSet<ResolvedNamespace>| {
+                if has_base_file || !discovered_namespaces.is_empty() {
+                    discovered_languages.insert(canonical_lang.clone());
+                }
+                if has_base_file {
+                    languages_with_base_file.insert(canonical_lang.clone());
+                }
+                for namespace in discovered_namespaces {
+                    namespaces.insert(namespace.clone());
+                    namespaces_by_language
+                        .entry(canonical_lang.clone())
+                        .or_default()
+                        .insert(namespace);
+                }
+            };
 
-        for entry in entries {
-            let entry =
-                entry.map_err(|source| SparseAssetResourcePlanError::ReadAssetsRootEntry {
-                    path: assets_root.to_path_buf(),
-                    source,
-                })?;
-            let path = entry.path();
-            if !path.is_dir() {
-                continue;
-            }
-
-            let raw_name = path
-                .file_name()
-                .and_then(|value| value.to_str())
-                .ok_or_else(|| SparseAssetResourcePlanError::NonUtf8LocaleDirectory {
-                    path: path.clone(),
-                })?;
-            let canonical_lang =
-                crate::parse_canonical_language_identifier(raw_name).map_err(|details| {
-                    SparseAssetResourcePlanError::InvalidLocaleDirectory {
-                        raw_name: raw_name.to_string(),
-                        path: path.clone(),
-                        details,
+        match layout {
+            AssetsLayout::LanguageFirst => {
+                for entry in entries {
+                    let entry = entry.map_err(|source| {
+                        SparseAssetResourcePlanError::ReadAssetsRootEntry {
+                            path: assets_root.to_path_buf(),
+                            source,
+                        }
+                    })?;
+                    let path = entry.path();
+                    if !path.is_dir() {
+                        continue;
                     }
-                })?;
-
-            let base_path = path.join(format!("{domain}.ftl"));
-            let namespace_root = path.join(domain);
-            let has_base_file = base_path.exists();
-            let discovered_namespaces = if namespace_root.is_dir() {
-                discover_namespaces(domain, &namespace_root)?
-            } else {
-                BTreeSet::new()
-            };
 
-            if has_base_file || !discovered_namespaces.is_empty() {
-                discovered_languages.insert(canonical_lang.clone());
-            }
-            if has_base_file {
-                languages_with_base_file.insert(canonical_lang.clone());
-            }
-            for namespace in discovered_namespaces {
-                namespaces.insert(namespace.clone());
-                namespaces_by_language
-                    .entry(canonical_lang.clone())
-                    .or_default()
-                    .insert(namespace);
-            }
+                    let canonical_lang = canonical_locale_from_entry(&path, &path)?;
+                    let namespace_root = path.join(domain);
+                    let discovered_namespaces = if namespace_root.is_dir() {
+                        discover_namespaces(domain, &namespace_root)?
+                    } else {
+                        BTreeSet::new()
+                    };
+                    record(
+                        canonical_lang,
+                        path.join(base_file.as_str()).exists(),
+                        discovered_namespaces,
+                    );
+                }
+            },
+            AssetsLayout::DomainFirst => {
+                for path in read_domain_dir(&assets_root.join(base_file_stem))? {
+                    if !path.is_file()
+                        || path.extension().and_then(|ext| ext.to_str()) != Some("ftl")
+                    {
+                        continue;
+                    }
+                    let canonical_lang =
+                        canonical_locale_from_entry(&path, &path.with_extension(""))?;
+                    record(canonical_lang, true, BTreeSet::new());
+                }
+                for path in read_domain_dir(&assets_root.join(domain))? {
+                    if !path.is_dir() {
+                        continue;
+                    }
+                    let canonical_lang = canonical_locale_from_entry(&path, &path)?;
+                    record(canonical_lang, false, discover_namespaces(domain, &path)?);
+                }
+            },
         }
 
         let namespaces: Vec<ResolvedNamespace> = namespaces.into_iter().collect();
         let languages: Vec<LanguageIdentifier> = discovered_languages.into_iter().collect();
         let mut resource_specs_by_language = Vec::with_capacity(languages.len());
 
+        let with_base_file = |plan: Self| {
+            plan.into_specs()
+                .into_iter()
+                .map(|mut spec| {
+                    if spec.key.as_str() == domain {
+                        spec.locale_relative_path = base_file.clone();
+                    }
+                    spec
+                })
+                .collect::<Vec<_>>()
+        };
+
         for lang in &languages {
             if namespaces.is_empty() {
                 let plan = Self::sparse_for_domain(domain, true, &[], true);
-                resource_specs_by_language.push((lang.clone(), plan.into_specs()));
+                resource_specs_by_language.push((lang.clone(), with_base_file(plan)));
                 continue;
             }
 
@@ -832,7 +1043,7 @@ impl ResourcePlan {
                 &resolved_namespaces,
                 false,
             );
-            resource_specs_by_language.push((lang.clone(), plan.into_specs()));
+            resource_specs_by_language.push((lang.clone(), with_base_file(plan)));
         }
 
         Ok(SparseAssetResourcePlans {
@@ -899,6 +1110,49 @@ fn namespace_from_relative_ftl_path(
         })
 }
 
+/// Parses the language named by `name_path`'s file name, reporting errors
+/// against the asset at `path`.
+fn canonical_locale_from_entry(
+    path: &Path,
+    name_path: &Path,
+) -> Result<LanguageIdentifier, SparseAssetResourcePlanError> {
+    let raw_name = name_path
+        .file_name()
+        .and_then(|value| value.to_str())
+        .ok_or_else(|| SparseAssetResourcePlanError::NonUtf8LocaleDirectory {
+            path: path.to_path_buf(),
+        })?;
+    crate::parse_canonical_language_identifier(raw_name).map_err(|details| {
+        SparseAssetResourcePlanError::InvalidLocaleDirectory {
+            raw_name: raw_name.to_string(),
+            path: path.to_path_buf(),
+            details,
+        }
+    })
+}
+
+/// Lists the entries of a domain-first domain directory, which may be absent.
+fn read_domain_dir(dir: &Path) -> Result<Vec<PathBuf>, SparseAssetResourcePlanError> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let entries =
+        std::fs::read_dir(dir).map_err(|source| SparseAssetResourcePlanError::ReadAssetsRoot {
+            path: dir.to_path_buf(),
+            source,
+        })?;
+    entries
+        .map(|entry| {
+            entry.map(|entry| entry.path()).map_err(|source| {
+                SparseAssetResourcePlanError::ReadAssetsRootEntry {
+                    path: dir.to_path_buf(),
+                    source,
+                }
+            })
+        })
+        .collect()
+}
+
 fn discover_namespaces(
     domain: &str,
     namespace_root: &Path,
@@ -1198,6 +1452,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn domain_first_layout_maps_locale_relative_paths_both_ways() {
+        let layout = AssetsLayout::DomainFirst;
+
+        assert_eq!(layout.locale_path("en", "demo.ftl"), "demo/en.ftl");
+        assert_eq!(
+            layout.locale_path("en", "demo/forms/button.ftl"),
+            "demo/en/forms/button.ftl"
+        );
+        assert_eq!(
+            AssetsLayout::LanguageFirst.locale_path("en", "demo/ui.ftl"),
+            "en/demo/ui.ftl"
+        );
+        assert_eq!(
+            layout.resolve_in_locale_dir(Path::new("i18n/fr"), "demo.ftl"),
+            Path::new("i18n/demo/fr.ftl")
+        );
+        assert_eq!(
+            layout.resolve_in_locale_dir(Path::new("i18n/fr"), "demo/ui.ftl"),
+            Path::new("i18n/demo/fr/ui.ftl")
+        );
+        assert_eq!(
+            layout.resolve_in_locale_dir(Path::new("i18n/fr"), "demo"),
+            Path::new("i18n/demo/fr")
+        );
+        assert_eq!(
+            layout.language_first_path("demo/en.ftl").as_deref(),
+            Some("en/demo.ftl")
+        );
+        assert_eq!(
+            layout
+                .language_first_path("demo/en/forms/button.ftl")
+                .as_deref(),
+            Some("en/demo/forms/button.ftl")
+        );
+        assert_eq!(layout.language_first_path("demo.ftl"), None);
+    }
+
+    #[test]
+    fn sparse_from_assets_with_domain_first_layout_discovers_resource_plans() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let assets = temp.path();
+        std::fs::create_dir_all(assets.join("demo/fr/forms")).expect("create fr assets");
+        std::fs::create_dir_all(assets.join("other")).expect("create other domain");
+        std::fs::write(assets.join("demo/en-US.ftl"), "hello = Hello").expect("write en base");
+        std::fs::write(assets.join("demo/fr/forms/button.ftl"), "button = Bouton")
+            .expect("write fr namespace");
+        std::fs::write(assets.join("other/de.ftl"), "hallo = Hallo").expect("write other");
+
+        let plans = ResourcePlan::sparse_from_assets_with_layout(
+            "demo",
+            "demo",
+            assets,
+            AssetsLayout::DomainFirst,
+        )
+        .expect("plans");
+
+        let specs_by_language = plans
+            .resource_specs_by_language()
+            .iter()
+            .map(|(language, specs)| (language.to_string(), specs.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            specs_by_language,
+            vec![
+                (
+                    "en-US".to_string(),
+                    vec![ModuleResourceSpec::base("demo", false)]
+                ),
+                (
+                    "fr".to_string(),
+                    vec![ModuleResourceSpec::namespaced(
+                        "demo",
+                        &ResolvedNamespace::new("forms/button").expect("forms namespace"),
+                        true
+                    )]
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn sparse_from_assets_requires_base_when_no_namespaces_exist() {
         let temp = tempfile::tempdir().expect("tempdir");
@@ -1221,6 +1556,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sparse_from_assets_with_base_file_reads_the_configured_base_file() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let assets = temp.path();
+        std::fs::create_dir_all(assets.join("en/demo")).expect("create en assets");
+        std::fs::write(assets.join("en/demo.ftl"), "ignored = Ignored").expect("write default");
+        std::fs::write(assets.join("en/demo-messages.ftl"), "hello = Hello")
+            .expect("write custom base");
+        std::fs::write(assets.join("en/demo/ui.ftl"), "title = UI").expect("write en ui");
+
+        let plans =
+            ResourcePlan::sparse_from_assets_with_base_file("demo", "demo-messages", assets)
+                .expect("plans");
+
+        let specs_by_language = plans
+            .resource_specs_by_language()
+            .iter()
+            .map(|(language, specs)| (language.to_string(), specs.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            specs_by_language,
+            vec![(
+                "en".to_string(),
+                vec![
+                    ModuleResourceSpec::base_with_file_stem("demo", "demo-messages", false),
+                    ModuleResourceSpec::namespaced(
+                        "demo",
+                        &ResolvedNamespace::new("ui").expect("ui namespace"),
+                        true
+                    ),
+                ]
+            )]
+        );
+        assert_eq!(
+            specs_by_language[0].1[0].locale_relative_path.as_str(),
+            "demo-messages.ftl"
+        );
+
+        let error = ResourcePlan::sparse_from_assets_with_base_file("demo", "../demo", assets)
+            .expect_err("invalid base file name");
+        assert!(matches!(
+            error,
+            SparseAssetResourcePlanError::InvalidBaseFileName { ref file_stem, .. }
+                if file_stem == "../demo"
+        ));
+    }
+
     #[test]
     fn sparse_from_assets_rejects_noncanonical_locale_directories() {
         let temp = tempfile::tempdir().expect("tempdir");
@@ -1,6 +1,7 @@
 use crate::I18nConfigError;
 use es_fluent_shared::CanonicalLanguageIdentifierError;
 use fs_err as fs;
+use std::ffi::OsString;
 use std::io;
 use unic_langid::LanguageIdentifier;
 
@@ -24,7 +25,14 @@ pub(crate) fn parse_language_entry(
         return Ok(None);
     }
 
-    let raw_name = entry.file_name();
+    parse_language_name(entry.file_name()).map(Some)
+}
+
+/// Parse an asset entry name, such as a locale directory name or the stem of
+/// a domain-first `{language}.ftl` file, as a language identifier.
+pub(crate) fn parse_language_name(
+    raw_name: OsString,
+) -> Result<ParsedLanguageEntry, I18nConfigError> {
     let name = raw_name.into_string().map_err(|raw| {
         I18nConfigError::ReadError(io::Error::new(
             io::ErrorKind::InvalidData,
@@ -54,10 +62,10 @@ pub(crate) fn parse_language_entry(
             },
         })?;
 
-    Ok(Some(ParsedLanguageEntry {
+    Ok(ParsedLanguageEntry {
         raw_name: name,
         language: lang,
-    }))
+    })
 }
 
 #[cfg(test)]
@@ -5,6 +5,7 @@ mod language;
 
 use es_fluent_shared::CanonicalLanguageIdentifierError;
 use es_fluent_shared::namespace::{NamespacePathError, ResolvedNamespace};
+pub use es_fluent_shared::resource::AssetsLayout;
 use fs_err::{self as fs, DirEntry};
 use path_slash::PathExt as _;
 use serde::{Deserialize, Serialize};
@@ -24,6 +25,21 @@ const CRATE_ROOT_ASSET_IGNORED_DIRS: &[&str] = &[
     "doc", "docs", "examples", "lib", "man", "src", "target", "tests",
 ];
 
+/// Environment variable that points directly at the `i18n.toml` to use.
+///
+/// When set to a non-empty path it takes precedence over the manifest-relative
+/// and workspace-root lookups, for build systems where the crate's manifest
+/// directory is not where the configuration lives. Relative `assets_dir`
+/// values resolve against the directory holding that file.
+pub const CONFIG_PATH_ENV: &str = "ES_FLUENT_CONFIG";
+
+/// Returns the configuration path from [`CONFIG_PATH_ENV`], when set.
+fn config_path_from_env() -> Option<PathBuf> {
+    env::var_os(CONFIG_PATH_ENV)
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+}
+
 /// Directory names ignored as locale candidates when `assets_dir = "."`.
 pub fn crate_root_asset_ignored_dir_names() -> &'static [&'static str] {
     CRATE_ROOT_ASSET_IGNORED_DIRS
@@ -115,6 +131,31 @@ pub enum I18nConfigError {
         /// The canonical fallback language string expected by the runtime.
         canonical: String,
     },
+    /// Encountered an invalid `supported_locales` entry.
+    #[error("Invalid supported locale '{name}' in i18n.toml")]
+    InvalidSupportedLocale {
+        /// The invalid identifier.
+        name: String,
+        /// The parsing error produced by `unic-langid`.
+        #[source]
+        source: LanguageIdentifierError,
+    },
+    /// Encountered a `supported_locales` entry that could not be converted to ICU.
+    #[error("Supported locale '{name}' could not be parsed as an ICU locale: {details}")]
+    IcuSupportedLocale {
+        /// The invalid identifier.
+        name: String,
+        /// The ICU parsing error.
+        details: String,
+    },
+    /// Encountered a non-canonical `supported_locales` entry.
+    #[error("Supported locale '{name}' must use canonical BCP-47 form '{canonical}'")]
+    NonCanonicalSupportedLocale {
+        /// The configured locale string.
+        name: String,
+        /// The canonical locale string expected by the runtime.
+        canonical: String,
+    },
     /// Encountered an invalid configured namespace allowlist entry.
     #[error("Invalid namespace '{namespace}' in i18n.toml: {source}")]
     InvalidNamespace {
@@ -132,15 +173,39 @@ pub enum I18nConfigError {
         /// Explanation of the validation failure.
         reason: &'static str,
     },
+    /// The fallback language directory is missing or holds no FTL files.
+    #[error("Fallback language directory '{path}' {reason}")]
+    MissingFallbackFtl {
+        /// The fallback language directory.
+        path: String,
+        /// Explanation of what is missing.
+        reason: &'static str,
+    },
+    /// Encountered an invalid configured base FTL file name pattern.
+    #[error("Invalid ftl_file_name '{pattern}' in i18n.toml: {reason}")]
+    InvalidFtlFileName {
+        /// The invalid ftl_file_name pattern.
+        pattern: String,
+        /// Explanation of the validation failure.
+        reason: &'static str,
+    },
 }
 
+/// Token in `ftl_file_name` that expands to the crate name.
+pub const FTL_FILE_NAME_CRATE_TOKEN: &str = "{crate}";
+
 /// Raw TOML shape for `i18n.toml` before validation and typed normalization.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RawI18nConfig {
     /// The fallback language identifier (e.g., "en-US").
     pub fallback_language: String,
     /// Path to the assets directory containing translation files.
-    /// Expected structure: {assets_dir}/{language}/{domain}.ftl
+    /// Expected structure: {assets_dir}/{language}/{domain}.ftl, or
+    /// {assets_dir}/{domain}/{language}.ftl with the `domain_first` layout.
+    ///
+    /// Relative paths resolve against the crate root and must stay inside it.
+    /// Absolute paths, and paths starting with `~`, `$VAR`, or `${VAR}`, are
+    /// used as written after expansion.
     pub assets_dir: PathBuf,
     /// Optional feature flag(s) that enable es-fluent derives in the crate.
     /// If specified, the CLI will enable these features when generating FTL files.
@@ -173,6 +238,72 @@ pub struct RawI18nConfig {
     /// ```
     #[serde(default = "default_check_fallback_copies")]
     pub check_fallback_copies: bool,
+    /// Whether `cargo es-fluent generate` and `clean` maintain a
+    /// `{file stem}.keys.json` manifest next to the base FTL file.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// keys_json = true
+    /// ```
+    #[serde(default)]
+    pub keys_json: bool,
+    /// Whether `cargo es-fluent generate` adds a `# @source:` comment naming
+    /// the Rust item behind every generated message and term.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// annotate_source = true
+    /// ```
+    #[serde(default)]
+    pub annotate_source: bool,
+    /// Optional ordered list of languages consulted when a message is missing
+    /// in the active language. The `fallback_language` terminates the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// fallback_chain = ["es", "en"]
+    /// ```
+    #[serde(default)]
+    pub fallback_chain: Option<Vec<String>>,
+    /// Optional file name, without the `.ftl` extension, of each crate's base
+    /// FTL file in a locale directory. `{crate}` expands to the crate name,
+    /// which is also the default. Namespaced files stay under
+    /// `{crate}/{namespace}.ftl`.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// ftl_file_name = "messages"
+    /// ```
+    #[serde(default)]
+    pub ftl_file_name: Option<String>,
+    /// Optional allowlist of locale directories that tooling treats as
+    /// shipped. Other locale directories, such as experimental translations,
+    /// are skipped when locales are enumerated. The `fallback_language` is
+    /// always included.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// supported_locales = ["en", "fr"]
+    /// ```
+    #[serde(default)]
+    pub supported_locales: Option<Vec<String>>,
+    /// How locale files are arranged below `assets_dir`: `language_first`
+    /// (`{language}/{domain}.ftl`, the default) or `domain_first`
+    /// (`{domain}/{language}.ftl`, with namespaced files under
+    /// `{domain}/{language}/`).
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// layout = "domain_first"
+    /// ```
+    #[serde(default)]
+    pub layout: AssetsLayout,
 }
 
 impl RawI18nConfig {
@@ -193,7 +324,43 @@ impl RawI18nConfig {
             })
             .transpose()?;
 
-        let assets_dir = normalize_relative_assets_dir(&self.assets_dir)?;
+        let fallback_chain = self
+            .fallback_chain
+            .map(|chain| {
+                chain
+                    .iter()
+                    .map(|language| parse_fallback_language_identifier(language))
+                    .collect()
+            })
+            .transpose()?;
+
+        let supported_locales = self
+            .supported_locales
+            .map(|locales| {
+                locales
+                    .iter()
+                    .map(|language| parse_supported_locale(language))
+                    .collect()
+            })
+            .transpose()?;
+
+        if let Some(pattern) = &self.ftl_file_name {
+            validate_ftl_file_name(pattern)?;
+            // A renamed base file would land in its own domain directory,
+            // apart from the crate's namespaced files.
+            if self.layout == AssetsLayout::DomainFirst && pattern != FTL_FILE_NAME_CRATE_TOKEN {
+                return Err(I18nConfigError::InvalidFtlFileName {
+                    pattern: pattern.clone(),
+                    reason: "is not supported with layout = \"domain_first\"",
+                });
+            }
+        }
+
+        let assets_dir = if is_external_assets_dir(&self.assets_dir) {
+            self.assets_dir
+        } else {
+            normalize_relative_assets_dir(&self.assets_dir)?
+        };
 
         Ok(I18nConfig {
             fallback_language,
@@ -201,22 +368,55 @@ impl RawI18nConfig {
             fluent_feature: self.fluent_feature,
             namespaces,
             check_fallback_copies: self.check_fallback_copies,
+            keys_json: self.keys_json,
+            annotate_source: self.annotate_source,
+            fallback_chain,
+            ftl_file_name: self.ftl_file_name,
+            supported_locales,
+            layout: self.layout,
+            config_dir: None,
         })
     }
 }
 
+fn validate_ftl_file_name(pattern: &str) -> Result<(), I18nConfigError> {
+    let invalid = |reason| I18nConfigError::InvalidFtlFileName {
+        pattern: pattern.to_string(),
+        reason,
+    };
+    let name = pattern.replace(FTL_FILE_NAME_CRATE_TOKEN, "crate");
+
+    if name.trim().is_empty() {
+        return Err(invalid("must not be empty"));
+    }
+    if name != name.trim() {
+        return Err(invalid("must not have leading or trailing whitespace"));
+    }
+    if name.contains(['/', '\\']) || matches!(name.as_str(), "." | "..") {
+        return Err(invalid("must be a file name, not a path"));
+    }
+    if name.ends_with(".ftl") {
+        return Err(invalid("must not include the .ftl extension"));
+    }
+    if name.contains(['{', '}']) {
+        return Err(invalid("only the {crate} token is supported"));
+    }
+
+    Ok(())
+}
+
 fn default_check_fallback_copies() -> bool {
     true
 }
 
 /// The configuration for `es-fluent`.
-#[derive(bon::Builder, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct I18nConfig {
     /// The fallback language identifier (e.g., "en-US").
     pub fallback_language: LanguageIdentifier,
     /// Path to the assets directory containing translation files.
-    /// Expected structure: {assets_dir}/{language}/{domain}.ftl
-    #[builder(into)]
+    /// Expected structure: {assets_dir}/{language}/{domain}.ftl, or
+    /// {assets_dir}/{domain}/{language}.ftl with the `domain_first` layout.
     pub assets_dir: PathBuf,
     /// Optional feature flag(s) that enable es-fluent derives in the crate.
     /// If specified, the CLI will enable these features when generating FTL files.
@@ -239,14 +439,114 @@ pub struct I18nConfig {
     pub namespaces: Option<Vec<ResolvedNamespace>>,
     /// Whether `cargo es-fluent check --all` should warn when a non-fallback
     /// locale copies the fallback message text.
-    #[builder(default = true)]
     pub check_fallback_copies: bool,
+    /// Whether generation maintains a `{file stem}.keys.json` manifest next
+    /// to the base FTL file.
+    pub keys_json: bool,
+    /// Whether generation adds a `# @source:` comment naming the Rust item
+    /// behind every generated entry.
+    pub annotate_source: bool,
+    /// Optional ordered list of languages consulted when a message is missing
+    /// in the active language.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// fallback_chain = ["es", "en"]
+    /// ```
+    pub fallback_chain: Option<Vec<LanguageIdentifier>>,
+    /// Optional file name, without the `.ftl` extension, of each crate's base
+    /// FTL file. `{crate}` expands to the crate name; `None` means `{crate}`.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// ftl_file_name = "messages"
+    /// ```
+    pub ftl_file_name: Option<String>,
+    /// Optional allowlist of locales that tooling enumerates. `None` means
+    /// every locale directory; the fallback language is always included.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// supported_locales = ["en", "fr"]
+    /// ```
+    pub supported_locales: Option<Vec<LanguageIdentifier>>,
+    /// How locale files are arranged below `assets_dir`.
+    ///
+    /// # Examples
+    ///
+    /// ```toml
+    /// layout = "domain_first"
+    /// ```
+    pub layout: AssetsLayout,
+    /// Directory that relative paths resolve against when no base directory
+    /// is given. `None` means `CARGO_MANIFEST_DIR`; a configuration shared
+    /// from the workspace root sets it to that root.
+    pub config_dir: Option<PathBuf>,
+}
+
+#[bon::bon]
+impl I18nConfig {
+    /// Builds a configuration in code, for tools that synthesize one instead
+    /// of reading `i18n.toml`.
+    ///
+    /// ```
+    /// let config = es_fluent_toml::I18nConfig::builder()
+    ///     .fallback_language("en")
+    ///     .assets_dir("i18n")
+    ///     .build()?;
+    /// assert_eq!(config.fallback_language_id(), "en");
+    /// # Ok::<(), es_fluent_toml::I18nConfigError>(())
+    /// ```
+    ///
+    /// Values are validated exactly as when they are read from `i18n.toml`:
+    /// languages must parse as canonical locales the runtime supports,
+    /// relative `assets_dir` paths must stay inside the crate, and namespaces
+    /// and `ftl_file_name` must be valid.
+    #[builder]
+    pub fn new(
+        #[builder(into)] fallback_language: String,
+        #[builder(into)] assets_dir: PathBuf,
+        fluent_feature: Option<Vec<String>>,
+        namespaces: Option<Vec<String>>,
+        #[builder(default = true)] check_fallback_copies: bool,
+        #[builder(default)] keys_json: bool,
+        #[builder(default)] annotate_source: bool,
+        fallback_chain: Option<Vec<String>>,
+        #[builder(into)] ftl_file_name: Option<String>,
+        supported_locales: Option<Vec<String>>,
+        #[builder(default)] layout: AssetsLayout,
+        #[builder(into)] config_dir: Option<PathBuf>,
+    ) -> Result<Self, I18nConfigError> {
+        let config = RawI18nConfig {
+            fallback_language,
+            assets_dir,
+            fluent_feature,
+            namespaces,
+            check_fallback_copies,
+            keys_json,
+            annotate_source,
+            fallback_chain,
+            ftl_file_name,
+            supported_locales,
+            layout,
+        }
+        .validate()?;
+
+        Ok(Self {
+            config_dir,
+            ..config
+        })
+    }
 }
 
 /// Fully resolved project i18n layout derived from `i18n.toml`.
 #[derive(Clone, Debug)]
 pub struct ResolvedI18nLayout {
-    /// Manifest directory that owns the configuration.
+    /// Directory holding the configuration: the crate's manifest directory,
+    /// or the workspace root for a shared `i18n.toml`.
     pub manifest_dir: PathBuf,
     /// Absolute path to `i18n.toml`.
     pub config_path: PathBuf,
@@ -257,13 +557,19 @@ pub struct ResolvedI18nLayout {
     /// Canonical fallback locale directory name.
     pub fallback_language: String,
     /// Absolute path to the fallback locale output directory.
+    ///
+    /// With the `domain_first` layout this is the language-first form of the
+    /// path; [`AssetsLayout::resolve_in_locale_dir`] maps files below it.
     pub output_dir: PathBuf,
 }
 
 impl ResolvedI18nLayout {
-    /// Resolve layout from a manifest directory containing `i18n.toml`.
+    /// Resolve layout for the crate in `manifest_dir`, using the `i18n.toml`
+    /// chosen by [`I18nConfig::config_path_for_manifest_dir`].
     pub fn from_manifest_dir(manifest_dir: &Path) -> Result<Self, I18nConfigError> {
-        Self::from_config_path(manifest_dir.join("i18n.toml"))
+        let config_path = I18nConfig::config_path_for_manifest_dir(manifest_dir)
+            .ok_or(I18nConfigError::NotFound)?;
+        Self::from_config_path(config_path)
     }
 
     /// Resolve layout from a concrete config path.
@@ -315,6 +621,18 @@ impl ResolvedI18nLayout {
             .available_locale_names_from_base(Some(&self.manifest_dir))
     }
 
+    /// Returns the domains (`*.ftl` file stems) available for `lang`.
+    ///
+    /// With the `domain_first` layout these are the domain directories that
+    /// hold `{lang}.ftl`.
+    pub fn available_domains(
+        &self,
+        lang: &LanguageIdentifier,
+    ) -> Result<Vec<String>, I18nConfigError> {
+        self.config
+            .available_domains_from_base(lang, Some(&self.manifest_dir))
+    }
+
     /// Returns the configured namespace allowlist when present.
     pub fn allowed_namespaces(&self) -> Option<&[ResolvedNamespace]> {
         self.config.namespaces.as_deref()
@@ -365,14 +683,65 @@ impl I18nConfig {
         raw.validate()
     }
 
-    /// Reads the configuration from the manifest directory.
+    /// Reads the configuration from the manifest directory, or from the file
+    /// named by [`CONFIG_PATH_ENV`] when that variable is set.
     pub fn read_from_manifest_dir() -> Result<Self, I18nConfigError> {
+        if let Some(config_path) = config_path_from_env() {
+            let mut config = Self::read_from_path(&config_path)?;
+            config.config_dir = config_path.parent().map(Path::to_path_buf);
+            return Ok(config);
+        }
+
         let manifest_dir = env::var("CARGO_MANIFEST_DIR").map_err(|_| I18nConfigError::NotFound)?;
 
         let config_path = Path::new(&manifest_dir).join("i18n.toml");
         Self::read_from_path(config_path)
     }
 
+    /// Reads the configuration for the crate in `CARGO_MANIFEST_DIR`, falling
+    /// back to an `i18n.toml` beside the workspace root `Cargo.toml`.
+    ///
+    /// See [`Self::from_manifest_dir_or_workspace`] for the precedence rule.
+    pub fn read_from_workspace_root() -> Result<Self, I18nConfigError> {
+        let manifest_dir = env::var("CARGO_MANIFEST_DIR").map_err(|_| I18nConfigError::NotFound)?;
+        Self::from_manifest_dir_or_workspace(Path::new(&manifest_dir))
+    }
+
+    /// Reads the configuration that applies to the crate in `manifest_dir`.
+    ///
+    /// A path in [`CONFIG_PATH_ENV`] wins over both files. Otherwise the
+    /// crate's own `i18n.toml` takes precedence. Without one, the `i18n.toml`
+    /// beside the workspace root `Cargo.toml` is used, and its relative paths
+    /// such as `assets_dir` resolve against the workspace root. The files are
+    /// not merged.
+    pub fn from_manifest_dir_or_workspace(manifest_dir: &Path) -> Result<Self, I18nConfigError> {
+        let config_path =
+            Self::config_path_for_manifest_dir(manifest_dir).ok_or(I18nConfigError::NotFound)?;
+        let mut config = Self::read_from_path(&config_path)?;
+        let config_dir = config_path.parent().unwrap_or(manifest_dir);
+        if config_dir != manifest_dir {
+            config.config_dir = Some(config_dir.to_path_buf());
+        }
+        Ok(config)
+    }
+
+    /// Returns the `i18n.toml` that applies to the crate in `manifest_dir`:
+    /// the file named by [`CONFIG_PATH_ENV`] when set, then the crate's own
+    /// file when present, otherwise the workspace root's.
+    pub fn config_path_for_manifest_dir(manifest_dir: &Path) -> Option<PathBuf> {
+        if let Some(config_path) = config_path_from_env() {
+            return Some(config_path);
+        }
+
+        let crate_config = manifest_dir.join("i18n.toml");
+        if crate_config.is_file() {
+            return Some(crate_config);
+        }
+
+        let workspace_config = find_workspace_root(manifest_dir)?.join("i18n.toml");
+        workspace_config.is_file().then_some(workspace_config)
+    }
+
     /// Returns the path to the assets directory.
     pub fn assets_dir_path(&self) -> PathBuf {
         PathBuf::from(&self.assets_dir)
@@ -384,13 +753,21 @@ impl I18nConfig {
     }
 
     /// Returns the path to the assets directory from a base directory.
-    /// If `base_dir` is `None`, uses `CARGO_MANIFEST_DIR` environment variable.
+    /// If `base_dir` is `None`, uses `config_dir`, or the `CARGO_MANIFEST_DIR`
+    /// environment variable when that is unset.
+    ///
+    /// An absolute `assets_dir`, or one starting with `~`, `$VAR`, or
+    /// `${VAR}`, is returned expanded and is not joined onto any base.
     pub fn assets_dir_from_base(
         &self,
         base_dir: Option<&Path>,
     ) -> Result<PathBuf, I18nConfigError> {
+        if let Some(assets_path) = expand_external_assets_dir(&self.assets_dir)? {
+            return Ok(assets_path);
+        }
+
         let assets_dir = normalize_relative_assets_dir(&self.assets_dir)?;
-        let base = match base_dir {
+        let base = match base_dir.or(self.config_dir.as_deref()) {
             Some(dir) => dir.to_path_buf(),
             None => {
                 let manifest_dir =
@@ -413,39 +790,88 @@ impl I18nConfig {
         Ok(assets_path)
     }
 
+    /// Returns the file name, without the `.ftl` extension, of `crate_name`'s
+    /// base FTL file in each locale directory.
+    ///
+    /// This is `ftl_file_name` with `{crate}` expanded, or the crate name when
+    /// `ftl_file_name` is unset.
+    pub fn ftl_file_stem(&self, crate_name: &str) -> String {
+        self.ftl_file_name.as_deref().map_or_else(
+            || crate_name.to_string(),
+            |pattern| pattern.replace(FTL_FILE_NAME_CRATE_TOKEN, crate_name),
+        )
+    }
+
     /// Returns the configured fallback language as a `LanguageIdentifier`.
     pub fn fallback_language_identifier(&self) -> Result<LanguageIdentifier, I18nConfigError> {
         Ok(self.fallback_language.clone())
     }
 
+    /// Returns the ordered fallback chain, always ending with the configured
+    /// fallback language.
+    ///
+    /// When `fallback_chain` is absent or empty this is just the single
+    /// `fallback_language`. Duplicate entries keep their first position.
+    pub fn fallback_chain_identifiers(&self) -> Result<Vec<LanguageIdentifier>, I18nConfigError> {
+        let mut chain: Vec<LanguageIdentifier> = Vec::new();
+        for language in self
+            .fallback_chain
+            .iter()
+            .flatten()
+            .chain(std::iter::once(&self.fallback_language))
+        {
+            let language = parse_fallback_language_identifier(&language.to_string())?;
+            if !chain.contains(&language) {
+                chain.push(language);
+            }
+        }
+        Ok(chain)
+    }
+
+    /// Returns whether `language` passes the `supported_locales` allowlist.
+    ///
+    /// Every language passes when no allowlist is configured, and the fallback
+    /// language always passes.
+    pub fn is_supported_locale(&self, language: &LanguageIdentifier) -> bool {
+        *language == self.fallback_language
+            || self
+                .supported_locales
+                .as_ref()
+                .is_none_or(|supported| supported.contains(language))
+    }
+
     /// Returns the languages available under the assets directory.
+    ///
+    /// Locales outside `supported_locales` are left out.
     pub fn available_languages(&self) -> Result<Vec<LanguageIdentifier>, I18nConfigError> {
         self.available_languages_from_base(None)
     }
 
     /// Returns the raw locale directory names under the assets directory.
+    ///
+    /// Locales outside `supported_locales` are left out.
     pub fn available_locale_names(&self) -> Result<Vec<String>, I18nConfigError> {
         self.available_locale_names_from_base(None)
     }
 
     /// Returns the languages available under the assets directory from a base directory.
-    /// If `base_dir` is `None`, uses `CARGO_MANIFEST_DIR` environment variable.
+    /// If `base_dir` is `None`, uses `config_dir`, or the `CARGO_MANIFEST_DIR`
+    /// environment variable when that is unset.
     pub fn available_languages_from_base(
         &self,
         base_dir: Option<&Path>,
     ) -> Result<Vec<LanguageIdentifier>, I18nConfigError> {
         let assets_path = self.validated_assets_dir_from_base(base_dir)?;
-        let entries = fs::read_dir(&assets_path).map_err(I18nConfigError::ReadError)?;
-        let entry_mode = self.language_entry_mode()?;
 
-        let mut languages: Vec<(String, LanguageIdentifier)> =
-            collect_language_entries(entries, entry_mode)?
-                .into_iter()
-                .map(|entry| {
-                    let canonical = entry.language.to_string();
-                    (canonical, entry.language)
-                })
-                .collect();
+        let mut languages: Vec<(String, LanguageIdentifier)> = self
+            .collect_language_entries(&assets_path)?
+            .into_iter()
+            .filter(|entry| self.is_supported_locale(&entry.language))
+            .map(|entry| {
+                let canonical = entry.language.to_string();
+                (canonical, entry.language)
+            })
+            .collect();
 
         languages.sort_by(|a, b| a.0.cmp(&b.0));
         languages.dedup_by(|a, b| a.0 == b.0);
@@ -454,25 +880,71 @@ impl I18nConfig {
     }
 
     /// Returns the raw locale directory names under the assets directory from a base directory.
-    /// If `base_dir` is `None`, uses `CARGO_MANIFEST_DIR` environment variable.
+    /// If `base_dir` is `None`, uses `config_dir`, or the `CARGO_MANIFEST_DIR`
+    /// environment variable when that is unset.
     pub fn available_locale_names_from_base(
         &self,
         base_dir: Option<&Path>,
     ) -> Result<Vec<String>, I18nConfigError> {
         let assets_path = self.validated_assets_dir_from_base(base_dir)?;
-        let entries = fs::read_dir(&assets_path).map_err(I18nConfigError::ReadError)?;
-        let entry_mode = self.language_entry_mode()?;
 
-        let mut locales = collect_language_entries(entries, entry_mode)?
+        let mut locales = self
+            .collect_language_entries(&assets_path)?
             .into_iter()
+            .filter(|entry| self.is_supported_locale(&entry.language))
             .map(|entry| entry.raw_name)
             .collect::<Vec<_>>();
 
         locales.sort();
+        locales.dedup();
         Ok(locales)
     }
 
+    /// Returns the domains (`*.ftl` file stems) available for `lang`.
+    pub fn available_domains(
+        &self,
+        lang: &LanguageIdentifier,
+    ) -> Result<Vec<String>, I18nConfigError> {
+        self.available_domains_from_base(lang, None)
+    }
+
+    /// Returns the domains (`*.ftl` file stems) available for `lang` from a base directory.
+    /// If `base_dir` is `None`, uses `config_dir`, or the `CARGO_MANIFEST_DIR`
+    /// environment variable when that is unset.
+    pub fn available_domains_from_base(
+        &self,
+        lang: &LanguageIdentifier,
+        base_dir: Option<&Path>,
+    ) -> Result<Vec<String>, I18nConfigError> {
+        let assets_path = self.validated_assets_dir_from_base(base_dir)?;
+        if self.layout == AssetsLayout::DomainFirst {
+            return self.domain_first_domains(&assets_path, &lang.to_string());
+        }
+        let locale_path = assets_path.join(lang.to_string());
+
+        if !locale_path.is_dir() {
+            return Err(I18nConfigError::ReadError(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!(
+                    "Locale directory '{}' does not exist",
+                    locale_path.to_slash_lossy()
+                ),
+            )));
+        }
+
+        let entries = fs::read_dir(&locale_path).map_err(I18nConfigError::ReadError)?;
+        let mut domains = collect_domain_entries(entries)?;
+
+        domains.sort();
+        domains.dedup();
+        Ok(domains)
+    }
+
     fn language_entry_mode(&self) -> Result<LanguageEntryMode, I18nConfigError> {
+        if is_external_assets_dir(&self.assets_dir) {
+            return Ok(LanguageEntryMode::Strict);
+        }
+
         let assets_dir = normalize_relative_assets_dir(&self.assets_dir)?;
         if assets_dir == Path::new(".") {
             Ok(LanguageEntryMode::CrateRootAssets)
@@ -481,39 +953,245 @@ impl I18nConfig {
         }
     }
 
+    /// Parses the locales found under `assets_path` for the configured layout.
+    fn collect_language_entries(
+        &self,
+        assets_path: &Path,
+    ) -> Result<Vec<language::ParsedLanguageEntry>, I18nConfigError> {
+        let entry_mode = self.language_entry_mode()?;
+        let entries = fs::read_dir(assets_path).map_err(I18nConfigError::ReadError)?;
+        if self.layout == AssetsLayout::LanguageFirst {
+            return collect_language_entries(entries, entry_mode);
+        }
+
+        let mut parsed_entries = Vec::new();
+        for domain_dir in domain_dirs(entries, entry_mode)? {
+            for entry in fs::read_dir(&domain_dir).map_err(I18nConfigError::ReadError)? {
+                let entry = entry.map_err(I18nConfigError::ReadError)?;
+                let path = entry.path();
+                let is_dir = entry
+                    .file_type()
+                    .map_err(I18nConfigError::ReadError)?
+                    .is_dir();
+                let raw_name = if is_dir {
+                    entry.file_name()
+                } else if path.extension().is_some_and(|extension| extension == "ftl") {
+                    path.file_stem().unwrap_or_default().to_os_string()
+                } else {
+                    continue;
+                };
+
+                match language::parse_language_name(raw_name) {
+                    Ok(entry) => parsed_entries.push(entry),
+                    Err(error) if entry_mode.should_ignore_error(&error) => {},
+                    Err(error) => return Err(error),
+                }
+            }
+        }
+
+        Ok(parsed_entries)
+    }
+
+    /// Returns the domain directories holding `{locale}.ftl` under a
+    /// domain-first assets directory.
+    fn domain_first_domains(
+        &self,
+        assets_path: &Path,
+        locale: &str,
+    ) -> Result<Vec<String>, I18nConfigError> {
+        let entries = fs::read_dir(assets_path).map_err(I18nConfigError::ReadError)?;
+        let mut found_locale = false;
+        let mut domains = Vec::new();
+
+        for domain_dir in domain_dirs(entries, self.language_entry_mode()?)? {
+            found_locale |= domain_dir.join(locale).is_dir();
+            if !domain_dir.join(format!("{locale}.ftl")).is_file() {
+                continue;
+            }
+            found_locale = true;
+            if let Some(domain) = domain_dir.file_name().and_then(|name| name.to_str()) {
+                domains.push(domain.to_string());
+            }
+        }
+
+        if !found_locale {
+            return Err(I18nConfigError::ReadError(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!(
+                    "No domain under '{}' has files for locale '{locale}'",
+                    assets_path.to_slash_lossy()
+                ),
+            )));
+        }
+
+        domains.sort();
+        Ok(domains)
+    }
+
     /// Validates the assets directory.
     pub fn validate_assets_dir(&self) -> Result<(), I18nConfigError> {
         let assets_path = self.assets_dir_from_manifest()?;
         Self::validate_resolved_assets_dir(&assets_path)
     }
 
+    /// Validates that `{assets_dir}/{fallback_language}/` exists and contains
+    /// at least one `.ftl` file, directly or in a namespace subdirectory.
+    /// With the `domain_first` layout, some `{assets_dir}/{domain}/` must hold
+    /// `{fallback_language}.ftl` or `.ftl` files below `{fallback_language}/`.
+    ///
+    /// Without fallback resources every lookup misses at runtime, so build
+    /// tooling calls this to report the misconfiguration early.
+    pub fn validate_fallback_present(&self) -> Result<(), I18nConfigError> {
+        self.validate_fallback_present_from_base(None)
+    }
+
+    /// Validates the fallback language directory like
+    /// [`Self::validate_fallback_present`], resolving `assets_dir` from a base
+    /// directory.
+    /// If `base_dir` is `None`, uses `config_dir`, or the `CARGO_MANIFEST_DIR`
+    /// environment variable when that is unset.
+    pub fn validate_fallback_present_from_base(
+        &self,
+        base_dir: Option<&Path>,
+    ) -> Result<(), I18nConfigError> {
+        let assets_path = self.validated_assets_dir_from_base(base_dir)?;
+        let fallback_language = self.fallback_language_id();
+        if self.layout == AssetsLayout::DomainFirst {
+            let entries = fs::read_dir(&assets_path).map_err(I18nConfigError::ReadError)?;
+            for domain_dir in domain_dirs(entries, self.language_entry_mode()?)? {
+                let locale_dir = domain_dir.join(&fallback_language);
+                if domain_dir
+                    .join(format!("{fallback_language}.ftl"))
+                    .is_file()
+                    || (locale_dir.is_dir()
+                        && contains_ftl_file(&locale_dir).map_err(I18nConfigError::ReadError)?)
+                {
+                    return Ok(());
+                }
+            }
+            return Err(I18nConfigError::MissingFallbackFtl {
+                path: assets_path
+                    .join("*")
+                    .join(&fallback_language)
+                    .to_slash_lossy()
+                    .into_owned(),
+                reason: "contains no .ftl files",
+            });
+        }
+
+        let fallback_path = assets_path.join(fallback_language);
+        let missing = |reason| I18nConfigError::MissingFallbackFtl {
+            path: fallback_path.to_slash_lossy().into_owned(),
+            reason,
+        };
+
+        if !fallback_path.is_dir() {
+            return Err(missing("does not exist"));
+        }
+        if !contains_ftl_file(&fallback_path).map_err(I18nConfigError::ReadError)? {
+            return Err(missing("contains no .ftl files"));
+        }
+
+        Ok(())
+    }
+
     /// Returns the fallback language identifier.
     pub fn fallback_language_id(&self) -> String {
         self.fallback_language.to_string()
     }
 
-    /// Read configuration and resolve paths for a given manifest directory.
+    /// Read the configuration that applies to the crate in `manifest_dir`.
     ///
-    /// This is a common pattern used across CLI tools and helpers.
+    /// This is a common pattern used across CLI tools and helpers. It follows
+    /// [`Self::from_manifest_dir_or_workspace`], so [`CONFIG_PATH_ENV`] and a
+    /// workspace-root `i18n.toml` are honored.
     pub fn from_manifest_dir(manifest_dir: &Path) -> Result<Self, I18nConfigError> {
-        let config_path = manifest_dir.join("i18n.toml");
-        Self::read_from_path(config_path)
+        Self::from_manifest_dir_or_workspace(manifest_dir)
     }
 
     /// Get assets directory resolved from a manifest directory.
     pub fn assets_dir_from_manifest_dir(manifest_dir: &Path) -> Result<PathBuf, I18nConfigError> {
         let config = Self::from_manifest_dir(manifest_dir)?;
-        config.assets_dir_from_base(Some(manifest_dir))
+        let base = config.config_dir.as_deref().unwrap_or(manifest_dir);
+        config.assets_dir_from_base(Some(base))
     }
 
     /// Get output directory (fallback language directory) from manifest directory.
     pub fn output_dir_from_manifest_dir(manifest_dir: &Path) -> Result<PathBuf, I18nConfigError> {
         let config = Self::from_manifest_dir(manifest_dir)?;
-        let assets_dir = config.assets_dir_from_base(Some(manifest_dir))?;
+        let base = config.config_dir.as_deref().unwrap_or(manifest_dir);
+        let assets_dir = config.assets_dir_from_base(Some(base))?;
         Ok(assets_dir.join(config.fallback_language_id()))
     }
 }
 
+/// Returns the nearest directory at or above `manifest_dir` whose
+/// `Cargo.toml` declares a `[workspace]` table.
+fn find_workspace_root(manifest_dir: &Path) -> Option<PathBuf> {
+    manifest_dir
+        .ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .ok()
+                .and_then(|content| toml::from_str::<toml::Table>(&content).ok())
+                .is_some_and(|manifest| manifest.contains_key("workspace"))
+        })
+        .map(Path::to_path_buf)
+}
+
+/// Whether `assets_dir` is written as an absolute path or starts with `~` or
+/// an environment variable, rather than relative to the crate root.
+fn is_external_assets_dir(path: &Path) -> bool {
+    path.is_absolute()
+        || path
+            .to_str()
+            .is_some_and(|path| path == "~" || path.starts_with("~/") || path.starts_with('$'))
+}
+
+/// Expands an external `assets_dir` into the absolute path it names.
+///
+/// A leading `~` uses `HOME` and a leading `$VAR` or `${VAR}` uses that
+/// variable. Returns `None` for crate-relative paths.
+fn expand_external_assets_dir(path: &Path) -> Result<Option<PathBuf>, I18nConfigError> {
+    if !is_external_assets_dir(path) {
+        return Ok(None);
+    }
+    if path.is_absolute() {
+        return Ok(Some(path.to_path_buf()));
+    }
+
+    let raw = path.to_slash_lossy().to_string();
+    let invalid = |reason| I18nConfigError::InvalidAssetsDir {
+        path: raw.clone(),
+        reason,
+    };
+    let (var, rest) = if let Some(rest) = raw.strip_prefix('~') {
+        ("HOME", rest)
+    } else if let Some(braced) = raw.strip_prefix("${") {
+        braced
+            .split_once('}')
+            .ok_or_else(|| invalid("has an unterminated '${' variable reference"))?
+    } else {
+        let unprefixed = &raw[1..];
+        let end = unprefixed
+            .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_'))
+            .unwrap_or(unprefixed.len());
+        unprefixed.split_at(end)
+    };
+    if var.is_empty() {
+        return Err(invalid("has an empty environment variable name"));
+    }
+
+    let value = env::var_os(var)
+        .ok_or_else(|| invalid("references an environment variable that is not set"))?;
+    let expanded = PathBuf::from(value).join(rest.trim_start_matches('/'));
+    if !expanded.is_absolute() {
+        return Err(invalid("must expand to an absolute path"));
+    }
+
+    Ok(Some(expanded))
+}
+
 fn normalize_relative_assets_dir(path: &Path) -> Result<PathBuf, I18nConfigError> {
     if path.as_os_str().is_empty() {
         return Err(I18nConfigError::InvalidAssetsDir {
@@ -638,6 +1316,29 @@ fn parse_fallback_language_identifier(value: &str) -> Result<LanguageIdentifier,
     })
 }
 
+fn parse_supported_locale(value: &str) -> Result<LanguageIdentifier, I18nConfigError> {
+    es_fluent_shared::parse_canonical_language_identifier(value).map_err(|err| match err {
+        CanonicalLanguageIdentifierError::Invalid { source, .. } => {
+            I18nConfigError::InvalidSupportedLocale {
+                name: value.to_string(),
+                source,
+            }
+        },
+        CanonicalLanguageIdentifierError::IcuInvalid { details, .. } => {
+            I18nConfigError::IcuSupportedLocale {
+                name: value.to_string(),
+                details,
+            }
+        },
+        CanonicalLanguageIdentifierError::NonCanonical { canonical, .. } => {
+            I18nConfigError::NonCanonicalSupportedLocale {
+                name: value.to_string(),
+                canonical,
+            }
+        },
+    })
+}
+
 fn collect_language_entries(
     entries: impl IntoIterator<Item = Result<DirEntry, std::io::Error>>,
     mode: LanguageEntryMode,
@@ -669,6 +1370,87 @@ fn collect_language_entries(
     Ok(parsed_entries)
 }
 
+/// Lists the domain directories of a domain-first assets directory.
+fn domain_dirs(
+    entries: impl IntoIterator<Item = Result<DirEntry, std::io::Error>>,
+    mode: LanguageEntryMode,
+) -> Result<Vec<PathBuf>, I18nConfigError> {
+    let mut dirs = Vec::new();
+
+    for entry in entries {
+        let entry = entry.map_err(I18nConfigError::ReadError)?;
+        if !entry
+            .file_type()
+            .map_err(I18nConfigError::ReadError)?
+            .is_dir()
+            || entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| mode.should_ignore_dir_name(name))
+        {
+            continue;
+        }
+        dirs.push(entry.path());
+    }
+
+    Ok(dirs)
+}
+
+fn collect_domain_entries(
+    entries: impl IntoIterator<Item = Result<DirEntry, std::io::Error>>,
+) -> Result<Vec<String>, I18nConfigError> {
+    let mut domains = Vec::new();
+
+    for entry in entries {
+        let entry = entry.map_err(I18nConfigError::ReadError)?;
+        if !entry
+            .file_type()
+            .map_err(I18nConfigError::ReadError)?
+            .is_file()
+        {
+            continue;
+        }
+
+        let path = entry.path();
+        if path.extension().is_none_or(|extension| extension != "ftl") {
+            continue;
+        }
+
+        let Some(stem) = path.file_stem() else {
+            continue;
+        };
+        let domain = stem.to_os_string().into_string().map_err(|raw| {
+            I18nConfigError::ReadError(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Locale directory contains a non UTF-8 entry: {:?}", raw),
+            ))
+        })?;
+        domains.push(domain);
+    }
+
+    Ok(domains)
+}
+
+/// Whether `dir` or any directory below it holds a `.ftl` file.
+fn contains_ftl_file(dir: &Path) -> Result<bool, std::io::Error> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let path = entry.path();
+        if file_type.is_dir() {
+            if contains_ftl_file(&path)? {
+                return Ok(true);
+            }
+        } else if file_type.is_file()
+            && path.extension().is_some_and(|extension| extension == "ftl")
+        {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
 #[cfg(test)]
 pub(crate) mod test_utils;
 
@@ -48,13 +48,10 @@ fn config_document(
 
 fn i18n_config(fallback_language: &str, assets_dir: &str) -> I18nConfig {
     I18nConfig::builder()
-        .fallback_language(
-            fallback_language
-                .parse::<LanguageIdentifier>()
-                .expect("test fallback language"),
-        )
+        .fallback_language(fallback_language)
         .assets_dir(assets_dir)
         .build()
+        .expect("valid test config")
 }
 
 #[test]
@@ -138,23 +135,126 @@ fn test_read_from_path_rejects_assets_dir_outside_crate() {
     let temp_dir = TempDir::new().unwrap();
     let config_path = temp_dir.path().join("i18n.toml");
 
-    for assets_dir in ["../outside/i18n", "/tmp/es-fluent-i18n"] {
-        write_toml(&config_path, &config_document("en", assets_dir, None, None));
+    write_toml(
+        &config_path,
+        &config_document("en", "../outside/i18n", None, None),
+    );
 
-        let err =
-            I18nConfig::read_from_path(&config_path).expect_err("assets_dir should be rejected");
+    let err = I18nConfig::read_from_path(&config_path).expect_err("assets_dir should be rejected");
 
-        assert!(
-            matches!(err, I18nConfigError::InvalidAssetsDir { ref path, .. } if path == assets_dir),
-            "unexpected error for {assets_dir}: {err:?}"
-        );
-    }
+    assert!(matches!(
+        err,
+        I18nConfigError::InvalidAssetsDir { ref path, .. } if path == "../outside/i18n"
+    ));
+}
+
+#[test]
+#[serial_test::serial(manifest)]
+fn test_assets_dir_from_manifest_joins_relative_assets_dir() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = i18n_config("en", "assets/locales");
+
+    let assets_dir = crate::test_utils::with_manifest_env(Some(temp_dir.path()), || {
+        config.assets_dir_from_manifest()
+    })
+    .expect("relative assets_dir");
+
+    assert_eq!(assets_dir, temp_dir.path().join("assets/locales"));
+}
+
+#[test]
+#[serial_test::serial(manifest)]
+fn test_assets_dir_from_manifest_returns_absolute_assets_dir_unchanged() {
+    let crate_dir = TempDir::new().unwrap();
+    let shared = TempDir::new().unwrap();
+    fs::create_dir(shared.path().join("en")).unwrap();
+    let config_path = crate_dir.path().join("i18n.toml");
+    let shared_path = shared.path().to_str().expect("UTF-8 temp path");
+    write_toml(
+        &config_path,
+        &config_document("en", shared_path, None, None),
+    );
+
+    let config = I18nConfig::read_from_path(&config_path).expect("absolute assets_dir");
+    let (assets_dir, languages) =
+        crate::test_utils::with_manifest_env(Some(crate_dir.path()), || {
+            (
+                config.assets_dir_from_manifest(),
+                config.available_locale_names(),
+            )
+        });
+
+    assert_eq!(assets_dir.expect("assets_dir"), shared.path());
+    assert_eq!(languages.expect("languages"), vec!["en"]);
+}
+
+#[test]
+fn test_assets_dir_from_base_expands_env_and_home_prefixes() {
+    let base = TempDir::new().unwrap();
+    let shared = TempDir::new().unwrap();
+
+    temp_env::with_vars(
+        [
+            ("ES_FLUENT_TEST_WORKSPACE", Some(shared.path())),
+            ("HOME", Some(shared.path())),
+        ],
+        || {
+            for assets_dir in [
+                "$ES_FLUENT_TEST_WORKSPACE/translations",
+                "${ES_FLUENT_TEST_WORKSPACE}/translations",
+                "~/translations",
+            ] {
+                let config = i18n_config("en", assets_dir);
+                assert_eq!(
+                    config
+                        .assets_dir_from_base(Some(base.path()))
+                        .expect("expanded assets_dir"),
+                    shared.path().join("translations"),
+                    "unexpected expansion for {assets_dir}"
+                );
+            }
+        },
+    );
+}
+
+#[test]
+fn test_assets_dir_from_base_rejects_unset_or_relative_expansions() {
+    let base = TempDir::new().unwrap();
+
+    temp_env::with_vars(
+        [
+            ("ES_FLUENT_TEST_UNSET", None),
+            ("ES_FLUENT_TEST_RELATIVE", Some("relative/dir")),
+        ],
+        || {
+            for (assets_dir, reason) in [
+                ("$ES_FLUENT_TEST_UNSET/translations", "not set"),
+                ("$ES_FLUENT_TEST_RELATIVE/translations", "absolute path"),
+                ("${ES_FLUENT_TEST_UNSET/translations", "unterminated"),
+            ] {
+                let err = i18n_config("en", assets_dir)
+                    .assets_dir_from_base(Some(base.path()))
+                    .expect_err("expansion should be rejected");
+                assert!(
+                    matches!(
+                        err,
+                        I18nConfigError::InvalidAssetsDir { ref path, reason: actual }
+                            if path == assets_dir && actual.contains(reason)
+                    ),
+                    "unexpected error for {assets_dir}: {err:?}"
+                );
+            }
+        },
+    );
 }
 
 #[test]
 fn test_assets_dir_from_base_rejects_programmatic_escape() {
     let temp_dir = TempDir::new().unwrap();
-    let escaping = i18n_config("en", "../outside/i18n");
+    let escaping = I18nConfig {
+        assets_dir: PathBuf::from("../outside/i18n"),
+        ..i18n_config("en", "i18n")
+    };
 
     let err = escaping
         .assets_dir_from_base(Some(temp_dir.path()))
@@ -277,6 +377,12 @@ fn test_raw_config_rejects_invalid_fallback_language() {
         fluent_feature: None,
         namespaces: None,
         check_fallback_copies: true,
+        keys_json: false,
+        annotate_source: false,
+        fallback_chain: None,
+        ftl_file_name: None,
+        supported_locales: None,
+        layout: AssetsLayout::default(),
     }
     .validate();
 
@@ -295,6 +401,12 @@ fn test_raw_config_rejects_invalid_namespace() {
         fluent_feature: None,
         namespaces: Some(vec!["../ui".to_string()]),
         check_fallback_copies: true,
+        keys_json: false,
+        annotate_source: false,
+        fallback_chain: None,
+        ftl_file_name: None,
+        supported_locales: None,
+        layout: AssetsLayout::default(),
     }
     .validate();
 
@@ -306,6 +418,84 @@ fn test_raw_config_rejects_invalid_namespace() {
     ));
 }
 
+#[test]
+fn test_builder_validates_and_normalizes_values() {
+    let config = I18nConfig::builder()
+        .fallback_language("en-US")
+        .assets_dir("./assets/../i18n")
+        .namespaces(vec!["ui".to_string()])
+        .supported_locales(vec!["fr".to_string()])
+        .config_dir("/workspace")
+        .build()
+        .expect("valid builder config");
+
+    assert_eq!(config.fallback_language_id(), "en-US");
+    assert_eq!(config.assets_dir, PathBuf::from("i18n"));
+    assert_eq!(
+        config.namespaces,
+        Some(vec![ResolvedNamespace::new("ui").expect("valid namespace")])
+    );
+    assert_eq!(
+        config.supported_locales,
+        Some(vec!["fr".parse::<LanguageIdentifier>().unwrap()])
+    );
+    assert!(config.check_fallback_copies);
+    assert_eq!(config.config_dir, Some(PathBuf::from("/workspace")));
+}
+
+#[test]
+fn test_builder_rejects_invalid_values() {
+    let invalid_fallback = I18nConfig::builder()
+        .fallback_language("invalid-lang!")
+        .assets_dir("i18n")
+        .build();
+    assert!(matches!(
+        invalid_fallback,
+        Err(I18nConfigError::InvalidFallbackLanguageIdentifier { name, .. })
+            if name == "invalid-lang!"
+    ));
+
+    let noncanonical_fallback = I18nConfig::builder()
+        .fallback_language("en-us")
+        .assets_dir("i18n")
+        .build();
+    assert!(matches!(
+        noncanonical_fallback,
+        Err(I18nConfigError::NonCanonicalFallbackLanguageIdentifier { name, canonical })
+            if name == "en-us" && canonical == "en-US"
+    ));
+
+    let escaping_assets_dir = I18nConfig::builder()
+        .fallback_language("en")
+        .assets_dir("../outside/i18n")
+        .build();
+    assert!(matches!(
+        escaping_assets_dir,
+        Err(I18nConfigError::InvalidAssetsDir { reason, .. })
+            if reason.contains("inside the crate root")
+    ));
+
+    let invalid_supported_locale = I18nConfig::builder()
+        .fallback_language("en")
+        .assets_dir("i18n")
+        .supported_locales(vec!["fr_FR!".to_string()])
+        .build();
+    assert!(matches!(
+        invalid_supported_locale,
+        Err(I18nConfigError::InvalidSupportedLocale { name, .. }) if name == "fr_FR!"
+    ));
+
+    let invalid_ftl_file_name = I18nConfig::builder()
+        .fallback_language("en")
+        .assets_dir("i18n")
+        .ftl_file_name("messages.ftl")
+        .build();
+    assert!(matches!(
+        invalid_ftl_file_name,
+        Err(I18nConfigError::InvalidFtlFileName { pattern, .. }) if pattern == "messages.ftl"
+    ));
+}
+
 #[test]
 fn test_available_languages_collects_directories() {
     let temp_dir = TempDir::new().unwrap();
@@ -348,6 +538,121 @@ fn test_available_languages_allows_language_only() {
     assert_eq!(codes, vec!["en"]);
 }
 
+#[test]
+fn test_supported_locales_filter_available_locales() {
+    let temp_dir = TempDir::new().unwrap();
+    let manifest_dir = temp_dir.path();
+    let assets = manifest_dir.join("i18n");
+    for locale in ["en", "fr", "de"] {
+        fs::create_dir_all(assets.join(locale)).unwrap();
+    }
+    let config_path = manifest_dir.join("i18n.toml");
+    let mut document = table([
+        ("fallback_language", string_value("en")),
+        ("assets_dir", string_value("i18n")),
+    ]);
+    document.insert(
+        "supported_locales".to_string(),
+        toml::Value::Array(vec![string_value("fr")]),
+    );
+    write_toml(&config_path, &toml::Value::Table(document));
+
+    let layout = ResolvedI18nLayout::from_config_path(&config_path).unwrap();
+    assert_eq!(layout.available_locale_names().unwrap(), vec!["en", "fr"]);
+    let languages = layout
+        .available_languages()
+        .unwrap()
+        .into_iter()
+        .map(|language| language.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(languages, vec!["en", "fr"]);
+    assert!(!layout.config.is_supported_locale(&"de".parse().unwrap()));
+
+    let unfiltered = i18n_config("en", "i18n");
+    assert!(unfiltered.is_supported_locale(&"de".parse().unwrap()));
+    assert_eq!(
+        unfiltered
+            .available_locale_names_from_base(Some(manifest_dir))
+            .unwrap(),
+        vec!["de", "en", "fr"]
+    );
+}
+
+#[test]
+fn test_domain_first_layout_discovers_locales_from_domain_dirs() {
+    let temp_dir = TempDir::new().unwrap();
+    let manifest_dir = temp_dir.path();
+    let assets = manifest_dir.join("i18n");
+    fs::create_dir_all(assets.join("app/de/ui")).unwrap();
+    fs::create_dir_all(assets.join("shared")).unwrap();
+    fs::write(assets.join("app/en.ftl"), "hello = Hello").unwrap();
+    fs::write(assets.join("app/de/ui/button.ftl"), "button = Knopf").unwrap();
+    fs::write(assets.join("shared/en.ftl"), "ok = OK").unwrap();
+    fs::write(assets.join("shared/fr.ftl"), "ok = OK").unwrap();
+    let config_path = manifest_dir.join("i18n.toml");
+    let mut document = table([
+        ("fallback_language", string_value("en")),
+        ("assets_dir", string_value("i18n")),
+    ]);
+    document.insert("layout".to_string(), string_value("domain_first"));
+    write_toml(&config_path, &toml::Value::Table(document));
+
+    let layout = ResolvedI18nLayout::from_config_path(&config_path).unwrap();
+    assert_eq!(layout.config.layout, AssetsLayout::DomainFirst);
+    assert_eq!(
+        layout.available_locale_names().unwrap(),
+        vec!["de", "en", "fr"]
+    );
+    assert_eq!(
+        layout
+            .config
+            .available_domains_from_base(&"en".parse().unwrap(), Some(manifest_dir))
+            .unwrap(),
+        vec!["app", "shared"]
+    );
+    assert_eq!(
+        layout
+            .config
+            .available_domains_from_base(&"de".parse().unwrap(), Some(manifest_dir))
+            .unwrap(),
+        Vec::<String>::new()
+    );
+    assert!(
+        layout
+            .config
+            .available_domains_from_base(&"ja".parse().unwrap(), Some(manifest_dir))
+            .is_err()
+    );
+    layout
+        .config
+        .validate_fallback_present_from_base(Some(manifest_dir))
+        .unwrap();
+}
+
+#[test]
+fn test_raw_config_rejects_noncanonical_supported_locale() {
+    let result = RawI18nConfig {
+        fallback_language: "en".to_string(),
+        assets_dir: PathBuf::from("i18n"),
+        fluent_feature: None,
+        namespaces: None,
+        check_fallback_copies: true,
+        keys_json: false,
+        annotate_source: false,
+        fallback_chain: None,
+        ftl_file_name: None,
+        supported_locales: Some(vec!["fr".to_string(), "en-us".to_string()]),
+        layout: AssetsLayout::default(),
+    }
+    .validate();
+
+    assert!(matches!(
+        result,
+        Err(I18nConfigError::NonCanonicalSupportedLocale { name, canonical })
+            if name == "en-us" && canonical == "en-US"
+    ));
+}
+
 #[test]
 fn test_available_locale_names_reject_noncanonical_directory_names() {
     let temp_dir = TempDir::new().unwrap();
@@ -431,6 +736,7 @@ fn test_root_assets_still_reject_noncanonical_locale_directories() {
 }
 
 #[test]
+#[serial_test::serial(manifest)]
 fn test_resolved_layout_helpers_delegate_to_underlying_config() {
     let temp_dir = TempDir::new().unwrap();
     fs::create_dir_all(temp_dir.path().join("i18n/en-US")).unwrap();
@@ -550,6 +856,103 @@ fn test_check_fallback_copies_defaults_to_true_and_parses_false() {
     assert!(!config.check_fallback_copies);
 }
 
+#[test]
+fn test_keys_json_defaults_to_false_and_parses_true() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("i18n.toml");
+
+    write_toml(&config_path, &config_document("en", "i18n", None, None));
+    let config = I18nConfig::read_from_path(&config_path).unwrap();
+    assert!(!config.keys_json);
+
+    let mut enabled = table([
+        ("fallback_language", string_value("en")),
+        ("assets_dir", string_value("i18n")),
+    ]);
+    enabled.insert("keys_json".to_string(), toml::Value::Boolean(true));
+    write_toml(&config_path, &toml::Value::Table(enabled));
+
+    let config = I18nConfig::read_from_path(&config_path).unwrap();
+    assert!(config.keys_json);
+}
+
+#[test]
+fn test_annotate_source_defaults_to_false_and_parses_true() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("i18n.toml");
+
+    write_toml(&config_path, &config_document("en", "i18n", None, None));
+    let config = I18nConfig::read_from_path(&config_path).unwrap();
+    assert!(!config.annotate_source);
+
+    let mut enabled = table([
+        ("fallback_language", string_value("en")),
+        ("assets_dir", string_value("i18n")),
+    ]);
+    enabled.insert("annotate_source".to_string(), toml::Value::Boolean(true));
+    write_toml(&config_path, &toml::Value::Table(enabled));
+
+    let config = I18nConfig::read_from_path(&config_path).unwrap();
+    assert!(config.annotate_source);
+}
+
+#[test]
+fn test_fallback_chain_defaults_to_fallback_language() {
+    let config = i18n_config("en", "i18n");
+
+    let chain = config.fallback_chain_identifiers().unwrap();
+    assert_eq!(chain, vec!["en".parse::<LanguageIdentifier>().unwrap()]);
+}
+
+#[test]
+fn test_fallback_chain_parses_and_ends_with_fallback_language() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("i18n.toml");
+
+    let mut document = table([
+        ("fallback_language", string_value("en")),
+        ("assets_dir", string_value("i18n")),
+    ]);
+    document.insert(
+        "fallback_chain".to_string(),
+        toml::Value::Array(vec![string_value("es"), string_value("es")]),
+    );
+    write_toml(&config_path, &toml::Value::Table(document));
+
+    let config = I18nConfig::read_from_path(&config_path).unwrap();
+    let chain = config
+        .fallback_chain_identifiers()
+        .unwrap()
+        .into_iter()
+        .map(|language| language.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(chain, vec!["es", "en"]);
+}
+
+#[test]
+fn test_raw_config_rejects_noncanonical_fallback_chain_entry() {
+    let result = RawI18nConfig {
+        fallback_language: "en".to_string(),
+        assets_dir: PathBuf::from("i18n"),
+        fluent_feature: None,
+        namespaces: None,
+        check_fallback_copies: true,
+        keys_json: false,
+        annotate_source: false,
+        fallback_chain: Some(vec!["es-419".to_string(), "en-us".to_string()]),
+        ftl_file_name: None,
+        supported_locales: None,
+        layout: AssetsLayout::default(),
+    }
+    .validate();
+
+    assert!(matches!(
+        result,
+        Err(I18nConfigError::NonCanonicalFallbackLanguageIdentifier { name, canonical })
+            if name == "en-us" && canonical == "en-US"
+    ));
+}
+
 #[test]
 fn test_fluent_feature_requires_array_shape() {
     let temp_dir = TempDir::new().unwrap();
@@ -613,6 +1016,32 @@ fn test_validate_assets_dir_reports_missing_and_non_directory() {
     ));
 }
 
+#[test]
+fn test_validate_fallback_present_requires_ftl_in_fallback_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = i18n_config("en", "i18n");
+    let validate = || config.validate_fallback_present_from_base(Some(temp_dir.path()));
+
+    fs::create_dir_all(temp_dir.path().join("i18n/fr")).unwrap();
+    fs::write(temp_dir.path().join("i18n/fr/app.ftl"), "hello = Salut").unwrap();
+    let missing = validate().expect_err("missing fallback directory");
+    assert!(matches!(
+        &missing,
+        I18nConfigError::MissingFallbackFtl { reason, .. } if *reason == "does not exist"
+    ));
+    assert!(missing.to_string().ends_with("i18n/en' does not exist"));
+
+    fs::create_dir_all(temp_dir.path().join("i18n/en/app")).unwrap();
+    fs::write(temp_dir.path().join("i18n/en/notes.txt"), "not fluent").unwrap();
+    assert!(matches!(
+        validate(),
+        Err(I18nConfigError::MissingFallbackFtl { reason, .. }) if reason == "contains no .ftl files"
+    ));
+
+    fs::write(temp_dir.path().join("i18n/en/app/ui.ftl"), "hello = Hello").unwrap();
+    validate().expect("namespaced fallback FTL counts");
+}
+
 #[test]
 fn test_available_languages_reports_missing_assets_directory_consistently() {
     let temp_dir = TempDir::new().unwrap();
@@ -632,7 +1061,204 @@ fn test_available_languages_reports_missing_assets_directory_consistently() {
     }
 }
 
+fn workspace_with_member(root_config: Option<&toml::Value>) -> (TempDir, PathBuf) {
+    let temp_dir = TempDir::new().unwrap();
+    let member_dir = temp_dir.path().join("crates/app");
+    fs::create_dir_all(&member_dir).unwrap();
+    fs::write(
+        temp_dir.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/app\"]\n",
+    )
+    .unwrap();
+    fs::write(
+        member_dir.join("Cargo.toml"),
+        "[package]\nname = \"app\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    if let Some(root_config) = root_config {
+        write_toml(&temp_dir.path().join("i18n.toml"), root_config);
+    }
+    (temp_dir, member_dir)
+}
+
+#[test]
+#[serial_test::serial(manifest)]
+fn test_workspace_config_is_used_when_crate_has_none() {
+    let (temp_dir, member_dir) =
+        workspace_with_member(Some(&config_document("fr", "locales", None, None)));
+    fs::create_dir_all(temp_dir.path().join("locales/fr")).unwrap();
+
+    assert_eq!(
+        I18nConfig::config_path_for_manifest_dir(&member_dir),
+        Some(temp_dir.path().join("i18n.toml"))
+    );
+    let config = I18nConfig::from_manifest_dir_or_workspace(&member_dir).expect("config");
+    assert_eq!(config.fallback_language_id(), "fr");
+    assert_eq!(config.config_dir.as_deref(), Some(temp_dir.path()));
+    assert_eq!(
+        config.assets_dir_from_manifest().expect("assets dir"),
+        temp_dir.path().join("locales")
+    );
+    assert_eq!(
+        config
+            .available_languages()
+            .expect("available languages")
+            .into_iter()
+            .map(|lang| lang.to_string())
+            .collect::<Vec<_>>(),
+        vec!["fr"]
+    );
+}
+
+#[test]
+#[serial_test::serial(manifest)]
+fn test_crate_config_takes_precedence_over_workspace_config() {
+    let (temp_dir, member_dir) =
+        workspace_with_member(Some(&config_document("fr", "locales", None, None)));
+    write_toml(
+        &member_dir.join("i18n.toml"),
+        &config_document("en", "i18n", None, None),
+    );
+
+    assert_eq!(
+        I18nConfig::config_path_for_manifest_dir(&member_dir),
+        Some(member_dir.join("i18n.toml"))
+    );
+    let config = I18nConfig::from_manifest_dir_or_workspace(&member_dir).expect("config");
+    assert_eq!(config.fallback_language_id(), "en");
+    assert_eq!(config.assets_dir, PathBuf::from("i18n"));
+    assert!(config.config_dir.is_none());
+
+    let root_config =
+        I18nConfig::from_manifest_dir_or_workspace(temp_dir.path()).expect("root config");
+    assert_eq!(root_config.fallback_language_id(), "fr");
+    assert!(root_config.config_dir.is_none());
+}
+
+#[test]
+#[serial_test::serial(manifest)]
+fn test_workspace_config_lookup_reports_not_found_without_any_config() {
+    let (_temp_dir, member_dir) = workspace_with_member(None);
+
+    assert!(I18nConfig::config_path_for_manifest_dir(&member_dir).is_none());
+    assert!(matches!(
+        I18nConfig::from_manifest_dir_or_workspace(&member_dir),
+        Err(I18nConfigError::NotFound)
+    ));
+}
+
+#[test]
+#[serial_test::serial(manifest)]
+fn test_read_from_workspace_root_uses_manifest_env() {
+    let (_temp_dir, member_dir) =
+        workspace_with_member(Some(&config_document("fr", "locales", None, None)));
+
+    let config = crate::test_utils::with_manifest_env(Some(&member_dir), || {
+        I18nConfig::read_from_workspace_root()
+    })
+    .expect("workspace config");
+    assert_eq!(config.fallback_language_id(), "fr");
+
+    let missing = crate::test_utils::with_manifest_env(Some(&member_dir), || {
+        I18nConfig::read_from_manifest_dir()
+    });
+    assert!(matches!(missing, Err(I18nConfigError::NotFound)));
+}
+
+#[test]
+#[serial_test::serial(manifest)]
+fn test_config_path_env_overrides_manifest_and_workspace_configs() {
+    let (temp_dir, member_dir) =
+        workspace_with_member(Some(&config_document("fr", "locales", None, None)));
+    write_toml(
+        &member_dir.join("i18n.toml"),
+        &config_document("en", "i18n", None, None),
+    );
+    let external = TempDir::new().unwrap();
+    let config_path = external.path().join("bazel/i18n.toml");
+    fs::create_dir_all(external.path().join("bazel/translations/de")).unwrap();
+    write_toml(
+        &config_path,
+        &config_document("de", "translations", None, None),
+    );
+
+    temp_env::with_vars(
+        [
+            (CONFIG_PATH_ENV, Some(config_path.as_os_str())),
+            ("CARGO_MANIFEST_DIR", Some(member_dir.as_os_str())),
+        ],
+        || {
+            assert_eq!(
+                I18nConfig::config_path_for_manifest_dir(&member_dir),
+                Some(config_path.clone())
+            );
+
+            for config in [
+                I18nConfig::read_from_manifest_dir().expect("manifest config"),
+                I18nConfig::read_from_workspace_root().expect("workspace config"),
+                I18nConfig::from_manifest_dir(&member_dir).expect("crate config"),
+            ] {
+                assert_eq!(config.fallback_language_id(), "de");
+                assert_eq!(
+                    config.assets_dir_from_manifest().expect("assets dir"),
+                    external.path().join("bazel/translations")
+                );
+            }
+
+            let layout = ResolvedI18nLayout::from_manifest_dir(&member_dir).expect("layout");
+            assert_eq!(layout.config_path, config_path);
+            assert_eq!(
+                layout.output_dir,
+                external.path().join("bazel/translations/de")
+            );
+            assert_eq!(
+                I18nConfig::assets_dir_from_manifest_dir(&member_dir).expect("assets dir"),
+                external.path().join("bazel/translations")
+            );
+        },
+    );
+
+    temp_env::with_var(CONFIG_PATH_ENV, Some(""), || {
+        assert_eq!(
+            I18nConfig::config_path_for_manifest_dir(&member_dir),
+            Some(member_dir.join("i18n.toml"))
+        );
+    });
+
+    let missing = temp_env::with_var(
+        CONFIG_PATH_ENV,
+        Some(temp_dir.path().join("missing.toml")),
+        || I18nConfig::from_manifest_dir_or_workspace(&member_dir),
+    );
+    assert!(matches!(missing, Err(I18nConfigError::NotFound)));
+}
+
 #[test]
+#[serial_test::serial(manifest)]
+fn test_manifest_dir_loaders_fall_back_to_workspace_config() {
+    let (temp_dir, member_dir) =
+        workspace_with_member(Some(&config_document("fr", "locales", None, None)));
+    fs::create_dir_all(temp_dir.path().join("locales/fr")).unwrap();
+
+    let config = I18nConfig::from_manifest_dir(&member_dir).expect("workspace config");
+    assert_eq!(config.fallback_language_id(), "fr");
+    assert_eq!(
+        I18nConfig::assets_dir_from_manifest_dir(&member_dir).expect("assets"),
+        temp_dir.path().join("locales")
+    );
+    assert_eq!(
+        I18nConfig::output_dir_from_manifest_dir(&member_dir).expect("output"),
+        temp_dir.path().join("locales/fr")
+    );
+
+    let layout = ResolvedI18nLayout::from_manifest_dir(&member_dir).expect("workspace layout");
+    assert_eq!(layout.config_path, temp_dir.path().join("i18n.toml"));
+    assert_eq!(layout.assets_dir, temp_dir.path().join("locales"));
+    assert_eq!(layout.output_dir, temp_dir.path().join("locales/fr"));
+}
+
+#[test]
+#[serial_test::serial(manifest)]
 fn test_manifest_dir_helper_methods() {
     let temp_dir = TempDir::new().unwrap();
     write_toml(
@@ -691,6 +1317,72 @@ fn test_available_languages_accepts_variant_language_directory() {
     );
 }
 
+#[test]
+fn test_available_domains_collects_sorted_ftl_stems() {
+    let temp_dir = TempDir::new().unwrap();
+    let locale = temp_dir.path().join("i18n/en");
+    fs::create_dir_all(locale.join("app")).unwrap();
+    fs::write(locale.join("ui.ftl"), "").unwrap();
+    fs::write(locale.join("app.ftl"), "").unwrap();
+    fs::write(locale.join("errors.ftl"), "").unwrap();
+    fs::write(locale.join("README.txt"), "ignored file").unwrap();
+    fs::write(locale.join("app/ui.ftl"), "").unwrap();
+
+    let config = i18n_config("en", "i18n");
+    let lang: LanguageIdentifier = "en".parse().unwrap();
+
+    let domains = config
+        .available_domains_from_base(&lang, Some(temp_dir.path()))
+        .unwrap();
+
+    assert_eq!(domains, vec!["app", "errors", "ui"]);
+}
+
+#[test]
+fn test_available_domains_reports_missing_locale_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::create_dir_all(temp_dir.path().join("i18n/en")).unwrap();
+
+    let config = i18n_config("en", "i18n");
+    let lang: LanguageIdentifier = "fr".parse().unwrap();
+
+    let err = config
+        .available_domains_from_base(&lang, Some(temp_dir.path()))
+        .expect_err("missing locale directory should fail");
+
+    match err {
+        I18nConfigError::ReadError(inner) => {
+            assert_eq!(inner.kind(), std::io::ErrorKind::NotFound);
+            assert!(inner.to_string().contains("Locale directory"));
+            assert!(inner.to_string().contains("does not exist"));
+        },
+        other => panic!("expected read error, got {other:?}"),
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_available_domains_rejects_non_utf8_file_names() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt as _;
+
+    let temp_dir = TempDir::new().unwrap();
+    let locale = temp_dir.path().join("i18n/en");
+    fs::create_dir_all(&locale).unwrap();
+    fs::write(locale.join(OsStr::from_bytes(b"bad\xff.ftl")), "").unwrap();
+
+    let config = i18n_config("en", "i18n");
+    let lang: LanguageIdentifier = "en".parse().unwrap();
+
+    let err = config
+        .available_domains_from_base(&lang, Some(temp_dir.path()))
+        .expect_err("non UTF-8 domain file names should fail");
+    assert!(matches!(
+        err,
+        I18nConfigError::ReadError(inner) if inner.kind() == std::io::ErrorKind::InvalidData
+    ));
+}
+
 #[test]
 fn test_collect_language_entries_propagates_directory_iteration_errors() {
     let err = collect_language_entries(
@@ -722,3 +1414,85 @@ fn test_fallback_language_identifier_accepts_variants() {
             .expect("language")
     );
 }
+
+#[test]
+fn test_ftl_file_stem_defaults_to_crate_name_and_expands_crate_token() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("i18n.toml");
+    assert_eq!(
+        i18n_config("en", "i18n").ftl_file_stem("my-crate"),
+        "my-crate"
+    );
+
+    let mut document = table([
+        ("fallback_language", string_value("en")),
+        ("assets_dir", string_value("i18n")),
+    ]);
+    document.insert("ftl_file_name".to_string(), string_value("messages"));
+    write_toml(&config_path, &toml::Value::Table(document.clone()));
+    let config = I18nConfig::read_from_path(&config_path).unwrap();
+    assert_eq!(config.ftl_file_stem("my-crate"), "messages");
+
+    document.insert("ftl_file_name".to_string(), string_value("{crate}-strings"));
+    write_toml(&config_path, &toml::Value::Table(document));
+    let config = I18nConfig::read_from_path(&config_path).unwrap();
+    assert_eq!(config.ftl_file_stem("my-crate"), "my-crate-strings");
+}
+
+#[test]
+fn test_raw_config_rejects_ftl_file_name_with_domain_first_layout() {
+    let raw = |ftl_file_name: &str| RawI18nConfig {
+        fallback_language: "en".to_string(),
+        assets_dir: PathBuf::from("i18n"),
+        fluent_feature: None,
+        namespaces: None,
+        check_fallback_copies: true,
+        keys_json: false,
+        annotate_source: false,
+        fallback_chain: None,
+        ftl_file_name: Some(ftl_file_name.to_string()),
+        supported_locales: None,
+        layout: AssetsLayout::DomainFirst,
+    };
+
+    assert!(matches!(
+        raw("{crate}-messages").validate(),
+        Err(I18nConfigError::InvalidFtlFileName { pattern, .. }) if pattern == "{crate}-messages"
+    ));
+    let config = raw("{crate}")
+        .validate()
+        .expect("plain crate name is allowed");
+    assert_eq!(config.ftl_file_stem("my-crate"), "my-crate");
+}
+
+#[rstest]
+#[case("", "must not be empty")]
+#[case(" messages", "must not have leading or trailing whitespace")]
+#[case("legacy/messages", "must be a file name, not a path")]
+#[case("messages.ftl", "must not include the .ftl extension")]
+#[case("{lang}-messages", "only the {crate} token is supported")]
+fn test_raw_config_rejects_invalid_ftl_file_name(
+    #[case] pattern: &str,
+    #[case] expected_reason: &str,
+) {
+    let result = RawI18nConfig {
+        fallback_language: "en".to_string(),
+        assets_dir: PathBuf::from("i18n"),
+        fluent_feature: None,
+        namespaces: None,
+        check_fallback_copies: true,
+        keys_json: false,
+        annotate_source: false,
+        fallback_chain: None,
+        ftl_file_name: Some(pattern.to_string()),
+        supported_locales: None,
+        layout: AssetsLayout::default(),
+    }
+    .validate();
+
+    assert!(matches!(
+        result,
+        Err(I18nConfigError::InvalidFtlFileName { pattern: actual, reason })
+            if actual == pattern && reason == expected_reason
+    ));
+}
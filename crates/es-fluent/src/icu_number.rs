@@ -0,0 +1,290 @@
+use crate::FluentValue;
+use ::icu_decimal::{
+    DecimalFormatter,
+    input::Decimal,
+    options::{DecimalFormatterOptions, GroupingStrategy},
+};
+use fluent_bundle::types::FluentType;
+use intl_memoizer::Memoizable;
+use std::borrow::Cow;
+use writeable::Writeable as _;
+
+/// A number argument rendered with the active locale's ICU4X decimal format.
+///
+/// Plain numeric arguments use Fluent's default number rendering, which never
+/// adds grouping separators. Wrap a value in `FormattedNumber` to render
+/// `1234567` as `1,234,567` in `en` or `1 234 567` in `fr`, and to control the
+/// number of fraction digits:
+///
+/// ```
+/// use es_fluent::{FluentArgs, FormattedNumber};
+///
+/// let args = FluentArgs::new()
+///     .arg("balance", FormattedNumber::from(1234567))
+///     .arg("rate", FormattedNumber::from(0.5).fraction_digits(2));
+/// assert_eq!(args.len(), 2);
+/// ```
+///
+/// Derived message fields of type `FormattedNumber` are formatted the same
+/// way. Formatted numbers render as text, so they do not select plural
+/// variants; pass the raw number as a separate argument when a message needs
+/// one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormattedNumber {
+    value: Option<Decimal>,
+    fallback: String,
+    use_grouping: bool,
+    minimum_integer_digits: Option<u8>,
+    minimum_fraction_digits: Option<u8>,
+    maximum_fraction_digits: Option<u8>,
+}
+
+impl FormattedNumber {
+    fn new(value: Option<Decimal>, fallback: String) -> Self {
+        Self {
+            value,
+            fallback,
+            use_grouping: true,
+            minimum_integer_digits: None,
+            minimum_fraction_digits: None,
+            maximum_fraction_digits: None,
+        }
+    }
+
+    /// Sets whether locale grouping separators are rendered. Defaults to
+    /// `true`.
+    pub fn use_grouping(mut self, use_grouping: bool) -> Self {
+        self.use_grouping = use_grouping;
+        self
+    }
+
+    /// Pads the integer part with leading zeros to at least `digits` digits.
+    pub fn minimum_integer_digits(mut self, digits: u8) -> Self {
+        self.minimum_integer_digits = Some(digits);
+        self
+    }
+
+    /// Pads the fraction with trailing zeros to at least `digits` digits.
+    pub fn minimum_fraction_digits(mut self, digits: u8) -> Self {
+        self.minimum_fraction_digits = Some(digits);
+        self
+    }
+
+    /// Rounds the fraction to at most `digits` digits.
+    pub fn maximum_fraction_digits(mut self, digits: u8) -> Self {
+        self.maximum_fraction_digits = Some(digits);
+        self
+    }
+
+    /// Renders exactly `digits` fraction digits, rounding or padding as
+    /// needed.
+    pub fn fraction_digits(self, digits: u8) -> Self {
+        self.minimum_fraction_digits(digits)
+            .maximum_fraction_digits(digits)
+    }
+
+    fn decimal(&self) -> Option<Decimal> {
+        let mut value = self.value.clone()?;
+        if let Some(digits) = self.maximum_fraction_digits {
+            value.round(-i16::from(digits));
+        }
+        if let Some(digits) = self.minimum_fraction_digits {
+            value.absolute.pad_end(-i16::from(digits));
+        }
+        if let Some(digits) = self.minimum_integer_digits {
+            value.absolute.pad_start(i16::from(digits));
+        }
+        Some(value)
+    }
+
+    fn format(&self, intls: &intl_memoizer::IntlLangMemoizer) -> String {
+        let Some(value) = self.decimal() else {
+            return self.fallback.clone();
+        };
+        intls
+            .with_try_get::<IcuDecimalFormatter, _, _>((self.use_grouping,), |formatter| {
+                formatter.0.format(&value).write_to_string().into_owned()
+            })
+            .unwrap_or_else(|_| value.to_string())
+    }
+
+    fn format_threadsafe(&self, intls: &intl_memoizer::concurrent::IntlLangMemoizer) -> String {
+        let Some(value) = self.decimal() else {
+            return self.fallback.clone();
+        };
+        intls
+            .with_try_get::<IcuDecimalFormatter, _, _>((self.use_grouping,), |formatter| {
+                formatter.0.format(&value).write_to_string().into_owned()
+            })
+            .unwrap_or_else(|_| value.to_string())
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl From<$ty> for FormattedNumber {
+                fn from(value: $ty) -> Self {
+                    Self::new(Some(Decimal::from(value)), value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+macro_rules! impl_from_float {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl From<$ty> for FormattedNumber {
+                fn from(value: $ty) -> Self {
+                    // Float `Display` is the shortest round-trip decimal and
+                    // never uses exponent notation. Non-finite values fail to
+                    // parse and render as their `Display` text.
+                    let fallback = value.to_string();
+                    Self::new(Decimal::try_from_str(&fallback).ok(), fallback)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_float!(f32, f64);
+
+impl FluentType for FormattedNumber {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(&self, intls: &intl_memoizer::IntlLangMemoizer) -> Cow<'static, str> {
+        Cow::Owned(self.format(intls))
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        intls: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        Cow::Owned(self.format_threadsafe(intls))
+    }
+}
+
+impl From<FormattedNumber> for FluentValue<'_> {
+    fn from(value: FormattedNumber) -> Self {
+        FluentValue::Custom(Box::new(value))
+    }
+}
+
+impl From<&FormattedNumber> for FluentValue<'_> {
+    fn from(value: &FormattedNumber) -> Self {
+        value.clone().into()
+    }
+}
+
+struct IcuDecimalFormatter(DecimalFormatter);
+
+impl Memoizable for IcuDecimalFormatter {
+    type Args = (bool,);
+    type Error = String;
+
+    fn construct(
+        language: unic_langid::LanguageIdentifier,
+        (use_grouping,): Self::Args,
+    ) -> Result<Self, Self::Error> {
+        let locale = language
+            .to_string()
+            .parse::<icu_locale::Locale>()
+            .map_err(|error| error.to_string())?;
+        let options = DecimalFormatterOptions::from(if use_grouping {
+            GroupingStrategy::Auto
+        } else {
+            GroupingStrategy::Never
+        });
+        DecimalFormatter::try_new(locale.into(), options)
+            .map(Self)
+            .map_err(|error| error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn localized(value: FormattedNumber, language: &str) -> String {
+        value
+            .as_string(&intl_memoizer::IntlLangMemoizer::new(
+                language.parse().unwrap(),
+            ))
+            .into_owned()
+    }
+
+    #[test]
+    fn integers_use_locale_grouping_separators() {
+        assert_eq!(
+            localized(FormattedNumber::from(1_234_567), "en-US"),
+            "1,234,567"
+        );
+        assert_eq!(
+            localized(FormattedNumber::from(1_234_567), "fr-FR"),
+            "1\u{202f}234\u{202f}567"
+        );
+        assert_eq!(
+            localized(FormattedNumber::from(-1_234_567i64), "de-DE"),
+            "-1.234.567"
+        );
+    }
+
+    #[test]
+    fn grouping_can_be_disabled() {
+        assert_eq!(
+            localized(
+                FormattedNumber::from(1_234_567).use_grouping(false),
+                "en-US"
+            ),
+            "1234567"
+        );
+    }
+
+    #[test]
+    fn fraction_digits_round_and_pad() {
+        assert_eq!(
+            localized(FormattedNumber::from(1234.5).fraction_digits(2), "en-US"),
+            "1,234.50"
+        );
+        assert_eq!(
+            localized(
+                FormattedNumber::from(1.23456).maximum_fraction_digits(3),
+                "en-US"
+            ),
+            "1.235"
+        );
+        assert_eq!(
+            localized(FormattedNumber::from(7).minimum_fraction_digits(1), "fr-FR"),
+            "7,0"
+        );
+        assert_eq!(
+            localized(FormattedNumber::from(7).minimum_integer_digits(3), "en-US"),
+            "007"
+        );
+    }
+
+    #[test]
+    fn non_finite_floats_render_their_display_text() {
+        assert_eq!(localized(FormattedNumber::from(f64::NAN), "en-US"), "NaN");
+        assert_eq!(
+            localized(FormattedNumber::from(f64::INFINITY), "en-US"),
+            "inf"
+        );
+    }
+
+    #[test]
+    fn threadsafe_memoizer_formats_like_the_local_memoizer() {
+        let intls = intl_memoizer::concurrent::IntlLangMemoizer::new("en-US".parse().unwrap());
+        let value = FormattedNumber::from(9_876_543.21).fraction_digits(1);
+
+        assert_eq!(value.as_string_threadsafe(&intls), "9,876,543.2");
+        assert_eq!(FluentValue::from(value.clone()), FluentValue::from(value));
+    }
+}
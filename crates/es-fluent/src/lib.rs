@@ -13,6 +13,12 @@ pub use es_fluent_derive::{EsFluent, EsFluentChoice, EsFluentLabel, EsFluentVari
 #[doc(hidden)]
 pub use fluent_bundle::FluentValue;
 
+/// Implemented by values passed to Fluent through `#[fluent(custom)]` fields.
+pub use fluent_bundle::types::FluentType;
+
+/// Memoizers handed to [`FluentType`] formatting methods.
+pub use intl_memoizer;
+
 #[doc(hidden)]
 pub use inventory as __inventory;
 
@@ -24,8 +30,10 @@ pub use unic_langid;
 
 mod traits;
 pub use traits::{
-    EsFluentChoice, FluentArgs, FluentLabel, FluentLocalizer, FluentLocalizerExt,
-    FluentLocalizerLookup, FluentMessage, FluentMessageLookup,
+    EsFluentChoice, FluentArgs, FluentLabel, FluentLocalizer, FluentLocalizerChain,
+    FluentLocalizerExt, FluentLocalizerLookup, FluentMessage, FluentMessageLookup,
+    LocalizationMiss, LocalizationMissKind, LocalizedDisplay, MissedLookup,
+    clear_missing_key_formatter, format_missing_key, set_missing_key_formatter,
 };
 
 #[cfg(feature = "jiff")]
@@ -37,10 +45,16 @@ mod chrono;
 #[cfg(feature = "icu-datetime")]
 mod icu_datetime;
 
+#[cfg(feature = "icu-number")]
+mod icu_number;
+#[cfg(feature = "icu-number")]
+pub use icu_number::FormattedNumber;
+
 #[doc(hidden)]
 pub mod __private {
     pub use crate::traits::{
-        FluentArgumentValue, FluentBorrowedArgumentValue, FluentLocalizerExt,
-        FluentOptionalArgumentValue, IntoFluentArgumentValue, IntoFluentValue, localize_label,
+        FluentArgumentValue, FluentBorrowedArgumentValue, FluentCustomArgumentValue,
+        FluentLocalizerExt, FluentOptionalArgumentValue, IntoFluentArgumentValue, IntoFluentValue,
+        assert_all_ftl_keys_present, entry_id_strs, localize_label,
     };
 }
@@ -3,8 +3,8 @@
 #[doc(hidden)]
 pub use es_fluent_shared::registry::__macro;
 pub use es_fluent_shared::registry::{
-    FtlTypeInfo, FtlVariant, NamespaceRule, StaticFluentArgumentName, StaticFluentDomain,
-    StaticFluentEntryId, StaticFluentVariantKey,
+    FtlArg, FtlSelector, FtlTermLink, FtlTypeInfo, FtlVariant, NamespaceRule,
+    StaticFluentArgumentName, StaticFluentDomain, StaticFluentEntryId, StaticFluentVariantKey,
 };
 pub use es_fluent_shared::source::{SourceFile, SourceLine, SourceLocation};
 
@@ -21,3 +21,31 @@ inventory::collect!(RegisteredFtlType);
 pub fn get_all_ftl_type_infos() -> impl Iterator<Item = &'static FtlTypeInfo> {
     inventory::iter::<RegisteredFtlType>().map(|r| r.0)
 }
+
+/// Returns every registered FTL type together with the source location of its
+/// definition, for tooling that jumps from a Fluent key to Rust code.
+///
+/// Unlike [`get_all_ftl_type_infos`], whose order depends on link order, the
+/// result is sorted by type name, then module path, then message keys, so it
+/// is stable across builds. The location is `None` for types registered
+/// without a file path or line.
+pub fn all_type_infos_with_location() -> Vec<(&'static FtlTypeInfo, Option<SourceLocation>)> {
+    let mut infos: Vec<_> = get_all_ftl_type_infos()
+        .map(|info| (info, info.source_location()))
+        .collect();
+    infos.sort_by(|(a, _), (b, _)| {
+        a.type_name()
+            .cmp(b.type_name())
+            .then_with(|| a.module_path().cmp(b.module_path()))
+            .then_with(|| {
+                let entry_ids = |info: &FtlTypeInfo| {
+                    info.variants()
+                        .iter()
+                        .map(FtlVariant::entry_id)
+                        .collect::<Vec<_>>()
+                };
+                entry_ids(a).cmp(&entry_ids(b))
+            })
+    });
+    infos
+}
@@ -30,4 +30,16 @@ use crate::registry::StaticFluentVariantKey;
 /// ```
 pub trait EsFluentChoice {
     fn as_fluent_choice(&self) -> StaticFluentVariantKey;
+
+    /// Returns every select variant key this type can produce, in declaration order.
+    ///
+    /// Derived implementations list all variants so FTL generation can scaffold
+    /// a select expression for `#[fluent(selector)]` fields. The default returns
+    /// no keys, which keeps generation on a plain variable reference.
+    fn fluent_choices() -> &'static [StaticFluentVariantKey]
+    where
+        Self: Sized,
+    {
+        &[]
+    }
 }
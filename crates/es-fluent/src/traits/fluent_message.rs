@@ -1,7 +1,7 @@
-use crate::FluentValue;
 use crate::registry::{
     StaticFluentArgumentName, StaticFluentDomain, StaticFluentEntryId, StaticFluentVariantKey,
 };
+use crate::{FluentType, FluentValue};
 use es_fluent_manager_core::FluentManager;
 use std::sync::Arc;
 
@@ -25,6 +25,28 @@ impl<'a> FluentArgs<'a> {
         self.values.insert(name, value);
     }
 
+    /// Adds an argument and returns the arguments for chaining.
+    ///
+    /// This keeps hand-written lookups short:
+    ///
+    /// ```
+    /// # use es_fluent::FluentArgs;
+    /// let args = FluentArgs::new().arg("name", "Ada").arg("count", 3);
+    /// assert_eq!(args.len(), 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not a valid Fluent identifier. Use
+    /// [`StaticFluentArgumentName::try_new`] with [`Self::insert`] to handle
+    /// invalid names without panicking.
+    pub fn arg(mut self, name: &'static str, value: impl Into<FluentValue<'a>>) -> Self {
+        let name = StaticFluentArgumentName::try_new(name)
+            .unwrap_or_else(|err| panic!("invalid Fluent argument name `{name}`: {err}"));
+        self.insert(name, value.into());
+        self
+    }
+
     pub fn as_raw(&self) -> &es_fluent_manager_core::FluentArgumentMap<'a> {
         &self.values
     }
@@ -32,6 +54,10 @@ impl<'a> FluentArgs<'a> {
     pub fn is_empty(&self) -> bool {
         self.values.is_empty()
     }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
 }
 
 /// Render-time lookup callback used by [`FluentMessage`] implementations.
@@ -52,6 +78,12 @@ pub type FluentLocalizerLookup<'lookup> = dyn for<'a> FnMut(
 /// Derive macros implement this trait for `#[derive(EsFluent)]` and generated
 /// variant enums. Runtime managers use it to keep typed message call sites while
 /// routing lookup through a request, component, or application-scoped manager.
+/// `#[fluent(as_terms)]` types write Fluent terms and do not implement it.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a Fluent message",
+    note = "derive `EsFluent` or `EsFluentVariants` for `{Self}`, or implement `FluentMessage` manually",
+    note = "`#[fluent(as_terms)]` types cannot be rendered, so they cannot be message arguments or `#[fluent(term)]` fields"
+)]
 pub trait FluentMessage {
     /// Converts the message into a localized string using the supplied lookup
     /// callback.
@@ -61,6 +93,18 @@ pub trait FluentMessage {
     /// language or perform other lock-taking lookups from this method; managers
     /// may hold snapshot locks while invoking it.
     fn to_fluent_string_with(&self, localize: &mut FluentMessageLookup<'_>) -> String;
+
+    /// Returns the FTL message ids this type can render, in declaration order.
+    ///
+    /// Derived implementations list their messages so FTL generation can link
+    /// `#[fluent(term)]` fields to them with Fluent terms. The default returns
+    /// no ids, which leaves generation on a plain variable reference.
+    fn fluent_entry_ids() -> &'static [StaticFluentEntryId]
+    where
+        Self: Sized,
+    {
+        &[]
+    }
 }
 
 #[diagnostic::do_not_recommend]
@@ -101,6 +145,40 @@ pub trait FluentLocalizer {
         args: Option<&'a FluentArgs<'a>>,
     ) -> Option<String>;
 
+    /// Localizes the `attribute` of a validated static message ID within a
+    /// validated static domain, such as `.placeholder` on `login_form`.
+    ///
+    /// The default reports every attribute as missing. The bundled managers
+    /// override it.
+    fn localize_attribute_in_domain<'a>(
+        &self,
+        _domain: StaticFluentDomain,
+        _id: StaticFluentEntryId,
+        _attribute: &str,
+        _args: Option<&'a FluentArgs<'a>>,
+    ) -> Option<String> {
+        None
+    }
+
+    /// Returns whether a validated static message ID resolves to a value
+    /// within a validated static domain, without formatting it.
+    ///
+    /// The default formats the message without arguments, so messages that
+    /// reference variables report as missing. The bundled managers override
+    /// it.
+    fn has_message_in_domain(&self, domain: StaticFluentDomain, id: StaticFluentEntryId) -> bool {
+        self.localize_in_domain(domain, id, None).is_some()
+    }
+
+    /// Returns whether any resources are loaded for a validated static domain.
+    ///
+    /// [`FluentLocalizerExt::try_localize`] uses it to tell a missing domain
+    /// from a missing message. The default reports every domain as loaded.
+    /// The bundled managers override it.
+    fn has_domain(&self, _domain: StaticFluentDomain) -> bool {
+        true
+    }
+
     /// Runs a group of lookups against one render-scoped localization view.
     ///
     /// Implementations must invoke the callback exactly once, must not call it
@@ -186,6 +264,30 @@ impl FluentLocalizer for FluentManager {
         FluentManager::localize_in_domain(self, domain, id, args.map(FluentArgs::as_raw))
     }
 
+    fn localize_attribute_in_domain<'a>(
+        &self,
+        domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&FluentArgs<'a>>,
+    ) -> Option<String> {
+        FluentManager::localize_attribute_in_domain(
+            self,
+            domain,
+            id,
+            attribute,
+            args.map(FluentArgs::as_raw),
+        )
+    }
+
+    fn has_message_in_domain(&self, domain: StaticFluentDomain, id: StaticFluentEntryId) -> bool {
+        FluentManager::has_message_in_domain(self, domain, id)
+    }
+
+    fn has_domain(&self, domain: StaticFluentDomain) -> bool {
+        FluentManager::has_domain(self, domain)
+    }
+
     fn with_lookup(&self, f: &mut dyn FnMut(&mut FluentLocalizerLookup<'_>)) {
         FluentManager::with_lookup(self, &mut |lookup| {
             let mut typed_lookup =
@@ -217,6 +319,24 @@ impl<T: FluentLocalizer + ?Sized> FluentLocalizer for &T {
         (**self).localize_in_domain(domain, id, args)
     }
 
+    fn localize_attribute_in_domain<'a>(
+        &self,
+        domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&FluentArgs<'a>>,
+    ) -> Option<String> {
+        (**self).localize_attribute_in_domain(domain, id, attribute, args)
+    }
+
+    fn has_message_in_domain(&self, domain: StaticFluentDomain, id: StaticFluentEntryId) -> bool {
+        (**self).has_message_in_domain(domain, id)
+    }
+
+    fn has_domain(&self, domain: StaticFluentDomain) -> bool {
+        (**self).has_domain(domain)
+    }
+
     fn with_lookup(&self, f: &mut dyn FnMut(&mut FluentLocalizerLookup<'_>)) {
         (**self).with_lookup(f);
     }
@@ -240,11 +360,160 @@ impl<T: FluentLocalizer + ?Sized> FluentLocalizer for Arc<T> {
         (**self).localize_in_domain(domain, id, args)
     }
 
+    fn localize_attribute_in_domain<'a>(
+        &self,
+        domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&FluentArgs<'a>>,
+    ) -> Option<String> {
+        (**self).localize_attribute_in_domain(domain, id, attribute, args)
+    }
+
+    fn has_message_in_domain(&self, domain: StaticFluentDomain, id: StaticFluentEntryId) -> bool {
+        (**self).has_message_in_domain(domain, id)
+    }
+
+    fn has_domain(&self, domain: StaticFluentDomain) -> bool {
+        (**self).has_domain(domain)
+    }
+
     fn with_lookup(&self, f: &mut dyn FnMut(&mut FluentLocalizerLookup<'_>)) {
         (**self).with_lookup(f);
     }
 }
 
+/// Why a lookup reported by [`LocalizationMiss`] could not be resolved.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LocalizationMissKind {
+    /// No resources are loaded for the lookup's domain.
+    MissingDomain,
+    /// The domain is loaded but has no value for the message.
+    MissingMessage,
+    /// The message exists but could not be formatted, for example because
+    /// an argument it references was not supplied.
+    FormatError,
+}
+
+/// One lookup reported by [`LocalizationMiss`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MissedLookup {
+    /// The domain the message was looked up in.
+    pub domain: StaticFluentDomain,
+    /// The message id that was looked up.
+    pub id: StaticFluentEntryId,
+    /// Why the lookup could not be resolved.
+    pub kind: LocalizationMissKind,
+}
+
+/// Message lookups that a localizer could not resolve while rendering a typed
+/// message.
+///
+/// Returned by [`FluentLocalizerExt::try_localize`] so callers such as tests or
+/// QA builds can report exactly which messages are untranslated.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocalizationMiss {
+    missing: Vec<MissedLookup>,
+}
+
+impl LocalizationMiss {
+    /// Returns every failed lookup in render order, without duplicates.
+    pub fn missing(&self) -> &[MissedLookup] {
+        &self.missing
+    }
+}
+
+impl std::fmt::Display for LocalizationMiss {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, lookup) in self.missing.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            let (id, domain) = (lookup.id.as_str(), lookup.domain.as_str());
+            match lookup.kind {
+                LocalizationMissKind::MissingDomain => write!(
+                    f,
+                    "no Fluent resources loaded for domain `{domain}` (message `{id}`)"
+                )?,
+                LocalizationMissKind::MissingMessage => {
+                    write!(f, "missing Fluent message `{id}` in domain `{domain}`")?
+                },
+                LocalizationMissKind::FormatError => write!(
+                    f,
+                    "Fluent message `{id}` in domain `{domain}` could not be formatted"
+                )?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for LocalizationMiss {}
+
+/// Backs the `ALL_FTL_KEYS` const generated by `#[fluent(export_keys)]`,
+/// copying the ids of [`FluentMessage::fluent_entry_ids`] as strings.
+#[doc(hidden)]
+pub const fn entry_id_strs<const N: usize>(ids: &[StaticFluentEntryId]) -> [&'static str; N] {
+    assert!(
+        ids.len() == N,
+        "entry id count does not match the key count"
+    );
+    let mut keys = [""; N];
+    let mut index = 0;
+    while index < N {
+        keys[index] = ids[index].as_str();
+        index += 1;
+    }
+    keys
+}
+
+/// Backs the `__assert_all_ftl_keys_present` function generated by
+/// `#[fluent(test_all_keys)]`.
+#[doc(hidden)]
+pub fn assert_all_ftl_keys_present<L>(
+    localizer: &L,
+    type_name: &str,
+    domain: StaticFluentDomain,
+    ids: &[StaticFluentEntryId],
+) where
+    L: FluentLocalizer + ?Sized,
+{
+    let missing = ids
+        .iter()
+        .filter(|id| !localizer.has_message_in_domain(domain, **id))
+        .map(|id| id.as_str())
+        .collect::<Vec<_>>();
+
+    assert!(
+        missing.is_empty(),
+        "`{type_name}` has no translation in domain `{}` for: {}",
+        domain.as_str(),
+        missing.join(", ")
+    );
+}
+
+/// A typed message paired with the localizer that renders it, implementing
+/// [`std::fmt::Display`].
+///
+/// Returned by [`FluentLocalizerExt::display_message`]. The message is
+/// rendered when the value is formatted, so `write!` and logging macros can
+/// take it directly without the caller building a `String` first. Width,
+/// fill, and alignment flags apply to the rendered text.
+pub struct LocalizedDisplay<'a, L: ?Sized, T: ?Sized> {
+    localizer: &'a L,
+    message: &'a T,
+}
+
+impl<L, T> std::fmt::Display for LocalizedDisplay<'_, L, T>
+where
+    L: FluentLocalizer + ?Sized,
+    T: FluentMessage + ?Sized,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(&self.localizer.localize_message(self.message))
+    }
+}
+
 /// Public extension methods for generic explicit localization contexts.
 ///
 /// Concrete manager crates expose inherent `localize_message(...)` methods for
@@ -261,7 +530,22 @@ pub trait FluentLocalizerExt: FluentLocalizer {
     where
         T: FluentMessage + ?Sized,
     {
-        let mut missing = false;
+        self.try_localize(message).ok()
+    }
+
+    /// Attempts to render a derived typed message and reports every lookup
+    /// that the localizer could not resolve.
+    ///
+    /// Use this instead of [`Self::try_localize_message`] when the caller needs
+    /// to know which messages are missing, for example to fail CI when a
+    /// screen references an untranslated key. Each miss is classified with
+    /// [`FluentLocalizer::has_domain`] and
+    /// [`FluentLocalizer::has_message_in_domain`] once the render finishes.
+    fn try_localize<T>(&self, message: &T) -> Result<String, LocalizationMiss>
+    where
+        T: FluentMessage + ?Sized,
+    {
+        let mut missing = Vec::new();
         let mut value = None;
         let mut callback_invocations = 0;
 
@@ -275,7 +559,9 @@ pub trait FluentLocalizerExt: FluentLocalizer {
 
             value = Some(message.to_fluent_string_with(&mut |domain, id, args| {
                 lookup(domain, id, args).unwrap_or_else(|| {
-                    missing = true;
+                    if !missing.contains(&(domain, id)) {
+                        missing.push((domain, id));
+                    }
                     String::new()
                 })
             }));
@@ -287,10 +573,30 @@ pub trait FluentLocalizerExt: FluentLocalizer {
             WITH_LOOKUP_CALLBACK_COUNT_ERROR
         );
         let value = value.expect(WITH_LOOKUP_CALLBACK_COUNT_ERROR);
-        if missing { None } else { Some(value) }
+        if missing.is_empty() {
+            return Ok(value);
+        }
+
+        let missing = missing
+            .into_iter()
+            .map(|(domain, id)| {
+                let kind = if !self.has_domain(domain) {
+                    LocalizationMissKind::MissingDomain
+                } else if self.has_message_in_domain(domain, id) {
+                    LocalizationMissKind::FormatError
+                } else {
+                    LocalizationMissKind::MissingMessage
+                };
+                MissedLookup { domain, id, kind }
+            })
+            .collect();
+        Err(LocalizationMiss { missing })
     }
 
     /// Renders a derived typed message through this explicit localizer.
+    ///
+    /// A missing message renders with the formatter set by
+    /// [`crate::set_missing_key_formatter`], and panics when none is set.
     fn localize_message<T>(&self, message: &T) -> String
     where
         T: FluentMessage + ?Sized,
@@ -306,15 +612,48 @@ pub trait FluentLocalizerExt: FluentLocalizer {
             );
             callback_invocations = 1;
 
-            value = Some(message.to_fluent_string_with(&mut |domain, id, args| {
-                lookup(domain, id, args).unwrap_or_else(|| {
-                    panic!(
-                        "missing Fluent message `{}` in domain `{}`",
-                        id.as_str(),
-                        domain.as_str(),
-                    )
-                })
-            }));
+            value = Some(render_or_format_missing_key(message, lookup));
+        });
+
+        assert!(
+            callback_invocations == 1,
+            "{}",
+            WITH_LOOKUP_CALLBACK_COUNT_ERROR
+        );
+        value.expect(WITH_LOOKUP_CALLBACK_COUNT_ERROR)
+    }
+
+    /// Renders every derived typed message yielded by `messages` and joins
+    /// them with `separator`, for example `", "` for a list of statuses.
+    ///
+    /// All messages render within one lookup scope, so a concurrent language
+    /// switch cannot mix locales in the result. Missing messages behave as in
+    /// [`Self::localize_message`].
+    fn localize_joined<I>(&self, messages: I, separator: &str) -> String
+    where
+        I: IntoIterator,
+        I::Item: FluentMessage,
+    {
+        let mut messages = messages.into_iter();
+        let mut value = None;
+        let mut callback_invocations = 0;
+
+        self.with_lookup(&mut |lookup| {
+            assert!(
+                callback_invocations == 0,
+                "{}",
+                WITH_LOOKUP_CALLBACK_COUNT_ERROR
+            );
+            callback_invocations = 1;
+
+            let mut joined = String::new();
+            for (index, message) in messages.by_ref().enumerate() {
+                if index > 0 {
+                    joined.push_str(separator);
+                }
+                joined.push_str(&render_or_format_missing_key(&message, lookup));
+            }
+            value = Some(joined);
         });
 
         assert!(
@@ -324,10 +663,70 @@ pub trait FluentLocalizerExt: FluentLocalizer {
         );
         value.expect(WITH_LOOKUP_CALLBACK_COUNT_ERROR)
     }
+
+    /// Renders one attribute of a derived typed message, such as the
+    /// `.placeholder` declared with `#[fluent(attributes = [...])]`.
+    ///
+    /// Nested message arguments render as usual and the attribute is looked up
+    /// on the message itself with the same arguments. Returns `None` when the
+    /// attribute or any nested message is missing.
+    ///
+    /// For hand-written [`FluentMessage`] implementations, the message itself
+    /// is the last lookup the render performs.
+    fn localize_message_attribute<T>(&self, message: &T, attribute: &str) -> Option<String>
+    where
+        T: FluentMessage + ?Sized,
+    {
+        let mut value = None;
+        let mut nested_missing = false;
+        let mut last_missing = false;
+
+        message.to_fluent_string_with(&mut |domain, id, args| {
+            nested_missing |= last_missing;
+            value = self.localize_attribute_in_domain(domain, id, attribute, args);
+            let text = self.localize_in_domain(domain, id, args);
+            last_missing = text.is_none();
+            text.unwrap_or_default()
+        });
+
+        if nested_missing { None } else { value }
+    }
+
+    /// Wraps a derived typed message so it formats through this localizer
+    /// with `{}`.
+    ///
+    /// Formatting renders like [`Self::localize_message`], including its panic
+    /// on a missing message.
+    fn display_message<'a, T>(&'a self, message: &'a T) -> LocalizedDisplay<'a, Self, T>
+    where
+        T: FluentMessage + ?Sized,
+    {
+        LocalizedDisplay {
+            localizer: self,
+            message,
+        }
+    }
 }
 
 impl<T: FluentLocalizer + ?Sized> FluentLocalizerExt for T {}
 
+fn render_or_format_missing_key<T>(message: &T, lookup: &mut FluentLocalizerLookup<'_>) -> String
+where
+    T: FluentMessage + ?Sized,
+{
+    message.to_fluent_string_with(&mut |domain, id, args| {
+        lookup(domain, id, args)
+            .or_else(|| super::format_missing_key(id.as_str()))
+            .unwrap_or_else(|| {
+                panic!(
+                    "missing Fluent message `{}` in domain `{}`",
+                    id.as_str(),
+                    domain.as_str(),
+                )
+            })
+    })
+}
+
 #[doc(hidden)]
 pub trait IntoFluentValue<'a> {
     fn into_fluent_value(self) -> FluentValue<'a>;
@@ -398,6 +797,19 @@ impl<T> FluentOptionalArgumentValue<T> {
     }
 }
 
+/// Wrapper used by generated `FluentMessage` implementations for
+/// `#[fluent(custom)]` fields, which reach Fluent as `FluentValue::Custom`.
+#[doc(hidden)]
+pub struct FluentCustomArgumentValue<'a, T: ?Sized> {
+    value: &'a T,
+}
+
+impl<'a, T: ?Sized> FluentCustomArgumentValue<'a, T> {
+    pub fn new(value: &'a T) -> Self {
+        Self { value }
+    }
+}
+
 /// Converts generated message arguments into Fluent values.
 ///
 /// This intentionally uses autoref-priority implementations: exact
@@ -501,6 +913,18 @@ impl<'a> IntoFluentArgumentValue<'a> for FluentOptionalArgumentValue<StaticFluen
     }
 }
 
+impl<'a, 'value, T> IntoFluentArgumentValue<'a> for FluentCustomArgumentValue<'value, T>
+where
+    T: FluentType,
+{
+    fn into_fluent_argument_value(
+        self,
+        _localize: &mut FluentMessageLookup<'_>,
+    ) -> FluentValue<'a> {
+        FluentValue::Custom(self.value.duplicate())
+    }
+}
+
 fn bool_fluent_value<'a>(value: bool) -> FluentValue<'a> {
     if value { "true" } else { "false" }.into()
 }
@@ -634,6 +1058,24 @@ mod tests {
         assert_string(borrowed_bool_value, "true");
     }
 
+    #[test]
+    fn fluent_args_arg_chains_values_by_name() {
+        let args = FluentArgs::new()
+            .arg("name", "Ada")
+            .arg("count", 3)
+            .arg("name", "Grace");
+
+        assert_eq!(args.len(), 2);
+        assert_string(args.as_raw()["name"].clone(), "Grace");
+        assert_number(args.as_raw()["count"].clone(), 3.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid Fluent argument name `not valid`")]
+    fn fluent_args_arg_rejects_invalid_names() {
+        let _ = FluentArgs::new().arg("not valid", 1);
+    }
+
     #[test]
     #[should_panic(expected = "ordinary arguments should not invoke nested localization")]
     fn panic_lookup_reports_unexpected_nested_localization() {
@@ -771,6 +1213,33 @@ mod tests {
         assert_eq!(en.localize_message(&NestedMessage), "Hello");
     }
 
+    #[test]
+    fn localize_joined_renders_each_message_with_the_separator() {
+        let localizer = StaticLocalizer { value: "Hello" };
+
+        assert_eq!(
+            localizer.localize_joined([NestedMessage, NestedMessage, NestedMessage], ", "),
+            "Hello, Hello, Hello"
+        );
+        assert_eq!(localizer.localize_joined(&[NestedMessage], " / "), "Hello");
+        assert_eq!(
+            localizer.localize_joined(std::iter::empty::<NestedMessage>(), ", "),
+            ""
+        );
+    }
+
+    #[test]
+    fn display_message_formats_through_the_explicit_localizer() {
+        let en = StaticLocalizer { value: "Hello" };
+        let fr = StaticLocalizer { value: "Bonjour" };
+
+        assert_eq!(en.display_message(&NestedMessage).to_string(), "Hello");
+        assert_eq!(
+            format!("[{:>9}]", fr.display_message(&NestedMessage)),
+            "[  Bonjour]"
+        );
+    }
+
     struct MissingMessage;
 
     impl FluentMessage for MissingMessage {
@@ -899,6 +1368,126 @@ mod tests {
         assert_eq!(localizer.try_localize_message(&MissingMessage), None);
     }
 
+    struct PartiallyMissingMessage;
+
+    impl FluentMessage for PartiallyMissingMessage {
+        fn to_fluent_string_with(&self, localize: &mut FluentMessageLookup<'_>) -> String {
+            let found = NestedMessage.to_fluent_string_with(localize);
+            let first = MissingMessage.to_fluent_string_with(localize);
+            let second = MissingMessage.to_fluent_string_with(localize);
+            format!("{found}{first}{second}")
+        }
+    }
+
+    #[test]
+    fn localizer_extension_reports_missing_typed_message_lookups() {
+        let localizer = StaticLocalizer { value: "Hello" };
+
+        assert_eq!(
+            localizer.try_localize(&NestedMessage),
+            Ok("Hello".to_string())
+        );
+
+        let miss = localizer
+            .try_localize(&PartiallyMissingMessage)
+            .expect_err("missing lookups should be reported");
+        assert_eq!(
+            miss.missing(),
+            &[MissedLookup {
+                domain: static_domain("missing-domain"),
+                id: static_entry("missing-id"),
+                kind: LocalizationMissKind::MissingMessage,
+            }]
+        );
+        assert_eq!(
+            miss.to_string(),
+            "missing Fluent message `missing-id` in domain `missing-domain`"
+        );
+    }
+
+    struct ClassifyingLocalizer;
+
+    impl FluentLocalizer for ClassifyingLocalizer {
+        fn localize<'a>(
+            &self,
+            _id: StaticFluentEntryId,
+            _args: Option<&FluentArgs<'a>>,
+        ) -> Option<String> {
+            None
+        }
+
+        fn localize_in_domain<'a>(
+            &self,
+            _domain: StaticFluentDomain,
+            _id: StaticFluentEntryId,
+            _args: Option<&FluentArgs<'a>>,
+        ) -> Option<String> {
+            None
+        }
+
+        fn has_message_in_domain(
+            &self,
+            domain: StaticFluentDomain,
+            id: StaticFluentEntryId,
+        ) -> bool {
+            domain == "loaded-domain" && id == "needs-args"
+        }
+
+        fn has_domain(&self, domain: StaticFluentDomain) -> bool {
+            domain == "loaded-domain"
+        }
+    }
+
+    struct ClassifiedMisses;
+
+    impl FluentMessage for ClassifiedMisses {
+        fn to_fluent_string_with(&self, localize: &mut FluentMessageLookup<'_>) -> String {
+            let lookups = [
+                ("unloaded-domain", "any-id"),
+                ("loaded-domain", "absent-id"),
+                ("loaded-domain", "needs-args"),
+            ];
+            lookups
+                .into_iter()
+                .map(|(domain, id)| localize(static_domain(domain), static_entry(id), None))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn localizer_extension_classifies_missing_typed_message_lookups() {
+        let miss = ClassifyingLocalizer
+            .try_localize(&ClassifiedMisses)
+            .expect_err("every lookup misses");
+
+        assert_eq!(
+            miss.missing(),
+            &[
+                MissedLookup {
+                    domain: static_domain("unloaded-domain"),
+                    id: static_entry("any-id"),
+                    kind: LocalizationMissKind::MissingDomain,
+                },
+                MissedLookup {
+                    domain: static_domain("loaded-domain"),
+                    id: static_entry("absent-id"),
+                    kind: LocalizationMissKind::MissingMessage,
+                },
+                MissedLookup {
+                    domain: static_domain("loaded-domain"),
+                    id: static_entry("needs-args"),
+                    kind: LocalizationMissKind::FormatError,
+                },
+            ]
+        );
+        assert_eq!(
+            miss.to_string(),
+            "no Fluent resources loaded for domain `unloaded-domain` (message `any-id`), \
+             missing Fluent message `absent-id` in domain `loaded-domain`, \
+             Fluent message `needs-args` in domain `loaded-domain` could not be formatted"
+        );
+    }
+
     struct MinimalScopedLocalizer;
 
     impl MinimalScopedLocalizer {
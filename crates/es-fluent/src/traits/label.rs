@@ -51,6 +51,7 @@ pub fn localize_label<L: FluentLocalizer + ?Sized>(
 ) -> String {
     localizer
         .localize_in_domain(domain, id, None)
+        .or_else(|| super::format_missing_key(id.as_str()))
         .unwrap_or_else(|| {
             panic!(
                 "missing Fluent label `{}` in domain `{}`",
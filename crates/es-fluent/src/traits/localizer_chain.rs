@@ -0,0 +1,320 @@
+use super::fluent_message::{FluentArgs, FluentLocalizer, FluentLocalizerLookup};
+use crate::registry::{StaticFluentDomain, StaticFluentEntryId};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+type SharedLocalizer = Arc<dyn FluentLocalizer + Send + Sync>;
+
+/// An ordered list of custom localizers that is itself a [`FluentLocalizer`].
+///
+/// Lookups try each registered localizer in registration order and return the
+/// first `Some`. Several integrations, for example a framework adapter and a
+/// test harness, can register into the same chain without replacing each
+/// other.
+#[derive(Default)]
+pub struct FluentLocalizerChain {
+    localizers: RwLock<Vec<SharedLocalizer>>,
+}
+
+impl FluentLocalizerChain {
+    /// Creates an empty chain. Every lookup misses until a localizer is pushed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a localizer that is consulted after every previously registered
+    /// one.
+    pub fn push_custom_localizer<L>(&self, localizer: L)
+    where
+        L: FluentLocalizer + Send + Sync + 'static,
+    {
+        self.localizers.write().push(Arc::new(localizer));
+    }
+
+    /// Replaces every registered localizer with `localizer`.
+    pub fn set_custom_localizer<L>(&self, localizer: L)
+    where
+        L: FluentLocalizer + Send + Sync + 'static,
+    {
+        *self.localizers.write() = vec![Arc::new(localizer)];
+    }
+
+    /// Removes every registered localizer.
+    pub fn clear(&self) {
+        self.localizers.write().clear();
+    }
+
+    /// Returns the number of registered localizers.
+    pub fn len(&self) -> usize {
+        self.localizers.read().len()
+    }
+
+    /// Returns `true` when no localizer is registered.
+    pub fn is_empty(&self) -> bool {
+        self.localizers.read().is_empty()
+    }
+}
+
+/// Innermost lookup of an empty chain, which misses every entry.
+fn no_entry(
+    _domain: StaticFluentDomain,
+    _id: StaticFluentEntryId,
+    _args: Option<&FluentArgs<'_>>,
+) -> Option<String> {
+    None
+}
+
+/// Enters the [`FluentLocalizer::with_lookup`] scope of every localizer in
+/// turn, then runs `f` with a lookup that tries `outer` before the innermost
+/// localizer.
+fn with_nested_lookups(
+    localizers: &[SharedLocalizer],
+    outer: &mut FluentLocalizerLookup<'_>,
+    f: &mut dyn FnMut(&mut FluentLocalizerLookup<'_>),
+) {
+    let Some((first, rest)) = localizers.split_first() else {
+        f(outer);
+        return;
+    };
+
+    first.with_lookup(&mut |lookup| {
+        let mut combined =
+            |domain: StaticFluentDomain, id: StaticFluentEntryId, args: Option<&FluentArgs<'_>>| {
+                outer(domain, id, args).or_else(|| lookup(domain, id, args))
+            };
+        with_nested_lookups(rest, &mut combined, f);
+    });
+}
+
+impl std::fmt::Debug for FluentLocalizerChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FluentLocalizerChain")
+            .field("localizers", &self.len())
+            .finish()
+    }
+}
+
+impl FluentLocalizer for FluentLocalizerChain {
+    fn localize<'a>(
+        &self,
+        id: StaticFluentEntryId,
+        args: Option<&FluentArgs<'a>>,
+    ) -> Option<String> {
+        self.localizers
+            .read()
+            .iter()
+            .find_map(|localizer| localizer.localize(id, args))
+    }
+
+    fn localize_in_domain<'a>(
+        &self,
+        domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+        args: Option<&FluentArgs<'a>>,
+    ) -> Option<String> {
+        self.localizers
+            .read()
+            .iter()
+            .find_map(|localizer| localizer.localize_in_domain(domain, id, args))
+    }
+
+    fn localize_attribute_in_domain<'a>(
+        &self,
+        domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+        attribute: &str,
+        args: Option<&FluentArgs<'a>>,
+    ) -> Option<String> {
+        self.localizers.read().iter().find_map(|localizer| {
+            localizer.localize_attribute_in_domain(domain, id, attribute, args)
+        })
+    }
+
+    fn has_message_in_domain(&self, domain: StaticFluentDomain, id: StaticFluentEntryId) -> bool {
+        self.localizers
+            .read()
+            .iter()
+            .any(|localizer| localizer.has_message_in_domain(domain, id))
+    }
+
+    fn has_domain(&self, domain: StaticFluentDomain) -> bool {
+        self.localizers
+            .read()
+            .iter()
+            .any(|localizer| localizer.has_domain(domain))
+    }
+
+    /// Renders against the localizers registered when the callback starts,
+    /// inside each one's own [`FluentLocalizer::with_lookup`] scope.
+    ///
+    /// A `FluentManager` in the chain therefore keeps
+    /// one language for the whole message, as it does on its own. Localizers
+    /// pushed or replaced during the render are not observed until the next
+    /// call.
+    fn with_lookup(&self, f: &mut dyn FnMut(&mut FluentLocalizerLookup<'_>)) {
+        let localizers = self.localizers.read().clone();
+        let mut miss = no_entry;
+        with_nested_lookups(&localizers, &mut miss, f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FluentLocalizerExt as _, FluentMessage, FluentMessageLookup};
+
+    fn static_domain(value: &'static str) -> StaticFluentDomain {
+        StaticFluentDomain::try_new(value).expect("valid test domain")
+    }
+
+    fn static_entry(value: &'static str) -> StaticFluentEntryId {
+        StaticFluentEntryId::try_new(value).expect("valid test message id")
+    }
+
+    struct KeyedLocalizer {
+        id: &'static str,
+        value: &'static str,
+    }
+
+    impl FluentLocalizer for KeyedLocalizer {
+        fn localize<'a>(
+            &self,
+            id: StaticFluentEntryId,
+            _args: Option<&FluentArgs<'a>>,
+        ) -> Option<String> {
+            (id == self.id).then(|| self.value.to_string())
+        }
+
+        fn localize_in_domain<'a>(
+            &self,
+            _domain: StaticFluentDomain,
+            id: StaticFluentEntryId,
+            args: Option<&FluentArgs<'a>>,
+        ) -> Option<String> {
+            self.localize(id, args)
+        }
+    }
+
+    struct PairMessage;
+
+    impl FluentMessage for PairMessage {
+        fn to_fluent_string_with(&self, localize: &mut FluentMessageLookup<'_>) -> String {
+            let domain = static_domain("chain-domain");
+            let hello = localize(domain, static_entry("hello"), None);
+            let bye = localize(domain, static_entry("bye"), None);
+            format!("{hello} {bye}")
+        }
+    }
+
+    #[test]
+    fn chain_tries_localizers_in_registration_order() {
+        let chain = FluentLocalizerChain::new();
+        assert!(chain.is_empty());
+        assert_eq!(chain.localize(static_entry("hello"), None), None);
+
+        chain.push_custom_localizer(KeyedLocalizer {
+            id: "hello",
+            value: "Hello",
+        });
+        chain.push_custom_localizer(KeyedLocalizer {
+            id: "hello",
+            value: "Shadowed",
+        });
+        chain.push_custom_localizer(KeyedLocalizer {
+            id: "bye",
+            value: "Bye",
+        });
+
+        assert_eq!(chain.len(), 3);
+        assert_eq!(
+            chain.localize(static_entry("hello"), None),
+            Some("Hello".to_string())
+        );
+        assert_eq!(chain.localize_message(&PairMessage), "Hello Bye");
+    }
+
+    /// Serves `value` only inside its own lookup scope, like a manager that
+    /// pins one language for a whole render.
+    struct ScopedLocalizer {
+        id: &'static str,
+        value: &'static str,
+    }
+
+    impl FluentLocalizer for ScopedLocalizer {
+        fn localize<'a>(
+            &self,
+            _id: StaticFluentEntryId,
+            _args: Option<&FluentArgs<'a>>,
+        ) -> Option<String> {
+            None
+        }
+
+        fn localize_in_domain<'a>(
+            &self,
+            _domain: StaticFluentDomain,
+            _id: StaticFluentEntryId,
+            _args: Option<&FluentArgs<'a>>,
+        ) -> Option<String> {
+            None
+        }
+
+        fn with_lookup(&self, f: &mut dyn FnMut(&mut FluentLocalizerLookup<'_>)) {
+            let mut lookup =
+                |_: StaticFluentDomain, id: StaticFluentEntryId, _: Option<&FluentArgs<'_>>| {
+                    (id == self.id).then(|| self.value.to_string())
+                };
+            f(&mut lookup);
+        }
+    }
+
+    #[test]
+    fn chain_renders_inside_each_localizer_lookup_scope() {
+        let chain = FluentLocalizerChain::new();
+        chain.push_custom_localizer(ScopedLocalizer {
+            id: "hello",
+            value: "Hello",
+        });
+        chain.push_custom_localizer(KeyedLocalizer {
+            id: "hello",
+            value: "Shadowed",
+        });
+        chain.push_custom_localizer(ScopedLocalizer {
+            id: "bye",
+            value: "Bye",
+        });
+
+        assert_eq!(chain.localize_message(&PairMessage), "Hello Bye");
+        assert_eq!(
+            chain.localize(static_entry("hello"), None),
+            Some("Shadowed".to_string())
+        );
+    }
+
+    #[test]
+    fn set_custom_localizer_replaces_registered_localizers() {
+        let chain = FluentLocalizerChain::new();
+        chain.push_custom_localizer(KeyedLocalizer {
+            id: "hello",
+            value: "Hello",
+        });
+        chain.push_custom_localizer(KeyedLocalizer {
+            id: "bye",
+            value: "Bye",
+        });
+
+        chain.set_custom_localizer(KeyedLocalizer {
+            id: "hello",
+            value: "Bonjour",
+        });
+
+        assert_eq!(chain.len(), 1);
+        assert_eq!(
+            chain.localize(static_entry("hello"), None),
+            Some("Bonjour".to_string())
+        );
+        assert_eq!(chain.try_localize_message(&PairMessage), None);
+
+        chain.clear();
+        assert!(chain.is_empty());
+    }
+}
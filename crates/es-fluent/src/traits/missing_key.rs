@@ -0,0 +1,41 @@
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+type MissingKeyFormatter = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+static MISSING_KEY_FORMATTER: RwLock<Option<MissingKeyFormatter>> = RwLock::new(None);
+
+/// Sets the process-wide renderer for message ids that no localizer resolves.
+///
+/// Without a formatter, [`FluentLocalizerExt::localize_message`] and
+/// `localize_label` panic on a missing entry. With one, they render the
+/// formatter's output instead, so a QA build can flag untranslated text on
+/// screen:
+///
+/// ```
+/// es_fluent::set_missing_key_formatter(|id| format!("⟦{id}⟧"));
+/// # es_fluent::clear_missing_key_formatter();
+/// ```
+///
+/// The Bevy and Dioxus adapters render through the same path.
+///
+/// [`FluentLocalizerExt::localize_message`]: crate::FluentLocalizerExt::localize_message
+pub fn set_missing_key_formatter<F>(formatter: F)
+where
+    F: Fn(&str) -> String + Send + Sync + 'static,
+{
+    *MISSING_KEY_FORMATTER.write() = Some(Arc::new(formatter));
+}
+
+/// Removes the formatter set with [`set_missing_key_formatter`].
+pub fn clear_missing_key_formatter() {
+    *MISSING_KEY_FORMATTER.write() = None;
+}
+
+/// Renders a missing message id with the configured formatter.
+///
+/// Returns `None` when no formatter is set.
+pub fn format_missing_key(id: &str) -> Option<String> {
+    let formatter = MISSING_KEY_FORMATTER.read().clone();
+    formatter.map(|formatter| formatter(id))
+}
@@ -1,11 +1,17 @@
 mod fluent_choice;
 mod fluent_message;
 mod label;
+mod localizer_chain;
+mod missing_key;
 
 pub use fluent_choice::EsFluentChoice;
 pub use fluent_message::{
-    FluentArgs, FluentArgumentValue, FluentBorrowedArgumentValue, FluentLocalizer,
-    FluentLocalizerExt, FluentLocalizerLookup, FluentMessage, FluentMessageLookup,
-    FluentOptionalArgumentValue, IntoFluentArgumentValue, IntoFluentValue,
+    FluentArgs, FluentArgumentValue, FluentBorrowedArgumentValue, FluentCustomArgumentValue,
+    FluentLocalizer, FluentLocalizerExt, FluentLocalizerLookup, FluentMessage, FluentMessageLookup,
+    FluentOptionalArgumentValue, IntoFluentArgumentValue, IntoFluentValue, LocalizationMiss,
+    LocalizationMissKind, LocalizedDisplay, MissedLookup, assert_all_ftl_keys_present,
+    entry_id_strs,
 };
 pub use label::{FluentLabel, localize_label};
+pub use localizer_chain::FluentLocalizerChain;
+pub use missing_key::{clear_missing_key_formatter, format_missing_key, set_missing_key_formatter};
@@ -0,0 +1,92 @@
+#![cfg(feature = "derive")]
+
+//! Derived inventory entries pair each Fluent argument with the Rust type of
+//! the field behind it, for tooling that needs more than argument names.
+
+use es_fluent::EsFluent;
+use es_fluent::registry::FtlVariant;
+
+#[derive(EsFluent)]
+#[allow(dead_code)]
+struct Invoice<'a> {
+    customer: &'a str,
+    total: Option<u64>,
+    #[fluent(arg = "line_count")]
+    lines: u32,
+}
+
+#[derive(EsFluent)]
+#[allow(dead_code)]
+enum Transfer {
+    Sent(u32, String),
+    Failed { code: i16, reason: String },
+    Idle,
+}
+
+#[derive(EsFluent)]
+#[allow(dead_code)]
+struct Basket {
+    #[fluent(value = |items: &Vec<String>| -> usize { items.len() })]
+    items: Vec<String>,
+    #[fluent(value = |owner: &String| owner.to_uppercase())]
+    owner: String,
+}
+
+fn variant(type_name: &str, entry_id: &str) -> &'static FtlVariant {
+    es_fluent::registry::get_all_ftl_type_infos()
+        .find(|info| info.type_name() == type_name)
+        .and_then(|info| {
+            info.variants()
+                .iter()
+                .find(|variant| variant.entry_id().as_str() == entry_id)
+        })
+        .unwrap_or_else(|| panic!("{type_name} registers {entry_id}"))
+}
+
+fn typed_args(variant: &FtlVariant) -> Vec<(&'static str, Option<&'static str>)> {
+    variant
+        .typed_args()
+        .map(|arg| (arg.name().as_str(), arg.ty()))
+        .collect()
+}
+
+#[test]
+fn named_fields_register_their_rust_types() {
+    assert_eq!(
+        typed_args(variant("Invoice", "invoice")),
+        [
+            ("customer", Some("&'a str")),
+            ("total", Some("Option<u64>")),
+            ("line_count", Some("u32")),
+        ]
+    );
+    assert_eq!(
+        typed_args(variant("Transfer", "transfer-Failed")),
+        [("code", Some("i16")), ("reason", Some("String"))]
+    );
+}
+
+#[test]
+fn tuple_fields_register_their_rust_types() {
+    let sent = variant("Transfer", "transfer-Sent");
+    assert_eq!(
+        typed_args(sent),
+        [("f0", Some("u32")), ("f1", Some("String"))]
+    );
+    assert_eq!(
+        sent.args()
+            .iter()
+            .map(|arg| arg.as_str())
+            .collect::<Vec<_>>(),
+        ["f0", "f1"]
+    );
+    assert!(typed_args(variant("Transfer", "transfer-Idle")).is_empty());
+}
+
+#[test]
+fn value_transforms_register_their_declared_output_type() {
+    assert_eq!(
+        typed_args(variant("Basket", "basket")),
+        [("items", Some("usize")), ("owner", None)]
+    );
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "derive")]
+
+//! `#[fluent(custom)]` fields reach Fluent as `FluentValue::Custom`.
+
+use es_fluent::intl_memoizer::{IntlLangMemoizer, concurrent};
+use es_fluent::registry::{StaticFluentDomain, StaticFluentEntryId};
+use es_fluent::{EsFluent, FluentArgs, FluentMessage, FluentType, FluentValue};
+use std::borrow::Cow;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Money {
+    cents: u64,
+    currency: &'static str,
+}
+
+impl Money {
+    fn render(&self) -> String {
+        format!(
+            "{}.{:02} {}",
+            self.cents / 100,
+            self.cents % 100,
+            self.currency
+        )
+    }
+}
+
+impl FluentType for Money {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(&self, _intls: &IntlLangMemoizer) -> Cow<'static, str> {
+        self.render().into()
+    }
+
+    fn as_string_threadsafe(&self, _intls: &concurrent::IntlLangMemoizer) -> Cow<'static, str> {
+        self.render().into()
+    }
+}
+
+#[derive(EsFluent)]
+struct OrderTotal {
+    #[fluent(custom)]
+    total: Money,
+}
+
+#[derive(EsFluent)]
+#[allow(dead_code)]
+enum Invoice {
+    Due(#[fluent(custom)] Money),
+    Paid {
+        #[fluent(custom)]
+        amount: Money,
+    },
+}
+
+fn rendered_custom_args(message: &impl FluentMessage) -> Vec<(String, String)> {
+    let intls = IntlLangMemoizer::new("en".parse().expect("valid language"));
+    let mut rendered = Vec::new();
+    message.to_fluent_string_with(
+        &mut |_domain: StaticFluentDomain,
+              _id: StaticFluentEntryId,
+              args: Option<&FluentArgs<'_>>| {
+            for (name, value) in args.expect("custom fields are arguments").as_raw() {
+                let FluentValue::Custom(value) = value else {
+                    panic!("expected a custom FluentValue for `{name}`, got {value:?}");
+                };
+                rendered.push((name.to_string(), value.as_string(&intls).into_owned()));
+            }
+            "rendered".to_string()
+        },
+    );
+    rendered
+}
+
+#[test]
+fn custom_struct_fields_are_passed_as_custom_fluent_values() {
+    let order = OrderTotal {
+        total: Money {
+            cents: 4_250,
+            currency: "EUR",
+        },
+    };
+
+    assert_eq!(
+        rendered_custom_args(&order),
+        [("total".to_string(), "42.50 EUR".to_string())]
+    );
+}
+
+#[test]
+fn custom_enum_fields_are_passed_as_custom_fluent_values() {
+    let due = Invoice::Due(Money {
+        cents: 1_999,
+        currency: "USD",
+    });
+    let paid = Invoice::Paid {
+        amount: Money {
+            cents: 5,
+            currency: "USD",
+        },
+    };
+
+    assert_eq!(
+        rendered_custom_args(&due),
+        [("f0".to_string(), "19.99 USD".to_string())]
+    );
+    assert_eq!(
+        rendered_custom_args(&paid),
+        [("amount".to_string(), "0.05 USD".to_string())]
+    );
+}
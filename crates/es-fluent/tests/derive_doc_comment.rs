@@ -0,0 +1,74 @@
+#![cfg(feature = "derive")]
+
+//! Tests for `#[fluent(doc_as_comment)]` FTL message comments.
+
+use es_fluent::EsFluent;
+use es_fluent_generate::FluentParseMode;
+use tempfile::TempDir;
+
+/// Status of a synchronized file.
+#[derive(EsFluent)]
+#[fluent(doc_as_comment)]
+#[allow(dead_code)]
+enum SyncStatus {
+    /// This variant is missing from the FTL file.
+    Missing,
+    Fresh,
+}
+
+/// Shown when the upload finishes.
+#[derive(EsFluent)]
+#[fluent(doc_as_comment)]
+#[allow(dead_code)]
+struct UploadComplete {
+    file_name: String,
+}
+
+/// Not copied: this type does not opt in.
+#[derive(EsFluent)]
+#[allow(dead_code)]
+struct UploadFailed;
+
+fn generated_ftl(type_names: &[&str]) -> String {
+    let temp_dir = TempDir::new().expect("tempdir");
+    let i18n_path = temp_dir.path().join("i18n");
+    let infos: Vec<_> = es_fluent::registry::get_all_ftl_type_infos()
+        .filter(|info| type_names.contains(&info.type_name()))
+        .collect();
+    assert_eq!(infos.len(), type_names.len());
+
+    es_fluent_generate::generate(
+        "test_doc_comment",
+        &i18n_path,
+        temp_dir.path(),
+        &infos,
+        FluentParseMode::Aggressive,
+        false,
+    )
+    .expect("generate");
+
+    std::fs::read_to_string(i18n_path.join("test_doc_comment.ftl")).expect("read generated ftl")
+}
+
+#[test]
+fn variant_doc_comments_become_message_comments() {
+    let content = generated_ftl(&["SyncStatus"]);
+
+    assert!(
+        content.contains(
+            "# This variant is missing from the FTL file.\nsync_status-Missing = Missing\n"
+        )
+    );
+    assert!(content.contains("\nsync_status-Fresh = Fresh\n"));
+    assert!(!content.contains("# Status of a synchronized file."));
+}
+
+#[test]
+fn struct_doc_comment_is_written_only_when_opted_in() {
+    let content = generated_ftl(&["UploadComplete", "UploadFailed"]);
+
+    assert!(content.contains(
+        "# Shown when the upload finishes.\nupload_complete = Upload Complete { $file_name }\n"
+    ));
+    assert!(!content.contains("Not copied"));
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "derive")]
+
+//! Tests for the `ALL_FTL_KEYS` const emitted by `#[fluent(export_keys)]`.
+
+use es_fluent::{EsFluent, FluentMessage as _};
+
+#[derive(EsFluent)]
+#[fluent(export_keys)]
+#[allow(dead_code)]
+enum LoginError {
+    InvalidPassword,
+    #[fluent(key = "locked")]
+    AccountLocked {
+        minutes: u32,
+    },
+}
+
+#[derive(EsFluent)]
+#[fluent(export_keys, case = "kebab")]
+#[allow(dead_code)]
+struct WelcomeBanner<'a> {
+    name: &'a str,
+}
+
+#[derive(EsFluent)]
+#[fluent(export_keys)]
+#[allow(dead_code)]
+enum NoMessages {}
+
+#[test]
+fn export_keys_lists_every_message_id_in_declaration_order() {
+    assert_eq!(
+        LoginError::ALL_FTL_KEYS,
+        ["login_error-InvalidPassword", "login_error-locked"]
+    );
+    assert_eq!(WelcomeBanner::ALL_FTL_KEYS, ["welcome-banner"]);
+    assert!(NoMessages::ALL_FTL_KEYS.is_empty());
+}
+
+#[test]
+fn export_keys_match_the_ids_used_for_runtime_lookup() {
+    let runtime_ids: Vec<_> = LoginError::fluent_entry_ids()
+        .iter()
+        .map(|id| id.as_str())
+        .collect();
+    assert_eq!(LoginError::ALL_FTL_KEYS, runtime_ids);
+
+    let runtime_ids: Vec<_> = WelcomeBanner::fluent_entry_ids()
+        .iter()
+        .map(|id| id.as_str())
+        .collect();
+    assert_eq!(WelcomeBanner::ALL_FTL_KEYS, runtime_ids);
+}
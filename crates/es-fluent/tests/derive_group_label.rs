@@ -0,0 +1,66 @@
+#![cfg(feature = "derive")]
+
+//! Tests for `#[fluent(group = "...")]` group header text.
+
+use es_fluent::EsFluent;
+use es_fluent_generate::FluentParseMode;
+use tempfile::TempDir;
+
+#[derive(EsFluent)]
+#[fluent(group = "User Facing Errors")]
+#[allow(dead_code)]
+enum LoginError {
+    Locked,
+}
+
+#[derive(EsFluent)]
+#[fluent(group = "User Facing Errors")]
+#[allow(dead_code)]
+struct QuotaExceeded {
+    limit: u32,
+}
+
+#[derive(EsFluent)]
+#[allow(dead_code)]
+struct Greeting;
+
+#[test]
+fn group_label_is_registered_with_the_type_info() {
+    let info = es_fluent::registry::get_all_ftl_type_infos()
+        .find(|info| info.type_name() == "LoginError")
+        .expect("LoginError registered");
+    assert_eq!(info.group_label(), Some("User Facing Errors"));
+    assert_eq!(info.group_name(), "User Facing Errors");
+
+    let info = es_fluent::registry::get_all_ftl_type_infos()
+        .find(|info| info.type_name() == "Greeting")
+        .expect("Greeting registered");
+    assert_eq!(info.group_label(), None);
+    assert_eq!(info.group_name(), "Greeting");
+}
+
+#[test]
+fn types_sharing_a_group_label_share_one_group_header() {
+    let temp_dir = TempDir::new().expect("tempdir");
+    let i18n_path = temp_dir.path().join("i18n");
+    let infos: Vec<_> = es_fluent::registry::get_all_ftl_type_infos()
+        .filter(|info| ["LoginError", "QuotaExceeded", "Greeting"].contains(&info.type_name()))
+        .collect();
+
+    es_fluent_generate::generate(
+        "test_group_label",
+        &i18n_path,
+        temp_dir.path(),
+        &infos,
+        FluentParseMode::Aggressive,
+        false,
+    )
+    .expect("generate");
+
+    let content =
+        std::fs::read_to_string(i18n_path.join("test_group_label.ftl")).expect("read ftl");
+    assert_eq!(
+        content,
+        "## Greeting\n\ngreeting = Greeting\n\n## User Facing Errors\n\nlogin_error-Locked = Locked\nquota_exceeded = Quota Exceeded { $limit }\n"
+    );
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "derive")]
+
+//! FTL generation reads message ids from the inventory entries emitted by the
+//! derive, so explicit `key`/`id` overrides must register the same ids that
+//! the derived `FluentMessage` impls look up at runtime.
+
+use es_fluent::registry::{StaticFluentDomain, StaticFluentEntryId};
+use es_fluent::{EsFluent, FluentArgs, FluentMessage};
+
+#[derive(EsFluent)]
+enum Greeting {
+    #[fluent(key = "hello")]
+    Hello,
+    #[fluent(key = "hello_named")]
+    Named {
+        name: String,
+    },
+    #[fluent(key = "hello_tuple")]
+    Tuple(String),
+    Plain,
+}
+
+#[derive(EsFluent)]
+#[fluent(id = "custom_farewell")]
+enum Farewell {
+    #[fluent(key = "bye")]
+    Bye,
+    Later,
+}
+
+fn runtime_id(message: &impl FluentMessage) -> String {
+    let mut id = None;
+    {
+        let mut localize = |_domain: StaticFluentDomain,
+                            entry_id: StaticFluentEntryId,
+                            _args: Option<&FluentArgs<'_>>| {
+            id = Some(entry_id.as_str().to_string());
+            String::new()
+        };
+
+        message.to_fluent_string_with(&mut localize);
+    }
+
+    id.expect("derived message localizes an id")
+}
+
+fn registered_ids(type_name: &str) -> Vec<String> {
+    let mut ids: Vec<_> = es_fluent::registry::get_all_ftl_type_infos()
+        .filter(|info| info.type_name() == type_name)
+        .flat_map(|info| info.variants())
+        .map(|variant| variant.entry_id().as_str().to_string())
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn explicit_variant_keys_register_runtime_ids() {
+    assert_eq!(
+        registered_ids("Greeting"),
+        [
+            "greeting-Plain",
+            "greeting-hello",
+            "greeting-hello_named",
+            "greeting-hello_tuple",
+        ]
+    );
+
+    for message in [
+        Greeting::Hello,
+        Greeting::Named {
+            name: "Ada".to_string(),
+        },
+        Greeting::Tuple("Ada".to_string()),
+        Greeting::Plain,
+    ] {
+        let id = runtime_id(&message);
+        assert!(
+            registered_ids("Greeting").contains(&id),
+            "runtime id `{id}` is not registered for generation"
+        );
+    }
+}
+
+#[test]
+fn explicit_enum_id_registers_runtime_ids() {
+    assert_eq!(
+        registered_ids("Farewell"),
+        ["custom_farewell-Later", "custom_farewell-bye"]
+    );
+    assert_eq!(runtime_id(&Farewell::Bye), "custom_farewell-bye");
+    assert_eq!(runtime_id(&Farewell::Later), "custom_farewell-Later");
+}
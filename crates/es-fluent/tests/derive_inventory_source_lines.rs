@@ -47,6 +47,46 @@ fn inventory_line(type_name: &str, ftl_key: &str) -> u32 {
     variant.source_line().get()
 }
 
+fn type_location(type_name: &str) -> (String, u32) {
+    let (_, location) = es_fluent::registry::all_type_infos_with_location()
+        .into_iter()
+        .find(|(info, _)| info.type_name() == type_name)
+        .unwrap_or_else(|| panic!("registered type {type_name} exists"));
+    let location = location.expect("derived types record their location");
+
+    (location.file().to_string(), location.line().get())
+}
+
+#[test]
+fn derive_inventory_type_locations_point_at_type_names() {
+    let file = file!().to_string();
+
+    assert_eq!(
+        type_location("InventoryLineEnum"),
+        (file.clone(), marker_line("enum InventoryLineEnum"))
+    );
+    assert_eq!(
+        type_location("InventoryLineStruct"),
+        (file.clone(), marker_line("struct InventoryLineStruct"))
+    );
+    assert_eq!(
+        type_location("InventoryLineLabel"),
+        (file, marker_line("enum InventoryLineLabel"))
+    );
+}
+
+#[test]
+fn all_type_infos_with_location_is_sorted_by_type_name() {
+    let infos = es_fluent::registry::all_type_infos_with_location();
+    let names: Vec<_> = infos.iter().map(|(info, _)| info.type_name()).collect();
+
+    assert!(names.is_sorted());
+    assert_eq!(
+        infos.len(),
+        es_fluent::registry::get_all_ftl_type_infos().count()
+    );
+}
+
 #[test]
 fn derive_inventory_source_lines_point_at_source_items() {
     assert_eq!(
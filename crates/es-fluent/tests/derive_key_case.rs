@@ -0,0 +1,132 @@
+#![cfg(feature = "derive")]
+
+//! Tests for `#[fluent(case = "...")]` message id casing.
+//!
+//! The derive and the generator must agree on the cased id, so FTL generated
+//! from the registry resolves the same type at runtime.
+
+use es_fluent::__manager_core::{SyncFluentBundle, build_sync_bundle, localize_with_bundle};
+use es_fluent::registry::{StaticFluentDomain, StaticFluentEntryId};
+use es_fluent::{EsFluent, FluentArgs, FluentLocalizer, FluentLocalizerExt as _};
+use es_fluent_generate::FluentParseMode;
+use fluent_bundle::FluentResource;
+use std::sync::Arc;
+use tempfile::TempDir;
+
+#[derive(EsFluent)]
+#[fluent(case = "kebab")]
+enum PaymentStatus {
+    AwaitingPayment,
+    Refunded {
+        amount: u32,
+    },
+    #[fluent(key = "on_hold")]
+    OnHold,
+}
+
+#[derive(EsFluent)]
+#[fluent(case = "camel")]
+struct ShippingNotice {
+    carrier: String,
+}
+
+struct BundleLocalizer {
+    bundle: SyncFluentBundle,
+}
+
+impl BundleLocalizer {
+    fn from_generated(type_names: &[&str]) -> Self {
+        let temp_dir = TempDir::new().expect("tempdir");
+        let i18n_path = temp_dir.path().join("i18n");
+        let infos: Vec<_> = es_fluent::registry::get_all_ftl_type_infos()
+            .filter(|info| type_names.contains(&info.type_name()))
+            .collect();
+        assert_eq!(infos.len(), type_names.len());
+
+        es_fluent_generate::generate(
+            "test_key_case",
+            &i18n_path,
+            temp_dir.path(),
+            &infos,
+            FluentParseMode::Aggressive,
+            false,
+        )
+        .expect("generate");
+
+        let content = std::fs::read_to_string(i18n_path.join("test_key_case.ftl"))
+            .expect("read generated ftl");
+        let resource = FluentResource::try_new(content).expect("generated ftl parses");
+        let (mut bundle, add_errors) =
+            build_sync_bundle(&unic_langid::langid!("en-US"), [Arc::new(resource)]);
+        assert!(add_errors.iter().all(Vec::is_empty));
+        bundle.set_use_isolating(false);
+
+        Self { bundle }
+    }
+
+    fn contains(&self, id: &str) -> bool {
+        self.bundle.has_message(id)
+    }
+}
+
+impl FluentLocalizer for BundleLocalizer {
+    fn localize<'a>(
+        &self,
+        id: StaticFluentEntryId,
+        args: Option<&FluentArgs<'a>>,
+    ) -> Option<String> {
+        let (value, errors) = localize_with_bundle(&self.bundle, id, args.map(FluentArgs::as_raw))?;
+        errors.is_empty().then_some(value)
+    }
+
+    fn localize_in_domain<'a>(
+        &self,
+        _domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+        args: Option<&FluentArgs<'a>>,
+    ) -> Option<String> {
+        self.localize(id, args)
+    }
+}
+
+#[test]
+fn kebab_cased_enum_resolves_against_generated_ftl() {
+    let localizer = BundleLocalizer::from_generated(&["PaymentStatus"]);
+
+    assert!(localizer.contains("payment-status-awaiting-payment"));
+    assert!(localizer.contains("payment-status-refunded"));
+    assert!(localizer.contains("payment-status-on_hold"));
+    assert!(!localizer.contains("payment_status-AwaitingPayment"));
+
+    for status in [
+        PaymentStatus::AwaitingPayment,
+        PaymentStatus::Refunded { amount: 3 },
+        PaymentStatus::OnHold,
+    ] {
+        let rendered = localizer
+            .try_localize(&status)
+            .expect("cased id resolves at runtime");
+        assert!(!rendered.is_empty());
+    }
+    assert_eq!(
+        localizer
+            .try_localize(&PaymentStatus::Refunded { amount: 3 })
+            .expect("refunded"),
+        "Refunded 3"
+    );
+}
+
+#[test]
+fn camel_cased_struct_resolves_against_generated_ftl() {
+    let localizer = BundleLocalizer::from_generated(&["ShippingNotice"]);
+
+    assert!(localizer.contains("shippingNotice"));
+    assert_eq!(
+        localizer
+            .try_localize(&ShippingNotice {
+                carrier: "Post".to_string(),
+            })
+            .expect("cased id resolves at runtime"),
+        "Shipping Notice Post"
+    );
+}
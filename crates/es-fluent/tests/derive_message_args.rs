@@ -64,6 +64,12 @@ struct GeneratedVariantSelector {
     field: GeneratedChoiceFormVariants,
 }
 
+#[derive(EsFluent)]
+struct PluralCart {
+    #[fluent(plural, value = |items: &Vec<String>| items.len())]
+    items: Vec<String>,
+}
+
 fn describe_arg(value: &FluentValue<'_>) -> String {
     match value {
         FluentValue::String(value) => value.as_ref().to_string(),
@@ -227,3 +233,19 @@ fn es_fluent_variants_generated_enums_infer_choice_and_render_as_selectors() {
     });
     assert_eq!(args["field"], "username");
 }
+
+#[test]
+fn plural_fields_render_their_value_and_register_for_generation() {
+    let args = render_args(&PluralCart {
+        items: vec!["apple".to_string(), "pear".to_string()],
+    });
+    assert!(args["items"].contains('2'));
+
+    let plurals: Vec<_> = es_fluent::registry::get_all_ftl_type_infos()
+        .filter(|info| info.type_name() == "PluralCart")
+        .flat_map(|info| info.variants())
+        .flat_map(|variant| variant.plurals())
+        .map(|arg| arg.as_str())
+        .collect();
+    assert_eq!(plurals, ["items"]);
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "derive")]
+
+//! Tests for `#[fluent(attributes = [...])]` message attributes.
+
+use es_fluent::{EsFluent, FluentLocalizerExt as _};
+use es_fluent_generate::FluentParseMode;
+use es_fluent_manager_core::FluentManager;
+use std::collections::HashMap;
+use tempfile::TempDir;
+use unic_langid::langid;
+
+#[derive(EsFluent)]
+#[fluent(attributes = ["label", "placeholder"])]
+#[allow(dead_code)]
+struct SearchField {
+    scope: String,
+}
+
+#[derive(EsFluent)]
+#[fluent(attributes = ["title"])]
+#[allow(dead_code)]
+enum Toolbar {
+    Save,
+    #[fluent(attributes = ["title", "aria-label"])]
+    Close,
+    #[fluent(attributes = [])]
+    Spacer,
+}
+
+fn generated_ftl(type_names: &[&str]) -> String {
+    let temp_dir = TempDir::new().expect("tempdir");
+    let i18n_path = temp_dir.path().join("i18n");
+    let infos: Vec<_> = es_fluent::registry::get_all_ftl_type_infos()
+        .filter(|info| type_names.contains(&info.type_name()))
+        .collect();
+    assert_eq!(infos.len(), type_names.len());
+
+    es_fluent_generate::generate(
+        "test_message_attributes",
+        &i18n_path,
+        temp_dir.path(),
+        &infos,
+        FluentParseMode::Aggressive,
+        false,
+    )
+    .expect("generate");
+
+    std::fs::read_to_string(i18n_path.join("test_message_attributes.ftl"))
+        .expect("read generated ftl")
+}
+
+#[test]
+fn struct_attributes_are_scaffolded_under_the_message() {
+    let content = generated_ftl(&["SearchField"]);
+
+    assert!(content.contains(
+        "search_field = Search Field { $scope }\n    .label = Search Field Label\n    .placeholder = Search Field Placeholder\n"
+    ));
+}
+
+#[test]
+fn variant_attributes_replace_the_enum_default() {
+    let content = generated_ftl(&["Toolbar"]);
+
+    assert!(content.contains("toolbar-Save = Save\n    .title = Save Title\n"));
+    assert!(content.contains(
+        "toolbar-Close = Close\n    .title = Close Title\n    .aria-label = Close Aria Label\n"
+    ));
+    assert!(content.contains("toolbar-Spacer = Spacer\n"));
+    assert!(!content.contains("Spacer Title"));
+}
+
+#[test]
+fn typed_messages_localize_requested_attributes() {
+    let mut resources = HashMap::new();
+    resources.insert(
+        langid!("en"),
+        vec![(
+            env!("CARGO_PKG_NAME").to_string(),
+            "search_field = Search { $scope }\n    .placeholder = Search { $scope }…\n".to_string(),
+        )],
+    );
+    let manager = FluentManager::from_resources(resources);
+    manager
+        .select_language(&langid!("en"))
+        .expect("en should be supported");
+
+    let field = SearchField {
+        scope: "docs".to_string(),
+    };
+    assert_eq!(
+        manager.localize_message_attribute(&field, "placeholder"),
+        Some("Search \u{2068}docs\u{2069}…".to_string())
+    );
+    assert_eq!(manager.localize_message_attribute(&field, "label"), None);
+}
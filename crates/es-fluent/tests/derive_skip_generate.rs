@@ -0,0 +1,55 @@
+#![cfg(feature = "derive")]
+
+//! Tests for `#[fluent(skip_generate)]` runtime-only types.
+
+use es_fluent::EsFluent;
+use es_fluent_generate::FluentParseMode;
+use tempfile::TempDir;
+
+#[derive(EsFluent)]
+#[fluent(skip_generate)]
+#[allow(dead_code)]
+enum SharedStatus {
+    Online,
+}
+
+#[derive(EsFluent)]
+#[allow(dead_code)]
+struct Greeting;
+
+#[test]
+fn skip_generate_types_stay_registered_for_runtime_lookup() {
+    let info = es_fluent::registry::get_all_ftl_type_infos()
+        .find(|info| info.type_name() == "SharedStatus")
+        .expect("SharedStatus registered");
+    assert!(info.skip_generate());
+    assert_eq!(info.variants().len(), 1);
+
+    let info = es_fluent::registry::get_all_ftl_type_infos()
+        .find(|info| info.type_name() == "Greeting")
+        .expect("Greeting registered");
+    assert!(!info.skip_generate());
+}
+
+#[test]
+fn skip_generate_types_are_left_out_of_generated_ftl() {
+    let temp_dir = TempDir::new().expect("tempdir");
+    let i18n_path = temp_dir.path().join("i18n");
+    let infos: Vec<_> = es_fluent::registry::get_all_ftl_type_infos()
+        .filter(|info| ["SharedStatus", "Greeting"].contains(&info.type_name()))
+        .collect();
+
+    es_fluent_generate::generate(
+        "test_skip_generate",
+        &i18n_path,
+        temp_dir.path(),
+        &infos,
+        FluentParseMode::Aggressive,
+        false,
+    )
+    .expect("generate");
+
+    let content =
+        std::fs::read_to_string(i18n_path.join("test_skip_generate.ftl")).expect("read ftl");
+    assert_eq!(content, "## Greeting\n\ngreeting = Greeting\n");
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "derive")]
+
+//! Tests for `#[fluent(as_terms)]` types generated as Fluent terms.
+
+use es_fluent::EsFluent;
+use es_fluent_generate::FluentParseMode;
+use tempfile::TempDir;
+
+#[derive(EsFluent)]
+#[fluent(as_terms)]
+struct BrandName;
+
+#[derive(EsFluent)]
+#[fluent(as_terms)]
+enum Platform {
+    Desktop,
+    Mobile,
+}
+
+#[derive(EsFluent)]
+struct Tagline;
+
+/// Reports whether `T` implements `FluentMessage`: the inherent method only
+/// applies when the bound holds, otherwise the trait fallback is picked.
+struct MessageProbe<T>(T);
+
+trait NotAMessage {
+    fn is_message(&self) -> bool {
+        false
+    }
+}
+
+impl<T> NotAMessage for MessageProbe<T> {}
+
+impl<T: es_fluent::FluentMessage> MessageProbe<T> {
+    fn is_message(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn term_types_have_no_runtime_message_impl() {
+    assert!(!MessageProbe(BrandName).is_message());
+    assert!(!MessageProbe(Platform::Desktop).is_message());
+    assert!(!MessageProbe(Platform::Mobile).is_message());
+    assert!(MessageProbe(Tagline).is_message());
+}
+
+#[test]
+fn term_types_register_the_term_flag() {
+    let info = es_fluent::registry::get_all_ftl_type_infos()
+        .find(|info| info.type_name() == "BrandName")
+        .expect("BrandName registered");
+    assert!(info.is_term());
+
+    let info = es_fluent::registry::get_all_ftl_type_infos()
+        .find(|info| info.type_name() == "Platform")
+        .expect("Platform registered");
+    assert!(info.is_term());
+}
+
+#[test]
+fn term_types_are_generated_as_terms() {
+    let temp_dir = TempDir::new().expect("tempdir");
+    let i18n_path = temp_dir.path().join("i18n");
+    let infos: Vec<_> = es_fluent::registry::get_all_ftl_type_infos()
+        .filter(|info| ["BrandName", "Platform"].contains(&info.type_name()))
+        .collect();
+
+    es_fluent_generate::generate(
+        "test_term",
+        &i18n_path,
+        temp_dir.path(),
+        &infos,
+        FluentParseMode::Aggressive,
+        false,
+    )
+    .expect("generate");
+
+    let content = std::fs::read_to_string(i18n_path.join("test_term.ftl")).expect("read ftl");
+    assert_eq!(
+        content,
+        "## BrandName\n\n-brand_name = Brand Name\n\n## Platform\n\n-platform-Desktop = Desktop\n-platform-Mobile = Mobile\n"
+    );
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "derive")]
+
+//! `#[fluent(term)]` fields register the linked type's message ids so FTL
+//! generation can emit one Fluent term per message.
+
+use es_fluent::{EsFluent, EsFluentVariants, FluentMessage as _};
+
+#[derive(EsFluent)]
+#[allow(dead_code)]
+enum UsaState {
+    Alabama,
+    Texas,
+}
+
+#[derive(EsFluentVariants)]
+#[fluent_variants(keys = ["label"])]
+#[allow(dead_code)]
+struct Address {
+    street: String,
+}
+
+#[derive(EsFluent)]
+#[allow(dead_code)]
+enum Country {
+    Usa(#[fluent(term)] UsaState),
+    Canada(String),
+    Field {
+        #[fluent(term)]
+        field: AddressLabelVariants,
+    },
+}
+
+fn term_link_ids(variant_id: &str) -> Vec<(String, Vec<String>)> {
+    es_fluent::registry::get_all_ftl_type_infos()
+        .filter(|info| info.type_name() == "Country")
+        .flat_map(|info| info.variants())
+        .filter(|variant| variant.entry_id().as_str() == variant_id)
+        .flat_map(|variant| variant.term_links())
+        .map(|link| {
+            (
+                link.arg().as_str().to_string(),
+                link.entry_ids()
+                    .iter()
+                    .map(|id| id.as_str().to_string())
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn derived_types_list_their_message_ids() {
+    let ids: Vec<_> = UsaState::fluent_entry_ids()
+        .iter()
+        .map(|id| id.as_str())
+        .collect();
+    assert_eq!(ids, ["usa_state-Alabama", "usa_state-Texas"]);
+}
+
+#[test]
+fn term_fields_register_links_to_the_field_type_messages() {
+    assert_eq!(
+        term_link_ids("country-Usa"),
+        [(
+            "f0".to_string(),
+            vec![
+                "usa_state-Alabama".to_string(),
+                "usa_state-Texas".to_string()
+            ]
+        )]
+    );
+    assert_eq!(
+        term_link_ids("country-Field"),
+        [(
+            "field".to_string(),
+            vec!["address_label_variants-street".to_string()]
+        )]
+    );
+    assert!(term_link_ids("country-Canada").is_empty());
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "derive")]
+
+//! Tests for the `__assert_all_ftl_keys_present` check emitted by
+//! `#[fluent(test_all_keys)]`.
+
+use es_fluent::EsFluent;
+use es_fluent_manager_core::FluentManager;
+use std::collections::HashMap;
+use unic_langid::langid;
+
+#[derive(EsFluent)]
+#[fluent(test_all_keys)]
+#[allow(dead_code)]
+enum PaymentError {
+    Declined,
+    #[fluent(key = "limit")]
+    LimitExceeded {
+        amount: u32,
+    },
+    #[fluent(skip)]
+    Internal,
+}
+
+fn manager(ftl: &str) -> FluentManager {
+    let mut resources = HashMap::new();
+    resources.insert(
+        langid!("en"),
+        vec![(env!("CARGO_PKG_NAME").to_string(), ftl.to_string())],
+    );
+    let manager = FluentManager::from_resources(resources);
+    manager
+        .select_language(&langid!("en"))
+        .expect("en should be supported");
+    manager
+}
+
+#[test]
+fn test_all_keys_accepts_complete_translations_with_arguments() {
+    let manager = manager(
+        "payment_error-Declined = Card declined\npayment_error-limit = Over the { $amount } limit\n",
+    );
+
+    PaymentError::__assert_all_ftl_keys_present(&manager);
+}
+
+#[test]
+#[should_panic(
+    expected = "`PaymentError` has no translation in domain `es-fluent` for: payment_error-limit"
+)]
+fn test_all_keys_names_each_variant_without_a_translation() {
+    let manager = manager("payment_error-Declined = Card declined\n");
+
+    PaymentError::__assert_all_ftl_keys_present(&manager);
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "derive")]
+
+//! Tests for variant-level `#[fluent(namespace = "...")]` overrides.
+
+use es_fluent::EsFluent;
+use es_fluent::registry::NamespaceRule;
+use es_fluent_generate::FluentParseMode;
+use tempfile::TempDir;
+
+#[derive(EsFluent)]
+#[fluent(namespace = "errors")]
+#[allow(dead_code)]
+enum AppError {
+    NotFound,
+    #[fluent(namespace = "network")]
+    Timeout {
+        seconds: u32,
+    },
+    #[fluent(namespace = "network")]
+    Offline,
+}
+
+fn app_error_infos() -> Vec<&'static es_fluent::registry::FtlTypeInfo> {
+    es_fluent::registry::get_all_ftl_type_infos()
+        .filter(|info| info.type_name() == "AppError")
+        .collect()
+}
+
+#[test]
+fn variant_namespace_registers_one_type_info_per_namespace() {
+    let mut routed: Vec<_> = app_error_infos()
+        .into_iter()
+        .map(|info| {
+            let namespace = match info.namespace() {
+                Some(NamespaceRule::Literal(namespace)) => namespace.as_str().to_string(),
+                other => panic!("unexpected namespace {other:?}"),
+            };
+            let keys: Vec<_> = info
+                .variants()
+                .iter()
+                .map(|variant| variant.entry_id().to_string())
+                .collect();
+            (namespace, keys)
+        })
+        .collect();
+    routed.sort();
+
+    assert_eq!(
+        routed,
+        vec![
+            ("errors".to_string(), vec!["app_error-NotFound".to_string()]),
+            (
+                "network".to_string(),
+                vec![
+                    "app_error-Timeout".to_string(),
+                    "app_error-Offline".to_string()
+                ]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn variant_namespace_writes_each_variant_to_its_own_file() {
+    let temp_dir = TempDir::new().expect("tempdir");
+    let i18n_path = temp_dir.path().join("i18n");
+    let infos = app_error_infos();
+
+    es_fluent_generate::generate(
+        "test_variant_namespace",
+        &i18n_path,
+        temp_dir.path(),
+        &infos,
+        FluentParseMode::Aggressive,
+        false,
+    )
+    .expect("generate");
+
+    let crate_dir = i18n_path.join("test_variant_namespace");
+    assert_eq!(
+        std::fs::read_to_string(crate_dir.join("errors.ftl")).expect("read errors.ftl"),
+        "## AppError\n\napp_error-NotFound = Not Found\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(crate_dir.join("network.ftl")).expect("read network.ftl"),
+        "## AppError\n\napp_error-Offline = Offline\napp_error-Timeout = Timeout { $seconds }\n"
+    );
+}
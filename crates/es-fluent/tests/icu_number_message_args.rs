@@ -0,0 +1,59 @@
+#![cfg(all(feature = "derive", feature = "icu-number"))]
+
+use es_fluent::registry::{StaticFluentDomain, StaticFluentEntryId};
+use es_fluent::{EsFluent, FluentArgs, FluentMessage, FluentValue, FormattedNumber};
+use std::collections::HashMap;
+
+#[derive(EsFluent)]
+struct AccountSummary<'a> {
+    balance: FormattedNumber,
+    borrowed_balance: &'a FormattedNumber,
+    maybe_limit_present: Option<FormattedNumber>,
+    maybe_limit_missing: Option<FormattedNumber>,
+    #[fluent(value = |value: &u64| FormattedNumber::from(*value).fraction_digits(2))]
+    transformed_cents: u64,
+}
+
+fn render_args(message: &impl FluentMessage, language: &str) -> HashMap<String, String> {
+    let mut rendered = HashMap::new();
+    let intls = intl_memoizer::IntlLangMemoizer::new(language.parse().unwrap());
+    message.to_fluent_string_with(
+        &mut |_domain: StaticFluentDomain,
+              _id: StaticFluentEntryId,
+              args: Option<&FluentArgs<'_>>| {
+            for (name, value) in args.expect("formatted number arguments").as_raw() {
+                let value = match value {
+                    FluentValue::Custom(value) => value.as_string(&intls).into_owned(),
+                    FluentValue::None => "<none>".to_string(),
+                    other => panic!("expected a formatted number custom value, got {other:?}"),
+                };
+                rendered.insert(name.as_str().to_string(), value);
+            }
+            "rendered".to_string()
+        },
+    );
+    rendered
+}
+
+#[test]
+fn derived_messages_accept_owned_borrowed_optional_and_transformed_numbers() {
+    let borrowed_balance = FormattedNumber::from(-42_000.5).fraction_digits(2);
+    let message = AccountSummary {
+        balance: FormattedNumber::from(1_234_567),
+        borrowed_balance: &borrowed_balance,
+        maybe_limit_present: Some(FormattedNumber::from(10_000u32).use_grouping(false)),
+        maybe_limit_missing: None,
+        transformed_cents: 1_999,
+    };
+
+    let en = render_args(&message, "en-US");
+    assert_eq!(en["balance"], "1,234,567");
+    assert_eq!(en["borrowed_balance"], "-42,000.50");
+    assert_eq!(en["maybe_limit_present"], "10000");
+    assert_eq!(en["maybe_limit_missing"], "<none>");
+    assert_eq!(en["transformed_cents"], "1,999.00");
+
+    let de = render_args(&message, "de-DE");
+    assert_eq!(de["balance"], "1.234.567");
+    assert_eq!(de["borrowed_balance"], "-42.000,50");
+}
@@ -0,0 +1,83 @@
+//! Tests for the process-wide missing-key formatter.
+
+use es_fluent::registry::{StaticFluentDomain, StaticFluentEntryId};
+use es_fluent::{
+    FluentArgs, FluentLocalizer, FluentLocalizerChain, FluentLocalizerExt as _, FluentMessage,
+    FluentMessageLookup,
+};
+
+struct HelloLocalizer;
+
+impl FluentLocalizer for HelloLocalizer {
+    fn localize<'a>(
+        &self,
+        id: StaticFluentEntryId,
+        _args: Option<&FluentArgs<'a>>,
+    ) -> Option<String> {
+        (id == "hello").then(|| "Hello".to_string())
+    }
+
+    fn localize_in_domain<'a>(
+        &self,
+        _domain: StaticFluentDomain,
+        id: StaticFluentEntryId,
+        args: Option<&FluentArgs<'a>>,
+    ) -> Option<String> {
+        self.localize(id, args)
+    }
+}
+
+struct Greeting;
+
+impl FluentMessage for Greeting {
+    fn to_fluent_string_with(&self, localize: &mut FluentMessageLookup<'_>) -> String {
+        let domain = StaticFluentDomain::try_new("demo").expect("valid domain");
+        let hello = localize(
+            domain,
+            StaticFluentEntryId::try_new("hello").expect("valid id"),
+            None,
+        );
+        let hint = localize(
+            domain,
+            StaticFluentEntryId::try_new("toggle-language-hint").expect("valid id"),
+            None,
+        );
+        format!("{hello} {hint}")
+    }
+}
+
+// One test owns the global formatter so parallel tests cannot observe it.
+#[test]
+fn missing_key_formatter_renders_missing_messages_until_cleared() {
+    let chain = FluentLocalizerChain::new();
+    chain.push_custom_localizer(HelloLocalizer);
+    assert_eq!(es_fluent::format_missing_key("hello"), None);
+
+    es_fluent::set_missing_key_formatter(|id| format!("⟦{id}⟧"));
+    assert_eq!(
+        chain.localize_message(&Greeting),
+        "Hello ⟦toggle-language-hint⟧"
+    );
+    assert_eq!(
+        chain.display_message(&Greeting).to_string(),
+        "Hello ⟦toggle-language-hint⟧"
+    );
+    assert!(chain.try_localize_message(&Greeting).is_none());
+
+    es_fluent::set_missing_key_formatter(str::to_owned);
+    assert_eq!(
+        chain.localize_message(&Greeting),
+        "Hello toggle-language-hint"
+    );
+
+    es_fluent::clear_missing_key_formatter();
+    assert_eq!(es_fluent::format_missing_key("hello"), None);
+    let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        chain.localize_message(&Greeting)
+    }))
+    .expect_err("missing message panics without a formatter");
+    let message = panic
+        .downcast_ref::<String>()
+        .expect("panic message is a String");
+    assert!(message.contains("missing Fluent message `toggle-language-hint`"));
+}
@@ -17,6 +17,8 @@ button_state-Pressed = Pressed
 canada_province_label = Canada Province
 canada_province-A = A
 canada_province-B = B
+-canada_province-A = { canada_province-A }
+-canada_province-B = { canada_province-B }
 
 ## Country
 
@@ -60,3 +62,4 @@ split_variants_label_variants-country = Country
 
 usa_state_label = Usa State
 usa_state-A = A
+-usa_state-A = { usa_state-A }
@@ -17,6 +17,8 @@ button_state-Pressed = Appuyé
 canada_province_label = Province canadienne
 canada_province-A = Alberta
 canada_province-B = Colombie-Britannique
+-canada_province-A = { canada_province-A }
+-canada_province-B = { canada_province-B }
 
 ## Country
 
@@ -64,3 +66,4 @@ split_variants_label_variants-country = Pays
 usa_state_label = État des États-Unis
 # es-fluent: same-as-fallback
 usa_state-A = A
+-usa_state-A = { usa_state-A }
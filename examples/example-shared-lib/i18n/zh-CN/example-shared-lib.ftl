@@ -17,6 +17,8 @@ button_state-Pressed = 按下
 canada_province_label = 加拿大省份
 canada_province-A = 阿尔伯塔省
 canada_province-B = 不列颠哥伦比亚省
+-canada_province-A = { canada_province-A }
+-canada_province-B = { canada_province-B }
 
 ## Country
 
@@ -61,3 +63,4 @@ split_variants_label_variants-country = 国家
 usa_state_label = 美国州
 # es-fluent: same-as-fallback
 usa_state-A = A
+-usa_state-A = { usa_state-A }
@@ -1,6 +1,5 @@
 use es_fluent::{EsFluent, EsFluentLabel, EsFluentVariants};
 use es_fluent_lang::es_fluent_language;
-use strum::EnumIter;
 
 #[cfg(feature = "bevy")]
 use bevy::prelude::*;
@@ -27,18 +26,8 @@ pub enum ButtonState {
 impl gpui::Global for CurrentLanguage {}
 
 #[es_fluent_language]
-#[derive(Clone, Copy, Debug, EnumIter, Eq, PartialEq)]
 pub enum Languages {}
 
-impl Languages {
-    pub fn next(self) -> Self {
-        use strum::IntoEnumIterator as _;
-        let all = Self::iter().collect::<Vec<_>>();
-        let current_index = all.iter().position(|&l| l == self).unwrap_or(0);
-        all[(current_index + 1) % all.len()]
-    }
-}
-
 pub struct CurrentLanguage(pub Languages);
 
 #[derive(Clone, Debug, Default, EsFluent, EsFluentLabel)]
@@ -53,8 +42,8 @@ pub enum EmptyEnum {}
 #[derive(Clone, EsFluent, EsFluentLabel, EsFluentVariants)]
 #[fluent_variants(keys = ["description", "label"])]
 pub enum Country {
-    USA(USAState),
-    Canada(CanadaProvince),
+    USA(#[fluent(term)] USAState),
+    Canada(#[fluent(term)] CanadaProvince),
 }
 
 #[derive(Clone, EsFluent, EsFluentLabel)]
@@ -72,6 +72,10 @@ network_error-ApiUnavailable = API is unavailable
 
 operation_elapsed = Completed in { $elapsed }
 
+## OrderTotal
+
+order_total = Total: { $total }
+
 ## SettingsTabVariants
 
 settings_tab_variants_label = Settings Tab Variants
@@ -72,6 +72,10 @@ network_error-ApiUnavailable = API indisponible
 
 operation_elapsed = Terminé en { $elapsed }
 
+## OrderTotal
+
+order_total = Total : { $total }
+
 ## SettingsTabVariants
 
 settings_tab_variants_label = Variantes d’onglets de paramètres
@@ -72,6 +72,10 @@ network_error-ApiUnavailable = API 不可用
 
 operation_elapsed = 用时 { $elapsed }
 
+## OrderTotal
+
+order_total = 总计：{ $total }
+
 ## SettingsTabVariants
 
 settings_tab_variants_label = 设置选项卡变体
@@ -42,6 +42,50 @@ pub struct OperationElapsed {
     pub elapsed: std::time::Duration,
 }
 
+// Types implementing FluentType reach Fluent as FluentValue::Custom.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Money {
+    pub cents: u64,
+    pub currency: &'static str,
+}
+
+impl Money {
+    fn render(&self) -> String {
+        format!(
+            "{}.{:02} {}",
+            self.cents / 100,
+            self.cents % 100,
+            self.currency
+        )
+    }
+}
+
+impl es_fluent::FluentType for Money {
+    fn duplicate(&self) -> Box<dyn es_fluent::FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(
+        &self,
+        _intls: &es_fluent::intl_memoizer::IntlLangMemoizer,
+    ) -> std::borrow::Cow<'static, str> {
+        self.render().into()
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        _intls: &es_fluent::intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> std::borrow::Cow<'static, str> {
+        self.render().into()
+    }
+}
+
+#[derive(EsFluent)]
+pub struct OrderTotal {
+    #[fluent(custom)]
+    pub total: Money,
+}
+
 // Unit-only #[derive(EsFluent)] enums infer EsFluentChoice for selector fields.
 #[derive(EsFluent)]
 pub enum GenderChoice {
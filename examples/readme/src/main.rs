@@ -2,11 +2,12 @@ use es_fluent::FluentLabel as _;
 use example_shared_lib::Languages;
 use readme::*;
 use std::time::{Duration, UNIX_EPOCH};
-use strum::IntoEnumIterator as _;
 
 fn main() {
     let i18n = i18n::try_new_with_language(Languages::default()).expect("i18n should initialize");
-    Languages::iter().for_each(|language| run(&i18n, language));
+    Languages::all()
+        .iter()
+        .for_each(|&language| run(&i18n, language));
 }
 
 fn run(i18n: &i18n::I18n, locale: Languages) {
@@ -45,6 +46,13 @@ fn run(i18n: &i18n::I18n, locale: Languages) {
         elapsed: Duration::from_secs(3_723),
     };
     println!("OperationElapsed: {}", i18n.localize_message(&operation));
+    let order = OrderTotal {
+        total: Money {
+            cents: 4_250,
+            currency: "EUR",
+        },
+    };
+    println!("OrderTotal: {}", i18n.localize_message(&order));
     println!(
         "TransactionError Network: {}",
         i18n.localize_message(&TransactionError::Network(NetworkError::ApiUnavailable))